@@ -56,12 +56,19 @@ where
         + 'static,
     C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
     C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
+    C::Api: zrml_combinatorial_tokens_rpc::CombinatorialTokensRuntimeApi<
+        Block,
+        AccountId,
+        Balance,
+        MarketId,
+    >,
     C::Api: zrml_swaps_rpc::SwapsRuntimeApi<Block, PoolId, AccountId, Balance, MarketId>,
     C::Api: BlockBuilder<Block>,
     P: TransactionPool + Sync + Send + 'static,
 {
     use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
     use substrate_frame_rpc_system::{System, SystemApiServer};
+    use zrml_combinatorial_tokens_rpc::{CombinatorialTokens, CombinatorialTokensApiServer};
     use zrml_swaps_rpc::{Swaps, SwapsApiServer};
 
     let mut module = RpcExtension::new(());
@@ -69,6 +76,7 @@ where
 
     module.merge(System::new(client.clone(), pool).into_rpc())?;
     module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+    module.merge(CombinatorialTokens::new(client.clone()).into_rpc())?;
     module.merge(Swaps::new(client).into_rpc())?;
 
     Ok(module)
@@ -57,7 +57,12 @@ pub trait RuntimeApiCollection:
     + sp_api::Metadata<Block>
     + sp_offchain::OffchainWorkerApi<Block>
     + sp_session::SessionKeys<Block>
-    + zrml_swaps_rpc::SwapsRuntimeApi<Block, PoolId, AccountId, Balance, MarketId>
+    + zrml_combinatorial_tokens_rpc::CombinatorialTokensRuntimeApi<
+        Block,
+        AccountId,
+        Balance,
+        MarketId,
+    > + zrml_swaps_rpc::SwapsRuntimeApi<Block, PoolId, AccountId, Balance, MarketId>
 {
 }
 
@@ -70,7 +75,12 @@ impl<Api> RuntimeApiCollection for Api where
         + sp_api::Metadata<Block>
         + sp_offchain::OffchainWorkerApi<Block>
         + sp_session::SessionKeys<Block>
-        + zrml_swaps_rpc::SwapsRuntimeApi<Block, PoolId, AccountId, Balance, MarketId>
+        + zrml_combinatorial_tokens_rpc::CombinatorialTokensRuntimeApi<
+            Block,
+            AccountId,
+            Balance,
+            MarketId,
+        > + zrml_swaps_rpc::SwapsRuntimeApi<Block, PoolId, AccountId, Balance, MarketId>
 {
 }
 
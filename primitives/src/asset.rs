@@ -22,6 +22,7 @@ use crate::{
     traits::PoolSharesId,
     types::{CategoryIndex, CombinatorialId, PoolId},
 };
+use alloc::{vec, vec::Vec};
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use serde::{Deserialize, Serialize};
@@ -94,3 +95,15 @@ pub enum ScalarPosition {
     Long,
     Short,
 }
+
+impl ScalarPosition {
+    /// Returns the index set which selects this position when splitting a scalar market into
+    /// combinatorial tokens. `Long` is the first and `Short` the second outcome, matching the
+    /// order of the market's outcome assets and payout vector.
+    pub fn index_set(&self) -> Vec<bool> {
+        match self {
+            ScalarPosition::Long => vec![true, false],
+            ScalarPosition::Short => vec![false, true],
+        }
+    }
+}
@@ -80,6 +80,14 @@ pub const COURT_PALLET_ID: PalletId = PalletId(*b"zge/cout");
 /// Lock identifier, mainly used for the locks on the accounts.
 pub const COURT_LOCK_ID: [u8; 8] = *b"zge/colk";
 
+// Data Feed Oracle
+/// Pallet identifier, used to derive the oracle account of data feed markets.
+pub const DATA_FEED_ORACLE_PALLET_ID: PalletId = PalletId(*b"zge/dfor");
+
+// Futarchy
+/// Pallet identifier, mainly used for named balance reserves.
+pub const FUTARCHY_PALLET_ID: PalletId = PalletId(*b"zge/futa");
+
 // Global Disputes
 pub const GLOBAL_DISPUTES_PALLET_ID: PalletId = PalletId(*b"zge/gldp");
 /// Lock identifier, mainly used for the locks on the accounts.
@@ -96,6 +104,10 @@ pub const LM_PALLET_ID: PalletId = PalletId(*b"zge/lymg");
 // NeoSwaps
 pub const NS_PALLET_ID: PalletId = PalletId(*b"zge/neos");
 
+// Oracle Registry
+/// Pallet identifier, mainly used for named balance reserves.
+pub const ORACLE_REGISTRY_PALLET_ID: PalletId = PalletId(*b"zge/orcl");
+
 // Prediction Markets
 /// The maximum allowed market life time, measured in blocks.
 pub const MAX_MARKET_LIFETIME: BlockNumber = 80 * BLOCKS_PER_YEAR;
@@ -23,17 +23,21 @@ use crate::{
 };
 use frame_support::{parameter_types, traits::LockIdentifier, PalletId};
 use orml_traits::parameter_type_with_key;
-use sp_arithmetic::Perbill;
+use sp_arithmetic::{Perbill, Percent};
 
 // Authorized
 parameter_types! {
+    pub const AuthorityAppealBond: Balance = 10 * BASE;
+    pub const AuthorityAppealPeriod: BlockNumber = 8;
     pub const AuthorizedPalletId: PalletId = PalletId(*b"zge/atzd");
     pub const CorrectionPeriod: BlockNumber = 4;
+    pub const MaxCommitteeMembers: u32 = 16;
 }
 
 // CombinatorialTokens
 parameter_types! {
     pub const CombinatorialTokensPalletId: PalletId = PalletId(*b"zge/coto");
+    pub const CombinatorialTokensMaxRedeemSteps: u32 = 16;
 }
 
 // Court
@@ -56,9 +60,19 @@ parameter_types! {
     pub const InflationPeriod: BlockNumber = 20;
 }
 
+// Data feed oracle parameters
+parameter_types! {
+    pub const DataFeedMaxMarketsPerBlock: u32 = 2;
+    pub const DataFeedOraclePalletId: PalletId = PalletId(*b"zge/dfor");
+    pub const DataFeedSubmissionPeriod: BlockNumber = 5;
+    pub const MaxFeeders: u32 = 8;
+    pub const MinSubmissions: u32 = 3;
+}
+
 // Global disputes parameters
 parameter_types! {
     pub const AddOutcomePeriod: BlockNumber = 20;
+    pub const ConvictionLockPeriod: BlockNumber = 10;
     pub const GlobalDisputeLockId: LockIdentifier = *b"zge/vote";
     pub const GlobalDisputesPalletId: PalletId = PalletId(*b"zge/gldp");
     pub const MaxGlobalDisputeVotes: u32 = 50;
@@ -66,12 +80,17 @@ parameter_types! {
     pub const MinOutcomeVoteAmount: Balance = 10 * CENT;
     pub const RemoveKeysLimit: u32 = 250;
     pub const GdVotingPeriod: BlockNumber = 140;
+    pub const VoterRewardPercentage: Perbill = Perbill::from_percent(50);
     pub const VotingOutcomeFee: Balance = 100 * CENT;
 }
 
 // Hybrid Router parameters
 parameter_types! {
+    pub const ConditionalOrderKeeperFee: Balance = CENT;
     pub const HybridRouterPalletId: PalletId = PalletId(*b"zge/hybr");
+    pub const MaxConditionalOrderChecks: u32 = 2;
+    pub const MaxConditionalOrdersPerMarket: u32 = 4;
+    pub const MaxLegs: u32 = 4;
     pub const MaxOrders: u32 = 100;
 }
 
@@ -83,6 +102,16 @@ parameter_types! {
     pub const NeoSwapsPalletId: PalletId = PalletId(*b"zge/neos");
 }
 
+// Oracle Registry parameters
+parameter_types! {
+    pub const MinOracleStake: Balance = 10 * BASE;
+    pub const OracleRegistryPalletId: PalletId = PalletId(*b"zge/orcl");
+    pub const OracleUnbondingPeriod: BlockNumber = 10;
+    pub const ReportSlashPercentage: Percent = Percent::from_percent(10);
+    pub const ReputationPenalty: u32 = 3;
+    pub const StakePerReputationPoint: Balance = 100 * BASE;
+}
+
 // Prediction Market parameters
 parameter_types! {
     pub const AdvisoryBond: Balance = 25 * CENT;
@@ -92,6 +121,8 @@ parameter_types! {
     pub const CloseEarlyDisputeBond: Balance = 10 * BASE;
     pub const DisputeBond: Balance = 5 * BASE;
     pub const DisputeFactor: Balance = 2 * BASE;
+    pub const MarketSeriesBond: Balance = 5 * BASE;
+    pub const MarketTemplateBond: Balance = 5 * BASE;
     pub const MaxCategories: u16 = 10;
     pub const MaxCreatorFee: Perbill = Perbill::from_percent(1);
     pub const MaxDisputeDuration: BlockNumber = 50;
@@ -99,6 +130,7 @@ parameter_types! {
     pub const MaxEditReasonLen: u32 = 1024;
     pub const MaxGracePeriod: BlockNumber = 20;
     pub const MaxMarketLifetime: BlockNumber = 100_000_000_000;
+    pub const MaxMarketSeriesRolloversPerBlock: u32 = 2;
     pub const MaxOracleDuration: BlockNumber = 30;
     pub const MaxRejectReasonLen: u32 = 1024;
     pub const MinCategories: u16 = 2;
@@ -126,11 +158,14 @@ parameter_types! {
 
 // Orderbook parameters
 parameter_types! {
+    pub const MaxIndexedOrders: u32 = 100;
+    pub const MinOrderAmount: Balance = CENT;
     pub const OrderbookPalletId: PalletId = PalletId(*b"zge/ordb");
 }
 
 // Parimutuel parameters
 parameter_types! {
+    pub const EarlyExitPenalty: Perbill = Perbill::from_percent(10);
     pub const ParimutuelPalletId: PalletId = PalletId(*b"zge/prmt");
     pub const MinBetSize: Balance = BASE;
 }
@@ -21,17 +21,25 @@ mod combinatorial_tokens_benchmark_helper;
 mod combinatorial_tokens_fuel;
 mod combinatorial_tokens_unsafe_api;
 mod complete_set_operations_api;
+mod create_market_api;
+mod deploy_combinatorial_pool_api;
 mod deploy_pool_api;
 mod dispute_api;
 mod distribute_fees;
 mod futarchy_benchmark_helper;
 mod futarchy_oracle;
+mod futarchy_oracle_deployer;
 mod hybrid_router_amm_api;
+mod hybrid_router_combo_amm_api;
 mod hybrid_router_orderbook_api;
 mod market_builder;
 mod market_commons_pallet_api;
 mod market_id;
+mod on_market_close;
+mod oracle_registry_api;
 mod payout_api;
+mod report_market_api;
+mod styx_api;
 mod swaps;
 mod zeitgeist_asset;
 
@@ -40,16 +48,24 @@ pub use combinatorial_tokens_benchmark_helper::*;
 pub use combinatorial_tokens_fuel::*;
 pub use combinatorial_tokens_unsafe_api::*;
 pub use complete_set_operations_api::*;
+pub use create_market_api::*;
+pub use deploy_combinatorial_pool_api::*;
 pub use deploy_pool_api::*;
 pub use dispute_api::*;
 pub use distribute_fees::*;
 pub use futarchy_benchmark_helper::*;
 pub use futarchy_oracle::*;
+pub use futarchy_oracle_deployer::*;
 pub use hybrid_router_amm_api::*;
+pub use hybrid_router_combo_amm_api::*;
 pub use hybrid_router_orderbook_api::*;
 pub use market_builder::*;
 pub use market_commons_pallet_api::*;
 pub use market_id::*;
+pub use on_market_close::*;
+pub use oracle_registry_api::*;
 pub use payout_api::*;
+pub use report_market_api::*;
+pub use styx_api::*;
 pub use swaps::*;
 pub use zeitgeist_asset::*;
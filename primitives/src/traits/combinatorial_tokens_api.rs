@@ -15,12 +15,15 @@
 // You should have received a copy of the GNU General Public License
 // along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{traits::CombinatorialTokensFuel, types::SplitPositionDispatchInfo};
+use crate::{
+    traits::CombinatorialTokensFuel,
+    types::{Asset, SplitPositionDispatchInfo},
+};
 use alloc::vec::Vec;
 use core::fmt::Debug;
 use parity_scale_codec::{Decode, Encode};
 use scale_info::TypeInfo;
-use sp_runtime::DispatchError;
+use sp_runtime::{DispatchError, DispatchResult};
 
 /// Trait that can be used to expose the internal functionality of zrml-combinatorial-tokens to
 /// other pallets.
@@ -39,4 +42,31 @@ pub trait CombinatorialTokensApi {
         amount: Self::Balance,
         force_max_work: Self::Fuel,
     ) -> Result<SplitPositionDispatchInfo<Self::CombinatorialId, Self::MarketId>, DispatchError>;
+
+    /// Burns `amount` units of the legacy outcome token `asset` of `who` and mints the same amount
+    /// of the equivalent first-level combinatorial token.
+    fn convert_from_legacy(
+        who: Self::AccountId,
+        asset: Asset<Self::MarketId>,
+        amount: Self::Balance,
+        fuel: Self::Fuel,
+    ) -> DispatchResult;
+
+    /// Returns the ID of the collection obtained by splitting `parent_collection_id` over
+    /// `market_id` and selecting `index_set`.
+    fn collection_id_from_parent_collection(
+        parent_collection_id: Option<Self::CombinatorialId>,
+        market_id: Self::MarketId,
+        index_set: Vec<bool>,
+        fuel: Self::Fuel,
+    ) -> Result<Self::CombinatorialId, DispatchError>;
+
+    /// Returns the position obtained by splitting `parent_collection_id` over `market_id` and
+    /// selecting `index_set`.
+    fn position_from_parent_collection(
+        parent_collection_id: Option<Self::CombinatorialId>,
+        market_id: Self::MarketId,
+        index_set: Vec<bool>,
+        fuel: Self::Fuel,
+    ) -> Result<Asset<Self::MarketId>, DispatchError>;
 }
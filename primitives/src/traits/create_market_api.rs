@@ -0,0 +1,51 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::types::{
+    Asset, Deadlines, MarketCreation, MarketDisputeMechanism, MarketPeriod, MarketType, MultiHash,
+    ScoringRule,
+};
+use frame_support::pallet_prelude::Weight;
+use sp_arithmetic::Perbill;
+use sp_runtime::DispatchError;
+
+pub trait CreateMarketApi {
+    type AccountId;
+    type BlockNumber;
+    type MarketId;
+    type Moment;
+
+    /// Creates a market on behalf of `who` and returns its id. The creator is charged the same
+    /// bonds as if they had created the market themselves.
+    #[allow(clippy::too_many_arguments)]
+    fn create_market(
+        who: Self::AccountId,
+        base_asset: Asset<Self::MarketId>,
+        creator_fee: Perbill,
+        oracle: Self::AccountId,
+        period: MarketPeriod<Self::BlockNumber, Self::Moment>,
+        deadlines: Deadlines<Self::BlockNumber>,
+        metadata: MultiHash,
+        creation: MarketCreation,
+        market_type: MarketType,
+        dispute_mechanism: Option<MarketDisputeMechanism>,
+        scoring_rule: ScoringRule,
+    ) -> Result<Self::MarketId, DispatchError>;
+
+    /// Returns the maximum weight consumed by `create_market`.
+    fn create_market_weight() -> Weight;
+}
@@ -0,0 +1,48 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use alloc::vec::Vec;
+use sp_runtime::DispatchError;
+
+/// Trait for deploying combinatorial pools.
+pub trait DeployCombinatorialPoolApi {
+    type AccountId;
+    type Balance;
+    type Fuel;
+    type MarketId;
+    type PoolId;
+
+    /// Deploys a combinatorial pool which trades the atoms of the markets `market_ids` and returns
+    /// its ID.
+    ///
+    /// # Arguments
+    ///
+    /// - `who`: The account which provides the liquidity.
+    /// - `market_ids`: The markets whose outcomes are combined.
+    /// - `amount`: The amount of collateral split into the atoms of the pool.
+    /// - `spot_prices`: The initial spot prices of the atoms.
+    /// - `swap_fee`: The swap fee of the pool.
+    /// - `fuel`: The fuel used to calculate the IDs of the combinatorial tokens.
+    fn deploy_combinatorial_pool(
+        who: Self::AccountId,
+        market_ids: Vec<Self::MarketId>,
+        amount: Self::Balance,
+        spot_prices: Vec<Self::Balance>,
+        swap_fee: Self::Balance,
+        fuel: Self::Fuel,
+    ) -> Result<Self::PoolId, DispatchError>;
+}
@@ -15,6 +15,8 @@
 // You should have received a copy of the GNU General Public License
 // along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
 
+use crate::types::FutarchyOracleScore;
+use alloc::{vec, vec::Vec};
 use frame_support::pallet_prelude::Weight;
 
 pub trait FutarchyOracle {
@@ -26,4 +28,20 @@ pub trait FutarchyOracle {
 
     /// Updates the oracle's data and returns the weight consumed.
     fn update(&mut self, now: Self::BlockNumber) -> Weight;
+
+    /// Returns the current score of the oracle or `None` if the oracle doesn't keep score.
+    fn score(&self) -> Option<FutarchyOracleScore>;
+
+    /// Returns the current score of every oracle this oracle consists of, in order. `None` marks
+    /// oracles which don't keep score. Oracles which don't combine other oracles return their own
+    /// score.
+    fn scores(&self) -> Vec<Option<FutarchyOracleScore>> {
+        vec![self.score()]
+    }
+
+    /// Returns `false` if the oracle can't be evaluated, for example if it combines other oracles
+    /// using a rule that doesn't fit their number.
+    fn is_valid(&self) -> bool {
+        true
+    }
 }
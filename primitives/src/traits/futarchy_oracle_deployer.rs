@@ -0,0 +1,45 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use frame_support::{pallet_prelude::Weight, Parameter};
+use sp_runtime::DispatchError;
+
+pub trait FutarchyOracleDeployer {
+    type AccountId;
+    type BlockNumber;
+    type Oracle;
+
+    /// The parameters of the markets and pools which are deployed for the oracle, e.g. the
+    /// collateral, the liquidity and the margins of the oracle. Set by governance.
+    type Params: Parameter;
+
+    /// The data of the markets which is specific to a proposal, e.g. their metadata. Provided by
+    /// the proposer.
+    type Metadata: Parameter;
+
+    /// Deploys the markets and pools specified by `params` and `metadata` on behalf of `who` and
+    /// returns an oracle which uses them to evaluate a proposal at `evaluate_at`.
+    fn deploy_oracle(
+        who: Self::AccountId,
+        evaluate_at: Self::BlockNumber,
+        params: Self::Params,
+        metadata: Self::Metadata,
+    ) -> Result<Self::Oracle, DispatchError>;
+
+    /// Returns the maximum weight consumed by `deploy_oracle`.
+    fn deploy_oracle_weight() -> Weight;
+}
@@ -77,6 +77,25 @@ pub trait HybridRouterAmmApi {
         until: Self::Balance,
     ) -> Result<Self::Balance, DispatchError>;
 
+    /// Calculates the amount of `asset_out` a user receives when buying with `amount_in` units of
+    /// the base asset, fees included.
+    ///
+    /// # Arguments
+    ///
+    /// - `market_id`: The market ID.
+    /// - `asset_out`: The asset to receive from the buy.
+    /// - `amount_in`: The base asset amount to input for the buy.
+    ///
+    /// # Returns
+    ///
+    /// Returns the amount out as a `Result` containing the balance, or an error if the amount
+    /// cannot be calculated.
+    fn calculate_buy_amount_out(
+        market_id: Self::MarketId,
+        asset_out: Self::Asset,
+        amount_in: Self::Balance,
+    ) -> Result<Self::Balance, DispatchError>;
+
     /// Executes a buy transaction.
     ///
     /// # Arguments
@@ -0,0 +1,107 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::hybrid_router_api_types::AmmTrade;
+use alloc::vec::Vec;
+use sp_runtime::DispatchError;
+
+/// Trait for handling the combinatorial pools in the hybrid router.
+pub trait HybridRouterComboAmmApi {
+    type AccountId;
+    type Asset;
+    type Balance;
+    type PoolId;
+
+    /// Returns the assets of the combinatorial pool `pool_id`.
+    ///
+    /// # Arguments
+    ///
+    /// - `pool_id`: The ID of the pool.
+    ///
+    /// # Returns
+    ///
+    /// Returns an error if the pool doesn't exist or isn't a combinatorial pool.
+    fn combo_pool_assets(pool_id: Self::PoolId) -> Result<Vec<Self::Asset>, DispatchError>;
+
+    /// Calculates the amount of each asset in `buy` a user receives when paying `amount_in` units
+    /// of collateral, fees included.
+    ///
+    /// # Arguments
+    ///
+    /// - `pool_id`: The ID of the pool.
+    /// - `buy`: The assets the user wants to have more of.
+    /// - `sell`: The assets the user doesn't want any of.
+    /// - `amount_in`: The amount of collateral paid by the user.
+    fn calculate_combo_buy_amount_out(
+        pool_id: Self::PoolId,
+        buy: Vec<Self::Asset>,
+        sell: Vec<Self::Asset>,
+        amount_in: Self::Balance,
+    ) -> Result<Self::Balance, DispatchError>;
+
+    /// Executes a combinatorial buy.
+    ///
+    /// # Arguments
+    ///
+    /// - `who`: The account ID of the user performing the buy.
+    /// - `pool_id`: The ID of the pool.
+    /// - `buy`: The assets the user wants to have more of.
+    /// - `sell`: The assets the user doesn't want any of.
+    /// - `amount_in`: The amount of collateral paid by the user.
+    /// - `min_amount_out`: The minimum amount to receive from the buy.
+    ///
+    /// # Returns
+    ///
+    /// Returns information about the buy trade made.
+    fn combo_buy(
+        who: Self::AccountId,
+        pool_id: Self::PoolId,
+        buy: Vec<Self::Asset>,
+        sell: Vec<Self::Asset>,
+        amount_in: Self::Balance,
+        min_amount_out: Self::Balance,
+    ) -> Result<AmmTrade<Self::Balance>, DispatchError>;
+
+    /// Executes a combinatorial sell, i.e. cancels a combinatorial bet.
+    ///
+    /// # Arguments
+    ///
+    /// - `who`: The account ID of the user performing the sell.
+    /// - `pool_id`: The ID of the pool.
+    /// - `buy`: The `buy` of the bet that the user wishes to cancel.
+    /// - `keep`: The tokens not contained in `buy` or `sell` of the bet that the user wishes to
+    ///   cancel.
+    /// - `sell`: The `sell` of the bet that the user wishes to cancel.
+    /// - `amount_buy`: The amount of each asset in `buy` the user sells.
+    /// - `amount_keep`: The amount of each asset in `keep` the user sells.
+    /// - `min_amount_out`: The minimum amount of collateral to receive from the sell.
+    ///
+    /// # Returns
+    ///
+    /// Returns information about the sell trade made.
+    #[allow(clippy::too_many_arguments)]
+    fn combo_sell(
+        who: Self::AccountId,
+        pool_id: Self::PoolId,
+        buy: Vec<Self::Asset>,
+        keep: Vec<Self::Asset>,
+        sell: Vec<Self::Asset>,
+        amount_buy: Self::Balance,
+        amount_keep: Self::Balance,
+        min_amount_out: Self::Balance,
+    ) -> Result<AmmTrade<Self::Balance>, DispatchError>;
+}
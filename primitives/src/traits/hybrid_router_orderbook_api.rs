@@ -15,6 +15,7 @@
 // You should have received a copy of the GNU General Public License
 // along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
 
+use alloc::vec::Vec;
 use sp_runtime::DispatchError;
 
 use crate::hybrid_router_api_types::{ApiError, OrderbookSoftFail, OrderbookTrade};
@@ -44,6 +45,23 @@ pub trait HybridRouterOrderbookApi {
     /// - `order_id`: The id of the order to return.
     fn order(order_id: Self::OrderId) -> Result<Self::Order, DispatchError>;
 
+    /// Returns the ids of up to `limit` orders which offer `maker_asset` for `taker_asset`,
+    /// sorted from the best to the worst price for the taker. Only orders whose price is at least
+    /// as good as `price_limit` are returned.
+    ///
+    /// # Arguments
+    ///
+    /// - `maker_asset`: The asset the makers of the orders offer.
+    /// - `taker_asset`: The asset the makers of the orders want to receive.
+    /// - `price_limit`: The worst acceptable price of the maker asset in units of the base asset.
+    /// - `limit`: The maximum number of order ids to return.
+    fn best_orders(
+        maker_asset: Self::Asset,
+        taker_asset: Self::Asset,
+        price_limit: Self::Balance,
+        limit: u32,
+    ) -> Vec<Self::OrderId>;
+
     /// Fills the order with the specified `order_id` with the specified `maker_partial_fill` amount.
     ///
     /// # Arguments
@@ -0,0 +1,30 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use frame_support::pallet_prelude::Weight;
+
+/// Allows other pallets to clean up their state when a market is closed.
+pub trait OnMarketClose {
+    type MarketId;
+
+    /// Called by `zrml-prediction-markets` when the market `market_id` is closed. Returns the
+    /// weight consumed, which must not exceed `on_market_close_max_weight`.
+    fn on_market_close(market_id: &Self::MarketId) -> Weight;
+
+    /// Returns the maximum weight consumed by `on_market_close`.
+    fn on_market_close_max_weight() -> Weight;
+}
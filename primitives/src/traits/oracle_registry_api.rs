@@ -0,0 +1,43 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use frame_support::pallet_prelude::Weight;
+
+/// Allows other pallets to query the oracle registry and to update the reputation of oracles.
+pub trait OracleRegistryApi {
+    type AccountId;
+    type Balance;
+
+    /// Returns the fallback oracle named by `oracle` if both are registered and the fallback
+    /// oracle isn't unbonding.
+    fn fallback_oracle(oracle: &Self::AccountId) -> Option<Self::AccountId>;
+
+    /// Returns the reputation of `oracle` or `None` if `oracle` isn't registered.
+    fn reputation(oracle: &Self::AccountId) -> Option<u32>;
+
+    /// Rewards `oracle` for an on-time report that was resolved without a dispute. The reward
+    /// grows with `market_stake`, the collateral held by the reported market.
+    fn on_accurate_report(oracle: &Self::AccountId, market_stake: Self::Balance) -> Weight;
+
+    /// Penalizes `oracle` for not reporting within its exclusive reporting window. The penalty
+    /// grows with `market_stake`, the collateral held by the market.
+    fn on_missed_report(oracle: &Self::AccountId, market_stake: Self::Balance) -> Weight;
+
+    /// Penalizes `oracle` for an on-time report whose outcome was overturned by a dispute. The
+    /// penalty grows with `market_stake`, the collateral held by the reported market.
+    fn on_overturned_report(oracle: &Self::AccountId, market_stake: Self::Balance) -> Weight;
+}
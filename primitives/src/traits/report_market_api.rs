@@ -0,0 +1,36 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::types::OutcomeReport;
+use frame_support::pallet_prelude::Weight;
+use sp_runtime::DispatchResult;
+
+pub trait ReportMarketApi {
+    type AccountId;
+    type MarketId;
+
+    /// Reports `outcome` for the market on behalf of `who`, as if `who` had called `report`
+    /// themselves. Doesn't change any storage if it fails.
+    fn report_market(
+        who: Self::AccountId,
+        market_id: Self::MarketId,
+        outcome: OutcomeReport,
+    ) -> DispatchResult;
+
+    /// Returns the maximum weight consumed by `report_market`.
+    fn report_market_weight() -> Weight;
+}
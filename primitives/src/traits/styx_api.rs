@@ -0,0 +1,28 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+/// Allows other pallets to query the Styx registry.
+pub trait StyxApi {
+    type AccountId;
+    type Hash;
+
+    /// Returns `true` if `who` has crossed Styx and the crossing wasn't revoked.
+    fn has_crossed(who: &Self::AccountId) -> bool;
+
+    /// Returns the avatar or metadata hash `who` attached to their crossing, if any.
+    fn crossing_metadata(who: &Self::AccountId) -> Option<Self::Hash>;
+}
@@ -209,3 +209,17 @@ pub struct SplitPositionDispatchInfo<CombinatorialId, MarketId> {
     pub position_ids: Vec<Asset<MarketId>>,
     pub post_dispatch_info: PostDispatchInfo,
 }
+
+/// The score of a futarchy oracle, measured in ticks.
+#[derive(Clone, Copy, Debug, Decode, Default, Encode, Eq, MaxEncodedLen, PartialEq, TypeInfo)]
+pub struct FutarchyOracleScore {
+    /// The number of ticks for the positive outcome.
+    pub pass_score: u128,
+
+    /// The number of ticks for the negative outcome.
+    pub reject_score: u128,
+
+    /// The number of ticks the positive outcome requires to have over the negative outcome in
+    /// order for the oracle to evaluate positively.
+    pub victory_margin: u128,
+}
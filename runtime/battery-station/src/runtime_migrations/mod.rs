@@ -21,7 +21,12 @@ use pallet_parachain_staking::migrations::{
     LegacyAtStakeCursorMigration, LegacyAtStakeMigrationKey, LegacyAtStakeMigrationList,
     LEGACY_AT_STAKE_MIGRATION_ID_LEN,
 };
+use zrml_futarchy::migrations::mbm::MigrateProposalsToProposalIds;
+use zrml_global_disputes::migrations::mbm::MigrateLocksToVoteLocks;
+use zrml_neo_swaps::types::DecisionMarketOracle;
+use zrml_orderbook::migrations::mbm::BuildOrderPriceIndex;
 use zrml_prediction_markets::migrations::mbm::TimeFrameRescaleMigration;
+use zrml_styx::migrations::mbm::MigrateCrossingsToCrossingInfo;
 
 mod legacy_keys;
 use legacy_keys::BATTERY_STATION_LEGACY_KEYS;
@@ -47,4 +52,12 @@ pub type LegacyMigrations = (
         BATTERY_STATION_MIGRATION_BATCH,
     >,
     TimeFrameRescaleMigration<crate::Runtime>,
+    BuildOrderPriceIndex<crate::Runtime>,
+    MigrateCrossingsToCrossingInfo<crate::Runtime>,
+    MigrateLocksToVoteLocks<crate::Runtime>,
+    MigrateProposalsToProposalIds<
+        crate::Runtime,
+        DecisionMarketOracle<crate::Runtime>,
+        crate::FutarchyLegacyOracleConverter,
+    >,
 );
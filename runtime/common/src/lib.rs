@@ -95,11 +95,16 @@ macro_rules! decl_common_types {
         };
         use zeitgeist_primitives::traits::{DeployPoolApi, DistributeFees, MarketCommonsPalletApi};
         use zrml_combinatorial_tokens::types::{CryptographicIdManager, Fuel};
-        use zrml_neo_swaps::types::DecisionMarketOracle;
+        use zrml_futarchy::types::{CompositeOracle, CompositeOracleDeployer};
+        use zrml_neo_swaps::types::{
+            DecisionMarketOracle, DecisionMarketSubOracle, DecisionMarketSubOracleDeployer,
+        };
 
         #[cfg(feature = "try-runtime")]
         use frame_try_runtime::{TryStateSelect, UpgradeCheckSelect};
 
+        #[cfg(feature = "runtime-benchmarks")]
+        use zrml_futarchy::types::CompositeOracleBenchmarkHelper;
         #[cfg(feature = "runtime-benchmarks")]
         use zrml_neo_swaps::types::DecisionMarketBenchmarkHelper;
 
@@ -295,6 +300,98 @@ macro_rules! decl_common_types {
             }
         }
 
+        // Accounts with an identity that received a positive judgement from a registrar.
+        pub struct IdentityWithPositiveJudgement;
+
+        impl Contains<AccountId> for IdentityWithPositiveJudgement {
+            fn contains(ai: &AccountId) -> bool {
+                pallet_identity::IdentityOf::<Runtime>::get(ai).is_some_and(|(registration, _)| {
+                    registration.judgements.iter().any(|(_, judgement)| {
+                        matches!(
+                            judgement,
+                            pallet_identity::Judgement::Reasonable
+                                | pallet_identity::Judgement::KnownGood
+                        )
+                    })
+                })
+            }
+        }
+
+        // Calls which futarchy proposals submitted without root privileges may dispatch.
+        pub struct FutarchyBondedCalls;
+
+        impl Contains<RuntimeCall> for FutarchyBondedCalls {
+            fn contains(call: &RuntimeCall) -> bool {
+                matches!(
+                    call,
+                    RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive { .. })
+                )
+            }
+        }
+
+        // The only account bonded futarchy proposals may be dispatched from.
+        impl frame_support::traits::SortedMembers<AccountId> for FutarchyAccount {
+            fn sorted_members() -> Vec<AccountId> {
+                vec![FutarchyAccount::get()]
+            }
+        }
+
+        pub type FutarchyOracle =
+            CompositeOracle<DecisionMarketSubOracle<Runtime>, FutarchyMaxSubOracles>;
+
+        // Converts the oracles of proposals submitted before futarchy supported composite oracles.
+        pub struct FutarchyLegacyOracleConverter;
+
+        impl sp_runtime::traits::Convert<DecisionMarketOracle<Runtime>, FutarchyOracle>
+            for FutarchyLegacyOracleConverter
+        {
+            fn convert(oracle: DecisionMarketOracle<Runtime>) -> FutarchyOracle {
+                CompositeOracle::new(
+                    vec![DecisionMarketSubOracle::Categorical(oracle)],
+                    zrml_futarchy::types::CompositeRule::All,
+                )
+                .expect("a single oracle satisfies `CompositeRule::All`; qed")
+            }
+        }
+
+        // Stands in for `zrml_oracle_registry` until its weights are benchmarked and the pallet is
+        // added to the runtime. Oracles have no fallback and reports don't affect reputation.
+        pub struct NoOracleRegistry;
+
+        impl zeitgeist_primitives::traits::OracleRegistryApi for NoOracleRegistry {
+            type AccountId = AccountId;
+            type Balance = Balance;
+
+            fn fallback_oracle(_oracle: &AccountId) -> Option<AccountId> {
+                None
+            }
+
+            fn reputation(_oracle: &AccountId) -> Option<u32> {
+                None
+            }
+
+            fn on_accurate_report(
+                _oracle: &AccountId,
+                _market_stake: Balance,
+            ) -> frame_support::weights::Weight {
+                frame_support::weights::Weight::zero()
+            }
+
+            fn on_missed_report(
+                _oracle: &AccountId,
+                _market_stake: Balance,
+            ) -> frame_support::weights::Weight {
+                frame_support::weights::Weight::zero()
+            }
+
+            fn on_overturned_report(
+                _oracle: &AccountId,
+                _market_stake: Balance,
+            ) -> frame_support::weights::Weight {
+                frame_support::weights::Weight::zero()
+            }
+        }
+
         common_runtime::impl_fee_types!();
 
         pub mod opaque {
@@ -1269,12 +1366,15 @@ macro_rules! impl_config_traits {
         impl parachain_info::Config for Runtime {}
 
         impl zrml_authorized::Config for Runtime {
+            type AppealBond = AuthorityAppealBond;
+            type AppealPeriod = AuthorityAppealPeriod;
             type AuthorizedDisputeResolutionOrigin = EnsureRootOrMoreThanHalfAdvisoryCommittee;
             type Currency = Balances;
             type CorrectionPeriod = CorrectionPeriod;
             type DisputeResolution = zrml_prediction_markets::Pallet<Runtime>;
             type RuntimeEvent = RuntimeEvent;
             type MarketCommons = MarketCommons;
+            type MaxCommitteeMembers = MaxCommitteeMembers;
             type PalletId = AuthorizedPalletId;
             type WeightInfo = zrml_authorized::weights::WeightInfo<Runtime>;
         }
@@ -1284,7 +1384,9 @@ macro_rules! impl_config_traits {
             type BenchmarkHelper = PredictionMarketsCombinatorialTokensBenchmarkHelper<Runtime>;
             type CombinatorialIdManager = CryptographicIdManager<MarketId, Blake2_256>;
             type Fuel = Fuel;
+            type LegacyMarketAccount = zrml_prediction_markets::MarketAccount<Runtime>;
             type MarketCommons = MarketCommons;
+            type MaxRedeemSteps = CombinatorialTokensMaxRedeemSteps;
             type MultiCurrency = AssetManager;
             type Payout = PredictionMarkets;
             type RuntimeEvent = RuntimeEvent;
@@ -1320,13 +1422,34 @@ macro_rules! impl_config_traits {
         }
 
         impl zrml_futarchy::Config for Runtime {
+            type AllowedDispatchOrigin = frame_system::EnsureSignedBy<FutarchyAccount, AccountId>;
             #[cfg(feature = "runtime-benchmarks")]
-            type BenchmarkHelper = DecisionMarketBenchmarkHelper<Runtime>;
+            type BenchmarkHelper = CompositeOracleBenchmarkHelper<
+                DecisionMarketBenchmarkHelper<Runtime>,
+                DecisionMarketSubOracle<Runtime>,
+                FutarchyMaxSubOracles,
+            >;
+            type BondedCallFilter = FutarchyBondedCalls;
+            type BondedOracleParams = FutarchyBondedOracleParams;
+            type Currency = Balances;
+            type MaxCallWeight = FutarchyMaxCallWeight;
+            type MaxDispatchOutcomes = FutarchyMaxDispatchOutcomes;
             type MaxProposals = FutarchyMaxProposals;
             type MinDuration = MinDuration;
-            type Oracle = DecisionMarketOracle<Runtime>;
+            type Oracle = FutarchyOracle;
+            type OracleDeployer = CompositeOracleDeployer<
+                Runtime,
+                DecisionMarketSubOracleDeployer<Runtime, PredictionMarkets>,
+                FutarchyMaxSubOracles,
+            >;
+            type PalletId = FutarchyPalletId;
+            type Preimages = Preimage;
+            type ProposalBond = FutarchyProposalBond;
+            type RuntimeCall = RuntimeCall;
             type RuntimeEvent = RuntimeEvent;
             type Scheduler = Scheduler;
+            type Slash = Treasury;
+            type VetoOrigin = EnsureRootOrAllTechnicalCommittee;
             type WeightInfo = zrml_futarchy::weights::WeightInfo<Runtime>;
         }
 
@@ -1357,6 +1480,7 @@ macro_rules! impl_config_traits {
             type MaxCreatorFee = MaxCreatorFee;
             type MaxDisputes = MaxDisputes;
             type MaxMarketLifetime = MaxMarketLifetime;
+            type MaxMarketSeriesRolloversPerBlock = MaxMarketSeriesRolloversPerBlock;
             type MinDisputeDuration = MinDisputeDuration;
             type MaxDisputeDuration = MaxDisputeDuration;
             type MaxGracePeriod = MaxGracePeriod;
@@ -1364,8 +1488,12 @@ macro_rules! impl_config_traits {
             type MinOracleDuration = MinOracleDuration;
             type MinCategories = MinCategories;
             type MaxEditReasonLen = MaxEditReasonLen;
+            type MarketTemplateBond = MarketTemplateBond;
+            type MarketSeriesBond = MarketSeriesBond;
             type MaxRejectReasonLen = MaxRejectReasonLen;
             type OracleBond = OracleBond;
+            type OnMarketClose = HybridRouter;
+            type OracleRegistry = NoOracleRegistry;
             type OutsiderBond = OutsiderBond;
             type PalletId = PmPalletId;
             type CloseEarlyBlockPeriod = CloseEarlyBlockPeriod;
@@ -1383,18 +1511,23 @@ macro_rules! impl_config_traits {
 
         impl zrml_global_disputes::Config for Runtime {
             type AddOutcomePeriod = AddOutcomePeriod;
+            type ConvictionLockPeriod = ConvictionLockPeriod;
             type Currency = Balances;
+            type DefaultVotingWeightMode = DefaultVotingWeightMode;
             type DisputeResolution = zrml_prediction_markets::Pallet<Runtime>;
             type RuntimeEvent = RuntimeEvent;
             type GlobalDisputeLockId = GlobalDisputeLockId;
             type GlobalDisputesPalletId = GlobalDisputesPalletId;
+            type IdentityVerification = IdentityWithPositiveJudgement;
             type MarketCommons = MarketCommons;
             type MaxGlobalDisputeVotes = MaxGlobalDisputeVotes;
             type MaxOwners = MaxOwners;
             type MinOutcomeVoteAmount = MinOutcomeVoteAmount;
             type RemoveKeysLimit = RemoveKeysLimit;
             type GdVotingPeriod = GdVotingPeriod;
+            type VoterRewardPercentage = VoterRewardPercentage;
             type VotingOutcomeFee = VotingOutcomeFee;
+            type VotingWeightModeOrigin = EnsureRootOrTwoThirdsCouncil;
             type WeightInfo = zrml_global_disputes::weights::WeightInfo<Runtime>;
         }
 
@@ -1414,6 +1547,7 @@ macro_rules! impl_config_traits {
         }
 
         impl zrml_styx::Config for Runtime {
+            type RevokeOrigin = EnsureRootOrTwoThirdsCouncil;
             type RuntimeEvent = RuntimeEvent;
             type SetBurnAmountOrigin = EnsureRootOrHalfCouncil;
             type Currency = Balances;
@@ -1444,11 +1578,14 @@ macro_rules! impl_config_traits {
             type ExternalFees = MarketCreatorFee;
             type RuntimeEvent = RuntimeEvent;
             type MarketCommons = MarketCommons;
+            type MaxIndexedOrders = MaxIndexedOrders;
+            type MinOrderAmount = MinOrderAmount;
             type PalletId = OrderbookPalletId;
             type WeightInfo = zrml_orderbook::weights::WeightInfo<Runtime>;
         }
 
         impl zrml_parimutuel::Config for Runtime {
+            type EarlyExitPenalty = EarlyExitPenalty;
             type ExternalFees = MarketCreatorFee;
             type RuntimeEvent = RuntimeEvent;
             type MarketCommons = MarketCommons;
@@ -1463,10 +1600,18 @@ macro_rules! impl_config_traits {
             #[cfg(feature = "runtime-benchmarks")]
             type AmmPoolDeployer = NeoSwaps;
             #[cfg(feature = "runtime-benchmarks")]
+            type ComboPoolDeployer = NeoSwaps;
+            #[cfg(feature = "runtime-benchmarks")]
             type CompleteSetOperations = PredictionMarkets;
             type MarketCommons = MarketCommons;
             type Amm = NeoSwaps;
+            type CombinatorialTokens = CombinatorialTokens;
+            type ComboAmm = NeoSwaps;
+            type ConditionalOrderKeeperFee = ConditionalOrderKeeperFee;
             type Orderbook = Orderbook;
+            type MaxConditionalOrderChecks = MaxConditionalOrderChecks;
+            type MaxConditionalOrdersPerMarket = MaxConditionalOrdersPerMarket;
+            type MaxLegs = MaxLegs;
             type MaxOrders = MaxOrders;
             type RuntimeEvent = RuntimeEvent;
             type PalletId = HybridRouterPalletId;
@@ -2424,6 +2569,127 @@ macro_rules! create_runtime_api {
                 }
             }
 
+            impl zrml_court_runtime_api::CourtApi<Block, AccountId, Balance> for Runtime {
+                fn simulate_reassign_court_stakes(
+                    court_id: zrml_court::CourtId,
+                    winner: zrml_court::VoteItem,
+                ) -> Result<
+                    Vec<(AccountId, zrml_court::StakeReassignment<Balance>)>,
+                    sp_runtime::DispatchError,
+                > {
+                    Court::simulate_reassign_court_stakes(court_id, winner)
+                }
+            }
+
+            impl zrml_combinatorial_tokens_runtime_api::CombinatorialTokensApi<
+                Block,
+                AccountId,
+                Balance,
+                MarketId,
+            > for Runtime
+            {
+                fn collection_id_from_parent_collection(
+                    parent_collection_id: Option<zeitgeist_primitives::types::CombinatorialId>,
+                    market_id: MarketId,
+                    index_set: Vec<bool>,
+                    fuel: zrml_combinatorial_tokens::types::Fuel,
+                ) -> Result<zeitgeist_primitives::types::CombinatorialId, sp_runtime::DispatchError> {
+                    CombinatorialTokens::collection_id_from_parent_collection(
+                        parent_collection_id,
+                        market_id,
+                        index_set,
+                        fuel,
+                    )
+                }
+
+                fn position_from_parent_collection(
+                    parent_collection_id: Option<zeitgeist_primitives::types::CombinatorialId>,
+                    market_id: MarketId,
+                    index_set: Vec<bool>,
+                    fuel: zrml_combinatorial_tokens::types::Fuel,
+                ) -> Result<Asset<MarketId>, sp_runtime::DispatchError> {
+                    CombinatorialTokens::position_from_parent_collection(
+                        parent_collection_id,
+                        market_id,
+                        index_set,
+                        fuel,
+                    )
+                }
+
+                fn scalar_position_from_parent_collection(
+                    parent_collection_id: Option<zeitgeist_primitives::types::CombinatorialId>,
+                    market_id: MarketId,
+                    scalar_position: zeitgeist_primitives::types::ScalarPosition,
+                    fuel: zrml_combinatorial_tokens::types::Fuel,
+                ) -> Result<Asset<MarketId>, sp_runtime::DispatchError> {
+                    CombinatorialTokens::scalar_position_from_parent_collection(
+                        parent_collection_id,
+                        market_id,
+                        scalar_position,
+                        fuel,
+                    )
+                }
+
+                fn positions_of(
+                    who: AccountId,
+                ) -> Vec<zrml_combinatorial_tokens::types::PositionValue<MarketId, Balance>> {
+                    let positions = orml_tokens::Accounts::<Runtime>::iter_key_prefix(&who)
+                        .filter_map(|asset| match asset {
+                            Asset::CombinatorialToken(position_id) => Some(position_id),
+                            _ => None,
+                        })
+                        .collect();
+                    CombinatorialTokens::position_values(&who, positions)
+                }
+            }
+
+            impl zrml_futarchy_runtime_api::FutarchyApi<Block, AccountId, BlockNumber> for Runtime {
+                fn proposals(
+                ) -> Vec<zrml_futarchy::types::ProposalStatus<AccountId, BlockNumber>> {
+                    Futarchy::proposal_statuses()
+                }
+            }
+
+            impl zrml_hybrid_router_runtime_api::HybridRouterApi<Block, AccountId, Balance, MarketId>
+            for Runtime
+            {
+                fn simulate_trade(
+                    tx_type: zrml_hybrid_router::types::TxType,
+                    who: AccountId,
+                    market_id: MarketId,
+                    asset_count: u16,
+                    asset: Asset<MarketId>,
+                    amount_in: Balance,
+                    price_limit: Balance,
+                    orders: Vec<zeitgeist_primitives::orderbook::OrderId>,
+                    strategy: zrml_hybrid_router::types::Strategy,
+                ) -> Result<
+                    zrml_hybrid_router::types::TradeSimulation<AccountId, Balance>,
+                    sp_runtime::DispatchError,
+                > {
+                    HybridRouter::simulate_trade(
+                        tx_type,
+                        who,
+                        market_id,
+                        asset_count,
+                        asset,
+                        amount_in,
+                        price_limit,
+                        orders,
+                        strategy,
+                    )
+                }
+            }
+
+            impl zrml_parimutuel_runtime_api::ParimutuelApi<Block, MarketId, Balance> for Runtime {
+                fn pot_info(
+                    market_id: MarketId,
+                ) -> Result<zrml_parimutuel::types::PotInfo<MarketId, Balance>, sp_runtime::DispatchError>
+                {
+                    Parimutuel::pot_info(market_id)
+                }
+            }
+
             impl zrml_swaps_runtime_api::SwapsApi<Block, PoolId, AccountId, Balance, MarketId>
             for Runtime
             {
@@ -2859,10 +3125,15 @@ macro_rules! create_common_tests {
             mod futarchy {
                 use crate::{
                     common_tests::utility, AccountId, Asset, AssetManager, Balance, Balances,
-                    Futarchy, MarketId, NeoSwaps, PredictionMarkets, Preimage, Runtime,
-                    RuntimeCall, RuntimeOrigin, Scheduler, System,
+                    Futarchy, FutarchyAccount, FutarchyBondedOracleParams, MarketCommons, MarketId,
+                    NeoSwaps, PredictionMarkets, Preimage, Runtime, RuntimeCall, RuntimeOrigin,
+                    Scheduler, System,
+                };
+                use frame_support::{
+                    assert_ok,
+                    dispatch::RawOrigin,
+                    traits::{NamedReservableCurrency, StorePreimage},
                 };
-                use frame_support::{assert_ok, dispatch::RawOrigin, traits::StorePreimage};
                 use orml_traits::MultiCurrency;
                 use sp_runtime::{
                     traits::{Hash, Zero},
@@ -2875,9 +3146,13 @@ macro_rules! create_common_tests {
                         Deadlines, MarketCreation, MarketPeriod, MarketType, MultiHash, ScoringRule,
                     },
                 };
-                use zrml_futarchy::types::Proposal;
-                use zrml_market_commons::types::MarketBuilder;
-                use zrml_neo_swaps::types::{DecisionMarketOracle, DecisionMarketOracleScoreboard};
+                use zrml_futarchy::types::{CompositeOracle, CompositeRule, Proposal};
+                use zrml_market_commons::{types::MarketBuilder, MarketCommonsPalletApi};
+                use zrml_neo_swaps::types::{
+                    DecisionMarketOracle, DecisionMarketOracleMetadata,
+                    DecisionMarketOracleScoreboard, DecisionMarketSubOracle,
+                    DecisionMarketSubOracleParams,
+                };
 
                 #[test]
                 fn futarchy_schedules_and_executes_call() {
@@ -2953,14 +3228,25 @@ macro_rules! create_common_tests {
                             Preimage::bound(RuntimeCall::from(remark_dispatched_as)).unwrap();
                         let scoreboard =
                             DecisionMarketOracleScoreboard::new(40_000, 10_000, one / 7, one);
-                        let oracle = DecisionMarketOracle::new(
+                        let decision_market_oracle = DecisionMarketOracle::new(
                             market_id,
                             Asset::CategoricalOutcome(market_id, 0),
                             Asset::CategoricalOutcome(market_id, 1),
                             scoreboard,
                         );
+                        let oracle = CompositeOracle::new(
+                            vec![DecisionMarketSubOracle::Categorical(decision_market_oracle)],
+                            CompositeRule::All,
+                        )
+                        .unwrap();
                         let when = duration + 10;
-                        let proposal = Proposal { when, call, oracle };
+                        let proposal = Proposal {
+                            when,
+                            call,
+                            origin: RawOrigin::Root.into(),
+                            oracle,
+                            bond: None,
+                        };
 
                         assert_ok!(Futarchy::submit_proposal(
                             RawOrigin::Root.into(),
@@ -2982,6 +3268,85 @@ macro_rules! create_common_tests {
                             }
                             .into(),
                         );
+                        assert_eq!(
+                            zrml_futarchy::DispatchOutcomes::<Runtime>::get(0).map(|o| o.result),
+                            Some(Ok(()))
+                        );
+                    });
+                }
+
+                #[test]
+                fn futarchy_deploys_oracle_for_bonded_proposal() {
+                    let mut t: sp_io::TestExternalities =
+                        frame_system::GenesisConfig::<Runtime>::default()
+                            .build_storage()
+                            .unwrap()
+                            .into();
+                    t.execute_with(|| {
+                        let alice = AccountId::from([0u8; 32]);
+
+                        let collateral: Asset<MarketId> = Asset::Ztg;
+                        let one: Balance = ZeitgeistBase::get().unwrap();
+                        let total_cost: Balance = one.saturating_mul(100_000u128);
+                        assert_ok!(AssetManager::deposit(collateral, &alice, total_cost));
+
+                        let mut metadata = [0x01; 50];
+                        metadata[0] = 0x15;
+                        metadata[1] = 0x30;
+                        let multihash = MultiHash::Sha3_384(metadata);
+
+                        let duration = <Runtime as zrml_futarchy::Config>::MinDuration::get();
+                        let when = duration + 10;
+                        let oracle_metadata = vec![DecisionMarketOracleMetadata {
+                            decision_metadata: multihash.clone(),
+                            welfare_metadata: multihash,
+                        }]
+                        .try_into()
+                        .unwrap();
+
+                        let bob = AccountId::from([0x01; 32]);
+                        let call = Preimage::bound(RuntimeCall::from(
+                            pallet_balances::Call::transfer_keep_alive {
+                                dest: bob.into(),
+                                value: one,
+                            },
+                        ))
+                        .unwrap();
+
+                        assert_ok!(Futarchy::submit_bonded_proposal(
+                            RuntimeOrigin::signed(alice.clone()),
+                            duration,
+                            when,
+                            call,
+                            Box::new(RawOrigin::Signed(FutarchyAccount::get()).into()),
+                            oracle_metadata,
+                        ));
+
+                        let DecisionMarketSubOracleParams::Categorical(params) =
+                            FutarchyBondedOracleParams::get().params[0].clone()
+                        else {
+                            panic!("expected the parameters of a categorical oracle");
+                        };
+                        let decision_market = MarketCommons::market(&0).unwrap();
+                        assert_eq!(decision_market.period, MarketPeriod::Block(0..duration));
+                        assert_eq!(decision_market.deadlines, params.deadlines);
+                        let welfare_market = MarketCommons::market(&1).unwrap();
+                        assert_eq!(
+                            welfare_market.period,
+                            MarketPeriod::Block(0..duration + params.welfare_duration)
+                        );
+
+                        let proposal =
+                            zrml_futarchy::Proposals::<Runtime>::get(0).unwrap().proposal;
+                        let bond = <Runtime as zrml_futarchy::Config>::ProposalBond::get();
+                        assert_eq!(
+                            proposal.bond.map(|b| (b.who, b.value)),
+                            Some((alice.clone(), bond))
+                        );
+                        assert_eq!(
+                            Balances::reserved_balance_named(&Futarchy::reserve_id(), &alice),
+                            bond
+                        );
                     });
                 }
             }
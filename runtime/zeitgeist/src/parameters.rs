@@ -22,9 +22,8 @@
     clippy::arithmetic_side_effects
 )]
 
-#[cfg(feature = "parachain")]
-use super::Runtime;
-use super::{RuntimeHoldReason, VERSION};
+use super::{Runtime, RuntimeHoldReason, VERSION};
+use alloc::vec;
 use frame_support::{
     dispatch::DispatchClass,
     parameter_types,
@@ -33,7 +32,7 @@ use frame_support::{
         constants::{BlockExecutionWeight, ExtrinsicBaseWeight, WEIGHT_REF_TIME_PER_SECOND},
         Weight,
     },
-    PalletId,
+    BoundedVec, PalletId,
 };
 use frame_system::limits::{BlockLength, BlockWeights};
 use orml_traits::parameter_type_with_key;
@@ -44,6 +43,8 @@ use sp_runtime::{
 };
 use sp_version::RuntimeVersion;
 use zeitgeist_primitives::{constants::*, types::*};
+use zrml_futarchy::types::{CompositeOracleParams, CompositeRule};
+use zrml_neo_swaps::types::{DecisionMarketOracleParams, DecisionMarketSubOracleParams};
 
 pub(crate) const AVERAGE_ON_INITIALIZE_RATIO: Perbill = Perbill::from_percent(10);
 pub(crate) const MAXIMUM_BLOCK_WEIGHT: Weight = Weight::from_parts(
@@ -66,8 +67,16 @@ parameter_types! {
 
 parameter_types! {
     // Authorized
+    /// (Slashable) Bond that is provided for appealing the report of the authority.
+    /// Slashed in case the final outcome matches the appealed report.
+    pub const AuthorityAppealBond: Balance = 2000 * BASE;
+    /// The period after an appeal of the report of the authority in which a global dispute
+    /// can be started.
+    pub const AuthorityAppealPeriod: BlockNumber = BLOCKS_PER_DAY;
     pub const AuthorizedPalletId: PalletId = AUTHORIZED_PALLET_ID;
     pub const CorrectionPeriod: BlockNumber = BLOCKS_PER_DAY;
+    /// The maximum number of members of an authority committee of a market.
+    pub const MaxCommitteeMembers: u32 = 16;
 
     // Balance
     pub const ExistentialDeposit: u128 = BASE / 2;
@@ -90,6 +99,8 @@ parameter_types! {
     pub const TechnicalCommitteeMotionDuration: BlockNumber = 7 * BLOCKS_PER_DAY;
 
     // CombinatorialTokens
+    /// Maximum number of positions `redeem_all` may redeem or merge in one call.
+    pub const CombinatorialTokensMaxRedeemSteps: u32 = 16;
     pub const CombinatorialTokensPalletId: PalletId = COMBINATORIAL_TOKENS_PALLET_ID;
 
     // Contracts
@@ -159,11 +170,59 @@ parameter_types! {
     pub const DemocracyMaxProposals: u32 = 100;
 
     // Futarchy
+    /// The account the calls of bonded futarchy proposals are dispatched from, e.g. to spend
+    /// funds which governance allocated to futarchy.
+    pub FutarchyAccount: AccountId = FutarchyPalletId::get().into_account_truncating();
+    /// Maximum weight of the call of a futarchy proposal. Leaves room for the overhead of
+    /// dispatching the proposal within `MaximumSchedulerWeight`.
+    pub FutarchyMaxCallWeight: Weight = Perbill::from_percent(50) * MaximumSchedulerWeight::get();
+    /// Maximum number of dispatch outcomes of futarchy proposals kept in storage.
+    pub const FutarchyMaxDispatchOutcomes: u32 = 64;
     pub const FutarchyMaxProposals: u32 = 4;
+    /// Maximum number of sub-oracles of a composite futarchy oracle.
+    pub const FutarchyMaxSubOracles: u32 = 2;
+    /// Parameters of the markets and the pool deployed for the oracle of a bonded futarchy
+    /// proposal. The proposer funds the pool with `amount` and only provides the metadata of
+    /// the markets.
+    pub FutarchyBondedOracleParams: CompositeOracleParams<
+        DecisionMarketSubOracleParams<Runtime>,
+        FutarchyMaxSubOracles,
+    > = CompositeOracleParams {
+        params: BoundedVec::truncate_from(vec![DecisionMarketSubOracleParams::Categorical(
+            DecisionMarketOracleParams {
+                collateral: Asset::Ztg,
+                welfare_duration: 30 * BLOCKS_PER_DAY,
+                deadlines: Deadlines {
+                    grace_period: 0,
+                    oracle_duration: MIN_ORACLE_DURATION,
+                    dispute_duration: MIN_DISPUTE_DURATION,
+                },
+                dispute_mechanism: Some(MarketDisputeMechanism::Court),
+                amount: 10_000 * BASE,
+                swap_fee: CENT,
+                victory_margin: u128::from(BLOCKS_PER_DAY),
+                price_margin_abs: CENT,
+                price_margin_rel: 5 * CENT,
+            },
+        )]),
+        rule: CompositeRule::All,
+    };
+    pub const FutarchyPalletId: PalletId = FUTARCHY_PALLET_ID;
+    /// (Slashable) Bond that is provided for submitting a proposal without root privileges.
+    /// Slashed in case the proposal is rejected by its oracle or can't be scheduled.
+    pub const FutarchyProposalBond: Balance = 1000 * BASE;
     pub const MinDuration: BlockNumber = 7 * BLOCKS_PER_DAY;
 
     // Hybrid Router parameters
+    /// The amount of the base asset paid to the keeper who executes a conditional order.
+    pub const ConditionalOrderKeeperFee: Balance = 5 * CENT;
     pub const HybridRouterPalletId: PalletId = HYBRID_ROUTER_PALLET_ID;
+    /// Maximum number of markets a combinatorial position bought through the router may span.
+    pub const MaxLegs: u32 = 4;
+    /// Maximum number of conditional orders evaluated in `on_idle` per block.
+    pub const MaxConditionalOrderChecks: u32 = 8;
+    /// Maximum number of conditional orders waiting for their trigger in a single market.
+    pub const MaxConditionalOrdersPerMarket: u32 = 16;
     /// Maximum number of orders that can be placed in a single trade transaction.
     pub const MaxOrders: u32 = 100;
 
@@ -226,6 +285,12 @@ parameter_types! {
     /// Unreserved in case the dispute was justified otherwise slashed.
     /// This is when the resolved outcome is different to the default (reported) outcome.
     pub const DisputeBond: Balance = 2_000 * BASE;
+    /// Bond that is reserved from the owner of a market series until the
+    /// series ends.
+    pub const MarketSeriesBond: Balance = 200 * BASE;
+    /// Bond that is reserved from the owner of a market template until the
+    /// template is removed.
+    pub const MarketTemplateBond: Balance = 200 * BASE;
     /// Maximum Categories a prediciton market can have (excluding base asset).
     pub const MaxCategories: u16 = MAX_CATEGORIES;
     /// Max creator fee, bounds the fraction per trade volume that is moved to the market creator.
@@ -241,6 +306,9 @@ parameter_types! {
     pub const MaxGracePeriod: BlockNumber = MAX_GRACE_PERIOD;
     /// The maximum allowed duration of a market from creation to market close in blocks.
     pub const MaxMarketLifetime: BlockNumber = MAX_MARKET_LIFETIME;
+    /// Maximum number of market series whose latest market closes in the same block or time
+    /// frame, and maximum number of queued series rollovers executed per block.
+    pub const MaxMarketSeriesRolloversPerBlock: u32 = 8;
     /// Maximum block period for an oracle_duration.
     /// The oracle_duration is a duration where the oracle has to submit its report.
     pub const MaxOracleDuration: BlockNumber = MAX_ORACLE_DURATION;
@@ -312,9 +380,15 @@ parameter_types! {
     pub const SwapsPalletId: PalletId = SWAPS_PALLET_ID;
 
     // Orderbook parameters
+    /// The maximum number of orders per asset pair which are indexed by price.
+    pub const MaxIndexedOrders: u32 = 200;
+    /// The minimum amount of base asset which an order must offer or ask for.
+    pub const MinOrderAmount: Balance = BASE;
     pub const OrderbookPalletId: PalletId = ORDERBOOK_PALLET_ID;
 
     // Parimutuel parameters
+    /// The share of the stake which remains in the pot when exiting a parimutuel bet early.
+    pub const EarlyExitPenalty: Perbill = Perbill::from_percent(10);
     pub const MinBetSize: Balance = 5 * BASE;
     pub const ParimutuelPalletId: PalletId = PARIMUTUEL_PALLET_ID;
 
@@ -426,6 +500,12 @@ parameter_types! {
     // Global Disputes
     /// The time period in which the addition of new outcomes are allowed.
     pub const AddOutcomePeriod: BlockNumber = BLOCKS_PER_DAY;
+    /// The period, which is multiplied by the lock periods of a vote's conviction,
+    /// for which conviction votes stay locked after the end of the global dispute.
+    pub const ConvictionLockPeriod: BlockNumber = 7 * BLOCKS_PER_DAY;
+    /// The voting weight mode of global disputes, unless governance sets a different one.
+    pub const DefaultVotingWeightMode: zrml_global_disputes::types::VotingWeightMode =
+        zrml_global_disputes::types::VotingWeightMode::TokenWeighted;
     /// Vote lock identifier, mainly used for the LockableCurrency on the native token.
     pub const GlobalDisputeLockId: LockIdentifier = GLOBAL_DISPUTES_LOCK_ID;
     /// Pallet identifier
@@ -438,6 +518,9 @@ parameter_types! {
     pub const MinOutcomeVoteAmount: Balance = 10 * BASE;
     /// The time period in which votes are allowed.
     pub const GdVotingPeriod: BlockNumber = 7 * BLOCKS_PER_DAY;
+    /// The share of the fees of the losing voting outcomes which rewards the voters of the
+    /// winning outcome.
+    pub const VoterRewardPercentage: Perbill = Perbill::from_percent(50);
     /// The fee required to add a voting outcome.
     pub const VotingOutcomeFee: Balance = 200 * BASE;
     /// The remove limit for the Outcomes storage double map.
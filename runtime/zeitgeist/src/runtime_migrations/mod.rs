@@ -21,7 +21,12 @@ use pallet_parachain_staking::migrations::{
     LegacyAtStakeCursorMigration, LegacyAtStakeMigrationKey, LegacyAtStakeMigrationList,
     LEGACY_AT_STAKE_MIGRATION_ID_LEN,
 };
+use zrml_futarchy::migrations::mbm::MigrateProposalsToProposalIds;
+use zrml_global_disputes::migrations::mbm::MigrateLocksToVoteLocks;
+use zrml_neo_swaps::types::DecisionMarketOracle;
+use zrml_orderbook::migrations::mbm::BuildOrderPriceIndex;
 use zrml_prediction_markets::migrations::mbm::TimeFrameRescaleMigration;
+use zrml_styx::migrations::mbm::MigrateCrossingsToCrossingInfo;
 
 mod legacy_keys;
 use legacy_keys::ZEITGEIST_LEGACY_KEYS;
@@ -43,4 +48,12 @@ impl LegacyAtStakeMigrationList for ZeitgeistLegacyKeys {
 pub type LegacyMigrations = (
     LegacyAtStakeCursorMigration<crate::Runtime, ZeitgeistLegacyKeys, ZEITGEIST_MIGRATION_BATCH>,
     TimeFrameRescaleMigration<crate::Runtime>,
+    BuildOrderPriceIndex<crate::Runtime>,
+    MigrateCrossingsToCrossingInfo<crate::Runtime>,
+    MigrateLocksToVoteLocks<crate::Runtime>,
+    MigrateProposalsToProposalIds<
+        crate::Runtime,
+        DecisionMarketOracle<crate::Runtime>,
+        crate::FutarchyLegacyOracleConverter,
+    >,
 );
@@ -16,6 +16,17 @@
 // You should have received a copy of the GNU General Public License
 // along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
 
+use frame_support::weights::Weight;
 use zeitgeist_primitives::traits::{DisputeApi, DisputeMaxWeightApi};
 
-pub trait AuthorizedPalletApi: DisputeApi + DisputeMaxWeightApi {}
+pub trait AuthorizedPalletApi: DisputeApi + DisputeMaxWeightApi {
+    /// Called when a market with the `Authorized` dispute mechanism is created. The creator of
+    /// the market can only set an authority committee in the block in which the market was
+    /// created, so that the committee is fixed before anyone trades on the market.
+    fn on_market_creation(market_id: &Self::MarketId);
+
+    /// Called when a market with the `Authorized` dispute mechanism is resolved or destroyed, or
+    /// when its dispute mechanism is edited. Removes the authority committee of the market and
+    /// the reports of its members. Returns the weight consumed.
+    fn clear_committee(market_id: &Self::MarketId) -> Weight;
+}
@@ -23,11 +23,17 @@
 #![cfg(feature = "runtime-benchmarks")]
 
 use crate::{
-    market_mock, AuthorizedOutcomeReports, Call, Config, NegativeImbalanceOf, Pallet as Authorized,
-    Pallet,
+    market_mock,
+    types::{AuthorityAppeal, AuthorityCommittee},
+    AuthorityAppeals, AuthorityCommittees, AuthorizedOutcomeReports, AuthorizedPalletApi, Call,
+    CommitteeReports, Config, NegativeImbalanceOf, Pallet as Authorized, Pallet,
 };
-use frame_benchmarking::benchmarks;
-use frame_support::traits::{EnsureOrigin, Get, Imbalance, UnfilteredDispatchable};
+use alloc::vec::Vec;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::traits::{
+    Currency, EnsureOrigin, Get, Imbalance, NamedReservableCurrency, UnfilteredDispatchable,
+};
+use frame_system::RawOrigin;
 use sp_runtime::traits::Saturating;
 use zeitgeist_primitives::{
     traits::{DisputeApi, DisputeResolutionApi},
@@ -35,6 +41,33 @@ use zeitgeist_primitives::{
 };
 use zrml_market_commons::MarketCommonsPalletApi;
 
+// Inserts a committee with `m` members, of which all but the first already reported.
+fn setup_committee<T: Config>(market_id: crate::MarketIdOf<T>, m: u32) -> Vec<T::AccountId> {
+    let members: Vec<T::AccountId> = (0..m).map(|i| account("member", i, 0)).collect();
+    let committee = AuthorityCommittee {
+        members: members.clone().try_into().unwrap(),
+        // the last report reaches the threshold
+        threshold: m,
+    };
+    AuthorityCommittees::<T>::insert(market_id, committee);
+    for member in members.iter().skip(1) {
+        CommitteeReports::<T>::insert(market_id, member, OutcomeReport::Scalar(1));
+    }
+    members
+}
+
+// Inserts an appeal against the report `Scalar(0)` with a reserved bond.
+fn setup_appeal<T: Config>(market_id: crate::MarketIdOf<T>) {
+    let appellant: T::AccountId = account("appellant", 0, 0);
+    let bond = T::AppealBond::get();
+    T::Currency::deposit_creating(&appellant, bond.saturating_mul(2u32.into()));
+    T::Currency::reserve_named(&Authorized::<T>::reserve_id(), &appellant, bond).unwrap();
+    let resolve_at =
+        frame_system::Pallet::<T>::block_number().saturating_add(T::AppealPeriod::get());
+    let appeal = AuthorityAppeal { appellant, bond, outcome: OutcomeReport::Scalar(0), resolve_at };
+    AuthorityAppeals::<T>::insert(market_id, appeal);
+}
+
 benchmarks! {
     authorize_market_outcome_first_report {
         let m in 1..63;
@@ -94,6 +127,79 @@ benchmarks! {
         assert_eq!(AuthorizedOutcomeReports::<T>::get(market_id).unwrap(), report);
     }
 
+    authorize_market_outcome_committee_report {
+        let m in 1..T::MaxCommitteeMembers::get();
+        let r in 1..63;
+
+        let market_id = 0u32.into();
+        let market = market_mock::<T>();
+        T::MarketCommons::push_market(market).unwrap();
+        let members = setup_committee::<T>(market_id, m);
+
+        frame_system::Pallet::<T>::set_block_number(42u32.into());
+        let now = frame_system::Pallet::<T>::block_number();
+        let correction_period_ends_at = now.saturating_add(T::CorrectionPeriod::get());
+        for _ in 1..=r {
+            let id = T::MarketCommons::push_market(market_mock::<T>()).unwrap();
+            T::DisputeResolution::add_auto_resolve(&id, correction_period_ends_at).unwrap();
+        }
+    }: authorize_market_outcome(
+        RawOrigin::Signed(members[0].clone()),
+        market_id,
+        OutcomeReport::Scalar(1)
+    )
+    verify {
+        let report = AuthorityReport {
+            resolve_at: correction_period_ends_at,
+            outcome: OutcomeReport::Scalar(1)
+        };
+        assert_eq!(AuthorizedOutcomeReports::<T>::get(market_id).unwrap(), report);
+    }
+
+    set_authority_committee {
+        let m in 1..T::MaxCommitteeMembers::get();
+
+        let caller: T::AccountId = whitelisted_caller();
+        let market_id = 0u32.into();
+        let mut market = market_mock::<T>();
+        market.creator = caller.clone();
+        market.status = zeitgeist_primitives::types::MarketStatus::Active;
+        T::MarketCommons::push_market(market).unwrap();
+        Authorized::<T>::on_market_creation(&market_id);
+
+        let members: Vec<T::AccountId> = (0..m).map(|i| account("member", i, 0)).collect();
+    }: _(RawOrigin::Signed(caller), market_id, members, m)
+    verify {
+        assert!(AuthorityCommittees::<T>::contains_key(market_id));
+    }
+
+    appeal_report {
+        let m in 1..63;
+
+        let caller: T::AccountId = whitelisted_caller();
+        let bond = T::AppealBond::get();
+        T::Currency::deposit_creating(&caller, bond.saturating_mul(2u32.into()));
+        let market_id = 0u32.into();
+        let market = market_mock::<T>();
+        T::MarketCommons::push_market(market).unwrap();
+
+        frame_system::Pallet::<T>::set_block_number(42u32.into());
+        let now = frame_system::Pallet::<T>::block_number();
+        let resolve_at = now.saturating_add(T::CorrectionPeriod::get());
+        let report = AuthorityReport { resolve_at, outcome: OutcomeReport::Scalar(0) };
+        AuthorizedOutcomeReports::<T>::insert(market_id, report);
+        T::DisputeResolution::add_auto_resolve(&market_id, resolve_at).unwrap();
+        let appeal_resolve_at = now.saturating_add(T::AppealPeriod::get());
+        for _ in 1..m {
+            let id = T::MarketCommons::push_market(market_mock::<T>()).unwrap();
+            T::DisputeResolution::add_auto_resolve(&id, resolve_at).unwrap();
+            T::DisputeResolution::add_auto_resolve(&id, appeal_resolve_at).unwrap();
+        }
+    }: _(RawOrigin::Signed(caller), market_id)
+    verify {
+        assert!(AuthorityAppeals::<T>::contains_key(market_id));
+    }
+
     on_dispute_weight {
         let market_id = 0u32.into();
         let market = market_mock::<T>();
@@ -109,6 +215,7 @@ benchmarks! {
 
         let report = AuthorityReport { resolve_at: 0u32.into(), outcome: OutcomeReport::Scalar(0) };
         AuthorizedOutcomeReports::<T>::insert(market_id, report);
+        setup_committee::<T>(market_id, T::MaxCommitteeMembers::get());
     }: {
         Authorized::<T>::on_resolution(&market_id, &market).unwrap();
     }
@@ -118,6 +225,11 @@ benchmarks! {
         let market = market_mock::<T>();
         T::MarketCommons::push_market(market.clone()).unwrap();
 
+        let report = AuthorityReport { resolve_at: 0u32.into(), outcome: OutcomeReport::Scalar(0) };
+        AuthorizedOutcomeReports::<T>::insert(market_id, report);
+        setup_committee::<T>(market_id, T::MaxCommitteeMembers::get());
+        setup_appeal::<T>(market_id);
+
         let outcome = OutcomeReport::Scalar(0);
         let imb = NegativeImbalanceOf::<T>::zero();
     }: {
@@ -138,6 +250,7 @@ benchmarks! {
         let market_id = 0u32.into();
         let market = market_mock::<T>();
         T::MarketCommons::push_market(market.clone()).unwrap();
+        setup_appeal::<T>(market_id);
     }: {
         Authorized::<T>::has_failed(&market_id, &market).unwrap();
     }
@@ -146,6 +259,7 @@ benchmarks! {
         let market_id = 0u32.into();
         let market = market_mock::<T>();
         T::MarketCommons::push_market(market.clone()).unwrap();
+        setup_appeal::<T>(market_id);
     }: {
         Authorized::<T>::on_global_dispute(&market_id, &market).unwrap();
     }
@@ -156,6 +270,8 @@ benchmarks! {
         T::MarketCommons::push_market(market.clone()).unwrap();
         let report = AuthorityReport { resolve_at: 0u32.into(), outcome: OutcomeReport::Scalar(0) };
         AuthorizedOutcomeReports::<T>::insert(market_id, report);
+        setup_committee::<T>(market_id, T::MaxCommitteeMembers::get());
+        setup_appeal::<T>(market_id);
     }: {
         Authorized::<T>::clear(&market_id, &market).unwrap();
     }
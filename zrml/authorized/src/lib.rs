@@ -27,6 +27,7 @@ pub mod migrations;
 mod mock;
 mod mock_storage;
 mod tests;
+pub mod types;
 pub mod weights;
 
 pub use authorized_pallet_api::AuthorizedPalletApi;
@@ -34,18 +35,32 @@ pub use pallet::*;
 
 #[frame_support::pallet]
 mod pallet {
-    use crate::{weights::WeightInfoZeitgeist, AuthorizedPalletApi};
+    use crate::{
+        types::{AuthorityAppeal, AuthorityCommittee},
+        weights::WeightInfoZeitgeist,
+        AuthorizedPalletApi,
+    };
     use alloc::vec::Vec;
     use core::marker::PhantomData;
     use frame_support::{
         dispatch::DispatchResultWithPostInfo,
         ensure,
-        pallet_prelude::{ConstU32, EnsureOrigin, OptionQuery, StorageMap, Weight},
-        traits::{Currency, Get, Hooks, IsType, StorageVersion},
-        PalletId, Twox64Concat,
+        pallet_prelude::{
+            ConstU32, EnsureOrigin, OptionQuery, StorageDoubleMap, StorageMap, Weight,
+        },
+        traits::{
+            Currency, Get, Hooks, Imbalance, IsType, NamedReservableCurrency, StorageVersion,
+        },
+        BoundedVec, PalletId, Twox64Concat,
+    };
+    use frame_system::{
+        ensure_signed,
+        pallet_prelude::{BlockNumberFor, OriginFor},
+    };
+    use sp_runtime::{
+        traits::{AccountIdConversion, Saturating, Zero},
+        DispatchError, DispatchResult,
     };
-    use frame_system::pallet_prelude::{BlockNumberFor, OriginFor};
-    use sp_runtime::{traits::Saturating, DispatchError, DispatchResult};
     use zeitgeist_primitives::{
         traits::{DisputeApi, DisputeMaxWeightApi, DisputeResolutionApi},
         types::{
@@ -74,15 +89,27 @@ mod pallet {
         MomentOf<T>,
         MarketIdOf<T>,
     >;
+    pub(crate) type CommitteeMembersOf<T> =
+        BoundedVec<<T as frame_system::Config>::AccountId, <T as Config>::MaxCommitteeMembers>;
+    pub type AuthorityCommitteeOf<T> = AuthorityCommittee<CommitteeMembersOf<T>>;
+    pub type AuthorityAppealOf<T> =
+        AuthorityAppeal<<T as frame_system::Config>::AccountId, BalanceOf<T>, BlockNumberFor<T>>;
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Overwrites already provided outcomes for the same market and account.
+        ///
+        /// If the market has an authority committee, only its members are allowed to report.
+        /// Each member's report is stored, and the outcome is only accepted once `threshold`
+        /// members have reported the same outcome.
         #[pallet::call_index(0)]
         #[pallet::weight(
-            T::WeightInfo::authorize_market_outcome_first_report(CacheSize::get()).max(
-                T::WeightInfo::authorize_market_outcome_existing_report(),
-            )
+            T::WeightInfo::authorize_market_outcome_first_report(CacheSize::get())
+                .max(T::WeightInfo::authorize_market_outcome_existing_report())
+                .max(T::WeightInfo::authorize_market_outcome_committee_report(
+                    T::MaxCommitteeMembers::get(),
+                    CacheSize::get(),
+                ))
         )]
         #[frame_support::transactional]
         pub fn authorize_market_outcome(
@@ -90,45 +117,206 @@ mod pallet {
             market_id: MarketIdOf<T>,
             outcome: OutcomeReport,
         ) -> DispatchResultWithPostInfo {
-            T::AuthorizedDisputeResolutionOrigin::ensure_origin(origin)?;
+            let committee_opt = AuthorityCommittees::<T>::get(market_id);
+            let member_opt = match committee_opt {
+                Some(ref committee) => {
+                    let who = ensure_signed(origin)?;
+                    ensure!(committee.members.contains(&who), Error::<T>::NotCommitteeMember);
+                    Some(who)
+                }
+                None => {
+                    T::AuthorizedDisputeResolutionOrigin::ensure_origin(origin)?;
+                    None
+                }
+            };
             let market = T::MarketCommons::market(&market_id)?;
             ensure!(market.status == MarketStatus::Disputed, Error::<T>::MarketIsNotDisputed);
             ensure!(market.matches_outcome_report(&outcome), Error::<T>::OutcomeMismatch);
             Self::ensure_dispute_mechanism(&market)?;
+            ensure!(!AuthorityAppeals::<T>::contains_key(market_id), Error::<T>::ReportAppealed);
+
+            if let (Some(committee), Some(member)) = (committee_opt, member_opt) {
+                CommitteeReports::<T>::insert(market_id, &member, outcome.clone());
+                Self::deposit_event(Event::CommitteeMemberReported {
+                    market_id,
+                    member,
+                    outcome: outcome.clone(),
+                });
+
+                let signatures = Self::committee_signatures(&market_id, &outcome);
+                let members_len = committee.members.len() as u32;
+                let ids_len = if signatures >= committee.threshold {
+                    Self::accept_report(market_id, outcome)?.unwrap_or(0u32)
+                } else if let Some(report) = AuthorizedOutcomeReports::<T>::get(market_id) {
+                    // the member may have withdrawn one of the signatures of the accepted outcome
+                    if Self::committee_signatures(&market_id, &report.outcome) < committee.threshold
+                    {
+                        Self::revoke_report(market_id, report)
+                    } else {
+                        0u32
+                    }
+                } else {
+                    0u32
+                };
+
+                return Ok(Some(T::WeightInfo::authorize_market_outcome_committee_report(
+                    members_len,
+                    ids_len,
+                ))
+                .into());
+            }
+
+            match Self::accept_report(market_id, outcome)? {
+                Some(ids_len) => {
+                    Ok(Some(T::WeightInfo::authorize_market_outcome_first_report(ids_len)).into())
+                }
+                None => Ok(Some(T::WeightInfo::authorize_market_outcome_existing_report()).into()),
+            }
+        }
 
+        /// Sets an M-of-N committee which resolves the market if it's disputed, instead of
+        /// `AuthorizedDisputeResolutionOrigin`.
+        ///
+        /// Must be called by the market creator in the block in which the market was created
+        /// (usually in the same batch as the market creation). The committee can't be changed
+        /// once it's set.
+        ///
+        /// # Arguments
+        ///
+        /// - `market_id`: The id of the market.
+        /// - `members`: The accounts which are allowed to report the outcome.
+        /// - `threshold`: The number of members which need to report the same outcome.
+        ///
+        /// # Weight
+        ///
+        /// Complexity: `O(n log n)`, where `n` is the number of members.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::set_authority_committee(T::MaxCommitteeMembers::get()))]
+        #[frame_support::transactional]
+        pub fn set_authority_committee(
+            origin: OriginFor<T>,
+            market_id: MarketIdOf<T>,
+            members: Vec<T::AccountId>,
+            threshold: u32,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let market = T::MarketCommons::market(&market_id)?;
+            ensure!(market.creator == who, Error::<T>::CallerNotMarketCreator);
+            Self::ensure_dispute_mechanism(&market)?;
+            ensure!(
+                !AuthorityCommittees::<T>::contains_key(market_id),
+                Error::<T>::CommitteeAlreadySet
+            );
             let now = frame_system::Pallet::<T>::block_number();
+            ensure!(
+                CommitteeSetupBlocks::<T>::take(market_id) == Some(now),
+                Error::<T>::CommitteeSetupClosed
+            );
 
-            let report_opt = AuthorizedOutcomeReports::<T>::get(market_id);
-            let (report, ids_len) = match &report_opt {
-                Some(report) => (
-                    AuthorityReport { resolve_at: report.resolve_at, outcome: outcome.clone() },
-                    0u32,
-                ),
-                None => {
-                    let resolve_at = now.saturating_add(T::CorrectionPeriod::get());
-                    let ids_len = T::DisputeResolution::add_auto_resolve(&market_id, resolve_at)?;
-                    (AuthorityReport { resolve_at, outcome: outcome.clone() }, ids_len)
-                }
-            };
+            let members_len = members.len() as u32;
+            ensure!(
+                threshold != 0 && threshold <= members_len,
+                Error::<T>::InvalidCommitteeThreshold
+            );
+            let mut sorted_members = members.clone();
+            sorted_members.sort();
+            sorted_members.dedup();
+            ensure!(
+                sorted_members.len() as u32 == members_len,
+                Error::<T>::DuplicateCommitteeMember
+            );
+            let bounded_members: CommitteeMembersOf<T> =
+                members.clone().try_into().map_err(|_| Error::<T>::TooManyCommitteeMembers)?;
 
-            AuthorizedOutcomeReports::<T>::insert(market_id, report);
+            AuthorityCommittees::<T>::insert(
+                market_id,
+                AuthorityCommittee { members: bounded_members, threshold },
+            );
 
-            Self::deposit_event(Event::AuthorityReported { market_id, outcome });
+            Self::deposit_event(Event::AuthorityCommitteeSet { market_id, members, threshold });
 
-            if report_opt.is_none() {
-                Ok(Some(T::WeightInfo::authorize_market_outcome_first_report(ids_len)).into())
-            } else {
-                Ok(Some(T::WeightInfo::authorize_market_outcome_existing_report()).into())
-            }
+            Ok(Some(T::WeightInfo::set_authority_committee(members_len)).into())
+        }
+
+        /// Appeals the report of the authority by locking `AppealBond`.
+        ///
+        /// The appealed market no longer resolves after the correction period. Instead, the
+        /// market dispute mechanism counts as failed, so that a global dispute can be started
+        /// (usually in the same batch as the appeal), in which the appealed report is added as
+        /// a vote outcome owned by the account of this pallet on behalf of the authority. If no global dispute is started within the
+        /// `AppealPeriod`, the market resolves to the appealed report. The bond is returned if
+        /// the market resolves to a different outcome than the appealed report and slashed
+        /// otherwise.
+        ///
+        /// # Arguments
+        ///
+        /// - `market_id`: The id of the market.
+        ///
+        /// # Weight
+        ///
+        /// Complexity: `O(n)`, where `n` is the number of markets which resolve in the same block
+        /// as the appealed market.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::appeal_report(CacheSize::get()))]
+        #[frame_support::transactional]
+        pub fn appeal_report(
+            origin: OriginFor<T>,
+            market_id: MarketIdOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let market = T::MarketCommons::market(&market_id)?;
+            ensure!(market.status == MarketStatus::Disputed, Error::<T>::MarketIsNotDisputed);
+            Self::ensure_dispute_mechanism(&market)?;
+            ensure!(!AuthorityAppeals::<T>::contains_key(market_id), Error::<T>::ReportAppealed);
+            let report = AuthorizedOutcomeReports::<T>::get(market_id)
+                .ok_or(Error::<T>::NoAuthorityReport)?;
+
+            let bond = T::AppealBond::get();
+            T::Currency::reserve_named(&Self::reserve_id(), &who, bond)?;
+
+            let ids_len_0 =
+                T::DisputeResolution::remove_auto_resolve(&market_id, report.resolve_at);
+            let now = frame_system::Pallet::<T>::block_number();
+            let resolve_at = now.saturating_add(T::AppealPeriod::get());
+            let ids_len_1 = T::DisputeResolution::add_auto_resolve(&market_id, resolve_at)?;
+
+            AuthorityAppeals::<T>::insert(
+                market_id,
+                AuthorityAppeal {
+                    appellant: who.clone(),
+                    bond,
+                    outcome: report.outcome.clone(),
+                    resolve_at,
+                },
+            );
+
+            Self::deposit_event(Event::ReportAppealed {
+                market_id,
+                appellant: who,
+                outcome: report.outcome,
+                bond,
+            });
+
+            Ok(Some(T::WeightInfo::appeal_report(ids_len_0.max(ids_len_1))).into())
         }
     }
 
     #[pallet::config]
     pub trait Config: frame_system::Config {
+        /// The bond which is locked to appeal the report of the authority.
+        #[pallet::constant]
+        type AppealBond: Get<BalanceOf<Self>>;
+
+        /// The period after an appeal in which a global dispute can be started. If no global
+        /// dispute was started until then, the market resolves to the appealed report.
+        #[pallet::constant]
+        type AppealPeriod: Get<BlockNumberFor<Self>>;
+
         /// Event
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
-        type Currency: Currency<Self::AccountId>;
+        type Currency: Currency<Self::AccountId>
+            + NamedReservableCurrency<Self::AccountId, ReserveIdentifier = [u8; 8]>;
 
         /// The period, in which the authority can correct the outcome of a market.
         /// This value must not be zero.
@@ -151,6 +339,10 @@ mod pallet {
         /// The origin that is allowed to resolved disupute in Authorized dispute mechanism.
         type AuthorizedDisputeResolutionOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
+        /// The maximum number of members of an authority committee.
+        #[pallet::constant]
+        type MaxCommitteeMembers: Get<u32>;
+
         /// Identifier of this pallet
         #[pallet::constant]
         type PalletId: Get<PalletId>;
@@ -167,6 +359,24 @@ mod pallet {
         MarketIsNotDisputed,
         /// The report does not match the market's type.
         OutcomeMismatch,
+        /// Only the market creator is allowed to set the authority committee.
+        CallerNotMarketCreator,
+        /// The market already has an authority committee.
+        CommitteeAlreadySet,
+        /// An account was specified more than once as committee member.
+        DuplicateCommitteeMember,
+        /// The threshold needs to be at least one and at most the number of committee members.
+        InvalidCommitteeThreshold,
+        /// The authority committee can only be set in the block in which the market was created.
+        CommitteeSetupClosed,
+        /// The sender is not a member of the authority committee of the market.
+        NotCommitteeMember,
+        /// The authority hasn't reported an outcome yet.
+        NoAuthorityReport,
+        /// The report of the authority was already appealed.
+        ReportAppealed,
+        /// The maximum number of committee members is exceeded.
+        TooManyCommitteeMembers,
     }
 
     #[pallet::event]
@@ -177,6 +387,28 @@ mod pallet {
     {
         /// The Authority reported.
         AuthorityReported { market_id: MarketIdOf<T>, outcome: OutcomeReport },
+        /// An authority committee was set for a market.
+        AuthorityCommitteeSet {
+            market_id: MarketIdOf<T>,
+            members: Vec<T::AccountId>,
+            threshold: u32,
+        },
+        /// A member of the authority committee reported.
+        CommitteeMemberReported {
+            market_id: MarketIdOf<T>,
+            member: T::AccountId,
+            outcome: OutcomeReport,
+        },
+        /// The report of the authority was appealed.
+        ReportAppealed {
+            market_id: MarketIdOf<T>,
+            appellant: T::AccountId,
+            outcome: OutcomeReport,
+            bond: BalanceOf<T>,
+        },
+        /// The outcome accepted from an authority committee was revoked, because members changed
+        /// their reports and it no longer has enough signatures.
+        AuthorityReportRevoked { market_id: MarketIdOf<T>, outcome: OutcomeReport },
     }
 
     #[pallet::hooks]
@@ -190,11 +422,88 @@ mod pallet {
     where
         T: Config,
     {
+        /// Store the accepted outcome of the authority. The first accepted outcome schedules the
+        /// resolution of the market after the `CorrectionPeriod`, later ones only correct it.
+        ///
+        /// Returns the number of markets which resolve at the same block if the outcome was
+        /// accepted for the first time.
+        fn accept_report(
+            market_id: MarketIdOf<T>,
+            outcome: OutcomeReport,
+        ) -> Result<Option<u32>, DispatchError> {
+            let now = frame_system::Pallet::<T>::block_number();
+
+            let report_opt = AuthorizedOutcomeReports::<T>::get(market_id);
+            let (report, ids_len_opt) = match &report_opt {
+                Some(report) => (
+                    AuthorityReport { resolve_at: report.resolve_at, outcome: outcome.clone() },
+                    None,
+                ),
+                None => {
+                    let resolve_at = now.saturating_add(T::CorrectionPeriod::get());
+                    let ids_len = T::DisputeResolution::add_auto_resolve(&market_id, resolve_at)?;
+                    (AuthorityReport { resolve_at, outcome: outcome.clone() }, Some(ids_len))
+                }
+            };
+
+            AuthorizedOutcomeReports::<T>::insert(market_id, report);
+
+            Self::deposit_event(Event::AuthorityReported { market_id, outcome });
+
+            Ok(ids_len_opt)
+        }
+
+        /// Remove the accepted outcome of a committee which no longer has enough signatures and
+        /// unschedule the resolution of the market.
+        ///
+        /// Returns the number of markets which resolved at the same block.
+        fn revoke_report(
+            market_id: MarketIdOf<T>,
+            report: AuthorityReport<BlockNumberFor<T>>,
+        ) -> u32 {
+            let ids_len = T::DisputeResolution::remove_auto_resolve(&market_id, report.resolve_at);
+            AuthorizedOutcomeReports::<T>::remove(market_id);
+            Self::deposit_event(Event::AuthorityReportRevoked {
+                market_id,
+                outcome: report.outcome,
+            });
+            ids_len
+        }
+
+        /// The number of members of the authority committee which reported `outcome`.
+        fn committee_signatures(market_id: &MarketIdOf<T>, outcome: &OutcomeReport) -> u32 {
+            CommitteeReports::<T>::iter_prefix_values(market_id)
+                .filter(|report| report == outcome)
+                .count() as u32
+        }
+
+        /// Remove the authority committee of a market and the reports of its members.
+        fn remove_committee(market_id: &MarketIdOf<T>) {
+            CommitteeSetupBlocks::<T>::remove(market_id);
+            AuthorityCommittees::<T>::remove(market_id);
+            let _ =
+                CommitteeReports::<T>::clear_prefix(market_id, T::MaxCommitteeMembers::get(), None);
+        }
+
         /// Return the resolution block number for the given market.
         fn get_auto_resolve(market_id: &MarketIdOf<T>) -> Option<BlockNumberFor<T>> {
+            if let Some(appeal) = AuthorityAppeals::<T>::get(market_id) {
+                return Some(appeal.resolve_at);
+            }
             AuthorizedOutcomeReports::<T>::get(market_id).map(|report| report.resolve_at)
         }
 
+        /// The reserve ID of the authorized pallet.
+        #[inline]
+        pub fn reserve_id() -> [u8; 8] {
+            T::PalletId::get().0
+        }
+
+        /// The account which owns the appealed reports of the authority in global disputes.
+        pub fn authority_account() -> T::AccountId {
+            T::PalletId::get().into_account_truncating()
+        }
+
         #[inline]
         fn ensure_dispute_mechanism(market: &MarketOf<T>) -> DispatchResult {
             ensure!(
@@ -268,6 +577,7 @@ mod pallet {
         ) -> Result<ResultWithWeightInfo<Option<OutcomeReport>>, DispatchError> {
             Self::ensure_dispute_mechanism(market)?;
             let report = AuthorizedOutcomeReports::<T>::take(market_id);
+            Self::remove_committee(market_id);
 
             let res = ResultWithWeightInfo {
                 result: report.map(|r| r.outcome),
@@ -278,12 +588,33 @@ mod pallet {
         }
 
         fn exchange(
-            _: &Self::MarketId,
+            market_id: &Self::MarketId,
             market: &MarketOf<T>,
-            _: &OutcomeReport,
-            overall_imbalance: NegativeImbalanceOf<T>,
+            resolved_outcome: &OutcomeReport,
+            mut overall_imbalance: NegativeImbalanceOf<T>,
         ) -> Result<ResultWithWeightInfo<NegativeImbalanceOf<T>>, DispatchError> {
             Self::ensure_dispute_mechanism(market)?;
+
+            if let Some(appeal) = AuthorityAppeals::<T>::take(market_id) {
+                if &appeal.outcome == resolved_outcome {
+                    let (imbalance, _) = T::Currency::slash_reserved_named(
+                        &Self::reserve_id(),
+                        &appeal.appellant,
+                        appeal.bond,
+                    );
+                    overall_imbalance.subsume(imbalance);
+                } else {
+                    T::Currency::unreserve_named(
+                        &Self::reserve_id(),
+                        &appeal.appellant,
+                        appeal.bond,
+                    );
+                }
+                // the market was resolved by a global dispute instead of `on_resolution`
+                AuthorizedOutcomeReports::<T>::remove(market_id);
+                Self::remove_committee(market_id);
+            }
+
             // all funds to treasury
             let res = ResultWithWeightInfo {
                 result: overall_imbalance,
@@ -312,19 +643,21 @@ mod pallet {
         }
 
         fn has_failed(
-            _: &Self::MarketId,
+            market_id: &Self::MarketId,
             market: &MarketOf<T>,
         ) -> Result<ResultWithWeightInfo<bool>, DispatchError> {
             Self::ensure_dispute_mechanism(market)?;
 
-            let res =
-                ResultWithWeightInfo { result: false, weight: T::WeightInfo::has_failed_weight() };
+            let res = ResultWithWeightInfo {
+                result: AuthorityAppeals::<T>::contains_key(market_id),
+                weight: T::WeightInfo::has_failed_weight(),
+            };
 
             Ok(res)
         }
 
         fn on_global_dispute(
-            _: &Self::MarketId,
+            market_id: &Self::MarketId,
             market: &MarketOf<T>,
         ) -> Result<
             ResultWithWeightInfo<Vec<GlobalDisputeItem<Self::AccountId, Self::Balance>>>,
@@ -332,8 +665,19 @@ mod pallet {
         > {
             Self::ensure_dispute_mechanism(market)?;
 
+            // the appealed report is the outcome of the authority, so it's owned by the account of
+            // this pallet and not by the appellant, who disputes it
+            let gd_items = AuthorityAppeals::<T>::get(market_id)
+                .map(|appeal| GlobalDisputeItem {
+                    outcome: appeal.outcome,
+                    owner: Self::authority_account(),
+                    initial_vote_amount: Zero::zero(),
+                })
+                .into_iter()
+                .collect();
+
             let res = ResultWithWeightInfo {
-                result: Vec::new(),
+                result: gd_items,
                 weight: T::WeightInfo::on_global_dispute_weight(),
             };
 
@@ -347,6 +691,10 @@ mod pallet {
             Self::ensure_dispute_mechanism(market)?;
 
             AuthorizedOutcomeReports::<T>::remove(market_id);
+            Self::remove_committee(market_id);
+            if let Some(appeal) = AuthorityAppeals::<T>::take(market_id) {
+                T::Currency::unreserve_named(&Self::reserve_id(), &appeal.appellant, appeal.bond);
+            }
 
             let res = ResultWithWeightInfo { result: (), weight: T::WeightInfo::clear_weight() };
 
@@ -354,13 +702,58 @@ mod pallet {
         }
     }
 
-    impl<T> AuthorizedPalletApi for Pallet<T> where T: Config {}
+    impl<T> AuthorizedPalletApi for Pallet<T>
+    where
+        T: Config,
+    {
+        fn on_market_creation(market_id: &Self::MarketId) {
+            let now = frame_system::Pallet::<T>::block_number();
+            CommitteeSetupBlocks::<T>::insert(market_id, now);
+        }
+
+        fn clear_committee(market_id: &Self::MarketId) -> Weight {
+            Self::remove_committee(market_id);
+            let max_reports: u64 = T::MaxCommitteeMembers::get().into();
+            T::DbWeight::get().reads_writes(max_reports, max_reports.saturating_add(2))
+        }
+    }
 
     /// Maps the market id to the outcome reported by the authorized account.    
     #[pallet::storage]
     #[pallet::getter(fn outcomes)]
     pub type AuthorizedOutcomeReports<T: Config> =
         StorageMap<_, Twox64Concat, MarketIdOf<T>, AuthorityReport<BlockNumberFor<T>>, OptionQuery>;
+
+    /// Maps the market id to the block in which the market was created, as long as no authority
+    /// committee was set. Removed when the market is resolved or destroyed.
+    #[pallet::storage]
+    pub type CommitteeSetupBlocks<T: Config> =
+        StorageMap<_, Twox64Concat, MarketIdOf<T>, BlockNumberFor<T>, OptionQuery>;
+
+    /// Maps the market id to the authority committee chosen by the market creator. Removed when
+    /// the market is resolved or destroyed.
+    #[pallet::storage]
+    pub type AuthorityCommittees<T: Config> =
+        StorageMap<_, Twox64Concat, MarketIdOf<T>, AuthorityCommitteeOf<T>, OptionQuery>;
+
+    /// Maps the market id and the committee member to the outcome reported by the member.
+    ///
+    /// TWOX-NOTE: SAFE as `AccountId`s are crypto hashes anyway.
+    #[pallet::storage]
+    pub type CommitteeReports<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        MarketIdOf<T>,
+        Twox64Concat,
+        T::AccountId,
+        OutcomeReport,
+        OptionQuery,
+    >;
+
+    /// Maps the market id to the appeal against the report of the authority.
+    #[pallet::storage]
+    pub type AuthorityAppeals<T: Config> =
+        StorageMap<_, Twox64Concat, MarketIdOf<T>, AuthorityAppealOf<T>, OptionQuery>;
 }
 
 #[cfg(any(feature = "runtime-benchmarks", test))]
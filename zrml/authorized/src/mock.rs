@@ -30,8 +30,9 @@ use sp_runtime::{
 };
 use zeitgeist_primitives::{
     constants::mock::{
-        AuthorizedPalletId, BlockHashCount, CorrectionPeriod, ExistentialDeposit, MaxLocks,
-        MaxReserves, MinimumPeriod, BASE,
+        AuthorityAppealBond, AuthorityAppealPeriod, AuthorizedPalletId, BlockHashCount,
+        CorrectionPeriod, ExistentialDeposit, MaxCommitteeMembers, MaxLocks, MaxReserves,
+        MinimumPeriod, BASE,
     },
     traits::{DisputeResolutionApi, MarketOfDisputeResolutionApi},
     types::{AccountIdTest, Balance, BlockNumber, Hash, MarketId, Moment},
@@ -101,11 +102,14 @@ impl DisputeResolutionApi for MockResolution {
 }
 
 impl crate::Config for Runtime {
+    type AppealBond = AuthorityAppealBond;
+    type AppealPeriod = AuthorityAppealPeriod;
     type Currency = Balances;
     type RuntimeEvent = RuntimeEvent;
     type CorrectionPeriod = CorrectionPeriod;
     type DisputeResolution = MockResolution;
     type MarketCommons = MarketCommons;
+    type MaxCommitteeMembers = MaxCommitteeMembers;
     type PalletId = AuthorizedPalletId;
     type AuthorizedDisputeResolutionOrigin =
         EnsureSignedBy<AuthorizedDisputeResolutionUser, AccountIdTest>;
@@ -20,15 +20,25 @@
 
 use crate::{
     market_mock,
-    mock::{Authorized, AuthorizedDisputeResolutionUser, ExtBuilder, Runtime, RuntimeOrigin, BOB},
+    mock::{
+        Authorized, AuthorizedDisputeResolutionUser, Balances, ExtBuilder, Runtime, RuntimeEvent,
+        RuntimeOrigin, System, ALICE, BOB, CHARLIE,
+    },
     mock_storage::pallet as mock_storage,
-    AuthorizedOutcomeReports, Error,
+    types::{AuthorityAppeal, AuthorityCommittee},
+    AuthorityAppeals, AuthorityCommittees, AuthorizedOutcomeReports, AuthorizedPalletApi,
+    CommitteeReports, Error, Event, NegativeImbalanceOf,
+};
+use frame_support::{
+    assert_noop, assert_ok,
+    traits::{Get, Imbalance, NamedReservableCurrency},
 };
-use frame_support::{assert_noop, assert_ok};
 use sp_runtime::DispatchError;
 use zeitgeist_primitives::{
     traits::DisputeApi,
-    types::{AuthorityReport, MarketDisputeMechanism, MarketStatus, OutcomeReport},
+    types::{
+        AuthorityReport, GlobalDisputeItem, MarketDisputeMechanism, MarketStatus, OutcomeReport,
+    },
 };
 use zrml_market_commons::Markets;
 
@@ -260,3 +270,475 @@ fn get_auto_resolve_returns_none_without_market_storage() {
         assert_eq!(Authorized::get_auto_resolve(&0, &market).result, None,);
     });
 }
+
+fn set_committee(market_id: u128, members: Vec<u128>, threshold: u32) {
+    let mut market = market_mock::<Runtime>();
+    market.creator = ALICE;
+    market.status = MarketStatus::Active;
+    Markets::<Runtime>::insert(market_id, market);
+    Authorized::on_market_creation(&market_id);
+    assert_ok!(Authorized::set_authority_committee(
+        RuntimeOrigin::signed(ALICE),
+        market_id,
+        members,
+        threshold
+    ));
+    Markets::<Runtime>::mutate(market_id, |market| {
+        market.as_mut().unwrap().status = MarketStatus::Disputed;
+    });
+}
+
+#[test]
+fn set_authority_committee_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        System::set_block_number(1);
+        set_committee(0, vec![BOB, CHARLIE, ALICE], 2);
+        assert_eq!(
+            AuthorityCommittees::<Runtime>::get(0).unwrap(),
+            AuthorityCommittee {
+                members: vec![BOB, CHARLIE, ALICE].try_into().unwrap(),
+                threshold: 2
+            }
+        );
+        System::assert_last_event(RuntimeEvent::Authorized(Event::AuthorityCommitteeSet {
+            market_id: 0,
+            members: vec![BOB, CHARLIE, ALICE],
+            threshold: 2,
+        }));
+    });
+}
+
+#[test]
+fn set_authority_committee_fails_if_caller_is_not_market_creator() {
+    ExtBuilder::default().build().execute_with(|| {
+        let mut market = market_mock::<Runtime>();
+        market.creator = ALICE;
+        market.status = MarketStatus::Active;
+        Markets::<Runtime>::insert(0, market);
+        Authorized::on_market_creation(&0);
+        assert_noop!(
+            Authorized::set_authority_committee(RuntimeOrigin::signed(BOB), 0, vec![BOB], 1),
+            Error::<Runtime>::CallerNotMarketCreator
+        );
+    });
+}
+
+#[test]
+fn set_authority_committee_fails_after_the_creation_block() {
+    ExtBuilder::default().build().execute_with(|| {
+        System::set_block_number(1);
+        let mut market = market_mock::<Runtime>();
+        market.creator = ALICE;
+        market.status = MarketStatus::Active;
+        Markets::<Runtime>::insert(0, market);
+        Authorized::on_market_creation(&0);
+        System::set_block_number(2);
+        assert_noop!(
+            Authorized::set_authority_committee(RuntimeOrigin::signed(ALICE), 0, vec![BOB], 1),
+            Error::<Runtime>::CommitteeSetupClosed
+        );
+    });
+}
+
+#[test]
+fn set_authority_committee_fails_if_market_was_not_created_with_authorized() {
+    ExtBuilder::default().build().execute_with(|| {
+        let mut market = market_mock::<Runtime>();
+        market.creator = ALICE;
+        market.status = MarketStatus::Active;
+        Markets::<Runtime>::insert(0, market);
+        assert_noop!(
+            Authorized::set_authority_committee(RuntimeOrigin::signed(ALICE), 0, vec![BOB], 1),
+            Error::<Runtime>::CommitteeSetupClosed
+        );
+    });
+}
+
+#[test]
+fn set_authority_committee_fails_if_already_set() {
+    ExtBuilder::default().build().execute_with(|| {
+        let mut market = market_mock::<Runtime>();
+        market.creator = ALICE;
+        market.status = MarketStatus::Active;
+        Markets::<Runtime>::insert(0, market);
+        Authorized::on_market_creation(&0);
+        assert_ok!(Authorized::set_authority_committee(
+            RuntimeOrigin::signed(ALICE),
+            0,
+            vec![BOB],
+            1
+        ));
+        assert_noop!(
+            Authorized::set_authority_committee(RuntimeOrigin::signed(ALICE), 0, vec![CHARLIE], 1),
+            Error::<Runtime>::CommitteeAlreadySet
+        );
+    });
+}
+
+#[test]
+fn set_authority_committee_fails_on_invalid_committee() {
+    ExtBuilder::default().build().execute_with(|| {
+        let mut market = market_mock::<Runtime>();
+        market.creator = ALICE;
+        market.status = MarketStatus::Active;
+        Markets::<Runtime>::insert(0, market);
+        Authorized::on_market_creation(&0);
+        let cases = [
+            (vec![BOB, CHARLIE], 0, Error::<Runtime>::InvalidCommitteeThreshold),
+            (vec![BOB, CHARLIE], 3, Error::<Runtime>::InvalidCommitteeThreshold),
+            (vec![BOB, CHARLIE, BOB], 2, Error::<Runtime>::DuplicateCommitteeMember),
+            ((0..17).collect(), 2, Error::<Runtime>::TooManyCommitteeMembers),
+        ];
+        for (members, threshold, error) in cases {
+            assert_noop!(
+                Authorized::set_authority_committee(
+                    RuntimeOrigin::signed(ALICE),
+                    0,
+                    members,
+                    threshold
+                ),
+                error
+            );
+        }
+    });
+}
+
+#[test]
+fn authorize_market_outcome_requires_threshold_of_committee_reports() {
+    ExtBuilder::default().build().execute_with(|| {
+        System::set_block_number(1);
+        set_committee(0, vec![ALICE, BOB, CHARLIE], 2);
+
+        assert_ok!(Authorized::authorize_market_outcome(
+            RuntimeOrigin::signed(BOB),
+            0,
+            OutcomeReport::Scalar(1)
+        ));
+        System::assert_last_event(RuntimeEvent::Authorized(Event::CommitteeMemberReported {
+            market_id: 0,
+            member: BOB,
+            outcome: OutcomeReport::Scalar(1),
+        }));
+        assert_ok!(Authorized::authorize_market_outcome(
+            RuntimeOrigin::signed(CHARLIE),
+            0,
+            OutcomeReport::Scalar(2)
+        ));
+        assert_eq!(CommitteeReports::<Runtime>::get(0, BOB), Some(OutcomeReport::Scalar(1)));
+        assert_eq!(CommitteeReports::<Runtime>::get(0, CHARLIE), Some(OutcomeReport::Scalar(2)));
+        assert_eq!(AuthorizedOutcomeReports::<Runtime>::get(0), None);
+
+        assert_ok!(Authorized::authorize_market_outcome(
+            RuntimeOrigin::signed(ALICE),
+            0,
+            OutcomeReport::Scalar(2)
+        ));
+        let now = frame_system::Pallet::<Runtime>::block_number();
+        let resolve_at = now + <Runtime as crate::Config>::CorrectionPeriod::get();
+        assert_eq!(
+            AuthorizedOutcomeReports::<Runtime>::get(0).unwrap(),
+            AuthorityReport { outcome: OutcomeReport::Scalar(2), resolve_at }
+        );
+        System::assert_last_event(RuntimeEvent::Authorized(Event::AuthorityReported {
+            market_id: 0,
+            outcome: OutcomeReport::Scalar(2),
+        }));
+        assert_eq!(mock_storage::MarketIdsPerDisputeBlock::<Runtime>::get(resolve_at), vec![0]);
+    });
+}
+
+#[test]
+fn authorize_market_outcome_lets_committee_correct_the_outcome() {
+    ExtBuilder::default().build().execute_with(|| {
+        set_committee(0, vec![ALICE, BOB, CHARLIE], 2);
+
+        for member in [ALICE, BOB] {
+            assert_ok!(Authorized::authorize_market_outcome(
+                RuntimeOrigin::signed(member),
+                0,
+                OutcomeReport::Scalar(1)
+            ));
+        }
+        let now = frame_system::Pallet::<Runtime>::block_number();
+        let resolve_at = now + <Runtime as crate::Config>::CorrectionPeriod::get();
+
+        frame_system::Pallet::<Runtime>::set_block_number(now + 1);
+        for member in [BOB, CHARLIE] {
+            assert_ok!(Authorized::authorize_market_outcome(
+                RuntimeOrigin::signed(member),
+                0,
+                OutcomeReport::Scalar(3)
+            ));
+        }
+        assert_eq!(
+            AuthorizedOutcomeReports::<Runtime>::get(0).unwrap(),
+            AuthorityReport { outcome: OutcomeReport::Scalar(3), resolve_at }
+        );
+        assert_eq!(mock_storage::MarketIdsPerDisputeBlock::<Runtime>::get(resolve_at), vec![0]);
+    });
+}
+
+#[test]
+fn authorize_market_outcome_revokes_outcome_if_committee_withdraws_signatures() {
+    ExtBuilder::default().build().execute_with(|| {
+        System::set_block_number(1);
+        set_committee(0, vec![ALICE, BOB, CHARLIE], 2);
+
+        for member in [ALICE, BOB] {
+            assert_ok!(Authorized::authorize_market_outcome(
+                RuntimeOrigin::signed(member),
+                0,
+                OutcomeReport::Scalar(1)
+            ));
+        }
+        let now = frame_system::Pallet::<Runtime>::block_number();
+        let resolve_at = now + <Runtime as crate::Config>::CorrectionPeriod::get();
+        assert_eq!(mock_storage::MarketIdsPerDisputeBlock::<Runtime>::get(resolve_at), vec![0]);
+
+        assert_ok!(Authorized::authorize_market_outcome(
+            RuntimeOrigin::signed(BOB),
+            0,
+            OutcomeReport::Scalar(2)
+        ));
+        System::assert_last_event(RuntimeEvent::Authorized(Event::AuthorityReportRevoked {
+            market_id: 0,
+            outcome: OutcomeReport::Scalar(1),
+        }));
+        assert_eq!(AuthorizedOutcomeReports::<Runtime>::get(0), None);
+        assert!(mock_storage::MarketIdsPerDisputeBlock::<Runtime>::get(resolve_at).is_empty());
+
+        frame_system::Pallet::<Runtime>::set_block_number(now + 1);
+        assert_ok!(Authorized::authorize_market_outcome(
+            RuntimeOrigin::signed(CHARLIE),
+            0,
+            OutcomeReport::Scalar(2)
+        ));
+        let resolve_at = now + 1 + <Runtime as crate::Config>::CorrectionPeriod::get();
+        assert_eq!(
+            AuthorizedOutcomeReports::<Runtime>::get(0).unwrap(),
+            AuthorityReport { outcome: OutcomeReport::Scalar(2), resolve_at }
+        );
+    });
+}
+
+#[test]
+fn authorize_market_outcome_fails_if_sender_is_not_committee_member() {
+    ExtBuilder::default().build().execute_with(|| {
+        set_committee(0, vec![BOB, CHARLIE], 2);
+        assert_noop!(
+            Authorized::authorize_market_outcome(
+                RuntimeOrigin::signed(AuthorizedDisputeResolutionUser::get()),
+                0,
+                OutcomeReport::Scalar(1)
+            ),
+            Error::<Runtime>::NotCommitteeMember
+        );
+    });
+}
+
+#[test]
+fn on_resolution_removes_committee_and_its_reports() {
+    ExtBuilder::default().build().execute_with(|| {
+        set_committee(0, vec![ALICE, BOB], 1);
+        assert_ok!(Authorized::authorize_market_outcome(
+            RuntimeOrigin::signed(BOB),
+            0,
+            OutcomeReport::Scalar(2)
+        ));
+        let market = Markets::<Runtime>::get(0).unwrap();
+        assert_eq!(
+            Authorized::on_resolution(&0, &market).unwrap().result,
+            Some(OutcomeReport::Scalar(2))
+        );
+        assert!(!AuthorityCommittees::<Runtime>::contains_key(0));
+        assert_eq!(CommitteeReports::<Runtime>::iter_prefix(0).count(), 0);
+    });
+}
+
+fn appeal_report(market_id: u128, outcome: OutcomeReport) {
+    Markets::<Runtime>::insert(market_id, market_mock::<Runtime>());
+    assert_ok!(Authorized::authorize_market_outcome(
+        RuntimeOrigin::signed(AuthorizedDisputeResolutionUser::get()),
+        market_id,
+        outcome
+    ));
+    assert_ok!(Authorized::appeal_report(RuntimeOrigin::signed(BOB), market_id));
+}
+
+fn appeal_bond() -> u128 {
+    <Runtime as crate::Config>::AppealBond::get()
+}
+
+#[test]
+fn appeal_report_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        System::set_block_number(1);
+        let free_bob_before = Balances::free_balance(BOB);
+        appeal_report(0, OutcomeReport::Scalar(1));
+
+        let bond = appeal_bond();
+        assert_eq!(Balances::reserved_balance_named(&Authorized::reserve_id(), &BOB), bond);
+        assert_eq!(Balances::free_balance(BOB), free_bob_before - bond);
+        let resolve_at = 1 + <Runtime as crate::Config>::AppealPeriod::get();
+        assert_eq!(
+            AuthorityAppeals::<Runtime>::get(0).unwrap(),
+            AuthorityAppeal { appellant: BOB, bond, outcome: OutcomeReport::Scalar(1), resolve_at }
+        );
+        let correction_period_ends_at = 1 + <Runtime as crate::Config>::CorrectionPeriod::get();
+        assert!(mock_storage::MarketIdsPerDisputeBlock::<Runtime>::get(correction_period_ends_at)
+            .is_empty());
+        assert_eq!(mock_storage::MarketIdsPerDisputeBlock::<Runtime>::get(resolve_at), vec![0]);
+        System::assert_last_event(RuntimeEvent::Authorized(Event::ReportAppealed {
+            market_id: 0,
+            appellant: BOB,
+            outcome: OutcomeReport::Scalar(1),
+            bond,
+        }));
+    });
+}
+
+#[test]
+fn appeal_report_fails_if_authority_did_not_report() {
+    ExtBuilder::default().build().execute_with(|| {
+        Markets::<Runtime>::insert(0, market_mock::<Runtime>());
+        assert_noop!(
+            Authorized::appeal_report(RuntimeOrigin::signed(BOB), 0),
+            Error::<Runtime>::NoAuthorityReport
+        );
+    });
+}
+
+#[test]
+fn appeal_report_fails_if_report_was_already_appealed() {
+    ExtBuilder::default().build().execute_with(|| {
+        appeal_report(0, OutcomeReport::Scalar(1));
+        assert_noop!(
+            Authorized::appeal_report(RuntimeOrigin::signed(CHARLIE), 0),
+            Error::<Runtime>::ReportAppealed
+        );
+    });
+}
+
+#[test]
+fn authorize_market_outcome_fails_if_report_was_appealed() {
+    ExtBuilder::default().build().execute_with(|| {
+        appeal_report(0, OutcomeReport::Scalar(1));
+        assert_noop!(
+            Authorized::authorize_market_outcome(
+                RuntimeOrigin::signed(AuthorizedDisputeResolutionUser::get()),
+                0,
+                OutcomeReport::Scalar(2)
+            ),
+            Error::<Runtime>::ReportAppealed
+        );
+    });
+}
+
+#[test]
+fn appealed_report_fails_the_dispute_mechanism() {
+    ExtBuilder::default().build().execute_with(|| {
+        let mut market = market_mock::<Runtime>();
+        market.creator = CHARLIE;
+        Markets::<Runtime>::insert(0, market.clone());
+        assert_ok!(Authorized::authorize_market_outcome(
+            RuntimeOrigin::signed(AuthorizedDisputeResolutionUser::get()),
+            0,
+            OutcomeReport::Scalar(1)
+        ));
+        assert!(!Authorized::has_failed(&0, &market).unwrap().result);
+        assert!(Authorized::on_global_dispute(&0, &market).unwrap().result.is_empty());
+
+        assert_ok!(Authorized::appeal_report(RuntimeOrigin::signed(BOB), 0));
+        assert!(Authorized::has_failed(&0, &market).unwrap().result);
+        let now = frame_system::Pallet::<Runtime>::block_number();
+        assert_eq!(
+            Authorized::get_auto_resolve(&0, &market).result,
+            Some(now + <Runtime as crate::Config>::AppealPeriod::get())
+        );
+        assert_eq!(
+            Authorized::on_global_dispute(&0, &market).unwrap().result,
+            vec![GlobalDisputeItem {
+                outcome: OutcomeReport::Scalar(1),
+                owner: Authorized::authority_account(),
+                initial_vote_amount: 0,
+            }]
+        );
+    });
+}
+
+#[test]
+fn appealed_market_resolves_to_appealed_report_without_global_dispute() {
+    ExtBuilder::default().build().execute_with(|| {
+        let free_bob_before = Balances::free_balance(BOB);
+        appeal_report(0, OutcomeReport::Scalar(1));
+        let market = Markets::<Runtime>::get(0).unwrap();
+        assert_eq!(
+            Authorized::on_resolution(&0, &market).unwrap().result,
+            Some(OutcomeReport::Scalar(1))
+        );
+        let imbalance = Authorized::exchange(
+            &0,
+            &market,
+            &OutcomeReport::Scalar(1),
+            NegativeImbalanceOf::<Runtime>::zero(),
+        )
+        .unwrap()
+        .result;
+        assert_eq!(imbalance.peek(), appeal_bond());
+        assert_eq!(Balances::free_balance(BOB), free_bob_before - appeal_bond());
+    });
+}
+
+#[test]
+fn exchange_returns_appeal_bond_if_resolved_outcome_differs() {
+    ExtBuilder::default().build().execute_with(|| {
+        let free_bob_before = Balances::free_balance(BOB);
+        appeal_report(0, OutcomeReport::Scalar(1));
+        let market = Markets::<Runtime>::get(0).unwrap();
+        let imbalance = Authorized::exchange(
+            &0,
+            &market,
+            &OutcomeReport::Scalar(2),
+            NegativeImbalanceOf::<Runtime>::zero(),
+        )
+        .unwrap()
+        .result;
+        assert_eq!(imbalance.peek(), 0);
+        assert_eq!(Balances::free_balance(BOB), free_bob_before);
+        assert!(!AuthorityAppeals::<Runtime>::contains_key(0));
+        assert!(!AuthorizedOutcomeReports::<Runtime>::contains_key(0));
+    });
+}
+
+#[test]
+fn exchange_slashes_appeal_bond_if_appealed_report_is_confirmed() {
+    ExtBuilder::default().build().execute_with(|| {
+        let free_bob_before = Balances::free_balance(BOB);
+        appeal_report(0, OutcomeReport::Scalar(1));
+        let market = Markets::<Runtime>::get(0).unwrap();
+        let imbalance = Authorized::exchange(
+            &0,
+            &market,
+            &OutcomeReport::Scalar(1),
+            NegativeImbalanceOf::<Runtime>::zero(),
+        )
+        .unwrap()
+        .result;
+        assert_eq!(imbalance.peek(), appeal_bond());
+        assert_eq!(Balances::free_balance(BOB), free_bob_before - appeal_bond());
+        assert_eq!(Balances::reserved_balance_named(&Authorized::reserve_id(), &BOB), 0);
+        assert!(!AuthorityAppeals::<Runtime>::contains_key(0));
+    });
+}
+
+#[test]
+fn clear_returns_appeal_bond() {
+    ExtBuilder::default().build().execute_with(|| {
+        let free_bob_before = Balances::free_balance(BOB);
+        appeal_report(0, OutcomeReport::Scalar(1));
+        let market = Markets::<Runtime>::get(0).unwrap();
+        assert_ok!(Authorized::clear(&0, &market));
+        assert_eq!(Balances::free_balance(BOB), free_bob_before);
+        assert!(!AuthorityAppeals::<Runtime>::contains_key(0));
+    });
+}
@@ -0,0 +1,44 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use frame_support::pallet_prelude::{Decode, Encode, MaxEncodedLen, TypeInfo};
+use sp_runtime::RuntimeDebug;
+use zeitgeist_primitives::types::OutcomeReport;
+
+/// An M-of-N committee which resolves a disputed market instead of
+/// `AuthorizedDisputeResolutionOrigin`.
+#[derive(Clone, Decode, Encode, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct AuthorityCommittee<Members> {
+    /// The accounts which are allowed to report the outcome.
+    pub members: Members,
+    /// The number of members which need to report the same outcome for it to be accepted.
+    pub threshold: u32,
+}
+
+/// An appeal against the report of the authority, which escalates the market to a global dispute.
+#[derive(Clone, Decode, Encode, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct AuthorityAppeal<AccountId, Balance, BlockNumber> {
+    /// The account which appealed and deposited the bond.
+    pub appellant: AccountId,
+    /// The bond which is returned if the final outcome differs from the appealed report.
+    pub bond: Balance,
+    /// The appealed outcome reported by the authority.
+    pub outcome: OutcomeReport,
+    /// The block at which the market resolves to the appealed outcome if no global dispute
+    /// was started until then.
+    pub resolve_at: BlockNumber,
+}
@@ -60,6 +60,9 @@ pub trait WeightInfoZeitgeist {
     fn has_failed_weight() -> Weight;
     fn on_global_dispute_weight() -> Weight;
     fn clear_weight() -> Weight;
+    fn authorize_market_outcome_committee_report(m: u32, r: u32) -> Weight;
+    fn set_authority_committee(m: u32) -> Weight;
+    fn appeal_report(m: u32) -> Weight;
 }
 
 /// Weight functions for zrml_authorized (automatically generated)
@@ -105,52 +108,133 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
     }
     /// Storage: `Authorized::AuthorizedOutcomeReports` (r:1 w:1)
     /// Proof: `Authorized::AuthorizedOutcomeReports` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+    /// Storage: `Authorized::AuthorityCommittees` (r:0 w:1)
+    /// Proof: `Authorized::AuthorityCommittees` (`max_values`: None, `max_size`: Some(536), added: 3011, mode: `MaxEncodedLen`)
+    /// Storage: `Authorized::CommitteeReports` (r:16 w:16)
+    /// Proof: `Authorized::CommitteeReports` (`max_values`: None, `max_size`: Some(90), added: 2565, mode: `MaxEncodedLen`)
     fn on_resolution_weight() -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `217`
-        //  Estimated: `3514`
-        // Minimum execution time: 10_050 nanoseconds.
-        Weight::from_parts(10_950_000, 3514)
-            .saturating_add(T::DbWeight::get().reads(1))
-            .saturating_add(T::DbWeight::get().writes(1))
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_authorized` to replace it.
+        Weight::from_parts(66_120_000, 42_555)
+            .saturating_add(T::DbWeight::get().reads(17))
+            .saturating_add(T::DbWeight::get().writes(18))
     }
+    /// Storage: `Authorized::AuthorityAppeals` (r:1 w:1)
+    /// Proof: `Authorized::AuthorityAppeals` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Reserves` (r:1 w:1)
+    /// Proof: `Balances::Reserves` (`max_values`: None, `max_size`: Some(1249), added: 3724, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+    /// Storage: `Authorized::AuthorizedOutcomeReports` (r:0 w:1)
+    /// Proof: `Authorized::AuthorizedOutcomeReports` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+    /// Storage: `Authorized::AuthorityCommittees` (r:0 w:1)
+    /// Proof: `Authorized::AuthorityCommittees` (`max_values`: None, `max_size`: Some(536), added: 3011, mode: `MaxEncodedLen`)
+    /// Storage: `Authorized::CommitteeReports` (r:16 w:16)
+    /// Proof: `Authorized::CommitteeReports` (`max_values`: None, `max_size`: Some(90), added: 2565, mode: `MaxEncodedLen`)
     fn exchange_weight() -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `0`
-        //  Estimated: `0`
-        // Minimum execution time: 250 nanoseconds.
-        Weight::from_parts(290_000, 0)
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_authorized` to replace it.
+        Weight::from_parts(93_410_000, 42_555)
+            .saturating_add(T::DbWeight::get().reads(19))
+            .saturating_add(T::DbWeight::get().writes(21))
     }
+    /// Storage: `Authorized::AuthorityAppeals` (r:1 w:0)
+    /// Proof: `Authorized::AuthorityAppeals` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
     /// Storage: `Authorized::AuthorizedOutcomeReports` (r:1 w:0)
     /// Proof: `Authorized::AuthorizedOutcomeReports` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
     fn get_auto_resolve_weight() -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `217`
-        //  Estimated: `3514`
-        // Minimum execution time: 9_440 nanoseconds.
-        Weight::from_parts(10_130_000, 3514).saturating_add(T::DbWeight::get().reads(1))
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_authorized` to replace it.
+        Weight::from_parts(12_920_000, 3555).saturating_add(T::DbWeight::get().reads(2))
     }
+    /// Storage: `Authorized::AuthorityAppeals` (r:1 w:0)
+    /// Proof: `Authorized::AuthorityAppeals` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
     fn has_failed_weight() -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `0`
-        //  Estimated: `0`
-        // Minimum execution time: 240 nanoseconds.
-        Weight::from_parts(300_000, 0)
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_authorized` to replace it.
+        Weight::from_parts(8_540_000, 3555).saturating_add(T::DbWeight::get().reads(1))
     }
+    /// Storage: `Authorized::AuthorityAppeals` (r:1 w:0)
+    /// Proof: `Authorized::AuthorityAppeals` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
     fn on_global_dispute_weight() -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `0`
-        //  Estimated: `0`
-        // Minimum execution time: 260 nanoseconds.
-        Weight::from_parts(310_000, 0)
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_authorized` to replace it.
+        Weight::from_parts(8_900_000, 3555).saturating_add(T::DbWeight::get().reads(1))
     }
     /// Storage: `Authorized::AuthorizedOutcomeReports` (r:0 w:1)
     /// Proof: `Authorized::AuthorizedOutcomeReports` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+    /// Storage: `Authorized::AuthorityCommittees` (r:0 w:1)
+    /// Proof: `Authorized::AuthorityCommittees` (`max_values`: None, `max_size`: Some(536), added: 3011, mode: `MaxEncodedLen`)
+    /// Storage: `Authorized::CommitteeReports` (r:16 w:16)
+    /// Proof: `Authorized::CommitteeReports` (`max_values`: None, `max_size`: Some(90), added: 2565, mode: `MaxEncodedLen`)
+    /// Storage: `Authorized::AuthorityAppeals` (r:1 w:1)
+    /// Proof: `Authorized::AuthorityAppeals` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Reserves` (r:1 w:1)
+    /// Proof: `Balances::Reserves` (`max_values`: None, `max_size`: Some(1249), added: 3724, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
     fn clear_weight() -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `0`
-        //  Estimated: `0`
-        // Minimum execution time: 2_060 nanoseconds.
-        Weight::from_parts(2_180_000, 0).saturating_add(T::DbWeight::get().writes(1))
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_authorized` to replace it.
+        Weight::from_parts(85_020_000, 42_555)
+            .saturating_add(T::DbWeight::get().reads(19))
+            .saturating_add(T::DbWeight::get().writes(21))
+    }
+    /// Storage: `Authorized::AuthorityCommittees` (r:1 w:0)
+    /// Proof: `Authorized::AuthorityCommittees` (`max_values`: None, `max_size`: Some(536), added: 3011, mode: `MaxEncodedLen`)
+    /// Storage: `MarketCommons::Markets` (r:1 w:0)
+    /// Proof: `MarketCommons::Markets` (`max_values`: None, `max_size`: Some(708), added: 3183, mode: `MaxEncodedLen`)
+    /// Storage: `Authorized::CommitteeReports` (r:17 w:1)
+    /// Proof: `Authorized::CommitteeReports` (`max_values`: None, `max_size`: Some(90), added: 2565, mode: `MaxEncodedLen`)
+    /// Storage: `Authorized::AuthorizedOutcomeReports` (r:1 w:1)
+    /// Proof: `Authorized::AuthorizedOutcomeReports` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+    /// Storage: `PredictionMarkets::MarketIdsPerDisputeBlock` (r:1 w:1)
+    /// Proof: `PredictionMarkets::MarketIdsPerDisputeBlock` (`max_values`: None, `max_size`: Some(1042), added: 3517, mode: `MaxEncodedLen`)
+    /// The range of component `m` is `[1, 16]`.
+    /// The range of component `r` is `[1, 63]`.
+    fn authorize_market_outcome_committee_report(m: u32, r: u32) -> Weight {
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_authorized` to replace it.
+        Weight::from_parts(49_842_019, 4507)
+            .saturating_add(Weight::from_parts(3_371_508, 0).saturating_mul(m.into()))
+            .saturating_add(Weight::from_parts(140_218, 0).saturating_mul(r.into()))
+            .saturating_add(T::DbWeight::get().reads(5))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(m.into())))
+            .saturating_add(T::DbWeight::get().writes(3))
+            .saturating_add(Weight::from_parts(0, 2565).saturating_mul(m.into()))
+    }
+    /// Storage: `MarketCommons::Markets` (r:1 w:0)
+    /// Proof: `MarketCommons::Markets` (`max_values`: None, `max_size`: Some(708), added: 3183, mode: `MaxEncodedLen`)
+    /// Storage: `Authorized::AuthorityCommittees` (r:1 w:1)
+    /// Proof: `Authorized::AuthorityCommittees` (`max_values`: None, `max_size`: Some(536), added: 3011, mode: `MaxEncodedLen`)
+    /// Storage: `Authorized::CommitteeSetupBlocks` (r:1 w:1)
+    /// Proof: `Authorized::CommitteeSetupBlocks` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+    /// The range of component `m` is `[1, 16]`.
+    fn set_authority_committee(m: u32) -> Weight {
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_authorized` to replace it.
+        Weight::from_parts(33_416_284, 4173)
+            .saturating_add(Weight::from_parts(182_655, 0).saturating_mul(m.into()))
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+    /// Storage: `MarketCommons::Markets` (r:1 w:0)
+    /// Proof: `MarketCommons::Markets` (`max_values`: None, `max_size`: Some(708), added: 3183, mode: `MaxEncodedLen`)
+    /// Storage: `Authorized::AuthorityAppeals` (r:1 w:1)
+    /// Proof: `Authorized::AuthorityAppeals` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+    /// Storage: `Authorized::AuthorizedOutcomeReports` (r:1 w:0)
+    /// Proof: `Authorized::AuthorizedOutcomeReports` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Reserves` (r:1 w:1)
+    /// Proof: `Balances::Reserves` (`max_values`: None, `max_size`: Some(1249), added: 3724, mode: `MaxEncodedLen`)
+    /// Storage: `PredictionMarkets::MarketIdsPerDisputeBlock` (r:2 w:2)
+    /// Proof: `PredictionMarkets::MarketIdsPerDisputeBlock` (`max_values`: None, `max_size`: Some(1042), added: 3517, mode: `MaxEncodedLen`)
+    /// The range of component `m` is `[1, 64]`.
+    fn appeal_report(m: u32) -> Weight {
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_authorized` to replace it.
+        Weight::from_parts(61_087_412, 8231)
+            .saturating_add(Weight::from_parts(254_788, 0).saturating_mul(m.into()))
+            .saturating_add(T::DbWeight::get().reads(6))
+            .saturating_add(T::DbWeight::get().writes(4))
     }
 }
@@ -31,6 +31,7 @@ use zrml_combinatorial_tokens::{
         ext_builder::ExtBuilder,
         runtime::{CombinatorialTokens, Runtime, RuntimeOrigin},
     },
+    types::Partition,
     AccountIdOf, BalanceOf, CombinatorialIdOf, Config, FuelOf, MarketIdOf,
 };
 
@@ -127,7 +128,7 @@ fuzz_target!(|params: MergePositionFuzzParams| {
             RuntimeOrigin::signed(params.account_id),
             params.parent_collection_id,
             params.market_id,
-            params.partition,
+            Partition::IndexSets(params.partition),
             params.amount,
             params.fuel,
         );
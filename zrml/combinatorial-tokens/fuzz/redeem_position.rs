@@ -34,6 +34,7 @@ use zrml_combinatorial_tokens::{
         types::MockPayout,
     },
     traits::CombinatorialIdManager,
+    types::IndexSet,
     AccountIdOf, BalanceOf, CombinatorialIdOf, Config, FuelOf, MarketIdOf,
 };
 
@@ -127,7 +128,7 @@ fuzz_target!(|params: RedeemPositionFuzzParams| {
             RuntimeOrigin::signed(params.account_id),
             params.parent_collection_id,
             params.market_id,
-            params.index_set,
+            IndexSet::Outcomes(params.index_set),
             params.fuel,
         );
     });
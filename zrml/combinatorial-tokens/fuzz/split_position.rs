@@ -32,6 +32,7 @@ use zrml_combinatorial_tokens::{
         runtime::{CombinatorialTokens, Runtime, RuntimeOrigin},
     },
     traits::CombinatorialIdManager,
+    types::Partition,
     AccountIdOf, BalanceOf, CombinatorialIdOf, Config, FuelOf, MarketIdOf,
 };
 
@@ -108,7 +109,7 @@ fuzz_target!(|params: SplitPositionFuzzParams| {
             RuntimeOrigin::signed(params.account_id),
             params.parent_collection_id,
             params.market_id,
-            params.partition,
+            Partition::IndexSets(params.partition),
             params.amount,
             params.fuel,
         );
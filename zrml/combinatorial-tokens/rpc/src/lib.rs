@@ -0,0 +1,252 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+#![doc = include_str!("../README.md")]
+
+extern crate alloc;
+
+use alloc::sync::Arc;
+use jsonrpsee::{
+    core::{async_trait, RpcResult},
+    proc_macros::rpc,
+    types::error::ErrorObject,
+};
+use parity_scale_codec::Codec;
+use serde::{de::DeserializeOwned, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+use zeitgeist_primitives::types::{Asset, CombinatorialId, ScalarPosition};
+use zrml_combinatorial_tokens::types::{Fuel, PositionValue};
+
+pub use zrml_combinatorial_tokens_runtime_api::CombinatorialTokensApi as CombinatorialTokensRuntimeApi;
+
+#[rpc(client, server)]
+pub trait CombinatorialTokensApi<BlockHash, AccountId, Balance, MarketId> {
+    /// Returns the collection ID obtained when splitting `parent_collection_id` over the market
+    /// given by `market_id` and the `index_set`. The `fuel` specifies the maximum amount of
+    /// work done by the cryptographic id manager.
+    #[method(name = "combinatorialTokens_collectionIdFromParentCollection")]
+    async fn collection_id_from_parent_collection(
+        &self,
+        parent_collection_id: Option<CombinatorialId>,
+        market_id: MarketId,
+        index_set: Vec<bool>,
+        fuel: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<CombinatorialId>;
+
+    /// Returns the position obtained when splitting `parent_collection_id` over the market given
+    /// by `market_id` and the `index_set`. The `fuel` specifies the maximum amount of work done
+    /// by the cryptographic id manager.
+    #[method(name = "combinatorialTokens_positionFromParentCollection")]
+    async fn position_from_parent_collection(
+        &self,
+        parent_collection_id: Option<CombinatorialId>,
+        market_id: MarketId,
+        index_set: Vec<bool>,
+        fuel: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Asset<MarketId>>;
+
+    /// Returns the position obtained when splitting `parent_collection_id` over the scalar market
+    /// given by `market_id` and selecting `scalar_position`. The `fuel` specifies the maximum
+    /// amount of work done by the cryptographic id manager.
+    #[method(name = "combinatorialTokens_scalarPositionFromParentCollection")]
+    async fn scalar_position_from_parent_collection(
+        &self,
+        parent_collection_id: Option<CombinatorialId>,
+        market_id: MarketId,
+        scalar_position: ScalarPosition,
+        fuel: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Asset<MarketId>>;
+
+    /// Returns the combinatorial positions held by `who` together with their redeemable value.
+    /// Positions which aren't recorded in `Positions` are omitted.
+    #[method(name = "combinatorialTokens_positionsOf")]
+    async fn positions_of(
+        &self,
+        who: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<PositionValue<MarketId, Balance>>>;
+}
+
+/// A struct that implements the [`CombinatorialTokensApi`].
+pub struct CombinatorialTokens<C, B> {
+    client: Arc<C>,
+    _marker: core::marker::PhantomData<B>,
+}
+
+impl<C, B> CombinatorialTokens<C, B> {
+    /// Create a new `CombinatorialTokens` with the given reference to the client.
+    pub fn new(client: Arc<C>) -> Self {
+        CombinatorialTokens { client, _marker: Default::default() }
+    }
+}
+
+pub enum Error {
+    /// The call to the runtime failed.
+    RuntimeError,
+
+    /// The runtime returned an error.
+    DispatchError,
+}
+
+impl From<Error> for i32 {
+    fn from(e: Error) -> i32 {
+        match e {
+            Error::RuntimeError => 1,
+            Error::DispatchError => 2,
+        }
+    }
+}
+
+#[async_trait]
+impl<C, Block, AccountId, Balance, MarketId>
+    CombinatorialTokensApiServer<<Block as BlockT>::Hash, AccountId, Balance, MarketId>
+    for CombinatorialTokens<C, Block>
+where
+    Block: BlockT,
+    C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+    C::Api: CombinatorialTokensRuntimeApi<Block, AccountId, Balance, MarketId>,
+    AccountId: Codec + DeserializeOwned + Send + 'static,
+    Balance: Codec + Serialize + Send + 'static,
+    MarketId: Codec + DeserializeOwned + Serialize + Send + 'static,
+{
+    async fn collection_id_from_parent_collection(
+        &self,
+        parent_collection_id: Option<CombinatorialId>,
+        market_id: MarketId,
+        index_set: Vec<bool>,
+        fuel: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<CombinatorialId> {
+        let api = self.client.runtime_api();
+        let hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        let res = api
+            .collection_id_from_parent_collection(
+                hash,
+                parent_collection_id,
+                market_id,
+                index_set,
+                Fuel::new(fuel, false),
+            )
+            .map_err(|e| {
+                ErrorObject::owned(
+                    Error::RuntimeError.into(),
+                    "Unable to get collection identifier.",
+                    Some(e.to_string()),
+                )
+            })?
+            .map_err(|e| {
+                ErrorObject::owned(
+                    Error::DispatchError.into(),
+                    "Unable to calculate collection identifier.",
+                    Some(format!("{:?}", e)),
+                )
+            })?;
+        Ok(res)
+    }
+
+    async fn position_from_parent_collection(
+        &self,
+        parent_collection_id: Option<CombinatorialId>,
+        market_id: MarketId,
+        index_set: Vec<bool>,
+        fuel: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Asset<MarketId>> {
+        let api = self.client.runtime_api();
+        let hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        let res = api
+            .position_from_parent_collection(
+                hash,
+                parent_collection_id,
+                market_id,
+                index_set,
+                Fuel::new(fuel, false),
+            )
+            .map_err(|e| {
+                ErrorObject::owned(
+                    Error::RuntimeError.into(),
+                    "Unable to get position.",
+                    Some(e.to_string()),
+                )
+            })?
+            .map_err(|e| {
+                ErrorObject::owned(
+                    Error::DispatchError.into(),
+                    "Unable to calculate position.",
+                    Some(format!("{:?}", e)),
+                )
+            })?;
+        Ok(res)
+    }
+
+    async fn scalar_position_from_parent_collection(
+        &self,
+        parent_collection_id: Option<CombinatorialId>,
+        market_id: MarketId,
+        scalar_position: ScalarPosition,
+        fuel: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Asset<MarketId>> {
+        let api = self.client.runtime_api();
+        let hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        let res = api
+            .scalar_position_from_parent_collection(
+                hash,
+                parent_collection_id,
+                market_id,
+                scalar_position,
+                Fuel::new(fuel, false),
+            )
+            .map_err(|e| {
+                ErrorObject::owned(
+                    Error::RuntimeError.into(),
+                    "Unable to get position.",
+                    Some(e.to_string()),
+                )
+            })?
+            .map_err(|e| {
+                ErrorObject::owned(
+                    Error::DispatchError.into(),
+                    "Unable to calculate position.",
+                    Some(format!("{:?}", e)),
+                )
+            })?;
+        Ok(res)
+    }
+
+    async fn positions_of(
+        &self,
+        who: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<PositionValue<MarketId, Balance>>> {
+        let api = self.client.runtime_api();
+        let hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        let res = api.positions_of(hash, who).map_err(|e| {
+            ErrorObject::owned(
+                Error::RuntimeError.into(),
+                "Unable to get positions.",
+                Some(e.to_string()),
+            )
+        })?;
+        Ok(res)
+    }
+}
@@ -0,0 +1,68 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+#![doc = include_str!("../README.md")]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use parity_scale_codec::Codec;
+use sp_runtime::DispatchError;
+use zeitgeist_primitives::types::{Asset, CombinatorialId, ScalarPosition};
+use zrml_combinatorial_tokens::types::{Fuel, PositionValue};
+
+sp_api::decl_runtime_apis! {
+    pub trait CombinatorialTokensApi<AccountId, Balance, MarketId> where
+        AccountId: Codec,
+        Balance: Codec,
+        MarketId: Codec,
+    {
+        /// Returns the collection ID obtained when splitting `parent_collection_id` over the
+        /// market given by `market_id` and the `index_set`.
+        fn collection_id_from_parent_collection(
+            parent_collection_id: Option<CombinatorialId>,
+            market_id: MarketId,
+            index_set: Vec<bool>,
+            fuel: Fuel,
+        ) -> Result<CombinatorialId, DispatchError>;
+
+        /// Returns the position obtained when splitting `parent_collection_id` over the market
+        /// given by `market_id` and the `index_set`.
+        fn position_from_parent_collection(
+            parent_collection_id: Option<CombinatorialId>,
+            market_id: MarketId,
+            index_set: Vec<bool>,
+            fuel: Fuel,
+        ) -> Result<Asset<MarketId>, DispatchError>;
+
+        /// Returns the position obtained when splitting `parent_collection_id` over the scalar
+        /// market given by `market_id` and selecting `scalar_position`.
+        fn scalar_position_from_parent_collection(
+            parent_collection_id: Option<CombinatorialId>,
+            market_id: MarketId,
+            scalar_position: ScalarPosition,
+            fuel: Fuel,
+        ) -> Result<Asset<MarketId>, DispatchError>;
+
+        /// Returns the combinatorial positions held by `who` together with their redeemable
+        /// value; positions of scalar markets are labeled with their leg. Positions created before the `Positions` storage map was introduced aren't
+        /// recorded and are omitted; see the README of `zrml-combinatorial-tokens` for how to
+        /// derive them from the event history.
+        fn positions_of(who: AccountId) -> Vec<PositionValue<MarketId, Balance>>;
+    }
+}
@@ -17,13 +17,19 @@
 
 #![cfg(feature = "runtime-benchmarks")]
 
-use crate::{BalanceOf, Call, Config, Event, MarketIdOf, Pallet};
+use crate::{
+    types::{IndexSet, Partition, PositionInfo},
+    BalanceOf, Call, Config, Event, LegacyConversions, MarketIdOf, Pallet, Positions,
+};
 use alloc::{vec, vec::Vec};
 use frame_benchmarking::v2::*;
-use frame_support::dispatch::RawOrigin;
+use frame_support::{dispatch::RawOrigin, BoundedVec};
 use frame_system::Pallet as System;
 use orml_traits::MultiCurrency;
-use sp_runtime::{traits::Zero, Perbill};
+use sp_runtime::{
+    traits::{Convert, Zero},
+    Perbill,
+};
 use zeitgeist_primitives::{
     math::fixed::{BaseProvider, ZeitgeistBase},
     traits::{CombinatorialTokensBenchmarkHelper, CombinatorialTokensFuel, MarketCommonsPalletApi},
@@ -91,7 +97,7 @@ mod benchmarks {
             RawOrigin::Signed(alice.clone()),
             parent_collection_id,
             market_id,
-            partition.clone(),
+            Partition::IndexSets(partition.clone()),
             amount,
             T::Fuel::from_total(total),
         );
@@ -167,7 +173,7 @@ mod benchmarks {
             RawOrigin::Signed(alice.clone()),
             Some(cid_01),
             child_market_id,
-            partition.clone(),
+            Partition::IndexSets(partition.clone()),
             amount,
             T::Fuel::from_total(total),
         );
@@ -243,7 +249,7 @@ mod benchmarks {
             RawOrigin::Signed(alice.clone()),
             parent_collection_id,
             market_id,
-            partition.clone(),
+            Partition::IndexSets(partition.clone()),
             amount,
             T::Fuel::from_total(total),
         );
@@ -324,7 +330,7 @@ mod benchmarks {
             RawOrigin::Signed(alice.clone()),
             parent_collection_id,
             market_id,
-            partition.clone(),
+            Partition::IndexSets(partition.clone()),
             amount,
             T::Fuel::from_total(total),
         );
@@ -395,7 +401,7 @@ mod benchmarks {
             RawOrigin::Signed(alice.clone()),
             Some(cid_01),
             child_market_id,
-            partition.clone(),
+            Partition::IndexSets(partition.clone()),
             amount,
             T::Fuel::from_total(total),
         );
@@ -456,7 +462,7 @@ mod benchmarks {
             RawOrigin::Signed(alice.clone()),
             parent_collection_id,
             market_id,
-            partition.clone(),
+            Partition::IndexSets(partition.clone()),
             amount,
             T::Fuel::from_total(total),
         );
@@ -513,12 +519,20 @@ mod benchmarks {
         T::MultiCurrency::deposit(position, &alice, amount).unwrap();
         T::MultiCurrency::deposit(Asset::Ztg, &Pallet::<T>::account_id(), amount).unwrap();
 
+        // Worst case: Collateral backing converted legacy outcome tokens which don't form
+        // complete sets needs to be settled.
+        let mut conversions = vec![amount; asset_count as usize];
+        *conversions.last_mut().unwrap() = Zero::zero();
+        LegacyConversions::<T>::insert(market_id, BoundedVec::truncate_from(conversions));
+        let market_account = T::LegacyMarketAccount::convert(market_id);
+        T::MultiCurrency::deposit(Asset::Ztg, &market_account, amount).unwrap();
+
         #[extrinsic_call]
         redeem_position(
             RawOrigin::Signed(alice.clone()),
             parent_collection_id,
             market_id,
-            index_set.clone(),
+            IndexSet::Outcomes(index_set.clone()),
             T::Fuel::from_total(total),
         );
 
@@ -577,7 +591,7 @@ mod benchmarks {
             RawOrigin::Signed(alice.clone()),
             Some(cid_01),
             child_market_id,
-            index_set.clone(),
+            IndexSet::Outcomes(index_set.clone()),
             T::Fuel::from_total(total),
         );
 
@@ -594,6 +608,145 @@ mod benchmarks {
         System::<T>::assert_last_event(expected_event.into());
     }
 
+    #[benchmark]
+    fn redeem_all(n: Linear<1, 16>) {
+        let alice: T::AccountId = whitelisted_caller();
+
+        // Create a chain of `n` nested positions, each of which is the first outcome of a binary
+        // market over the previous one. Redeeming the innermost position walks up the entire
+        // chain.
+        let index_set = vec![true, false];
+        let mut parent_collection_id = None;
+        let mut assets_in = vec![];
+        let mut assets_out = vec![];
+        let mut position = Asset::Ztg;
+        let amount = ZeitgeistBase::get().unwrap();
+        for _ in 0..n {
+            let market_id = create_market::<T>(alice.clone(), 2);
+            let payout_vector = create_payout_vector::<T>(2);
+            T::BenchmarkHelper::setup_payout_vector(market_id, Some(payout_vector)).unwrap();
+
+            let collection_id = Pallet::<T>::collection_id_from_parent_collection(
+                parent_collection_id,
+                market_id,
+                index_set.clone(),
+                T::Fuel::from_total(32),
+            )
+            .unwrap();
+            let child = Pallet::<T>::position_from_collection_id(market_id, collection_id).unwrap();
+            let Asset::CombinatorialToken(child_id) = child else { unreachable!() };
+            Positions::<T>::insert(
+                child_id,
+                PositionInfo::new(parent_collection_id, market_id, index_set.clone()).unwrap(),
+            );
+
+            assets_in.insert(0, (child, amount));
+            assets_out.insert(0, (position, amount));
+            parent_collection_id = Some(collection_id);
+            position = child;
+        }
+        let Asset::CombinatorialToken(position_id) = position else { unreachable!() };
+        T::MultiCurrency::deposit(position, &alice, amount).unwrap();
+        T::MultiCurrency::deposit(Asset::Ztg, &Pallet::<T>::account_id(), amount).unwrap();
+
+        #[extrinsic_call]
+        redeem_all(RawOrigin::Signed(alice.clone()), vec![position_id], n);
+
+        let expected_event = <T as Config>::RuntimeEvent::from(Event::<T>::PositionsRedeemed {
+            who: alice,
+            assets_in,
+            assets_out,
+            merged: vec![],
+        });
+        System::<T>::assert_last_event(expected_event.into());
+    }
+
+    #[benchmark]
+    fn convert_from_legacy(m: Linear<32, 64>) {
+        let alice: T::AccountId = whitelisted_caller();
+
+        let asset_count = 33u16;
+        let market_id = create_market::<T>(alice.clone(), asset_count);
+        let asset_in = Asset::CategoricalOutcome(market_id, asset_count - 1);
+        let amount = ZeitgeistBase::get().unwrap();
+        T::MultiCurrency::deposit(asset_in, &alice, amount).unwrap();
+        // Worst case: The conversion completes a set, so collateral is moved out of the market
+        // account.
+        let mut conversions = vec![amount; asset_count as usize];
+        *conversions.last_mut().unwrap() = Zero::zero();
+        LegacyConversions::<T>::insert(market_id, BoundedVec::truncate_from(conversions));
+        let market_account = T::LegacyMarketAccount::convert(market_id);
+        T::MultiCurrency::deposit(Asset::Ztg, &market_account, amount).unwrap();
+
+        let mut index_set = vec![false; asset_count as usize];
+        *index_set.last_mut().unwrap() = true;
+        let asset_out = Pallet::<T>::position_from_parent_collection(
+            None,
+            market_id,
+            index_set,
+            T::Fuel::from_total(m),
+        )
+        .unwrap();
+
+        #[extrinsic_call]
+        convert_from_legacy(
+            RawOrigin::Signed(alice.clone()),
+            asset_in,
+            amount,
+            T::Fuel::from_total(m),
+        );
+
+        let expected_event = <T as Config>::RuntimeEvent::from(Event::<T>::ConvertedFromLegacy {
+            who: alice,
+            asset_in,
+            asset_out,
+            amount,
+        });
+        System::<T>::assert_last_event(expected_event.into());
+    }
+
+    #[benchmark]
+    fn convert_to_legacy(m: Linear<32, 64>) {
+        let alice: T::AccountId = whitelisted_caller();
+
+        let asset_count = 33u16;
+        let market_id = create_market::<T>(alice.clone(), asset_count);
+        let asset_out = Asset::CategoricalOutcome(market_id, asset_count - 1);
+        let amount = ZeitgeistBase::get().unwrap();
+
+        let mut index_set = vec![false; asset_count as usize];
+        *index_set.last_mut().unwrap() = true;
+        let asset_in = Pallet::<T>::position_from_parent_collection(
+            None,
+            market_id,
+            index_set,
+            T::Fuel::from_total(m),
+        )
+        .unwrap();
+        T::MultiCurrency::deposit(asset_in, &alice, amount).unwrap();
+        // Worst case: The conversion breaks up a set, so collateral is moved back into the
+        // market account.
+        let conversions = vec![amount; asset_count as usize].try_into().unwrap();
+        LegacyConversions::<T>::insert(market_id, conversions);
+        T::MultiCurrency::deposit(Asset::Ztg, &Pallet::<T>::account_id(), amount).unwrap();
+
+        #[extrinsic_call]
+        convert_to_legacy(
+            RawOrigin::Signed(alice.clone()),
+            asset_out,
+            amount,
+            T::Fuel::from_total(m),
+        );
+
+        let expected_event = <T as Config>::RuntimeEvent::from(Event::<T>::ConvertedToLegacy {
+            who: alice,
+            asset_in,
+            asset_out,
+            amount,
+        });
+        System::<T>::assert_last_event(expected_event.into());
+    }
+
     impl_benchmark_test_suite!(
         Pallet,
         crate::mock::ext_builder::ExtBuilder::build(),
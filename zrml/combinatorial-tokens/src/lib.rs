@@ -40,7 +40,9 @@ pub use pallet::*;
 mod pallet {
     use crate::{
         traits::CombinatorialIdManager,
-        types::{CollectionIdError, TransmutationType},
+        types::{
+            CollectionIdError, IndexSet, Partition, PositionInfo, PositionValue, TransmutationType,
+        },
         weights::WeightInfoZeitgeist,
     };
     use alloc::{vec, vec::Vec};
@@ -48,8 +50,10 @@ mod pallet {
     use frame_support::{
         dispatch::DispatchResultWithPostInfo,
         ensure,
-        pallet_prelude::{IsType, StorageVersion},
-        require_transactional, transactional, PalletId,
+        pallet_prelude::{
+            Blake2_128Concat, ConstU32, IsType, OptionQuery, StorageMap, StorageVersion, ValueQuery,
+        },
+        require_transactional, transactional, BoundedVec, PalletId,
     };
     use frame_system::{
         ensure_signed,
@@ -59,16 +63,23 @@ mod pallet {
     use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
     use scale_info::TypeInfo;
     use sp_runtime::{
-        traits::{AccountIdConversion, Get, Zero},
+        traits::{AccountIdConversion, Convert, Get, Zero},
         DispatchError, DispatchResult, SaturatedConversion,
     };
     use zeitgeist_primitives::{
-        math::{checked_ops_res::CheckedAddRes, fixed::FixedMul},
+        constants::MAX_CATEGORIES,
+        math::{
+            checked_ops_res::{CheckedAddRes, CheckedSubRes},
+            fixed::FixedMul,
+        },
         traits::{
             CombinatorialTokensApi, CombinatorialTokensFuel, CombinatorialTokensUnsafeApi,
             MarketCommonsPalletApi, PayoutApi,
         },
-        types::{Asset, CombinatorialId, SplitPositionDispatchInfo},
+        types::{
+            Asset, CombinatorialId, MarketStatus, MarketType, ScalarPosition,
+            SplitPositionDispatchInfo,
+        },
     };
 
     #[cfg(feature = "runtime-benchmarks")]
@@ -90,6 +101,10 @@ mod pallet {
             Fuel = Self::Fuel,
         >;
 
+        /// Converts a market ID into the account which holds the collateral backing the market's
+        /// legacy outcome tokens (`CategoricalOutcome` and `ScalarOutcome`).
+        type LegacyMarketAccount: Convert<MarketIdOf<Self>, Self::AccountId>;
+
         type Fuel: Clone
             + CombinatorialTokensFuel
             + Debug
@@ -105,6 +120,10 @@ mod pallet {
             BlockNumber = BlockNumberFor<Self>,
         >;
 
+        /// The maximum number of steps (redemptions and merges) `redeem_all` may take.
+        #[pallet::constant]
+        type MaxRedeemSteps: Get<u32>;
+
         type MultiCurrency: MultiCurrency<Self::AccountId, CurrencyId = AssetOf<Self>>;
 
         /// Interface for acquiring the payout vector by market ID.
@@ -132,9 +151,34 @@ mod pallet {
     pub type FuelOf<T> = <<T as Config>::CombinatorialIdManager as CombinatorialIdManager>::Fuel;
     pub(crate) type SplitPositionDispatchInfoOf<T> =
         SplitPositionDispatchInfo<CombinatorialIdOf<T>, MarketIdOf<T>>;
+    pub type PositionInfoOf<T> = PositionInfo<MarketIdOf<T>>;
+    pub type PositionValueOf<T> = PositionValue<MarketIdOf<T>, BalanceOf<T>>;
 
     pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
 
+    /// Maps the ID of a position created by this pallet to the information required to redeem
+    /// it. Only positions created or received by splitting or merging since this map was
+    /// introduced are recorded; older positions have to be derived from `TokenSplit` and
+    /// `TokenMerged` events. An entry is removed once all tokens of its position are burned, so
+    /// each entry is backed by outstanding tokens, whose holders pay the existential deposit.
+    #[pallet::storage]
+    pub type Positions<T: Config> =
+        StorageMap<_, Blake2_128Concat, CombinatorialId, PositionInfoOf<T>, OptionQuery>;
+
+    /// Maps a market ID to the net amount of each legacy outcome token of the market converted
+    /// into first-level combinatorial tokens. The collateral backing the complete sets among these
+    /// amounts (the smallest amount converted of any outcome) is held by the pallet account. The
+    /// collateral backing the remainder is moved from the market account into the pallet account
+    /// when the first first-level position of the market is redeemed.
+    #[pallet::storage]
+    pub type LegacyConversions<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        MarketIdOf<T>,
+        BoundedVec<BalanceOf<T>, ConstU32<{ MAX_CATEGORIES as u32 }>>,
+        ValueQuery,
+    >;
+
     #[pallet::event]
     #[pallet::generate_deposit(fn deposit_event)]
     pub enum Event<T>
@@ -188,6 +232,37 @@ mod pallet {
             asset_out: AssetOf<T>,
             amount_out: BalanceOf<T>,
         },
+
+        /// User `who` has redeemed their positions using `redeem_all`. The ith element of
+        /// `assets_in` was redeemed for the ith element of `assets_out`. Each element
+        /// `(positions, asset_out, amount)` of `merged` means that `amount` units of each of the
+        /// `positions` were merged into `amount` units of `asset_out`. If redeeming or merging
+        /// yields a position which can be redeemed or merged, too, the vectors may contain
+        /// positions which weren't specified by the user.
+        PositionsRedeemed {
+            who: AccountIdOf<T>,
+            assets_in: Vec<(AssetOf<T>, BalanceOf<T>)>,
+            assets_out: Vec<(AssetOf<T>, BalanceOf<T>)>,
+            merged: Vec<(Vec<AssetOf<T>>, AssetOf<T>, BalanceOf<T>)>,
+        },
+
+        /// User `who` has converted `amount` units of the legacy outcome token `asset_in` into the
+        /// same amount of the equivalent first-level combinatorial token `asset_out`.
+        ConvertedFromLegacy {
+            who: AccountIdOf<T>,
+            asset_in: AssetOf<T>,
+            asset_out: AssetOf<T>,
+            amount: BalanceOf<T>,
+        },
+
+        /// User `who` has converted `amount` units of the first-level combinatorial token
+        /// `asset_in` into the same amount of the equivalent legacy outcome token `asset_out`.
+        ConvertedToLegacy {
+            who: AccountIdOf<T>,
+            asset_in: AssetOf<T>,
+            asset_out: AssetOf<T>,
+            amount: BalanceOf<T>,
+        },
     }
 
     #[pallet::error]
@@ -208,11 +283,28 @@ mod pallet {
         /// Account holds no tokens of this type.
         NoTokensFound,
 
+        /// Specified market is not a scalar market.
+        NotScalarMarket,
+
+        /// Specified asset is not a legacy outcome token (`CategoricalOutcome` or
+        /// `ScalarOutcome`).
+        NotLegacyOutcome,
+
+        /// Legacy outcome tokens can't be converted after the market is resolved.
+        MarketAlreadyResolved,
+
+        /// Only combinatorial tokens previously converted from legacy outcome tokens can be
+        /// converted back.
+        InsufficientConvertedBalance,
+
         /// Specified token holds no redeemable value.
         TokenHasNoValue,
 
         /// Something unexpected happened. You shouldn't see this.
         UnexpectedError,
+
+        /// The specified number of steps exceeds `MaxRedeemSteps`.
+        TooManySteps,
     }
 
     #[pallet::call]
@@ -220,9 +312,11 @@ mod pallet {
         /// Split `amount` units of the position specified by `parent_collection_id` over the market
         /// with ID `market_id` according to the given `partition`.
         ///
-        /// The `partition` is specified as a vector whose elements are equal-length `Vec<bool>`. A
-        /// `true` entry at the `i`th index of a partition element means that the `i`th outcome
-        /// token of the market is contained in this element of the partition.
+        /// The `partition` is specified as `Partition::IndexSets`, a vector whose elements are
+        /// equal-length `Vec<bool>`. A `true` entry at the `i`th index of a partition element means
+        /// that the `i`th outcome token of the market is contained in this element of the
+        /// partition. If the market is scalar, `Partition::Scalar` splits the position into its
+        /// long and short leg.
         ///
         /// For each element `b` of the partition, the split mints a new outcome token which is made
         /// up of the position to be split and the conjunction `(x|...|z)` where `x, ..., z` are the
@@ -263,11 +357,12 @@ mod pallet {
             origin: OriginFor<T>,
             parent_collection_id: Option<CombinatorialIdOf<T>>,
             market_id: MarketIdOf<T>,
-            partition: Vec<Vec<bool>>,
+            partition: Partition,
             amount: BalanceOf<T>,
             fuel: FuelOf<T>,
         ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
+            let partition = Self::partition_index_sets(market_id, partition)?;
 
             let SplitPositionDispatchInfo { post_dispatch_info, .. } = Self::do_split_position(
                 who,
@@ -286,9 +381,10 @@ mod pallet {
         /// the position obtained by splitting `parent_collection_id` according to `partiton` over
         /// the market with ID `market_id` (horizontal; see below for details).
         ///
-        /// The `partition` is specified as a vector whose elements are equal-length `Vec<bool>`. A
-        /// `true` entry at the `i`th index of a partition element means that the `i`th outcome
-        /// token of the market is contained in this element of the partition.
+        /// The `partition` is specified as `Partition::IndexSets`, a vector whose elements are
+        /// equal-length `Vec<bool>`. A `true` entry at the `i`th index of a partition element means
+        /// that the `i`th outcome token of the market is contained in this element of the
+        /// partition. If the market is scalar, `Partition::Scalar` merges its long and short leg.
         ///
         /// For each element `b` of the partition, the split burns the outcome tokens which are made
         /// up of the position to be split and the conjunction `(x|...|z)` where `x, ..., z` are the
@@ -326,11 +422,12 @@ mod pallet {
             origin: OriginFor<T>,
             parent_collection_id: Option<CombinatorialIdOf<T>>,
             market_id: MarketIdOf<T>,
-            partition: Vec<Vec<bool>>,
+            partition: Partition,
             amount: BalanceOf<T>,
             fuel: FuelOf<T>,
         ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
+            let partition = Self::partition_index_sets(market_id, partition)?;
             Self::do_merge_position(who, parent_collection_id, market_id, partition, amount, fuel)
         }
 
@@ -339,7 +436,8 @@ mod pallet {
         ///
         /// The position to be redeemed is the position obtained by combining the position given by
         /// `parent_collection_id` and `collateral` with the conjunction `(x|...|z)` where `x, ...
-        /// z` are the outcome tokens of the market `market_id` given by `partition`.
+        /// z` are the outcome tokens of the market `market_id` given by `index_set`. If the market
+        /// is scalar, `IndexSet::Scalar` specifies its long or short leg.
         ///
         /// The position to be redeemed is completely removed from the origin's wallet. According to
         /// how much the conjunction `(x|...|z)` is valued, the user is paid in the position defined
@@ -363,12 +461,90 @@ mod pallet {
             origin: OriginFor<T>,
             parent_collection_id: Option<CombinatorialIdOf<T>>,
             market_id: MarketIdOf<T>,
-            index_set: Vec<bool>,
+            index_set: IndexSet,
             fuel: FuelOf<T>,
         ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
+            let index_set = Self::index_set_outcomes(market_id, index_set)?;
             Self::do_redeem_position(who, parent_collection_id, market_id, index_set, fuel)
         }
+
+        /// Redeems and merges the origin's entire holdings of the specified `positions` and walks
+        /// up the chain of parent collections bottom-up:
+        ///
+        /// - A position whose market is resolved is redeemed using the market's payout vector.
+        /// - Positions of an unresolved market which share their parent collection and whose index
+        ///   sets partition the market's outcomes are merged into the parent position (or the
+        ///   collateral). The amount merged is the smallest amount held of these positions.
+        ///
+        /// If redeeming or merging yields a position, that position is redeemed or merged as well,
+        /// until the collateral or a position which can't be redeemed or merged is reached.
+        ///
+        /// Only positions created using `split_position` or `merge_position` are considered.
+        /// Positions which are unknown or aren't held by the origin are skipped. Worthless
+        /// positions are burned.
+        ///
+        /// The `max_steps` parameter specifies the maximum number of positions to visit (at most
+        /// `MaxRedeemSteps`); any positions left over when the steps run out are skipped. Fails
+        /// with `NoTokensFound` if nothing was redeemed or merged.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::redeem_all(*max_steps))]
+        #[transactional]
+        pub fn redeem_all(
+            origin: OriginFor<T>,
+            positions: Vec<CombinatorialIdOf<T>>,
+            max_steps: u32,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::do_redeem_all(who, positions, max_steps)
+        }
+
+        /// Convert `amount` units of the legacy outcome token `asset` (`CategoricalOutcome` or
+        /// `ScalarOutcome`) into the same amount of the equivalent first-level combinatorial
+        /// token, i.e. the position obtained by splitting the market's collateral into the
+        /// outcome specified by `asset`.
+        ///
+        /// The legacy tokens are burned. If the converted tokens complete a set (i.e. every outcome
+        /// of the market has been converted), the collateral backing the set is moved from the
+        /// market account into the pallet account, so that the set can be merged. The collateral
+        /// backing incomplete sets remains in the market account until the first first-level
+        /// position of the market is redeemed using this pallet, as it still backs the legacy
+        /// tokens of the other outcomes.
+        ///
+        /// The `fuel` parameter specifies how much work the cryptographic id manager will do
+        /// and can be used for benchmarking purposes.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::convert_from_legacy(fuel.total()))]
+        #[transactional]
+        pub fn convert_from_legacy(
+            origin: OriginFor<T>,
+            asset: AssetOf<T>,
+            amount: BalanceOf<T>,
+            fuel: FuelOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::do_convert_from_legacy(who, asset, amount, fuel)
+        }
+
+        /// Convert `amount` units of the first-level combinatorial token equivalent to the legacy
+        /// outcome token `asset` back into `asset`. The amount is limited by the net amount
+        /// converted from `asset` using `convert_from_legacy`. If this breaks up a complete set of
+        /// converted tokens, the collateral backing it is moved back into the market account.
+        ///
+        /// The `fuel` parameter specifies how much work the cryptographic id manager will do
+        /// and can be used for benchmarking purposes.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::convert_to_legacy(fuel.total()))]
+        #[transactional]
+        pub fn convert_to_legacy(
+            origin: OriginFor<T>,
+            asset: AssetOf<T>,
+            amount: BalanceOf<T>,
+            fuel: FuelOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::do_convert_to_legacy(who, asset, amount, fuel)
+        }
     }
 
     impl<T: Config> Pallet<T> {
@@ -396,6 +572,7 @@ mod pallet {
                     // markets.
                     T::MultiCurrency::ensure_can_withdraw(position, &who, amount)?;
                     T::MultiCurrency::withdraw(position, &who, amount)?;
+                    Self::prune_position(position);
 
                     T::WeightInfo::split_position_vertical_with_parent(
                         partition.len().saturated_into(),
@@ -417,6 +594,7 @@ mod pallet {
                     // Horizontal split.
                     T::MultiCurrency::ensure_can_withdraw(position, &who, amount)?;
                     T::MultiCurrency::withdraw(position, &who, amount)?;
+                    Self::prune_position(position);
 
                     T::WeightInfo::split_position_horizontal(
                         partition.len().saturated_into(),
@@ -445,8 +623,9 @@ mod pallet {
                 .collect::<Result<Vec<_>, _>>()?;
             // Security note: Safe as iterations are limited to the number of assets in the market
             // thanks to the `ensure!` invocations in `Self::free_index_set`.
-            for &position in positions.iter() {
+            for (&position, index_set) in positions.iter().zip(partition.iter()) {
                 T::MultiCurrency::deposit(position, &who, amount)?;
+                Self::record_position(position, parent_collection_id, market_id, index_set)?;
             }
 
             Self::deposit_event(Event::<T>::TokenSplit {
@@ -502,6 +681,7 @@ mod pallet {
             // thanks to the `ensure!` invocations in `Self::free_index_set`.
             for &position in positions.iter() {
                 T::MultiCurrency::withdraw(position, &who, amount)?;
+                Self::prune_position(position);
             }
 
             let weight = match transmutation_type {
@@ -527,6 +707,11 @@ mod pallet {
                 TransmutationType::Horizontal => {
                     // Horizontal merge.
                     T::MultiCurrency::deposit(position, &who, amount)?;
+                    let index_set = Self::free_index_set(market_id, &partition)?
+                        .into_iter()
+                        .map(|i| !i)
+                        .collect::<Vec<_>>();
+                    Self::record_position(position, parent_collection_id, market_id, &index_set)?;
 
                     T::WeightInfo::merge_position_horizontal(
                         partition.len().saturated_into(),
@@ -587,6 +772,7 @@ mod pallet {
             let amount = T::MultiCurrency::free_balance(position, &who);
             ensure!(!amount.is_zero(), Error::<T>::NoTokensFound);
             T::MultiCurrency::withdraw(position, &who, amount)?;
+            Self::prune_position(position);
 
             let total_payout = total_stake.bmul(amount)?;
 
@@ -603,6 +789,7 @@ mod pallet {
 
                 (weight, position)
             } else {
+                Self::settle_legacy_conversions(market_id, &payout_vector, collateral_token)?;
                 T::MultiCurrency::transfer(
                     collateral_token,
                     &Self::account_id(),
@@ -632,6 +819,371 @@ mod pallet {
             Ok(Some(weight).into())
         }
 
+        #[require_transactional]
+        fn do_redeem_all(
+            who: AccountIdOf<T>,
+            positions: Vec<CombinatorialIdOf<T>>,
+            max_steps: u32,
+        ) -> DispatchResultWithPostInfo {
+            ensure!(max_steps <= T::MaxRedeemSteps::get(), Error::<T>::TooManySteps);
+
+            let mut steps: u32 = 0;
+            let mut assets_in = vec![];
+            let mut assets_out = vec![];
+            let mut merged = vec![];
+            // Positions of unresolved markets which couldn't be merged (yet).
+            let mut pending: Vec<(CombinatorialIdOf<T>, PositionInfoOf<T>)> = vec![];
+
+            // Security note: Safe as the total number of iterations of the inner loop is limited
+            // by `max_steps`, which is accounted for in the weight. `pending` contains at most
+            // `max_steps` elements.
+            'positions: for position_id in positions.into_iter() {
+                let mut next = Some(position_id);
+                while let Some(position_id) = next {
+                    if steps >= max_steps {
+                        break 'positions;
+                    }
+                    steps = steps.saturating_add(1);
+
+                    next = None;
+                    let Some(position_info) = Positions::<T>::get(position_id) else {
+                        continue;
+                    };
+                    let position = Asset::CombinatorialToken(position_id);
+                    if T::MultiCurrency::free_balance(position, &who).is_zero() {
+                        continue;
+                    }
+
+                    let asset_out = if let Some((amount_in, asset_out, amount_out)) =
+                        Self::redeem_recorded_position(&who, position_id, &position_info)?
+                    {
+                        assets_in.push((position, amount_in));
+                        assets_out.push((asset_out, amount_out));
+                        asset_out
+                    } else if let Some((positions_in, asset_out, amount)) =
+                        Self::merge_recorded_positions(
+                            &who,
+                            position_id,
+                            &position_info,
+                            &mut pending,
+                        )?
+                    {
+                        merged.push((positions_in, asset_out, amount));
+                        asset_out
+                    } else {
+                        if !pending.iter().any(|(id, _)| *id == position_id) {
+                            pending.push((position_id, position_info));
+                        }
+                        continue;
+                    };
+
+                    if let Asset::CombinatorialToken(parent_position_id) = asset_out {
+                        next = Some(parent_position_id);
+                    }
+                }
+            }
+
+            ensure!(!assets_in.is_empty() || !merged.is_empty(), Error::<T>::NoTokensFound);
+
+            Self::deposit_event(Event::<T>::PositionsRedeemed {
+                who,
+                assets_in,
+                assets_out,
+                merged,
+            });
+
+            Ok(Some(T::WeightInfo::redeem_all(steps)).into())
+        }
+
+        /// Redeems all units of the position `position_id` held by `who` using the information
+        /// recorded in `Positions`. Returns the amount redeemed and the asset and amount paid out,
+        /// or `None` if its market is unresolved or `who` holds no units of it.
+        fn redeem_recorded_position(
+            who: &AccountIdOf<T>,
+            position_id: CombinatorialIdOf<T>,
+            position_info: &PositionInfoOf<T>,
+        ) -> Result<Option<(BalanceOf<T>, AssetOf<T>, BalanceOf<T>)>, DispatchError> {
+            let position = Asset::CombinatorialToken(position_id);
+            let amount = T::MultiCurrency::free_balance(position, who);
+            if amount.is_zero() {
+                return Ok(None);
+            }
+            let Some((asset_out, total_payout)) = Self::redeemable_value(position_info, amount)?
+            else {
+                return Ok(None);
+            };
+
+            T::MultiCurrency::withdraw(position, who, amount)?;
+            Self::prune_position(position);
+            if position_info.parent_collection_id.is_some() {
+                T::MultiCurrency::deposit(asset_out, who, total_payout)?;
+            } else {
+                let payout_vector = T::Payout::payout_vector(position_info.market_id)
+                    .ok_or(Error::<T>::PayoutVectorNotFound)?;
+                Self::settle_legacy_conversions(
+                    position_info.market_id,
+                    &payout_vector,
+                    asset_out,
+                )?;
+                T::MultiCurrency::transfer(asset_out, &Self::account_id(), who, total_payout)?;
+            }
+
+            Ok(Some((amount, asset_out, total_payout)))
+        }
+
+        /// Merges the position `position_id` with the positions in `pending` which share its
+        /// parent collection and market if their index sets partition the market's outcomes. The
+        /// merged positions are removed from `pending`. Returns the positions merged, the position
+        /// (or collateral) received and the amount merged, or `None` if the positions don't
+        /// partition the market's outcomes or `who` holds no units of one of them.
+        fn merge_recorded_positions(
+            who: &AccountIdOf<T>,
+            position_id: CombinatorialIdOf<T>,
+            position_info: &PositionInfoOf<T>,
+            pending: &mut Vec<(CombinatorialIdOf<T>, PositionInfoOf<T>)>,
+        ) -> Result<Option<(Vec<AssetOf<T>>, AssetOf<T>, BalanceOf<T>)>, DispatchError> {
+            let is_sibling = |info: &PositionInfoOf<T>| {
+                info.parent_collection_id == position_info.parent_collection_id
+                    && info.market_id == position_info.market_id
+            };
+            let mut siblings = vec![(position_id, position_info.index_set.as_slice())];
+            siblings.extend(
+                pending
+                    .iter()
+                    .filter(|(id, info)| *id != position_id && is_sibling(info))
+                    .map(|(id, info)| (*id, info.index_set.as_slice())),
+            );
+            let index_sets = siblings.iter().map(|(_, index_set)| *index_set).collect::<Vec<_>>();
+            if !Self::is_partition(&index_sets) {
+                return Ok(None);
+            }
+
+            let positions =
+                siblings.iter().map(|(id, _)| Asset::CombinatorialToken(*id)).collect::<Vec<_>>();
+            let amount = positions
+                .iter()
+                .map(|&position| T::MultiCurrency::free_balance(position, who))
+                .min()
+                .unwrap_or_else(Zero::zero);
+            if amount.is_zero() {
+                return Ok(None);
+            }
+
+            // Security note: Safe as `positions` is bounded by the number of outcomes of the
+            // market, since `index_sets` is a partition.
+            for &position in positions.iter() {
+                T::MultiCurrency::withdraw(position, who, amount)?;
+                Self::prune_position(position);
+            }
+
+            let market = T::MarketCommons::market(&position_info.market_id)?;
+            let collateral_token = market.base_asset;
+            let asset_out = if let Some(pci) = position_info.parent_collection_id {
+                let position_id = T::CombinatorialIdManager::get_position_id(collateral_token, pci);
+                let asset_out = Asset::CombinatorialToken(position_id);
+                T::MultiCurrency::deposit(asset_out, who, amount)?;
+                asset_out
+            } else {
+                T::MultiCurrency::transfer(collateral_token, &Self::account_id(), who, amount)?;
+                collateral_token
+            };
+
+            pending.retain(|(id, _)| !positions.contains(&Asset::CombinatorialToken(*id)));
+
+            Ok(Some((positions, asset_out, amount)))
+        }
+
+        /// Returns `true` if the (non-empty) `index_sets` have the same length and each index is
+        /// contained in exactly one of them.
+        fn is_partition(index_sets: &[&[bool]]) -> bool {
+            let Some(first) = index_sets.first() else {
+                return false;
+            };
+            if index_sets.iter().any(|index_set| index_set.len() != first.len()) {
+                return false;
+            }
+
+            (0..first.len()).all(|i| {
+                index_sets.iter().filter(|index_set| index_set.get(i) == Some(&true)).count() == 1
+            })
+        }
+
+        #[require_transactional]
+        fn do_convert_from_legacy(
+            who: AccountIdOf<T>,
+            asset: AssetOf<T>,
+            amount: BalanceOf<T>,
+            fuel: FuelOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let (market_id, index, index_set) = Self::legacy_index_set(asset)?;
+            let position =
+                Self::position_from_parent_collection(None, market_id, index_set.clone(), fuel)?;
+
+            T::MultiCurrency::ensure_can_withdraw(asset, &who, amount)?;
+            T::MultiCurrency::withdraw(asset, &who, amount)?;
+            T::MultiCurrency::deposit(position, &who, amount)?;
+            Self::record_position(position, None, market_id, &index_set)?;
+
+            let (complete_sets_before, complete_sets_after) = LegacyConversions::<T>::try_mutate(
+                market_id,
+                |conversions| -> Result<(BalanceOf<T>, BalanceOf<T>), DispatchError> {
+                    if conversions.is_empty() {
+                        *conversions = vec![Zero::zero(); index_set.len()]
+                            .try_into()
+                            .map_err(|_| Error::<T>::InvalidIndexSet)?;
+                    }
+                    let complete_sets_before = Self::complete_sets(conversions);
+                    let converted =
+                        conversions.get_mut(index).ok_or(Error::<T>::UnexpectedError)?;
+                    *converted = converted.checked_add_res(&amount)?;
+                    Ok((complete_sets_before, Self::complete_sets(conversions)))
+                },
+            )?;
+            Self::move_complete_set_collateral(
+                market_id,
+                complete_sets_before,
+                complete_sets_after,
+            )?;
+
+            Self::deposit_event(Event::<T>::ConvertedFromLegacy {
+                who,
+                asset_in: asset,
+                asset_out: position,
+                amount,
+            });
+
+            Ok(().into())
+        }
+
+        #[require_transactional]
+        fn do_convert_to_legacy(
+            who: AccountIdOf<T>,
+            asset: AssetOf<T>,
+            amount: BalanceOf<T>,
+            fuel: FuelOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let (market_id, index, index_set) = Self::legacy_index_set(asset)?;
+            let position = Self::position_from_parent_collection(None, market_id, index_set, fuel)?;
+
+            let (complete_sets_before, complete_sets_after) = LegacyConversions::<T>::try_mutate(
+                market_id,
+                |conversions| -> Result<(BalanceOf<T>, BalanceOf<T>), DispatchError> {
+                    let complete_sets_before = Self::complete_sets(conversions);
+                    let converted = conversions
+                        .get_mut(index)
+                        .ok_or(Error::<T>::InsufficientConvertedBalance)?;
+                    *converted = converted
+                        .checked_sub_res(&amount)
+                        .map_err(|_| Error::<T>::InsufficientConvertedBalance)?;
+                    Ok((complete_sets_before, Self::complete_sets(conversions)))
+                },
+            )?;
+            Self::move_complete_set_collateral(
+                market_id,
+                complete_sets_before,
+                complete_sets_after,
+            )?;
+
+            T::MultiCurrency::ensure_can_withdraw(position, &who, amount)?;
+            T::MultiCurrency::withdraw(position, &who, amount)?;
+            Self::prune_position(position);
+            T::MultiCurrency::deposit(asset, &who, amount)?;
+
+            Self::deposit_event(Event::<T>::ConvertedToLegacy {
+                who,
+                asset_in: position,
+                asset_out: asset,
+                amount,
+            });
+
+            Ok(().into())
+        }
+
+        /// Returns the market ID, outcome index and index set of the legacy outcome token `asset`.
+        /// Fails if the market is already resolved.
+        fn legacy_index_set(
+            asset: AssetOf<T>,
+        ) -> Result<(MarketIdOf<T>, usize, Vec<bool>), DispatchError> {
+            let (market_id, index) = match asset {
+                Asset::CategoricalOutcome(market_id, index) => (market_id, index as usize),
+                Asset::ScalarOutcome(market_id, ScalarPosition::Long) => (market_id, 0),
+                Asset::ScalarOutcome(market_id, ScalarPosition::Short) => (market_id, 1),
+                _ => return Err(Error::<T>::NotLegacyOutcome.into()),
+            };
+            let market = T::MarketCommons::market(&market_id)?;
+            ensure!(market.status != MarketStatus::Resolved, Error::<T>::MarketAlreadyResolved);
+            let asset_count = market.outcomes() as usize;
+            ensure!(index < asset_count, Error::<T>::InvalidIndexSet);
+            let mut index_set = vec![false; asset_count];
+            index_set[index] = true;
+
+            Ok((market_id, index, index_set))
+        }
+
+        /// Returns the number of complete sets among the converted amounts `conversions`, i.e. the
+        /// smallest amount converted of any outcome.
+        fn complete_sets(conversions: &[BalanceOf<T>]) -> BalanceOf<T> {
+            conversions.iter().min().copied().unwrap_or_else(Zero::zero)
+        }
+
+        /// Moves the collateral backing the complete sets of converted legacy outcome tokens of
+        /// `market_id` between the market account and the pallet account after the number of
+        /// complete sets changed from `before` to `after`.
+        fn move_complete_set_collateral(
+            market_id: MarketIdOf<T>,
+            before: BalanceOf<T>,
+            after: BalanceOf<T>,
+        ) -> DispatchResult {
+            if before == after {
+                return Ok(());
+            }
+
+            let collateral = T::MarketCommons::market(&market_id)?.base_asset;
+            let market_account = T::LegacyMarketAccount::convert(market_id);
+            if after > before {
+                T::MultiCurrency::transfer(
+                    collateral,
+                    &market_account,
+                    &Self::account_id(),
+                    after.checked_sub_res(&before)?,
+                )
+            } else {
+                T::MultiCurrency::transfer(
+                    collateral,
+                    &Self::account_id(),
+                    &market_account,
+                    before.checked_sub_res(&after)?,
+                )
+            }
+        }
+
+        /// Moves the collateral backing the legacy outcome tokens of `market_id` which were
+        /// converted into first-level combinatorial tokens but don't form complete sets from the
+        /// market account into the pallet account. The collateral backing the complete sets is
+        /// already held by the pallet account. Does nothing if there are no (unsettled)
+        /// conversions.
+        fn settle_legacy_conversions(
+            market_id: MarketIdOf<T>,
+            payout_vector: &[BalanceOf<T>],
+            collateral: AssetOf<T>,
+        ) -> DispatchResult {
+            let conversions = LegacyConversions::<T>::take(market_id);
+            if conversions.is_empty() {
+                return Ok(());
+            }
+
+            let complete_sets = Self::complete_sets(&conversions);
+            let mut total: BalanceOf<T> = Zero::zero();
+            // Security note: Safe as `conversions` is bounded by the maximum number of categories.
+            for (converted, value) in conversions.iter().zip(payout_vector.iter()) {
+                let unsettled = converted.checked_sub_res(&complete_sets)?;
+                total = total.checked_add_res(&value.bmul(unsettled)?)?;
+            }
+
+            let market_account = T::LegacyMarketAccount::convert(market_id);
+            T::MultiCurrency::transfer(collateral, &market_account, &Self::account_id(), total)
+        }
+
         pub fn account_id() -> T::AccountId {
             T::PalletId::get().into_account_truncating()
         }
@@ -701,7 +1253,7 @@ mod pallet {
             Ok(result)
         }
 
-        pub(crate) fn collection_id_from_parent_collection(
+        pub fn collection_id_from_parent_collection(
             parent_collection_id: Option<CombinatorialIdOf<T>>,
             market_id: MarketIdOf<T>,
             index_set: Vec<bool>,
@@ -747,6 +1299,174 @@ mod pallet {
 
             Self::position_from_collection_id(market_id, collection_id)
         }
+
+        /// Returns the position obtained by splitting `parent_collection_id` over the scalar
+        /// market `market_id` and selecting `scalar_position`. This is a shorthand for
+        /// `position_from_parent_collection` with the index set of `scalar_position`.
+        pub fn scalar_position_from_parent_collection(
+            parent_collection_id: Option<CombinatorialIdOf<T>>,
+            market_id: MarketIdOf<T>,
+            scalar_position: ScalarPosition,
+            fuel: FuelOf<T>,
+        ) -> Result<AssetOf<T>, DispatchError> {
+            Self::ensure_scalar_market(market_id)?;
+
+            Self::position_from_parent_collection(
+                parent_collection_id,
+                market_id,
+                scalar_position.index_set(),
+                fuel,
+            )
+        }
+
+        /// Returns the index sets described by `partition`. Fails if `partition` describes the
+        /// legs of a scalar market and `market_id` isn't scalar.
+        fn partition_index_sets(
+            market_id: MarketIdOf<T>,
+            partition: Partition,
+        ) -> Result<Vec<Vec<bool>>, DispatchError> {
+            match partition {
+                Partition::IndexSets(index_sets) => Ok(index_sets),
+                Partition::Scalar => {
+                    Self::ensure_scalar_market(market_id)?;
+                    Ok(vec![ScalarPosition::Long.index_set(), ScalarPosition::Short.index_set()])
+                }
+            }
+        }
+
+        /// Returns the outcomes described by `index_set`. Fails if `index_set` describes a leg of
+        /// a scalar market and `market_id` isn't scalar.
+        fn index_set_outcomes(
+            market_id: MarketIdOf<T>,
+            index_set: IndexSet,
+        ) -> Result<Vec<bool>, DispatchError> {
+            match index_set {
+                IndexSet::Outcomes(index_set) => Ok(index_set),
+                IndexSet::Scalar(scalar_position) => {
+                    Self::ensure_scalar_market(market_id)?;
+                    Ok(scalar_position.index_set())
+                }
+            }
+        }
+
+        fn ensure_scalar_market(market_id: MarketIdOf<T>) -> DispatchResult {
+            let market = T::MarketCommons::market(&market_id)?;
+            ensure!(
+                matches!(market.market_type, MarketType::Scalar(_)),
+                Error::<T>::NotScalarMarket
+            );
+
+            Ok(())
+        }
+
+        /// Returns the leg of the scalar market `market_id` which `index_set` selects, or `None`
+        /// if the market isn't scalar or `index_set` doesn't select exactly one leg.
+        fn scalar_position_of(
+            market_id: MarketIdOf<T>,
+            index_set: &[bool],
+        ) -> Option<ScalarPosition> {
+            Self::ensure_scalar_market(market_id).ok()?;
+            [ScalarPosition::Long, ScalarPosition::Short]
+                .into_iter()
+                .find(|scalar_position| scalar_position.index_set() == index_set)
+        }
+
+        /// Returns the positions among `positions` which are held by `who` together with their
+        /// redeemable value. Positions which weren't created by this pallet and positions which
+        /// `who` doesn't hold are skipped.
+        pub fn position_values(
+            who: &AccountIdOf<T>,
+            positions: Vec<CombinatorialId>,
+        ) -> Vec<PositionValueOf<T>> {
+            positions
+                .into_iter()
+                .filter_map(|position_id| {
+                    let position_info = Positions::<T>::get(position_id)?;
+                    let position = Asset::CombinatorialToken(position_id);
+                    let amount = T::MultiCurrency::free_balance(position, who);
+                    if amount.is_zero() {
+                        return None;
+                    }
+
+                    let redeemable = Self::redeemable_value(&position_info, amount).ok()?;
+                    let scalar_position =
+                        Self::scalar_position_of(position_info.market_id, &position_info.index_set);
+
+                    Some(PositionValue {
+                        position,
+                        parent_collection_id: position_info.parent_collection_id,
+                        market_id: position_info.market_id,
+                        index_set: position_info.index_set.into_inner(),
+                        scalar_position,
+                        amount,
+                        redeemable,
+                    })
+                })
+                .collect()
+        }
+
+        /// Calculates the asset and amount received when redeeming `amount` units of the position
+        /// described by `position_info`. Returns `None` if the market is not resolved.
+        fn redeemable_value(
+            position_info: &PositionInfoOf<T>,
+            amount: BalanceOf<T>,
+        ) -> Result<Option<(AssetOf<T>, BalanceOf<T>)>, DispatchError> {
+            let payout_vector = match T::Payout::payout_vector(position_info.market_id) {
+                Some(payout_vector) => payout_vector,
+                None => return Ok(None),
+            };
+
+            let mut total_stake: BalanceOf<T> = Zero::zero();
+            // Security note: Safe because `zip` will limit this loop to `payout_vector.len()`
+            // iterations.
+            for (&index, value) in position_info.index_set.iter().zip(payout_vector.iter()) {
+                if index {
+                    total_stake = total_stake.checked_add_res(value)?;
+                }
+            }
+            let total_payout = total_stake.bmul(amount)?;
+
+            let market = T::MarketCommons::market(&position_info.market_id)?;
+            let collateral_token = market.base_asset;
+            let asset_out = if let Some(pci) = position_info.parent_collection_id {
+                let position_id = T::CombinatorialIdManager::get_position_id(collateral_token, pci);
+                Asset::CombinatorialToken(position_id)
+            } else {
+                collateral_token
+            };
+
+            Ok(Some((asset_out, total_payout)))
+        }
+
+        /// Records the information required to redeem `position` in `Positions` unless it's
+        /// already recorded.
+        fn record_position(
+            position: AssetOf<T>,
+            parent_collection_id: Option<CombinatorialIdOf<T>>,
+            market_id: MarketIdOf<T>,
+            index_set: &[bool],
+        ) -> DispatchResult {
+            if let Asset::CombinatorialToken(position_id) = position {
+                if Positions::<T>::contains_key(position_id) {
+                    return Ok(());
+                }
+                let position_info =
+                    PositionInfo::new(parent_collection_id, market_id, index_set.to_vec())
+                        .ok_or(Error::<T>::InvalidIndexSet)?;
+                Positions::<T>::insert(position_id, position_info);
+            }
+
+            Ok(())
+        }
+
+        /// Removes `position` from `Positions` once all of its tokens are burned.
+        fn prune_position(position: AssetOf<T>) {
+            if let Asset::CombinatorialToken(position_id) = position {
+                if T::MultiCurrency::total_issuance(position).is_zero() {
+                    Positions::<T>::remove(position_id);
+                }
+            }
+        }
     }
 
     impl<T> CombinatorialTokensApi for Pallet<T>
@@ -769,6 +1489,45 @@ mod pallet {
         ) -> Result<SplitPositionDispatchInfoOf<T>, DispatchError> {
             Self::do_split_position(who, parent_collection_id, market_id, partition, amount, fuel)
         }
+
+        fn convert_from_legacy(
+            who: Self::AccountId,
+            asset: AssetOf<T>,
+            amount: Self::Balance,
+            fuel: Self::Fuel,
+        ) -> DispatchResult {
+            Self::do_convert_from_legacy(who, asset, amount, fuel)
+                .map(|_| ())
+                .map_err(|err| err.error)
+        }
+
+        fn collection_id_from_parent_collection(
+            parent_collection_id: Option<Self::CombinatorialId>,
+            market_id: Self::MarketId,
+            index_set: Vec<bool>,
+            fuel: Self::Fuel,
+        ) -> Result<Self::CombinatorialId, DispatchError> {
+            Pallet::<T>::collection_id_from_parent_collection(
+                parent_collection_id,
+                market_id,
+                index_set,
+                fuel,
+            )
+        }
+
+        fn position_from_parent_collection(
+            parent_collection_id: Option<Self::CombinatorialId>,
+            market_id: Self::MarketId,
+            index_set: Vec<bool>,
+            fuel: Self::Fuel,
+        ) -> Result<AssetOf<T>, DispatchError> {
+            Pallet::<T>::position_from_parent_collection(
+                parent_collection_id,
+                market_id,
+                index_set,
+                fuel,
+            )
+        }
     }
 
     impl<T> CombinatorialTokensUnsafeApi for Pallet<T>
@@ -17,7 +17,7 @@
 
 use crate as zrml_combinatorial_tokens;
 use crate::{
-    mock::types::MockPayout,
+    mock::types::{MockMarketAccount, MockPayout},
     types::{cryptographic_id_manager::Fuel, CryptographicIdManager},
     weights::WeightInfo,
 };
@@ -26,8 +26,9 @@ use frame_system::mocking::MockBlock;
 use sp_runtime::traits::{BlakeTwo256, ConstU32, IdentityLookup};
 use zeitgeist_primitives::{
     constants::mock::{
-        BlockHashCount, CombinatorialTokensPalletId, ExistentialDeposit, ExistentialDeposits,
-        GetNativeCurrencyId, MaxLocks, MaxReserves, MinimumPeriod,
+        BlockHashCount, CombinatorialTokensMaxRedeemSteps, CombinatorialTokensPalletId,
+        ExistentialDeposit, ExistentialDeposits, GetNativeCurrencyId, MaxLocks, MaxReserves,
+        MinimumPeriod,
     },
     types::{
         AccountIdTest, Amount, Balance, BasicCurrencyAdapter, CurrencyId, Hash, MarketId, Moment,
@@ -61,7 +62,9 @@ impl zrml_combinatorial_tokens::Config for Runtime {
     type BenchmarkHelper = BenchmarkHelper;
     type CombinatorialIdManager = CryptographicIdManager<MarketId, Blake2_256>;
     type Fuel = Fuel;
+    type LegacyMarketAccount = MockMarketAccount;
     type MarketCommons = MarketCommons;
+    type MaxRedeemSteps = CombinatorialTokensMaxRedeemSteps;
     type MultiCurrency = Currencies;
     type Payout = MockPayout;
     type RuntimeEvent = RuntimeEvent;
@@ -0,0 +1,30 @@
+// Copyright 2024-2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use sp_runtime::traits::Convert;
+use zeitgeist_primitives::types::{AccountIdTest, MarketId};
+
+/// Offset which separates the mock market accounts from the accounts used in tests.
+const MARKET_ACCOUNT_OFFSET: AccountIdTest = 1_000_000;
+
+pub struct MockMarketAccount;
+
+impl Convert<MarketId, AccountIdTest> for MockMarketAccount {
+    fn convert(market_id: MarketId) -> AccountIdTest {
+        MARKET_ACCOUNT_OFFSET + market_id
+    }
+}
@@ -17,8 +17,10 @@
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmark_helper;
+mod market_account;
 mod payout;
 
 #[cfg(feature = "runtime-benchmarks")]
 pub(crate) use benchmark_helper::BenchmarkHelper;
+pub use market_account::MockMarketAccount;
 pub use payout::MockPayout;
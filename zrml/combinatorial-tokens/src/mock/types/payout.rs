@@ -15,7 +15,7 @@
 // You should have received a copy of the GNU General Public License
 // along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
 
-use alloc::vec;
+use alloc::{collections::BTreeMap, vec};
 use core::cell::RefCell;
 use zeitgeist_primitives::{
     traits::PayoutApi,
@@ -29,6 +29,11 @@ impl MockPayout {
         PAYOUT_VECTOR_RETURN_VALUE.with(|v| *v.borrow_mut() = Some(value));
     }
 
+    /// Overrides the return value configured using `set_return_value` for `market_id`.
+    pub fn set_return_value_for(market_id: MarketId, value: Option<Vec<Balance>>) {
+        PAYOUT_VECTOR_RETURN_VALUES.with(|v| v.borrow_mut().insert(market_id, value));
+    }
+
     pub fn not_called() -> bool {
         PAYOUT_VECTOR_CALL_DATA.with(|values| values.borrow().is_empty())
     }
@@ -52,6 +57,12 @@ impl PayoutApi for MockPayout {
     fn payout_vector(market_id: Self::MarketId) -> Option<Vec<Self::Balance>> {
         PAYOUT_VECTOR_CALL_DATA.with(|values| values.borrow_mut().push(market_id));
 
+        if let Some(value) =
+            PAYOUT_VECTOR_RETURN_VALUES.with(|values| values.borrow().get(&market_id).cloned())
+        {
+            return value;
+        }
+
         PAYOUT_VECTOR_RETURN_VALUE
             .with(|value| value.borrow().clone())
             .expect("MockPayout: No return value configured")
@@ -61,4 +72,5 @@ impl PayoutApi for MockPayout {
 thread_local! {
     pub static PAYOUT_VECTOR_CALL_DATA: RefCell<Vec<MarketId>> = const { RefCell::new(vec![]) };
     pub static PAYOUT_VECTOR_RETURN_VALUE: RefCell<Option<Option<Vec<Balance>>>> = const { RefCell::new(None) };
+    pub static PAYOUT_VECTOR_RETURN_VALUES: RefCell<BTreeMap<MarketId, Option<Vec<Balance>>>> = const { RefCell::new(BTreeMap::new()) };
 }
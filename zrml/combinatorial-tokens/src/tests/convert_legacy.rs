@@ -0,0 +1,346 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::LegacyConversions;
+use sp_runtime::traits::Convert;
+use zeitgeist_primitives::types::ScalarPosition;
+
+fn position(market_id: MarketId, index_set: Vec<bool>) -> Asset<MarketId> {
+    CombinatorialTokens::position_from_parent_collection(
+        None,
+        market_id,
+        index_set,
+        Fuel::new(16, false),
+    )
+    .unwrap()
+}
+
+fn market_account(market_id: MarketId) -> AccountIdTest {
+    <Runtime as crate::Config>::LegacyMarketAccount::convert(market_id)
+}
+
+#[test]
+fn convert_from_legacy_works_with_categorical_outcome() {
+    ExtBuilder::build().execute_with(|| {
+        let market_id = create_market(Asset::Ztg, MarketType::Categorical(3));
+        let asset_in = Asset::CategoricalOutcome(market_id, 1);
+        let alice = Account::new(0).deposit(asset_in, _5).unwrap();
+        let asset_out = position(market_id, vec![B0, B1, B0]);
+
+        assert_ok!(CombinatorialTokens::convert_from_legacy(
+            alice.signed(),
+            asset_in,
+            _3,
+            Fuel::new(16, false),
+        ));
+
+        assert_eq!(alice.free_balance(asset_in), _2);
+        assert_eq!(alice.free_balance(asset_out), _3);
+        assert_eq!(LegacyConversions::<Runtime>::get(market_id).into_inner(), vec![0, _3, 0]);
+        let CombinatorialToken(position_id) = asset_out else { panic!("unexpected asset") };
+        assert!(crate::Positions::<Runtime>::get(position_id).is_some());
+        System::assert_last_event(
+            Event::<Runtime>::ConvertedFromLegacy {
+                who: alice.id,
+                asset_in,
+                asset_out,
+                amount: _3,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn convert_from_legacy_maps_scalar_positions_to_long_and_short() {
+    ExtBuilder::build().execute_with(|| {
+        let market_id = create_market(Asset::Ztg, MarketType::Scalar(0..=100));
+        let long = Asset::ScalarOutcome(market_id, ScalarPosition::Long);
+        let short = Asset::ScalarOutcome(market_id, ScalarPosition::Short);
+        let alice = Account::new(0).deposit(long, _2).unwrap().deposit(short, _3).unwrap();
+        assert_ok!(Currencies::deposit(Asset::Ztg, &market_account(market_id), _3));
+
+        assert_ok!(CombinatorialTokens::convert_from_legacy(
+            alice.signed(),
+            long,
+            _2,
+            Fuel::new(16, false),
+        ));
+        assert_ok!(CombinatorialTokens::convert_from_legacy(
+            alice.signed(),
+            short,
+            _3,
+            Fuel::new(16, false),
+        ));
+
+        assert_eq!(alice.free_balance(position(market_id, vec![B1, B0])), _2);
+        assert_eq!(alice.free_balance(position(market_id, vec![B0, B1])), _3);
+        assert_eq!(LegacyConversions::<Runtime>::get(market_id).into_inner(), vec![_2, _3]);
+    });
+}
+
+#[test]
+fn convert_to_legacy_reverses_conversion() {
+    ExtBuilder::build().execute_with(|| {
+        let market_id = create_market(Asset::Ztg, MarketType::Categorical(2));
+        let legacy = Asset::CategoricalOutcome(market_id, 0);
+        let alice = Account::new(0).deposit(legacy, _5).unwrap();
+        let combinatorial = position(market_id, vec![B1, B0]);
+
+        assert_ok!(CombinatorialTokens::convert_from_legacy(
+            alice.signed(),
+            legacy,
+            _5,
+            Fuel::new(16, false),
+        ));
+        assert_ok!(CombinatorialTokens::convert_to_legacy(
+            alice.signed(),
+            legacy,
+            _2,
+            Fuel::new(16, false),
+        ));
+
+        assert_eq!(alice.free_balance(legacy), _2);
+        assert_eq!(alice.free_balance(combinatorial), _3);
+        assert_eq!(LegacyConversions::<Runtime>::get(market_id).into_inner(), vec![_3, 0]);
+        System::assert_last_event(
+            Event::<Runtime>::ConvertedToLegacy {
+                who: alice.id,
+                asset_in: combinatorial,
+                asset_out: legacy,
+                amount: _2,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn convert_to_legacy_fails_on_tokens_which_were_not_converted() {
+    ExtBuilder::build().execute_with(|| {
+        let alice = Account::new(0).deposit(Asset::Ztg, _10).unwrap();
+        let market_id = create_market(Asset::Ztg, MarketType::Categorical(2));
+        let legacy = Asset::CategoricalOutcome(market_id, 0);
+
+        // Tokens obtained by splitting collateral are backed by the pallet account and can't be
+        // converted into legacy outcome tokens.
+        assert_ok!(CombinatorialTokens::split_position(
+            alice.signed(),
+            None,
+            market_id,
+            Partition::IndexSets(vec![vec![B1, B0], vec![B0, B1]]),
+            _3,
+            Fuel::new(16, false),
+        ));
+        assert_noop!(
+            CombinatorialTokens::convert_to_legacy(
+                alice.signed(),
+                legacy,
+                _1,
+                Fuel::new(16, false),
+            ),
+            Error::<Runtime>::InsufficientConvertedBalance
+        );
+    });
+}
+
+#[test]
+fn convert_from_legacy_fails_on_non_legacy_asset() {
+    ExtBuilder::build().execute_with(|| {
+        let alice = Account::new(0).deposit(Asset::Ztg, _10).unwrap();
+        assert_noop!(
+            CombinatorialTokens::convert_from_legacy(
+                alice.signed(),
+                Asset::Ztg,
+                _1,
+                Fuel::new(16, false),
+            ),
+            Error::<Runtime>::NotLegacyOutcome
+        );
+    });
+}
+
+#[test]
+fn convert_from_legacy_fails_on_resolved_market() {
+    ExtBuilder::build().execute_with(|| {
+        let market_id = create_market(Asset::Ztg, MarketType::Categorical(2));
+        let legacy = Asset::CategoricalOutcome(market_id, 0);
+        let alice = Account::new(0).deposit(legacy, _1).unwrap();
+        assert_ok!(MarketCommons::mutate_market(&market_id, |market| {
+            market.status = MarketStatus::Resolved;
+            Ok(())
+        }));
+        assert_noop!(
+            CombinatorialTokens::convert_from_legacy(
+                alice.signed(),
+                legacy,
+                _1,
+                Fuel::new(16, false),
+            ),
+            Error::<Runtime>::MarketAlreadyResolved
+        );
+    });
+}
+
+#[test]
+fn convert_from_legacy_fails_on_insufficient_balance() {
+    ExtBuilder::build().execute_with(|| {
+        let market_id = create_market(Asset::Ztg, MarketType::Categorical(2));
+        let legacy = Asset::CategoricalOutcome(market_id, 0);
+        let alice = Account::new(0).deposit(legacy, _1).unwrap();
+        assert_noop!(
+            CombinatorialTokens::convert_from_legacy(
+                alice.signed(),
+                legacy,
+                _2,
+                Fuel::new(16, false),
+            ),
+            orml_tokens::Error::<Runtime>::BalanceTooLow
+        );
+    });
+}
+
+#[test]
+fn redeem_position_settles_legacy_collateral() {
+    ExtBuilder::build().execute_with(|| {
+        let market_id = create_market(Asset::Ztg, MarketType::Categorical(2));
+        let outcome_0 = Asset::CategoricalOutcome(market_id, 0);
+        let outcome_1 = Asset::CategoricalOutcome(market_id, 1);
+        // Alice holds a legacy complete set which is backed by collateral in the market account.
+        let alice = Account::new(0).deposit(outcome_0, _4).unwrap().deposit(outcome_1, _4).unwrap();
+        assert_ok!(Currencies::deposit(Asset::Ztg, &market_account(market_id), _4));
+
+        assert_ok!(CombinatorialTokens::convert_from_legacy(
+            alice.signed(),
+            outcome_0,
+            _4,
+            Fuel::new(16, false),
+        ));
+        assert_ok!(CombinatorialTokens::convert_from_legacy(
+            alice.signed(),
+            outcome_1,
+            _1,
+            Fuel::new(16, false),
+        ));
+
+        // The collateral backing the converted complete set has been moved into the pallet
+        // account.
+        assert_eq!(Currencies::free_balance(Asset::Ztg, &Pallet::<Runtime>::account_id()), _1);
+        assert_eq!(Currencies::free_balance(Asset::Ztg, &market_account(market_id)), _3);
+
+        MockPayout::set_return_value(Some(vec![_3_4, _1_4]));
+        assert_ok!(CombinatorialTokens::redeem_position(
+            alice.signed(),
+            None,
+            market_id,
+            IndexSet::Outcomes(vec![B1, B0]),
+            Fuel::new(16, false),
+        ));
+
+        assert_eq!(alice.free_balance(Asset::Ztg), _3);
+        // The collateral backing the converted tokens has been moved into the pallet account,
+        // where it backs the remaining `_1` units of the second outcome's combinatorial token.
+        assert_eq!(Currencies::free_balance(Asset::Ztg, &Pallet::<Runtime>::account_id()), _1_4);
+        assert!(LegacyConversions::<Runtime>::get(market_id).is_empty());
+        // The market account still backs the remaining `_3` units of the legacy second outcome.
+        assert_eq!(alice.free_balance(outcome_1), _3);
+        assert_eq!(Currencies::free_balance(Asset::Ztg, &market_account(market_id)), _3_4);
+    });
+}
+
+#[test]
+fn converted_complete_sets_are_backed_by_pallet_account() {
+    ExtBuilder::build().execute_with(|| {
+        let market_id = create_market(Asset::Ztg, MarketType::Categorical(2));
+        let outcome_0 = Asset::CategoricalOutcome(market_id, 0);
+        let outcome_1 = Asset::CategoricalOutcome(market_id, 1);
+        let alice = Account::new(0).deposit(outcome_0, _3).unwrap().deposit(outcome_1, _3).unwrap();
+        let pallet = Account::new(Pallet::<Runtime>::account_id());
+        assert_ok!(Currencies::deposit(Asset::Ztg, &market_account(market_id), _3));
+
+        assert_ok!(CombinatorialTokens::convert_from_legacy(
+            alice.signed(),
+            outcome_0,
+            _3,
+            Fuel::new(16, false),
+        ));
+        // An incomplete set still backs the legacy tokens of the other outcome.
+        assert_eq!(pallet.free_balance(Asset::Ztg), 0);
+        assert_ok!(CombinatorialTokens::convert_from_legacy(
+            alice.signed(),
+            outcome_1,
+            _2,
+            Fuel::new(16, false),
+        ));
+        assert_eq!(pallet.free_balance(Asset::Ztg), _2);
+        assert_eq!(Currencies::free_balance(Asset::Ztg, &market_account(market_id)), _1);
+
+        // Breaking up a set moves its collateral back into the market account.
+        assert_ok!(CombinatorialTokens::convert_to_legacy(
+            alice.signed(),
+            outcome_1,
+            _1,
+            Fuel::new(16, false),
+        ));
+        assert_eq!(pallet.free_balance(Asset::Ztg), _1);
+        assert_eq!(Currencies::free_balance(Asset::Ztg, &market_account(market_id)), _2);
+
+        // Merging the converted set pays out the collateral moved into the pallet account.
+        assert_ok!(CombinatorialTokens::merge_position(
+            alice.signed(),
+            None,
+            market_id,
+            Partition::IndexSets(vec![vec![B1, B0], vec![B0, B1]]),
+            _1,
+            Fuel::new(16, false),
+        ));
+        assert_eq!(alice.free_balance(Asset::Ztg), _1);
+        assert_eq!(pallet.free_balance(Asset::Ztg), 0);
+        // The market account still backs Alice's remaining legacy tokens.
+        assert_eq!(alice.free_balance(outcome_1), _2);
+        assert_eq!(Currencies::free_balance(Asset::Ztg, &market_account(market_id)), _2);
+    });
+}
+
+#[test]
+fn redeem_position_fails_if_market_account_cannot_back_conversions() {
+    ExtBuilder::build().execute_with(|| {
+        let market_id = create_market(Asset::Ztg, MarketType::Categorical(2));
+        let outcome_0 = Asset::CategoricalOutcome(market_id, 0);
+        let alice = Account::new(0).deposit(outcome_0, _2).unwrap();
+
+        assert_ok!(CombinatorialTokens::convert_from_legacy(
+            alice.signed(),
+            outcome_0,
+            _2,
+            Fuel::new(16, false),
+        ));
+
+        // The market account holds no collateral, so the shortfall surfaces as an error instead
+        // of being paid from the collateral of other markets held by the pallet account.
+        MockPayout::set_return_value(Some(vec![_1, 0]));
+        assert!(CombinatorialTokens::redeem_position(
+            alice.signed(),
+            None,
+            market_id,
+            IndexSet::Outcomes(vec![B1, B0]),
+            Fuel::new(16, false),
+        )
+        .is_err());
+    });
+}
@@ -40,7 +40,7 @@ fn split_followed_by_merge_vertical_no_parent() {
             alice.signed(),
             None,
             market_id,
-            partition.clone(),
+            Partition::IndexSets(partition.clone()),
             amount,
             Fuel::new(16, false),
         ));
@@ -53,7 +53,7 @@ fn split_followed_by_merge_vertical_no_parent() {
             alice.signed(),
             None,
             market_id,
-            partition,
+            Partition::IndexSets(partition),
             amount,
             Fuel::new(16, false),
         ));
@@ -94,7 +94,7 @@ fn split_followed_by_merge_vertical_with_parent() {
             alice.signed(),
             None,
             parent_market_id,
-            parent_partition.clone(),
+            Partition::IndexSets(parent_partition.clone()),
             parent_amount,
             Fuel::new(16, false),
         ));
@@ -111,7 +111,7 @@ fn split_followed_by_merge_vertical_with_parent() {
             alice.signed(),
             Some(parent_collection_id),
             child_market_id,
-            child_partition.clone(),
+            Partition::IndexSets(child_partition.clone()),
             child_amount,
             Fuel::new(16, false),
         ));
@@ -126,7 +126,7 @@ fn split_followed_by_merge_vertical_with_parent() {
             alice.signed(),
             Some(parent_collection_id),
             child_market_id,
-            child_partition,
+            Partition::IndexSets(child_partition),
             child_amount,
             Fuel::new(16, false),
         ));
@@ -141,7 +141,7 @@ fn split_followed_by_merge_vertical_with_parent() {
             alice.signed(),
             None,
             parent_market_id,
-            parent_partition,
+            Partition::IndexSets(parent_partition),
             parent_amount,
             Fuel::new(16, false),
         ));
@@ -223,7 +223,7 @@ fn split_followed_by_merge_vertical_with_parent_in_opposite_order() {
             alice.signed(),
             None,
             market_0,
-            partition_0.clone(),
+            Partition::IndexSets(partition_0.clone()),
             amount,
             Fuel::new(16, false),
         ));
@@ -233,7 +233,7 @@ fn split_followed_by_merge_vertical_with_parent_in_opposite_order() {
             alice.signed(),
             Some(id_001),
             market_1,
-            partition_1.clone(),
+            Partition::IndexSets(partition_1.clone()),
             amount,
             Fuel::new(16, false),
         ));
@@ -243,7 +243,7 @@ fn split_followed_by_merge_vertical_with_parent_in_opposite_order() {
             alice.signed(),
             Some(id_110),
             market_1,
-            partition_1.clone(),
+            Partition::IndexSets(partition_1.clone()),
             amount,
             Fuel::new(16, false),
         ));
@@ -263,7 +263,7 @@ fn split_followed_by_merge_vertical_with_parent_in_opposite_order() {
             alice.signed(),
             Some(id_1100),
             market_0,
-            partition_0.clone(),
+            Partition::IndexSets(partition_0.clone()),
             amount,
             Fuel::new(16, false),
         ));
@@ -283,7 +283,7 @@ fn split_followed_by_merge_vertical_with_parent_in_opposite_order() {
             alice.signed(),
             Some(id_0011),
             market_0,
-            partition_0,
+            Partition::IndexSets(partition_0),
             amount,
             Fuel::new(16, false),
         ));
@@ -303,7 +303,7 @@ fn split_followed_by_merge_vertical_with_parent_in_opposite_order() {
             alice.signed(),
             None,
             market_1,
-            partition_1,
+            Partition::IndexSets(partition_1),
             amount,
             Fuel::new(16, false),
         ));
@@ -335,7 +335,7 @@ fn split_vertical_followed_by_horizontal_split_no_parent() {
             alice.signed(),
             None,
             market_id,
-            vec![vec![B0, B0, B1], vec![B1, B1, B0]],
+            Partition::IndexSets(vec![vec![B0, B0, B1], vec![B1, B1, B0]]),
             amount,
             Fuel::new(16, false),
         ));
@@ -343,7 +343,7 @@ fn split_vertical_followed_by_horizontal_split_no_parent() {
             alice.signed(),
             None,
             market_id,
-            vec![vec![B1, B0, B0], vec![B0, B1, B0]],
+            Partition::IndexSets(vec![vec![B1, B0, B0], vec![B0, B1, B0]]),
             amount,
             Fuel::new(16, false),
         ));
@@ -370,7 +370,7 @@ fn split_vertical_followed_by_horizontal_split_no_parent() {
             alice.signed(),
             None,
             market_id,
-            vec![vec![B1, B0, B0], vec![B0, B1, B0], vec![B0, B0, B1]],
+            Partition::IndexSets(vec![vec![B1, B0, B0], vec![B0, B1, B0], vec![B0, B0, B1]]),
             amount,
             Fuel::new(16, false),
         ));
@@ -396,7 +396,7 @@ fn split_vertical_followed_by_horizontal_split_with_parent() {
             alice.signed(),
             None,
             parent_market_id,
-            vec![vec![B0, B0, B1], vec![B1, B1, B0]],
+            Partition::IndexSets(vec![vec![B0, B0, B1], vec![B1, B1, B0]]),
             parent_amount,
             Fuel::new(16, false),
         ));
@@ -439,7 +439,7 @@ fn split_vertical_followed_by_horizontal_split_with_parent() {
             alice.signed(),
             Some(parent_collection_id),
             child_market_id,
-            vec![vec![B0, B0, B1, B1], vec![B1, B1, B0, B0]],
+            Partition::IndexSets(vec![vec![B0, B0, B1, B1], vec![B1, B1, B0, B0]]),
             child_amount_first_pass,
             Fuel::new(16, false),
         ));
@@ -447,7 +447,7 @@ fn split_vertical_followed_by_horizontal_split_with_parent() {
             alice.signed(),
             Some(parent_collection_id),
             child_market_id,
-            vec![vec![B1, B0, B0, B0], vec![B0, B1, B0, B0]],
+            Partition::IndexSets(vec![vec![B1, B0, B0, B0], vec![B0, B1, B0, B0]]),
             child_amount_first_pass,
             Fuel::new(16, false),
         ));
@@ -467,7 +467,11 @@ fn split_vertical_followed_by_horizontal_split_with_parent() {
             alice.signed(),
             Some(parent_collection_id),
             child_market_id,
-            vec![vec![B1, B0, B0, B0], vec![B0, B1, B0, B0], vec![B0, B0, B1, B1]],
+            Partition::IndexSets(vec![
+                vec![B1, B0, B0, B0],
+                vec![B0, B1, B0, B0],
+                vec![B0, B0, B1, B1]
+            ]),
             child_amount_second_pass,
             Fuel::new(16, false),
         ));
@@ -505,7 +509,7 @@ fn split_horizontal_followed_by_merge_horizontal() {
             alice.signed(),
             None,
             market_id,
-            vec![vec![B0, B0, B1], vec![B1, B1, B0]],
+            Partition::IndexSets(vec![vec![B0, B0, B1], vec![B1, B1, B0]]),
             amount,
             Fuel::new(16, false),
         ));
@@ -514,7 +518,7 @@ fn split_horizontal_followed_by_merge_horizontal() {
             alice.signed(),
             None,
             market_id,
-            vec![vec![B1, B0, B0], vec![B0, B1, B0]],
+            Partition::IndexSets(vec![vec![B1, B0, B0], vec![B0, B1, B0]]),
             amount,
             Fuel::new(16, false),
         ));
@@ -523,7 +527,7 @@ fn split_horizontal_followed_by_merge_horizontal() {
             alice.signed(),
             None,
             market_id,
-            vec![vec![B1, B0, B0], vec![B0, B1, B0]],
+            Partition::IndexSets(vec![vec![B1, B0, B0], vec![B0, B1, B0]]),
             amount,
             Fuel::new(16, false),
         ));
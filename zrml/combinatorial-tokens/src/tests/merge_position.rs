@@ -48,7 +48,7 @@ fn merge_position_works_no_parent(
             alice.signed(),
             parent_collection_id,
             market_id,
-            partition.clone(),
+            Partition::IndexSets(partition.clone()),
             amount,
             Fuel::new(16, false),
         ));
@@ -109,7 +109,7 @@ fn merge_position_works_parent() {
             alice.signed(),
             parent_collection_id,
             market_id,
-            partition.clone(),
+            Partition::IndexSets(partition.clone()),
             amount,
             Fuel::new(16, false),
         ));
@@ -158,7 +158,7 @@ fn merge_position_horizontal_works() {
             alice.signed(),
             None,
             market_id,
-            vec![vec![B0, B1, B0], vec![B1, B0, B0]],
+            Partition::IndexSets(vec![vec![B0, B1, B0], vec![B1, B0, B0]]),
             amount,
             Fuel::new(16, false),
         ));
@@ -179,7 +179,7 @@ fn merge_position_fails_if_market_not_found() {
                 alice.signed(),
                 None,
                 0,
-                vec![vec![B0, B0, B1], vec![B1, B1, B0]],
+                Partition::IndexSets(vec![vec![B0, B0, B1], vec![B1, B1, B0]]),
                 1,
                 Fuel::new(16, false),
             ),
@@ -202,7 +202,7 @@ fn merge_position_fails_on_invalid_partition_length() {
                 alice.signed(),
                 None,
                 market_id,
-                partition,
+                Partition::IndexSets(partition),
                 _1,
                 Fuel::new(16, false)
             ),
@@ -224,7 +224,7 @@ fn merge_position_fails_on_trivial_partition_member() {
                 alice.signed(),
                 None,
                 market_id,
-                partition,
+                Partition::IndexSets(partition),
                 _1,
                 Fuel::new(16, false)
             ),
@@ -246,7 +246,7 @@ fn merge_position_fails_on_overlapping_partition_members() {
                 alice.signed(),
                 None,
                 market_id,
-                partition,
+                Partition::IndexSets(partition),
                 _1,
                 Fuel::new(16, false)
             ),
@@ -267,7 +267,7 @@ fn merge_position_fails_on_insufficient_funds() {
                 alice.signed(),
                 None,
                 market_id,
-                vec![vec![B1, B0, B1], vec![B0, B1, B0]],
+                Partition::IndexSets(vec![vec![B1, B0, B1], vec![B0, B1, B0]]),
                 _100,
                 Fuel::new(16, false),
             ),
@@ -288,7 +288,7 @@ fn merge_position_fails_on_insufficient_funds_foreign_token() {
                 alice.signed(),
                 None,
                 market_id,
-                vec![vec![B1, B0, B1], vec![B0, B1, B0]],
+                Partition::IndexSets(vec![vec![B1, B0, B1], vec![B0, B1, B0]]),
                 _100,
                 Fuel::new(16, false),
             ),
@@ -296,3 +296,56 @@ fn merge_position_fails_on_insufficient_funds_foreign_token() {
         );
     });
 }
+
+#[test]
+fn merge_position_removes_positions_once_all_tokens_are_burned() {
+    ExtBuilder::build().execute_with(|| {
+        let alice = Account::new(0).deposit(Asset::Ztg, _100).unwrap();
+        let market_id = create_market(Asset::Ztg, MarketType::Categorical(3));
+        let partition = vec![vec![B0, B0, B1], vec![B1, B1, B0]];
+        assert_ok!(CombinatorialTokens::split_position(
+            alice.signed(),
+            None,
+            market_id,
+            Partition::IndexSets(partition.clone()),
+            _100,
+            Fuel::new(16, false),
+        ));
+        let position_ids = partition
+            .iter()
+            .map(|index_set| {
+                let position = CombinatorialTokens::position_from_parent_collection(
+                    None,
+                    market_id,
+                    index_set.clone(),
+                    Fuel::new(16, false),
+                )
+                .unwrap();
+                let CombinatorialToken(position_id) = position else { panic!("unexpected asset") };
+                position_id
+            })
+            .collect::<Vec<_>>();
+        let is_recorded =
+            |position_id: &[u8; 32]| crate::Positions::<Runtime>::contains_key(position_id);
+
+        assert_ok!(CombinatorialTokens::merge_position(
+            alice.signed(),
+            None,
+            market_id,
+            Partition::IndexSets(partition.clone()),
+            _30,
+            Fuel::new(16, false),
+        ));
+        assert!(position_ids.iter().all(is_recorded));
+
+        assert_ok!(CombinatorialTokens::merge_position(
+            alice.signed(),
+            None,
+            market_id,
+            Partition::IndexSets(partition),
+            _70,
+            Fuel::new(16, false),
+        ));
+        assert!(!position_ids.iter().any(is_recorded));
+    });
+}
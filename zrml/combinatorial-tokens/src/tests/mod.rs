@@ -17,9 +17,13 @@
 
 #![cfg(all(feature = "mock", test))]
 
+mod convert_legacy;
 mod integration;
 mod merge_position;
+mod position_values;
+mod redeem_all;
 mod redeem_position;
+mod scalar;
 mod split_position;
 
 use crate::{
@@ -28,7 +32,7 @@ use crate::{
         runtime::{CombinatorialTokens, Currencies, MarketCommons, Runtime, RuntimeOrigin, System},
         types::MockPayout,
     },
-    types::cryptographic_id_manager::Fuel,
+    types::{cryptographic_id_manager::Fuel, IndexSet, Partition},
     Error, Event, Pallet,
 };
 use frame_support::{assert_noop, assert_ok};
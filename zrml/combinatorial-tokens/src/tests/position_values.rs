@@ -0,0 +1,181 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::{types::PositionValue, Positions};
+
+#[test]
+fn split_position_records_positions() {
+    ExtBuilder::build().execute_with(|| {
+        let alice = Account::new(0).deposit(Asset::Ztg, _100).unwrap();
+        let market_id = create_market(Asset::Ztg, MarketType::Categorical(3));
+        let partition = vec![vec![B1, B0, B0], vec![B0, B1, B1]];
+        assert_ok!(CombinatorialTokens::split_position(
+            alice.signed(),
+            None,
+            market_id,
+            Partition::IndexSets(partition.clone()),
+            _1,
+            Fuel::new(16, false),
+        ));
+
+        for index_set in partition {
+            let position = CombinatorialTokens::position_from_parent_collection(
+                None,
+                market_id,
+                index_set.clone(),
+                Fuel::new(16, false),
+            )
+            .unwrap();
+            let CombinatorialToken(position_id) = position else { panic!("unexpected asset") };
+            let position_info = Positions::<Runtime>::get(position_id).unwrap();
+            assert_eq!(position_info.parent_collection_id, None);
+            assert_eq!(position_info.market_id, market_id);
+            assert_eq!(position_info.index_set.into_inner(), index_set);
+        }
+    });
+}
+
+#[test]
+fn merge_position_records_horizontally_merged_position() {
+    ExtBuilder::build().execute_with(|| {
+        let alice = Account::new(0).deposit(Asset::Ztg, _100).unwrap();
+        let market_id = create_market(Asset::Ztg, MarketType::Categorical(3));
+        assert_ok!(CombinatorialTokens::split_position(
+            alice.signed(),
+            None,
+            market_id,
+            Partition::IndexSets(vec![vec![B1, B0, B0], vec![B0, B1, B0], vec![B0, B0, B1]]),
+            _1,
+            Fuel::new(16, false),
+        ));
+        assert_ok!(CombinatorialTokens::merge_position(
+            alice.signed(),
+            None,
+            market_id,
+            Partition::IndexSets(vec![vec![B0, B1, B0], vec![B0, B0, B1]]),
+            _1,
+            Fuel::new(16, false),
+        ));
+
+        let position = CombinatorialTokens::position_from_parent_collection(
+            None,
+            market_id,
+            vec![B0, B1, B1],
+            Fuel::new(16, false),
+        )
+        .unwrap();
+        let CombinatorialToken(position_id) = position else { panic!("unexpected asset") };
+        let position_info = Positions::<Runtime>::get(position_id).unwrap();
+        assert_eq!(position_info.index_set.into_inner(), vec![B0, B1, B1]);
+    });
+}
+
+#[test]
+fn position_values_returns_held_positions_with_redeemable_value() {
+    ExtBuilder::build().execute_with(|| {
+        let alice = Account::new(0).deposit(Asset::Ztg, _100).unwrap();
+        let market_id = create_market(Asset::Ztg, MarketType::Categorical(3));
+        let partition = vec![vec![B1, B0, B0], vec![B0, B1, B1]];
+        assert_ok!(CombinatorialTokens::split_position(
+            alice.signed(),
+            None,
+            market_id,
+            Partition::IndexSets(partition.clone()),
+            _3,
+            Fuel::new(16, false),
+        ));
+        let positions = partition
+            .iter()
+            .map(|index_set| {
+                CombinatorialTokens::position_from_parent_collection(
+                    None,
+                    market_id,
+                    index_set.clone(),
+                    Fuel::new(16, false),
+                )
+                .unwrap()
+            })
+            .collect::<Vec<_>>();
+        let position_ids = positions
+            .iter()
+            .map(|position| match position {
+                CombinatorialToken(position_id) => *position_id,
+                _ => panic!("unexpected asset"),
+            })
+            .collect::<Vec<_>>();
+        // An unknown position is skipped.
+        let mut queried = position_ids.clone();
+        queried.push([0; 32]);
+
+        MockPayout::set_return_value(Some(vec![_1_4, _3_4, 0]));
+        let expected = vec![
+            PositionValue {
+                position: positions[0],
+                parent_collection_id: None,
+                market_id,
+                index_set: partition[0].clone(),
+                scalar_position: None,
+                amount: _3,
+                redeemable: Some((Asset::Ztg, _3_4)),
+            },
+            PositionValue {
+                position: positions[1],
+                parent_collection_id: None,
+                market_id,
+                index_set: partition[1].clone(),
+                scalar_position: None,
+                amount: _3,
+                redeemable: Some((Asset::Ztg, _2 + _1_4)),
+            },
+        ];
+        assert_eq!(CombinatorialTokens::position_values(&alice.id, queried), expected);
+
+        // Positions held by other accounts are skipped.
+        assert_eq!(CombinatorialTokens::position_values(&1, position_ids), vec![]);
+    });
+}
+
+#[test]
+fn position_values_returns_no_redeemable_value_if_market_is_not_resolved() {
+    ExtBuilder::build().execute_with(|| {
+        let alice = Account::new(0).deposit(Asset::Ztg, _100).unwrap();
+        let market_id = create_market(Asset::Ztg, MarketType::Categorical(2));
+        assert_ok!(CombinatorialTokens::split_position(
+            alice.signed(),
+            None,
+            market_id,
+            Partition::IndexSets(vec![vec![B1, B0], vec![B0, B1]]),
+            _1,
+            Fuel::new(16, false),
+        ));
+        let position = CombinatorialTokens::position_from_parent_collection(
+            None,
+            market_id,
+            vec![B1, B0],
+            Fuel::new(16, false),
+        )
+        .unwrap();
+        let CombinatorialToken(position_id) = position else { panic!("unexpected asset") };
+
+        MockPayout::set_return_value(None);
+        let values = CombinatorialTokens::position_values(&alice.id, vec![position_id]);
+        assert_eq!(values.len(), 1);
+        assert_eq!(values[0].amount, _1);
+        assert_eq!(values[0].redeemable, None);
+    });
+}
@@ -0,0 +1,219 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use frame_support::traits::Get;
+use zeitgeist_primitives::constants::mock::CombinatorialTokensMaxRedeemSteps;
+
+fn position_id(
+    parent_collection_id: Option<[u8; 32]>,
+    market_id: MarketId,
+    index_set: Vec<bool>,
+) -> [u8; 32] {
+    match CombinatorialTokens::position_from_parent_collection(
+        parent_collection_id,
+        market_id,
+        index_set,
+        Fuel::new(16, false),
+    )
+    .unwrap()
+    {
+        CombinatorialToken(position_id) => position_id,
+        _ => panic!("unexpected asset"),
+    }
+}
+
+/// Splits `amount` units of collateral into `A0`/`A1` and `A0` into `A0 & B0`/`A0 & B1` (where
+/// `A` and `B` are binary markets) and returns the market IDs and the collection ID of `A0`.
+fn setup_nested_positions(alice: &Account, amount: Balance) -> (MarketId, MarketId, [u8; 32]) {
+    let market_a = create_market(Asset::Ztg, MarketType::Categorical(2));
+    let market_b = create_market(Asset::Ztg, MarketType::Categorical(2));
+    assert_ok!(CombinatorialTokens::split_position(
+        alice.signed(),
+        None,
+        market_a,
+        Partition::IndexSets(vec![vec![B1, B0], vec![B0, B1]]),
+        amount,
+        Fuel::new(16, false),
+    ));
+    let cid_a0 = CombinatorialTokens::collection_id_from_parent_collection(
+        None,
+        market_a,
+        vec![B1, B0],
+        Fuel::new(16, false),
+    )
+    .unwrap();
+    assert_ok!(CombinatorialTokens::split_position(
+        alice.signed(),
+        Some(cid_a0),
+        market_b,
+        Partition::IndexSets(vec![vec![B1, B0], vec![B0, B1]]),
+        amount,
+        Fuel::new(16, false),
+    ));
+
+    (market_a, market_b, cid_a0)
+}
+
+#[test]
+fn redeem_all_walks_up_nested_positions() {
+    ExtBuilder::build().execute_with(|| {
+        let alice = Account::new(0).deposit(Asset::Ztg, _100).unwrap();
+        let (market_a, market_b, cid_a0) = setup_nested_positions(&alice, _3);
+        let a0 = position_id(None, market_a, vec![B1, B0]);
+        let a0_b0 = position_id(Some(cid_a0), market_b, vec![B1, B0]);
+        let a0_b1 = position_id(Some(cid_a0), market_b, vec![B0, B1]);
+
+        // Both markets resolve to their first outcome.
+        MockPayout::set_return_value(Some(vec![_1, 0]));
+        assert_ok!(CombinatorialTokens::redeem_all(alice.signed(), vec![a0_b0, a0_b1], 16));
+
+        assert_eq!(alice.free_balance(CombinatorialToken(a0_b0)), 0);
+        assert_eq!(alice.free_balance(CombinatorialToken(a0_b1)), 0);
+        assert_eq!(alice.free_balance(CombinatorialToken(a0)), 0);
+        assert_eq!(alice.free_balance(Asset::Ztg), _100);
+        System::assert_last_event(
+            Event::<Runtime>::PositionsRedeemed {
+                who: alice.id,
+                assets_in: vec![
+                    (CombinatorialToken(a0_b0), _3),
+                    (CombinatorialToken(a0), _3),
+                    (CombinatorialToken(a0_b1), _3),
+                ],
+                assets_out: vec![
+                    (CombinatorialToken(a0), _3),
+                    (Asset::Ztg, _3),
+                    (CombinatorialToken(a0), 0),
+                ],
+                merged: vec![],
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn redeem_all_stops_when_steps_run_out() {
+    ExtBuilder::build().execute_with(|| {
+        let alice = Account::new(0).deposit(Asset::Ztg, _100).unwrap();
+        let (market_a, market_b, cid_a0) = setup_nested_positions(&alice, _3);
+        let a0 = position_id(None, market_a, vec![B1, B0]);
+        let a0_b0 = position_id(Some(cid_a0), market_b, vec![B1, B0]);
+        let a0_b1 = position_id(Some(cid_a0), market_b, vec![B0, B1]);
+
+        MockPayout::set_return_value(Some(vec![_1_4, _3_4]));
+        assert_ok!(CombinatorialTokens::redeem_all(alice.signed(), vec![a0_b0, a0_b1], 1));
+
+        assert_eq!(alice.free_balance(CombinatorialToken(a0_b0)), 0);
+        assert_eq!(alice.free_balance(CombinatorialToken(a0_b1)), _3);
+        assert_eq!(alice.free_balance(CombinatorialToken(a0)), _3_4);
+        assert_eq!(alice.free_balance(Asset::Ztg), _100 - _3);
+        System::assert_last_event(
+            Event::<Runtime>::PositionsRedeemed {
+                who: alice.id,
+                assets_in: vec![(CombinatorialToken(a0_b0), _3)],
+                assets_out: vec![(CombinatorialToken(a0), _3_4)],
+                merged: vec![],
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn redeem_all_skips_positions_of_unresolved_markets() {
+    ExtBuilder::build().execute_with(|| {
+        let alice = Account::new(0).deposit(Asset::Ztg, _100).unwrap();
+        let (_, market_b, cid_a0) = setup_nested_positions(&alice, _3);
+        let a0_b0 = position_id(Some(cid_a0), market_b, vec![B1, B0]);
+
+        MockPayout::set_return_value(None);
+        assert_noop!(
+            CombinatorialTokens::redeem_all(alice.signed(), vec![a0_b0], 16),
+            Error::<Runtime>::NoTokensFound
+        );
+    });
+}
+
+#[test]
+fn redeem_all_fails_if_user_holds_no_tokens() {
+    ExtBuilder::build().execute_with(|| {
+        let alice = Account::new(0).deposit(Asset::Ztg, _100).unwrap();
+        let (_, market_b, cid_a0) = setup_nested_positions(&alice, _3);
+        let a0_b0 = position_id(Some(cid_a0), market_b, vec![B1, B0]);
+        let bob = Account::new(1);
+
+        MockPayout::set_return_value(Some(vec![_1, 0]));
+        assert_noop!(
+            CombinatorialTokens::redeem_all(bob.signed(), vec![a0_b0], 16),
+            Error::<Runtime>::NoTokensFound
+        );
+    });
+}
+
+#[test]
+fn redeem_all_merges_complete_sets_of_unresolved_markets() {
+    ExtBuilder::build().execute_with(|| {
+        let alice = Account::new(0).deposit(Asset::Ztg, _100).unwrap();
+        let (market_a, market_b, cid_a0) = setup_nested_positions(&alice, _3);
+        let a0 = position_id(None, market_a, vec![B1, B0]);
+        let a0_b0 = position_id(Some(cid_a0), market_b, vec![B1, B0]);
+        let a0_b1 = position_id(Some(cid_a0), market_b, vec![B0, B1]);
+
+        // `A` resolves to its first outcome, `B` is unresolved.
+        MockPayout::set_return_value_for(market_a, Some(vec![_1, 0]));
+        MockPayout::set_return_value_for(market_b, None);
+        assert_ok!(CombinatorialTokens::redeem_all(alice.signed(), vec![a0_b0, a0_b1], 16));
+
+        assert_eq!(alice.free_balance(CombinatorialToken(a0_b0)), 0);
+        assert_eq!(alice.free_balance(CombinatorialToken(a0_b1)), 0);
+        assert_eq!(alice.free_balance(CombinatorialToken(a0)), 0);
+        assert_eq!(alice.free_balance(Asset::Ztg), _100);
+        System::assert_last_event(
+            Event::<Runtime>::PositionsRedeemed {
+                who: alice.id,
+                assets_in: vec![(CombinatorialToken(a0), _3)],
+                assets_out: vec![(Asset::Ztg, _3)],
+                merged: vec![(
+                    vec![CombinatorialToken(a0_b1), CombinatorialToken(a0_b0)],
+                    CombinatorialToken(a0),
+                    _3,
+                )],
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn redeem_all_fails_if_max_steps_exceeds_limit() {
+    ExtBuilder::build().execute_with(|| {
+        let alice = Account::new(0).deposit(Asset::Ztg, _100).unwrap();
+        let (_, market_b, cid_a0) = setup_nested_positions(&alice, _3);
+        let a0_b0 = position_id(Some(cid_a0), market_b, vec![B1, B0]);
+
+        MockPayout::set_return_value(Some(vec![_1, 0]));
+        assert_noop!(
+            CombinatorialTokens::redeem_all(
+                alice.signed(),
+                vec![a0_b0],
+                CombinatorialTokensMaxRedeemSteps::get() + 1
+            ),
+            Error::<Runtime>::TooManySteps
+        );
+    });
+}
@@ -29,7 +29,7 @@ fn redeem_position_fails_on_no_payout_vector() {
                 alice.signed(),
                 None,
                 market_id,
-                vec![],
+                IndexSet::Outcomes(vec![]),
                 Fuel::new(16, false)
             ),
             Error::<Runtime>::PayoutVectorNotFound
@@ -48,7 +48,7 @@ fn redeem_position_fails_on_market_not_found() {
                 alice.signed(),
                 None,
                 0,
-                vec![],
+                IndexSet::Outcomes(vec![]),
                 Fuel::new(16, false)
             ),
             zrml_market_commons::Error::<Runtime>::MarketDoesNotExist
@@ -69,7 +69,7 @@ fn redeem_position_fails_on_incorrect_index_set(index_set: Vec<bool>) {
                 alice.signed(),
                 None,
                 market_id,
-                index_set,
+                IndexSet::Outcomes(index_set),
                 Fuel::new(16, false)
             ),
             Error::<Runtime>::InvalidIndexSet
@@ -89,7 +89,7 @@ fn redeem_position_fails_if_tokens_have_no_value() {
                 alice.signed(),
                 None,
                 market_id,
-                index_set,
+                IndexSet::Outcomes(index_set),
                 Fuel::new(16, false)
             ),
             Error::<Runtime>::TokenHasNoValue
@@ -109,7 +109,7 @@ fn redeem_position_fails_if_user_holds_no_winning_tokens() {
                 alice.signed(),
                 None,
                 market_id,
-                index_set,
+                IndexSet::Outcomes(index_set),
                 Fuel::new(16, false)
             ),
             Error::<Runtime>::NoTokensFound,
@@ -138,7 +138,7 @@ fn redeem_position_works_sans_parent() {
             alice.signed(),
             parent_collection_id,
             market_id,
-            index_set.clone(),
+            IndexSet::Outcomes(index_set.clone()),
             Fuel::new(16, false),
         ));
 
@@ -195,7 +195,7 @@ fn redeem_position_works_with_parent() {
             alice.signed(),
             parent_collection_id,
             market_id,
-            index_set.clone(),
+            IndexSet::Outcomes(index_set.clone()),
             Fuel::new(16, false),
         ));
 
@@ -220,3 +220,38 @@ fn redeem_position_works_with_parent() {
         assert!(MockPayout::called_once_with(market_id));
     });
 }
+
+#[test]
+fn redeem_position_removes_position_once_all_tokens_are_burned() {
+    ExtBuilder::build().execute_with(|| {
+        let alice = Account::new(0).deposit(Asset::Ztg, _3).unwrap();
+        let market_id = create_market(Asset::Ztg, MarketType::Categorical(3));
+        let partition = vec![vec![B0, B0, B1], vec![B1, B1, B0]];
+        assert_ok!(CombinatorialTokens::split_position(
+            alice.signed(),
+            None,
+            market_id,
+            Partition::IndexSets(partition),
+            _3,
+            Fuel::new(16, false),
+        ));
+        let ct_110 = CombinatorialToken([
+            101, 210, 61, 196, 5, 247, 150, 41, 186, 49, 11, 63, 139, 53, 25, 65, 161, 83, 24, 142,
+            225, 102, 57, 241, 199, 18, 226, 137, 68, 3, 219, 131,
+        ]);
+        let CombinatorialToken(position_id) = ct_110 else { panic!("unexpected asset") };
+        assert!(crate::Positions::<Runtime>::contains_key(position_id));
+
+        MockPayout::set_return_value(Some(vec![_1_4, _1_2, _1_4]));
+        assert_ok!(CombinatorialTokens::redeem_position(
+            alice.signed(),
+            None,
+            market_id,
+            IndexSet::Outcomes(vec![B1, B1, B0]),
+            Fuel::new(16, false),
+        ));
+
+        assert_eq!(alice.free_balance(ct_110), 0);
+        assert!(!crate::Positions::<Runtime>::contains_key(position_id));
+    });
+}
@@ -0,0 +1,306 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use zeitgeist_primitives::{constants::CENT, types::ScalarPosition};
+
+fn scalar_position(
+    parent_collection_id: Option<[u8; 32]>,
+    market_id: MarketId,
+    scalar_position: ScalarPosition,
+) -> Asset<MarketId> {
+    CombinatorialTokens::scalar_position_from_parent_collection(
+        parent_collection_id,
+        market_id,
+        scalar_position,
+        Fuel::new(16, false),
+    )
+    .unwrap()
+}
+
+#[test]
+fn scalar_position_from_parent_collection_matches_index_set() {
+    ExtBuilder::build().execute_with(|| {
+        let market_id = create_market(Asset::Ztg, MarketType::Scalar(0..=100));
+        for position in [ScalarPosition::Long, ScalarPosition::Short] {
+            assert_eq!(
+                scalar_position(None, market_id, position),
+                CombinatorialTokens::position_from_parent_collection(
+                    None,
+                    market_id,
+                    position.index_set(),
+                    Fuel::new(16, false),
+                )
+                .unwrap()
+            );
+        }
+    });
+}
+
+#[test]
+fn scalar_position_from_parent_collection_fails_on_categorical_market() {
+    ExtBuilder::build().execute_with(|| {
+        let market_id = create_market(Asset::Ztg, MarketType::Categorical(2));
+        assert_noop!(
+            CombinatorialTokens::scalar_position_from_parent_collection(
+                None,
+                market_id,
+                ScalarPosition::Long,
+                Fuel::new(16, false),
+            ),
+            Error::<Runtime>::NotScalarMarket
+        );
+    });
+}
+
+#[test]
+fn scalar_market_nested_in_categorical_market_redeems_non_binary_payout() {
+    ExtBuilder::build().execute_with(|| {
+        let alice = Account::new(0).deposit(Asset::Ztg, _100).unwrap();
+        let pallet = Account::new(Pallet::<Runtime>::account_id());
+        let categorical_market_id = create_market(Asset::Ztg, MarketType::Categorical(2));
+        let scalar_market_id = create_market(Asset::Ztg, MarketType::Scalar(0..=100));
+        let amount = _10;
+
+        assert_ok!(CombinatorialTokens::split_position(
+            alice.signed(),
+            None,
+            categorical_market_id,
+            Partition::IndexSets(vec![vec![B1, B0], vec![B0, B1]]),
+            amount,
+            Fuel::new(16, false),
+        ));
+        let cid_10 = CombinatorialTokens::collection_id_from_parent_collection(
+            None,
+            categorical_market_id,
+            vec![B1, B0],
+            Fuel::new(16, false),
+        )
+        .unwrap();
+        let ct_10 = CombinatorialTokens::position_from_collection_id(categorical_market_id, cid_10)
+            .unwrap();
+        assert_ok!(CombinatorialTokens::split_position(
+            alice.signed(),
+            Some(cid_10),
+            scalar_market_id,
+            Partition::Scalar,
+            amount,
+            Fuel::new(16, false),
+        ));
+        let ct_10_long = scalar_position(Some(cid_10), scalar_market_id, ScalarPosition::Long);
+        let ct_10_short = scalar_position(Some(cid_10), scalar_market_id, ScalarPosition::Short);
+        assert_eq!(alice.free_balance(ct_10), 0);
+        assert_eq!(alice.free_balance(ct_10_long), amount);
+        assert_eq!(alice.free_balance(ct_10_short), amount);
+
+        // Merging and splitting again is lossless.
+        assert_ok!(CombinatorialTokens::merge_position(
+            alice.signed(),
+            Some(cid_10),
+            scalar_market_id,
+            Partition::Scalar,
+            amount,
+            Fuel::new(16, false),
+        ));
+        assert_eq!(alice.free_balance(ct_10), amount);
+        assert_ok!(CombinatorialTokens::split_position(
+            alice.signed(),
+            Some(cid_10),
+            scalar_market_id,
+            Partition::Scalar,
+            amount,
+            Fuel::new(16, false),
+        ));
+
+        // The scalar market resolves to 37, i.e. Long is worth 0.37 and Short 0.63.
+        MockPayout::set_return_value(Some(vec![37 * CENT, 63 * CENT]));
+        assert_ok!(CombinatorialTokens::redeem_position(
+            alice.signed(),
+            Some(cid_10),
+            scalar_market_id,
+            IndexSet::Scalar(ScalarPosition::Long),
+            Fuel::new(16, false),
+        ));
+        assert_eq!(alice.free_balance(ct_10_long), 0);
+        assert_eq!(alice.free_balance(ct_10), 37 * CENT * 10);
+        assert_ok!(CombinatorialTokens::redeem_position(
+            alice.signed(),
+            Some(cid_10),
+            scalar_market_id,
+            IndexSet::Scalar(ScalarPosition::Short),
+            Fuel::new(16, false),
+        ));
+        assert_eq!(alice.free_balance(ct_10_short), 0);
+        assert_eq!(alice.free_balance(ct_10), amount);
+
+        // The categorical market resolves to the first outcome.
+        MockPayout::set_return_value(Some(vec![_1, 0]));
+        assert_ok!(CombinatorialTokens::redeem_position(
+            alice.signed(),
+            None,
+            categorical_market_id,
+            IndexSet::Outcomes(vec![B1, B0]),
+            Fuel::new(16, false),
+        ));
+        assert_eq!(alice.free_balance(ct_10), 0);
+        assert_eq!(alice.free_balance(Asset::Ztg), _100);
+        assert_eq!(pallet.free_balance(Asset::Ztg), 0);
+    });
+}
+
+#[test]
+fn categorical_market_nested_in_scalar_market_redeems_non_binary_payout() {
+    ExtBuilder::build().execute_with(|| {
+        let alice = Account::new(0).deposit(Asset::Ztg, _100).unwrap();
+        let pallet = Account::new(Pallet::<Runtime>::account_id());
+        let scalar_market_id = create_market(Asset::Ztg, MarketType::Scalar(0..=100));
+        let categorical_market_id = create_market(Asset::Ztg, MarketType::Categorical(3));
+        let amount = _10;
+
+        assert_ok!(CombinatorialTokens::split_position(
+            alice.signed(),
+            None,
+            scalar_market_id,
+            Partition::Scalar,
+            amount,
+            Fuel::new(16, false),
+        ));
+        let cid_long = CombinatorialTokens::collection_id_from_parent_collection(
+            None,
+            scalar_market_id,
+            ScalarPosition::Long.index_set(),
+            Fuel::new(16, false),
+        )
+        .unwrap();
+        let ct_long = scalar_position(None, scalar_market_id, ScalarPosition::Long);
+        let ct_short = scalar_position(None, scalar_market_id, ScalarPosition::Short);
+        assert_ok!(CombinatorialTokens::split_position(
+            alice.signed(),
+            Some(cid_long),
+            categorical_market_id,
+            Partition::IndexSets(vec![vec![B1, B0, B0], vec![B0, B1, B1]]),
+            amount,
+            Fuel::new(16, false),
+        ));
+        assert_eq!(alice.free_balance(ct_long), 0);
+
+        // The categorical market resolves to the second outcome; `Long & (B|C)` redeems for
+        // `Long`.
+        MockPayout::set_return_value(Some(vec![0, _1, 0]));
+        assert_ok!(CombinatorialTokens::redeem_position(
+            alice.signed(),
+            Some(cid_long),
+            categorical_market_id,
+            IndexSet::Outcomes(vec![B0, B1, B1]),
+            Fuel::new(16, false),
+        ));
+        assert_eq!(alice.free_balance(ct_long), amount);
+
+        // The scalar market resolves so that Long is worth 0.37 and Short 0.63.
+        MockPayout::set_return_value(Some(vec![37 * CENT, 63 * CENT]));
+        for position in [ScalarPosition::Long, ScalarPosition::Short] {
+            assert_ok!(CombinatorialTokens::redeem_position(
+                alice.signed(),
+                None,
+                scalar_market_id,
+                IndexSet::Scalar(position),
+                Fuel::new(16, false),
+            ));
+        }
+        assert_eq!(alice.free_balance(ct_long), 0);
+        assert_eq!(alice.free_balance(ct_short), 0);
+        assert_eq!(alice.free_balance(Asset::Ztg), _100);
+        assert_eq!(pallet.free_balance(Asset::Ztg), 0);
+    });
+}
+
+#[test]
+fn split_position_fails_on_scalar_partition_of_categorical_market() {
+    ExtBuilder::build().execute_with(|| {
+        let alice = Account::new(0).deposit(Asset::Ztg, _100).unwrap();
+        let market_id = create_market(Asset::Ztg, MarketType::Categorical(2));
+        assert_noop!(
+            CombinatorialTokens::split_position(
+                alice.signed(),
+                None,
+                market_id,
+                Partition::Scalar,
+                _1,
+                Fuel::new(16, false),
+            ),
+            Error::<Runtime>::NotScalarMarket
+        );
+    });
+}
+
+#[test]
+fn redeem_position_fails_on_scalar_index_set_of_categorical_market() {
+    ExtBuilder::build().execute_with(|| {
+        let alice = Account::new(0).deposit(Asset::Ztg, _100).unwrap();
+        let market_id = create_market(Asset::Ztg, MarketType::Categorical(2));
+        assert_ok!(CombinatorialTokens::split_position(
+            alice.signed(),
+            None,
+            market_id,
+            Partition::IndexSets(vec![vec![B1, B0], vec![B0, B1]]),
+            _1,
+            Fuel::new(16, false),
+        ));
+
+        MockPayout::set_return_value(Some(vec![_1, 0]));
+        assert_noop!(
+            CombinatorialTokens::redeem_position(
+                alice.signed(),
+                None,
+                market_id,
+                IndexSet::Scalar(ScalarPosition::Long),
+                Fuel::new(16, false),
+            ),
+            Error::<Runtime>::NotScalarMarket
+        );
+    });
+}
+
+#[test]
+fn position_values_reports_scalar_positions() {
+    ExtBuilder::build().execute_with(|| {
+        let alice = Account::new(0).deposit(Asset::Ztg, _100).unwrap();
+        let market_id = create_market(Asset::Ztg, MarketType::Scalar(0..=100));
+        assert_ok!(CombinatorialTokens::split_position(
+            alice.signed(),
+            None,
+            market_id,
+            Partition::Scalar,
+            _1,
+            Fuel::new(16, false),
+        ));
+        let position_ids = [ScalarPosition::Long, ScalarPosition::Short]
+            .into_iter()
+            .map(|position| match scalar_position(None, market_id, position) {
+                CombinatorialToken(position_id) => position_id,
+                _ => panic!("unexpected asset"),
+            })
+            .collect::<Vec<_>>();
+
+        MockPayout::set_return_value(None);
+        let values = CombinatorialTokens::position_values(&alice.id, position_ids);
+        assert_eq!(
+            values.iter().map(|value| value.scalar_position).collect::<Vec<_>>(),
+            vec![Some(ScalarPosition::Long), Some(ScalarPosition::Short)]
+        );
+    });
+}
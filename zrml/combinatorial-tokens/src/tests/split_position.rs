@@ -32,7 +32,7 @@ fn split_position_works_vertical_no_parent() {
             alice.signed(),
             parent_collection_id,
             market_id,
-            partition.clone(),
+            Partition::IndexSets(partition.clone()),
             amount,
             Fuel::new(16, false),
         ));
@@ -88,7 +88,7 @@ fn split_position_works_vertical_with_parent() {
             alice.signed(),
             None,
             parent_market_id,
-            vec![vec![B0, B0, B1], vec![B1, B1, B0]],
+            Partition::IndexSets(vec![vec![B0, B0, B1], vec![B1, B1, B0]]),
             parent_amount,
             Fuel::new(16, false),
         ));
@@ -105,7 +105,7 @@ fn split_position_works_vertical_with_parent() {
             alice.signed(),
             Some(parent_collection_id),
             child_market_id,
-            partition.clone(),
+            Partition::IndexSets(partition.clone()),
             child_amount,
             Fuel::new(16, false),
         ));
@@ -181,7 +181,7 @@ fn split_position_fails_if_market_not_found() {
                 alice.signed(),
                 None,
                 0,
-                vec![vec![B0, B0, B1], vec![B1, B1, B0]],
+                Partition::IndexSets(vec![vec![B0, B0, B1], vec![B1, B1, B0]]),
                 1,
                 Fuel::new(16, false),
             ),
@@ -204,7 +204,7 @@ fn split_position_fails_on_invalid_partition_length() {
                 alice.signed(),
                 None,
                 market_id,
-                partition,
+                Partition::IndexSets(partition),
                 _1,
                 Fuel::new(16, false),
             ),
@@ -227,7 +227,7 @@ fn split_position_fails_on_empty_partition_member() {
                 alice.signed(),
                 None,
                 market_id,
-                partition,
+                Partition::IndexSets(partition),
                 _1,
                 Fuel::new(16, false)
             ),
@@ -250,7 +250,7 @@ fn split_position_fails_on_overlapping_partition_members() {
                 alice.signed(),
                 None,
                 market_id,
-                partition,
+                Partition::IndexSets(partition),
                 _1,
                 Fuel::new(16, false),
             ),
@@ -272,7 +272,7 @@ fn split_position_fails_on_trivial_partition() {
                 alice.signed(),
                 None,
                 market_id,
-                partition,
+                Partition::IndexSets(partition),
                 _1,
                 Fuel::new(16, false)
             ),
@@ -293,7 +293,7 @@ fn split_position_fails_on_insufficient_funds_native_token_no_parent() {
                 alice.signed(),
                 None,
                 market_id,
-                vec![vec![B1, B0, B1], vec![B0, B1, B0]],
+                Partition::IndexSets(vec![vec![B1, B0, B1], vec![B0, B1, B0]]),
                 _100,
                 Fuel::new(16, false),
             ),
@@ -314,7 +314,7 @@ fn split_position_fails_on_insufficient_funds_foreign_token_no_parent() {
                 alice.signed(),
                 None,
                 market_id,
-                vec![vec![B1, B0, B1], vec![B0, B1, B0]],
+                Partition::IndexSets(vec![vec![B1, B0, B1], vec![B0, B1, B0]]),
                 _100,
                 Fuel::new(16, false),
             ),
@@ -347,7 +347,7 @@ fn split_position_vertical_fails_on_insufficient_funds_combinatorial_token() {
                 alice.signed(),
                 Some(parent_collection_id),
                 market_id,
-                vec![vec![B1, B0, B1, B0], vec![B0, B1, B0, B1]],
+                Partition::IndexSets(vec![vec![B1, B0, B1, B0], vec![B0, B1, B0, B1]]),
                 _100,
                 Fuel::new(16, false),
             ),
@@ -359,7 +359,7 @@ fn split_position_vertical_fails_on_insufficient_funds_combinatorial_token() {
             alice.signed(),
             Some(parent_collection_id),
             market_id,
-            vec![vec![B1, B0, B1, B0], vec![B0, B1, B0, B1]],
+            Partition::IndexSets(vec![vec![B1, B0, B1, B0], vec![B0, B1, B0, B1]]),
             _99,
             Fuel::new(16, false),
         ));
@@ -384,7 +384,7 @@ fn split_position_horizontal_fails_on_insufficient_funds_combinatorial_token() {
                 alice.signed(),
                 None,
                 market_id,
-                vec![vec![B1, B0, B0], vec![B0, B1, B0]],
+                Partition::IndexSets(vec![vec![B1, B0, B0], vec![B0, B1, B0]]),
                 _100,
                 Fuel::new(16, false),
             ),
@@ -396,7 +396,7 @@ fn split_position_horizontal_fails_on_insufficient_funds_combinatorial_token() {
             alice.signed(),
             None,
             market_id,
-            vec![vec![B1, B0, B0], vec![B0, B1, B0]],
+            Partition::IndexSets(vec![vec![B1, B0, B0], vec![B0, B1, B0]]),
             _99,
             Fuel::new(16, false),
         ));
@@ -0,0 +1,52 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use alloc::vec::Vec;
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use zeitgeist_primitives::types::ScalarPosition;
+
+/// Specifies the outcomes of a market which make up a position.
+#[derive(Clone, Debug, Decode, Encode, Eq, PartialEq, TypeInfo)]
+pub enum IndexSet {
+    /// A `true` entry at the `i`th index means that the `i`th outcome token of the market is
+    /// contained in the position.
+    Outcomes(Vec<bool>),
+
+    /// The long or short position of a scalar market.
+    Scalar(ScalarPosition),
+}
+
+impl IndexSet {
+    /// Returns the number of outcomes the index set ranges over.
+    pub fn len(&self) -> usize {
+        match self {
+            IndexSet::Outcomes(index_set) => index_set.len(),
+            IndexSet::Scalar(_) => 2,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl From<Vec<bool>> for IndexSet {
+    fn from(index_set: Vec<bool>) -> Self {
+        IndexSet::Outcomes(index_set)
+    }
+}
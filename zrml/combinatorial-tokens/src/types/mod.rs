@@ -18,9 +18,17 @@
 mod collection_id_error;
 pub(crate) mod cryptographic_id_manager;
 pub(crate) mod hash;
+mod index_set;
+mod partition;
+mod position_info;
+mod position_value;
 mod transmutation_type;
 
 pub use collection_id_error::CollectionIdError;
 pub use cryptographic_id_manager::{CryptographicIdManager, Fuel};
 pub(crate) use hash::Hash256;
+pub use index_set::IndexSet;
+pub use partition::Partition;
+pub use position_info::PositionInfo;
+pub use position_value::PositionValue;
 pub use transmutation_type::TransmutationType;
@@ -0,0 +1,51 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use alloc::vec::Vec;
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+
+/// Specifies how a position is split over a market or which positions are merged.
+#[derive(Clone, Debug, Decode, Encode, Eq, PartialEq, TypeInfo)]
+pub enum Partition {
+    /// A vector whose elements are equal-length index sets. A `true` entry at the `i`th index of
+    /// an element means that the `i`th outcome token of the market is contained in this element.
+    IndexSets(Vec<Vec<bool>>),
+
+    /// The long and short position of a scalar market.
+    Scalar,
+}
+
+impl Partition {
+    /// Returns the number of elements of the partition.
+    pub fn len(&self) -> usize {
+        match self {
+            Partition::IndexSets(index_sets) => index_sets.len(),
+            Partition::Scalar => 2,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl From<Vec<Vec<bool>>> for Partition {
+    fn from(index_sets: Vec<Vec<bool>>) -> Self {
+        Partition::IndexSets(index_sets)
+    }
+}
@@ -0,0 +1,45 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use alloc::vec::Vec;
+use frame_support::{traits::ConstU32, BoundedVec};
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use zeitgeist_primitives::{constants::MAX_CATEGORIES, types::CombinatorialId};
+
+/// Describes how a position was obtained: By splitting the position of `parent_collection_id`
+/// (collateral if `None`) over the market `market_id` using `index_set`. Used to recover the
+/// redeemable value of a position from its ID.
+#[derive(Clone, Debug, Decode, Encode, Eq, MaxEncodedLen, PartialEq, TypeInfo)]
+pub struct PositionInfo<MarketId> {
+    pub parent_collection_id: Option<CombinatorialId>,
+    pub market_id: MarketId,
+    pub index_set: BoundedVec<bool, ConstU32<{ MAX_CATEGORIES as u32 }>>,
+}
+
+impl<MarketId> PositionInfo<MarketId> {
+    /// Returns `None` if `index_set` is longer than the maximum number of categories.
+    pub fn new(
+        parent_collection_id: Option<CombinatorialId>,
+        market_id: MarketId,
+        index_set: Vec<bool>,
+    ) -> Option<Self> {
+        let index_set = index_set.try_into().ok()?;
+
+        Some(PositionInfo { parent_collection_id, market_id, index_set })
+    }
+}
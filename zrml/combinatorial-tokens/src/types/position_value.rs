@@ -0,0 +1,50 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use alloc::vec::Vec;
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use zeitgeist_primitives::types::{Asset, CombinatorialId, ScalarPosition};
+
+/// A combinatorial position held by an account together with its redeemable value.
+#[derive(Clone, Debug, Decode, Encode, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct PositionValue<MarketId, Balance> {
+    /// The position held.
+    pub position: Asset<MarketId>,
+
+    /// The collection ID of the position the held position redeems into; `None` if it redeems
+    /// into collateral.
+    pub parent_collection_id: Option<CombinatorialId>,
+
+    /// The market over which the parent position was split to obtain the held position.
+    pub market_id: MarketId,
+
+    /// The index set used to split the parent position.
+    pub index_set: Vec<bool>,
+
+    /// The leg selected by `index_set` if the market is scalar.
+    pub scalar_position: Option<ScalarPosition>,
+
+    /// The free balance of the position.
+    pub amount: Balance,
+
+    /// The asset and amount received when redeeming the position, or `None` if the market is
+    /// not resolved yet. The amount is zero if the position holds no value.
+    pub redeemable: Option<(Asset<MarketId>, Balance)>,
+}
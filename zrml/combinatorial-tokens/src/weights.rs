@@ -59,6 +59,9 @@ pub trait WeightInfoZeitgeist {
     fn merge_position_horizontal(n: u32, m: u32) -> Weight;
     fn redeem_position_sans_parent(n: u32, m: u32) -> Weight;
     fn redeem_position_with_parent(n: u32, m: u32) -> Weight;
+    fn redeem_all(n: u32) -> Weight;
+    fn convert_from_legacy(m: u32) -> Weight;
+    fn convert_to_legacy(m: u32) -> Weight;
 }
 
 /// Weight functions for zrml_combinatorial_tokens (automatically generated)
@@ -72,22 +75,20 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
     /// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(137), added: 2612, mode: `MaxEncodedLen`)
     /// Storage: `Tokens::TotalIssuance` (r:32 w:32)
     /// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+    /// Storage: `CombinatorialTokens::Positions` (r:32 w:32)
+    /// Proof: `CombinatorialTokens::Positions` (`max_values`: None, `max_size`: Some(162), added: 2637, mode: `MaxEncodedLen`)
     /// The range of component `n` is `[2, 32]`.
     /// The range of component `m` is `[32, 64]`.
     fn split_position_vertical_sans_parent(n: u32, m: u32) -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `441`
-        //  Estimated: `4173 + n * (2612 ±0)`
-        // Minimum execution time: 6_292_768 nanoseconds.
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_combinatorial_tokens` to replace it.
         Weight::from_parts(6_393_340_000, 4173)
-            // Standard Error: 32_644_607
             .saturating_add(Weight::from_parts(2_092_111_890, 0).saturating_mul(n.into()))
-            // Standard Error: 15_079_588
             .saturating_add(Weight::from_parts(222_872_311, 0).saturating_mul(m.into()))
             .saturating_add(T::DbWeight::get().reads(2))
-            .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
             .saturating_add(T::DbWeight::get().writes(1))
-            .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
             .saturating_add(Weight::from_parts(0, 2612).saturating_mul(n.into()))
     }
     /// Storage: `MarketCommons::Markets` (r:1 w:0)
@@ -96,22 +97,20 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
     /// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(137), added: 2612, mode: `MaxEncodedLen`)
     /// Storage: `Tokens::TotalIssuance` (r:33 w:33)
     /// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+    /// Storage: `CombinatorialTokens::Positions` (r:32 w:33)
+    /// Proof: `CombinatorialTokens::Positions` (`max_values`: None, `max_size`: Some(162), added: 2637, mode: `MaxEncodedLen`)
     /// The range of component `n` is `[2, 32]`.
     /// The range of component `m` is `[32, 64]`.
     fn split_position_vertical_with_parent(n: u32, m: u32) -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `671`
-        //  Estimated: `4173 + n * (2612 ±0)`
-        // Minimum execution time: 6_434_991 nanoseconds.
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_combinatorial_tokens` to replace it.
         Weight::from_parts(7_038_494_000, 4173)
-            // Standard Error: 33_187_296
             .saturating_add(Weight::from_parts(2_168_228_746, 0).saturating_mul(n.into()))
-            // Standard Error: 15_330_274
             .saturating_add(Weight::from_parts(221_999_448, 0).saturating_mul(m.into()))
             .saturating_add(T::DbWeight::get().reads(3))
-            .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
-            .saturating_add(T::DbWeight::get().writes(2))
-            .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes(3))
+            .saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
             .saturating_add(Weight::from_parts(0, 2612).saturating_mul(n.into()))
     }
     /// Storage: `MarketCommons::Markets` (r:1 w:0)
@@ -120,22 +119,20 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
     /// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(137), added: 2612, mode: `MaxEncodedLen`)
     /// Storage: `Tokens::TotalIssuance` (r:33 w:33)
     /// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+    /// Storage: `CombinatorialTokens::Positions` (r:32 w:33)
+    /// Proof: `CombinatorialTokens::Positions` (`max_values`: None, `max_size`: Some(162), added: 2637, mode: `MaxEncodedLen`)
     /// The range of component `n` is `[2, 32]`.
     /// The range of component `m` is `[32, 64]`.
     fn split_position_horizontal(n: u32, m: u32) -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `633`
-        //  Estimated: `4173 + n * (2612 ±0)`
-        // Minimum execution time: 9_981_917 nanoseconds.
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_combinatorial_tokens` to replace it.
         Weight::from_parts(10_208_792_000, 4173)
-            // Standard Error: 33_713_142
             .saturating_add(Weight::from_parts(2_077_792_907, 0).saturating_mul(n.into()))
-            // Standard Error: 15_573_179
             .saturating_add(Weight::from_parts(236_924_694, 0).saturating_mul(m.into()))
             .saturating_add(T::DbWeight::get().reads(3))
-            .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
-            .saturating_add(T::DbWeight::get().writes(2))
-            .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes(3))
+            .saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
             .saturating_add(Weight::from_parts(0, 2612).saturating_mul(n.into()))
     }
     /// Storage: `MarketCommons::Markets` (r:1 w:0)
@@ -146,22 +143,20 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
     /// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
     /// Storage: `System::Account` (r:1 w:1)
     /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+    /// Storage: `CombinatorialTokens::Positions` (r:0 w:32)
+    /// Proof: `CombinatorialTokens::Positions` (`max_values`: None, `max_size`: Some(162), added: 2637, mode: `MaxEncodedLen`)
     /// The range of component `n` is `[2, 32]`.
     /// The range of component `m` is `[32, 64]`.
     fn merge_position_vertical_sans_parent(n: u32, m: u32) -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `619 + n * (160 ±0)`
-        //  Estimated: `4173 + n * (2612 ±0)`
-        // Minimum execution time: 6_248_244 nanoseconds.
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_combinatorial_tokens` to replace it.
         Weight::from_parts(6_757_766_000, 4173)
-            // Standard Error: 32_688_123
             .saturating_add(Weight::from_parts(2_122_250_298, 0).saturating_mul(n.into()))
-            // Standard Error: 15_099_690
             .saturating_add(Weight::from_parts(226_524_198, 0).saturating_mul(m.into()))
             .saturating_add(T::DbWeight::get().reads(2))
             .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
             .saturating_add(T::DbWeight::get().writes(1))
-            .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
             .saturating_add(Weight::from_parts(0, 2612).saturating_mul(n.into()))
     }
     /// Storage: `MarketCommons::Markets` (r:1 w:0)
@@ -170,22 +165,20 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
     /// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(137), added: 2612, mode: `MaxEncodedLen`)
     /// Storage: `Tokens::TotalIssuance` (r:33 w:33)
     /// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+    /// Storage: `CombinatorialTokens::Positions` (r:0 w:32)
+    /// Proof: `CombinatorialTokens::Positions` (`max_values`: None, `max_size`: Some(162), added: 2637, mode: `MaxEncodedLen`)
     /// The range of component `n` is `[2, 32]`.
     /// The range of component `m` is `[32, 64]`.
     fn merge_position_vertical_with_parent(n: u32, m: u32) -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `517 + n * (160 ±0)`
-        //  Estimated: `4173 + n * (2612 ±0)`
-        // Minimum execution time: 6_400_069 nanoseconds.
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_combinatorial_tokens` to replace it.
         Weight::from_parts(7_057_472_000, 4173)
-            // Standard Error: 31_873_496
             .saturating_add(Weight::from_parts(2_172_133_902, 0).saturating_mul(n.into()))
-            // Standard Error: 14_723_388
             .saturating_add(Weight::from_parts(227_784_456, 0).saturating_mul(m.into()))
             .saturating_add(T::DbWeight::get().reads(3))
             .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
             .saturating_add(T::DbWeight::get().writes(2))
-            .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
             .saturating_add(Weight::from_parts(0, 2612).saturating_mul(n.into()))
     }
     /// Storage: `MarketCommons::Markets` (r:1 w:0)
@@ -194,22 +187,20 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
     /// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(137), added: 2612, mode: `MaxEncodedLen`)
     /// Storage: `Tokens::TotalIssuance` (r:33 w:33)
     /// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+    /// Storage: `CombinatorialTokens::Positions` (r:1 w:33)
+    /// Proof: `CombinatorialTokens::Positions` (`max_values`: None, `max_size`: Some(162), added: 2637, mode: `MaxEncodedLen`)
     /// The range of component `n` is `[2, 32]`.
     /// The range of component `m` is `[32, 64]`.
     fn merge_position_horizontal(n: u32, m: u32) -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `478 + n * (160 ±0)`
-        //  Estimated: `4173 + n * (2612 ±0)`
-        // Minimum execution time: 9_966_376 nanoseconds.
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_combinatorial_tokens` to replace it.
         Weight::from_parts(10_082_179_000, 4173)
-            // Standard Error: 32_915_254
             .saturating_add(Weight::from_parts(2_091_693_974, 0).saturating_mul(n.into()))
-            // Standard Error: 15_204_609
             .saturating_add(Weight::from_parts(232_352_835, 0).saturating_mul(m.into()))
-            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().reads(4))
             .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
-            .saturating_add(T::DbWeight::get().writes(2))
-            .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes(3))
+            .saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
             .saturating_add(Weight::from_parts(0, 2612).saturating_mul(n.into()))
     }
     /// Storage: `MarketCommons::Markets` (r:1 w:0)
@@ -218,20 +209,21 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
     /// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(137), added: 2612, mode: `MaxEncodedLen`)
     /// Storage: `Tokens::TotalIssuance` (r:1 w:1)
     /// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
-    /// Storage: `System::Account` (r:1 w:1)
+    /// Storage: `System::Account` (r:2 w:2)
     /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+    /// Storage: `CombinatorialTokens::LegacyConversions` (r:1 w:1)
+    /// Proof: `CombinatorialTokens::LegacyConversions` (`max_values`: None, `max_size`: Some(1058), added: 3533, mode: `MaxEncodedLen`)
+    /// Storage: `CombinatorialTokens::Positions` (r:0 w:1)
+    /// Proof: `CombinatorialTokens::Positions` (`max_values`: None, `max_size`: Some(162), added: 2637, mode: `MaxEncodedLen`)
     /// The range of component `n` is `[2, 32]`.
     /// The range of component `m` is `[32, 64]`.
     fn redeem_position_sans_parent(_n: u32, m: u32) -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `780`
-        //  Estimated: `4173`
-        // Minimum execution time: 1_653_265 nanoseconds.
-        Weight::from_parts(1_699_956_000, 4173)
-            // Standard Error: 252_760
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_combinatorial_tokens` to replace it.
+        Weight::from_parts(1_728_193_000, 4523)
             .saturating_add(Weight::from_parts(33_971_052, 0).saturating_mul(m.into()))
-            .saturating_add(T::DbWeight::get().reads(4))
-            .saturating_add(T::DbWeight::get().writes(3))
+            .saturating_add(T::DbWeight::get().reads(6))
+            .saturating_add(T::DbWeight::get().writes(6))
     }
     /// Storage: `MarketCommons::Markets` (r:1 w:0)
     /// Proof: `MarketCommons::Markets` (`max_values`: None, `max_size`: Some(708), added: 3183, mode: `MaxEncodedLen`)
@@ -239,17 +231,82 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
     /// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(137), added: 2612, mode: `MaxEncodedLen`)
     /// Storage: `Tokens::TotalIssuance` (r:2 w:2)
     /// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+    /// Storage: `CombinatorialTokens::Positions` (r:0 w:1)
+    /// Proof: `CombinatorialTokens::Positions` (`max_values`: None, `max_size`: Some(162), added: 2637, mode: `MaxEncodedLen`)
     /// The range of component `n` is `[2, 32]`.
     /// The range of component `m` is `[32, 64]`.
     fn redeem_position_with_parent(_n: u32, m: u32) -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `674`
-        //  Estimated: `6214`
-        // Minimum execution time: 1_689_296 nanoseconds.
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_combinatorial_tokens` to replace it.
         Weight::from_parts(138_110_913, 6214)
-            // Standard Error: 283_911
             .saturating_add(Weight::from_parts(53_680_273, 0).saturating_mul(m.into()))
             .saturating_add(T::DbWeight::get().reads(5))
-            .saturating_add(T::DbWeight::get().writes(4))
+            .saturating_add(T::DbWeight::get().writes(5))
+    }
+    /// Storage: `CombinatorialTokens::Positions` (r:16 w:16)
+    /// Proof: `CombinatorialTokens::Positions` (`max_values`: None, `max_size`: Some(162), added: 2637, mode: `MaxEncodedLen`)
+    /// Storage: `Tokens::Accounts` (r:16 w:16)
+    /// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(137), added: 2612, mode: `MaxEncodedLen`)
+    /// Storage: `MarketCommons::Markets` (r:16 w:0)
+    /// Proof: `MarketCommons::Markets` (`max_values`: None, `max_size`: Some(708), added: 3183, mode: `MaxEncodedLen`)
+    /// Storage: `Tokens::TotalIssuance` (r:16 w:16)
+    /// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:2 w:2)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+    /// Storage: `CombinatorialTokens::LegacyConversions` (r:1 w:1)
+    /// Proof: `CombinatorialTokens::LegacyConversions` (`max_values`: None, `max_size`: Some(1058), added: 3533, mode: `MaxEncodedLen`)
+    /// The range of component `n` is `[1, 16]`.
+    fn redeem_all(n: u32) -> Weight {
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_combinatorial_tokens` to replace it.
+        Weight::from_parts(80_711_353, 4523)
+            .saturating_add(Weight::from_parts(80_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().reads((6_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes(3))
+            .saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 5795).saturating_mul(n.into()))
+    }
+    /// Storage: `MarketCommons::Markets` (r:1 w:0)
+    /// Proof: `MarketCommons::Markets` (`max_values`: None, `max_size`: Some(708), added: 3183, mode: `MaxEncodedLen`)
+    /// Storage: `Tokens::Accounts` (r:2 w:2)
+    /// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(137), added: 2612, mode: `MaxEncodedLen`)
+    /// Storage: `Tokens::TotalIssuance` (r:2 w:2)
+    /// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+    /// Storage: `CombinatorialTokens::Positions` (r:1 w:1)
+    /// Proof: `CombinatorialTokens::Positions` (`max_values`: None, `max_size`: Some(162), added: 2637, mode: `MaxEncodedLen`)
+    /// Storage: `CombinatorialTokens::LegacyConversions` (r:1 w:1)
+    /// Proof: `CombinatorialTokens::LegacyConversions` (`max_values`: None, `max_size`: Some(1058), added: 3533, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:2 w:2)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+    /// The range of component `m` is `[32, 64]`.
+    fn convert_from_legacy(m: u32) -> Weight {
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_combinatorial_tokens` to replace it.
+        Weight::from_parts(1_712_404_000, 9737)
+            .saturating_add(Weight::from_parts(34_106_281, 0).saturating_mul(m.into()))
+            .saturating_add(T::DbWeight::get().reads(9))
+            .saturating_add(T::DbWeight::get().writes(8))
+    }
+    /// Storage: `MarketCommons::Markets` (r:1 w:0)
+    /// Proof: `MarketCommons::Markets` (`max_values`: None, `max_size`: Some(708), added: 3183, mode: `MaxEncodedLen`)
+    /// Storage: `CombinatorialTokens::LegacyConversions` (r:1 w:1)
+    /// Proof: `CombinatorialTokens::LegacyConversions` (`max_values`: None, `max_size`: Some(1058), added: 3533, mode: `MaxEncodedLen`)
+    /// Storage: `Tokens::Accounts` (r:2 w:2)
+    /// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(137), added: 2612, mode: `MaxEncodedLen`)
+    /// Storage: `Tokens::TotalIssuance` (r:2 w:2)
+    /// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:2 w:2)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+    /// Storage: `CombinatorialTokens::Positions` (r:0 w:1)
+    /// Proof: `CombinatorialTokens::Positions` (`max_values`: None, `max_size`: Some(162), added: 2637, mode: `MaxEncodedLen`)
+    /// The range of component `m` is `[32, 64]`.
+    fn convert_to_legacy(m: u32) -> Weight {
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_combinatorial_tokens` to replace it.
+        Weight::from_parts(1_709_127_000, 9737)
+            .saturating_add(Weight::from_parts(33_988_406, 0).saturating_mul(m.into()))
+            .saturating_add(T::DbWeight::get().reads(8))
+            .saturating_add(T::DbWeight::get().writes(8))
     }
 }
@@ -0,0 +1,40 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+#![doc = include_str!("../README.md")]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use parity_scale_codec::{Codec, MaxEncodedLen};
+use sp_runtime::DispatchError;
+use zrml_court::{CourtId, StakeReassignment, VoteItem};
+
+sp_api::decl_runtime_apis! {
+    pub trait CourtApi<AccountId, Balance> where
+        AccountId: Codec,
+        Balance: Codec + MaxEncodedLen,
+    {
+        /// Returns the amounts `reassign_court_stakes` would slash from and reward to each court
+        /// participant if the court `court_id` resolved to `winner`.
+        fn simulate_reassign_court_stakes(
+            court_id: CourtId,
+            winner: VoteItem,
+        ) -> Result<Vec<(AccountId, StakeReassignment<Balance>)>, DispatchError>;
+    }
+}
@@ -24,8 +24,9 @@ extern crate alloc;
 
 use crate::{
     weights::WeightInfoZeitgeist, AppealInfo, CourtId, CourtInfo, CourtParticipantInfo,
-    CourtPoolItem, CourtStatus, Draw, JurorVoteWithStakes, RawCommitment, RoundTiming,
-    SelectionAdd, SelectionError, SelectionValue, SelfInfo, Vote, VoteItem, VoteItemType,
+    CourtPoolItem, CourtStatus, Draw, JurorVoteWithStakes, RawCommitment, Reassignment,
+    RoundTiming, SelectionAdd, SelectionError, SelectionValue, SelfInfo, StakeReassignment, Vote,
+    VoteItem, VoteItemType,
 };
 use alloc::{
     collections::{BTreeMap, BTreeSet},
@@ -234,6 +235,7 @@ mod pallet {
     pub(crate) type DelegationsOf<T> = BoundedVec<AccountIdOf<T>, <T as Config>::MaxDelegations>;
     pub(crate) type VoteOf<T> = Vote<HashOf<T>, DelegatedStakesOf<T>>;
     pub(crate) type JurorVoteWithStakesOf<T> = JurorVoteWithStakes<AccountIdOf<T>, BalanceOf<T>>;
+    pub(crate) type ReassignmentOf<T> = Reassignment<AccountIdOf<T>, BalanceOf<T>>;
     pub(crate) type CourtParticipantInfoOf<T> =
         CourtParticipantInfo<BalanceOf<T>, BlockNumberFor<T>, DelegationsOf<T>>;
     pub(crate) type CourtPoolItemOf<T> =
@@ -1064,67 +1066,7 @@ mod pallet {
             let draws = SelectedDraws::<T>::get(court_id);
             let draws_len = draws.len() as u32;
 
-            let reward_pot = Self::reward_pot(court_id);
-            let slash_juror = |ai: &T::AccountId, slashable: BalanceOf<T>| {
-                let (imbalance, missing) = T::Currency::slash(ai, slashable);
-                debug_assert!(
-                    missing.is_zero(),
-                    "Could not slash all of the amount for juror {:?}.",
-                    ai
-                );
-                T::Currency::resolve_creating(&reward_pot, imbalance);
-            };
-
-            // map delegated jurors to own_slashable, vote item and Vec<(delegator, delegator_stake)>
-            let mut jurors_to_stakes = BTreeMap::<T::AccountId, JurorVoteWithStakesOf<T>>::new();
-
-            let mut handle_vote = |draw: DrawOf<T>| -> DispatchResult {
-                match draw.vote {
-                    Vote::Drawn
-                    | Vote::Secret { commitment: _ }
-                    | Vote::Denounced { commitment: _, vote_item: _, salt: _ } => {
-                        slash_juror(&draw.court_participant, draw.slashable);
-                    }
-                    Vote::Revealed { commitment: _, vote_item, salt: _ } => {
-                        jurors_to_stakes.entry(draw.court_participant).or_default().self_info =
-                            Some(SelfInfo { slashable: draw.slashable, vote_item });
-                    }
-                    Vote::Delegated { delegated_stakes } => {
-                        let delegator = draw.court_participant;
-                        for (j, delegated_stake) in delegated_stakes {
-                            // fill the delegations for each juror
-                            // [(juror_0, [(delegator_0, delegator_stake_0), ...]),
-                            // (juror_1, [(delegator_42, delegator_stake_42), ...]), ...]
-                            let jurors_to_stakes_entry = jurors_to_stakes.entry(j);
-                            let juror_vote_with_stakes = jurors_to_stakes_entry.or_default();
-
-                            // future-proof binary search by key
-                            // because many delegators can back one juror
-                            // we might want to fastly find elements later on
-                            match juror_vote_with_stakes
-                                .delegations
-                                .binary_search_by_key(&delegator, |(d, _)| d.clone())
-                            {
-                                Ok(i) => {
-                                    let delegations = juror_vote_with_stakes
-                                        .delegations
-                                        .get_mut(i)
-                                        .ok_or(Error::<T>::Unexpected(
-                                            UnexpectedError::BinarySearchByKeyFailed,
-                                        ))?;
-                                    delegations.1 = delegations.1.saturating_add(delegated_stake);
-                                }
-                                Err(i) => {
-                                    juror_vote_with_stakes
-                                        .delegations
-                                        .insert(i, (delegator.clone(), delegated_stake));
-                                }
-                            }
-                        }
-                    }
-                }
-                Ok(())
-            };
+            let reassignment = Self::get_reassignment(court_id, draws.as_slice(), &winner)?;
 
             for draw in draws {
                 if let Some(mut p_info) = <Participants<T>>::get(&draw.court_participant) {
@@ -1140,11 +1082,9 @@ mod pallet {
                     );
                     debug_assert!(false);
                 }
-
-                handle_vote(draw)?;
             }
 
-            Self::slash_losers_to_award_winners(court_id, jurors_to_stakes, &winner);
+            Self::execute_reassignment(court_id, reassignment);
 
             court.status = CourtStatus::Reassigned;
             <Courts<T>>::insert(court_id, court);
@@ -2068,28 +2008,85 @@ mod pallet {
                 .saturating_add(APPEAL_BASIS.saturating_pow(appeals_len as u32).saturating_sub(1))
         }
 
-        // Slash the losers and use the slashed amount plus the reward pot to reward the winners.
-        fn slash_losers_to_award_winners(
-            court_id: CourtId,
-            jurors_to_stakes: BTreeMap<T::AccountId, JurorVoteWithStakesOf<T>>,
-            winner_vote_item: &VoteItem,
-        ) {
-            let mut total_incentives = <NegativeImbalanceOf<T>>::zero();
+        // Map the delegated jurors to their own slashable, vote item and delegations
+        // and collect the slashes of the tardy (juror did not reveal or did not vote)
+        // and denounced jurors.
+        fn get_jurors_to_stakes(
+            draws: &[DrawOf<T>],
+        ) -> Result<
+            (BTreeMap<T::AccountId, JurorVoteWithStakesOf<T>>, Vec<(T::AccountId, BalanceOf<T>)>),
+            DispatchError,
+        > {
+            let mut jurors_to_stakes = BTreeMap::<T::AccountId, JurorVoteWithStakesOf<T>>::new();
+            let mut tardy_slashes = Vec::<(T::AccountId, BalanceOf<T>)>::new();
 
-            let slash_all_delegators =
-                |delegations: &[(T::AccountId, BalanceOf<T>)]| -> NegativeImbalanceOf<T> {
-                    let mut total_imb = <NegativeImbalanceOf<T>>::zero();
-                    for (delegator, d_slashable) in delegations.iter() {
-                        let (imb, missing) = T::Currency::slash(delegator, *d_slashable);
-                        total_imb.subsume(imb);
-                        debug_assert!(
-                            missing.is_zero(),
-                            "Could not slash all of the amount for delegator {:?}.",
-                            delegator
-                        );
+            for draw in draws {
+                match &draw.vote {
+                    Vote::Drawn
+                    | Vote::Secret { commitment: _ }
+                    | Vote::Denounced { commitment: _, vote_item: _, salt: _ } => {
+                        tardy_slashes.push((draw.court_participant.clone(), draw.slashable));
                     }
-                    total_imb
-                };
+                    Vote::Revealed { commitment: _, vote_item, salt: _ } => {
+                        let self_info =
+                            SelfInfo { slashable: draw.slashable, vote_item: vote_item.clone() };
+                        jurors_to_stakes
+                            .entry(draw.court_participant.clone())
+                            .or_default()
+                            .self_info = Some(self_info);
+                    }
+                    Vote::Delegated { delegated_stakes } => {
+                        let delegator = &draw.court_participant;
+                        for (j, delegated_stake) in delegated_stakes {
+                            // fill the delegations for each juror
+                            // [(juror_0, [(delegator_0, delegator_stake_0), ...]),
+                            // (juror_1, [(delegator_42, delegator_stake_42), ...]), ...]
+                            let jurors_to_stakes_entry = jurors_to_stakes.entry(j.clone());
+                            let juror_vote_with_stakes = jurors_to_stakes_entry.or_default();
+
+                            // future-proof binary search by key
+                            // because many delegators can back one juror
+                            // we might want to fastly find elements later on
+                            match juror_vote_with_stakes
+                                .delegations
+                                .binary_search_by_key(delegator, |(d, _)| d.clone())
+                            {
+                                Ok(i) => {
+                                    let delegations = juror_vote_with_stakes
+                                        .delegations
+                                        .get_mut(i)
+                                        .ok_or(Error::<T>::Unexpected(
+                                            UnexpectedError::BinarySearchByKeyFailed,
+                                        ))?;
+                                    delegations.1 = delegations.1.saturating_add(*delegated_stake);
+                                }
+                                Err(i) => {
+                                    juror_vote_with_stakes
+                                        .delegations
+                                        .insert(i, (delegator.clone(), *delegated_stake));
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            Ok((jurors_to_stakes, tardy_slashes))
+        }
+
+        // Calculates who gets slashed and who gets rewarded by how much
+        // if the court of the specified draws resolves to `winner_vote_item`.
+        // This is the single source of truth for `reassign_court_stakes`
+        // and `simulate_reassign_court_stakes`.
+        //
+        // The losers and the tardy or denounced jurors (and associated delegators) get slashed.
+        // The slashed amount plus the reward pot is used to reward the winners.
+        pub(crate) fn get_reassignment(
+            court_id: CourtId,
+            draws: &[DrawOf<T>],
+            winner_vote_item: &VoteItem,
+        ) -> Result<ReassignmentOf<T>, DispatchError> {
+            let (jurors_to_stakes, mut slashes) = Self::get_jurors_to_stakes(draws)?;
 
             let mut total_winner_stake = BalanceOf::<T>::zero();
             let mut winners = Vec::<(T::AccountId, BalanceOf<T>)>::new();
@@ -2109,41 +2106,65 @@ mod pallet {
                             total_winner_stake =
                                 total_winner_stake.saturating_add(total_delegation_stake);
                         } else {
-                            let (imb, missing) = T::Currency::slash(juror, *slashable);
-                            total_incentives.subsume(imb);
-                            debug_assert!(
-                                missing.is_zero(),
-                                "Could not slash all of the amount for juror {:?}.",
-                                juror
-                            );
-
-                            let imb = slash_all_delegators(delegations.as_slice());
-                            total_incentives.subsume(imb);
+                            slashes.push((juror.clone(), *slashable));
+                            slashes.extend(delegations.clone());
                         }
                     }
                     None => {
                         // in this case the delegators have delegated their vote
                         // to a tardy or denounced juror
-                        let imb = slash_all_delegators(delegations.as_slice());
-                        total_incentives.subsume(imb);
+                        slashes.extend(delegations.clone());
                     }
                 }
             }
 
             // reward from denounce slashes and tardy jurors of this market / court
             let reward_pot = Self::reward_pot(court_id);
-            let reward = T::Currency::free_balance(&reward_pot);
-            let (imb, missing) = T::Currency::slash(&reward_pot, reward);
-            debug_assert!(missing.is_zero(), "Could not slash all of the amount for reward pot.");
-            total_incentives.subsume(imb);
+            let total_reward = slashes
+                .iter()
+                .fold(T::Currency::free_balance(&reward_pot), |acc, (_, slashable)| {
+                    acc.saturating_add(*slashable)
+                });
 
-            let total_reward = total_incentives.peek();
+            let mut remainder = total_reward;
+            let mut rewards = Vec::<(T::AccountId, BalanceOf<T>)>::with_capacity(winners.len());
             for (winner, risked_amount) in winners {
                 let r = risked_amount.saturated_into::<u128>();
                 let t = total_winner_stake.saturated_into::<u128>();
                 let share = Perquintill::from_rational(r, t);
                 let reward_per_each = (share * total_reward.saturated_into::<u128>())
-                    .saturated_into::<BalanceOf<T>>();
+                    .saturated_into::<BalanceOf<T>>()
+                    .min(remainder);
+                remainder = remainder.saturating_sub(reward_per_each);
+                rewards.push((winner, reward_per_each));
+            }
+
+            Ok(Reassignment { slashes, rewards })
+        }
+
+        // Slash the losers and use the slashed amount plus the reward pot to reward the winners.
+        fn execute_reassignment(court_id: CourtId, reassignment: ReassignmentOf<T>) {
+            let Reassignment { slashes, rewards } = reassignment;
+
+            let mut total_incentives = <NegativeImbalanceOf<T>>::zero();
+
+            for (court_participant, slashable) in slashes.iter() {
+                let (imb, missing) = T::Currency::slash(court_participant, *slashable);
+                total_incentives.subsume(imb);
+                debug_assert!(
+                    missing.is_zero(),
+                    "Could not slash all of the amount for court participant {:?}.",
+                    court_participant
+                );
+            }
+
+            let reward_pot = Self::reward_pot(court_id);
+            let reward = T::Currency::free_balance(&reward_pot);
+            let (imb, missing) = T::Currency::slash(&reward_pot, reward);
+            debug_assert!(missing.is_zero(), "Could not slash all of the amount for reward pot.");
+            total_incentives.subsume(imb);
+
+            for (winner, reward_per_each) in rewards {
                 let (actual_reward, leftover) = total_incentives.split(reward_per_each);
                 total_incentives = leftover;
                 T::Currency::resolve_creating(&winner, actual_reward);
@@ -2155,6 +2176,35 @@ mod pallet {
             }
         }
 
+        /// Simulates `reassign_court_stakes` for the specified court
+        /// as if the court resolved to `winner`, without changing any state.
+        /// Returns the total amount slashed from and rewarded to each affected account.
+        /// The court must not be reassigned yet, but it may still be open.
+        pub fn simulate_reassign_court_stakes(
+            court_id: CourtId,
+            winner: VoteItem,
+        ) -> Result<Vec<(T::AccountId, StakeReassignment<BalanceOf<T>>)>, DispatchError> {
+            let court = <Courts<T>>::get(court_id).ok_or(Error::<T>::CourtNotFound)?;
+            ensure!(court.status != CourtStatus::Reassigned, Error::<T>::CourtAlreadyReassigned);
+            Self::check_vote_item(&court, &winner)?;
+
+            let draws = SelectedDraws::<T>::get(court_id);
+            let Reassignment { slashes, rewards } =
+                Self::get_reassignment(court_id, draws.as_slice(), &winner)?;
+
+            let mut accounts = BTreeMap::<T::AccountId, StakeReassignment<BalanceOf<T>>>::new();
+            for (court_participant, slashable) in slashes {
+                let entry = accounts.entry(court_participant).or_default();
+                entry.slashed = entry.slashed.saturating_add(slashable);
+            }
+            for (winner, reward) in rewards {
+                let entry = accounts.entry(winner).or_default();
+                entry.rewarded = entry.rewarded.saturating_add(reward);
+            }
+
+            Ok(accounts.into_iter().collect())
+        }
+
         // Returns the winner of the current court round.
         // If there is no element inside `draws`, returns `None`.
         // If the best two vote items have the same score, returns the last court round winner.
@@ -25,7 +25,7 @@ use crate::{
         RuntimeOrigin, System, ALICE, BOB, CHARLIE, DAVE, EVE, INITIAL_BALANCE, POOR_PAUL,
     },
     mock_storage::pallet::MarketIdsPerDisputeBlock,
-    types::{CourtStatus, Draw, Vote, VoteItem},
+    types::{CourtStatus, Draw, StakeReassignment, Vote, VoteItem},
     AppealInfo, BalanceOf, CourtId, CourtIdToMarketId, CourtParticipantInfo,
     CourtParticipantInfoOf, CourtPool, CourtPoolItem, CourtPoolOf, Courts, Error, Event,
     MarketIdToCourtId, MarketOf, NegativeImbalanceOf, Participants, RequestBlock, SelectedDraws,
@@ -2444,6 +2444,132 @@ fn reassign_court_stakes_rewards_treasury_if_no_winner() {
     });
 }
 
+#[test]
+fn simulate_reassign_court_stakes_matches_reassign_court_stakes() {
+    ExtBuilder::default().build().execute_with(|| {
+        fill_juror_pool(MaxCourtParticipants::get());
+        let court_id = initialize_court();
+
+        let amount = MinJurorStake::get() * 100;
+        assert_ok!(Court::join_court(RuntimeOrigin::signed(ALICE), amount));
+        assert_ok!(Court::join_court(RuntimeOrigin::signed(BOB), amount));
+        assert_ok!(Court::join_court(RuntimeOrigin::signed(CHARLIE), amount));
+        assert_ok!(Court::join_court(RuntimeOrigin::signed(DAVE), amount));
+        assert_ok!(Court::join_court(RuntimeOrigin::signed(EVE), amount));
+
+        let outcome = OutcomeReport::Scalar(42u128);
+        let vote_item = VoteItem::Outcome(outcome);
+        let salt = <Runtime as frame_system::Config>::Hash::default();
+        let commitment = BlakeTwo256::hash_of(&(ALICE, vote_item.clone(), salt));
+
+        let wrong_outcome = OutcomeReport::Scalar(69u128);
+        let wrong_vote_item = VoteItem::Outcome(wrong_outcome);
+
+        let delegated_stakes_dave: crate::DelegatedStakesOf<Runtime> =
+            vec![(ALICE, 2 * MinJurorStake::get()), (BOB, MinJurorStake::get())]
+                .try_into()
+                .unwrap();
+
+        let draws: crate::SelectedDrawsOf<Runtime> = vec![
+            Draw {
+                court_participant: ALICE,
+                weight: 1,
+                vote: Vote::Revealed { commitment, vote_item: vote_item.clone(), salt },
+                slashable: MinJurorStake::get(),
+            },
+            Draw {
+                court_participant: BOB,
+                weight: 1,
+                vote: Vote::Revealed { commitment, vote_item: wrong_vote_item, salt },
+                slashable: 2 * MinJurorStake::get(),
+            },
+            Draw {
+                court_participant: CHARLIE,
+                weight: 1,
+                vote: Vote::Drawn,
+                slashable: 3 * MinJurorStake::get(),
+            },
+            Draw {
+                court_participant: DAVE,
+                weight: 1,
+                vote: Vote::Delegated { delegated_stakes: delegated_stakes_dave },
+                slashable: 3 * MinJurorStake::get(),
+            },
+        ]
+        .try_into()
+        .unwrap();
+        <SelectedDraws<Runtime>>::insert(court_id, draws);
+
+        let reward_pot = Court::reward_pot(court_id);
+        let _ = Balances::deposit(&reward_pot, MinJurorStake::get(), Precision::Exact).unwrap();
+
+        let mut court = <Courts<Runtime>>::get(court_id).unwrap();
+        court.status = CourtStatus::Closed { winner: vote_item.clone() };
+        <Courts<Runtime>>::insert(court_id, court);
+
+        let accounts = [ALICE, BOB, CHARLIE, DAVE, EVE];
+        let free_before = accounts.map(Balances::free_balance);
+
+        let root_before = storage_root(StateVersion::V1);
+        let simulation = Court::simulate_reassign_court_stakes(court_id, vote_item).unwrap();
+        assert_eq!(storage_root(StateVersion::V1), root_before);
+
+        assert_ok!(Court::reassign_court_stakes(RuntimeOrigin::signed(EVE), court_id));
+
+        for (account, free_before) in accounts.iter().zip(free_before) {
+            let StakeReassignment { slashed, rewarded } = simulation
+                .iter()
+                .find(|(acc, _)| acc == account)
+                .map(|(_, info)| info.clone())
+                .unwrap_or_default();
+            assert_eq!(Balances::free_balance(account), free_before + rewarded - slashed);
+        }
+        // EVE wasn't drawn
+        assert!(!simulation.iter().any(|(acc, _)| *acc == EVE));
+        // DAVE delegated to a winner and a loser
+        let (_, dave_info) = simulation.iter().find(|(acc, _)| *acc == DAVE).unwrap();
+        assert_eq!(dave_info.slashed, MinJurorStake::get());
+        assert!(!dave_info.rewarded.is_zero());
+    });
+}
+
+#[test]
+fn simulate_reassign_court_stakes_fails_if_court_already_reassigned() {
+    ExtBuilder::default().build().execute_with(|| {
+        let court_id = initialize_court();
+
+        let mut court = <Courts<Runtime>>::get(court_id).unwrap();
+        court.status = CourtStatus::Reassigned;
+        <Courts<Runtime>>::insert(court_id, court);
+
+        assert_noop!(
+            Court::simulate_reassign_court_stakes(court_id, VoteItem::Outcome(ORACLE_REPORT)),
+            Error::<Runtime>::CourtAlreadyReassigned
+        );
+    });
+}
+
+#[test]
+fn simulate_reassign_court_stakes_fails_if_court_not_found() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            Court::simulate_reassign_court_stakes(0, VoteItem::Outcome(ORACLE_REPORT)),
+            Error::<Runtime>::CourtNotFound
+        );
+    });
+}
+
+#[test]
+fn simulate_reassign_court_stakes_fails_for_invalid_vote_item() {
+    ExtBuilder::default().build().execute_with(|| {
+        let court_id = initialize_court();
+        assert_noop!(
+            Court::simulate_reassign_court_stakes(court_id, VoteItem::Binary(true)),
+            Error::<Runtime>::InvalidVoteItemForOutcomeCourt
+        );
+    });
+}
+
 #[test]
 fn on_dispute_denies_non_court_markets() {
     ExtBuilder::default().build().execute_with(|| {
@@ -359,6 +359,35 @@ impl<AccountId, Balance> Default for JurorVoteWithStakes<AccountId, Balance> {
     }
 }
 
+/// The slashes and rewards of a court stake reassignment.
+pub(crate) struct Reassignment<AccountId, Balance> {
+    /// The court participants to slash with the amount to slash.
+    /// A delegator can occur multiple times, once for each delegated juror.
+    pub(crate) slashes: Vec<(AccountId, Balance)>,
+    /// The winning court participants with the amount to reward.
+    pub(crate) rewards: Vec<(AccountId, Balance)>,
+}
+
+/// The total amount slashed from and rewarded to a single court participant
+/// by a (simulated) court stake reassignment.
+#[derive(
+    parity_scale_codec::Decode,
+    parity_scale_codec::Encode,
+    parity_scale_codec::MaxEncodedLen,
+    scale_info::TypeInfo,
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+)]
+pub struct StakeReassignment<Balance> {
+    /// The amount that is slashed from the court participant.
+    pub slashed: Balance,
+    /// The amount that is rewarded to the court participant.
+    pub rewarded: Balance,
+}
+
 /// An internal error type to determine how the selection of draws fails.
 pub(crate) enum SelectionError {
     NoValidDelegatedJuror,
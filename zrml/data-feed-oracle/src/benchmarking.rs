@@ -0,0 +1,166 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+#![allow(
+    // Auto-generated code is a no man's land
+    clippy::arithmetic_side_effects
+)]
+#![cfg(feature = "runtime-benchmarks")]
+
+use crate::{utils::*, Pallet as DataFeedOracle, *};
+use alloc::vec::Vec;
+use frame_benchmarking::v2::*;
+use frame_support::{
+    traits::{EnsureOrigin, Get, Hooks},
+    BoundedVec,
+};
+use frame_system::{pallet_prelude::BlockNumberFor, Pallet as System, RawOrigin};
+use sp_runtime::traits::{One, Saturating};
+use zeitgeist_primitives::types::MarketStatus;
+use zrml_market_commons::MarketCommonsPalletApi;
+
+fn setup_feeders<T: Config>(count: u32) -> Vec<T::AccountId> {
+    let feeders: Vec<T::AccountId> = (0..count).map(|i| account("feeder", i, 0)).collect();
+    Feeders::<T>::put(BoundedVec::truncate_from(feeders.clone()));
+    feeders
+}
+
+fn setup_market<T: Config>() -> MarketIdOf<T> {
+    // The market closed at block 1, so it can be reported at block 2.
+    System::<T>::set_block_number(2u32.into());
+    let market = market_mock::<T>(whitelisted_caller());
+    T::MarketCommons::push_market(market).unwrap()
+}
+
+fn setup_submissions<T: Config>(market_id: MarketIdOf<T>, feeders: Vec<T::AccountId>) {
+    let submissions: Vec<_> =
+        feeders.into_iter().enumerate().map(|(i, feeder)| (feeder, i as u128)).collect();
+    Submissions::<T>::insert(market_id, BoundedVec::truncate_from(submissions));
+}
+
+fn submission_period_end<T: Config>() -> BlockNumberFor<T> {
+    System::<T>::block_number().saturating_add(T::SubmissionPeriod::get().max(One::one()))
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn add_feeder(f: Linear<0, { T::MaxFeeders::get() - 1 }>) {
+        let origin = T::FeederOrigin::try_successful_origin().unwrap();
+        setup_feeders::<T>(f);
+        let feeder: T::AccountId = whitelisted_caller();
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, feeder.clone());
+
+        assert!(Feeders::<T>::get().contains(&feeder));
+    }
+
+    #[benchmark]
+    fn remove_feeder(f: Linear<1, { T::MaxFeeders::get() }>) {
+        let origin = T::FeederOrigin::try_successful_origin().unwrap();
+        // Removing the last feeder is the worst case.
+        let feeder = setup_feeders::<T>(f).pop().unwrap();
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, feeder.clone());
+
+        assert!(!Feeders::<T>::get().contains(&feeder));
+    }
+
+    #[benchmark]
+    fn submit_value(
+        f: Linear<1, { T::MaxFeeders::get() }>,
+        m: Linear<1, { T::MaxMarketsPerBlock::get() }>,
+    ) {
+        let market_id = setup_market::<T>();
+        let mut feeders = setup_feeders::<T>(f);
+        let caller = feeders.pop().unwrap();
+        // The worst case is the submission which starts the submission period of the market.
+        setup_submissions::<T>(market_id, feeders);
+        let period_end = submission_period_end::<T>();
+        let market_ids: Vec<_> = (1..m).map(|i| market_id + i.into()).collect();
+        MarketIdsPerSubmissionPeriodEnd::<T>::insert(
+            period_end,
+            BoundedVec::truncate_from(market_ids),
+        );
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), market_id, 50);
+
+        assert_eq!(SubmissionPeriodEnds::<T>::get(market_id), Some(period_end));
+    }
+
+    #[benchmark]
+    fn report_median(f: Linear<{ T::MinSubmissions::get() }, { T::MaxFeeders::get() }>) {
+        let market_id = setup_market::<T>();
+        setup_submissions::<T>(market_id, setup_feeders::<T>(f));
+        SubmissionPeriodEnds::<T>::insert(market_id, System::<T>::block_number());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(whitelisted_caller()), market_id);
+
+        assert!(!Submissions::<T>::contains_key(market_id));
+    }
+
+    #[benchmark]
+    fn on_initialize(m: Linear<0, { T::MaxMarketsPerBlock::get() }>) {
+        let feeders = setup_feeders::<T>(T::MaxFeeders::get());
+        let now = System::<T>::block_number().saturating_add(One::one());
+        let market_ids: Vec<_> = (0..m)
+            .map(|_| {
+                let market_id = setup_market::<T>();
+                setup_submissions::<T>(market_id, feeders.clone());
+                SubmissionPeriodEnds::<T>::insert(market_id, now);
+                market_id
+            })
+            .collect();
+        MarketIdsPerSubmissionPeriodEnd::<T>::insert(now, BoundedVec::truncate_from(market_ids));
+
+        #[block]
+        {
+            DataFeedOracle::<T>::on_initialize(now);
+        }
+
+        assert!(!MarketIdsPerSubmissionPeriodEnd::<T>::contains_key(now));
+    }
+
+    #[benchmark]
+    fn clear_submissions() {
+        let market_id = setup_market::<T>();
+        setup_submissions::<T>(market_id, setup_feeders::<T>(T::MaxFeeders::get()));
+        SubmissionPeriodEnds::<T>::insert(market_id, System::<T>::block_number());
+        T::MarketCommons::mutate_market(&market_id, |market| {
+            market.status = MarketStatus::Reported;
+            Ok(())
+        })
+        .unwrap();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(whitelisted_caller()), market_id);
+
+        assert!(!Submissions::<T>::contains_key(market_id));
+    }
+
+    impl_benchmark_test_suite!(
+        DataFeedOracle,
+        crate::mock::ExtBuilder::default().build(),
+        crate::mock::Runtime
+    );
+}
@@ -0,0 +1,472 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+#![doc = include_str!("../README.md")]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+mod benchmarking;
+mod mock;
+mod tests;
+mod utils;
+pub mod weights;
+
+pub use pallet::*;
+
+#[frame_support::pallet]
+mod pallet {
+    use crate::weights::WeightInfoZeitgeist;
+    use alloc::vec::Vec;
+    use core::marker::PhantomData;
+    use frame_support::{
+        dispatch::DispatchResultWithPostInfo,
+        ensure,
+        pallet_prelude::{Hooks, StorageMap, StorageValue, ValueQuery, Weight},
+        traits::{EnsureOrigin, Get, IsType, StorageVersion},
+        BoundedVec, PalletId, Twox64Concat,
+    };
+    use frame_system::{
+        ensure_signed,
+        pallet_prelude::{BlockNumberFor, OriginFor},
+    };
+    use sp_runtime::{
+        traits::{AccountIdConversion, One, Saturating},
+        DispatchError, DispatchResult,
+    };
+    use zeitgeist_primitives::{
+        traits::ReportMarketApi,
+        types::{Market, MarketStatus, MarketType, OutcomeReport},
+    };
+    use zrml_market_commons::MarketCommonsPalletApi;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// The origin that is allowed to add and remove feeders.
+        type FeederOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        type MarketCommons: MarketCommonsPalletApi<
+            AccountId = Self::AccountId,
+            BlockNumber = BlockNumberFor<Self>,
+        >;
+
+        /// The maximum number of feeders.
+        #[pallet::constant]
+        type MaxFeeders: Get<u32>;
+
+        /// The maximum number of markets whose submission period ends in the same block.
+        #[pallet::constant]
+        type MaxMarketsPerBlock: Get<u32>;
+
+        /// The number of submissions required before a market is reported with the median of the
+        /// submitted values.
+        #[pallet::constant]
+        type MinSubmissions: Get<u32>;
+
+        /// Identifier of this pallet. The account derived from it is the oracle of the markets
+        /// which are reported by this pallet.
+        #[pallet::constant]
+        type PalletId: Get<PalletId>;
+
+        /// Used to report markets on behalf of the oracle account of this pallet.
+        type ReportMarket: ReportMarketApi<AccountId = Self::AccountId, MarketId = MarketIdOf<Self>>;
+
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// The number of blocks after the first submission for a market during which feeders may
+        /// submit values before the market is reported with the median of all submitted values.
+        #[pallet::constant]
+        type SubmissionPeriod: Get<BlockNumberFor<Self>>;
+
+        /// Weights generated by benchmarks.
+        type WeightInfo: WeightInfoZeitgeist;
+    }
+
+    /// The current storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
+    pub(crate) type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+    pub(crate) type BalanceOf<T> =
+        <<T as Config>::MarketCommons as MarketCommonsPalletApi>::Balance;
+    pub(crate) type MarketIdOf<T> =
+        <<T as Config>::MarketCommons as MarketCommonsPalletApi>::MarketId;
+    pub(crate) type MomentOf<T> = <<T as Config>::MarketCommons as MarketCommonsPalletApi>::Moment;
+    pub(crate) type MarketOf<T> =
+        Market<AccountIdOf<T>, BalanceOf<T>, BlockNumberFor<T>, MomentOf<T>, MarketIdOf<T>>;
+    pub(crate) type FeedersOf<T> = BoundedVec<AccountIdOf<T>, <T as Config>::MaxFeeders>;
+    pub(crate) type SubmissionsOf<T> =
+        BoundedVec<(AccountIdOf<T>, u128), <T as Config>::MaxFeeders>;
+    pub(crate) type CacheOf<T> = BoundedVec<MarketIdOf<T>, <T as Config>::MaxMarketsPerBlock>;
+
+    #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
+    pub struct Pallet<T>(PhantomData<T>);
+
+    /// The accounts which are allowed to submit values.
+    #[pallet::storage]
+    pub type Feeders<T: Config> = StorageValue<_, FeedersOf<T>, ValueQuery>;
+
+    /// The values submitted for closed markets which weren't reported yet.
+    #[pallet::storage]
+    pub type Submissions<T: Config> =
+        StorageMap<_, Twox64Concat, MarketIdOf<T>, SubmissionsOf<T>, ValueQuery>;
+
+    /// Maps markets with submissions to the block in which their submission period ends.
+    #[pallet::storage]
+    pub type SubmissionPeriodEnds<T: Config> =
+        StorageMap<_, Twox64Concat, MarketIdOf<T>, BlockNumberFor<T>>;
+
+    /// Maps blocks to the markets whose submission period ends in them. These markets are
+    /// reported in `on_initialize` of the block.
+    #[pallet::storage]
+    pub type MarketIdsPerSubmissionPeriodEnd<T: Config> =
+        StorageMap<_, Twox64Concat, BlockNumberFor<T>, CacheOf<T>, ValueQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(crate) fn deposit_event)]
+    pub enum Event<T>
+    where
+        T: Config,
+    {
+        /// A feeder was added.
+        FeederAdded { feeder: AccountIdOf<T> },
+        /// A feeder was removed.
+        FeederRemoved { feeder: AccountIdOf<T> },
+        /// A feeder submitted a value for a market.
+        ValueSubmitted { market_id: MarketIdOf<T>, feeder: AccountIdOf<T>, value: u128 },
+        /// A market was reported with the median of the submitted values.
+        MarketReported { market_id: MarketIdOf<T>, value: u128 },
+        /// Reporting a market with the median of the submitted values failed. The submissions
+        /// are kept and reporting is retried with the next submission or with `report_median`.
+        MarketReportFailed { market_id: MarketIdOf<T>, value: u128, error: DispatchError },
+        /// The submissions for a market which can no longer be reported by this pallet were
+        /// removed.
+        SubmissionsCleared { market_id: MarketIdOf<T> },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// The account is already a feeder.
+        FeederAlreadyExists,
+        /// The account is not a feeder.
+        FeederNotFound,
+        /// The maximum number of feeders is reached.
+        TooManyFeeders,
+        /// The caller is not a feeder.
+        CallerNotFeeder,
+        /// The oracle of the market is not the oracle account of this pallet.
+        OracleIsNotDataFeed,
+        /// The market is not a scalar market.
+        MarketIsNotScalar,
+        /// The market is not closed.
+        MarketIsNotClosed,
+        /// The feeder already submitted a value for the market.
+        AlreadySubmitted,
+        /// Fewer than `MinSubmissions` values were submitted for the market.
+        NotEnoughSubmissions,
+        /// The submission period of the market is not over yet.
+        SubmissionPeriodNotOver,
+        /// The maximum number of markets whose submission period ends in the same block is
+        /// reached.
+        TooManyMarketsPerBlock,
+        /// No values were submitted for the market.
+        NoSubmissions,
+        /// The market can still be reported by this pallet.
+        MarketIsReportable,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+            let market_ids = MarketIdsPerSubmissionPeriodEnd::<T>::take(now);
+            let market_ids_len = market_ids.len() as u32;
+            for market_id in market_ids {
+                let submissions = Submissions::<T>::get(market_id);
+                if submissions.is_empty() {
+                    // The submissions were already cleared.
+                    continue;
+                }
+                // The market may have been reported by someone else during the submission period.
+                if Self::ensure_market_is_reportable(&market_id).is_err() {
+                    Self::remove_submissions(market_id);
+                    continue;
+                }
+                // If too few values were submitted, the market is reported as soon as enough
+                // values are submitted.
+                if (submissions.len() as u32) < T::MinSubmissions::get() {
+                    continue;
+                }
+                Self::report_or_emit_failure(market_id, Self::median(submissions));
+            }
+            T::WeightInfo::on_initialize(market_ids_len).saturating_add(
+                T::ReportMarket::report_market_weight().saturating_mul(market_ids_len.into()),
+            )
+        }
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Adds an account to the feeders.
+        ///
+        /// Complexity: `O(n)`, where `n` is the number of feeders.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::add_feeder(T::MaxFeeders::get()))]
+        #[frame_support::transactional]
+        pub fn add_feeder(origin: OriginFor<T>, feeder: AccountIdOf<T>) -> DispatchResult {
+            T::FeederOrigin::ensure_origin(origin)?;
+
+            Feeders::<T>::try_mutate(|feeders| -> DispatchResult {
+                ensure!(!feeders.contains(&feeder), Error::<T>::FeederAlreadyExists);
+                feeders.try_push(feeder.clone()).map_err(|_| Error::<T>::TooManyFeeders)?;
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::FeederAdded { feeder });
+
+            Ok(())
+        }
+
+        /// Removes an account from the feeders. Values which the feeder already submitted are
+        /// kept.
+        ///
+        /// Complexity: `O(n)`, where `n` is the number of feeders.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::remove_feeder(T::MaxFeeders::get()))]
+        #[frame_support::transactional]
+        pub fn remove_feeder(origin: OriginFor<T>, feeder: AccountIdOf<T>) -> DispatchResult {
+            T::FeederOrigin::ensure_origin(origin)?;
+
+            Feeders::<T>::try_mutate(|feeders| -> DispatchResult {
+                let index =
+                    feeders.iter().position(|f| f == &feeder).ok_or(Error::<T>::FeederNotFound)?;
+                feeders.remove(index);
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::FeederRemoved { feeder });
+
+            Ok(())
+        }
+
+        /// Submits a value for a closed scalar market whose oracle is the oracle account of this
+        /// pallet.
+        ///
+        /// The first submission for a market starts its submission period of `SubmissionPeriod`
+        /// blocks. Once the period is over, the market is reported with the median of all values
+        /// submitted so far. If fewer than `MinSubmissions` values were submitted by then, the
+        /// market is reported as soon as `MinSubmissions` values are submitted.
+        ///
+        /// # Arguments
+        ///
+        /// - `market_id`: The market to submit the value for.
+        /// - `value`: The value to report, in the units of the market's scalar range.
+        ///
+        /// Complexity: `O(n log n)`, where `n` is the number of values submitted for the market.
+        #[pallet::call_index(2)]
+        #[pallet::weight(
+            T::WeightInfo::submit_value(T::MaxFeeders::get(), T::MaxMarketsPerBlock::get())
+                .saturating_add(T::ReportMarket::report_market_weight())
+        )]
+        #[frame_support::transactional]
+        pub fn submit_value(
+            origin: OriginFor<T>,
+            #[pallet::compact] market_id: MarketIdOf<T>,
+            #[pallet::compact] value: u128,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            ensure!(Feeders::<T>::get().contains(&who), Error::<T>::CallerNotFeeder);
+            Self::ensure_market_is_reportable(&market_id)?;
+
+            let submissions = Submissions::<T>::try_mutate(
+                market_id,
+                |submissions| -> Result<SubmissionsOf<T>, DispatchError> {
+                    ensure!(
+                        !submissions.iter().any(|(feeder, _)| feeder == &who),
+                        Error::<T>::AlreadySubmitted
+                    );
+                    submissions.try_push((who.clone(), value)).map_err(|_| {
+                        // Can only happen if feeders were removed after submitting values.
+                        Error::<T>::TooManyFeeders
+                    })?;
+                    Ok(submissions.clone())
+                },
+            )?;
+
+            let now = frame_system::Pallet::<T>::block_number();
+            let (period_is_over, cache_len) = match SubmissionPeriodEnds::<T>::get(market_id) {
+                Some(period_end) => (period_end <= now, 0),
+                None => (false, Self::start_submission_period(market_id, now)?),
+            };
+
+            Self::deposit_event(Event::ValueSubmitted { market_id, feeder: who, value });
+
+            let submissions_len = submissions.len() as u32;
+            let mut weight = T::WeightInfo::submit_value(submissions_len, cache_len);
+            if period_is_over && submissions_len >= T::MinSubmissions::get() {
+                Self::report_or_emit_failure(market_id, Self::median(submissions));
+                weight = weight.saturating_add(T::ReportMarket::report_market_weight());
+            }
+
+            Ok(Some(weight).into())
+        }
+
+        /// Reports a market with the median of the submitted values once its submission period
+        /// is over. Used to retry reporting a market if the automatic report failed, e.g. because
+        /// the grace period of the market wasn't over yet.
+        ///
+        /// Complexity: `O(n log n)`, where `n` is the number of values submitted for the market.
+        #[pallet::call_index(3)]
+        #[pallet::weight(
+            T::WeightInfo::report_median(T::MaxFeeders::get())
+                .saturating_add(T::ReportMarket::report_market_weight())
+        )]
+        #[frame_support::transactional]
+        pub fn report_median(
+            origin: OriginFor<T>,
+            #[pallet::compact] market_id: MarketIdOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+
+            Self::ensure_market_is_reportable(&market_id)?;
+
+            let submissions = Submissions::<T>::get(market_id);
+            let submissions_len = submissions.len() as u32;
+            ensure!(submissions_len >= T::MinSubmissions::get(), Error::<T>::NotEnoughSubmissions);
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(
+                SubmissionPeriodEnds::<T>::get(market_id)
+                    .is_some_and(|period_end| period_end <= now),
+                Error::<T>::SubmissionPeriodNotOver
+            );
+            Self::do_report(market_id, Self::median(submissions))?;
+
+            Ok(Some(
+                T::WeightInfo::report_median(submissions_len)
+                    .saturating_add(T::ReportMarket::report_market_weight()),
+            )
+            .into())
+        }
+
+        /// Removes the submissions for a market which can no longer be reported by this pallet,
+        /// e.g. because it was reported by someone else before enough values were submitted or
+        /// because it was destroyed.
+        ///
+        /// Complexity: `O(1)`
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::clear_submissions())]
+        #[frame_support::transactional]
+        pub fn clear_submissions(
+            origin: OriginFor<T>,
+            #[pallet::compact] market_id: MarketIdOf<T>,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            ensure!(Submissions::<T>::contains_key(market_id), Error::<T>::NoSubmissions);
+            ensure!(
+                Self::ensure_market_is_reportable(&market_id).is_err(),
+                Error::<T>::MarketIsReportable
+            );
+            Self::remove_submissions(market_id);
+
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// The account which has to be set as oracle of markets reported by this pallet.
+        pub fn oracle_account() -> AccountIdOf<T> {
+            T::PalletId::get().into_account_truncating()
+        }
+
+        fn ensure_market_is_reportable(market_id: &MarketIdOf<T>) -> DispatchResult {
+            let market: MarketOf<T> = T::MarketCommons::market(market_id)?;
+            ensure!(market.oracle == Self::oracle_account(), Error::<T>::OracleIsNotDataFeed);
+            ensure!(
+                matches!(market.market_type, MarketType::Scalar(_)),
+                Error::<T>::MarketIsNotScalar
+            );
+            ensure!(market.status == MarketStatus::Closed, Error::<T>::MarketIsNotClosed);
+            Ok(())
+        }
+
+        /// Starts the submission period of `market_id`, which ends `SubmissionPeriod` blocks
+        /// (but at least one block) after `now`. Returns the number of markets whose submission
+        /// period ends in the same block.
+        fn start_submission_period(
+            market_id: MarketIdOf<T>,
+            now: BlockNumberFor<T>,
+        ) -> Result<u32, DispatchError> {
+            let period_end = now.saturating_add(T::SubmissionPeriod::get().max(One::one()));
+            let cache_len = MarketIdsPerSubmissionPeriodEnd::<T>::try_mutate(
+                period_end,
+                |market_ids| -> Result<u32, DispatchError> {
+                    market_ids
+                        .try_push(market_id)
+                        .map_err(|_| Error::<T>::TooManyMarketsPerBlock)?;
+                    Ok(market_ids.len() as u32)
+                },
+            )?;
+            SubmissionPeriodEnds::<T>::insert(market_id, period_end);
+            Ok(cache_len)
+        }
+
+        /// Reports the market with `value` and removes its submissions. Doesn't change any
+        /// storage if the report fails.
+        fn do_report(market_id: MarketIdOf<T>, value: u128) -> DispatchResult {
+            T::ReportMarket::report_market(
+                Self::oracle_account(),
+                market_id,
+                OutcomeReport::Scalar(value),
+            )?;
+            Submissions::<T>::remove(market_id);
+            SubmissionPeriodEnds::<T>::remove(market_id);
+            Self::deposit_event(Event::MarketReported { market_id, value });
+            Ok(())
+        }
+
+        /// Removes the submissions for `market_id` without reporting the market.
+        fn remove_submissions(market_id: MarketIdOf<T>) {
+            Submissions::<T>::remove(market_id);
+            SubmissionPeriodEnds::<T>::remove(market_id);
+            Self::deposit_event(Event::SubmissionsCleared { market_id });
+        }
+
+        /// Reports the market with `value`. A failed report must not revert the submissions, so
+        /// that the report can be retried using `report_median`.
+        fn report_or_emit_failure(market_id: MarketIdOf<T>, value: u128) {
+            if let Err(error) = Self::do_report(market_id, value) {
+                Self::deposit_event(Event::MarketReportFailed { market_id, value, error });
+            }
+        }
+
+        /// Returns the median of the submitted values. If the number of values is even, the mean
+        /// of the two middle values is returned, rounded down.
+        fn median(submissions: SubmissionsOf<T>) -> u128 {
+            let mut values: Vec<u128> = submissions.into_iter().map(|(_, value)| value).collect();
+            values.sort_unstable();
+            let mid = values.len() / 2;
+            match (values.get(mid.saturating_sub(1)), values.get(mid)) {
+                (Some(lower), Some(upper)) if values.len() % 2 == 0 => {
+                    lower.saturating_add(upper.saturating_sub(*lower) / 2)
+                }
+                (_, Some(value)) => *value,
+                _ => 0,
+            }
+        }
+    }
+}
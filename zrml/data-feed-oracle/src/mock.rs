@@ -0,0 +1,200 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(test)]
+
+extern crate alloc;
+
+use crate as zrml_data_feed_oracle;
+use crate::MarketIdOf;
+use alloc::{vec, vec::Vec};
+use core::cell::RefCell;
+use frame_support::{construct_runtime, parameter_types, traits::Everything, weights::Weight};
+use frame_system::{mocking::MockBlock, EnsureSignedBy};
+use sp_runtime::{
+    traits::{BlakeTwo256, IdentityLookup},
+    BuildStorage, DispatchError, DispatchResult,
+};
+use zeitgeist_primitives::{
+    constants::mock::{
+        BlockHashCount, DataFeedMaxMarketsPerBlock, DataFeedOraclePalletId,
+        DataFeedSubmissionPeriod, MaxFeeders, MinSubmissions, MinimumPeriod,
+    },
+    traits::ReportMarketApi,
+    types::{AccountIdTest, Balance, Hash, MarketId, MarketStatus, Moment, OutcomeReport},
+};
+use zrml_market_commons::MarketCommonsPalletApi;
+
+pub const ALICE: AccountIdTest = 0;
+pub const BOB: AccountIdTest = 1;
+pub const CHARLIE: AccountIdTest = 2;
+pub const DAVE: AccountIdTest = 3;
+pub const EVE: AccountIdTest = 4;
+
+pub const MARKET_CREATOR: AccountIdTest = 42;
+pub const SUDO: AccountIdTest = 1337;
+
+parameter_types! {
+    pub const Sudo: AccountIdTest = SUDO;
+}
+
+thread_local! {
+    pub static REPORT_MARKET_CALL_DATA: RefCell<Vec<ReportMarketCallData>> =
+        const { RefCell::new(vec![]) };
+    pub static REPORT_MARKET_RETURN_VALUE: RefCell<DispatchResult> = const { RefCell::new(Ok(())) };
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReportMarketCallData {
+    pub who: AccountIdTest,
+    pub market_id: MarketIdOf<Runtime>,
+    pub outcome: OutcomeReport,
+}
+
+/// Mock for the prediction markets pallet. Records the reports and marks the market as reported
+/// if `REPORT_MARKET_RETURN_VALUE` is `Ok`.
+pub struct ReportMarketMock;
+
+impl ReportMarketMock {
+    pub fn set_return_value(value: DispatchResult) {
+        REPORT_MARKET_RETURN_VALUE.with(|v| *v.borrow_mut() = value);
+    }
+
+    pub fn called_with(who: AccountIdTest, market_id: MarketId, outcome: OutcomeReport) -> bool {
+        let expected = ReportMarketCallData { who, market_id, outcome };
+        REPORT_MARKET_CALL_DATA.with(|values| values.borrow().contains(&expected))
+    }
+
+    pub fn call_count() -> usize {
+        REPORT_MARKET_CALL_DATA.with(|values| values.borrow().len())
+    }
+}
+
+impl ReportMarketApi for ReportMarketMock {
+    type AccountId = AccountIdTest;
+    type MarketId = MarketIdOf<Runtime>;
+
+    fn report_market(
+        who: Self::AccountId,
+        market_id: Self::MarketId,
+        outcome: OutcomeReport,
+    ) -> DispatchResult {
+        REPORT_MARKET_CALL_DATA.with(|values| {
+            values.borrow_mut().push(ReportMarketCallData { who, market_id, outcome })
+        });
+        REPORT_MARKET_RETURN_VALUE.with(|value| *value.borrow())?;
+        MarketCommons::mutate_market(&market_id, |market| {
+            market.status = MarketStatus::Reported;
+            Ok(())
+        })
+    }
+
+    fn report_market_weight() -> Weight {
+        Weight::zero()
+    }
+}
+
+construct_runtime!(
+    pub enum Runtime {
+        DataFeedOracle: zrml_data_feed_oracle,
+        MarketCommons: zrml_market_commons,
+        System: frame_system,
+        Timestamp: pallet_timestamp,
+    }
+);
+
+impl crate::Config for Runtime {
+    type FeederOrigin = EnsureSignedBy<Sudo, AccountIdTest>;
+    type MarketCommons = MarketCommons;
+    type MaxFeeders = MaxFeeders;
+    type MaxMarketsPerBlock = DataFeedMaxMarketsPerBlock;
+    type MinSubmissions = MinSubmissions;
+    type PalletId = DataFeedOraclePalletId;
+    type ReportMarket = ReportMarketMock;
+    type RuntimeEvent = RuntimeEvent;
+    type SubmissionPeriod = DataFeedSubmissionPeriod;
+    type WeightInfo = crate::weights::WeightInfo<Runtime>;
+}
+
+impl frame_system::Config for Runtime {
+    type AccountData = ();
+    type AccountId = AccountIdTest;
+    type BaseCallFilter = Everything;
+    type Block = MockBlock<Runtime>;
+    type BlockHashCount = BlockHashCount;
+    type BlockLength = ();
+    type BlockWeights = ();
+    type RuntimeCall = RuntimeCall;
+    type RuntimeTask = RuntimeTask;
+    type DbWeight = ();
+    type RuntimeEvent = RuntimeEvent;
+    type Hash = Hash;
+    type Hashing = BlakeTwo256;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Nonce = u64;
+    type MaxConsumers = frame_support::traits::ConstU32<16>;
+    type MultiBlockMigrator = ();
+    type OnKilledAccount = ();
+    type OnNewAccount = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type PalletInfo = PalletInfo;
+    type PreInherents = ();
+    type PostInherents = ();
+    type PostTransactions = ();
+    type SingleBlockMigrations = ();
+    type SS58Prefix = ();
+    type SystemWeightInfo = ();
+    type Version = ();
+    type OnSetCode = ();
+}
+
+impl zrml_market_commons::Config for Runtime {
+    type Balance = Balance;
+    type MarketId = MarketId;
+    type Timestamp = Timestamp;
+}
+
+impl pallet_timestamp::Config for Runtime {
+    type MinimumPeriod = MinimumPeriod;
+    type Moment = Moment;
+    type OnTimestampSet = ();
+    type WeightInfo = ();
+}
+
+#[derive(Default)]
+pub struct ExtBuilder;
+
+impl ExtBuilder {
+    pub fn build(self) -> sp_io::TestExternalities {
+        let t = frame_system::GenesisConfig::<Runtime>::default().build_storage().unwrap();
+
+        // see the logs in tests when using `RUST_LOG=debug cargo test -- --nocapture`
+        let _ = env_logger::builder().is_test(true).try_init();
+
+        let mut t: sp_io::TestExternalities = t.into();
+
+        // to ensure we can have events emitted in the tests. events not present at genesis block
+        t.execute_with(|| System::set_block_number(1));
+
+        t
+    }
+}
+
+/// Error returned by the `ReportMarketMock` if a report is supposed to fail.
+pub fn report_market_error() -> DispatchError {
+    DispatchError::Other("report failed")
+}
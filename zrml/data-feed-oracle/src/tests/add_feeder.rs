@@ -0,0 +1,63 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use frame_support::{assert_noop, assert_ok, traits::Get};
+use sp_runtime::DispatchError;
+
+#[test]
+fn add_feeder_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(DataFeedOracle::add_feeder(RuntimeOrigin::signed(SUDO), ALICE));
+        assert_eq!(Feeders::<Runtime>::get().into_inner(), vec![ALICE]);
+        System::assert_last_event(Event::FeederAdded { feeder: ALICE }.into());
+    });
+}
+
+#[test]
+fn add_feeder_fails_on_bad_origin() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            DataFeedOracle::add_feeder(RuntimeOrigin::signed(ALICE), ALICE),
+            DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn add_feeder_fails_if_feeder_already_exists() {
+    ExtBuilder::default().build().execute_with(|| {
+        add_feeders(&[ALICE]);
+        assert_noop!(
+            DataFeedOracle::add_feeder(RuntimeOrigin::signed(SUDO), ALICE),
+            Error::<Runtime>::FeederAlreadyExists
+        );
+    });
+}
+
+#[test]
+fn add_feeder_fails_if_there_are_too_many_feeders() {
+    ExtBuilder::default().build().execute_with(|| {
+        let max_feeders: u32 = <Runtime as Config>::MaxFeeders::get();
+        let feeders: Vec<_> = (0..max_feeders.into()).collect();
+        add_feeders(&feeders);
+        assert_noop!(
+            DataFeedOracle::add_feeder(RuntimeOrigin::signed(SUDO), max_feeders.into()),
+            Error::<Runtime>::TooManyFeeders
+        );
+    });
+}
@@ -0,0 +1,84 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use zeitgeist_primitives::types::MarketStatus;
+
+fn set_market_status(market_id: MarketIdOf<Runtime>, status: MarketStatus) {
+    Markets::<Runtime>::mutate(market_id, |market| market.as_mut().unwrap().status = status);
+}
+
+#[test]
+fn clear_submissions_removes_submissions_of_market_reported_by_someone_else() {
+    ExtBuilder::default().build().execute_with(|| {
+        add_feeders(&[ALICE, BOB]);
+        let market_id = setup_closed_market();
+        assert_ok!(DataFeedOracle::submit_value(RuntimeOrigin::signed(ALICE), market_id, 5));
+        assert_ok!(DataFeedOracle::submit_value(RuntimeOrigin::signed(BOB), market_id, 3));
+        end_submission_period(market_id);
+        set_market_status(market_id, MarketStatus::Reported);
+
+        assert_ok!(DataFeedOracle::clear_submissions(RuntimeOrigin::signed(EVE), market_id));
+
+        assert!(!Submissions::<Runtime>::contains_key(market_id));
+        assert!(!SubmissionPeriodEnds::<Runtime>::contains_key(market_id));
+        System::assert_last_event(Event::SubmissionsCleared { market_id }.into());
+    });
+}
+
+#[test]
+fn clear_submissions_removes_submissions_of_destroyed_market() {
+    ExtBuilder::default().build().execute_with(|| {
+        add_feeders(&[ALICE]);
+        let market_id = setup_closed_market();
+        assert_ok!(DataFeedOracle::submit_value(RuntimeOrigin::signed(ALICE), market_id, 5));
+        Markets::<Runtime>::remove(market_id);
+
+        assert_ok!(DataFeedOracle::clear_submissions(RuntimeOrigin::signed(EVE), market_id));
+
+        assert!(!Submissions::<Runtime>::contains_key(market_id));
+        assert!(!SubmissionPeriodEnds::<Runtime>::contains_key(market_id));
+    });
+}
+
+#[test]
+fn clear_submissions_fails_if_market_is_reportable() {
+    ExtBuilder::default().build().execute_with(|| {
+        add_feeders(&[ALICE]);
+        let market_id = setup_closed_market();
+        assert_ok!(DataFeedOracle::submit_value(RuntimeOrigin::signed(ALICE), market_id, 5));
+
+        assert_noop!(
+            DataFeedOracle::clear_submissions(RuntimeOrigin::signed(EVE), market_id),
+            Error::<Runtime>::MarketIsReportable
+        );
+    });
+}
+
+#[test]
+fn clear_submissions_fails_if_there_are_no_submissions() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = setup_closed_market();
+        set_market_status(market_id, MarketStatus::Reported);
+
+        assert_noop!(
+            DataFeedOracle::clear_submissions(RuntimeOrigin::signed(EVE), market_id),
+            Error::<Runtime>::NoSubmissions
+        );
+    });
+}
@@ -0,0 +1,49 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(test)]
+
+mod add_feeder;
+mod clear_submissions;
+mod on_initialize;
+mod remove_feeder;
+mod report_median;
+mod submit_value;
+
+use crate::{mock::*, utils::*, *};
+use frame_support::{assert_ok, traits::Hooks};
+use zeitgeist_primitives::types::AccountIdTest;
+use zrml_market_commons::Markets;
+
+fn setup_closed_market() -> MarketIdOf<Runtime> {
+    let market_id = 0;
+    Markets::<Runtime>::insert(market_id, market_mock::<Runtime>(MARKET_CREATOR));
+    market_id
+}
+
+fn add_feeders(feeders: &[AccountIdTest]) {
+    for feeder in feeders {
+        assert_ok!(DataFeedOracle::add_feeder(RuntimeOrigin::signed(SUDO), *feeder));
+    }
+}
+
+/// Moves to the end of the submission period of `market_id` and runs `on_initialize`.
+fn end_submission_period(market_id: MarketIdOf<Runtime>) {
+    let period_end = SubmissionPeriodEnds::<Runtime>::get(market_id).unwrap();
+    System::set_block_number(period_end);
+    DataFeedOracle::on_initialize(period_end);
+}
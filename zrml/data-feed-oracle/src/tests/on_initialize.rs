@@ -0,0 +1,112 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use test_case::test_case;
+use zeitgeist_primitives::types::{MarketStatus, OutcomeReport};
+
+#[test_case(&[(ALICE, 30), (BOB, 10), (CHARLIE, 20)], 20; "odd")]
+#[test_case(&[(ALICE, 30), (BOB, 10), (CHARLIE, 20), (DAVE, 25)], 22; "even rounds down")]
+#[test_case(&[(ALICE, 7), (BOB, 7), (CHARLIE, 100), (DAVE, 0), (EVE, 7)], 7; "outliers")]
+fn on_initialize_reports_median_at_end_of_submission_period(
+    submissions: &[(AccountIdTest, u128)],
+    expected: u128,
+) {
+    ExtBuilder::default().build().execute_with(|| {
+        let feeders: Vec<_> = submissions.iter().map(|(feeder, _)| *feeder).collect();
+        add_feeders(&feeders);
+        let market_id = setup_closed_market();
+        for (feeder, value) in submissions {
+            assert_ok!(DataFeedOracle::submit_value(
+                RuntimeOrigin::signed(*feeder),
+                market_id,
+                *value
+            ));
+        }
+        let period_end = SubmissionPeriodEnds::<Runtime>::get(market_id).unwrap();
+
+        end_submission_period(market_id);
+
+        assert_eq!(ReportMarketMock::call_count(), 1);
+        assert!(ReportMarketMock::called_with(
+            DataFeedOracle::oracle_account(),
+            market_id,
+            OutcomeReport::Scalar(expected)
+        ));
+        assert!(!Submissions::<Runtime>::contains_key(market_id));
+        assert!(!SubmissionPeriodEnds::<Runtime>::contains_key(market_id));
+        assert!(!MarketIdsPerSubmissionPeriodEnd::<Runtime>::contains_key(period_end));
+        System::assert_last_event(Event::MarketReported { market_id, value: expected }.into());
+    });
+}
+
+#[test]
+fn on_initialize_keeps_submissions_if_report_fails() {
+    ExtBuilder::default().build().execute_with(|| {
+        add_feeders(&[ALICE, BOB, CHARLIE]);
+        let market_id = setup_closed_market();
+        assert_ok!(DataFeedOracle::submit_value(RuntimeOrigin::signed(ALICE), market_id, 1));
+        assert_ok!(DataFeedOracle::submit_value(RuntimeOrigin::signed(BOB), market_id, 2));
+        assert_ok!(DataFeedOracle::submit_value(RuntimeOrigin::signed(CHARLIE), market_id, 3));
+        ReportMarketMock::set_return_value(Err(report_market_error()));
+
+        end_submission_period(market_id);
+
+        assert_eq!(ReportMarketMock::call_count(), 1);
+        assert_eq!(Submissions::<Runtime>::get(market_id).len(), 3);
+        assert!(SubmissionPeriodEnds::<Runtime>::contains_key(market_id));
+        System::assert_last_event(
+            Event::MarketReportFailed { market_id, value: 2, error: report_market_error() }.into(),
+        );
+    });
+}
+
+#[test]
+fn on_initialize_does_not_report_if_not_enough_values_are_submitted() {
+    ExtBuilder::default().build().execute_with(|| {
+        add_feeders(&[ALICE, BOB]);
+        let market_id = setup_closed_market();
+        assert_ok!(DataFeedOracle::submit_value(RuntimeOrigin::signed(ALICE), market_id, 1));
+        assert_ok!(DataFeedOracle::submit_value(RuntimeOrigin::signed(BOB), market_id, 2));
+
+        end_submission_period(market_id);
+
+        assert_eq!(ReportMarketMock::call_count(), 0);
+        assert_eq!(Submissions::<Runtime>::get(market_id).len(), 2);
+    });
+}
+
+#[test]
+fn on_initialize_clears_submissions_if_market_was_reported_by_someone_else() {
+    ExtBuilder::default().build().execute_with(|| {
+        add_feeders(&[ALICE, BOB, CHARLIE]);
+        let market_id = setup_closed_market();
+        assert_ok!(DataFeedOracle::submit_value(RuntimeOrigin::signed(ALICE), market_id, 1));
+        assert_ok!(DataFeedOracle::submit_value(RuntimeOrigin::signed(BOB), market_id, 2));
+        assert_ok!(DataFeedOracle::submit_value(RuntimeOrigin::signed(CHARLIE), market_id, 3));
+        Markets::<Runtime>::mutate(market_id, |market| {
+            market.as_mut().unwrap().status = MarketStatus::Reported
+        });
+
+        end_submission_period(market_id);
+
+        assert_eq!(ReportMarketMock::call_count(), 0);
+        assert!(!Submissions::<Runtime>::contains_key(market_id));
+        assert!(!SubmissionPeriodEnds::<Runtime>::contains_key(market_id));
+        System::assert_last_event(Event::SubmissionsCleared { market_id }.into());
+    });
+}
@@ -0,0 +1,52 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::DispatchError;
+
+#[test]
+fn remove_feeder_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        add_feeders(&[ALICE, BOB, CHARLIE]);
+        assert_ok!(DataFeedOracle::remove_feeder(RuntimeOrigin::signed(SUDO), BOB));
+        assert_eq!(Feeders::<Runtime>::get().into_inner(), vec![ALICE, CHARLIE]);
+        System::assert_last_event(Event::FeederRemoved { feeder: BOB }.into());
+    });
+}
+
+#[test]
+fn remove_feeder_fails_on_bad_origin() {
+    ExtBuilder::default().build().execute_with(|| {
+        add_feeders(&[ALICE]);
+        assert_noop!(
+            DataFeedOracle::remove_feeder(RuntimeOrigin::signed(ALICE), ALICE),
+            DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn remove_feeder_fails_if_feeder_not_found() {
+    ExtBuilder::default().build().execute_with(|| {
+        add_feeders(&[ALICE]);
+        assert_noop!(
+            DataFeedOracle::remove_feeder(RuntimeOrigin::signed(SUDO), BOB),
+            Error::<Runtime>::FeederNotFound
+        );
+    });
+}
@@ -0,0 +1,95 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use zeitgeist_primitives::types::OutcomeReport;
+
+#[test]
+fn report_median_retries_failed_report() {
+    ExtBuilder::default().build().execute_with(|| {
+        add_feeders(&[ALICE, BOB, CHARLIE]);
+        let market_id = setup_closed_market();
+        ReportMarketMock::set_return_value(Err(report_market_error()));
+        assert_ok!(DataFeedOracle::submit_value(RuntimeOrigin::signed(ALICE), market_id, 5));
+        assert_ok!(DataFeedOracle::submit_value(RuntimeOrigin::signed(BOB), market_id, 3));
+        assert_ok!(DataFeedOracle::submit_value(RuntimeOrigin::signed(CHARLIE), market_id, 4));
+        end_submission_period(market_id);
+
+        ReportMarketMock::set_return_value(Ok(()));
+        assert_ok!(DataFeedOracle::report_median(RuntimeOrigin::signed(EVE), market_id));
+
+        assert_eq!(ReportMarketMock::call_count(), 2);
+        assert!(ReportMarketMock::called_with(
+            DataFeedOracle::oracle_account(),
+            market_id,
+            OutcomeReport::Scalar(4)
+        ));
+        assert!(!Submissions::<Runtime>::contains_key(market_id));
+        assert!(!SubmissionPeriodEnds::<Runtime>::contains_key(market_id));
+        System::assert_last_event(Event::MarketReported { market_id, value: 4 }.into());
+    });
+}
+
+#[test]
+fn report_median_propagates_report_error() {
+    ExtBuilder::default().build().execute_with(|| {
+        add_feeders(&[ALICE, BOB, CHARLIE]);
+        let market_id = setup_closed_market();
+        ReportMarketMock::set_return_value(Err(report_market_error()));
+        assert_ok!(DataFeedOracle::submit_value(RuntimeOrigin::signed(ALICE), market_id, 5));
+        assert_ok!(DataFeedOracle::submit_value(RuntimeOrigin::signed(BOB), market_id, 3));
+        assert_ok!(DataFeedOracle::submit_value(RuntimeOrigin::signed(CHARLIE), market_id, 4));
+        end_submission_period(market_id);
+
+        assert_noop!(
+            DataFeedOracle::report_median(RuntimeOrigin::signed(EVE), market_id),
+            report_market_error()
+        );
+    });
+}
+
+#[test]
+fn report_median_fails_if_not_enough_submissions() {
+    ExtBuilder::default().build().execute_with(|| {
+        add_feeders(&[ALICE, BOB]);
+        let market_id = setup_closed_market();
+        assert_ok!(DataFeedOracle::submit_value(RuntimeOrigin::signed(ALICE), market_id, 5));
+        assert_ok!(DataFeedOracle::submit_value(RuntimeOrigin::signed(BOB), market_id, 3));
+
+        assert_noop!(
+            DataFeedOracle::report_median(RuntimeOrigin::signed(EVE), market_id),
+            Error::<Runtime>::NotEnoughSubmissions
+        );
+    });
+}
+
+#[test]
+fn report_median_fails_if_submission_period_is_not_over() {
+    ExtBuilder::default().build().execute_with(|| {
+        add_feeders(&[ALICE, BOB, CHARLIE]);
+        let market_id = setup_closed_market();
+        assert_ok!(DataFeedOracle::submit_value(RuntimeOrigin::signed(ALICE), market_id, 5));
+        assert_ok!(DataFeedOracle::submit_value(RuntimeOrigin::signed(BOB), market_id, 3));
+        assert_ok!(DataFeedOracle::submit_value(RuntimeOrigin::signed(CHARLIE), market_id, 4));
+
+        assert_noop!(
+            DataFeedOracle::report_median(RuntimeOrigin::signed(EVE), market_id),
+            Error::<Runtime>::SubmissionPeriodNotOver
+        );
+    });
+}
@@ -0,0 +1,190 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use test_case::test_case;
+use zeitgeist_primitives::{
+    constants::mock::{DataFeedMaxMarketsPerBlock, DataFeedSubmissionPeriod},
+    types::{MarketStatus, MarketType, OutcomeReport},
+};
+use zrml_market_commons::Error as MError;
+
+#[test]
+fn submit_value_stores_value_and_emits_event() {
+    ExtBuilder::default().build().execute_with(|| {
+        add_feeders(&[ALICE, BOB, CHARLIE]);
+        let market_id = setup_closed_market();
+
+        assert_ok!(DataFeedOracle::submit_value(RuntimeOrigin::signed(ALICE), market_id, 42));
+
+        assert_eq!(Submissions::<Runtime>::get(market_id).into_inner(), vec![(ALICE, 42)]);
+        let period_end = 1 + DataFeedSubmissionPeriod::get();
+        assert_eq!(SubmissionPeriodEnds::<Runtime>::get(market_id), Some(period_end));
+        assert_eq!(
+            MarketIdsPerSubmissionPeriodEnd::<Runtime>::get(period_end).into_inner(),
+            vec![market_id]
+        );
+        System::assert_last_event(
+            Event::ValueSubmitted { market_id, feeder: ALICE, value: 42 }.into(),
+        );
+        assert_eq!(ReportMarketMock::call_count(), 0);
+    });
+}
+
+#[test]
+fn submit_value_does_not_report_during_submission_period() {
+    ExtBuilder::default().build().execute_with(|| {
+        add_feeders(&[ALICE, BOB, CHARLIE, DAVE]);
+        let market_id = setup_closed_market();
+        assert_ok!(DataFeedOracle::submit_value(RuntimeOrigin::signed(ALICE), market_id, 1));
+        assert_ok!(DataFeedOracle::submit_value(RuntimeOrigin::signed(BOB), market_id, 2));
+        assert_ok!(DataFeedOracle::submit_value(RuntimeOrigin::signed(CHARLIE), market_id, 3));
+        System::set_block_number(SubmissionPeriodEnds::<Runtime>::get(market_id).unwrap() - 1);
+        assert_ok!(DataFeedOracle::submit_value(RuntimeOrigin::signed(DAVE), market_id, 4));
+
+        assert_eq!(ReportMarketMock::call_count(), 0);
+        assert_eq!(Submissions::<Runtime>::get(market_id).len(), 4);
+    });
+}
+
+#[test]
+fn submit_value_reports_median_if_enough_values_are_submitted_after_submission_period() {
+    ExtBuilder::default().build().execute_with(|| {
+        add_feeders(&[ALICE, BOB, CHARLIE]);
+        let market_id = setup_closed_market();
+        assert_ok!(DataFeedOracle::submit_value(RuntimeOrigin::signed(ALICE), market_id, 5));
+        assert_ok!(DataFeedOracle::submit_value(RuntimeOrigin::signed(BOB), market_id, 3));
+        end_submission_period(market_id);
+        assert_eq!(ReportMarketMock::call_count(), 0);
+
+        System::set_block_number(System::block_number() + 1);
+        assert_ok!(DataFeedOracle::submit_value(RuntimeOrigin::signed(CHARLIE), market_id, 4));
+
+        assert!(ReportMarketMock::called_with(
+            DataFeedOracle::oracle_account(),
+            market_id,
+            OutcomeReport::Scalar(4)
+        ));
+        assert!(!Submissions::<Runtime>::contains_key(market_id));
+        assert!(!SubmissionPeriodEnds::<Runtime>::contains_key(market_id));
+        System::assert_last_event(Event::MarketReported { market_id, value: 4 }.into());
+    });
+}
+
+#[test]
+fn submit_value_fails_if_too_many_submission_periods_end_in_the_same_block() {
+    ExtBuilder::default().build().execute_with(|| {
+        add_feeders(&[ALICE]);
+        let max_markets: MarketIdOf<Runtime> = DataFeedMaxMarketsPerBlock::get().into();
+        for market_id in 0..=max_markets {
+            Markets::<Runtime>::insert(market_id, market_mock::<Runtime>(MARKET_CREATOR));
+        }
+        for market_id in 0..max_markets {
+            assert_ok!(DataFeedOracle::submit_value(RuntimeOrigin::signed(ALICE), market_id, 1));
+        }
+        assert_noop!(
+            DataFeedOracle::submit_value(RuntimeOrigin::signed(ALICE), max_markets, 1),
+            Error::<Runtime>::TooManyMarketsPerBlock
+        );
+    });
+}
+
+#[test]
+fn submit_value_fails_if_caller_is_not_feeder() {
+    ExtBuilder::default().build().execute_with(|| {
+        add_feeders(&[ALICE]);
+        let market_id = setup_closed_market();
+        assert_noop!(
+            DataFeedOracle::submit_value(RuntimeOrigin::signed(BOB), market_id, 1),
+            Error::<Runtime>::CallerNotFeeder
+        );
+    });
+}
+
+#[test]
+fn submit_value_fails_if_market_does_not_exist() {
+    ExtBuilder::default().build().execute_with(|| {
+        add_feeders(&[ALICE]);
+        assert_noop!(
+            DataFeedOracle::submit_value(RuntimeOrigin::signed(ALICE), 0, 1),
+            MError::<Runtime>::MarketDoesNotExist
+        );
+    });
+}
+
+#[test]
+fn submit_value_fails_if_oracle_is_not_data_feed() {
+    ExtBuilder::default().build().execute_with(|| {
+        add_feeders(&[ALICE]);
+        let market_id = 0;
+        let mut market = market_mock::<Runtime>(MARKET_CREATOR);
+        market.oracle = BOB;
+        Markets::<Runtime>::insert(market_id, market);
+        assert_noop!(
+            DataFeedOracle::submit_value(RuntimeOrigin::signed(ALICE), market_id, 1),
+            Error::<Runtime>::OracleIsNotDataFeed
+        );
+    });
+}
+
+#[test]
+fn submit_value_fails_if_market_is_not_scalar() {
+    ExtBuilder::default().build().execute_with(|| {
+        add_feeders(&[ALICE]);
+        let market_id = 0;
+        let mut market = market_mock::<Runtime>(MARKET_CREATOR);
+        market.market_type = MarketType::Categorical(2);
+        Markets::<Runtime>::insert(market_id, market);
+        assert_noop!(
+            DataFeedOracle::submit_value(RuntimeOrigin::signed(ALICE), market_id, 1),
+            Error::<Runtime>::MarketIsNotScalar
+        );
+    });
+}
+
+#[test_case(MarketStatus::Proposed)]
+#[test_case(MarketStatus::Active)]
+#[test_case(MarketStatus::Reported)]
+#[test_case(MarketStatus::Disputed)]
+#[test_case(MarketStatus::Resolved)]
+fn submit_value_fails_if_market_is_not_closed(status: MarketStatus) {
+    ExtBuilder::default().build().execute_with(|| {
+        add_feeders(&[ALICE]);
+        let market_id = 0;
+        let mut market = market_mock::<Runtime>(MARKET_CREATOR);
+        market.status = status;
+        Markets::<Runtime>::insert(market_id, market);
+        assert_noop!(
+            DataFeedOracle::submit_value(RuntimeOrigin::signed(ALICE), market_id, 1),
+            Error::<Runtime>::MarketIsNotClosed
+        );
+    });
+}
+
+#[test]
+fn submit_value_fails_if_feeder_already_submitted() {
+    ExtBuilder::default().build().execute_with(|| {
+        add_feeders(&[ALICE, BOB, CHARLIE]);
+        let market_id = setup_closed_market();
+        assert_ok!(DataFeedOracle::submit_value(RuntimeOrigin::signed(ALICE), market_id, 1));
+        assert_noop!(
+            DataFeedOracle::submit_value(RuntimeOrigin::signed(ALICE), market_id, 2),
+            Error::<Runtime>::AlreadySubmitted
+        );
+    });
+}
@@ -0,0 +1,52 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+#[cfg(any(feature = "runtime-benchmarks", test))]
+pub(crate) fn market_mock<T>(creator: T::AccountId) -> crate::MarketOf<T>
+where
+    T: crate::Config,
+{
+    use sp_runtime::Perbill;
+    use zeitgeist_primitives::types::{
+        Asset, Deadlines, MarketBonds, MarketCreation, MarketDisputeMechanism, MarketPeriod,
+        MarketStatus, MarketType, ScoringRule,
+    };
+
+    zeitgeist_primitives::types::Market {
+        market_id: Default::default(),
+        base_asset: Asset::Ztg,
+        creation: MarketCreation::Permissionless,
+        creator_fee: Perbill::zero(),
+        creator,
+        market_type: MarketType::Scalar(0u128..=100u128),
+        dispute_mechanism: Some(MarketDisputeMechanism::Authorized),
+        metadata: Default::default(),
+        oracle: crate::Pallet::<T>::oracle_account(),
+        period: MarketPeriod::Block(0u32.into()..1u32.into()),
+        deadlines: Deadlines {
+            grace_period: 0u32.into(),
+            oracle_duration: 10u32.into(),
+            dispute_duration: 10u32.into(),
+        },
+        report: None,
+        resolved_outcome: None,
+        scoring_rule: ScoringRule::AmmCdaHybrid,
+        status: MarketStatus::Closed,
+        bonds: MarketBonds::default(),
+        early_close: None,
+    }
+}
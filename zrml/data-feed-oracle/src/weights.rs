@@ -0,0 +1,135 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+//! Placeholder weights for zrml_data_feed_oracle
+//!
+//! These weights are hand-written estimates and have not been measured. Run the
+//! benchmarks of `zrml_data_feed_oracle` on reference hardware and replace this
+//! file with the generated output before the pallet is added to a runtime.
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use core::marker::PhantomData;
+use frame_support::{traits::Get, weights::Weight};
+
+///  Trait containing the required functions for weight retrival within
+/// zrml_data_feed_oracle (automatically generated)
+pub trait WeightInfoZeitgeist {
+    fn add_feeder(f: u32) -> Weight;
+    fn remove_feeder(f: u32) -> Weight;
+    fn submit_value(f: u32, m: u32) -> Weight;
+    fn report_median(f: u32) -> Weight;
+    fn on_initialize(m: u32) -> Weight;
+    fn clear_submissions() -> Weight;
+}
+
+/// Weight functions for zrml_data_feed_oracle (automatically generated)
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
+    /// Storage: `DataFeedOracle::Feeders` (r:1 w:1)
+    /// Proof: `DataFeedOracle::Feeders` (`max_values`: Some(1), `max_size`: Some(513), added: 1008, mode: `MaxEncodedLen`)
+    /// The range of component `f` is `[0, 15]`.
+    fn add_feeder(f: u32) -> Weight {
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_data_feed_oracle` to replace it.
+        Weight::from_parts(12_105_361, 1998)
+            .saturating_add(Weight::from_parts(98_447, 0).saturating_mul(f.into()))
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: `DataFeedOracle::Feeders` (r:1 w:1)
+    /// Proof: `DataFeedOracle::Feeders` (`max_values`: Some(1), `max_size`: Some(513), added: 1008, mode: `MaxEncodedLen`)
+    /// The range of component `f` is `[1, 16]`.
+    fn remove_feeder(f: u32) -> Weight {
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_data_feed_oracle` to replace it.
+        Weight::from_parts(12_473_952, 1998)
+            .saturating_add(Weight::from_parts(104_718, 0).saturating_mul(f.into()))
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: `DataFeedOracle::Feeders` (r:1 w:0)
+    /// Proof: `DataFeedOracle::Feeders` (`max_values`: Some(1), `max_size`: Some(513), added: 1008, mode: `MaxEncodedLen`)
+    /// Storage: `MarketCommons::Markets` (r:1 w:0)
+    /// Proof: `MarketCommons::Markets` (`max_values`: None, `max_size`: Some(708), added: 3183, mode: `MaxEncodedLen`)
+    /// Storage: `DataFeedOracle::Submissions` (r:1 w:1)
+    /// Proof: `DataFeedOracle::Submissions` (`max_values`: None, `max_size`: Some(793), added: 3268, mode: `MaxEncodedLen`)
+    /// Storage: `DataFeedOracle::SubmissionPeriodEnds` (r:1 w:1)
+    /// Proof: `DataFeedOracle::SubmissionPeriodEnds` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+    /// Storage: `DataFeedOracle::MarketIdsPerSubmissionPeriodEnd` (r:1 w:1)
+    /// Proof: `DataFeedOracle::MarketIdsPerSubmissionPeriodEnd` (`max_values`: None, `max_size`: Some(142), added: 2617, mode: `MaxEncodedLen`)
+    /// The range of component `f` is `[1, 16]`.
+    /// The range of component `m` is `[1, 16]`.
+    fn submit_value(f: u32, m: u32) -> Weight {
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_data_feed_oracle` to replace it.
+        Weight::from_parts(37_981_426, 4258)
+            .saturating_add(Weight::from_parts(311_593, 0).saturating_mul(f.into()))
+            .saturating_add(Weight::from_parts(120_000, 0).saturating_mul(m.into()))
+            .saturating_add(T::DbWeight::get().reads(5))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+    /// Storage: `MarketCommons::Markets` (r:1 w:0)
+    /// Proof: `MarketCommons::Markets` (`max_values`: None, `max_size`: Some(708), added: 3183, mode: `MaxEncodedLen`)
+    /// Storage: `DataFeedOracle::Submissions` (r:1 w:1)
+    /// Proof: `DataFeedOracle::Submissions` (`max_values`: None, `max_size`: Some(793), added: 3268, mode: `MaxEncodedLen`)
+    /// Storage: `DataFeedOracle::SubmissionPeriodEnds` (r:1 w:1)
+    /// Proof: `DataFeedOracle::SubmissionPeriodEnds` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+    /// The range of component `f` is `[1, 16]`.
+    fn report_median(f: u32) -> Weight {
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_data_feed_oracle` to replace it.
+        Weight::from_parts(25_614_087, 4258)
+            .saturating_add(Weight::from_parts(276_105, 0).saturating_mul(f.into()))
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+    /// Storage: `DataFeedOracle::MarketIdsPerSubmissionPeriodEnd` (r:1 w:1)
+    /// Proof: `DataFeedOracle::MarketIdsPerSubmissionPeriodEnd` (`max_values`: None, `max_size`: Some(142), added: 2617, mode: `MaxEncodedLen`)
+    /// Storage: `DataFeedOracle::Submissions` (r:16 w:16)
+    /// Proof: `DataFeedOracle::Submissions` (`max_values`: None, `max_size`: Some(793), added: 3268, mode: `MaxEncodedLen`)
+    /// Storage: `MarketCommons::Markets` (r:16 w:0)
+    /// Proof: `MarketCommons::Markets` (`max_values`: None, `max_size`: Some(708), added: 3183, mode: `MaxEncodedLen`)
+    /// Storage: `DataFeedOracle::SubmissionPeriodEnds` (r:0 w:16)
+    /// Proof: `DataFeedOracle::SubmissionPeriodEnds` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+    /// The range of component `m` is `[0, 16]`.
+    fn on_initialize(m: u32) -> Weight {
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_data_feed_oracle` to replace it.
+        Weight::from_parts(4_530_000, 3607)
+            .saturating_add(Weight::from_parts(9_800_000, 0).saturating_mul(m.into()))
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(m.into())))
+            .saturating_add(T::DbWeight::get().writes(1))
+            .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(m.into())))
+            .saturating_add(Weight::from_parts(0, 6451).saturating_mul(m.into()))
+    }
+    /// Storage: `DataFeedOracle::Submissions` (r:1 w:1)
+    /// Proof: `DataFeedOracle::Submissions` (`max_values`: None, `max_size`: Some(793), added: 3268, mode: `MaxEncodedLen`)
+    /// Storage: `MarketCommons::Markets` (r:1 w:0)
+    /// Proof: `MarketCommons::Markets` (`max_values`: None, `max_size`: Some(708), added: 3183, mode: `MaxEncodedLen`)
+    /// Storage: `DataFeedOracle::SubmissionPeriodEnds` (r:0 w:1)
+    /// Proof: `DataFeedOracle::SubmissionPeriodEnds` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+    fn clear_submissions() -> Weight {
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_data_feed_oracle` to replace it.
+        Weight::from_parts(18_500_000, 4258)
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+}
@@ -17,15 +17,59 @@
 
 #![cfg(feature = "runtime-benchmarks")]
 
-use crate::{traits::ProposalStorage, types::Proposal, Call, Config, Event, Pallet, Proposals};
+use crate::{
+    traits::ProposalStorage,
+    types::{DispatchOutcome, Proposal, ProposalInfo},
+    BalanceOf, Call, CallOf, Config, DispatchOutcomes, Event, NextProposalId, Pallet,
+    ProposalCount, ProposalId, ProposalIdsPerEvaluationBlock, Proposals, ScheduledProposals,
+};
 use alloc::vec;
 use frame_benchmarking::v2::*;
 use frame_support::{
     dispatch::RawOrigin,
-    traits::{Bounded, Get},
+    traits::{Bounded, Currency, EnsureOrigin, Get, NamedReservableCurrency, QueryPreimage},
 };
-use frame_system::Pallet as System;
-use zeitgeist_primitives::traits::FutarchyBenchmarkHelper;
+use frame_system::{pallet_prelude::BlockNumberFor, Pallet as System};
+use parity_scale_codec::Encode;
+use sp_runtime::Saturating;
+use zeitgeist_primitives::{traits::FutarchyBenchmarkHelper, types::Bond};
+
+fn fund_proposer<T: Config>() -> (T::AccountId, BalanceOf<T>) {
+    let caller: T::AccountId = whitelisted_caller();
+    let value = T::ProposalBond::get();
+    let _ = T::Currency::deposit_creating(&caller, value.saturating_mul(2u8.into()));
+
+    (caller, value)
+}
+
+fn create_proposal<T: Config>(when: BlockNumberFor<T>) -> Proposal<T> {
+    let oracle = T::BenchmarkHelper::create_oracle(true);
+
+    Proposal {
+        when,
+        call: Bounded::Inline(vec![7u8; 128].try_into().unwrap()),
+        origin: RawOrigin::Root.into(),
+        oracle,
+        bond: None,
+    }
+}
+
+/// Fills storage with `n` proposals to be evaluated at `evaluate_at`, bypassing the checks of
+/// `ProposalStorage::add`.
+fn insert_proposals<T: Config>(evaluate_at: BlockNumberFor<T>, proposal: Proposal<T>, n: u32) {
+    let mut proposal_ids = ProposalIdsPerEvaluationBlock::<T>::get(evaluate_at);
+    for proposal_id in 0..n {
+        let proposal_id: ProposalId = proposal_id.into();
+        Proposals::<T>::insert(
+            proposal_id,
+            ProposalInfo { evaluate_at, proposal: proposal.clone() },
+        );
+        proposal_ids.try_push(proposal_id).unwrap();
+    }
+    ProposalIdsPerEvaluationBlock::<T>::insert(evaluate_at, proposal_ids);
+    ProposalCount::<T>::put(n);
+    NextProposalId::<T>::put(ProposalId::from(n));
+}
 
 #[benchmarks]
 mod benchmarks {
@@ -34,59 +78,71 @@ mod benchmarks {
     #[benchmark]
     fn submit_proposal() {
         let duration = T::MinDuration::get();
-
-        let oracle = T::BenchmarkHelper::create_oracle(true);
-        let proposal = Proposal {
-            when: Default::default(),
-            call: Bounded::Inline(vec![7u8; 128].try_into().unwrap()),
-            oracle,
-        };
+        let mut proposal = create_proposal::<T>(Default::default());
+        // Worst case: The preimage of the call is requested.
+        proposal.call = Bounded::Lookup { hash: Default::default(), len: 128 };
 
         let now = System::<T>::block_number();
         let to_be_scheduled_at = now + duration;
-        let mut proposals = Proposals::<T>::get(to_be_scheduled_at);
-        for _ in 0..(T::MaxProposals::get() - 1) {
-            proposals.try_push(proposal.clone()).unwrap();
-        }
-        Proposals::<T>::insert(to_be_scheduled_at, proposals);
+        let proposal_count = T::MaxProposals::get() - 1;
+        insert_proposals::<T>(to_be_scheduled_at, proposal.clone(), proposal_count);
 
         #[extrinsic_call]
         _(RawOrigin::Root, duration, proposal.clone());
 
-        let expected_event =
-            <T as Config>::RuntimeEvent::from(Event::<T>::Submitted { duration, proposal });
+        let expected_event = <T as Config>::RuntimeEvent::from(Event::<T>::Submitted {
+            proposal_id: proposal_count.into(),
+            duration,
+            proposal,
+        });
         System::<T>::assert_last_event(expected_event.into());
     }
 
+    #[benchmark]
+    fn submit_bonded_proposal() {
+        let duration = T::MinDuration::get();
+        let (caller, value) = fund_proposer::<T>();
+
+        // The deployment of the oracle is accounted for by `OracleDeployer::deploy_oracle_weight`,
+        // so we only benchmark reserving the bond and storing the proposal.
+        let proposal = create_proposal::<T>(Default::default());
+
+        let now = System::<T>::block_number();
+        let to_be_scheduled_at = now + duration;
+        insert_proposals::<T>(to_be_scheduled_at, proposal.clone(), T::MaxProposals::get() - 1);
+
+        #[block]
+        {
+            Pallet::<T>::reserve_bond_and_submit_proposal(caller.clone(), duration, proposal)
+                .unwrap();
+        }
+
+        assert_eq!(T::Currency::reserved_balance_named(&Pallet::<T>::reserve_id(), &caller), value);
+    }
+
     #[benchmark]
     fn maybe_schedule_proposal() {
-        let when = u32::MAX.into();
-        let oracle = T::BenchmarkHelper::create_oracle(true);
-        let proposal =
-            Proposal { when, call: Bounded::Inline(vec![7u8; 128].try_into().unwrap()), oracle };
+        let (caller, value) = fund_proposer::<T>();
+        T::Currency::reserve_named(&Pallet::<T>::reserve_id(), &caller, value).unwrap();
+        let mut proposal = create_proposal::<T>(u32::MAX.into());
+        proposal.bond = Some(Bond::new(caller, value));
 
         #[block]
         {
-            Pallet::<T>::maybe_schedule_proposal(proposal.clone());
+            Pallet::<T>::maybe_schedule_proposal(0, proposal.clone());
         }
 
-        let expected_event = <T as Config>::RuntimeEvent::from(Event::<T>::Scheduled { proposal });
+        let expected_event =
+            <T as Config>::RuntimeEvent::from(Event::<T>::Scheduled { proposal_id: 0, proposal });
         System::<T>::assert_last_event(expected_event.into());
     }
 
     #[benchmark]
     fn take_proposals(n: Linear<1, 4>) {
-        let when = u32::MAX.into();
-        let oracle = T::BenchmarkHelper::create_oracle(true);
-        let proposal =
-            Proposal { when, call: Bounded::Inline(vec![7u8; 128].try_into().unwrap()), oracle };
+        let proposal = create_proposal::<T>(u32::MAX.into());
 
         let now = System::<T>::block_number();
-        let mut proposals = Proposals::<T>::get(now);
-        for _ in 0..n {
-            proposals.try_push(proposal.clone()).unwrap();
-        }
-        Proposals::<T>::insert(now, proposals);
+        insert_proposals::<T>(now, proposal, n);
 
         #[block]
         {
@@ -94,6 +150,50 @@ mod benchmarks {
         }
     }
 
+    #[benchmark]
+    fn cancel_proposal() {
+        let origin = T::VetoOrigin::try_successful_origin().unwrap();
+        let (caller, value) = fund_proposer::<T>();
+        T::Currency::reserve_named(&Pallet::<T>::reserve_id(), &caller, value).unwrap();
+        let mut proposal = create_proposal::<T>(Default::default());
+        proposal.bond = Some(Bond::new(caller.clone(), value));
+        // Worst case: The requested preimage of the call is released.
+        proposal.call = Bounded::Lookup { hash: Default::default(), len: 128 };
+        T::Preimages::hold(&proposal.call);
+
+        let now = System::<T>::block_number();
+        let to_be_scheduled_at = now + T::MinDuration::get();
+        insert_proposals::<T>(to_be_scheduled_at, proposal.clone(), T::MaxProposals::get());
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, 0);
+
+        let expected_event =
+            <T as Config>::RuntimeEvent::from(Event::<T>::Cancelled { proposal_id: 0, proposal });
+        System::<T>::assert_last_event(expected_event.into());
+    }
+
+    #[benchmark]
+    fn dispatch_proposal() {
+        let call: CallOf<T> = frame_system::Call::<T>::remark { remark: vec![] }.into();
+        let mut proposal = create_proposal::<T>(Default::default());
+        proposal.call = Bounded::Inline(call.encode().try_into().unwrap());
+
+        // Worst case: The oldest outcome is removed.
+        let max_outcomes: ProposalId = T::MaxDispatchOutcomes::get().into();
+        let proposal_id = max_outcomes;
+        for old_proposal_id in 0..max_outcomes {
+            let outcome = DispatchOutcome { dispatched_at: Default::default(), result: Ok(()) };
+            Pallet::<T>::record_dispatch_outcome(old_proposal_id, outcome);
+        }
+        ScheduledProposals::<T>::insert(proposal_id, proposal);
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, proposal_id);
+
+        assert!(DispatchOutcomes::<T>::get(proposal_id).unwrap().result.is_ok());
+    }
+
     impl_benchmark_test_suite!(
         Pallet,
         crate::mock::ext_builder::ExtBuilder::build(),
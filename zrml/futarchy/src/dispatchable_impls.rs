@@ -15,10 +15,22 @@
 // You should have received a copy of the GNU General Public License
 // along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{traits::ProposalStorage, types::Proposal, Config, Error, Event, Pallet};
-use frame_support::{ensure, require_transactional, traits::Get};
+use crate::{
+    traits::ProposalStorage,
+    types::{DispatchOutcome, Proposal},
+    weights::WeightInfoZeitgeist,
+    BoundedCallOf, CallOf, Config, Error, Event, OracleMetadataOf, Pallet, PalletsOriginOf,
+    ProposalId, ScheduledProposals,
+};
+use frame_support::{
+    ensure, require_transactional,
+    traits::{
+        Bounded, CallerTrait, Contains, EnsureOrigin, Get, NamedReservableCurrency, QueryPreimage,
+    },
+};
 use frame_system::pallet_prelude::BlockNumberFor;
-use sp_runtime::{DispatchResult, Saturating};
+use sp_runtime::{DispatchResult, DispatchResultWithPostInfo, Saturating};
+use zeitgeist_primitives::{traits::FutarchyOracleDeployer, types::Bond};
 
 impl<T: Config> Pallet<T> {
     #[require_transactional]
@@ -27,14 +39,99 @@ impl<T: Config> Pallet<T> {
         proposal: Proposal<T>,
     ) -> DispatchResult {
         ensure!(duration >= T::MinDuration::get(), Error::<T>::DurationTooShort);
+        // Oracles decoded from a call bypass the checks of their constructors.
+        ensure!(proposal.oracle.is_valid(), Error::<T>::InvalidCompositeOracle);
 
         let now = frame_system::Pallet::<T>::block_number();
         let to_be_scheduled_at = now.saturating_add(duration);
 
-        <Pallet<T> as ProposalStorage<T>>::add(to_be_scheduled_at, proposal.clone())?;
+        let proposal_id =
+            <Pallet<T> as ProposalStorage<T>>::add(to_be_scheduled_at, proposal.clone())?;
 
-        Self::deposit_event(Event::<T>::Submitted { duration, proposal });
+        // Keep the preimage of the call until the proposal is dispatched or dropped. No-op for
+        // inlined calls.
+        T::Preimages::hold(&proposal.call);
+
+        Self::deposit_event(Event::<T>::Submitted { proposal_id, duration, proposal });
 
         Ok(())
     }
+
+    #[require_transactional]
+    pub(crate) fn do_submit_bonded_proposal(
+        who: T::AccountId,
+        duration: BlockNumberFor<T>,
+        when: BlockNumberFor<T>,
+        call: BoundedCallOf<T>,
+        origin: PalletsOriginOf<T>,
+        oracle_metadata: OracleMetadataOf<T>,
+    ) -> DispatchResult {
+        ensure!(duration >= T::MinDuration::get(), Error::<T>::DurationTooShort);
+        ensure!(
+            !origin.is_root()
+                && T::AllowedDispatchOrigin::try_origin(origin.clone().into()).is_ok(),
+            Error::<T>::DispatchOriginNotAllowed
+        );
+
+        // Inlined calls can be checked without reading the preimage from storage.
+        ensure!(matches!(call, Bounded::Inline(_)), Error::<T>::CallNotInline);
+        let (inner_call, _) = T::Preimages::peek::<CallOf<T>>(&call)?;
+        ensure!(T::BondedCallFilter::contains(&inner_call), Error::<T>::CallNotAllowed);
+
+        let now = frame_system::Pallet::<T>::block_number();
+        let evaluate_at = now.saturating_add(duration);
+        let oracle = T::OracleDeployer::deploy_oracle(
+            who.clone(),
+            evaluate_at,
+            T::BondedOracleParams::get(),
+            oracle_metadata,
+        )?;
+        let proposal = Proposal { when, call, origin, oracle, bond: None };
+
+        Self::reserve_bond_and_submit_proposal(who, duration, proposal)
+    }
+
+    /// Reserves the proposal bond of `who` and submits `proposal` on their behalf.
+    #[require_transactional]
+    pub(crate) fn reserve_bond_and_submit_proposal(
+        who: T::AccountId,
+        duration: BlockNumberFor<T>,
+        mut proposal: Proposal<T>,
+    ) -> DispatchResult {
+        let value = T::ProposalBond::get();
+        T::Currency::reserve_named(&Self::reserve_id(), &who, value)?;
+        proposal.bond = Some(Bond::new(who, value));
+
+        Self::do_submit_proposal(duration, proposal)
+    }
+
+    #[require_transactional]
+    pub(crate) fn do_cancel_proposal(proposal_id: ProposalId) -> DispatchResult {
+        let info = <Pallet<T> as ProposalStorage<T>>::remove(proposal_id)?;
+        let proposal = info.proposal;
+
+        T::Preimages::drop(&proposal.call);
+        if let Some(bond) = proposal.bond.clone() {
+            Self::unreserve_bond(bond);
+        }
+
+        Self::deposit_event(Event::<T>::Cancelled { proposal_id, proposal });
+
+        Ok(())
+    }
+
+    #[require_transactional]
+    pub(crate) fn do_dispatch_proposal(proposal_id: ProposalId) -> DispatchResultWithPostInfo {
+        let proposal = ScheduledProposals::<T>::take(proposal_id)
+            .ok_or(Error::<T>::ScheduledProposalNotFound)?;
+
+        let (result, call_weight) = Self::dispatch_call(proposal);
+
+        let dispatched_at = frame_system::Pallet::<T>::block_number();
+        Self::record_dispatch_outcome(proposal_id, DispatchOutcome { dispatched_at, result });
+
+        Self::deposit_event(Event::<T>::Dispatched { proposal_id, result });
+
+        Ok(Some(T::WeightInfo::dispatch_proposal().saturating_add(call_weight)).into())
+    }
 }
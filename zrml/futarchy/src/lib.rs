@@ -40,6 +40,7 @@ extern crate alloc;
 
 mod benchmarking;
 mod dispatchable_impls;
+pub mod migrations;
 pub mod mock;
 mod pallet_impls;
 mod proposal_storage;
@@ -52,32 +53,70 @@ pub use pallet::*;
 
 #[frame_support::pallet]
 mod pallet {
-    use crate::{traits::ProposalStorage, types::Proposal, weights::WeightInfoZeitgeist};
-    use alloc::fmt::Debug;
+    use crate::{
+        traits::ProposalStorage,
+        types::{DispatchOutcome, Proposal, ProposalInfo, ProposalStatus},
+        weights::WeightInfoZeitgeist,
+    };
+    use alloc::{boxed::Box, fmt::Debug, vec::Vec};
     use core::marker::PhantomData;
     use frame_support::{
-        pallet_prelude::{IsType, StorageMap, StorageValue, StorageVersion, ValueQuery, Weight},
-        traits::{schedule::v3::Anon as ScheduleAnon, Bounded, Hooks, OriginTrait},
-        transactional, Blake2_128Concat, BoundedVec,
+        dispatch::{GetDispatchInfo, PostDispatchInfo},
+        ensure,
+        pallet_prelude::{
+            CountedStorageMap, IsType, StorageMap, StorageValue, StorageVersion, ValueQuery, Weight,
+        },
+        traits::{
+            schedule::v3::Anon as ScheduleAnon, Bounded, Contains, Currency, EnsureOrigin, Hooks,
+            NamedReservableCurrency, OnUnbalanced, OriginTrait, QueryPreimage,
+        },
+        transactional, Blake2_128Concat, BoundedVec, PalletId, Parameter, Twox64Concat,
     };
     use frame_system::{
-        ensure_root,
+        ensure_root, ensure_signed,
         pallet_prelude::{BlockNumberFor, OriginFor},
     };
     use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
     use scale_info::TypeInfo;
-    use sp_runtime::{traits::Get, DispatchResult, SaturatedConversion};
-    use zeitgeist_primitives::traits::FutarchyOracle;
+    use sp_runtime::{
+        traits::{Dispatchable, Get},
+        DispatchResult, DispatchResultWithPostInfo, SaturatedConversion,
+    };
+    use zeitgeist_primitives::traits::{FutarchyOracle, FutarchyOracleDeployer};
 
     #[cfg(feature = "runtime-benchmarks")]
     use zeitgeist_primitives::traits::FutarchyBenchmarkHelper;
 
     #[pallet::config]
     pub trait Config: frame_system::Config {
+        /// The origins which the calls of proposals submitted without root privileges may be
+        /// dispatched with. The dispatch origin of a proposal is checked when the proposal is
+        /// submitted. The root origin is never allowed, even if this origin allows it.
+        type AllowedDispatchOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
         #[cfg(feature = "runtime-benchmarks")]
         type BenchmarkHelper: FutarchyBenchmarkHelper<Self::Oracle>;
 
-        /// The maximum number of proposals allowed to be in flight simultaneously.
+        /// The calls which proposals submitted without root privileges may dispatch.
+        type BondedCallFilter: Contains<CallOf<Self>>;
+
+        /// The parameters used to deploy the oracles of proposals submitted without root
+        /// privileges. Set by governance; the proposer only provides the metadata of the markets.
+        type BondedOracleParams: Get<OracleParamsOf<Self>>;
+
+        /// The currency used to reserve the bonds of proposals submitted without root privileges.
+        type Currency: NamedReservableCurrency<Self::AccountId, ReserveIdentifier = [u8; 8]>;
+
+        /// The maximum weight of the call of a proposal submitted without root privileges. Calls
+        /// which exceed this weight fail when dispatched. The calls of proposals submitted with
+        /// root privileges aren't limited.
+        type MaxCallWeight: Get<Weight>;
+
+        /// The maximum number of dispatch outcomes kept in `DispatchOutcomes`. The oldest outcome
+        /// is removed when a new one is recorded.
+        type MaxDispatchOutcomes: Get<u32>;
+
+        /// The maximum number of proposals allowed to be in flight or scheduled simultaneously.
         type MaxProposals: Get<u32>;
 
         /// The minimum allowed duration between the creation of a proposal and its evaluation.
@@ -94,6 +133,30 @@ mod pallet {
             + PartialEq
             + TypeInfo;
 
+        /// Deploys the markets and pools backing the oracle of a bonded proposal.
+        type OracleDeployer: FutarchyOracleDeployer<
+            AccountId = Self::AccountId,
+            BlockNumber = BlockNumberFor<Self>,
+            Oracle = Self::Oracle,
+        >;
+
+        /// Identifier of this pallet, used for named reserves.
+        type PalletId: Get<PalletId>;
+
+        /// Provider used to look up the calls of proposals when they are dispatched. The preimages
+        /// of the calls are requested when a proposal is submitted and released when it's
+        /// dispatched, cancelled or rejected.
+        type Preimages: QueryPreimage<H = Self::Hashing>;
+
+        /// The bond reserved when submitting a proposal without root privileges.
+        type ProposalBond: Get<BalanceOf<Self>>;
+
+        type RuntimeCall: Parameter
+            + Dispatchable<RuntimeOrigin = Self::RuntimeOrigin, PostInfo = PostDispatchInfo>
+            + GetDispatchInfo
+            + From<Call<Self>>
+            + IsType<<Self as frame_system::Config>::RuntimeCall>;
+
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
         /// Scheduler interface for executing proposals.
@@ -104,6 +167,12 @@ mod pallet {
             Hasher = <Self as frame_system::Config>::Hashing,
         >;
 
+        /// Handler for slashed proposal bonds.
+        type Slash: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+        /// The origin that is allowed to cancel proposals in flight.
+        type VetoOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
         type WeightInfo: WeightInfoZeitgeist;
     }
 
@@ -111,22 +180,61 @@ mod pallet {
     #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(PhantomData<T>);
 
+    pub(crate) type BalanceOf<T> =
+        <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
     pub(crate) type CallOf<T> = <T as frame_system::Config>::RuntimeCall;
+    pub(crate) type DispatchOutcomeOf<T> = DispatchOutcome<BlockNumberFor<T>>;
     pub(crate) type BoundedCallOf<T> = Bounded<CallOf<T>, <T as frame_system::Config>::Hashing>;
+    pub(crate) type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
+        <T as frame_system::Config>::AccountId,
+    >>::NegativeImbalance;
     pub(crate) type OracleOf<T> = <T as Config>::Oracle;
+    pub(crate) type OracleMetadataOf<T> =
+        <<T as Config>::OracleDeployer as FutarchyOracleDeployer>::Metadata;
+    pub(crate) type OracleParamsOf<T> =
+        <<T as Config>::OracleDeployer as FutarchyOracleDeployer>::Params;
     pub(crate) type PalletsOriginOf<T> =
         <<T as frame_system::Config>::RuntimeOrigin as OriginTrait>::PalletsOrigin;
-    pub(crate) type ProposalsOf<T> = BoundedVec<Proposal<T>, <T as Config>::MaxProposals>;
+    pub(crate) type DispatchOutcomeIdsOf<T> =
+        BoundedVec<ProposalId, <T as Config>::MaxDispatchOutcomes>;
+    pub(crate) type ProposalIdsOf<T> = BoundedVec<ProposalId, <T as Config>::MaxProposals>;
+    pub(crate) type ProposalStatusOf<T> =
+        ProposalStatus<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>;
+
+    pub type ProposalId = u128;
 
-    pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+    pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
 
+    /// The proposals in flight.
     #[pallet::storage]
-    pub type Proposals<T: Config> =
-        StorageMap<_, Blake2_128Concat, BlockNumberFor<T>, ProposalsOf<T>, ValueQuery>;
+    pub type Proposals<T: Config> = StorageMap<_, Twox64Concat, ProposalId, ProposalInfo<T>>;
+
+    /// The ids of the proposals which are evaluated at a certain block.
+    #[pallet::storage]
+    pub type ProposalIdsPerEvaluationBlock<T: Config> =
+        StorageMap<_, Blake2_128Concat, BlockNumberFor<T>, ProposalIdsOf<T>, ValueQuery>;
 
     #[pallet::storage]
     pub type ProposalCount<T: Config> = StorageValue<_, u32, ValueQuery>;
 
+    /// The id of the next proposal.
+    #[pallet::storage]
+    pub type NextProposalId<T: Config> = StorageValue<_, ProposalId, ValueQuery>;
+
+    /// The proposals which have been scheduled for dispatch, but not yet dispatched.
+    #[pallet::storage]
+    pub type ScheduledProposals<T: Config> =
+        CountedStorageMap<_, Twox64Concat, ProposalId, Proposal<T>>;
+
+    /// The outcomes of the last `MaxDispatchOutcomes` dispatched proposals.
+    #[pallet::storage]
+    pub type DispatchOutcomes<T: Config> =
+        StorageMap<_, Twox64Concat, ProposalId, DispatchOutcomeOf<T>>;
+
+    /// The ids of the proposals in `DispatchOutcomes`, from oldest to newest.
+    #[pallet::storage]
+    pub type DispatchOutcomeIds<T: Config> = StorageValue<_, DispatchOutcomeIdsOf<T>, ValueQuery>;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(crate) fn deposit_event)]
     pub enum Event<T>
@@ -134,16 +242,28 @@ mod pallet {
         T: Config,
     {
         /// A proposal has been submitted.
-        Submitted { duration: BlockNumberFor<T>, proposal: Proposal<T> },
+        Submitted { proposal_id: ProposalId, duration: BlockNumberFor<T>, proposal: Proposal<T> },
 
         /// A proposal has been rejected by the oracle.
-        Rejected { proposal: Proposal<T> },
+        Rejected { proposal_id: ProposalId, proposal: Proposal<T> },
 
         /// A proposal has been scheduled for execution.
-        Scheduled { proposal: Proposal<T> },
+        Scheduled { proposal_id: ProposalId, proposal: Proposal<T> },
+
+        /// A proposal has been cancelled by the veto origin.
+        Cancelled { proposal_id: ProposalId, proposal: Proposal<T> },
+
+        /// The call of a scheduled proposal has been dispatched.
+        Dispatched { proposal_id: ProposalId, result: DispatchResult },
 
         /// This is a logic error. You shouldn't see this.
         UnexpectedSchedulerError,
+
+        /// The bond of a proposal has been returned to the proposer.
+        BondUnreserved { who: T::AccountId, value: BalanceOf<T> },
+
+        /// The bond of a proposal has been slashed.
+        BondSlashed { who: T::AccountId, value: BalanceOf<T> },
     }
 
     #[pallet::error]
@@ -156,6 +276,31 @@ mod pallet {
 
         /// This is a logic error. You shouldn't see this.
         UnexpectedStorageFailure,
+
+        /// Proposals submitted with root privileges must not have a bond.
+        UnexpectedBond,
+
+        /// The specified proposal doesn't exist or is no longer in flight.
+        ProposalNotFound,
+
+        /// The sub-oracles of a composite oracle don't match its rule or the oracle is otherwise
+        /// invalid.
+        InvalidCompositeOracle,
+
+        /// The dispatch origin of the proposal is root or not allowed by `AllowedDispatchOrigin`.
+        DispatchOriginNotAllowed,
+
+        /// The specified proposal isn't scheduled for dispatch.
+        ScheduledProposalNotFound,
+
+        /// The weight of the call of the bonded proposal exceeds `MaxCallWeight`.
+        CallWeightTooHigh,
+
+        /// Proposals submitted without root privileges must inline their call.
+        CallNotInline,
+
+        /// The call of the proposal is not allowed by `BondedCallFilter`.
+        CallNotAllowed,
     }
 
     #[pallet::call]
@@ -173,9 +318,82 @@ mod pallet {
             proposal: Proposal<T>,
         ) -> DispatchResult {
             ensure_root(origin)?;
+            ensure!(proposal.bond.is_none(), Error::<T>::UnexpectedBond);
 
             Self::do_submit_proposal(duration, proposal)
         }
+
+        /// Submits a proposal to execute `call` at `when` for evaluation in `duration` blocks
+        /// without root privileges.
+        ///
+        /// The call must be inlined and allowed by `BondedCallFilter`. The origin reserves
+        /// `ProposalBond` and the oracle of the proposal is deployed on behalf of the origin using
+        /// `BondedOracleParams` and `oracle_metadata`. The bond is returned if the proposal is
+        /// scheduled for execution and slashed if the proposal is rejected or can't be scheduled.
+        ///
+        /// The call is dispatched with `dispatch_origin`, which must be allowed by
+        /// `AllowedDispatchOrigin` and must not be root.
+        #[pallet::call_index(1)]
+        #[transactional]
+        #[pallet::weight(
+            T::WeightInfo::submit_bonded_proposal()
+                .saturating_add(T::OracleDeployer::deploy_oracle_weight())
+        )]
+        pub fn submit_bonded_proposal(
+            origin: OriginFor<T>,
+            duration: BlockNumberFor<T>,
+            when: BlockNumberFor<T>,
+            call: BoundedCallOf<T>,
+            dispatch_origin: Box<PalletsOriginOf<T>>,
+            oracle_metadata: OracleMetadataOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Self::do_submit_bonded_proposal(
+                who,
+                duration,
+                when,
+                call,
+                *dispatch_origin,
+                oracle_metadata,
+            )
+        }
+
+        /// Cancels the proposal `proposal_id` before its oracle is evaluated.
+        ///
+        /// The bond of the proposal (if any) is returned to the proposer.
+        #[pallet::call_index(2)]
+        #[transactional]
+        #[pallet::weight(T::WeightInfo::cancel_proposal())]
+        pub fn cancel_proposal(origin: OriginFor<T>, proposal_id: ProposalId) -> DispatchResult {
+            T::VetoOrigin::ensure_origin(origin)?;
+
+            Self::do_cancel_proposal(proposal_id)
+        }
+
+        /// Dispatches the call of the scheduled proposal `proposal_id` with the dispatch origin of
+        /// the proposal and records the outcome in `DispatchOutcomes`, removing the oldest outcome
+        /// if there are more than `MaxDispatchOutcomes`.
+        ///
+        /// Scheduled by this pallet when a proposal is accepted and executed with root privileges
+        /// by the scheduler. Failing to look up or dispatch the call doesn't fail this call, but is
+        /// recorded as the outcome.
+        ///
+        /// The weight of the calls of proposals submitted with root privileges isn't limited by
+        /// `MaxCallWeight`, so they're only accounted for by the actual weight after dispatch.
+        #[pallet::call_index(3)]
+        #[transactional]
+        #[pallet::weight(
+            T::WeightInfo::dispatch_proposal().saturating_add(T::MaxCallWeight::get())
+        )]
+        pub fn dispatch_proposal(
+            origin: OriginFor<T>,
+            proposal_id: ProposalId,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+
+            Self::do_dispatch_proposal(proposal_id)
+        }
     }
 
     #[pallet::hooks]
@@ -186,16 +404,14 @@ mod pallet {
             // Update all oracles.
             let mutate_all_result =
                 <Pallet<T> as ProposalStorage<T>>::mutate_all(|p| p.oracle.update(now));
-            if let Ok(block_to_weights) = mutate_all_result {
-                // We did one storage read per vector cached. Shouldn't saturate, but technically
+            if let Ok(proposal_id_to_weight) = mutate_all_result {
+                // We did one storage read per proposal cached. Shouldn't saturate, but technically
                 // might.
-                let reads: u64 = block_to_weights.len().saturated_into();
+                let reads: u64 = proposal_id_to_weight.len().saturated_into();
                 total_weight = total_weight.saturating_add(T::DbWeight::get().reads(reads));
 
-                for weights in block_to_weights.values() {
-                    for &weight in weights.iter() {
-                        total_weight = total_weight.saturating_add(weight);
-                    }
+                for &weight in proposal_id_to_weight.values() {
+                    total_weight = total_weight.saturating_add(weight);
                 }
             } else {
                 // Unreachable!
@@ -213,12 +429,36 @@ mod pallet {
                 return total_weight;
             };
 
-            for proposal in proposals.into_iter() {
-                let weight = Self::maybe_schedule_proposal(proposal);
+            for (proposal_id, proposal) in proposals.into_iter() {
+                let weight = Self::maybe_schedule_proposal(proposal_id, proposal);
                 total_weight = total_weight.saturating_add(weight);
             }
 
             total_weight
         }
     }
+
+    impl<T: Config> Pallet<T> {
+        /// The reserve ID of the futarchy pallet.
+        #[inline]
+        pub fn reserve_id() -> [u8; 8] {
+            T::PalletId::get().0
+        }
+
+        /// Returns the status of all proposals in flight, ordered by id.
+        pub fn proposal_statuses() -> Vec<ProposalStatusOf<T>> {
+            let mut statuses = Proposals::<T>::iter()
+                .map(|(proposal_id, info)| ProposalStatus {
+                    proposal_id,
+                    evaluate_at: info.evaluate_at,
+                    when: info.proposal.when,
+                    scores: info.proposal.oracle.scores(),
+                    proposer: info.proposal.bond.map(|bond| bond.who),
+                })
+                .collect::<Vec<_>>();
+            statuses.sort_by_key(|status| status.proposal_id);
+
+            statuses
+        }
+    }
 }
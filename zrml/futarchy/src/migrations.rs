@@ -0,0 +1,239 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+pub mod mbm {
+    use crate::{
+        types::{Proposal, ProposalInfo},
+        BoundedCallOf, Config, NextProposalId, OracleOf, Pallet, ProposalCount, ProposalId,
+        ProposalIdsPerEvaluationBlock, Proposals,
+    };
+    use alloc::vec::Vec;
+    use core::marker::PhantomData;
+    use frame_support::{
+        dispatch::RawOrigin,
+        migrations::{SteppedMigration, SteppedMigrationError},
+        pallet_prelude::ConstU32,
+        storage::{unhashed, PrefixIterator, StoragePrefixedMap},
+        traits::{Get, QueryPreimage, StorageVersion},
+        weights::WeightMeter,
+        BoundedVec,
+    };
+    use frame_system::pallet_prelude::BlockNumberFor;
+    use parity_scale_codec::{Decode, DecodeAll};
+    use sp_runtime::traits::Convert;
+
+    /// Target pallet storage version after migration.
+    const TARGET_STORAGE_VERSION: u16 = 1;
+
+    /// Length of the `Blake2_128` hash which prefixes the keys of the legacy `Proposals` map.
+    const LEGACY_KEY_HASH_LEN: usize = 16;
+
+    /// A proposal as stored in the legacy `Proposals` map.
+    #[derive(Decode)]
+    struct LegacyProposal<T, O>
+    where
+        T: Config,
+    {
+        when: BlockNumberFor<T>,
+        call: BoundedCallOf<T>,
+        oracle: O,
+    }
+
+    /// Multi-block migration that moves the proposals of the legacy `Proposals` map, which stored
+    /// the proposals of each evaluation block in a single vector, to the id-based layout. Legacy
+    /// proposals could only be submitted by root, so they're dispatched with root origin and
+    /// have no bond. The legacy oracles of type `O` are converted using `C`.
+    ///
+    /// There are at most `MaxProposals` legacy proposals, so the migration runs in a single step.
+    pub struct MigrateProposalsToProposalIds<T, O, C>(PhantomData<(T, O, C)>);
+
+    impl<T, O, C> MigrateProposalsToProposalIds<T, O, C> {
+        const IDENT: &'static [u8] = b"futarchy-proposal-ids-v1";
+    }
+
+    impl<T, O, C> SteppedMigration for MigrateProposalsToProposalIds<T, O, C>
+    where
+        T: Config,
+        O: Decode,
+        C: Convert<O, OracleOf<T>>,
+    {
+        type Cursor = ();
+        type Identifier = BoundedVec<u8, ConstU32<64>>;
+
+        fn id() -> Self::Identifier {
+            BoundedVec::try_from(Self::IDENT.to_vec()).expect("fits in Identifier bound; qed")
+        }
+
+        fn step(
+            _cursor: Option<Self::Cursor>,
+            meter: &mut WeightMeter,
+        ) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+            if StorageVersion::get::<Pallet<T>>() >= StorageVersion::new(TARGET_STORAGE_VERSION) {
+                return Ok(None);
+            }
+
+            // Each legacy proposal occupies at most one legacy entry, is written to `Proposals`
+            // and `ProposalIdsPerEvaluationBlock` and requests the preimage of its call.
+            let proposal_count: u64 = ProposalCount::<T>::get().into();
+            let required = T::DbWeight::get().reads_writes(
+                proposal_count.saturating_mul(2).saturating_add(2),
+                proposal_count.saturating_mul(4).saturating_add(2),
+            );
+            if meter.try_consume(required).is_err() {
+                return Err(SteppedMigrationError::InsufficientWeight { required });
+            }
+
+            // Collect the legacy entries before writing to the shared storage prefix.
+            let prefix = Proposals::<T>::final_prefix();
+            let legacy_entries: Vec<(Vec<u8>, Vec<u8>)> =
+                PrefixIterator::<_>::new(prefix.to_vec(), prefix.to_vec(), |key, value| {
+                    Ok((key.to_vec(), value.to_vec()))
+                })
+                .collect();
+
+            let mut next_proposal_id = NextProposalId::<T>::get();
+
+            for (key, value) in legacy_entries.into_iter() {
+                let mut full_key = prefix.to_vec();
+                full_key.extend_from_slice(&key);
+                unhashed::kill(&full_key);
+
+                let Some(evaluate_at) = key
+                    .get(LEGACY_KEY_HASH_LEN..)
+                    .and_then(|raw| BlockNumberFor::<T>::decode_all(&mut &raw[..]).ok())
+                else {
+                    continue;
+                };
+                let Ok(legacy_proposals) = Vec::<LegacyProposal<T, O>>::decode_all(&mut &value[..])
+                else {
+                    continue;
+                };
+
+                let mut proposal_ids = Vec::with_capacity(legacy_proposals.len());
+                for legacy in legacy_proposals.into_iter() {
+                    let proposal = Proposal {
+                        when: legacy.when,
+                        call: legacy.call,
+                        origin: RawOrigin::Root.into(),
+                        oracle: C::convert(legacy.oracle),
+                        bond: None,
+                    };
+                    // Legacy proposals didn't request the preimages of their calls.
+                    T::Preimages::hold(&proposal.call);
+                    Proposals::<T>::insert(
+                        next_proposal_id,
+                        ProposalInfo { evaluate_at, proposal },
+                    );
+                    proposal_ids.push(next_proposal_id);
+                    next_proposal_id = next_proposal_id.saturating_add(ProposalId::from(1u8));
+                }
+
+                // Same bound as the legacy vector.
+                ProposalIdsPerEvaluationBlock::<T>::insert(
+                    evaluate_at,
+                    BoundedVec::truncate_from(proposal_ids),
+                );
+            }
+
+            NextProposalId::<T>::put(next_proposal_id);
+            StorageVersion::new(TARGET_STORAGE_VERSION).put::<Pallet<T>>();
+
+            Ok(None)
+        }
+    }
+}
+
+#[cfg(all(feature = "mock", test))]
+mod tests {
+    use super::mbm::MigrateProposalsToProposalIds;
+    use crate::{
+        mock::{ext_builder::ExtBuilder, runtime::*, types::MockOracle},
+        types::{Proposal, ProposalInfo},
+        NextProposalId, ProposalCount, ProposalIdsPerEvaluationBlock, Proposals,
+    };
+    use frame_support::{
+        dispatch::RawOrigin,
+        migrations::SteppedMigration,
+        storage::{unhashed, StoragePrefixedMap},
+        traits::{Bounded, StorageVersion},
+        weights::{Weight, WeightMeter},
+        Blake2_128Concat, StorageHasher,
+    };
+    use parity_scale_codec::Encode;
+    use sp_runtime::traits::{BlakeTwo256, Identity};
+
+    fn legacy_key(block_number: u64) -> Vec<u8> {
+        let mut key = Proposals::<Runtime>::final_prefix().to_vec();
+        key.extend(Blake2_128Concat::hash(&block_number.encode()));
+        key
+    }
+
+    #[test]
+    fn migration_moves_legacy_proposals_to_proposal_ids() {
+        ExtBuilder::build().execute_with(|| {
+            let call = Bounded::<RuntimeCall, BlakeTwo256>::Inline(Default::default());
+            let oracle = MockOracle::new(Weight::from_parts(1, 2), true);
+            let legacy_at_5 =
+                vec![(6u64, call.clone(), oracle.clone()), (7u64, call.clone(), oracle.clone())];
+            unhashed::put(&legacy_key(5), &legacy_at_5);
+            let legacy_at_9 = vec![(10u64, call.clone(), oracle.clone())];
+            unhashed::put(&legacy_key(9), &legacy_at_9);
+            ProposalCount::<Runtime>::put(3);
+            StorageVersion::new(0).put::<Futarchy>();
+
+            let mut meter = WeightMeter::with_limit(Weight::from_parts(u64::MAX, u64::MAX));
+            let cursor = MigrateProposalsToProposalIds::<Runtime, MockOracle, Identity>::step(
+                None, &mut meter,
+            )
+            .unwrap();
+            assert!(cursor.is_none());
+
+            assert_eq!(StorageVersion::get::<Futarchy>(), StorageVersion::new(1));
+            assert_eq!(ProposalCount::<Runtime>::get(), 3);
+            assert_eq!(NextProposalId::<Runtime>::get(), 3);
+            assert_eq!(Proposals::<Runtime>::iter().count(), 3);
+            assert!(unhashed::get_raw(&legacy_key(5)).is_none());
+            assert!(unhashed::get_raw(&legacy_key(9)).is_none());
+
+            let ids_at_5 = ProposalIdsPerEvaluationBlock::<Runtime>::get(5).into_inner();
+            let ids_at_9 = ProposalIdsPerEvaluationBlock::<Runtime>::get(9).into_inner();
+            let mut all_ids = [ids_at_5.clone(), ids_at_9.clone()].concat();
+            all_ids.sort();
+            assert_eq!(all_ids, vec![0, 1, 2]);
+            let when_at_5 = ids_at_5
+                .iter()
+                .map(|id| Proposals::<Runtime>::get(id).unwrap().proposal.when)
+                .collect::<Vec<_>>();
+            assert_eq!(when_at_5, vec![6, 7]);
+
+            let info = Proposals::<Runtime>::get(ids_at_9[0]).unwrap();
+            assert_eq!(
+                info,
+                ProposalInfo {
+                    evaluate_at: 9,
+                    proposal: Proposal {
+                        when: 10,
+                        call,
+                        origin: RawOrigin::Root.into(),
+                        oracle,
+                        bond: None
+                    },
+                }
+            );
+        });
+    }
+}
@@ -17,22 +17,27 @@
 
 use crate as zrml_futarchy;
 use crate::{
-    mock::types::{MockOracle, MockScheduler},
+    mock::types::{MockOracle, MockOracleDeployer, MockPreimages, MockScheduler},
     weights::WeightInfo,
 };
-use frame_support::{construct_runtime, parameter_types, traits::Everything};
-use frame_system::mocking::MockBlock;
+use frame_support::{
+    construct_runtime, ord_parameter_types, parameter_types,
+    traits::{Contains, EitherOfDiverse, Everything},
+    weights::Weight,
+    PalletId,
+};
+use frame_system::{mocking::MockBlock, EnsureRoot, EnsureSignedBy};
 use sp_runtime::traits::{BlakeTwo256, ConstU32, IdentityLookup};
 use zeitgeist_primitives::{
     constants::mock::{
-        BlockHashCount, ExistentialDeposit, ExistentialDeposits, MaxLocks, MaxReserves,
+        BlockHashCount, ExistentialDeposit, ExistentialDeposits, MaxLocks, MaxReserves, BASE,
     },
     types::{AccountIdTest, Amount, Balance, BlockNumber, CurrencyId, Hash},
 };
 #[cfg(feature = "parachain")]
 use {
-    frame_system::EnsureRoot, orml_traits::asset_registry::AssetProcessor,
-    sp_runtime::DispatchError, zeitgeist_primitives::types::CustomMetadata,
+    orml_traits::asset_registry::AssetProcessor, sp_runtime::DispatchError,
+    zeitgeist_primitives::types::CustomMetadata,
 };
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -40,8 +45,18 @@ use crate::mock::types::MockBenchmarkHelper;
 
 parameter_types! {
     // zrml-futarchy
+    pub const FutarchyPalletId: PalletId = PalletId(*b"zge/futa");
+    pub const MaxCallWeight: Weight = Weight::from_parts(1_000_000_000, 100_000);
+    pub const MaxDispatchOutcomes: u32 = 2;
     pub const MaxProposals: u32 = 16;
     pub const MinDuration: BlockNumber = 10;
+    pub const ProposalBond: Balance = 10 * BASE;
+    pub storage BondedOracleParams: MockOracle = MockOracle::default();
+}
+
+ord_parameter_types! {
+    // zrml-futarchy
+    pub const WhitelistedDispatcher: AccountIdTest = 3;
 }
 
 construct_runtime! {
@@ -55,6 +70,15 @@ construct_runtime! {
     }
 }
 
+/// Allows bonded proposals to dispatch `remark` calls.
+pub struct BondedCallFilter;
+
+impl Contains<RuntimeCall> for BondedCallFilter {
+    fn contains(call: &RuntimeCall) -> bool {
+        matches!(call, RuntimeCall::System(frame_system::Call::remark { .. }))
+    }
+}
+
 impl frame_system::Config for Runtime {
     type AccountData = pallet_balances::AccountData<Balance>;
     type AccountId = AccountIdTest;
@@ -147,12 +171,28 @@ impl orml_tokens::Config for Runtime {
 }
 
 impl zrml_futarchy::Config for Runtime {
+    type AllowedDispatchOrigin = EitherOfDiverse<
+        EnsureRoot<AccountIdTest>,
+        EnsureSignedBy<WhitelistedDispatcher, AccountIdTest>,
+    >;
     #[cfg(feature = "runtime-benchmarks")]
     type BenchmarkHelper = MockBenchmarkHelper;
+    type BondedCallFilter = BondedCallFilter;
+    type BondedOracleParams = BondedOracleParams;
+    type Currency = Balances;
+    type MaxCallWeight = MaxCallWeight;
+    type MaxDispatchOutcomes = MaxDispatchOutcomes;
     type MaxProposals = MaxProposals;
     type MinDuration = MinDuration;
     type Oracle = MockOracle;
+    type OracleDeployer = MockOracleDeployer;
+    type PalletId = FutarchyPalletId;
+    type Preimages = MockPreimages;
+    type ProposalBond = ProposalBond;
+    type RuntimeCall = RuntimeCall;
     type RuntimeEvent = RuntimeEvent;
     type Scheduler = MockScheduler;
+    type Slash = ();
+    type VetoOrigin = EnsureRoot<AccountIdTest>;
     type WeightInfo = WeightInfo<Runtime>;
 }
@@ -18,9 +18,13 @@
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmark_helper;
 mod oracle;
+mod oracle_deployer;
+mod preimages;
 mod scheduler;
 
 #[cfg(feature = "runtime-benchmarks")]
 pub use benchmark_helper::MockBenchmarkHelper;
 pub(crate) use oracle::MockOracle;
+pub(crate) use oracle_deployer::MockOracleDeployer;
+pub(crate) use preimages::MockPreimages;
 pub(crate) use scheduler::MockScheduler;
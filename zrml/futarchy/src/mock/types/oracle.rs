@@ -20,7 +20,10 @@ use frame_support::pallet_prelude::Weight;
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_runtime::traits::Zero;
-use zeitgeist_primitives::{traits::FutarchyOracle, types::BlockNumber};
+use zeitgeist_primitives::{
+    traits::FutarchyOracle,
+    types::{BlockNumber, FutarchyOracleScore},
+};
 
 #[cfg(feature = "fuzzing")]
 use arbitrary::{Arbitrary, Result as ArbitraryResult, Unstructured};
@@ -29,17 +32,27 @@ use arbitrary::{Arbitrary, Result as ArbitraryResult, Unstructured};
 pub struct MockOracle {
     weight: Weight,
     value: bool,
+    score: Option<FutarchyOracleScore>,
+    valid: bool,
 }
 
 impl Default for MockOracle {
     fn default() -> Self {
-        MockOracle { weight: Default::default(), value: true }
+        MockOracle { weight: Default::default(), value: true, score: None, valid: true }
     }
 }
 
 impl MockOracle {
     pub fn new(weight: Weight, value: bool) -> Self {
-        Self { weight, value }
+        Self { weight, value, score: None, valid: true }
+    }
+
+    pub fn with_score(self, score: FutarchyOracleScore) -> Self {
+        Self { score: Some(score), ..self }
+    }
+
+    pub fn invalid(self) -> Self {
+        Self { valid: false, ..self }
     }
 }
 
@@ -53,6 +66,14 @@ impl FutarchyOracle for MockOracle {
     fn update(&mut self, _: Self::BlockNumber) -> Weight {
         Zero::zero()
     }
+
+    fn score(&self) -> Option<FutarchyOracleScore> {
+        self.score
+    }
+
+    fn is_valid(&self) -> bool {
+        self.valid
+    }
 }
 
 #[cfg(feature = "fuzzing")]
@@ -0,0 +1,48 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::mock::types::MockOracle;
+use frame_support::pallet_prelude::Weight;
+use sp_runtime::{traits::Zero, DispatchError};
+use zeitgeist_primitives::{
+    traits::FutarchyOracleDeployer,
+    types::{AccountIdTest, BlockNumber},
+};
+
+/// Deploys the oracle passed in as parameter. Doesn't require any metadata.
+pub struct MockOracleDeployer;
+
+impl FutarchyOracleDeployer for MockOracleDeployer {
+    type AccountId = AccountIdTest;
+    type BlockNumber = BlockNumber;
+    type Oracle = MockOracle;
+    type Params = MockOracle;
+    type Metadata = ();
+
+    fn deploy_oracle(
+        _who: Self::AccountId,
+        _evaluate_at: Self::BlockNumber,
+        params: Self::Params,
+        _metadata: Self::Metadata,
+    ) -> Result<Self::Oracle, DispatchError> {
+        Ok(params)
+    }
+
+    fn deploy_oracle_weight() -> Weight {
+        Zero::zero()
+    }
+}
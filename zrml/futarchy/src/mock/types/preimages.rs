@@ -0,0 +1,76 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use alloc::{borrow::Cow, collections::BTreeMap};
+use core::cell::RefCell;
+use frame_support::traits::{QueryPreimage, StorePreimage};
+use sp_runtime::{traits::BlakeTwo256, DispatchError};
+use zeitgeist_primitives::types::Hash;
+
+/// Preimage provider which only supports inlined calls and counts the requests of preimages.
+pub struct MockPreimages;
+
+impl MockPreimages {
+    /// Returns the number of outstanding requests for the preimage of `hash`.
+    pub fn request_count(hash: &Hash) -> u32 {
+        PREIMAGE_REQUESTS.with(|requests| requests.borrow().get(hash).copied().unwrap_or(0))
+    }
+}
+
+impl QueryPreimage for MockPreimages {
+    type H = BlakeTwo256;
+
+    fn len(_hash: &Hash) -> Option<u32> {
+        None
+    }
+
+    fn fetch(_hash: &Hash, _len: Option<u32>) -> Result<Cow<'static, [u8]>, DispatchError> {
+        Err(DispatchError::Unavailable)
+    }
+
+    fn is_requested(hash: &Hash) -> bool {
+        Self::request_count(hash) > 0
+    }
+
+    fn request(hash: &Hash) {
+        PREIMAGE_REQUESTS.with(|requests| *requests.borrow_mut().entry(*hash).or_default() += 1);
+    }
+
+    fn unrequest(hash: &Hash) {
+        PREIMAGE_REQUESTS.with(|requests| {
+            let mut requests = requests.borrow_mut();
+            if let Some(count) = requests.get_mut(hash) {
+                *count = count.saturating_sub(1);
+                if *count == 0 {
+                    requests.remove(hash);
+                }
+            }
+        });
+    }
+}
+
+impl StorePreimage for MockPreimages {
+    const MAX_LENGTH: usize = 0;
+
+    fn note(_bytes: Cow<[u8]>) -> Result<Hash, DispatchError> {
+        Err(DispatchError::Exhausted)
+    }
+}
+
+thread_local! {
+    pub static PREIMAGE_REQUESTS: RefCell<BTreeMap<Hash, u32>> = const { RefCell::new(BTreeMap::new()) };
+}
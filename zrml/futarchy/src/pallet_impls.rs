@@ -15,38 +15,142 @@
 // You should have received a copy of the GNU General Public License
 // along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{types::Proposal, weights::WeightInfoZeitgeist, Config, Event, Pallet};
+use crate::{
+    types::Proposal, weights::WeightInfoZeitgeist, BalanceOf, BoundedCallOf, Call, CallOf, Config,
+    DispatchOutcomeIds, DispatchOutcomeOf, DispatchOutcomes, Error, Event, Pallet, ProposalId,
+    ScheduledProposals,
+};
 use frame_support::{
-    dispatch::RawOrigin,
+    dispatch::{extract_actual_weight, GetDispatchInfo, RawOrigin},
     pallet_prelude::Weight,
-    traits::schedule::{v3::Anon, DispatchTime, HARD_DEADLINE},
+    traits::{
+        schedule::{v3::Anon, DispatchTime, HARD_DEADLINE},
+        Bounded, Get, NamedReservableCurrency, OnUnbalanced, QueryPreimage,
+    },
+};
+use parity_scale_codec::Encode;
+use sp_runtime::{
+    traits::{Dispatchable, Zero},
+    DispatchError, DispatchResult,
 };
-use zeitgeist_primitives::traits::FutarchyOracle;
+use zeitgeist_primitives::{traits::FutarchyOracle, types::Bond};
 
 impl<T: Config> Pallet<T> {
-    /// Evaluates `proposal` using the specified oracle and schedules the contained call if the
-    /// oracle approves.
-    pub(crate) fn maybe_schedule_proposal(proposal: Proposal<T>) -> Weight {
+    /// Evaluates `proposal` using the specified oracle and schedules the dispatch of the contained
+    /// call if the oracle approves.
+    ///
+    /// The bond of the proposal (if any) is returned if the call is scheduled and slashed if the
+    /// oracle rejects the proposal or the call can't be scheduled.
+    pub(crate) fn maybe_schedule_proposal(
+        proposal_id: ProposalId,
+        proposal: Proposal<T>,
+    ) -> Weight {
         let (evaluate_weight, approved) = proposal.oracle.evaluate();
 
         if approved {
-            let result = T::Scheduler::schedule(
-                DispatchTime::At(proposal.when),
-                None,
-                HARD_DEADLINE,
-                RawOrigin::Root.into(),
-                proposal.call.clone(),
-            );
+            let result = Self::dispatch_proposal_call(proposal_id).and_then(|call| {
+                T::Scheduler::schedule(
+                    DispatchTime::At(proposal.when),
+                    None,
+                    HARD_DEADLINE,
+                    RawOrigin::Root.into(),
+                    call,
+                )
+            });
 
             if result.is_ok() {
-                Self::deposit_event(Event::<T>::Scheduled { proposal });
+                ScheduledProposals::<T>::insert(proposal_id, proposal.clone());
+                if let Some(bond) = proposal.bond.clone() {
+                    Self::unreserve_bond(bond);
+                }
+                Self::deposit_event(Event::<T>::Scheduled { proposal_id, proposal });
             } else {
+                T::Preimages::drop(&proposal.call);
+                if let Some(bond) = proposal.bond {
+                    Self::slash_bond(bond);
+                }
                 Self::deposit_event(Event::<T>::UnexpectedSchedulerError);
             }
         } else {
-            Self::deposit_event(Event::<T>::Rejected { proposal });
+            T::Preimages::drop(&proposal.call);
+            if let Some(bond) = proposal.bond.clone() {
+                Self::slash_bond(bond);
+            }
+            Self::deposit_event(Event::<T>::Rejected { proposal_id, proposal });
         }
 
         T::WeightInfo::maybe_schedule_proposal().saturating_add(evaluate_weight)
     }
+
+    /// Returns the bounded call which dispatches the proposal `proposal_id` when executed by the
+    /// scheduler.
+    ///
+    /// The call only contains the proposal id, so it's always inlined and no preimage is requested
+    /// which would have to be unrequested after the dispatch.
+    pub(crate) fn dispatch_proposal_call(
+        proposal_id: ProposalId,
+    ) -> Result<BoundedCallOf<T>, DispatchError> {
+        let call: <T as Config>::RuntimeCall = Call::<T>::dispatch_proposal { proposal_id }.into();
+        let call: CallOf<T> = call.into();
+        let inline = call.encode().try_into().map_err(|_| Error::<T>::UnexpectedStorageFailure)?;
+
+        Ok(Bounded::Inline(inline))
+    }
+
+    /// Records the dispatch outcome of `proposal_id` and removes the oldest outcome if there are
+    /// more than `MaxDispatchOutcomes`.
+    pub(crate) fn record_dispatch_outcome(proposal_id: ProposalId, outcome: DispatchOutcomeOf<T>) {
+        let result = DispatchOutcomeIds::<T>::mutate(|proposal_ids| {
+            proposal_ids.force_insert_keep_right(proposal_ids.len(), proposal_id)
+        });
+
+        match result {
+            Ok(Some(oldest_proposal_id)) => DispatchOutcomes::<T>::remove(oldest_proposal_id),
+            Ok(None) => {}
+            // Only fails if `MaxDispatchOutcomes` is zero.
+            Err(_) => return,
+        }
+
+        DispatchOutcomes::<T>::insert(proposal_id, outcome);
+    }
+
+    /// Dispatches the call of `proposal` with the dispatch origin of `proposal` and returns the
+    /// result and the actual weight of the call.
+    ///
+    /// The preimage of the call is released even if the call can't be looked up. The weight of
+    /// the calls of bonded proposals is limited by `MaxCallWeight`.
+    pub(crate) fn dispatch_call(proposal: Proposal<T>) -> (DispatchResult, Weight) {
+        let lookup = T::Preimages::peek(&proposal.call);
+        T::Preimages::drop(&proposal.call);
+        let call: <T as Config>::RuntimeCall = match lookup {
+            Ok((call, _)) => call.into(),
+            Err(err) => return (Err(err), Zero::zero()),
+        };
+
+        let info = call.get_dispatch_info();
+        if proposal.bond.is_some() && info.weight.any_gt(T::MaxCallWeight::get()) {
+            return (Err(Error::<T>::CallWeightTooHigh.into()), Zero::zero());
+        }
+
+        let result = call.dispatch(proposal.origin.into());
+        let weight = extract_actual_weight(&result, &info);
+
+        (result.map(|_| ()).map_err(|err| err.error), weight)
+    }
+
+    pub(crate) fn unreserve_bond(bond: Bond<T::AccountId, BalanceOf<T>>) {
+        let missing = T::Currency::unreserve_named(&Self::reserve_id(), &bond.who, bond.value);
+        debug_assert!(missing.is_zero(), "Could not unreserve all of the proposal bond.");
+
+        Self::deposit_event(Event::<T>::BondUnreserved { who: bond.who, value: bond.value });
+    }
+
+    fn slash_bond(bond: Bond<T::AccountId, BalanceOf<T>>) {
+        let (imbalance, missing) =
+            T::Currency::slash_reserved_named(&Self::reserve_id(), &bond.who, bond.value);
+        debug_assert!(missing.is_zero(), "Could not slash all of the proposal bond.");
+        T::Slash::on_unbalanced(imbalance);
+
+        Self::deposit_event(Event::<T>::BondSlashed { who: bond.who, value: bond.value });
+    }
 }
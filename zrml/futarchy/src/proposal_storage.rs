@@ -16,10 +16,12 @@
 // along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    traits::ProposalStorage, types::Proposal, Config, Error, Pallet, ProposalCount, Proposals,
-    ProposalsOf,
+    traits::ProposalStorage,
+    types::{Proposal, ProposalInfo},
+    Config, Error, NextProposalId, Pallet, ProposalCount, ProposalId,
+    ProposalIdsPerEvaluationBlock, Proposals, ScheduledProposals,
 };
-use alloc::{collections::BTreeMap, vec, vec::Vec};
+use alloc::{collections::BTreeMap, vec::Vec};
 use frame_support::{ensure, require_transactional, traits::Get};
 use frame_system::pallet_prelude::BlockNumberFor;
 use sp_runtime::{DispatchError, SaturatedConversion};
@@ -34,24 +36,45 @@ where
     }
 
     #[require_transactional]
-    fn add(block_number: BlockNumberFor<T>, proposal: Proposal<T>) -> Result<(), DispatchError> {
+    fn add(
+        block_number: BlockNumberFor<T>,
+        proposal: Proposal<T>,
+    ) -> Result<ProposalId, DispatchError> {
+        // Proposals which are accepted are moved to `ScheduledProposals`, so bounding the sum
+        // bounds both.
         let proposal_count = ProposalCount::<T>::get();
-        ensure!(proposal_count < T::MaxProposals::get(), Error::<T>::CacheFull);
+        let scheduled_count = ScheduledProposals::<T>::count();
+        ensure!(
+            proposal_count.saturating_add(scheduled_count) < T::MaxProposals::get(),
+            Error::<T>::CacheFull
+        );
 
         let new_proposal_count = proposal_count.checked_inc_res()?;
         ProposalCount::<T>::put(new_proposal_count);
 
+        let proposal_id = NextProposalId::<T>::get();
+        NextProposalId::<T>::put(proposal_id.checked_inc_res()?);
+
         // Can't error unless state is invalid.
-        let mutate_result = Proposals::<T>::try_mutate(block_number, |proposals| {
-            proposals.try_push(proposal).map_err(|_| Error::<T>::CacheFull)
-        });
+        ProposalIdsPerEvaluationBlock::<T>::try_mutate(block_number, |proposal_ids| {
+            proposal_ids.try_push(proposal_id).map_err(|_| Error::<T>::CacheFull)
+        })?;
 
-        Ok(mutate_result?)
+        Proposals::<T>::insert(proposal_id, ProposalInfo { evaluate_at: block_number, proposal });
+
+        Ok(proposal_id)
     }
 
     /// Take all proposals scheduled at `block_number`.
-    fn take(block_number: BlockNumberFor<T>) -> Result<ProposalsOf<T>, DispatchError> {
-        let proposals = Proposals::<T>::take(block_number);
+    fn take(
+        block_number: BlockNumberFor<T>,
+    ) -> Result<Vec<(ProposalId, Proposal<T>)>, DispatchError> {
+        let proposals = ProposalIdsPerEvaluationBlock::<T>::take(block_number)
+            .into_iter()
+            .filter_map(|proposal_id| {
+                Proposals::<T>::take(proposal_id).map(|info| (proposal_id, info.proposal))
+            })
+            .collect::<Vec<_>>();
 
         // Can't error unless state is invalid.
         let proposal_count = ProposalCount::<T>::get();
@@ -62,14 +85,26 @@ where
         Ok(proposals)
     }
 
-    /// Returns all proposals scheduled at `block_number`.
-    fn get(block_number: BlockNumberFor<T>) -> ProposalsOf<T> {
-        Proposals::<T>::get(block_number)
+    /// Returns the proposal with id `proposal_id`.
+    fn get(proposal_id: ProposalId) -> Option<ProposalInfo<T>> {
+        Proposals::<T>::get(proposal_id)
     }
 
-    fn mutate_all<R, F>(
-        mut mutator: F,
-    ) -> Result<BTreeMap<BlockNumberFor<T>, Vec<R>>, DispatchError>
+    #[require_transactional]
+    fn remove(proposal_id: ProposalId) -> Result<ProposalInfo<T>, DispatchError> {
+        let info = Proposals::<T>::take(proposal_id).ok_or(Error::<T>::ProposalNotFound)?;
+
+        ProposalIdsPerEvaluationBlock::<T>::mutate(info.evaluate_at, |proposal_ids| {
+            proposal_ids.retain(|&id| id != proposal_id)
+        });
+
+        let proposal_count = ProposalCount::<T>::get();
+        ProposalCount::<T>::put(proposal_count.checked_sub_res(&1)?);
+
+        Ok(info)
+    }
+
+    fn mutate_all<R, F>(mut mutator: F) -> Result<BTreeMap<ProposalId, R>, DispatchError>
     where
         F: FnMut(&mut Proposal<T>) -> R,
     {
@@ -81,23 +116,12 @@ where
         let mut result_map = BTreeMap::new();
 
         for k in keys.into_iter() {
-            let proposals = Self::get(k);
-
-            let mut results = vec![];
-
-            // If mutation goes out of bounds, we've clearly failed.
-            let proposals = proposals
-                .try_mutate(|v| {
-                    for p in v.iter_mut() {
-                        let r = mutator(p);
-                        results.push(r);
-                    }
-                })
-                .ok_or(Error::<T>::UnexpectedStorageFailure)?;
+            let mut info = Self::get(k).ok_or(Error::<T>::UnexpectedStorageFailure)?;
 
-            result_map.insert(k, results);
+            let r = mutator(&mut info.proposal);
+            result_map.insert(k, r);
 
-            Proposals::<T>::insert(k, proposals);
+            Proposals::<T>::insert(k, info);
         }
 
         Ok(result_map)
@@ -0,0 +1,140 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+#[test]
+fn cancel_proposal_removes_proposal_and_unreserves_bond() {
+    ExtBuilder::build().execute_with(|| {
+        let alice = Account::new(0).deposit(BASE * 100);
+        let duration = <Runtime as Config>::MinDuration::get();
+        MockScheduler::set_return_value(Ok(()));
+
+        assert_ok!(Futarchy::submit_bonded_proposal(
+            alice.signed(),
+            duration,
+            Default::default(),
+            remark_call(),
+            Box::new(RawOrigin::Signed(WhitelistedDispatcher::get()).into()),
+            ()
+        ));
+        let proposal = Proposals::<Runtime>::get(0).unwrap().proposal;
+
+        assert_ok!(Futarchy::cancel_proposal(RawOrigin::Root.into(), 0));
+
+        let value = <Runtime as Config>::ProposalBond::get();
+        System::assert_has_event(Event::<Runtime>::BondUnreserved { who: alice.id, value }.into());
+        System::assert_last_event(Event::<Runtime>::Cancelled { proposal_id: 0, proposal }.into());
+        assert!(Proposals::<Runtime>::get(0).is_none());
+        assert_eq!(ProposalCount::<Runtime>::get(), 0);
+        assert_eq!(Balances::reserved_balance_named(&Futarchy::reserve_id(), &alice.id), 0);
+        assert_eq!(Balances::free_balance(alice.id), BASE * 100);
+
+        // The cancelled proposal is never evaluated.
+        let to_be_scheduled_at = System::block_number() + duration;
+        assert!(ProposalIdsPerEvaluationBlock::<Runtime>::get(to_be_scheduled_at).is_empty());
+        utility::run_to_block(to_be_scheduled_at);
+        assert!(MockScheduler::not_called());
+    });
+}
+
+#[test]
+fn cancel_proposal_keeps_other_proposals() {
+    ExtBuilder::build().execute_with(|| {
+        let duration = <Runtime as Config>::MinDuration::get();
+        MockScheduler::set_return_value(Ok(()));
+
+        let call = Bounded::Inline(vec![7u8; 128].try_into().unwrap());
+        let oracle = MockOracle::new(Default::default(), true);
+        let proposal = Proposal {
+            when: Default::default(),
+            call,
+            origin: RawOrigin::Root.into(),
+            oracle,
+            bond: None,
+        };
+        assert_ok!(Futarchy::submit_proposal(RawOrigin::Root.into(), duration, proposal.clone()));
+        assert_ok!(Futarchy::submit_proposal(RawOrigin::Root.into(), duration, proposal.clone()));
+
+        assert_ok!(Futarchy::cancel_proposal(RawOrigin::Root.into(), 0));
+
+        let to_be_scheduled_at = System::block_number() + duration;
+        assert_eq!(ProposalIdsPerEvaluationBlock::<Runtime>::get(to_be_scheduled_at).to_vec(), [1]);
+        assert_eq!(ProposalCount::<Runtime>::get(), 1);
+
+        utility::run_to_block(to_be_scheduled_at);
+
+        assert!(MockScheduler::called_once_with(
+            DispatchTime::At(proposal.when),
+            Futarchy::dispatch_proposal_call(1).unwrap()
+        ));
+        assert_eq!(ScheduledProposals::<Runtime>::get(1), Some(proposal.clone()));
+        System::assert_last_event(Event::<Runtime>::Scheduled { proposal_id: 1, proposal }.into());
+    });
+}
+
+#[test]
+fn cancel_proposal_releases_preimage() {
+    ExtBuilder::build().execute_with(|| {
+        let duration = <Runtime as Config>::MinDuration::get();
+        let hash = Default::default();
+        let proposal = Proposal {
+            when: Default::default(),
+            call: Bounded::Lookup { hash, len: 128 },
+            origin: RawOrigin::Root.into(),
+            oracle: MockOracle::new(Default::default(), true),
+            bond: None,
+        };
+        assert_ok!(Futarchy::submit_proposal(RawOrigin::Root.into(), duration, proposal));
+        assert_eq!(MockPreimages::request_count(&hash), 1);
+
+        assert_ok!(Futarchy::cancel_proposal(RawOrigin::Root.into(), 0));
+
+        assert_eq!(MockPreimages::request_count(&hash), 0);
+    });
+}
+
+#[test]
+fn cancel_proposal_fails_on_bad_origin() {
+    ExtBuilder::build().execute_with(|| {
+        let alice = Account::new(0);
+        let duration = <Runtime as Config>::MinDuration::get();
+
+        let call = Bounded::Inline(vec![7u8; 128].try_into().unwrap());
+        let oracle = MockOracle::new(Default::default(), true);
+        let proposal = Proposal {
+            when: Default::default(),
+            call,
+            origin: RawOrigin::Root.into(),
+            oracle,
+            bond: None,
+        };
+        assert_ok!(Futarchy::submit_proposal(RawOrigin::Root.into(), duration, proposal));
+
+        assert_noop!(Futarchy::cancel_proposal(alice.signed(), 0), DispatchError::BadOrigin);
+    });
+}
+
+#[test]
+fn cancel_proposal_fails_if_proposal_does_not_exist() {
+    ExtBuilder::build().execute_with(|| {
+        assert_noop!(
+            Futarchy::cancel_proposal(RawOrigin::Root.into(), 0),
+            Error::<Runtime>::ProposalNotFound
+        );
+    });
+}
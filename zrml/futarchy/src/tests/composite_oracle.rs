@@ -0,0 +1,151 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::{
+    mock::types::MockOracleDeployer,
+    types::{CompositeOracle, CompositeOracleDeployer, CompositeOracleParams, CompositeRule},
+};
+use frame_support::{pallet_prelude::Weight, traits::ConstU32};
+use parity_scale_codec::Decode;
+use test_case::test_case;
+use zeitgeist_primitives::{
+    traits::{FutarchyOracle, FutarchyOracleDeployer},
+    types::FutarchyOracleScore,
+};
+
+type MaxOracles = ConstU32<3>;
+type TestCompositeOracle = CompositeOracle<MockOracle, MaxOracles>;
+type TestDeployer = CompositeOracleDeployer<Runtime, MockOracleDeployer, MaxOracles>;
+
+fn mock_oracles(values: &[bool]) -> Vec<MockOracle> {
+    values
+        .iter()
+        .enumerate()
+        .map(|(i, &value)| MockOracle::new(Weight::from_parts(i as u64 + 1, 2), value))
+        .collect()
+}
+
+#[test_case(CompositeRule::All, &[true, true, true], true)]
+#[test_case(CompositeRule::All, &[true, false, true], false)]
+#[test_case(CompositeRule::Any, &[false, false, true], true)]
+#[test_case(CompositeRule::Any, &[false, false, false], false)]
+#[test_case(CompositeRule::AtLeast(2), &[true, false, true], true)]
+#[test_case(CompositeRule::AtLeast(2), &[false, false, true], false)]
+fn evaluate_applies_rule_and_sums_weights(rule: CompositeRule, values: &[bool], expected: bool) {
+    let oracle = TestCompositeOracle::new(mock_oracles(values), rule).unwrap();
+
+    let (weight, value) = oracle.evaluate();
+
+    assert_eq!(weight, Weight::from_parts(6, 6));
+    assert_eq!(value, expected);
+}
+
+#[test_case(CompositeRule::All, &[])]
+#[test_case(CompositeRule::Any, &[])]
+#[test_case(CompositeRule::AtLeast(0), &[true])]
+#[test_case(CompositeRule::AtLeast(3), &[true, true])]
+#[test_case(CompositeRule::All, &[true, true, true, true])]
+fn new_fails_on_invalid_oracles(rule: CompositeRule, values: &[bool]) {
+    assert_eq!(TestCompositeOracle::new(mock_oracles(values), rule), None);
+}
+
+#[test]
+fn scores_returns_scores_of_sub_oracles() {
+    let score = |pass_score, reject_score, victory_margin| FutarchyOracleScore {
+        pass_score,
+        reject_score,
+        victory_margin,
+    };
+    let oracles = vec![
+        MockOracle::new(Weight::zero(), true).with_score(score(10, 2, 5)),
+        MockOracle::new(Weight::zero(), true),
+        MockOracle::new(Weight::zero(), false).with_score(score(1, 7, 3)),
+    ];
+    let oracle = TestCompositeOracle::new(oracles, CompositeRule::All).unwrap();
+    assert_eq!(oracle.scores(), vec![Some(score(10, 2, 5)), None, Some(score(1, 7, 3))]);
+    assert_eq!(oracle.score(), None);
+}
+
+#[test_case(CompositeRule::All, &[])]
+#[test_case(CompositeRule::Any, &[])]
+#[test_case(CompositeRule::AtLeast(0), &[true])]
+#[test_case(CompositeRule::AtLeast(3), &[true, true])]
+fn is_valid_returns_false_on_invalid_rule(rule: CompositeRule, values: &[bool]) {
+    // Decoding bypasses the checks of `CompositeOracle::new`.
+    let encoded = (mock_oracles(values), rule).encode();
+    let oracle = TestCompositeOracle::decode(&mut &encoded[..]).unwrap();
+    assert!(!oracle.is_valid());
+}
+
+#[test]
+fn is_valid_returns_false_on_invalid_sub_oracle() {
+    let oracles = vec![MockOracle::default(), MockOracle::default().invalid()];
+    let oracle = TestCompositeOracle::new(oracles, CompositeRule::Any).unwrap();
+    assert!(!oracle.is_valid());
+}
+
+#[test]
+fn deploy_oracle_deploys_all_sub_oracles() {
+    ExtBuilder::build().execute_with(|| {
+        let oracles = mock_oracles(&[true, false]);
+        let params = CompositeOracleParams {
+            params: oracles.clone().try_into().unwrap(),
+            rule: CompositeRule::Any,
+        };
+
+        let metadata = vec![(); 2].try_into().unwrap();
+
+        let oracle = TestDeployer::deploy_oracle(0, 10, params, metadata).unwrap();
+
+        assert_eq!(oracle.oracles(), oracles.as_slice());
+        assert_eq!(oracle.rule(), CompositeRule::Any);
+    });
+}
+
+#[test]
+fn deploy_oracle_fails_on_invalid_rule() {
+    ExtBuilder::build().execute_with(|| {
+        let params = CompositeOracleParams {
+            params: mock_oracles(&[true, false]).try_into().unwrap(),
+            rule: CompositeRule::AtLeast(3),
+        };
+
+        let metadata = vec![(); 2].try_into().unwrap();
+
+        assert_eq!(
+            TestDeployer::deploy_oracle(0, 10, params, metadata),
+            Err(Error::<Runtime>::InvalidCompositeOracle.into())
+        );
+    });
+}
+
+#[test]
+fn deploy_oracle_fails_if_metadata_does_not_match_params() {
+    ExtBuilder::build().execute_with(|| {
+        let params = CompositeOracleParams {
+            params: mock_oracles(&[true, false]).try_into().unwrap(),
+            rule: CompositeRule::Any,
+        };
+        let metadata = vec![()].try_into().unwrap();
+
+        assert_eq!(
+            TestDeployer::deploy_oracle(0, 10, params, metadata),
+            Err(Error::<Runtime>::InvalidCompositeOracle.into())
+        );
+    });
+}
@@ -0,0 +1,159 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::{types::DispatchOutcome, DispatchOutcomeIds};
+use sp_runtime::traits::Hash;
+use zeitgeist_primitives::types::AccountIdTest;
+
+/// Submits and schedules a proposal which dispatches `call` with `origin`.
+fn schedule_proposal(call: Bounded<RuntimeCall, BlakeTwo256>, origin: RawOrigin<AccountIdTest>) {
+    let duration = <Runtime as Config>::MinDuration::get();
+    let oracle = MockOracle::new(Default::default(), true);
+    let proposal =
+        Proposal { when: Default::default(), call, origin: origin.into(), oracle, bond: None };
+    MockScheduler::set_return_value(Ok(()));
+
+    assert_ok!(Futarchy::submit_proposal(RawOrigin::Root.into(), duration, proposal.clone()));
+    utility::run_to_block(System::block_number() + duration);
+    assert_eq!(ScheduledProposals::<Runtime>::get(0), Some(proposal));
+}
+
+fn inline(call: RuntimeCall) -> Bounded<RuntimeCall, BlakeTwo256> {
+    Bounded::Inline(call.encode().try_into().unwrap())
+}
+
+#[test]
+fn dispatch_proposal_dispatches_call_with_dispatch_origin_and_records_outcome() {
+    ExtBuilder::build().execute_with(|| {
+        let remark = b"hullo".to_vec();
+        let call = frame_system::Call::remark_with_event { remark: remark.clone() }.into();
+        let whitelisted_dispatcher = 3;
+        schedule_proposal(inline(call), RawOrigin::Signed(whitelisted_dispatcher));
+
+        assert_ok!(Futarchy::dispatch_proposal(RawOrigin::Root.into(), 0));
+
+        let hash = BlakeTwo256::hash(&remark);
+        System::assert_has_event(
+            frame_system::Event::<Runtime>::Remarked { sender: whitelisted_dispatcher, hash }
+                .into(),
+        );
+        System::assert_last_event(
+            Event::<Runtime>::Dispatched { proposal_id: 0, result: Ok(()) }.into(),
+        );
+        assert_eq!(
+            DispatchOutcomes::<Runtime>::get(0),
+            Some(DispatchOutcome { dispatched_at: System::block_number(), result: Ok(()) })
+        );
+        assert!(ScheduledProposals::<Runtime>::get(0).is_none());
+    });
+}
+
+#[test]
+fn dispatch_proposal_records_failed_dispatch() {
+    ExtBuilder::build().execute_with(|| {
+        // Requires root privileges.
+        let call = frame_system::Call::set_heap_pages { pages: 1 }.into();
+        schedule_proposal(inline(call), RawOrigin::Signed(3));
+
+        assert_ok!(Futarchy::dispatch_proposal(RawOrigin::Root.into(), 0));
+
+        let result = Err(DispatchError::BadOrigin);
+        System::assert_last_event(Event::<Runtime>::Dispatched { proposal_id: 0, result }.into());
+        assert_eq!(
+            DispatchOutcomes::<Runtime>::get(0),
+            Some(DispatchOutcome { dispatched_at: System::block_number(), result })
+        );
+    });
+}
+
+#[test]
+fn dispatch_proposal_records_error_if_call_cannot_be_decoded() {
+    ExtBuilder::build().execute_with(|| {
+        schedule_proposal(Bounded::Inline(vec![7u8; 128].try_into().unwrap()), RawOrigin::Root);
+
+        assert_ok!(Futarchy::dispatch_proposal(RawOrigin::Root.into(), 0));
+
+        assert!(DispatchOutcomes::<Runtime>::get(0).unwrap().result.is_err());
+        assert!(ScheduledProposals::<Runtime>::get(0).is_none());
+    });
+}
+
+#[test]
+fn dispatch_proposal_releases_preimage_if_call_cannot_be_looked_up() {
+    ExtBuilder::build().execute_with(|| {
+        let hash = Default::default();
+        schedule_proposal(Bounded::Lookup { hash, len: 128 }, RawOrigin::Root);
+        assert_eq!(MockPreimages::request_count(&hash), 1);
+
+        assert_ok!(Futarchy::dispatch_proposal(RawOrigin::Root.into(), 0));
+
+        let result = Err(DispatchError::Unavailable);
+        System::assert_last_event(Event::<Runtime>::Dispatched { proposal_id: 0, result }.into());
+        assert_eq!(MockPreimages::request_count(&hash), 0);
+    });
+}
+
+#[test]
+fn dispatch_proposal_fails_on_bad_origin() {
+    ExtBuilder::build().execute_with(|| {
+        let alice = Account::new(0);
+        let call = frame_system::Call::remark { remark: vec![] }.into();
+        schedule_proposal(inline(call), RawOrigin::Root);
+
+        assert_noop!(Futarchy::dispatch_proposal(alice.signed(), 0), DispatchError::BadOrigin);
+    });
+}
+
+#[test]
+fn dispatch_proposal_fails_if_proposal_is_not_scheduled() {
+    ExtBuilder::build().execute_with(|| {
+        assert_noop!(
+            Futarchy::dispatch_proposal(RawOrigin::Root.into(), 0),
+            Error::<Runtime>::ScheduledProposalNotFound
+        );
+    });
+}
+
+#[test]
+fn dispatch_proposal_removes_oldest_outcome() {
+    ExtBuilder::build().execute_with(|| {
+        let call = frame_system::Call::remark { remark: vec![] }.into();
+        let proposal = Proposal {
+            when: Default::default(),
+            call: inline(call),
+            origin: RawOrigin::Root.into(),
+            oracle: MockOracle::new(Default::default(), true),
+            bond: None,
+        };
+        let max_outcomes = <Runtime as Config>::MaxDispatchOutcomes::get() as u128;
+        for proposal_id in 0..=max_outcomes {
+            ScheduledProposals::<Runtime>::insert(proposal_id, proposal.clone());
+            assert_ok!(Futarchy::dispatch_proposal(RawOrigin::Root.into(), proposal_id));
+        }
+
+        assert!(DispatchOutcomes::<Runtime>::get(0).is_none());
+        for proposal_id in 1..=max_outcomes {
+            assert!(DispatchOutcomes::<Runtime>::get(proposal_id).is_some());
+        }
+        assert_eq!(
+            DispatchOutcomeIds::<Runtime>::get().into_inner(),
+            (1..=max_outcomes).collect::<Vec<_>>()
+        );
+        assert_eq!(ScheduledProposals::<Runtime>::count(), 0);
+    });
+}
@@ -17,24 +17,38 @@
 
 #![cfg(all(feature = "mock", test))]
 
+mod cancel_proposal;
+mod composite_oracle;
+mod dispatch_proposal;
+mod proposal_statuses;
+mod submit_bonded_proposal;
 mod submit_proposal;
 
 use crate::{
     mock::{
         ext_builder::ExtBuilder,
-        runtime::{Futarchy, Runtime, RuntimeOrigin, System},
-        types::{MockOracle, MockScheduler},
+        runtime::{
+            Balances, BondedOracleParams, Futarchy, Runtime, RuntimeCall, RuntimeOrigin, System,
+            WhitelistedDispatcher,
+        },
+        types::{MockOracle, MockPreimages, MockScheduler},
         utility,
     },
-    types::Proposal,
-    Config, Error, Event, Proposals, ProposalsOf,
+    types::{Proposal, ProposalInfo, ProposalStatus},
+    Config, DispatchOutcomes, Error, Event, ProposalCount, ProposalIdsPerEvaluationBlock,
+    Proposals, ScheduledProposals,
 };
 use frame_support::{
     assert_noop, assert_ok,
     dispatch::RawOrigin,
-    traits::{schedule::DispatchTime, Bounded},
+    traits::{schedule::DispatchTime, Bounded, Currency, NamedReservableCurrency},
+};
+use parity_scale_codec::Encode;
+use sp_runtime::{traits::BlakeTwo256, DispatchError};
+use zeitgeist_primitives::{
+    constants::BASE,
+    types::{Balance, Bond},
 };
-use sp_runtime::DispatchError;
 
 /// Utility struct for managing test accounts.
 pub(crate) struct Account {
@@ -50,4 +64,15 @@ impl Account {
     pub(crate) fn signed(&self) -> RuntimeOrigin {
         RuntimeOrigin::signed(self.id)
     }
+
+    pub(crate) fn deposit(self, amount: Balance) -> Account {
+        let _ = Balances::deposit_creating(&self.id, amount);
+        self
+    }
+}
+
+/// Returns an inlined `remark` call, which may be dispatched by bonded proposals.
+pub(crate) fn remark_call() -> Bounded<RuntimeCall, BlakeTwo256> {
+    let call: RuntimeCall = frame_system::Call::remark { remark: vec![7u8; 32] }.into();
+    Bounded::Inline(call.encode().try_into().unwrap())
 }
@@ -0,0 +1,71 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+#[test]
+fn proposal_statuses_lists_proposals_in_flight() {
+    ExtBuilder::build().execute_with(|| {
+        let alice = Account::new(0).deposit(BASE * 100);
+        let duration = <Runtime as Config>::MinDuration::get();
+        MockScheduler::set_return_value(Ok(()));
+
+        let call = remark_call();
+        let oracle = MockOracle::new(Default::default(), true);
+        let proposal = Proposal {
+            when: 123,
+            call: call.clone(),
+            origin: RawOrigin::Root.into(),
+            oracle,
+            bond: None,
+        };
+        assert_ok!(Futarchy::submit_proposal(RawOrigin::Root.into(), duration, proposal));
+        utility::run_to_block(2);
+        assert_ok!(Futarchy::submit_bonded_proposal(
+            alice.signed(),
+            duration + 1,
+            456,
+            call,
+            Box::new(RawOrigin::Signed(WhitelistedDispatcher::get()).into()),
+            ()
+        ));
+
+        assert_eq!(
+            Futarchy::proposal_statuses(),
+            vec![
+                ProposalStatus {
+                    proposal_id: 0,
+                    evaluate_at: 1 + duration,
+                    when: 123,
+                    proposer: None,
+                    scores: vec![None],
+                },
+                ProposalStatus {
+                    proposal_id: 1,
+                    evaluate_at: 3 + duration,
+                    when: 456,
+                    proposer: Some(alice.id),
+                    scores: vec![None],
+                },
+            ]
+        );
+
+        utility::run_to_block(1 + duration);
+
+        assert_eq!(Futarchy::proposal_statuses().len(), 1);
+    });
+}
@@ -0,0 +1,279 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use frame_support::pallet_prelude::Weight;
+
+fn submit_bonded_proposal(alice: &Account, value: bool) -> Proposal<Runtime> {
+    let duration = <Runtime as Config>::MinDuration::get();
+
+    let call = remark_call();
+    let oracle = MockOracle::new(Default::default(), value);
+    BondedOracleParams::set(&oracle);
+    let bond_value = <Runtime as Config>::ProposalBond::get();
+    let proposal = Proposal {
+        when: Default::default(),
+        call: call.clone(),
+        origin: RawOrigin::Signed(WhitelistedDispatcher::get()).into(),
+        oracle: oracle.clone(),
+        bond: Some(Bond::new(alice.id, bond_value)),
+    };
+
+    assert_ok!(Futarchy::submit_bonded_proposal(
+        alice.signed(),
+        duration,
+        Default::default(),
+        call,
+        Box::new(RawOrigin::Signed(WhitelistedDispatcher::get()).into()),
+        ()
+    ));
+
+    System::assert_last_event(
+        Event::<Runtime>::Submitted { proposal_id: 0, duration, proposal: proposal.clone() }.into(),
+    );
+    assert_eq!(Balances::reserved_balance_named(&Futarchy::reserve_id(), &alice.id), bond_value);
+
+    proposal
+}
+
+#[test]
+fn submit_bonded_proposal_reserves_bond_and_unreserves_it_if_proposal_is_scheduled() {
+    ExtBuilder::build().execute_with(|| {
+        let alice = Account::new(0).deposit(BASE * 100);
+        MockScheduler::set_return_value(Ok(()));
+
+        let proposal = submit_bonded_proposal(&alice, true);
+
+        let now = System::block_number();
+        let to_be_scheduled_at = now + <Runtime as Config>::MinDuration::get();
+        assert_eq!(
+            Proposals::<Runtime>::get(0),
+            Some(ProposalInfo { evaluate_at: to_be_scheduled_at, proposal: proposal.clone() })
+        );
+
+        utility::run_to_block(to_be_scheduled_at);
+
+        assert!(MockScheduler::called_once_with(
+            DispatchTime::At(proposal.when),
+            Futarchy::dispatch_proposal_call(0).unwrap()
+        ));
+        assert_eq!(ScheduledProposals::<Runtime>::get(0), Some(proposal.clone()));
+        let value = <Runtime as Config>::ProposalBond::get();
+        System::assert_has_event(Event::<Runtime>::BondUnreserved { who: alice.id, value }.into());
+        System::assert_last_event(Event::<Runtime>::Scheduled { proposal_id: 0, proposal }.into());
+        assert_eq!(Balances::reserved_balance_named(&Futarchy::reserve_id(), &alice.id), 0);
+        assert_eq!(Balances::free_balance(alice.id), BASE * 100);
+    });
+}
+
+#[test]
+fn submit_bonded_proposal_slashes_bond_if_proposal_is_rejected() {
+    ExtBuilder::build().execute_with(|| {
+        let alice = Account::new(0).deposit(BASE * 100);
+        MockScheduler::set_return_value(Ok(()));
+
+        let proposal = submit_bonded_proposal(&alice, false);
+
+        let to_be_scheduled_at = System::block_number() + <Runtime as Config>::MinDuration::get();
+        utility::run_to_block(to_be_scheduled_at);
+
+        assert!(MockScheduler::not_called());
+        let value = <Runtime as Config>::ProposalBond::get();
+        System::assert_has_event(Event::<Runtime>::BondSlashed { who: alice.id, value }.into());
+        System::assert_last_event(Event::<Runtime>::Rejected { proposal_id: 0, proposal }.into());
+        assert_eq!(Balances::reserved_balance_named(&Futarchy::reserve_id(), &alice.id), 0);
+        assert_eq!(Balances::free_balance(alice.id), BASE * 100 - value);
+    });
+}
+
+#[test]
+fn submit_bonded_proposal_slashes_bond_if_proposal_cannot_be_scheduled() {
+    ExtBuilder::build().execute_with(|| {
+        let alice = Account::new(0).deposit(BASE * 100);
+        MockScheduler::set_return_value(Err(DispatchError::Other("malformed")));
+
+        submit_bonded_proposal(&alice, true);
+
+        let to_be_scheduled_at = System::block_number() + <Runtime as Config>::MinDuration::get();
+        utility::run_to_block(to_be_scheduled_at);
+
+        let value = <Runtime as Config>::ProposalBond::get();
+        System::assert_has_event(Event::<Runtime>::BondSlashed { who: alice.id, value }.into());
+        System::assert_last_event(Event::<Runtime>::UnexpectedSchedulerError.into());
+        assert_eq!(Balances::reserved_balance_named(&Futarchy::reserve_id(), &alice.id), 0);
+        assert_eq!(Balances::free_balance(alice.id), BASE * 100 - value);
+    });
+}
+
+#[test]
+fn submit_bonded_proposal_fails_on_bad_origin() {
+    ExtBuilder::build().execute_with(|| {
+        let duration = <Runtime as Config>::MinDuration::get();
+
+        assert_noop!(
+            Futarchy::submit_bonded_proposal(
+                RawOrigin::Root.into(),
+                duration,
+                Default::default(),
+                remark_call(),
+                Box::new(RawOrigin::Signed(WhitelistedDispatcher::get()).into()),
+                ()
+            ),
+            DispatchError::BadOrigin,
+        );
+    });
+}
+
+#[test]
+fn submit_bonded_proposal_fails_if_duration_is_too_short() {
+    ExtBuilder::build().execute_with(|| {
+        let alice = Account::new(0).deposit(BASE * 100);
+        let duration = <Runtime as Config>::MinDuration::get() - 1;
+
+        assert_noop!(
+            Futarchy::submit_bonded_proposal(
+                alice.signed(),
+                duration,
+                Default::default(),
+                remark_call(),
+                Box::new(RawOrigin::Signed(WhitelistedDispatcher::get()).into()),
+                ()
+            ),
+            Error::<Runtime>::DurationTooShort
+        );
+    });
+}
+
+#[test]
+fn submit_bonded_proposal_fails_if_bond_cannot_be_reserved() {
+    ExtBuilder::build().execute_with(|| {
+        let alice = Account::new(0);
+        let duration = <Runtime as Config>::MinDuration::get();
+
+        assert_noop!(
+            Futarchy::submit_bonded_proposal(
+                alice.signed(),
+                duration,
+                Default::default(),
+                remark_call(),
+                Box::new(RawOrigin::Signed(WhitelistedDispatcher::get()).into()),
+                ()
+            ),
+            pallet_balances::Error::<Runtime>::InsufficientBalance
+        );
+    });
+}
+
+#[test]
+fn submit_bonded_proposal_deploys_oracle_with_bonded_oracle_params() {
+    ExtBuilder::build().execute_with(|| {
+        let alice = Account::new(0).deposit(BASE * 100);
+        let oracle = MockOracle::new(Weight::from_parts(1, 2), true);
+        BondedOracleParams::set(&oracle);
+
+        assert_ok!(Futarchy::submit_bonded_proposal(
+            alice.signed(),
+            <Runtime as Config>::MinDuration::get(),
+            Default::default(),
+            remark_call(),
+            Box::new(RawOrigin::Signed(WhitelistedDispatcher::get()).into()),
+            ()
+        ));
+
+        assert_eq!(Proposals::<Runtime>::get(0).unwrap().proposal.oracle, oracle);
+    });
+}
+
+#[test]
+fn submit_bonded_proposal_fails_if_call_is_not_allowed() {
+    ExtBuilder::build().execute_with(|| {
+        let alice = Account::new(0).deposit(BASE * 100);
+        let call: RuntimeCall = frame_system::Call::set_heap_pages { pages: 1 }.into();
+
+        assert_noop!(
+            Futarchy::submit_bonded_proposal(
+                alice.signed(),
+                <Runtime as Config>::MinDuration::get(),
+                Default::default(),
+                Bounded::Inline(call.encode().try_into().unwrap()),
+                Box::new(RawOrigin::Signed(WhitelistedDispatcher::get()).into()),
+                ()
+            ),
+            Error::<Runtime>::CallNotAllowed
+        );
+    });
+}
+
+#[test]
+fn submit_bonded_proposal_fails_if_call_is_not_inline() {
+    ExtBuilder::build().execute_with(|| {
+        let alice = Account::new(0).deposit(BASE * 100);
+        let call = Bounded::Lookup { hash: Default::default(), len: 128 };
+
+        assert_noop!(
+            Futarchy::submit_bonded_proposal(
+                alice.signed(),
+                <Runtime as Config>::MinDuration::get(),
+                Default::default(),
+                call,
+                Box::new(RawOrigin::Signed(WhitelistedDispatcher::get()).into()),
+                ()
+            ),
+            Error::<Runtime>::CallNotInline
+        );
+    });
+}
+
+#[test]
+fn submit_bonded_proposal_fails_if_dispatch_origin_is_root() {
+    ExtBuilder::build().execute_with(|| {
+        let alice = Account::new(0).deposit(BASE * 100);
+
+        // `AllowedDispatchOrigin` of the mock runtime allows root, but bonded proposals must never
+        // be dispatched with root privileges.
+        assert_noop!(
+            Futarchy::submit_bonded_proposal(
+                alice.signed(),
+                <Runtime as Config>::MinDuration::get(),
+                Default::default(),
+                remark_call(),
+                Box::new(RawOrigin::Root.into()),
+                ()
+            ),
+            Error::<Runtime>::DispatchOriginNotAllowed
+        );
+    });
+}
+
+#[test]
+fn submit_bonded_proposal_fails_if_dispatch_origin_is_not_allowed() {
+    ExtBuilder::build().execute_with(|| {
+        let alice = Account::new(0).deposit(BASE * 100);
+
+        assert_noop!(
+            Futarchy::submit_bonded_proposal(
+                alice.signed(),
+                <Runtime as Config>::MinDuration::get(),
+                Default::default(),
+                remark_call(),
+                Box::new(RawOrigin::Signed(alice.id).into()),
+                ()
+            ),
+            Error::<Runtime>::DispatchOriginNotAllowed
+        );
+    });
+}
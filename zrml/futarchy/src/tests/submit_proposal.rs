@@ -24,7 +24,13 @@ fn submit_proposal_schedules_proposals() {
 
         let call = Bounded::Inline(vec![7u8; 128].try_into().unwrap());
         let oracle = MockOracle::new(Default::default(), true);
-        let proposal = Proposal { when: Default::default(), call, oracle };
+        let proposal = Proposal {
+            when: Default::default(),
+            call,
+            origin: RawOrigin::Root.into(),
+            oracle,
+            bond: None,
+        };
 
         // This ensures that if the scheduler is erroneously called, the test doesn't fail due to a
         // failure to configure the return value.
@@ -33,24 +39,31 @@ fn submit_proposal_schedules_proposals() {
         assert_ok!(Futarchy::submit_proposal(RawOrigin::Root.into(), duration, proposal.clone()));
 
         System::assert_last_event(
-            Event::<Runtime>::Submitted { duration, proposal: proposal.clone() }.into(),
+            Event::<Runtime>::Submitted { proposal_id: 0, duration, proposal: proposal.clone() }
+                .into(),
         );
 
-        // Check that vector now contains proposal.
+        // Check that storage now contains proposal.
         let now = System::block_number();
         let to_be_scheduled_at = now + duration;
-        assert_eq!(Proposals::get(to_be_scheduled_at).pop(), Some(proposal.clone()));
+        assert_eq!(ProposalIdsPerEvaluationBlock::<Runtime>::get(to_be_scheduled_at).to_vec(), [0]);
+        assert_eq!(
+            Proposals::<Runtime>::get(0),
+            Some(ProposalInfo { evaluate_at: to_be_scheduled_at, proposal: proposal.clone() })
+        );
 
         utility::run_to_block(to_be_scheduled_at);
 
         // The proposal has now been removed and failed.
-        assert!(Proposals::<Runtime>::get(to_be_scheduled_at).is_empty());
+        assert!(ProposalIdsPerEvaluationBlock::<Runtime>::get(to_be_scheduled_at).is_empty());
+        assert!(Proposals::<Runtime>::get(0).is_none());
         assert!(MockScheduler::called_once_with(
             DispatchTime::At(proposal.when),
-            proposal.call.clone()
+            Futarchy::dispatch_proposal_call(0).unwrap()
         ));
+        assert_eq!(ScheduledProposals::<Runtime>::get(0), Some(proposal.clone()));
 
-        System::assert_last_event(Event::<Runtime>::Scheduled { proposal }.into());
+        System::assert_last_event(Event::<Runtime>::Scheduled { proposal_id: 0, proposal }.into());
     });
 }
 
@@ -61,7 +74,13 @@ fn submit_proposal_rejects_proposals() {
 
         let call = Bounded::Inline(vec![7u8; 128].try_into().unwrap());
         let oracle = MockOracle::new(Default::default(), false);
-        let proposal = Proposal { when: Default::default(), call, oracle };
+        let proposal = Proposal {
+            when: Default::default(),
+            call,
+            origin: RawOrigin::Root.into(),
+            oracle,
+            bond: None,
+        };
 
         // This ensures that if the scheduler is erroneously called, the test doesn't fail due to a
         // failure to configure the return value.
@@ -70,21 +89,48 @@ fn submit_proposal_rejects_proposals() {
         assert_ok!(Futarchy::submit_proposal(RawOrigin::Root.into(), duration, proposal.clone()));
 
         System::assert_last_event(
-            Event::<Runtime>::Submitted { duration, proposal: proposal.clone() }.into(),
+            Event::<Runtime>::Submitted { proposal_id: 0, duration, proposal: proposal.clone() }
+                .into(),
         );
 
-        // Check that vector now contains proposal.
+        // Check that storage now contains proposal.
         let now = System::block_number();
         let to_be_scheduled_at = now + duration;
-        assert_eq!(Proposals::get(to_be_scheduled_at).pop(), Some(proposal.clone()));
+        assert_eq!(ProposalIdsPerEvaluationBlock::<Runtime>::get(to_be_scheduled_at).to_vec(), [0]);
+        assert_eq!(
+            Proposals::<Runtime>::get(0),
+            Some(ProposalInfo { evaluate_at: to_be_scheduled_at, proposal: proposal.clone() })
+        );
 
         utility::run_to_block(to_be_scheduled_at);
 
         // The proposal has now been removed and failed.
-        assert!(Proposals::<Runtime>::get(to_be_scheduled_at).is_empty());
+        assert!(ProposalIdsPerEvaluationBlock::<Runtime>::get(to_be_scheduled_at).is_empty());
+        assert!(Proposals::<Runtime>::get(0).is_none());
         assert!(MockScheduler::not_called());
 
-        System::assert_last_event(Event::<Runtime>::Rejected { proposal }.into());
+        System::assert_last_event(Event::<Runtime>::Rejected { proposal_id: 0, proposal }.into());
+    });
+}
+
+#[test]
+fn submit_proposal_requests_preimage_until_proposal_is_rejected() {
+    ExtBuilder::build().execute_with(|| {
+        let duration = <Runtime as Config>::MinDuration::get();
+        let hash = Default::default();
+        let proposal = Proposal {
+            when: Default::default(),
+            call: Bounded::Lookup { hash, len: 128 },
+            origin: RawOrigin::Root.into(),
+            oracle: MockOracle::new(Default::default(), false),
+            bond: None,
+        };
+
+        assert_ok!(Futarchy::submit_proposal(RawOrigin::Root.into(), duration, proposal));
+        assert_eq!(MockPreimages::request_count(&hash), 1);
+
+        utility::run_to_block(System::block_number() + duration);
+        assert_eq!(MockPreimages::request_count(&hash), 0);
     });
 }
 
@@ -97,7 +143,13 @@ fn submit_proposal_fails_on_bad_origin() {
 
         let call = Bounded::Inline(vec![7u8; 128].try_into().unwrap());
         let oracle = MockOracle::new(Default::default(), Default::default());
-        let proposal = Proposal { when: Default::default(), call, oracle };
+        let proposal = Proposal {
+            when: Default::default(),
+            call,
+            origin: RawOrigin::Root.into(),
+            oracle,
+            bond: None,
+        };
 
         assert_noop!(
             Futarchy::submit_proposal(alice.signed(), duration, proposal),
@@ -106,6 +158,25 @@ fn submit_proposal_fails_on_bad_origin() {
     });
 }
 
+#[test]
+fn submit_proposal_fails_on_invalid_oracle() {
+    ExtBuilder::build().execute_with(|| {
+        let duration = <Runtime as Config>::MinDuration::get();
+        let proposal = Proposal {
+            when: Default::default(),
+            call: Bounded::Inline(vec![7u8; 128].try_into().unwrap()),
+            origin: RawOrigin::Root.into(),
+            oracle: MockOracle::default().invalid(),
+            bond: None,
+        };
+
+        assert_noop!(
+            Futarchy::submit_proposal(RawOrigin::Root.into(), duration, proposal),
+            Error::<Runtime>::InvalidCompositeOracle
+        );
+    });
+}
+
 #[test]
 fn submit_proposal_fails_if_duration_is_too_short() {
     ExtBuilder::build().execute_with(|| {
@@ -113,7 +184,13 @@ fn submit_proposal_fails_if_duration_is_too_short() {
 
         let call = Bounded::Inline(vec![7u8; 128].try_into().unwrap());
         let oracle = MockOracle::new(Default::default(), Default::default());
-        let proposal = Proposal { when: Default::default(), call, oracle };
+        let proposal = Proposal {
+            when: Default::default(),
+            call,
+            origin: RawOrigin::Root.into(),
+            oracle,
+            bond: None,
+        };
 
         assert_noop!(
             Futarchy::submit_proposal(RawOrigin::Root.into(), duration, proposal),
@@ -129,15 +206,49 @@ fn submit_proposal_fails_if_cache_is_full() {
 
         let call = Bounded::Inline(vec![7u8; 128].try_into().unwrap());
         let oracle = MockOracle::new(Default::default(), Default::default());
-        let proposal = Proposal { when: Default::default(), call, oracle };
+        let proposal = Proposal {
+            when: Default::default(),
+            call,
+            origin: RawOrigin::Root.into(),
+            oracle,
+            bond: None,
+        };
 
-        // Mock up a full vector of proposals.
-        let now = System::block_number();
-        let to_be_scheduled_at = now + duration;
-        let max_proposals: u32 = <Runtime as Config>::MaxProposals::get();
-        let proposals_vec = vec![proposal.clone(); max_proposals as usize];
-        let proposals: ProposalsOf<Runtime> = proposals_vec.try_into().unwrap();
-        Proposals::<Runtime>::insert(to_be_scheduled_at, proposals);
+        // Mock up a full cache of proposals.
+        for _ in 0..<Runtime as Config>::MaxProposals::get() {
+            assert_ok!(Futarchy::submit_proposal(
+                RawOrigin::Root.into(),
+                duration,
+                proposal.clone()
+            ));
+        }
+
+        assert_noop!(
+            Futarchy::submit_proposal(RawOrigin::Root.into(), duration, proposal),
+            Error::<Runtime>::CacheFull
+        );
+    });
+}
+
+#[test]
+fn submit_proposal_fails_if_cache_is_full_of_scheduled_proposals() {
+    ExtBuilder::build().execute_with(|| {
+        let duration = <Runtime as Config>::MinDuration::get();
+
+        let call = Bounded::Inline(vec![7u8; 128].try_into().unwrap());
+        let oracle = MockOracle::new(Default::default(), true);
+        let proposal = Proposal {
+            when: Default::default(),
+            call,
+            origin: RawOrigin::Root.into(),
+            oracle,
+            bond: None,
+        };
+
+        // Scheduled proposals which aren't dispatched yet count towards `MaxProposals`.
+        for proposal_id in 0..<Runtime as Config>::MaxProposals::get() {
+            ScheduledProposals::<Runtime>::insert(proposal_id as u128, proposal.clone());
+        }
 
         assert_noop!(
             Futarchy::submit_proposal(RawOrigin::Root.into(), duration, proposal),
@@ -145,3 +256,26 @@ fn submit_proposal_fails_if_cache_is_full() {
         );
     });
 }
+
+#[test]
+fn submit_proposal_fails_if_proposal_has_bond() {
+    ExtBuilder::build().execute_with(|| {
+        let duration = <Runtime as Config>::MinDuration::get();
+
+        let call = Bounded::Inline(vec![7u8; 128].try_into().unwrap());
+        let oracle = MockOracle::new(Default::default(), Default::default());
+        let bond = Some(Bond::new(0, 1));
+        let proposal = Proposal {
+            when: Default::default(),
+            call,
+            origin: RawOrigin::Root.into(),
+            oracle,
+            bond,
+        };
+
+        assert_noop!(
+            Futarchy::submit_proposal(RawOrigin::Root.into(), duration, proposal),
+            Error::<Runtime>::UnexpectedBond
+        );
+    });
+}
@@ -15,7 +15,10 @@
 // You should have received a copy of the GNU General Public License
 // along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{types::Proposal, Config, ProposalsOf};
+use crate::{
+    types::{Proposal, ProposalInfo},
+    Config, ProposalId,
+};
 use alloc::{collections::BTreeMap, vec::Vec};
 use frame_system::pallet_prelude::BlockNumberFor;
 use sp_runtime::DispatchError;
@@ -28,18 +31,25 @@ where
     #[allow(dead_code)]
     fn count() -> u32;
 
-    /// Schedule `proposal` for evaluation at `block_number`.
-    fn add(block_number: BlockNumberFor<T>, proposal: Proposal<T>) -> Result<(), DispatchError>;
+    /// Schedule `proposal` for evaluation at `block_number` and return its id.
+    fn add(
+        block_number: BlockNumberFor<T>,
+        proposal: Proposal<T>,
+    ) -> Result<ProposalId, DispatchError>;
 
     /// Take all proposals scheduled at `block_number`.
-    fn take(block_number: BlockNumberFor<T>) -> Result<ProposalsOf<T>, DispatchError>;
+    fn take(
+        block_number: BlockNumberFor<T>,
+    ) -> Result<Vec<(ProposalId, Proposal<T>)>, DispatchError>;
 
-    /// Returns all proposals scheduled at `block_number`.
-    #[allow(dead_code)]
-    fn get(block_number: BlockNumberFor<T>) -> ProposalsOf<T>;
+    /// Returns the proposal with id `proposal_id`.
+    fn get(proposal_id: ProposalId) -> Option<ProposalInfo<T>>;
+
+    /// Removes the proposal with id `proposal_id` from storage and returns it.
+    fn remove(proposal_id: ProposalId) -> Result<ProposalInfo<T>, DispatchError>;
 
     /// Mutates all scheduled proposals.
-    fn mutate_all<R, F>(mutator: F) -> Result<BTreeMap<BlockNumberFor<T>, Vec<R>>, DispatchError>
+    fn mutate_all<R, F>(mutator: F) -> Result<BTreeMap<ProposalId, R>, DispatchError>
     where
         F: FnMut(&mut Proposal<T>) -> R;
 }
@@ -0,0 +1,126 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::types::CompositeRule;
+use alloc::{fmt::Debug, vec::Vec};
+use frame_support::{
+    pallet_prelude::Weight, BoundedVec, CloneNoBound, EqNoBound, PartialEqNoBound,
+    RuntimeDebugNoBound,
+};
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::{
+    traits::{Get, Zero},
+    SaturatedConversion, Saturating,
+};
+use zeitgeist_primitives::{traits::FutarchyOracle, types::FutarchyOracleScore};
+
+/// Oracle which combines the verdicts of up to `S` sub-oracles using a [`CompositeRule`].
+///
+/// Updating or evaluating the oracle updates or evaluates all sub-oracles and consumes the sum of
+/// their weights.
+#[derive(
+    CloneNoBound,
+    Decode,
+    Encode,
+    EqNoBound,
+    MaxEncodedLen,
+    PartialEqNoBound,
+    RuntimeDebugNoBound,
+    TypeInfo,
+)]
+#[scale_info(skip_type_params(S))]
+pub struct CompositeOracle<O, S>
+where
+    O: Clone + Debug + Eq + FutarchyOracle,
+    S: Get<u32>,
+{
+    oracles: BoundedVec<O, S>,
+    rule: CompositeRule,
+}
+
+impl<O, S> CompositeOracle<O, S>
+where
+    O: Clone + Debug + Eq + FutarchyOracle,
+    S: Get<u32>,
+{
+    /// Creates a composite oracle from `oracles` and `rule`. Returns `None` if there are too many
+    /// oracles or if `rule` can't be applied to the number of oracles.
+    pub fn new(oracles: Vec<O>, rule: CompositeRule) -> Option<Self> {
+        let oracles: BoundedVec<O, S> = oracles.try_into().ok()?;
+        if !rule.is_valid(oracles.len().saturated_into()) {
+            return None;
+        }
+
+        Some(Self { oracles, rule })
+    }
+
+    pub fn oracles(&self) -> &[O] {
+        &self.oracles
+    }
+
+    pub fn rule(&self) -> CompositeRule {
+        self.rule
+    }
+}
+
+impl<O, S> FutarchyOracle for CompositeOracle<O, S>
+where
+    O: Clone + Debug + Eq + FutarchyOracle,
+    O::BlockNumber: Copy,
+    S: Get<u32>,
+{
+    type BlockNumber = O::BlockNumber;
+
+    fn evaluate(&self) -> (Weight, bool) {
+        let mut total_weight = Weight::zero();
+        let mut approvals = 0u32;
+
+        for oracle in self.oracles.iter() {
+            let (weight, value) = oracle.evaluate();
+            total_weight = total_weight.saturating_add(weight);
+            if value {
+                approvals.saturating_inc();
+            }
+        }
+
+        let value = self.rule.is_satisfied(approvals, self.oracles.len().saturated_into());
+
+        (total_weight, value)
+    }
+
+    fn update(&mut self, now: Self::BlockNumber) -> Weight {
+        self.oracles
+            .iter_mut()
+            .fold(Weight::zero(), |acc, oracle| acc.saturating_add(oracle.update(now)))
+    }
+
+    /// Returns `None`. The scores of sub-oracles of different kinds aren't comparable, so they're
+    /// returned separately by [`scores`](Self::scores).
+    fn score(&self) -> Option<FutarchyOracleScore> {
+        None
+    }
+
+    fn scores(&self) -> Vec<Option<FutarchyOracleScore>> {
+        self.oracles.iter().flat_map(|oracle| oracle.scores()).collect()
+    }
+
+    fn is_valid(&self) -> bool {
+        self.rule.is_valid(self.oracles.len().saturated_into())
+            && self.oracles.iter().all(|oracle| oracle.is_valid())
+    }
+}
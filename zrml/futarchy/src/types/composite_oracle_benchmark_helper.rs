@@ -0,0 +1,41 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use crate::types::{CompositeOracle, CompositeRule};
+use alloc::{fmt::Debug, vec::Vec};
+use core::marker::PhantomData;
+use sp_runtime::traits::Get;
+use zeitgeist_primitives::traits::{FutarchyBenchmarkHelper, FutarchyOracle};
+
+/// Creates composite oracles with the maximum number of sub-oracles created by `H`.
+pub struct CompositeOracleBenchmarkHelper<H, O, S>(PhantomData<(H, O, S)>);
+
+impl<H, O, S> FutarchyBenchmarkHelper<CompositeOracle<O, S>>
+    for CompositeOracleBenchmarkHelper<H, O, S>
+where
+    H: FutarchyBenchmarkHelper<O>,
+    O: Clone + Debug + Eq + FutarchyOracle,
+    S: Get<u32>,
+{
+    fn create_oracle(value: bool) -> CompositeOracle<O, S> {
+        let oracles = (0..S::get()).map(|_| H::create_oracle(value)).collect::<Vec<_>>();
+
+        CompositeOracle::new(oracles, CompositeRule::All).expect("`S` must not be zero")
+    }
+}
@@ -0,0 +1,69 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    types::{CompositeOracle, CompositeOracleParams},
+    Config, Error,
+};
+use alloc::{fmt::Debug, vec::Vec};
+use core::marker::PhantomData;
+use frame_support::{ensure, pallet_prelude::Weight, BoundedVec};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::{traits::Get, DispatchError, SaturatedConversion};
+use zeitgeist_primitives::traits::{FutarchyOracle, FutarchyOracleDeployer};
+
+/// Deploys a [`CompositeOracle`] by deploying each of its sub-oracles using `D`. The metadata must
+/// contain one element per sub-oracle.
+pub struct CompositeOracleDeployer<T, D, S>(PhantomData<(T, D, S)>);
+
+impl<T, D, S> FutarchyOracleDeployer for CompositeOracleDeployer<T, D, S>
+where
+    T: Config,
+    D: FutarchyOracleDeployer<AccountId = T::AccountId, BlockNumber = BlockNumberFor<T>>,
+    D::Oracle: Clone + Debug + Eq + FutarchyOracle,
+    D::Params: Clone + Debug + Eq,
+    S: Get<u32>,
+{
+    type AccountId = T::AccountId;
+    type BlockNumber = BlockNumberFor<T>;
+    type Oracle = CompositeOracle<D::Oracle, S>;
+    type Params = CompositeOracleParams<D::Params, S>;
+    type Metadata = BoundedVec<D::Metadata, S>;
+
+    fn deploy_oracle(
+        who: Self::AccountId,
+        evaluate_at: Self::BlockNumber,
+        params: Self::Params,
+        metadata: Self::Metadata,
+    ) -> Result<Self::Oracle, DispatchError> {
+        let CompositeOracleParams { params, rule } = params;
+        ensure!(rule.is_valid(params.len().saturated_into()), Error::<T>::InvalidCompositeOracle);
+        ensure!(params.len() == metadata.len(), Error::<T>::InvalidCompositeOracle);
+
+        let oracles = params
+            .into_iter()
+            .zip(metadata)
+            .map(|(p, m)| D::deploy_oracle(who.clone(), evaluate_at, p, m))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(CompositeOracle::new(oracles, rule).ok_or(Error::<T>::InvalidCompositeOracle)?)
+    }
+
+    fn deploy_oracle_weight() -> Weight {
+        D::deploy_oracle_weight().saturating_mul(S::get().into())
+    }
+}
@@ -0,0 +1,42 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::types::CompositeRule;
+use alloc::fmt::Debug;
+use frame_support::{BoundedVec, CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound};
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::traits::Get;
+
+/// Parameters used by [`CompositeOracleDeployer`](crate::types::CompositeOracleDeployer) to deploy
+/// a [`CompositeOracle`](crate::types::CompositeOracle). Each element of `params` is used to deploy
+/// one sub-oracle.
+#[derive(
+    CloneNoBound, Decode, Encode, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound, TypeInfo,
+)]
+#[scale_info(skip_type_params(S))]
+pub struct CompositeOracleParams<P, S>
+where
+    P: Clone + Debug + Eq,
+    S: Get<u32>,
+{
+    /// The parameters of the sub-oracles.
+    pub params: BoundedVec<P, S>,
+
+    /// The rule used to combine the verdicts of the sub-oracles.
+    pub rule: CompositeRule,
+}
@@ -0,0 +1,53 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+/// Rule used by a [`CompositeOracle`](crate::types::CompositeOracle) to combine the verdicts of
+/// its sub-oracles.
+#[derive(Clone, Copy, Decode, Encode, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum CompositeRule {
+    /// All sub-oracles must evaluate positively.
+    All,
+
+    /// At least one sub-oracle must evaluate positively.
+    Any,
+
+    /// At least the specified number of sub-oracles must evaluate positively.
+    AtLeast(u32),
+}
+
+impl CompositeRule {
+    /// Returns `true` if and only if the rule can be applied to `count` sub-oracles.
+    pub fn is_valid(&self, count: u32) -> bool {
+        match *self {
+            CompositeRule::All | CompositeRule::Any => count > 0,
+            CompositeRule::AtLeast(threshold) => threshold > 0 && threshold <= count,
+        }
+    }
+
+    /// Returns `true` if and only if `approvals` out of `count` positive verdicts satisfy the rule.
+    pub fn is_satisfied(&self, approvals: u32, count: u32) -> bool {
+        match *self {
+            CompositeRule::All => approvals == count,
+            CompositeRule::Any => approvals > 0,
+            CompositeRule::AtLeast(threshold) => approvals >= threshold,
+        }
+    }
+}
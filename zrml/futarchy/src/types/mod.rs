@@ -15,6 +15,23 @@
 // You should have received a copy of the GNU General Public License
 // along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
 
+mod composite_oracle;
+mod composite_oracle_benchmark_helper;
+mod composite_oracle_deployer;
+mod composite_oracle_params;
+mod composite_rule;
+mod dispatch_outcome;
 mod proposal;
+mod proposal_info;
+mod proposal_status;
 
+pub use composite_oracle::CompositeOracle;
+#[cfg(feature = "runtime-benchmarks")]
+pub use composite_oracle_benchmark_helper::CompositeOracleBenchmarkHelper;
+pub use composite_oracle_deployer::CompositeOracleDeployer;
+pub use composite_oracle_params::CompositeOracleParams;
+pub use composite_rule::CompositeRule;
+pub use dispatch_outcome::DispatchOutcome;
 pub use proposal::Proposal;
+pub use proposal_info::ProposalInfo;
+pub use proposal_status::ProposalStatus;
@@ -15,16 +15,17 @@
 // You should have received a copy of the GNU General Public License
 // along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{BoundedCallOf, Config, OracleOf};
+use crate::{BalanceOf, BoundedCallOf, Config, OracleOf, PalletsOriginOf};
 use frame_support::{CloneNoBound, PartialEqNoBound, RuntimeDebugNoBound};
 use frame_system::pallet_prelude::BlockNumberFor;
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
+use zeitgeist_primitives::types::Bond;
 
 #[cfg(feature = "fuzzing")]
 use {
     arbitrary::{Arbitrary, Result as ArbitraryResult, Unstructured},
-    frame_support::traits::Bounded,
+    frame_support::{dispatch::RawOrigin, traits::Bounded},
     sp_core::H256,
     sp_runtime::traits::Hash,
 };
@@ -44,8 +45,15 @@ where
     /// The proposed call.
     pub call: BoundedCallOf<T>,
 
+    /// The origin the call is dispatched with.
+    pub origin: PalletsOriginOf<T>,
+
     /// The oracle that evaluates if the proposal should be enacted.
     pub oracle: OracleOf<T>,
+
+    /// The bond reserved by the proposer; `None` if the proposal was submitted with root
+    /// privileges.
+    pub bond: Option<Bond<T::AccountId, BalanceOf<T>>>,
 }
 
 #[cfg(feature = "fuzzing")]
@@ -65,6 +73,6 @@ where
 
         let oracle = Arbitrary::arbitrary(u)?;
 
-        Ok(Proposal { when, call, oracle })
+        Ok(Proposal { when, call, origin: RawOrigin::Root.into(), oracle, bond: None })
     }
 }
@@ -0,0 +1,44 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::ProposalId;
+use alloc::vec::Vec;
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+use zeitgeist_primitives::types::FutarchyOracleScore;
+
+/// The status of a proposal in flight, as returned by the runtime API.
+#[derive(Clone, Decode, Encode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct ProposalStatus<AccountId, BlockNumber> {
+    /// The id of the proposal.
+    pub proposal_id: ProposalId,
+
+    /// The block at which the oracle of the proposal is evaluated.
+    pub evaluate_at: BlockNumber,
+
+    /// The block at which the proposal is enacted if the oracle evaluates positively.
+    pub when: BlockNumber,
+
+    /// The account which reserved the bond of the proposal; `None` if the proposal was submitted
+    /// with root privileges.
+    pub proposer: Option<AccountId>,
+
+    /// The current scores of the oracles of the proposal, one for each sub-oracle of a composite
+    /// oracle; `None` for oracles which don't keep score.
+    pub scores: Vec<Option<FutarchyOracleScore>>,
+}
@@ -52,8 +52,11 @@ use frame_support::{traits::Get, weights::Weight};
 /// zrml_futarchy (automatically generated)
 pub trait WeightInfoZeitgeist {
     fn submit_proposal() -> Weight;
+    fn submit_bonded_proposal() -> Weight;
     fn maybe_schedule_proposal() -> Weight;
     fn take_proposals(n: u32) -> Weight;
+    fn cancel_proposal() -> Weight;
+    fn dispatch_proposal() -> Weight;
 }
 
 /// Weight functions for zrml_futarchy (automatically generated)
@@ -61,42 +64,115 @@ pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
     /// Storage: `Futarchy::ProposalCount` (r:1 w:1)
     /// Proof: `Futarchy::ProposalCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
-    /// Storage: `Futarchy::Proposals` (r:1 w:1)
-    /// Proof: `Futarchy::Proposals` (`max_values`: None, `max_size`: Some(1261), added: 3736, mode: `MaxEncodedLen`)
+    /// Storage: `Futarchy::CounterForScheduledProposals` (r:1 w:0)
+    /// Proof: `Futarchy::CounterForScheduledProposals` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `Futarchy::NextProposalId` (r:1 w:1)
+    /// Proof: `Futarchy::NextProposalId` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+    /// Storage: `Futarchy::ProposalIdsPerEvaluationBlock` (r:1 w:1)
+    /// Proof: `Futarchy::ProposalIdsPerEvaluationBlock` (`max_values`: None, `max_size`: Some(87), added: 2562, mode: `MaxEncodedLen`)
+    /// Storage: `Futarchy::Proposals` (r:0 w:1)
+    /// Proof: `Futarchy::Proposals` (`max_values`: None, `max_size`: Some(521), added: 2996, mode: `MaxEncodedLen`)
+    /// Storage: `Preimage::RequestStatusFor` (r:1 w:1)
+    /// Proof: `Preimage::RequestStatusFor` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
     fn submit_proposal() -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `122`
-        //  Estimated: `4726`
-        // Minimum execution time: 25_661 nanoseconds.
-        Weight::from_parts(26_931_000, 4726)
-            .saturating_add(T::DbWeight::get().reads(2))
-            .saturating_add(T::DbWeight::get().writes(2))
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_futarchy` to replace it.
+        Weight::from_parts(31_370_000, 3552)
+            .saturating_add(T::DbWeight::get().reads(5))
+            .saturating_add(T::DbWeight::get().writes(5))
+    }
+    /// Storage: `Balances::Reserves` (r:1 w:1)
+    /// Proof: `Balances::Reserves` (`max_values`: None, `max_size`: Some(1249), added: 3724, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+    /// Storage: `Futarchy::ProposalCount` (r:1 w:1)
+    /// Proof: `Futarchy::ProposalCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `Futarchy::CounterForScheduledProposals` (r:1 w:0)
+    /// Proof: `Futarchy::CounterForScheduledProposals` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `Futarchy::NextProposalId` (r:1 w:1)
+    /// Proof: `Futarchy::NextProposalId` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+    /// Storage: `Futarchy::ProposalIdsPerEvaluationBlock` (r:1 w:1)
+    /// Proof: `Futarchy::ProposalIdsPerEvaluationBlock` (`max_values`: None, `max_size`: Some(87), added: 2562, mode: `MaxEncodedLen`)
+    /// Storage: `Futarchy::Proposals` (r:0 w:1)
+    /// Proof: `Futarchy::Proposals` (`max_values`: None, `max_size`: Some(521), added: 2996, mode: `MaxEncodedLen`)
+    fn submit_bonded_proposal() -> Weight {
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_futarchy` to replace it.
+        Weight::from_parts(64_600_000, 4714)
+            .saturating_add(T::DbWeight::get().reads(6))
+            .saturating_add(T::DbWeight::get().writes(6))
     }
     /// Storage: `Scheduler::Agenda` (r:1 w:1)
     /// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(109074), added: 111549, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Reserves` (r:1 w:1)
+    /// Proof: `Balances::Reserves` (`max_values`: None, `max_size`: Some(1249), added: 3724, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+    /// Storage: `Futarchy::CounterForScheduledProposals` (r:1 w:1)
+    /// Proof: `Futarchy::CounterForScheduledProposals` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `Futarchy::ScheduledProposals` (r:1 w:1)
+    /// Proof: `Futarchy::ScheduledProposals` (`max_values`: None, `max_size`: Some(566), added: 3041, mode: `MaxEncodedLen`)
+    /// Storage: `Preimage::RequestStatusFor` (r:1 w:1)
+    /// Proof: `Preimage::RequestStatusFor` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
     fn maybe_schedule_proposal() -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `3`
-        //  Estimated: `112539`
-        // Minimum execution time: 15_980 nanoseconds.
-        Weight::from_parts(16_851_000, 112539)
-            .saturating_add(T::DbWeight::get().reads(1))
-            .saturating_add(T::DbWeight::get().writes(1))
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_futarchy` to replace it.
+        Weight::from_parts(51_631_000, 112539)
+            .saturating_add(T::DbWeight::get().reads(6))
+            .saturating_add(T::DbWeight::get().writes(6))
     }
-    /// Storage: `Futarchy::Proposals` (r:1 w:1)
-    /// Proof: `Futarchy::Proposals` (`max_values`: None, `max_size`: Some(1261), added: 3736, mode: `MaxEncodedLen`)
-    /// Storage: `Futarchy::ProposalCount` (r:1 w:0)
+    /// Storage: `Futarchy::ProposalIdsPerEvaluationBlock` (r:1 w:1)
+    /// Proof: `Futarchy::ProposalIdsPerEvaluationBlock` (`max_values`: None, `max_size`: Some(87), added: 2562, mode: `MaxEncodedLen`)
+    /// Storage: `Futarchy::Proposals` (r:4 w:4)
+    /// Proof: `Futarchy::Proposals` (`max_values`: None, `max_size`: Some(521), added: 2996, mode: `MaxEncodedLen`)
+    /// Storage: `Futarchy::ProposalCount` (r:1 w:1)
     /// Proof: `Futarchy::ProposalCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
     /// The range of component `n` is `[1, 4]`.
     fn take_proposals(n: u32) -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `94 + n * (309 ±0)`
-        //  Estimated: `4726`
-        // Minimum execution time: 12_461 nanoseconds.
-        Weight::from_parts(12_714_998, 4726)
-            // Standard Error: 9_608
-            .saturating_add(Weight::from_parts(476_725, 0).saturating_mul(n.into()))
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_futarchy` to replace it.
+        Weight::from_parts(13_901_322, 3552)
+            .saturating_add(Weight::from_parts(5_012_473, 0).saturating_mul(n.into()))
             .saturating_add(T::DbWeight::get().reads(2))
-            .saturating_add(T::DbWeight::get().writes(1))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes(2))
+            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 2996).saturating_mul(n.into()))
+    }
+    /// Storage: `Futarchy::Proposals` (r:1 w:1)
+    /// Proof: `Futarchy::Proposals` (`max_values`: None, `max_size`: Some(521), added: 2996, mode: `MaxEncodedLen`)
+    /// Storage: `Futarchy::ProposalIdsPerEvaluationBlock` (r:1 w:1)
+    /// Proof: `Futarchy::ProposalIdsPerEvaluationBlock` (`max_values`: None, `max_size`: Some(87), added: 2562, mode: `MaxEncodedLen`)
+    /// Storage: `Futarchy::ProposalCount` (r:1 w:1)
+    /// Proof: `Futarchy::ProposalCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Reserves` (r:1 w:1)
+    /// Proof: `Balances::Reserves` (`max_values`: None, `max_size`: Some(1249), added: 3724, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+    /// Storage: `Preimage::RequestStatusFor` (r:1 w:1)
+    /// Proof: `Preimage::RequestStatusFor` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+    fn cancel_proposal() -> Weight {
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_futarchy` to replace it.
+        Weight::from_parts(53_441_000, 4714)
+            .saturating_add(T::DbWeight::get().reads(6))
+            .saturating_add(T::DbWeight::get().writes(6))
+    }
+    /// Storage: `Futarchy::ScheduledProposals` (r:1 w:1)
+    /// Proof: `Futarchy::ScheduledProposals` (`max_values`: None, `max_size`: Some(566), added: 3041, mode: `MaxEncodedLen`)
+    /// Storage: `Futarchy::CounterForScheduledProposals` (r:1 w:1)
+    /// Proof: `Futarchy::CounterForScheduledProposals` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `Futarchy::DispatchOutcomeIds` (r:1 w:1)
+    /// Proof: `Futarchy::DispatchOutcomeIds` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `MaxEncodedLen`)
+    /// Storage: `Futarchy::DispatchOutcomes` (r:0 w:2)
+    /// Proof: `Futarchy::DispatchOutcomes` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+    /// Storage: `Preimage::RequestStatusFor` (r:1 w:1)
+    /// Proof: `Preimage::RequestStatusFor` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+    fn dispatch_proposal() -> Weight {
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_futarchy` to replace it.
+        Weight::from_parts(33_830_000, 4031)
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(6))
     }
 }
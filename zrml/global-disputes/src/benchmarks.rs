@@ -30,12 +30,12 @@ use crate::{
 use frame_benchmarking::{account, benchmarks, whitelisted_caller};
 use frame_support::{
     sp_runtime::traits::StaticLookup,
-    traits::{Currency, Get},
+    traits::{Currency, EnsureOrigin, Get, UnfilteredDispatchable},
     BoundedVec,
 };
 use frame_system::RawOrigin;
 use num_traits::ops::checked::CheckedRem;
-use sp_runtime::traits::{Bounded, SaturatedConversion, Saturating};
+use sp_runtime::traits::{Bounded, SaturatedConversion, Saturating, Zero};
 use sp_std::prelude::*;
 use zeitgeist_primitives::types::OutcomeReport;
 use zrml_market_commons::MarketCommonsPalletApi;
@@ -94,14 +94,11 @@ benchmarks! {
         )
         .unwrap();
 
-        let mut vote_locks: BoundedVec<(
-            MarketIdOf<T>,
-            BalanceOf<T>
-        ), T::MaxGlobalDisputeVotes> = Default::default();
+        let mut vote_locks: LockInfoOf<T> = Default::default();
         for i in 0..v {
             let market_id: MarketIdOf<T> = i.saturated_into();
             let locked_balance: BalanceOf<T> = T::MinOutcomeVoteAmount::get().saturated_into();
-            vote_locks.try_push((market_id, locked_balance)).unwrap();
+            vote_locks.try_push(VoteLock::new(market_id, locked_balance)).unwrap();
         }
         <Locks<T>>::insert(caller.clone(), vote_locks);
 
@@ -133,6 +130,104 @@ benchmarks! {
         );
     }
 
+    vote_on_outcome_with_conviction {
+        // only Outcomes owners, but not GlobalDisputesInfo owners is present during vote_on_outcome
+        let o in 2..T::MaxOwners::get();
+
+        // ensure we have one vote left for the call
+        let v in 0..(T::MaxGlobalDisputeVotes::get() - 1);
+
+        let caller: T::AccountId = whitelisted_caller();
+        // ensure that we get the worst case
+        // to actually insert the new item at the end of the binary search
+        let market_id: MarketIdOf<T> = v.into();
+        let market = market_mock::<T>();
+        for i in 0..=v {
+            T::MarketCommons::push_market(market.clone()).unwrap();
+        }
+
+        let outcome = OutcomeReport::Scalar(0);
+        let amount: BalanceOf<T> = T::MinOutcomeVoteAmount::get().saturated_into();
+        deposit::<T>(&caller);
+
+        let mut initial_items: Vec<crate::InitialItemOf<T>> = Vec::new();
+        initial_items.push(InitialItem {
+            outcome: outcome.clone(),
+            owner: caller.clone(),
+            amount: 1_000_000_000u128.saturated_into(),
+        });
+        for i in 1..=o {
+            let owner = account("outcomes_owner", i, 0);
+            initial_items.push(InitialItem {
+                outcome: OutcomeReport::Scalar(i.saturated_into()),
+                owner,
+                amount: 1_000_000_000u128.saturated_into(),
+            });
+        }
+
+        GlobalDisputes::<T>::start_global_dispute(
+            &market_id,
+            initial_items.as_slice(),
+        )
+        .unwrap();
+
+        let mut vote_locks: LockInfoOf<T> = Default::default();
+        for i in 0..v {
+            let market_id: MarketIdOf<T> = i.saturated_into();
+            let locked_balance: BalanceOf<T> = T::MinOutcomeVoteAmount::get().saturated_into();
+            vote_locks.try_push(VoteLock::new(market_id, locked_balance)).unwrap();
+        }
+        <Locks<T>>::insert(caller.clone(), vote_locks);
+
+        // minus one to ensure, that we use the worst case
+        // for using a new winner info after the vote_on_outcome call
+        let vote_sum = amount - 1u128.saturated_into();
+        let possession = Possession::Shared { owners: Default::default() };
+        let outcome_info = OutcomeInfo { outcome_sum: vote_sum, possession };
+        let now = <frame_system::Pallet<T>>::block_number();
+        let add_outcome_end = now + T::AddOutcomePeriod::get();
+        let vote_end = add_outcome_end + T::GdVotingPeriod::get();
+        let gd_info = GlobalDisputeInfo {
+            winner_outcome: outcome.clone(),
+            status: GdStatus::Active { add_outcome_end, vote_end },
+            outcome_info,
+        };
+        <GlobalDisputesInfo<T>>::insert(market_id, gd_info);
+        <VotingWeightModes<T>>::insert(market_id, VotingWeightMode::Conviction);
+        <frame_system::Pallet<T>>::set_block_number(add_outcome_end + 1u32.into());
+    }: _(
+        RawOrigin::Signed(caller.clone()),
+        market_id,
+        outcome.clone(),
+        amount,
+        Conviction::Locked6x
+    )
+    verify {
+        assert_last_event::<T>(
+            Event::VotedOnOutcome::<T> {
+                market_id,
+                voter: caller,
+                outcome,
+                vote_amount: amount,
+            }
+            .into(),
+        );
+    }
+
+    set_voting_weight_mode {
+        let market_id: MarketIdOf<T> = 0u128.saturated_into();
+        let market = market_mock::<T>();
+        T::MarketCommons::push_market(market).unwrap();
+
+        let origin = T::VotingWeightModeOrigin::try_successful_origin().unwrap();
+        let mode = VotingWeightMode::Quadratic;
+        let call = Call::<T>::set_voting_weight_mode { market_id, mode };
+    }: {
+        call.dispatch_bypass_filter(origin)?
+    } verify {
+        assert_last_event::<T>(Event::VotingWeightModeSet::<T> { market_id, mode }.into());
+    }
+
     unlock_vote_balance_set {
         let l in 0..T::MaxGlobalDisputeVotes::get();
         let o in 1..T::MaxOwners::get();
@@ -161,12 +256,11 @@ benchmarks! {
         let caller: T::AccountId = whitelisted_caller();
         let voter: T::AccountId = account("voter", 0, 0);
         let voter_lookup = T::Lookup::unlookup(voter.clone());
-        let mut vote_locks: BoundedVec<(MarketIdOf<T>, BalanceOf<T>), T::MaxGlobalDisputeVotes> =
-            Default::default();
+        let mut vote_locks: LockInfoOf<T> = Default::default();
         for i in 0..l {
             let market_id: MarketIdOf<T> = i.saturated_into();
             let locked_balance: BalanceOf<T> = i.saturated_into();
-            vote_locks.try_push((market_id, locked_balance)).unwrap();
+            vote_locks.try_push(VoteLock::new(market_id, locked_balance)).unwrap();
             <GlobalDisputesInfo<T>>::insert(market_id, gd_info.clone());
         }
         <Locks<T>>::insert(voter.clone(), vote_locks.clone());
@@ -202,14 +296,11 @@ benchmarks! {
         let caller: T::AccountId = whitelisted_caller();
         let voter: T::AccountId = account("voter", 0, 0);
         let voter_lookup = T::Lookup::unlookup(voter.clone());
-        let mut vote_locks: BoundedVec<(
-            MarketIdOf<T>,
-            BalanceOf<T>
-        ), T::MaxGlobalDisputeVotes> = Default::default();
+        let mut vote_locks: LockInfoOf<T> = Default::default();
         for i in 0..l {
             let market_id: MarketIdOf<T> = i.saturated_into();
             let locked_balance: BalanceOf<T> = 1u128.saturated_into();
-            vote_locks.try_push((market_id, locked_balance)).unwrap();
+            vote_locks.try_push(VoteLock::new(market_id, locked_balance)).unwrap();
             <GlobalDisputesInfo<T>>::insert(market_id, gd_info.clone());
         }
         <Locks<T>>::insert(voter.clone(), vote_locks);
@@ -503,6 +594,45 @@ benchmarks! {
         assert_last_event::<T>(Event::OutcomesFullyCleaned::<T> { market_id }.into());
     }
 
+    claim_voter_rewards {
+        let k in 1..T::RemoveKeysLimit::get();
+
+        let market_id: MarketIdOf<T> = 0u128.saturated_into();
+        let market = market_mock::<T>();
+        T::MarketCommons::push_market(market).unwrap();
+
+        let winner_outcome = OutcomeReport::Scalar(0);
+        let vote_amount: BalanceOf<T> = 1_000_000_000u128.saturated_into();
+        // every voter is rewarded in the worst case
+        for i in 1..=k {
+            let voter: T::AccountId = account("voter", i, 0);
+            <VoterVotes<T>>::insert(market_id, (voter, winner_outcome.clone()), vote_amount);
+        }
+
+        let reward: BalanceOf<T> = vote_amount.saturating_mul(k.into());
+        let voter_reward_pool = VoterRewardPool {
+            reward,
+            votes: vote_amount.saturating_mul(k.into()),
+            paid: Zero::zero(),
+        };
+        <VoterRewardPools<T>>::insert(market_id, voter_reward_pool);
+        deposit::<T>(&GlobalDisputes::<T>::reward_account(&market_id));
+
+        let possession = Possession::Shared { owners: Default::default() };
+        let outcome_info = OutcomeInfo {
+            outcome_sum: 42u128.saturated_into(),
+            possession,
+        };
+        let gd_info = GlobalDisputeInfo {winner_outcome, status: GdStatus::Finished, outcome_info};
+        <GlobalDisputesInfo<T>>::insert(market_id, gd_info);
+
+        let caller: T::AccountId = whitelisted_caller();
+    }: _(RawOrigin::Signed(caller), market_id)
+    verify {
+        assert!(<VoterVotes<T>>::iter_prefix(market_id).next().is_none());
+        assert_last_event::<T>(Event::VotesFullyCleaned::<T> { market_id }.into());
+    }
+
     impl_benchmark_test_suite!(
         GlobalDisputes,
         crate::mock::ExtBuilder::default().build(),
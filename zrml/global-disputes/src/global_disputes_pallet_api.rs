@@ -18,7 +18,7 @@
 extern crate alloc;
 
 use crate::types::InitialItem;
-use sp_runtime::DispatchError;
+use sp_runtime::{DispatchError, DispatchResult};
 use zeitgeist_primitives::types::OutcomeReport;
 
 /// The trait to initiate and resolve the global disputes.
@@ -29,6 +29,20 @@ pub trait GlobalDisputesPalletApi<MarketId, AccountId, Balance, BlockNumber> {
     /// Return the `GdVotingPeriod` parameter.
     fn get_vote_period() -> BlockNumber;
 
+    /// Return the account which holds the fees and rewards of a global dispute.
+    ///
+    /// # Arguments
+    /// - `market_id` - The id of the market.
+    fn get_reward_account(market_id: &MarketId) -> AccountId;
+
+    /// Add funds to the rewards of the voters of the winning outcome of a finished global
+    /// dispute. The funds need to be deposited into the reward account of the global dispute.
+    ///
+    /// # Arguments
+    /// - `market_id` - The id of the market.
+    /// - `amount` - The amount which was deposited into the reward account.
+    fn add_voter_rewards(market_id: &MarketId, amount: Balance) -> DispatchResult;
+
     /// Start a global dispute.
     ///
     /// # Arguments
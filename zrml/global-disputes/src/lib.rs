@@ -45,8 +45,8 @@ mod pallet {
         pallet_prelude::{OptionQuery, StorageDoubleMap, StorageMap, ValueQuery},
         sp_runtime::traits::StaticLookup,
         traits::{
-            Currency, ExistenceRequirement, Get, IsType, LockIdentifier, LockableCurrency,
-            StorageVersion, WithdrawReasons,
+            Contains, Currency, EnsureOrigin, ExistenceRequirement, Get, IsType, LockIdentifier,
+            LockableCurrency, StorageVersion, WithdrawReasons,
         },
         Blake2_128Concat, BoundedVec, PalletId, Twox64Concat,
     };
@@ -55,11 +55,14 @@ mod pallet {
         pallet_prelude::{BlockNumberFor, OriginFor},
     };
     use sp_runtime::{
-        traits::{AccountIdConversion, CheckedDiv, Saturating, Zero},
-        DispatchError, DispatchResult,
+        helpers_128bit::multiply_by_rational_with_rounding,
+        traits::{AccountIdConversion, CheckedDiv, IntegerSquareRoot, Saturating, Zero},
+        DispatchError, DispatchResult, Perbill, Rounding, SaturatedConversion,
     };
     use sp_std::{vec, vec::Vec};
-    use zeitgeist_primitives::{traits::DisputeResolutionApi, types::OutcomeReport};
+    use zeitgeist_primitives::{
+        constants::BASE, traits::DisputeResolutionApi, types::OutcomeReport,
+    };
     use zrml_market_commons::MarketCommonsPalletApi;
 
     pub(crate) type BalanceOf<T> = <<T as Config>::Currency as Currency<AccountIdOf<T>>>::Balance;
@@ -74,12 +77,14 @@ mod pallet {
     pub type GlobalDisputeInfoOf<T> =
         GlobalDisputeInfo<AccountIdOf<T>, BalanceOf<T>, OwnerInfoOf<T>, BlockNumberFor<T>>;
 
+    pub type VoterRewardPoolOf<T> = VoterRewardPool<BalanceOf<T>>;
+
     type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
-    pub type LockInfoOf<T> =
-        BoundedVec<(MarketIdOf<T>, BalanceOf<T>), <T as Config>::MaxGlobalDisputeVotes>;
+    pub type VoteLockOf<T> = VoteLock<MarketIdOf<T>, BalanceOf<T>, BlockNumberFor<T>>;
+    pub type LockInfoOf<T> = BoundedVec<VoteLockOf<T>, <T as Config>::MaxGlobalDisputeVotes>;
 
     /// The current storage version.
-    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
     const LOG_TARGET: &str = "runtime::zrml-global-disputes";
 
     #[pallet::config]
@@ -88,9 +93,19 @@ mod pallet {
         #[pallet::constant]
         type AddOutcomePeriod: Get<BlockNumberFor<Self>>;
 
+        /// The period which is multiplied by the lock periods of a vote's conviction to determine
+        /// for how long the votes stay locked after the global dispute is over.
+        #[pallet::constant]
+        type ConvictionLockPeriod: Get<BlockNumberFor<Self>>;
+
         /// The currency implementation used to lock tokens for voting.
         type Currency: LockableCurrency<Self::AccountId, Moment = BlockNumberFor<Self>>;
 
+        /// The voting weight mode of global disputes for which
+        /// `VotingWeightModeOrigin` didn't set a different one.
+        #[pallet::constant]
+        type DefaultVotingWeightMode: Get<VotingWeightMode>;
+
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
         type DisputeResolution: DisputeResolutionApi<
@@ -108,6 +123,10 @@ mod pallet {
         #[pallet::constant]
         type GlobalDisputesPalletId: Get<PalletId>;
 
+        /// Used to check if an account has a verified identity,
+        /// which is required to vote in global disputes with `VotingWeightMode::Quadratic`.
+        type IdentityVerification: Contains<Self::AccountId>;
+
         /// To reference the market id type.
         type MarketCommons: MarketCommonsPalletApi<
             AccountId = Self::AccountId,
@@ -139,10 +158,18 @@ mod pallet {
         #[pallet::constant]
         type GdVotingPeriod: Get<BlockNumberFor<Self>>;
 
+        /// The share of the fees of the losing voting outcomes
+        /// which is used to reward the voters of the winning outcome.
+        #[pallet::constant]
+        type VoterRewardPercentage: Get<Perbill>;
+
         /// The fee required to add a voting outcome.
         #[pallet::constant]
         type VotingOutcomeFee: Get<BalanceOf<Self>>;
 
+        /// The origin that is allowed to set the voting weight mode of a global dispute.
+        type VotingWeightModeOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
         type WeightInfo: WeightInfoZeitgeist;
     }
 
@@ -150,7 +177,7 @@ mod pallet {
     #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(PhantomData<T>);
 
-    /// All highest lock information (vote id, outcome index and locked balance)
+    /// All lock information (market id, locked balance and conviction locks)
     /// for a particular voter.
     ///
     /// TWOX-NOTE: SAFE as `AccountId`s are crypto hashes anyway.
@@ -176,6 +203,43 @@ mod pallet {
     pub type GlobalDisputesInfo<T: Config> =
         StorageMap<_, Twox64Concat, MarketIdOf<T>, GlobalDisputeInfoOf<T>, OptionQuery>;
 
+    /// Maps the market id to the voting weight mode of the global dispute,
+    /// if it differs from `DefaultVotingWeightMode`.
+    #[pallet::storage]
+    pub type VotingWeightModes<T: Config> =
+        StorageMap<_, Twox64Concat, MarketIdOf<T>, VotingWeightMode, OptionQuery>;
+
+    /// Maps the market id and the voter and outcome to the votes of the voter on the outcome.
+    #[pallet::storage]
+    pub type VoterVotes<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        MarketIdOf<T>,
+        Blake2_128Concat,
+        (AccountIdOf<T>, OutcomeReport),
+        BalanceOf<T>,
+        ValueQuery,
+    >;
+
+    /// Maps the market id and the outcome to the sum of the votes of all voters on the outcome.
+    /// In contrast to the `outcome_sum`, this excludes the initial vote amounts and fees.
+    #[pallet::storage]
+    pub type OutcomeVoterVotes<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        MarketIdOf<T>,
+        Blake2_128Concat,
+        OutcomeReport,
+        BalanceOf<T>,
+        ValueQuery,
+    >;
+
+    /// Maps the market id to the rewards for the voters of the winning outcome.
+    /// The rewards are held by the reward account of the global dispute.
+    #[pallet::storage]
+    pub type VoterRewardPools<T: Config> =
+        StorageMap<_, Twox64Concat, MarketIdOf<T>, VoterRewardPoolOf<T>, ValueQuery>;
+
     #[pallet::event]
     #[pallet::generate_deposit(fn deposit_event)]
     pub enum Event<T>
@@ -205,6 +269,16 @@ mod pallet {
             outcome: OutcomeReport,
             vote_amount: BalanceOf<T>,
         },
+        /// The voting weight mode of a global dispute was set.
+        VotingWeightModeSet { market_id: MarketIdOf<T>, mode: VotingWeightMode },
+        /// Funds were added to the rewards for the voters of the winning outcome.
+        VoterRewardsAdded { market_id: MarketIdOf<T>, amount: BalanceOf<T> },
+        /// A voter of the winning outcome has been rewarded.
+        VoterRewarded { market_id: MarketIdOf<T>, voter: AccountIdOf<T>, amount: BalanceOf<T> },
+        /// The votes storage item is partially cleaned.
+        VotesPartiallyCleaned { market_id: MarketIdOf<T> },
+        /// The votes storage item is fully cleaned.
+        VotesFullyCleaned { market_id: MarketIdOf<T> },
     }
 
     #[pallet::error]
@@ -243,6 +317,10 @@ mod pallet {
         GlobalDisputeNotDestroyed,
         /// The global dispute was already started.
         GlobalDisputeAlreadyExists,
+        /// A conviction was specified, but the global dispute doesn't use conviction voting.
+        ConvictionNotAllowed,
+        /// The voter requires a verified identity to vote in this global dispute.
+        IdentityNotVerified,
     }
 
     #[pallet::call]
@@ -299,7 +377,9 @@ mod pallet {
             )?;
 
             let possession = Possession::Paid { owner: owner.clone(), fee: voting_outcome_fee };
-            let outcome_info = OutcomeInfo { outcome_sum: voting_outcome_fee, possession };
+            let outcome_sum =
+                Self::base_votes(Self::voting_weight_mode(&market_id), voting_outcome_fee);
+            let outcome_info = OutcomeInfo { outcome_sum, possession };
             Self::update_winner(&market_id, &outcome, outcome_info.clone());
             <Outcomes<T>>::insert(market_id, outcome.clone(), outcome_info);
 
@@ -338,9 +418,10 @@ mod pallet {
 
             let mut owners_len = 0u32;
             let mut removed_keys_amount = 0u32;
-            for (_, outcome_info) in
+            for (outcome, outcome_info) in
                 <Outcomes<T>>::drain_prefix(market_id).take(T::RemoveKeysLimit::get() as usize)
             {
+                <OutcomeVoterVotes<T>>::remove(market_id, &outcome);
                 match outcome_info.possession {
                     Possession::Paid { owner, fee } => {
                         let res = T::Currency::transfer(
@@ -398,11 +479,14 @@ mod pallet {
             let winning_outcome: Option<OutcomeInfoOf<T>> =
                 <Outcomes<T>>::take(market_id, &gd_info.winner_outcome);
             let mut owners_len = 0u32;
+            let mut voter_reward_pool = <VoterRewardPools<T>>::get(market_id);
             // move the winning outcome info to GlobalDisputesInfo before it gets drained
             if let Some(outcome_info) = winning_outcome {
                 if let Possession::Shared { owners } = &outcome_info.possession {
                     owners_len = owners.len() as u32;
                 }
+                voter_reward_pool.votes =
+                    <OutcomeVoterVotes<T>>::take(market_id, &gd_info.winner_outcome);
                 // storage write is needed in case to save the owners
                 // of the winning outcome before they are drained
                 gd_info.outcome_info = outcome_info;
@@ -410,15 +494,31 @@ mod pallet {
             }
 
             let mut removed_keys_amount = 0u32;
-            for (_, outcome_info) in
+            for (outcome, outcome_info) in
                 <Outcomes<T>>::drain_prefix(market_id).take(T::RemoveKeysLimit::get() as usize)
             {
-                if let Possession::Shared { owners } = outcome_info.possession {
-                    owners_len = owners_len.saturating_add(owners.len() as u32);
+                match outcome_info.possession {
+                    Possession::Paid { owner: _, fee } => {
+                        // a share of the fees of the losing outcomes rewards the winning voters
+                        let voter_reward = T::VoterRewardPercentage::get().mul_floor(fee);
+                        voter_reward_pool.reward =
+                            voter_reward_pool.reward.saturating_add(voter_reward);
+                    }
+                    Possession::Shared { owners } => {
+                        owners_len = owners_len.saturating_add(owners.len() as u32);
+                    }
                 }
+                <OutcomeVoterVotes<T>>::remove(market_id, &outcome);
                 removed_keys_amount = removed_keys_amount.saturating_add(1u32);
             }
 
+            if voter_reward_pool.votes.is_zero() {
+                // nobody voted on the winning outcome, so the outcome owners get all rewards
+                <VoterRewardPools<T>>::remove(market_id);
+            } else {
+                <VoterRewardPools<T>>::insert(market_id, voter_reward_pool);
+            }
+
             if <Outcomes<T>>::iter_prefix(market_id).next().is_none() {
                 Self::deposit_event(Event::OutcomesFullyCleaned { market_id });
             } else {
@@ -459,7 +559,10 @@ mod pallet {
             ensure!(gd_info.status == GdStatus::Finished, Error::<T>::UnfinishedGlobalDispute);
 
             let reward_account = Self::reward_account(&market_id);
-            let reward_account_free_balance = T::Currency::free_balance(&reward_account);
+            // the unpaid rewards of the winning voters are not available for the outcome owners
+            let voter_rewards = <VoterRewardPools<T>>::get(market_id).unpaid();
+            let reward_account_free_balance =
+                T::Currency::free_balance(&reward_account).saturating_sub(voter_rewards);
             ensure!(!reward_account_free_balance.is_zero(), Error::<T>::NoFundsToReward);
 
             match gd_info.outcome_info.possession {
@@ -481,6 +584,10 @@ mod pallet {
         /// Vote on existing voting outcomes by locking native tokens.
         /// Fails if the global dispute has not started or has already finished.
         ///
+        /// The voting weight depends on the voting weight mode of the global dispute.
+        /// If the global dispute uses `VotingWeightMode::Conviction`,
+        /// this votes with `Conviction::None`.
+        ///
         /// # Arguments
         ///
         /// - `market_id`: The id of the market.
@@ -504,74 +611,8 @@ mod pallet {
             #[pallet::compact] amount: BalanceOf<T>,
         ) -> DispatchResultWithPostInfo {
             let voter = ensure_signed(origin)?;
-            let voter_free_balance = T::Currency::free_balance(&voter);
-            ensure!(amount <= voter_free_balance, Error::<T>::InsufficientAmount);
-            ensure!(amount >= T::MinOutcomeVoteAmount::get(), Error::<T>::AmountTooLow);
-
-            let gd_info =
-                <GlobalDisputesInfo<T>>::get(market_id).ok_or(Error::<T>::GlobalDisputeNotFound)?;
-            let now = <frame_system::Pallet<T>>::block_number();
-            if let GdStatus::Active { add_outcome_end, vote_end } = gd_info.status {
-                ensure!(add_outcome_end < now && now <= vote_end, Error::<T>::NotInGdVotingPeriod);
-            } else {
-                return Err(Error::<T>::InvalidGlobalDisputeStatus.into());
-            }
-
-            let mut outcome_info =
-                <Outcomes<T>>::get(market_id, &outcome).ok_or(Error::<T>::OutcomeDoesNotExist)?;
-            let outcome_owners_len = match outcome_info.possession {
-                Possession::Shared { ref owners } => owners.len() as u32,
-                Possession::Paid { .. } => 1u32,
-            };
-
-            // The `outcome_sum` never decreases (only increases) to allow
-            // caching the outcome with the highest `outcome_sum`.
-            // If the `outcome_sum` decreases, it would lead to more storage,
-            // because the winning outcome could have a smaller `outcome_sum`
-            // than the second highest `outcome_sum`.
-            let add_to_outcome_sum = |a| {
-                outcome_info.outcome_sum = outcome_info.outcome_sum.saturating_add(a);
-                Self::update_winner(&market_id, &outcome, outcome_info.clone());
-                <Outcomes<T>>::insert(market_id, &outcome, outcome_info);
-            };
-
-            let mut lock_info = <Locks<T>>::get(&voter);
-
-            let vote_lock_counter = lock_info.len() as u32;
-
-            let lock_amount = match lock_info.binary_search_by_key(&market_id, |i| i.0) {
-                Ok(i) => {
-                    let prev_amount_acc: BalanceOf<T> = lock_info[i].1;
-                    let new_amount_acc = amount.saturating_add(prev_amount_acc);
-                    ensure!(new_amount_acc <= voter_free_balance, Error::<T>::InsufficientAmount);
-                    add_to_outcome_sum(amount);
-                    lock_info[i].1 = new_amount_acc;
-                    new_amount_acc
-                }
-                Err(i) => {
-                    lock_info
-                        .try_insert(i, (market_id, amount))
-                        .map_err(|_| Error::<T>::MaxVotesReached)?;
-                    add_to_outcome_sum(amount);
-                    amount
-                }
-            };
-
-            T::Currency::extend_lock(
-                T::GlobalDisputeLockId::get(),
-                &voter,
-                lock_amount,
-                WithdrawReasons::TRANSFER,
-            );
-
-            <Locks<T>>::insert(&voter, lock_info);
-
-            Self::deposit_event(Event::VotedOnOutcome {
-                market_id,
-                voter,
-                outcome,
-                vote_amount: amount,
-            });
+            let (outcome_owners_len, vote_lock_counter) =
+                Self::do_vote_on_outcome(voter, market_id, outcome, amount, Conviction::None)?;
             Ok(Some(T::WeightInfo::vote_on_outcome(outcome_owners_len, vote_lock_counter)).into())
         }
 
@@ -605,34 +646,39 @@ mod pallet {
             ensure_signed(origin)?;
             let voter = T::Lookup::lookup(voter)?;
 
+            let now = <frame_system::Pallet<T>>::block_number();
             let mut lock_needed: BalanceOf<T> = Zero::zero();
             let mut lock_info = <Locks<T>>::get(&voter);
             let vote_lock_counter = lock_info.len() as u32;
             // Inside retain we follow these rules:
-            // 1. Remove all locks from resolved (/ finished / destroyed) global disputes.
-            // 2. Then find the maximum lock from all unresolved global disputes.
-            lock_info.retain(|&(market_id, locked_balance)| {
+            // 1. Remove all locks from destroyed global disputes and from finished global
+            //    disputes, unless some of the votes are still locked by their conviction.
+            // 2. Then find the maximum lock from all remaining global disputes.
+            lock_info.retain(|vote_lock| {
                 // weight component MaxOwners comes from querying the winner information
-                match <GlobalDisputesInfo<T>>::get(market_id) {
-                    Some(gd_info) => {
-                        if matches!(gd_info.status, GdStatus::Finished | GdStatus::Destroyed) {
-                            false
-                        } else {
-                            lock_needed = lock_needed.max(locked_balance);
-                            true
+                let (locked_balance, keep) = match <GlobalDisputesInfo<T>>::get(vote_lock.market_id)
+                {
+                    Some(gd_info) => match gd_info.status {
+                        GdStatus::Active { .. } => (vote_lock.amount, true),
+                        GdStatus::Finished => {
+                            let conviction_locked = vote_lock.conviction_locked(now);
+                            (conviction_locked, !conviction_locked.is_zero())
                         }
-                    }
+                        GdStatus::Destroyed => (Zero::zero(), false),
+                    },
                     None => {
                         log::warn!(
                             target: LOG_TARGET,
                             "Winner info is not found for market with id {:?}.",
-                            market_id
+                            vote_lock.market_id
                         );
                         debug_assert!(false);
                         // unlock these funds
-                        false
+                        (Zero::zero(), false)
                     }
-                }
+                };
+                lock_needed = lock_needed.max(locked_balance);
+                keep
             });
 
             <Locks<T>>::insert(&voter, lock_info);
@@ -661,6 +707,162 @@ mod pallet {
                 .into())
             }
         }
+
+        /// Vote on existing voting outcomes by locking native tokens with a conviction.
+        /// Fails if the global dispute has not started or has already finished,
+        /// or if the global dispute doesn't use `VotingWeightMode::Conviction`.
+        ///
+        /// The voting weight is the amount multiplied by the conviction multiplier.
+        /// The amount stays locked for the lock periods of the conviction
+        /// after the end of the global dispute.
+        ///
+        /// # Arguments
+        ///
+        /// - `market_id`: The id of the market.
+        /// - `outcome`: The existing outcome report to vote on.
+        /// - `amount`: The amount to vote with.
+        /// - `conviction`: The conviction to vote with.
+        ///
+        /// # Weight
+        ///
+        /// Complexity: `O(n + m)`, where `n` is the number of all current votes on global disputes,
+        /// and `m` is the number of owners for the specified outcome.
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::vote_on_outcome_with_conviction(
+            T::MaxOwners::get(),
+            T::MaxGlobalDisputeVotes::get(),
+        ))]
+        #[frame_support::transactional]
+        pub fn vote_on_outcome_with_conviction(
+            origin: OriginFor<T>,
+            #[pallet::compact] market_id: MarketIdOf<T>,
+            outcome: OutcomeReport,
+            #[pallet::compact] amount: BalanceOf<T>,
+            conviction: Conviction,
+        ) -> DispatchResultWithPostInfo {
+            let voter = ensure_signed(origin)?;
+            let (outcome_owners_len, vote_lock_counter) =
+                Self::do_vote_on_outcome(voter, market_id, outcome, amount, conviction)?;
+            Ok(Some(T::WeightInfo::vote_on_outcome_with_conviction(
+                outcome_owners_len,
+                vote_lock_counter,
+            ))
+            .into())
+        }
+
+        /// Set the voting weight mode of the global dispute of a market.
+        /// This is only allowed before the global dispute has started, so that the initial vote
+        /// amounts, the voting outcome fees and the votes are all weighted with the same mode.
+        ///
+        /// # Arguments
+        ///
+        /// - `market_id`: The id of the market.
+        /// - `mode`: The voting weight mode to use for the global dispute.
+        ///
+        /// # Weight
+        ///
+        /// Complexity: `O(1)`
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::set_voting_weight_mode())]
+        #[frame_support::transactional]
+        pub fn set_voting_weight_mode(
+            origin: OriginFor<T>,
+            #[pallet::compact] market_id: MarketIdOf<T>,
+            mode: VotingWeightMode,
+        ) -> DispatchResult {
+            T::VotingWeightModeOrigin::ensure_origin(origin)?;
+
+            T::MarketCommons::market(&market_id)?;
+            ensure!(
+                !<GlobalDisputesInfo<T>>::contains_key(market_id),
+                Error::<T>::GlobalDisputeAlreadyExists
+            );
+
+            <VotingWeightModes<T>>::insert(market_id, mode);
+
+            Self::deposit_event(Event::VotingWeightModeSet { market_id, mode });
+
+            Ok(())
+        }
+
+        /// Reward the voters of the winning outcome pro rata to their votes on it.
+        /// The rewards consist of a share of the fees of the losing outcomes
+        /// and the bonds which were slashed on the resolution of the market.
+        /// Fails if the outcomes are not already purged.
+        ///
+        /// Each call pays out at most `RemoveKeysLimit` votes, so this needs to be called
+        /// until `VotesFullyCleaned` is emitted. For a destroyed global dispute,
+        /// this only cleans up the votes, since the voting outcome fees are refunded.
+        ///
+        /// # Arguments
+        ///
+        /// - `market_id`: The id of the market.
+        ///
+        /// # Weight
+        ///
+        /// Complexity: `O(n)`,
+        /// where `n` is the number of all votes of distinct voters and outcomes for a global dispute.
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::claim_voter_rewards(T::RemoveKeysLimit::get()))]
+        #[frame_support::transactional]
+        pub fn claim_voter_rewards(
+            origin: OriginFor<T>,
+            #[pallet::compact] market_id: MarketIdOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+
+            let gd_info =
+                <GlobalDisputesInfo<T>>::get(market_id).ok_or(Error::<T>::GlobalDisputeNotFound)?;
+            let is_finished = match gd_info.status {
+                GdStatus::Finished => true,
+                GdStatus::Destroyed => false,
+                GdStatus::Active { .. } => return Err(Error::<T>::UnfinishedGlobalDispute.into()),
+            };
+            // the voter reward pool is complete, as soon as the outcomes are purged
+            ensure!(
+                <Outcomes<T>>::iter_prefix(market_id).next().is_none(),
+                <Error<T>>::OutcomesNotFullyCleaned
+            );
+
+            let reward_account = Self::reward_account(&market_id);
+            let mut voter_reward_pool = <VoterRewardPools<T>>::get(market_id);
+            let mut removed_keys_amount = 0u32;
+            for ((voter, outcome), votes) in
+                <VoterVotes<T>>::drain_prefix(market_id).take(T::RemoveKeysLimit::get() as usize)
+            {
+                removed_keys_amount = removed_keys_amount.saturating_add(1u32);
+                if !is_finished || outcome != gd_info.winner_outcome {
+                    continue;
+                }
+                let reward = Self::voter_reward(&voter_reward_pool, votes);
+                if reward.is_zero() {
+                    continue;
+                }
+                let res = T::Currency::transfer(
+                    &reward_account,
+                    &voter,
+                    reward,
+                    ExistenceRequirement::AllowDeath,
+                );
+                // not really much we can do if it fails
+                debug_assert!(res.is_ok(), "Global Disputes: Rewarding a voter failed.");
+                if res.is_ok() {
+                    voter_reward_pool.paid = voter_reward_pool.paid.saturating_add(reward);
+                    Self::deposit_event(Event::VoterRewarded { market_id, voter, amount: reward });
+                }
+            }
+
+            if <VoterVotes<T>>::iter_prefix(market_id).next().is_none() {
+                // the dust is left for the outcome owners
+                <VoterRewardPools<T>>::remove(market_id);
+                Self::deposit_event(Event::VotesFullyCleaned { market_id });
+            } else {
+                <VoterRewardPools<T>>::insert(market_id, voter_reward_pool);
+                Self::deposit_event(Event::VotesPartiallyCleaned { market_id });
+            }
+
+            Ok((Some(T::WeightInfo::claim_voter_rewards(removed_keys_amount))).into())
+        }
     }
 
     impl<T: Config> Pallet<T> {
@@ -668,6 +870,165 @@ mod pallet {
             T::GlobalDisputesPalletId::get().into_sub_account_truncating(market_id)
         }
 
+        // Returns the pro rata share of the voter reward pool for the specified votes
+        // on the winning outcome.
+        fn voter_reward(
+            voter_reward_pool: &VoterRewardPoolOf<T>,
+            votes: BalanceOf<T>,
+        ) -> BalanceOf<T> {
+            let reward = multiply_by_rational_with_rounding(
+                voter_reward_pool.reward.saturated_into::<u128>(),
+                votes.saturated_into::<u128>(),
+                voter_reward_pool.votes.saturated_into::<u128>(),
+                Rounding::Down,
+            )
+            .unwrap_or(0u128)
+            .saturated_into::<BalanceOf<T>>();
+            reward.min(voter_reward_pool.unpaid())
+        }
+
+        /// Returns the voting weight mode of the global dispute of the specified market.
+        pub fn voting_weight_mode(market_id: &MarketIdOf<T>) -> VotingWeightMode {
+            <VotingWeightModes<T>>::get(market_id).unwrap_or_else(T::DefaultVotingWeightMode::get)
+        }
+
+        // Returns the voting weight of an initial vote amount or a voting outcome fee. These are
+        // weighted like votes, so that they are comparable with the votes on the outcomes.
+        // With conviction voting, they count like votes with `Conviction::Locked1x`.
+        fn base_votes(mode: VotingWeightMode, amount: BalanceOf<T>) -> BalanceOf<T> {
+            match mode {
+                VotingWeightMode::TokenWeighted => amount,
+                VotingWeightMode::Conviction => Conviction::Locked1x.votes(amount),
+                VotingWeightMode::Quadratic => Self::quadratic_votes(amount),
+            }
+        }
+
+        // Returns the quadratic voting weight of all tokens a voter has locked in a global
+        // dispute. The amount is scaled by `BASE`, so that locking one unit counts as one vote.
+        fn quadratic_votes(amount: BalanceOf<T>) -> BalanceOf<T> {
+            amount.saturated_into::<u128>().saturating_mul(BASE).integer_sqrt().saturated_into()
+        }
+
+        // Returns the number of outcome owners and the number of vote locks of the voter
+        // before the vote, which are the weight components of the vote.
+        fn do_vote_on_outcome(
+            voter: AccountIdOf<T>,
+            market_id: MarketIdOf<T>,
+            outcome: OutcomeReport,
+            amount: BalanceOf<T>,
+            conviction: Conviction,
+        ) -> Result<(u32, u32), DispatchError> {
+            let voter_free_balance = T::Currency::free_balance(&voter);
+            ensure!(amount <= voter_free_balance, Error::<T>::InsufficientAmount);
+            ensure!(amount >= T::MinOutcomeVoteAmount::get(), Error::<T>::AmountTooLow);
+
+            let gd_info =
+                <GlobalDisputesInfo<T>>::get(market_id).ok_or(Error::<T>::GlobalDisputeNotFound)?;
+            let now = <frame_system::Pallet<T>>::block_number();
+            let vote_end = if let GdStatus::Active { add_outcome_end, vote_end } = gd_info.status {
+                ensure!(add_outcome_end < now && now <= vote_end, Error::<T>::NotInGdVotingPeriod);
+                vote_end
+            } else {
+                return Err(Error::<T>::InvalidGlobalDisputeStatus.into());
+            };
+
+            let mode = Self::voting_weight_mode(&market_id);
+            match mode {
+                VotingWeightMode::TokenWeighted => {
+                    ensure!(conviction == Conviction::None, Error::<T>::ConvictionNotAllowed);
+                }
+                VotingWeightMode::Conviction => {}
+                VotingWeightMode::Quadratic => {
+                    ensure!(conviction == Conviction::None, Error::<T>::ConvictionNotAllowed);
+                    ensure!(
+                        T::IdentityVerification::contains(&voter),
+                        Error::<T>::IdentityNotVerified
+                    );
+                }
+            }
+
+            let mut outcome_info =
+                <Outcomes<T>>::get(market_id, &outcome).ok_or(Error::<T>::OutcomeDoesNotExist)?;
+            let outcome_owners_len = match outcome_info.possession {
+                Possession::Shared { ref owners } => owners.len() as u32,
+                Possession::Paid { .. } => 1u32,
+            };
+
+            let mut lock_info = <Locks<T>>::get(&voter);
+
+            let vote_lock_counter = lock_info.len() as u32;
+
+            let (index, prev_lock_amount) =
+                match lock_info.binary_search_by_key(&market_id, |vote_lock| vote_lock.market_id) {
+                    Ok(i) => (i, lock_info[i].amount),
+                    Err(i) => {
+                        lock_info
+                            .try_insert(i, VoteLock::new(market_id, Zero::zero()))
+                            .map_err(|_| Error::<T>::MaxVotesReached)?;
+                        (i, Zero::zero())
+                    }
+                };
+            let lock_amount = amount.saturating_add(prev_lock_amount);
+            ensure!(lock_amount <= voter_free_balance, Error::<T>::InsufficientAmount);
+            lock_info[index].amount = lock_amount;
+
+            let lock_periods = conviction.lock_periods();
+            if mode == VotingWeightMode::Conviction && lock_periods > 0 {
+                let lock_duration =
+                    T::ConvictionLockPeriod::get().saturating_mul(lock_periods.into());
+                let expiry = vote_end.saturating_add(lock_duration);
+                // each conviction has its own expiry, so there is room for all of them
+                ensure!(
+                    lock_info[index].add_conviction_lock(expiry, amount),
+                    Error::<T>::MaxVotesReached
+                );
+            }
+
+            let votes = match mode {
+                VotingWeightMode::TokenWeighted => amount,
+                VotingWeightMode::Conviction => conviction.votes(amount),
+                // the voting weight of all tokens locked in this global dispute
+                // minus the voting weight the voter already used
+                VotingWeightMode::Quadratic => Self::quadratic_votes(lock_amount)
+                    .saturating_sub(Self::quadratic_votes(prev_lock_amount)),
+            };
+
+            // The `outcome_sum` never decreases (only increases) to allow
+            // caching the outcome with the highest `outcome_sum`.
+            // If the `outcome_sum` decreases, it would lead to more storage,
+            // because the winning outcome could have a smaller `outcome_sum`
+            // than the second highest `outcome_sum`.
+            outcome_info.outcome_sum = outcome_info.outcome_sum.saturating_add(votes);
+            Self::update_winner(&market_id, &outcome, outcome_info.clone());
+            <Outcomes<T>>::insert(market_id, &outcome, outcome_info);
+
+            // remember the votes to reward the voters in case the outcome wins
+            <VoterVotes<T>>::mutate(market_id, (&voter, &outcome), |voter_votes| {
+                *voter_votes = voter_votes.saturating_add(votes);
+            });
+            <OutcomeVoterVotes<T>>::mutate(market_id, &outcome, |outcome_voter_votes| {
+                *outcome_voter_votes = outcome_voter_votes.saturating_add(votes);
+            });
+
+            T::Currency::extend_lock(
+                T::GlobalDisputeLockId::get(),
+                &voter,
+                lock_amount,
+                WithdrawReasons::TRANSFER,
+            );
+
+            <Locks<T>>::insert(&voter, lock_info);
+
+            Self::deposit_event(Event::VotedOnOutcome {
+                market_id,
+                voter,
+                outcome,
+                vote_amount: amount,
+            });
+
+            Ok((outcome_owners_len, vote_lock_counter))
+        }
+
         fn update_winner(
             market_id: &MarketIdOf<T>,
             outcome: &OutcomeReport,
@@ -767,12 +1128,33 @@ mod pallet {
             T::GdVotingPeriod::get()
         }
 
+        fn get_reward_account(market_id: &MarketIdOf<T>) -> AccountIdOf<T> {
+            Self::reward_account(market_id)
+        }
+
+        fn add_voter_rewards(market_id: &MarketIdOf<T>, amount: BalanceOf<T>) -> DispatchResult {
+            let gd_info =
+                <GlobalDisputesInfo<T>>::get(market_id).ok_or(Error::<T>::GlobalDisputeNotFound)?;
+            ensure!(gd_info.status == GdStatus::Finished, Error::<T>::UnfinishedGlobalDispute);
+
+            <VoterRewardPools<T>>::mutate(market_id, |voter_reward_pool| {
+                voter_reward_pool.reward = voter_reward_pool.reward.saturating_add(amount);
+            });
+
+            Self::deposit_event(Event::VoterRewardsAdded { market_id: *market_id, amount });
+
+            Ok(())
+        }
+
         fn determine_voting_winner(market_id: &MarketIdOf<T>) -> Option<OutcomeReport> {
             match <GlobalDisputesInfo<T>>::get(market_id) {
                 Some(mut gd_info) => {
+                    // the initial vote amounts, the voting outcome fees and the votes are all
+                    // weighted by the voting weight mode, so the cached winner is correct
                     gd_info.status = GdStatus::Finished;
                     let winner_outcome = gd_info.winner_outcome.clone();
                     <GlobalDisputesInfo<T>>::insert(market_id, gd_info);
+                    <VotingWeightModes<T>>::remove(market_id);
                     Self::deposit_event(Event::GlobalDisputeWinnerDetermined {
                         market_id: *market_id,
                     });
@@ -806,12 +1188,14 @@ mod pallet {
                 Error::<T>::GlobalDisputeAlreadyExists
             );
 
+            let mode = Self::voting_weight_mode(market_id);
             for InitialItem { outcome, owner, amount } in initial_items {
                 ensure!(market.matches_outcome_report(outcome), Error::<T>::OutcomeMismatch);
+                let votes = Self::base_votes(mode, *amount);
 
                 match <Outcomes<T>>::get(market_id, outcome) {
                     Some(mut outcome_info) => {
-                        let outcome_sum = outcome_info.outcome_sum.saturating_add(*amount);
+                        let outcome_sum = outcome_info.outcome_sum.saturating_add(votes);
                         outcome_info.outcome_sum = outcome_sum;
                         let mut owners = outcome_info
                             .possession
@@ -826,7 +1210,7 @@ mod pallet {
                         // adding one item to BoundedVec can not fail
                         if let Ok(owners) = BoundedVec::try_from(vec![owner.clone()]) {
                             let possession = Possession::Shared { owners };
-                            let outcome_info = OutcomeInfo { outcome_sum: *amount, possession };
+                            let outcome_info = OutcomeInfo { outcome_sum: votes, possession };
                             Self::update_winner(market_id, outcome, outcome_info.clone());
                             <Outcomes<T>>::insert(market_id, outcome, outcome_info);
                         } else {
@@ -865,6 +1249,7 @@ mod pallet {
 
                     raw_gd_info.status = GdStatus::Destroyed;
                     *gd_info = Some(raw_gd_info.clone());
+                    <VotingWeightModes<T>>::remove(market_id);
                 }
 
                 Ok(())
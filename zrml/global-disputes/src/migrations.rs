@@ -15,3 +15,144 @@
 //
 // You should have received a copy of the GNU General Public License
 // along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+pub mod mbm {
+    use crate::{types::VoteLock, BalanceOf, Config, LockInfoOf, Locks, MarketIdOf, Pallet};
+    use core::marker::PhantomData;
+    use frame_support::{
+        migrations::{SteppedMigration, SteppedMigrationError},
+        pallet_prelude::ConstU32,
+        storage::unhashed,
+        traits::{Get, StorageVersion},
+        weights::WeightMeter,
+        BoundedVec,
+    };
+    use log::{info, warn};
+    use parity_scale_codec::DecodeAll;
+    use sp_std::vec::Vec;
+
+    const LOG_TARGET: &str = "runtime::zrml-global-disputes";
+
+    /// Target pallet storage version after migration.
+    const TARGET_STORAGE_VERSION: u16 = 2;
+
+    type LegacyLockInfoOf<T> =
+        BoundedVec<(MarketIdOf<T>, BalanceOf<T>), <T as Config>::MaxGlobalDisputeVotes>;
+
+    /// Multi-block migration that turns the legacy `(market_id, amount)` locks into `VoteLock`s
+    /// without conviction locks. Votes cast before the upgrade were not conviction locked beyond
+    /// the end of their global dispute. The cursor is the last account that was processed.
+    pub struct MigrateLocksToVoteLocks<T>(PhantomData<T>);
+
+    impl<T: Config> MigrateLocksToVoteLocks<T> {
+        const IDENT: &'static [u8] = b"global-disputes-vote-locks-v2";
+    }
+
+    impl<T: Config> SteppedMigration for MigrateLocksToVoteLocks<T> {
+        type Cursor = T::AccountId;
+        type Identifier = BoundedVec<u8, ConstU32<64>>;
+
+        fn id() -> Self::Identifier {
+            BoundedVec::try_from(Self::IDENT.to_vec()).expect("fits in Identifier bound; qed")
+        }
+
+        fn step(
+            cursor: Option<Self::Cursor>,
+            meter: &mut WeightMeter,
+        ) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+            if StorageVersion::get::<Pallet<T>>() >= StorageVersion::new(TARGET_STORAGE_VERSION) {
+                return Ok(None);
+            }
+
+            let weight_per_voter = T::DbWeight::get().reads_writes(1, 1);
+
+            let mut iter = if let Some(last_voter) = cursor.as_ref() {
+                Locks::<T>::iter_keys_from(Locks::<T>::hashed_key_for(last_voter))
+            } else {
+                Locks::<T>::iter_keys()
+            };
+            let mut last_voter = cursor.clone();
+
+            loop {
+                if meter.try_consume(weight_per_voter).is_err() {
+                    if last_voter == cursor {
+                        return Err(SteppedMigrationError::InsufficientWeight {
+                            required: weight_per_voter,
+                        });
+                    }
+                    return Ok(last_voter);
+                }
+
+                let Some(voter) = iter.next() else {
+                    break;
+                };
+
+                let key = Locks::<T>::hashed_key_for(&voter);
+                let legacy = unhashed::get_raw(&key)
+                    .and_then(|raw| LegacyLockInfoOf::<T>::decode_all(&mut &raw[..]).ok());
+                match legacy {
+                    Some(legacy) => {
+                        let vote_locks = legacy
+                            .into_iter()
+                            .map(|(market_id, amount)| VoteLock::new(market_id, amount))
+                            .collect::<Vec<_>>();
+                        // same bound as the legacy locks
+                        let lock_info: LockInfoOf<T> = BoundedVec::truncate_from(vote_locks);
+                        Locks::<T>::insert(&voter, lock_info);
+                    }
+                    None => {
+                        warn!(
+                            target: LOG_TARGET,
+                            "MigrateLocksToVoteLocks: could not decode the locks of {:?}", voter
+                        );
+                    }
+                }
+                last_voter = Some(voter);
+            }
+
+            StorageVersion::new(TARGET_STORAGE_VERSION).put::<Pallet<T>>();
+            info!(target: LOG_TARGET, "MigrateLocksToVoteLocks: migration complete");
+
+            Ok(None)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::mbm::MigrateLocksToVoteLocks;
+    use crate::{mock::*, types::VoteLock, Locks};
+    use frame_support::{
+        migrations::SteppedMigration,
+        storage::unhashed,
+        traits::StorageVersion,
+        weights::{Weight, WeightMeter},
+        BoundedVec,
+    };
+    use zeitgeist_primitives::constants::mock::BASE;
+
+    #[test]
+    fn migration_converts_legacy_locks() {
+        ExtBuilder::default().build().execute_with(|| {
+            let legacy_alice = vec![(0u128, 10 * BASE), (3u128, 20 * BASE)];
+            unhashed::put(&Locks::<Runtime>::hashed_key_for(ALICE), &legacy_alice);
+            let legacy_bob = vec![(1u128, 30 * BASE)];
+            unhashed::put(&Locks::<Runtime>::hashed_key_for(BOB), &legacy_bob);
+            StorageVersion::new(1).put::<GlobalDisputes>();
+
+            let mut meter = WeightMeter::with_limit(Weight::from_parts(u64::MAX, u64::MAX));
+            let cursor = MigrateLocksToVoteLocks::<Runtime>::step(None, &mut meter).unwrap();
+            assert!(cursor.is_none());
+
+            assert_eq!(StorageVersion::get::<GlobalDisputes>(), StorageVersion::new(2));
+            let expected_alice: BoundedVec<_, _> = BoundedVec::truncate_from(vec![
+                VoteLock::new(0u128, 10 * BASE),
+                VoteLock::new(3u128, 20 * BASE),
+            ]);
+            assert_eq!(Locks::<Runtime>::get(ALICE), expected_alice);
+            let expected_bob: BoundedVec<_, _> =
+                BoundedVec::truncate_from(vec![VoteLock::new(1u128, 30 * BASE)]);
+            assert_eq!(Locks::<Runtime>::get(BOB), expected_bob);
+        });
+    }
+}
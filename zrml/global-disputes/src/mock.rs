@@ -17,23 +17,23 @@
 
 #![cfg(test)]
 
-use crate::{self as zrml_global_disputes};
+use crate::{self as zrml_global_disputes, types::VotingWeightMode};
 use frame_support::{
     construct_runtime,
     pallet_prelude::{DispatchError, Weight},
     parameter_types,
-    traits::Everything,
+    traits::{Contains, Everything},
 };
-use frame_system::mocking::MockBlock;
+use frame_system::{mocking::MockBlock, EnsureRoot};
 use sp_runtime::{
     traits::{BlakeTwo256, IdentityLookup},
     BuildStorage,
 };
 use zeitgeist_primitives::{
     constants::mock::{
-        AddOutcomePeriod, BlockHashCount, GdVotingPeriod, GlobalDisputeLockId,
-        GlobalDisputesPalletId, MaxLocks, MaxReserves, MinOutcomeVoteAmount, MinimumPeriod,
-        RemoveKeysLimit, VotingOutcomeFee, BASE,
+        AddOutcomePeriod, BlockHashCount, ConvictionLockPeriod, GdVotingPeriod,
+        GlobalDisputeLockId, GlobalDisputesPalletId, MaxLocks, MaxReserves, MinOutcomeVoteAmount,
+        MinimumPeriod, RemoveKeysLimit, VoterRewardPercentage, VotingOutcomeFee, BASE,
     },
     traits::{DisputeResolutionApi, MarketOfDisputeResolutionApi},
     types::{AccountIdTest, Balance, BlockNumber, Hash, MarketId, Moment},
@@ -89,25 +89,40 @@ impl DisputeResolutionApi for NoopResolution {
     }
 }
 
+// Every account except `EVE` and `POOR_PAUL` has a verified identity.
+pub struct VerifiedIdentities;
+
+impl Contains<AccountIdTest> for VerifiedIdentities {
+    fn contains(account: &AccountIdTest) -> bool {
+        ![EVE, POOR_PAUL].contains(account)
+    }
+}
+
 parameter_types! {
+    pub const DefaultVotingWeightMode: VotingWeightMode = VotingWeightMode::TokenWeighted;
     pub const MaxGlobalDisputeVotes: u32 = 50;
     pub const MaxOwners: u32 = 10;
 }
 
 impl crate::Config for Runtime {
     type AddOutcomePeriod = AddOutcomePeriod;
+    type ConvictionLockPeriod = ConvictionLockPeriod;
     type Currency = Balances;
+    type DefaultVotingWeightMode = DefaultVotingWeightMode;
     type DisputeResolution = NoopResolution;
     type RuntimeEvent = RuntimeEvent;
     type GlobalDisputeLockId = GlobalDisputeLockId;
     type GlobalDisputesPalletId = GlobalDisputesPalletId;
+    type IdentityVerification = VerifiedIdentities;
     type MarketCommons = MarketCommons;
     type MaxGlobalDisputeVotes = MaxGlobalDisputeVotes;
     type MaxOwners = MaxOwners;
     type MinOutcomeVoteAmount = MinOutcomeVoteAmount;
     type RemoveKeysLimit = RemoveKeysLimit;
     type GdVotingPeriod = GdVotingPeriod;
+    type VoterRewardPercentage = VoterRewardPercentage;
     type VotingOutcomeFee = VotingOutcomeFee;
+    type VotingWeightModeOrigin = EnsureRoot<AccountIdTest>;
     type WeightInfo = crate::weights::WeightInfo<Runtime>;
 }
 
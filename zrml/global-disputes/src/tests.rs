@@ -20,9 +20,13 @@
 use crate::{
     global_disputes_pallet_api::GlobalDisputesPalletApi,
     mock::*,
-    types::{GdStatus, GlobalDisputeInfo, InitialItem, OutcomeInfo, Possession},
+    types::{
+        Conviction, GdStatus, GlobalDisputeInfo, InitialItem, OutcomeInfo, Possession,
+        VoterRewardPool, VotingWeightMode,
+    },
     utils::market_mock,
-    BalanceOf, Error, Event, GlobalDisputesInfo, InitialItemOf, Locks, MarketIdOf, Outcomes,
+    AccountIdOf, BalanceOf, Error, Event, GlobalDisputesInfo, InitialItemOf, Locks, MarketIdOf,
+    OutcomeVoterVotes, Outcomes, VoterRewardPools, VoterVotes, VotingWeightModes,
 };
 use frame_support::{
     assert_noop, assert_ok,
@@ -34,7 +38,8 @@ use sp_runtime::{traits::Zero, DispatchError, SaturatedConversion, TokenError};
 use test_case::test_case;
 use zeitgeist_primitives::{
     constants::mock::{
-        GlobalDisputeLockId, MinOutcomeVoteAmount, RemoveKeysLimit, VotingOutcomeFee, BASE,
+        ConvictionLockPeriod, GlobalDisputeLockId, MinOutcomeVoteAmount, RemoveKeysLimit,
+        VoterRewardPercentage, VotingOutcomeFee, BASE,
     },
     types::{BlockNumber, OutcomeReport},
 };
@@ -62,6 +67,13 @@ fn set_vote_period() {
     );
 }
 
+fn locks(voter: AccountIdOf<Runtime>) -> Vec<(MarketIdOf<Runtime>, BalanceOf<Runtime>)> {
+    <Locks<Runtime>>::get(voter)
+        .into_iter()
+        .map(|vote_lock| (vote_lock.market_id, vote_lock.amount))
+        .collect()
+}
+
 fn check_outcome_sum(
     market_id: &MarketIdOf<Runtime>,
     outcome: OutcomeReport,
@@ -1272,11 +1284,11 @@ fn unlock_clears_lock_info() {
 
         assert!(GlobalDisputes::determine_voting_winner(&market_id).is_some());
 
-        assert_eq!(<Locks<Runtime>>::get(ALICE), vec![(market_id, 50 * BASE)]);
+        assert_eq!(locks(ALICE), vec![(market_id, 50 * BASE)]);
 
         assert_ok!(GlobalDisputes::unlock_vote_balance(RuntimeOrigin::signed(ALICE), ALICE));
 
-        assert_eq!(<Locks<Runtime>>::get(ALICE), vec![]);
+        assert_eq!(locks(ALICE), vec![]);
     });
 }
 
@@ -1322,13 +1334,13 @@ fn locking_works_for_one_market() {
 
         set_vote_period();
 
-        assert_eq!(<Locks<Runtime>>::get(ALICE), vec![]);
+        assert_eq!(locks(ALICE), vec![]);
         assert!(Balances::locks(&ALICE).is_empty());
-        assert_eq!(<Locks<Runtime>>::get(BOB), vec![]);
+        assert_eq!(locks(BOB), vec![]);
         assert!(Balances::locks(&BOB).is_empty());
-        assert_eq!(<Locks<Runtime>>::get(CHARLIE), vec![]);
+        assert_eq!(locks(CHARLIE), vec![]);
         assert!(Balances::locks(&CHARLIE).is_empty());
-        assert_eq!(<Locks<Runtime>>::get(EVE), vec![]);
+        assert_eq!(locks(EVE), vec![]);
         assert!(Balances::locks(&EVE).is_empty());
 
         assert_ok!(GlobalDisputes::vote_on_outcome(
@@ -1356,47 +1368,47 @@ fn locking_works_for_one_market() {
             20 * BASE
         ));
 
-        assert_eq!(<Locks<Runtime>>::get(ALICE), vec![(market_id, 50 * BASE)]);
+        assert_eq!(locks(ALICE), vec![(market_id, 50 * BASE)]);
         assert_eq!(Balances::locks(&ALICE), vec![the_lock(50 * BASE)]);
-        assert_eq!(<Locks<Runtime>>::get(BOB), vec![(market_id, 40 * BASE)]);
+        assert_eq!(locks(BOB), vec![(market_id, 40 * BASE)]);
         assert_eq!(Balances::locks(&BOB), vec![the_lock(40 * BASE)]);
-        assert_eq!(<Locks<Runtime>>::get(CHARLIE), vec![(market_id, 30 * BASE)]);
+        assert_eq!(locks(CHARLIE), vec![(market_id, 30 * BASE)]);
         assert_eq!(Balances::locks(&CHARLIE), vec![the_lock(30 * BASE)]);
-        assert_eq!(<Locks<Runtime>>::get(EVE), vec![(market_id, 20 * BASE)]);
+        assert_eq!(locks(EVE), vec![(market_id, 20 * BASE)]);
         assert_eq!(Balances::locks(&EVE), vec![the_lock(20 * BASE)]);
 
         assert!(GlobalDisputes::determine_voting_winner(&market_id).is_some());
 
-        assert_eq!(<Locks<Runtime>>::get(ALICE), vec![(market_id, 50 * BASE)]);
+        assert_eq!(locks(ALICE), vec![(market_id, 50 * BASE)]);
         assert_eq!(Balances::locks(&ALICE), vec![the_lock(50 * BASE)]);
 
         assert_ok!(GlobalDisputes::unlock_vote_balance(RuntimeOrigin::signed(ALICE), ALICE));
-        assert_eq!(<Locks<Runtime>>::get(ALICE), vec![]);
+        assert_eq!(locks(ALICE), vec![]);
         assert!(Balances::locks(&ALICE).is_empty());
 
-        assert_eq!(<Locks<Runtime>>::get(BOB), vec![(market_id, 40 * BASE)]);
+        assert_eq!(locks(BOB), vec![(market_id, 40 * BASE)]);
         assert_eq!(Balances::locks(&BOB), vec![the_lock(40 * BASE)]);
-        assert_eq!(<Locks<Runtime>>::get(CHARLIE), vec![(market_id, 30 * BASE)]);
+        assert_eq!(locks(CHARLIE), vec![(market_id, 30 * BASE)]);
         assert_eq!(Balances::locks(&CHARLIE), vec![the_lock(30 * BASE)]);
-        assert_eq!(<Locks<Runtime>>::get(EVE), vec![(market_id, 20 * BASE)]);
+        assert_eq!(locks(EVE), vec![(market_id, 20 * BASE)]);
         assert_eq!(Balances::locks(&EVE), vec![the_lock(20 * BASE)]);
 
         assert_ok!(GlobalDisputes::unlock_vote_balance(RuntimeOrigin::signed(BOB), BOB));
-        assert_eq!(<Locks<Runtime>>::get(BOB), vec![]);
+        assert_eq!(locks(BOB), vec![]);
         assert!(Balances::locks(&BOB).is_empty());
-        assert_eq!(<Locks<Runtime>>::get(CHARLIE), vec![(market_id, 30 * BASE)]);
+        assert_eq!(locks(CHARLIE), vec![(market_id, 30 * BASE)]);
         assert_eq!(Balances::locks(&CHARLIE), vec![the_lock(30 * BASE)]);
-        assert_eq!(<Locks<Runtime>>::get(EVE), vec![(market_id, 20 * BASE)]);
+        assert_eq!(locks(EVE), vec![(market_id, 20 * BASE)]);
         assert_eq!(Balances::locks(&EVE), vec![the_lock(20 * BASE)]);
 
         assert_ok!(GlobalDisputes::unlock_vote_balance(RuntimeOrigin::signed(CHARLIE), CHARLIE));
-        assert_eq!(<Locks<Runtime>>::get(CHARLIE), vec![]);
+        assert_eq!(locks(CHARLIE), vec![]);
         assert!(Balances::locks(&CHARLIE).is_empty());
-        assert_eq!(<Locks<Runtime>>::get(EVE), vec![(market_id, 20 * BASE)]);
+        assert_eq!(locks(EVE), vec![(market_id, 20 * BASE)]);
         assert_eq!(Balances::locks(&EVE), vec![the_lock(20 * BASE)]);
 
         assert_ok!(GlobalDisputes::unlock_vote_balance(RuntimeOrigin::signed(EVE), EVE));
-        assert_eq!(<Locks<Runtime>>::get(EVE), vec![]);
+        assert_eq!(locks(EVE), vec![]);
         assert!(Balances::locks(&EVE).is_empty());
     });
 }
@@ -1419,9 +1431,9 @@ fn locking_works_for_two_markets_with_stronger_first_unlock() {
 
         set_vote_period();
 
-        assert_eq!(<Locks<Runtime>>::get(ALICE), vec![]);
+        assert_eq!(locks(ALICE), vec![]);
         assert!(Balances::locks(&ALICE).is_empty());
-        assert_eq!(<Locks<Runtime>>::get(BOB), vec![]);
+        assert_eq!(locks(BOB), vec![]);
         assert!(Balances::locks(&BOB).is_empty());
 
         assert_ok!(GlobalDisputes::vote_on_outcome(
@@ -1450,52 +1462,40 @@ fn locking_works_for_two_markets_with_stronger_first_unlock() {
             20 * BASE
         ));
 
-        assert_eq!(
-            <Locks<Runtime>>::get(ALICE),
-            vec![(market_id_1, 50 * BASE), (market_id_2, 30 * BASE)]
-        );
+        assert_eq!(locks(ALICE), vec![(market_id_1, 50 * BASE), (market_id_2, 30 * BASE)]);
         assert_eq!(Balances::locks(&ALICE), vec![the_lock(50 * BASE)]);
-        assert_eq!(
-            <Locks<Runtime>>::get(BOB),
-            vec![(market_id_1, 40 * BASE), (market_id_2, 20 * BASE)]
-        );
+        assert_eq!(locks(BOB), vec![(market_id_1, 40 * BASE), (market_id_2, 20 * BASE)]);
         assert_eq!(Balances::locks(&BOB), vec![the_lock(40 * BASE)]);
 
         // market_id_1 has stronger locks
         assert!(GlobalDisputes::determine_voting_winner(&market_id_1).is_some());
 
-        assert_eq!(
-            <Locks<Runtime>>::get(ALICE),
-            vec![(market_id_1, 50 * BASE), (market_id_2, 30 * BASE)]
-        );
+        assert_eq!(locks(ALICE), vec![(market_id_1, 50 * BASE), (market_id_2, 30 * BASE)]);
         assert_eq!(Balances::locks(&ALICE), vec![the_lock(50 * BASE)]);
         assert_ok!(GlobalDisputes::unlock_vote_balance(RuntimeOrigin::signed(ALICE), ALICE));
-        assert_eq!(<Locks<Runtime>>::get(ALICE), vec![(market_id_2, 30 * BASE)]);
+        assert_eq!(locks(ALICE), vec![(market_id_2, 30 * BASE)]);
         assert_eq!(Balances::locks(&ALICE), vec![the_lock(30 * BASE)]);
-        assert_eq!(
-            <Locks<Runtime>>::get(BOB),
-            vec![(market_id_1, 40 * BASE), (market_id_2, 20 * BASE)]
-        );
+        assert_eq!(locks(BOB), vec![(market_id_1, 40 * BASE), (market_id_2, 20 * BASE)]);
         assert_eq!(Balances::locks(&BOB), vec![the_lock(40 * BASE)]);
 
         assert_ok!(GlobalDisputes::unlock_vote_balance(RuntimeOrigin::signed(BOB), BOB));
-        assert_eq!(<Locks<Runtime>>::get(BOB), vec![(market_id_2, 20 * BASE)]);
+        assert_eq!(locks(BOB), vec![(market_id_2, 20 * BASE)]);
         assert_eq!(Balances::locks(&BOB), vec![the_lock(20 * BASE)]);
-        assert_eq!(<Locks<Runtime>>::get(ALICE), vec![(market_id_2, 30 * BASE)]);
+        assert_eq!(locks(ALICE), vec![(market_id_2, 30 * BASE)]);
         assert_eq!(Balances::locks(&ALICE), vec![the_lock(30 * BASE)]);
 
         assert!(GlobalDisputes::determine_voting_winner(&market_id_2).is_some());
 
-        assert_eq!(<Locks<Runtime>>::get(ALICE), vec![(market_id_2, 30 * BASE)]);
+        assert_eq!(locks(ALICE), vec![(market_id_2, 30 * BASE)]);
         assert_ok!(GlobalDisputes::unlock_vote_balance(RuntimeOrigin::signed(ALICE), ALICE));
 
-        assert_eq!(<Locks<Runtime>>::get(ALICE), vec![]);
+        assert_eq!(locks(ALICE), vec![]);
         assert!(Balances::locks(&ALICE).is_empty());
-        assert_eq!(<Locks<Runtime>>::get(BOB), vec![(market_id_2, 20 * BASE)]);
+        assert_eq!(locks(BOB), vec![(market_id_2, 20 * BASE)]);
         assert_eq!(Balances::locks(&BOB), vec![the_lock(20 * BASE)]);
 
         assert_ok!(GlobalDisputes::unlock_vote_balance(RuntimeOrigin::signed(BOB), BOB));
-        assert_eq!(<Locks<Runtime>>::get(BOB), vec![]);
+        assert_eq!(locks(BOB), vec![]);
         assert!(Balances::locks(&BOB).is_empty());
     });
 }
@@ -1518,9 +1518,9 @@ fn locking_works_for_two_markets_with_weaker_first_unlock() {
 
         set_vote_period();
 
-        assert_eq!(<Locks<Runtime>>::get(ALICE), vec![]);
+        assert_eq!(locks(ALICE), vec![]);
         assert!(Balances::locks(&ALICE).is_empty());
-        assert_eq!(<Locks<Runtime>>::get(BOB), vec![]);
+        assert_eq!(locks(BOB), vec![]);
         assert!(Balances::locks(&BOB).is_empty());
 
         assert_ok!(GlobalDisputes::vote_on_outcome(
@@ -1549,53 +1549,646 @@ fn locking_works_for_two_markets_with_weaker_first_unlock() {
             20 * BASE
         ));
 
-        assert_eq!(
-            <Locks<Runtime>>::get(ALICE),
-            vec![(market_id_1, 50 * BASE), (market_id_2, 30 * BASE)]
-        );
+        assert_eq!(locks(ALICE), vec![(market_id_1, 50 * BASE), (market_id_2, 30 * BASE)]);
         assert_eq!(Balances::locks(&ALICE), vec![the_lock(50 * BASE)]);
-        assert_eq!(
-            <Locks<Runtime>>::get(BOB),
-            vec![(market_id_1, 40 * BASE), (market_id_2, 20 * BASE)]
-        );
+        assert_eq!(locks(BOB), vec![(market_id_1, 40 * BASE), (market_id_2, 20 * BASE)]);
         assert_eq!(Balances::locks(&BOB), vec![the_lock(40 * BASE)]);
 
         // market_id_2 has weaker locks
         assert!(GlobalDisputes::determine_voting_winner(&market_id_2).is_some());
 
-        assert_eq!(
-            <Locks<Runtime>>::get(ALICE),
-            vec![(market_id_1, 50 * BASE), (market_id_2, 30 * BASE)]
-        );
+        assert_eq!(locks(ALICE), vec![(market_id_1, 50 * BASE), (market_id_2, 30 * BASE)]);
         assert_eq!(Balances::locks(&ALICE), vec![the_lock(50 * BASE)]);
         assert_ok!(GlobalDisputes::unlock_vote_balance(RuntimeOrigin::signed(ALICE), ALICE));
-        assert_eq!(<Locks<Runtime>>::get(ALICE), vec![(market_id_1, 50 * BASE)]);
+        assert_eq!(locks(ALICE), vec![(market_id_1, 50 * BASE)]);
         assert_eq!(Balances::locks(&ALICE), vec![the_lock(50 * BASE)]);
-        assert_eq!(
-            <Locks<Runtime>>::get(BOB),
-            vec![(market_id_1, 40 * BASE), (market_id_2, 20 * BASE)]
-        );
+        assert_eq!(locks(BOB), vec![(market_id_1, 40 * BASE), (market_id_2, 20 * BASE)]);
         assert_eq!(Balances::locks(&BOB), vec![the_lock(40 * BASE)]);
 
         assert_ok!(GlobalDisputes::unlock_vote_balance(RuntimeOrigin::signed(BOB), BOB));
-        assert_eq!(<Locks<Runtime>>::get(BOB), vec![(market_id_1, 40 * BASE)]);
+        assert_eq!(locks(BOB), vec![(market_id_1, 40 * BASE)]);
         assert_eq!(Balances::locks(&BOB), vec![the_lock(40 * BASE)]);
-        assert_eq!(<Locks<Runtime>>::get(ALICE), vec![(market_id_1, 50 * BASE)]);
+        assert_eq!(locks(ALICE), vec![(market_id_1, 50 * BASE)]);
         assert_eq!(Balances::locks(&ALICE), vec![the_lock(50 * BASE)]);
 
         assert!(GlobalDisputes::determine_voting_winner(&market_id_1).is_some());
 
-        assert_eq!(<Locks<Runtime>>::get(ALICE), vec![(market_id_1, 50 * BASE)]);
+        assert_eq!(locks(ALICE), vec![(market_id_1, 50 * BASE)]);
         assert_eq!(Balances::locks(&ALICE), vec![the_lock(50 * BASE)]);
         assert_ok!(GlobalDisputes::unlock_vote_balance(RuntimeOrigin::signed(ALICE), ALICE));
 
-        assert_eq!(<Locks<Runtime>>::get(ALICE), vec![]);
+        assert_eq!(locks(ALICE), vec![]);
         assert!(Balances::locks(&ALICE).is_empty());
-        assert_eq!(<Locks<Runtime>>::get(BOB), vec![(market_id_1, 40 * BASE)]);
+        assert_eq!(locks(BOB), vec![(market_id_1, 40 * BASE)]);
         assert_eq!(Balances::locks(&BOB), vec![the_lock(40 * BASE)]);
 
         assert_ok!(GlobalDisputes::unlock_vote_balance(RuntimeOrigin::signed(BOB), BOB));
-        assert_eq!(<Locks<Runtime>>::get(BOB), vec![]);
+        assert_eq!(locks(BOB), vec![]);
         assert!(Balances::locks(&BOB).is_empty());
     });
 }
+
+fn start_global_dispute_with_mode(market_id: MarketIdOf<Runtime>, mode: VotingWeightMode) {
+    let market = market_mock::<Runtime>();
+    Markets::<Runtime>::insert(market_id, market);
+
+    assert_ok!(GlobalDisputes::set_voting_weight_mode(RuntimeOrigin::root(), market_id, mode));
+    let initial_items = get_initial_items();
+    assert_ok!(GlobalDisputes::start_global_dispute(&market_id, initial_items.as_slice()));
+}
+
+#[test]
+fn set_voting_weight_mode_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+        assert_eq!(GlobalDisputes::voting_weight_mode(&market_id), VotingWeightMode::TokenWeighted);
+
+        start_global_dispute_with_mode(market_id, VotingWeightMode::Conviction);
+
+        System::assert_has_event(
+            Event::<Runtime>::VotingWeightModeSet { market_id, mode: VotingWeightMode::Conviction }
+                .into(),
+        );
+        assert_eq!(GlobalDisputes::voting_weight_mode(&market_id), VotingWeightMode::Conviction);
+    });
+}
+
+#[test]
+fn set_voting_weight_mode_fails_if_global_dispute_already_exists() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+        start_global_dispute_with_mode(market_id, VotingWeightMode::Conviction);
+
+        assert_noop!(
+            GlobalDisputes::set_voting_weight_mode(
+                RuntimeOrigin::root(),
+                market_id,
+                VotingWeightMode::Quadratic
+            ),
+            Error::<Runtime>::GlobalDisputeAlreadyExists
+        );
+    });
+}
+
+#[test]
+fn set_voting_weight_mode_fails_if_market_does_not_exist() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            GlobalDisputes::set_voting_weight_mode(
+                RuntimeOrigin::root(),
+                0u128,
+                VotingWeightMode::Quadratic
+            ),
+            MarketError::<Runtime>::MarketDoesNotExist
+        );
+    });
+}
+
+#[test]
+fn set_voting_weight_mode_fails_on_bad_origin() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+        let market = market_mock::<Runtime>();
+        Markets::<Runtime>::insert(market_id, market);
+
+        assert_noop!(
+            GlobalDisputes::set_voting_weight_mode(
+                RuntimeOrigin::signed(ALICE),
+                market_id,
+                VotingWeightMode::Quadratic
+            ),
+            DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn determine_voting_winner_removes_voting_weight_mode() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+        start_global_dispute_with_mode(market_id, VotingWeightMode::Quadratic);
+
+        assert!(GlobalDisputes::determine_voting_winner(&market_id).is_some());
+
+        assert!(!<VotingWeightModes<Runtime>>::contains_key(market_id));
+    });
+}
+
+#[test_case(Conviction::None, 5 * BASE; "none")]
+#[test_case(Conviction::Locked1x, 50 * BASE; "locked_1x")]
+#[test_case(Conviction::Locked3x, 150 * BASE; "locked_3x")]
+#[test_case(Conviction::Locked6x, 300 * BASE; "locked_6x")]
+fn vote_on_outcome_with_conviction_multiplies_votes(conviction: Conviction, votes: u128) {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+        start_global_dispute_with_mode(market_id, VotingWeightMode::Conviction);
+
+        set_vote_period();
+
+        assert_ok!(GlobalDisputes::vote_on_outcome_with_conviction(
+            RuntimeOrigin::signed(ALICE),
+            market_id,
+            OutcomeReport::Scalar(20),
+            50 * BASE,
+            conviction,
+        ));
+
+        check_outcome_sum(&market_id, OutcomeReport::Scalar(20), votes);
+        assert_eq!(Balances::locks(ALICE), vec![the_lock(50 * BASE)]);
+    });
+}
+
+#[test]
+fn vote_on_outcome_with_conviction_fails_if_not_conviction_mode() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+        start_global_dispute_with_mode(market_id, VotingWeightMode::TokenWeighted);
+
+        set_vote_period();
+
+        assert_noop!(
+            GlobalDisputes::vote_on_outcome_with_conviction(
+                RuntimeOrigin::signed(ALICE),
+                market_id,
+                OutcomeReport::Scalar(20),
+                50 * BASE,
+                Conviction::Locked1x,
+            ),
+            Error::<Runtime>::ConvictionNotAllowed
+        );
+    });
+}
+
+#[test]
+fn unlock_keeps_conviction_lock_until_expiry() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+        start_global_dispute_with_mode(market_id, VotingWeightMode::Conviction);
+
+        set_vote_period();
+        let vote_end = match <GlobalDisputesInfo<Runtime>>::get(market_id).unwrap().status {
+            GdStatus::Active { add_outcome_end: _, vote_end } => vote_end,
+            _ => panic!("global dispute should be active"),
+        };
+
+        assert_ok!(GlobalDisputes::vote_on_outcome_with_conviction(
+            RuntimeOrigin::signed(ALICE),
+            market_id,
+            OutcomeReport::Scalar(20),
+            50 * BASE,
+            Conviction::Locked2x,
+        ));
+        let expiry = vote_end + 2 * ConvictionLockPeriod::get();
+        assert_eq!(
+            <Locks<Runtime>>::get(ALICE)[0].conviction_locks.to_vec(),
+            vec![(expiry, 50 * BASE)]
+        );
+
+        <frame_system::Pallet<Runtime>>::set_block_number(vote_end + 1);
+        assert!(GlobalDisputes::determine_voting_winner(&market_id).is_some());
+
+        assert_ok!(GlobalDisputes::unlock_vote_balance(RuntimeOrigin::signed(ALICE), ALICE));
+        assert_eq!(locks(ALICE), vec![(market_id, 50 * BASE)]);
+        assert_eq!(Balances::locks(ALICE), vec![the_lock(50 * BASE)]);
+
+        <frame_system::Pallet<Runtime>>::set_block_number(expiry);
+        assert_ok!(GlobalDisputes::unlock_vote_balance(RuntimeOrigin::signed(ALICE), ALICE));
+        assert_eq!(locks(ALICE), vec![]);
+        assert!(Balances::locks(ALICE).is_empty());
+    });
+}
+
+#[test]
+fn unlock_only_keeps_the_tokens_locked_with_conviction() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+        start_global_dispute_with_mode(market_id, VotingWeightMode::Conviction);
+
+        set_vote_period();
+        let vote_end = match <GlobalDisputesInfo<Runtime>>::get(market_id).unwrap().status {
+            GdStatus::Active { add_outcome_end: _, vote_end } => vote_end,
+            _ => panic!("global dispute should be active"),
+        };
+
+        assert_ok!(GlobalDisputes::vote_on_outcome_with_conviction(
+            RuntimeOrigin::signed(ALICE),
+            market_id,
+            OutcomeReport::Scalar(20),
+            20 * BASE,
+            Conviction::Locked1x,
+        ));
+        assert_ok!(GlobalDisputes::vote_on_outcome_with_conviction(
+            RuntimeOrigin::signed(ALICE),
+            market_id,
+            OutcomeReport::Scalar(40),
+            30 * BASE,
+            Conviction::Locked3x,
+        ));
+        assert_ok!(GlobalDisputes::vote_on_outcome_with_conviction(
+            RuntimeOrigin::signed(ALICE),
+            market_id,
+            OutcomeReport::Scalar(60),
+            40 * BASE,
+            Conviction::None,
+        ));
+        assert_eq!(locks(ALICE), vec![(market_id, 90 * BASE)]);
+
+        <frame_system::Pallet<Runtime>>::set_block_number(vote_end + 1);
+        assert!(GlobalDisputes::determine_voting_winner(&market_id).is_some());
+
+        // the tokens voted without conviction are unlocked right away
+        assert_ok!(GlobalDisputes::unlock_vote_balance(RuntimeOrigin::signed(ALICE), ALICE));
+        assert_eq!(Balances::locks(ALICE), vec![the_lock(50 * BASE)]);
+
+        <frame_system::Pallet<Runtime>>::set_block_number(vote_end + ConvictionLockPeriod::get());
+        assert_ok!(GlobalDisputes::unlock_vote_balance(RuntimeOrigin::signed(ALICE), ALICE));
+        assert_eq!(Balances::locks(ALICE), vec![the_lock(30 * BASE)]);
+
+        <frame_system::Pallet<Runtime>>::set_block_number(
+            vote_end + 4 * ConvictionLockPeriod::get(),
+        );
+        assert_ok!(GlobalDisputes::unlock_vote_balance(RuntimeOrigin::signed(ALICE), ALICE));
+        assert_eq!(locks(ALICE), vec![]);
+        assert!(Balances::locks(ALICE).is_empty());
+    });
+}
+
+#[test]
+fn unlock_releases_conviction_lock_of_destroyed_global_dispute() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+        start_global_dispute_with_mode(market_id, VotingWeightMode::Conviction);
+
+        set_vote_period();
+
+        assert_ok!(GlobalDisputes::vote_on_outcome_with_conviction(
+            RuntimeOrigin::signed(ALICE),
+            market_id,
+            OutcomeReport::Scalar(20),
+            50 * BASE,
+            Conviction::Locked6x,
+        ));
+
+        assert_ok!(GlobalDisputes::destroy_global_dispute(&market_id));
+
+        assert_ok!(GlobalDisputes::unlock_vote_balance(RuntimeOrigin::signed(ALICE), ALICE));
+        assert_eq!(locks(ALICE), vec![]);
+        assert!(Balances::locks(ALICE).is_empty());
+    });
+}
+
+#[test]
+fn vote_on_outcome_uses_square_root_of_locked_amount_in_quadratic_mode() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+        start_global_dispute_with_mode(market_id, VotingWeightMode::Quadratic);
+
+        set_vote_period();
+
+        assert_ok!(GlobalDisputes::vote_on_outcome(
+            RuntimeOrigin::signed(ALICE),
+            market_id,
+            OutcomeReport::Scalar(20),
+            100 * BASE,
+        ));
+        // the initial vote amount of 100 is weighted as sqrt(100) = 10
+        let outcome_sum =
+            |outcome| <Outcomes<Runtime>>::get(market_id, outcome).unwrap().outcome_sum;
+        assert_eq!(outcome_sum(OutcomeReport::Scalar(20)), 20 * BASE);
+
+        // sqrt(400) - sqrt(100) = 10
+        assert_ok!(GlobalDisputes::vote_on_outcome(
+            RuntimeOrigin::signed(ALICE),
+            market_id,
+            OutcomeReport::Scalar(40),
+            300 * BASE,
+        ));
+        assert_eq!(outcome_sum(OutcomeReport::Scalar(40)), 20 * BASE);
+        assert_eq!(locks(ALICE), vec![(market_id, 400 * BASE)]);
+    });
+}
+
+#[test]
+fn vote_on_outcome_fails_without_verified_identity_in_quadratic_mode() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+        start_global_dispute_with_mode(market_id, VotingWeightMode::Quadratic);
+
+        set_vote_period();
+
+        assert_noop!(
+            GlobalDisputes::vote_on_outcome(
+                RuntimeOrigin::signed(EVE),
+                market_id,
+                OutcomeReport::Scalar(20),
+                100 * BASE,
+            ),
+            Error::<Runtime>::IdentityNotVerified
+        );
+    });
+}
+
+// Starts a global dispute in which BOB adds a losing outcome and BOB and CHARLIE vote with
+// 30 and 10 units on the winning outcome `Scalar(20)`, while EVE votes on `Scalar(40)`.
+fn setup_voter_rewards(market_id: MarketIdOf<Runtime>) {
+    let market = market_mock::<Runtime>();
+    Markets::<Runtime>::insert(market_id, market);
+
+    let initial_items = get_initial_items();
+    assert_ok!(GlobalDisputes::start_global_dispute(&market_id, initial_items.as_slice()));
+    assert_ok!(GlobalDisputes::add_vote_outcome(
+        RuntimeOrigin::signed(BOB),
+        market_id,
+        OutcomeReport::Scalar(80),
+    ));
+
+    set_vote_period();
+
+    assert_ok!(GlobalDisputes::vote_on_outcome(
+        RuntimeOrigin::signed(BOB),
+        market_id,
+        OutcomeReport::Scalar(20),
+        30 * BASE,
+    ));
+    assert_ok!(GlobalDisputes::vote_on_outcome(
+        RuntimeOrigin::signed(CHARLIE),
+        market_id,
+        OutcomeReport::Scalar(20),
+        10 * BASE,
+    ));
+    assert_ok!(GlobalDisputes::vote_on_outcome(
+        RuntimeOrigin::signed(EVE),
+        market_id,
+        OutcomeReport::Scalar(40),
+        20 * BASE,
+    ));
+
+    assert_eq!(
+        GlobalDisputes::determine_voting_winner(&market_id).unwrap(),
+        OutcomeReport::Scalar(20)
+    );
+}
+
+#[test]
+fn vote_on_outcome_records_voter_votes() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+        setup_voter_rewards(market_id);
+
+        assert_eq!(
+            <VoterVotes<Runtime>>::get(market_id, (BOB, OutcomeReport::Scalar(20))),
+            30 * BASE
+        );
+        assert_eq!(
+            <VoterVotes<Runtime>>::get(market_id, (CHARLIE, OutcomeReport::Scalar(20))),
+            10 * BASE
+        );
+        assert_eq!(
+            <VoterVotes<Runtime>>::get(market_id, (EVE, OutcomeReport::Scalar(40))),
+            20 * BASE
+        );
+        assert_eq!(
+            <OutcomeVoterVotes<Runtime>>::get(market_id, OutcomeReport::Scalar(20)),
+            40 * BASE
+        );
+        assert_eq!(
+            <OutcomeVoterVotes<Runtime>>::get(market_id, OutcomeReport::Scalar(40)),
+            20 * BASE
+        );
+    });
+}
+
+#[test]
+fn purge_outcomes_funds_voter_rewards_with_losing_outcome_fees() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+        setup_voter_rewards(market_id);
+
+        assert_ok!(GlobalDisputes::purge_outcomes(RuntimeOrigin::signed(ALICE), market_id));
+
+        assert_eq!(
+            <VoterRewardPools<Runtime>>::get(market_id),
+            VoterRewardPool {
+                reward: VoterRewardPercentage::get() * VotingOutcomeFee::get(),
+                votes: 40 * BASE,
+                paid: 0,
+            }
+        );
+        assert_eq!(<OutcomeVoterVotes<Runtime>>::iter_prefix(market_id).count(), 0);
+    });
+}
+
+#[test]
+fn add_voter_rewards_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+        setup_voter_rewards(market_id);
+
+        assert_ok!(GlobalDisputes::add_voter_rewards(&market_id, 3 * BASE));
+
+        System::assert_last_event(
+            Event::<Runtime>::VoterRewardsAdded { market_id, amount: 3 * BASE }.into(),
+        );
+        assert_eq!(<VoterRewardPools<Runtime>>::get(market_id).reward, 3 * BASE);
+    });
+}
+
+#[test]
+fn add_voter_rewards_fails_if_global_dispute_is_unfinished() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+        let market = market_mock::<Runtime>();
+        Markets::<Runtime>::insert(market_id, market);
+
+        let initial_items = get_initial_items();
+        assert_ok!(GlobalDisputes::start_global_dispute(&market_id, initial_items.as_slice()));
+
+        assert_noop!(
+            GlobalDisputes::add_voter_rewards(&market_id, 3 * BASE),
+            Error::<Runtime>::UnfinishedGlobalDispute
+        );
+    });
+}
+
+#[test]
+fn claim_voter_rewards_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+        setup_voter_rewards(market_id);
+
+        let reward_account = GlobalDisputes::reward_account(&market_id);
+        let _ = Balances::deposit_creating(&reward_account, 3 * BASE);
+        assert_ok!(GlobalDisputes::add_voter_rewards(&market_id, 3 * BASE));
+        assert_ok!(GlobalDisputes::purge_outcomes(RuntimeOrigin::signed(ALICE), market_id));
+
+        let free_balance_bob_before = Balances::free_balance(BOB);
+        let free_balance_charlie_before = Balances::free_balance(CHARLIE);
+        let free_balance_eve_before = Balances::free_balance(EVE);
+
+        assert_ok!(GlobalDisputes::claim_voter_rewards(RuntimeOrigin::signed(EVE), market_id));
+
+        System::assert_last_event(Event::<Runtime>::VotesFullyCleaned { market_id }.into());
+
+        // 3 units of slashed bonds and half of the fee of the losing outcome
+        let reward = 3 * BASE + VoterRewardPercentage::get() * VotingOutcomeFee::get();
+        let reward_bob = reward * 3 / 4;
+        let reward_charlie = reward / 4;
+        System::assert_has_event(
+            Event::<Runtime>::VoterRewarded { market_id, voter: BOB, amount: reward_bob }.into(),
+        );
+        assert_eq!(Balances::free_balance(BOB), free_balance_bob_before + reward_bob);
+        assert_eq!(Balances::free_balance(CHARLIE), free_balance_charlie_before + reward_charlie);
+        assert_eq!(Balances::free_balance(EVE), free_balance_eve_before);
+        assert!(<VoterVotes<Runtime>>::iter_prefix(market_id).next().is_none());
+        assert!(!<VoterRewardPools<Runtime>>::contains_key(market_id));
+
+        let free_balance_alice_before = Balances::free_balance(ALICE);
+        assert_ok!(GlobalDisputes::reward_outcome_owner(RuntimeOrigin::signed(ALICE), market_id));
+        assert_eq!(
+            Balances::free_balance(ALICE),
+            free_balance_alice_before + VotingOutcomeFee::get() + 3 * BASE - reward
+        );
+        assert!(Balances::free_balance(reward_account).is_zero());
+    });
+}
+
+#[test]
+fn reward_outcome_owner_keeps_unpaid_voter_rewards() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+        setup_voter_rewards(market_id);
+
+        assert_ok!(GlobalDisputes::purge_outcomes(RuntimeOrigin::signed(ALICE), market_id));
+
+        let voter_rewards = VoterRewardPercentage::get() * VotingOutcomeFee::get();
+        let free_balance_alice_before = Balances::free_balance(ALICE);
+        assert_ok!(GlobalDisputes::reward_outcome_owner(RuntimeOrigin::signed(ALICE), market_id));
+        assert_eq!(
+            Balances::free_balance(ALICE),
+            free_balance_alice_before + VotingOutcomeFee::get() - voter_rewards
+        );
+
+        let reward_account = GlobalDisputes::reward_account(&market_id);
+        assert_eq!(Balances::free_balance(reward_account), voter_rewards);
+
+        let free_balance_bob_before = Balances::free_balance(BOB);
+        assert_ok!(GlobalDisputes::claim_voter_rewards(RuntimeOrigin::signed(BOB), market_id));
+        assert_eq!(Balances::free_balance(BOB), free_balance_bob_before + voter_rewards * 3 / 4);
+        assert_eq!(Balances::free_balance(reward_account), 0);
+    });
+}
+
+#[test]
+fn reward_outcome_owner_gets_voter_rewards_if_nobody_voted_on_winner() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+        let market = market_mock::<Runtime>();
+        Markets::<Runtime>::insert(market_id, market);
+
+        let initial_items = get_initial_items();
+        assert_ok!(GlobalDisputes::start_global_dispute(&market_id, initial_items.as_slice()));
+        assert_ok!(GlobalDisputes::add_vote_outcome(
+            RuntimeOrigin::signed(BOB),
+            market_id,
+            OutcomeReport::Scalar(80),
+        ));
+        assert_eq!(
+            GlobalDisputes::determine_voting_winner(&market_id).unwrap(),
+            OutcomeReport::Scalar(60)
+        );
+
+        assert_ok!(GlobalDisputes::purge_outcomes(RuntimeOrigin::signed(ALICE), market_id));
+        assert!(!<VoterRewardPools<Runtime>>::contains_key(market_id));
+
+        let free_balance_alice_before = Balances::free_balance(ALICE);
+        assert_ok!(GlobalDisputes::reward_outcome_owner(RuntimeOrigin::signed(ALICE), market_id));
+        assert_eq!(
+            Balances::free_balance(ALICE),
+            free_balance_alice_before + VotingOutcomeFee::get()
+        );
+    });
+}
+
+#[test]
+fn claim_voter_rewards_partially_cleans_votes() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+        setup_voter_rewards(market_id);
+        assert_ok!(GlobalDisputes::purge_outcomes(RuntimeOrigin::signed(ALICE), market_id));
+
+        let winner_outcome = OutcomeReport::Scalar(20);
+        let voters = RemoveKeysLimit::get() as u128;
+        for voter in 1000..1000 + voters {
+            <VoterVotes<Runtime>>::insert(market_id, (voter, winner_outcome.clone()), 0);
+        }
+
+        assert_ok!(GlobalDisputes::claim_voter_rewards(RuntimeOrigin::signed(ALICE), market_id));
+        System::assert_last_event(Event::<Runtime>::VotesPartiallyCleaned { market_id }.into());
+        assert!(<VoterRewardPools<Runtime>>::contains_key(market_id));
+
+        assert_ok!(GlobalDisputes::claim_voter_rewards(RuntimeOrigin::signed(ALICE), market_id));
+        System::assert_last_event(Event::<Runtime>::VotesFullyCleaned { market_id }.into());
+        assert!(<VoterVotes<Runtime>>::iter_prefix(market_id).next().is_none());
+    });
+}
+
+#[test]
+fn claim_voter_rewards_only_cleans_votes_of_destroyed_global_dispute() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+        let market = market_mock::<Runtime>();
+        Markets::<Runtime>::insert(market_id, market);
+
+        let initial_items = get_initial_items();
+        assert_ok!(GlobalDisputes::start_global_dispute(&market_id, initial_items.as_slice()));
+
+        set_vote_period();
+
+        assert_ok!(GlobalDisputes::vote_on_outcome(
+            RuntimeOrigin::signed(BOB),
+            market_id,
+            OutcomeReport::Scalar(20),
+            30 * BASE,
+        ));
+
+        assert_ok!(GlobalDisputes::destroy_global_dispute(&market_id));
+        assert_ok!(GlobalDisputes::refund_vote_fees(RuntimeOrigin::signed(ALICE), market_id));
+
+        let free_balance_bob_before = Balances::free_balance(BOB);
+        assert_ok!(GlobalDisputes::claim_voter_rewards(RuntimeOrigin::signed(BOB), market_id));
+        System::assert_last_event(Event::<Runtime>::VotesFullyCleaned { market_id }.into());
+        assert_eq!(Balances::free_balance(BOB), free_balance_bob_before);
+        assert!(<VoterVotes<Runtime>>::iter_prefix(market_id).next().is_none());
+        assert!(<OutcomeVoterVotes<Runtime>>::iter_prefix(market_id).next().is_none());
+    });
+}
+
+#[test]
+fn claim_voter_rewards_fails_if_global_dispute_is_unfinished() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+        let market = market_mock::<Runtime>();
+        Markets::<Runtime>::insert(market_id, market);
+
+        let initial_items = get_initial_items();
+        assert_ok!(GlobalDisputes::start_global_dispute(&market_id, initial_items.as_slice()));
+
+        assert_noop!(
+            GlobalDisputes::claim_voter_rewards(RuntimeOrigin::signed(ALICE), market_id),
+            Error::<Runtime>::UnfinishedGlobalDispute
+        );
+    });
+}
+
+#[test]
+fn claim_voter_rewards_fails_if_outcomes_are_not_purged() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+        setup_voter_rewards(market_id);
+
+        assert_noop!(
+            GlobalDisputes::claim_voter_rewards(RuntimeOrigin::signed(ALICE), market_id),
+            Error::<Runtime>::OutcomesNotFullyCleaned
+        );
+    });
+}
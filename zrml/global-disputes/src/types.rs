@@ -15,8 +15,11 @@
 // You should have received a copy of the GNU General Public License
 // along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
 
-use frame_support::pallet_prelude::{Decode, Encode, MaxEncodedLen, TypeInfo};
-use sp_runtime::traits::Saturating;
+use frame_support::{
+    pallet_prelude::{ConstU32, Decode, Encode, MaxEncodedLen, TypeInfo},
+    BoundedVec,
+};
+use sp_runtime::traits::{CheckedDiv, Saturating, Zero};
 use zeitgeist_primitives::types::OutcomeReport;
 
 /// The original voting outcome owner information.
@@ -102,3 +105,138 @@ pub struct InitialItem<AccountId, Balance> {
     /// The vote amount at the start of the global dispute.
     pub amount: Balance,
 }
+
+/// The way locked tokens are translated into voting weight.
+#[derive(TypeInfo, Debug, Decode, Encode, MaxEncodedLen, Clone, Copy, PartialEq, Eq)]
+pub enum VotingWeightMode {
+    /// Each locked token counts as one vote.
+    TokenWeighted,
+    /// The locked tokens are multiplied by the conviction of the voter.
+    /// A higher conviction locks the tokens for longer after the global dispute has ended.
+    Conviction,
+    /// The voting weight of a voter in a global dispute is the square root of all the tokens
+    /// the voter locked in it. Only accounts with a verified identity are allowed to vote.
+    Quadratic,
+}
+
+/// The conviction of a vote, which works like the conviction of `pallet_democracy`.
+/// The tokens stay locked for `2^(n - 1)` conviction lock periods after the end of the global
+/// dispute, where `n` is the number of the conviction (e.g. `Locked3x` has `n = 3`).
+#[derive(TypeInfo, Debug, Decode, Encode, MaxEncodedLen, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Conviction {
+    /// 0.1x votes, unlocked as soon as the global dispute is over.
+    #[default]
+    None,
+    /// 1x votes, locked for one period after the global dispute is over.
+    Locked1x,
+    /// 2x votes, locked for 2x periods after the global dispute is over.
+    Locked2x,
+    /// 3x votes, locked for 4x periods after the global dispute is over.
+    Locked3x,
+    /// 4x votes, locked for 8x periods after the global dispute is over.
+    Locked4x,
+    /// 5x votes, locked for 16x periods after the global dispute is over.
+    Locked5x,
+    /// 6x votes, locked for 32x periods after the global dispute is over.
+    Locked6x,
+}
+
+impl Conviction {
+    /// The number of lock periods the tokens stay locked after the global dispute is over.
+    pub fn lock_periods(self) -> u32 {
+        match self {
+            Conviction::None => 0,
+            Conviction::Locked1x => 1,
+            Conviction::Locked2x => 2,
+            Conviction::Locked3x => 4,
+            Conviction::Locked4x => 8,
+            Conviction::Locked5x => 16,
+            Conviction::Locked6x => 32,
+        }
+    }
+
+    /// The voting weight of `amount` locked tokens.
+    pub fn votes<Balance>(self, amount: Balance) -> Balance
+    where
+        Balance: From<u8> + Saturating + CheckedDiv + Zero,
+    {
+        match self {
+            Conviction::None => amount.checked_div(&10u8.into()).unwrap_or_else(Zero::zero),
+            Conviction::Locked1x => amount,
+            Conviction::Locked2x => amount.saturating_mul(2u8.into()),
+            Conviction::Locked3x => amount.saturating_mul(3u8.into()),
+            Conviction::Locked4x => amount.saturating_mul(4u8.into()),
+            Conviction::Locked5x => amount.saturating_mul(5u8.into()),
+            Conviction::Locked6x => amount.saturating_mul(6u8.into()),
+        }
+    }
+}
+
+/// The maximum number of distinct conviction lock expiries of a vote lock. Each conviction
+/// except `Conviction::None` has its own expiry in a global dispute.
+pub const MAX_CONVICTION_LOCKS: u32 = 6;
+
+/// The tokens a voter locked in a global dispute.
+#[derive(TypeInfo, Debug, Decode, Encode, MaxEncodedLen, Clone, PartialEq, Eq)]
+pub struct VoteLock<MarketId, Balance, BlockNumber> {
+    /// The market of the global dispute.
+    pub market_id: MarketId,
+    /// All tokens the voter locked in the global dispute. They stay locked at least until the
+    /// global dispute is over.
+    pub amount: Balance,
+    /// The tokens voted with a conviction, which stay locked after the global dispute is over,
+    /// and the block number until which they stay locked.
+    pub conviction_locks: BoundedVec<(BlockNumber, Balance), ConstU32<MAX_CONVICTION_LOCKS>>,
+}
+
+impl<MarketId, Balance, BlockNumber> VoteLock<MarketId, Balance, BlockNumber>
+where
+    Balance: Copy + Ord + Saturating + Zero,
+    BlockNumber: Copy + Ord,
+{
+    pub fn new(market_id: MarketId, amount: Balance) -> Self {
+        VoteLock { market_id, amount, conviction_locks: Default::default() }
+    }
+
+    /// Locks `amount` of the locked tokens until `expiry`.
+    /// Returns `false` if there are already `MAX_CONVICTION_LOCKS` other expiries.
+    pub fn add_conviction_lock(&mut self, expiry: BlockNumber, amount: Balance) -> bool {
+        if let Some((_, locked)) = self.conviction_locks.iter_mut().find(|(e, _)| *e == expiry) {
+            *locked = locked.saturating_add(amount);
+            return true;
+        }
+        self.conviction_locks.try_push((expiry, amount)).is_ok()
+    }
+
+    /// The amount which stays locked at `now` after the global dispute is over.
+    pub fn conviction_locked(&self, now: BlockNumber) -> Balance {
+        self.conviction_locks
+            .iter()
+            .filter(|(expiry, _)| now < *expiry)
+            .fold(Zero::zero(), |acc: Balance, (_, locked)| acc.saturating_add(*locked))
+            .min(self.amount)
+    }
+}
+
+/// The rewards for the voters of the winning outcome of a global dispute.
+#[derive(TypeInfo, Debug, Decode, Encode, MaxEncodedLen, Clone, Default, PartialEq, Eq)]
+pub struct VoterRewardPool<Balance> {
+    /// The total amount which is distributed among the voters of the winning outcome.
+    pub reward: Balance,
+    /// The sum of the votes of all voters on the winning outcome.
+    pub votes: Balance,
+    /// The amount which has already been paid out to voters.
+    pub paid: Balance,
+}
+
+impl<Balance: Copy + Saturating + Zero> VoterRewardPool<Balance> {
+    /// The amount of the reward account which is still reserved for the voters.
+    /// If nobody voted on the winning outcome, nothing is reserved.
+    pub fn unpaid(&self) -> Balance {
+        if self.votes.is_zero() {
+            Zero::zero()
+        } else {
+            self.reward.saturating_sub(self.paid)
+        }
+    }
+}
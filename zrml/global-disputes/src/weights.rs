@@ -59,6 +59,9 @@ pub trait WeightInfoZeitgeist {
     fn reward_outcome_owner_paid_possession() -> Weight;
     fn purge_outcomes(k: u32, o: u32) -> Weight;
     fn refund_vote_fees(k: u32, o: u32) -> Weight;
+    fn vote_on_outcome_with_conviction(o: u32, v: u32) -> Weight;
+    fn set_voting_weight_mode() -> Weight;
+    fn claim_voter_rewards(k: u32) -> Weight;
 }
 
 /// Weight functions for zrml_global_disputes (automatically generated)
@@ -69,28 +72,28 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
     /// Storage: `GlobalDisputes::Outcomes` (r:1 w:1)
     /// Proof: `GlobalDisputes::Outcomes` (`max_values`: None, `max_size`: Some(395), added: 2870, mode: `MaxEncodedLen`)
     /// Storage: `GlobalDisputes::Locks` (r:1 w:1)
-    /// Proof: `GlobalDisputes::Locks` (`max_values`: None, `max_size`: Some(1641), added: 4116, mode: `MaxEncodedLen`)
+    /// Proof: `GlobalDisputes::Locks` (`max_values`: None, `max_size`: Some(8891), added: 11366, mode: `MaxEncodedLen`)
     /// Storage: `Balances::Locks` (r:1 w:1)
     /// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
     /// Storage: `Balances::Freezes` (r:1 w:0)
     /// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(65), added: 2540, mode: `MaxEncodedLen`)
+    /// Storage: `GlobalDisputes::VoterVotes` (r:1 w:1)
+    /// Proof: `GlobalDisputes::VoterVotes` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+    /// Storage: `GlobalDisputes::OutcomeVoterVotes` (r:1 w:1)
+    /// Proof: `GlobalDisputes::OutcomeVoterVotes` (`max_values`: None, `max_size`: Some(82), added: 2557, mode: `MaxEncodedLen`)
     /// The range of component `o` is `[2, 10]`.
     /// The range of component `v` is `[0, 49]`.
     fn vote_on_outcome(o: u32, v: u32) -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `452 + o * (33 ±0) + v * (32 ±0)`
-        //  Estimated: `5106`
-        // Minimum execution time: 71_111 nanoseconds.
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_global_disputes` to replace it.
         Weight::from_parts(75_403_275, 5106)
-            // Standard Error: 33_892
             .saturating_add(Weight::from_parts(103_011, 0).saturating_mul(o.into()))
-            // Standard Error: 5_960
             .saturating_add(Weight::from_parts(82_641, 0).saturating_mul(v.into()))
-            .saturating_add(T::DbWeight::get().reads(5))
-            .saturating_add(T::DbWeight::get().writes(4))
+            .saturating_add(T::DbWeight::get().reads(7))
+            .saturating_add(T::DbWeight::get().writes(6))
     }
     /// Storage: `GlobalDisputes::Locks` (r:1 w:1)
-    /// Proof: `GlobalDisputes::Locks` (`max_values`: None, `max_size`: Some(1641), added: 4116, mode: `MaxEncodedLen`)
+    /// Proof: `GlobalDisputes::Locks` (`max_values`: None, `max_size`: Some(8891), added: 11366, mode: `MaxEncodedLen`)
     /// Storage: `GlobalDisputes::GlobalDisputesInfo` (r:50 w:0)
     /// Proof: `GlobalDisputes::GlobalDisputesInfo` (`max_values`: None, `max_size`: Some(396), added: 2871, mode: `MaxEncodedLen`)
     /// Storage: `Balances::Locks` (r:1 w:1)
@@ -102,14 +105,10 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
     /// The range of component `l` is `[0, 50]`.
     /// The range of component `o` is `[1, 10]`.
     fn unlock_vote_balance_set(l: u32, o: u32) -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `0 + l * (435 ±0) + o * (1600 ±0)`
-        //  Estimated: `5106 + l * (2871 ±0)`
-        // Minimum execution time: 41_101 nanoseconds.
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_global_disputes` to replace it.
         Weight::from_parts(28_439_775, 5106)
-            // Standard Error: 8_782
             .saturating_add(Weight::from_parts(4_858_634, 0).saturating_mul(l.into()))
-            // Standard Error: 46_095
             .saturating_add(Weight::from_parts(1_242_145, 0).saturating_mul(o.into()))
             .saturating_add(T::DbWeight::get().reads(4))
             .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(l.into())))
@@ -117,7 +116,7 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
             .saturating_add(Weight::from_parts(0, 2871).saturating_mul(l.into()))
     }
     /// Storage: `GlobalDisputes::Locks` (r:1 w:1)
-    /// Proof: `GlobalDisputes::Locks` (`max_values`: None, `max_size`: Some(1641), added: 4116, mode: `MaxEncodedLen`)
+    /// Proof: `GlobalDisputes::Locks` (`max_values`: None, `max_size`: Some(8891), added: 11366, mode: `MaxEncodedLen`)
     /// Storage: `GlobalDisputes::GlobalDisputesInfo` (r:50 w:0)
     /// Proof: `GlobalDisputes::GlobalDisputesInfo` (`max_values`: None, `max_size`: Some(396), added: 2871, mode: `MaxEncodedLen`)
     /// Storage: `Balances::Locks` (r:1 w:1)
@@ -129,14 +128,10 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
     /// The range of component `l` is `[0, 50]`.
     /// The range of component `o` is `[1, 10]`.
     fn unlock_vote_balance_remove(l: u32, o: u32) -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `0 + l * (419 ±0) + o * (1600 ±0)`
-        //  Estimated: `5106 + l * (2871 ±0)`
-        // Minimum execution time: 41_421 nanoseconds.
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_global_disputes` to replace it.
         Weight::from_parts(32_178_265, 5106)
-            // Standard Error: 9_586
             .saturating_add(Weight::from_parts(4_846_390, 0).saturating_mul(l.into()))
-            // Standard Error: 50_317
             .saturating_add(Weight::from_parts(1_216_883, 0).saturating_mul(o.into()))
             .saturating_add(T::DbWeight::get().reads(4))
             .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(l.into())))
@@ -203,40 +198,102 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
     /// Proof: `GlobalDisputes::GlobalDisputesInfo` (`max_values`: None, `max_size`: Some(396), added: 2871, mode: `MaxEncodedLen`)
     /// Storage: `GlobalDisputes::Outcomes` (r:250 w:249)
     /// Proof: `GlobalDisputes::Outcomes` (`max_values`: None, `max_size`: Some(395), added: 2870, mode: `MaxEncodedLen`)
+    /// Storage: `GlobalDisputes::VoterRewardPools` (r:1 w:1)
+    /// Proof: `GlobalDisputes::VoterRewardPools` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+    /// Storage: `GlobalDisputes::OutcomeVoterVotes` (r:1 w:249)
+    /// Proof: `GlobalDisputes::OutcomeVoterVotes` (`max_values`: None, `max_size`: Some(82), added: 2557, mode: `MaxEncodedLen`)
     /// The range of component `k` is `[2, 248]`.
     /// The range of component `o` is `[1, 10]`.
     fn purge_outcomes(k: u32, _o: u32) -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `385 + k * (90 ±0) + o * (32 ±0)`
-        //  Estimated: `6730 + k * (2870 ±0)`
-        // Minimum execution time: 60_762 nanoseconds.
-        Weight::from_parts(82_761_236, 6730)
-            // Standard Error: 15_360
-            .saturating_add(Weight::from_parts(8_414_694, 0).saturating_mul(k.into()))
-            .saturating_add(T::DbWeight::get().reads(3))
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_global_disputes` to replace it.
+        Weight::from_parts(91_342_108, 6730)
+            .saturating_add(Weight::from_parts(10_927_013, 0).saturating_mul(k.into()))
+            .saturating_add(T::DbWeight::get().reads(5))
             .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(k.into())))
-            .saturating_add(T::DbWeight::get().writes(2))
-            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(k.into())))
+            .saturating_add(T::DbWeight::get().writes(4))
+            .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(k.into())))
             .saturating_add(Weight::from_parts(0, 2870).saturating_mul(k.into()))
     }
     /// Storage: `GlobalDisputes::GlobalDisputesInfo` (r:1 w:0)
     /// Proof: `GlobalDisputes::GlobalDisputesInfo` (`max_values`: None, `max_size`: Some(396), added: 2871, mode: `MaxEncodedLen`)
     /// Storage: `GlobalDisputes::Outcomes` (r:250 w:249)
     /// Proof: `GlobalDisputes::Outcomes` (`max_values`: None, `max_size`: Some(395), added: 2870, mode: `MaxEncodedLen`)
+    /// Storage: `GlobalDisputes::OutcomeVoterVotes` (r:0 w:249)
+    /// Proof: `GlobalDisputes::OutcomeVoterVotes` (`max_values`: None, `max_size`: Some(82), added: 2557, mode: `MaxEncodedLen`)
     /// The range of component `k` is `[2, 248]`.
     /// The range of component `o` is `[1, 10]`.
     fn refund_vote_fees(k: u32, _o: u32) -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `385 + k * (90 ±0) + o * (32 ±0)`
-        //  Estimated: `6730 + k * (2870 ±0)`
-        // Minimum execution time: 56_422 nanoseconds.
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_global_disputes` to replace it.
         Weight::from_parts(38_799_860, 6730)
-            // Standard Error: 13_955
-            .saturating_add(Weight::from_parts(8_418_257, 0).saturating_mul(k.into()))
+            .saturating_add(Weight::from_parts(10_493_126, 0).saturating_mul(k.into()))
             .saturating_add(T::DbWeight::get().reads(3))
             .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(k.into())))
             .saturating_add(T::DbWeight::get().writes(1))
-            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(k.into())))
+            .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(k.into())))
             .saturating_add(Weight::from_parts(0, 2870).saturating_mul(k.into()))
     }
+    /// Storage: `GlobalDisputes::GlobalDisputesInfo` (r:1 w:1)
+    /// Proof: `GlobalDisputes::GlobalDisputesInfo` (`max_values`: None, `max_size`: Some(396), added: 2871, mode: `MaxEncodedLen`)
+    /// Storage: `GlobalDisputes::VotingWeightModes` (r:1 w:0)
+    /// Proof: `GlobalDisputes::VotingWeightModes` (`max_values`: None, `max_size`: Some(25), added: 2500, mode: `MaxEncodedLen`)
+    /// Storage: `GlobalDisputes::Outcomes` (r:1 w:1)
+    /// Proof: `GlobalDisputes::Outcomes` (`max_values`: None, `max_size`: Some(395), added: 2870, mode: `MaxEncodedLen`)
+    /// Storage: `GlobalDisputes::Locks` (r:1 w:1)
+    /// Proof: `GlobalDisputes::Locks` (`max_values`: None, `max_size`: Some(8891), added: 11366, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Locks` (r:1 w:1)
+    /// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Freezes` (r:1 w:0)
+    /// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(65), added: 2540, mode: `MaxEncodedLen`)
+    /// Storage: `GlobalDisputes::VoterVotes` (r:1 w:1)
+    /// Proof: `GlobalDisputes::VoterVotes` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+    /// Storage: `GlobalDisputes::OutcomeVoterVotes` (r:1 w:1)
+    /// Proof: `GlobalDisputes::OutcomeVoterVotes` (`max_values`: None, `max_size`: Some(82), added: 2557, mode: `MaxEncodedLen`)
+    /// The range of component `o` is `[2, 10]`.
+    /// The range of component `v` is `[0, 49]`.
+    fn vote_on_outcome_with_conviction(o: u32, v: u32) -> Weight {
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_global_disputes` to replace it.
+        Weight::from_parts(83_925_117, 5106)
+            .saturating_add(Weight::from_parts(103_011, 0).saturating_mul(o.into()))
+            .saturating_add(Weight::from_parts(82_641, 0).saturating_mul(v.into()))
+            .saturating_add(T::DbWeight::get().reads(8))
+            .saturating_add(T::DbWeight::get().writes(6))
+    }
+    /// Storage: `MarketCommons::Markets` (r:1 w:0)
+    /// Proof: `MarketCommons::Markets` (`max_values`: None, `max_size`: Some(708), added: 3183, mode: `MaxEncodedLen`)
+    /// Storage: `GlobalDisputes::GlobalDisputesInfo` (r:1 w:0)
+    /// Proof: `GlobalDisputes::GlobalDisputesInfo` (`max_values`: None, `max_size`: Some(396), added: 2871, mode: `MaxEncodedLen`)
+    /// Storage: `GlobalDisputes::VotingWeightModes` (r:0 w:1)
+    /// Proof: `GlobalDisputes::VotingWeightModes` (`max_values`: None, `max_size`: Some(25), added: 2500, mode: `MaxEncodedLen`)
+    fn set_voting_weight_mode() -> Weight {
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_global_disputes` to replace it.
+        Weight::from_parts(22_040_000, 4173)
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: `GlobalDisputes::GlobalDisputesInfo` (r:1 w:0)
+    /// Proof: `GlobalDisputes::GlobalDisputesInfo` (`max_values`: None, `max_size`: Some(396), added: 2871, mode: `MaxEncodedLen`)
+    /// Storage: `GlobalDisputes::Outcomes` (r:1 w:0)
+    /// Proof: `GlobalDisputes::Outcomes` (`max_values`: None, `max_size`: Some(395), added: 2870, mode: `MaxEncodedLen`)
+    /// Storage: `GlobalDisputes::VoterRewardPools` (r:1 w:1)
+    /// Proof: `GlobalDisputes::VoterRewardPools` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+    /// Storage: `GlobalDisputes::VoterVotes` (r:251 w:250)
+    /// Proof: `GlobalDisputes::VoterVotes` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:251 w:251)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+    /// The range of component `k` is `[1, 250]`.
+    fn claim_voter_rewards(k: u32) -> Weight {
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_global_disputes` to replace it.
+        Weight::from_parts(52_384_630, 6204)
+            .saturating_add(Weight::from_parts(47_108_562, 0).saturating_mul(k.into()))
+            .saturating_add(T::DbWeight::get().reads(5))
+            .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(k.into())))
+            .saturating_add(T::DbWeight::get().writes(2))
+            .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(k.into())))
+            .saturating_add(Weight::from_parts(0, 2607).saturating_mul(k.into()))
+    }
 }
@@ -0,0 +1,51 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+#![doc = include_str!("../README.md")]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use parity_scale_codec::Codec;
+use sp_runtime::DispatchError;
+use zeitgeist_primitives::{orderbook::OrderId, types::Asset};
+use zrml_hybrid_router::types::{Strategy, TradeSimulation, TxType};
+
+sp_api::decl_runtime_apis! {
+    pub trait HybridRouterApi<AccountId, Balance, MarketId> where
+        AccountId: Codec,
+        Balance: Codec,
+        MarketId: Codec,
+    {
+        /// Simulates a `buy` (`tx_type` is `Buy`) or `sell` (`tx_type` is `Sell`) of `who`
+        /// without committing it. Returns the order fills, the AMM trades, the amount left over
+        /// for a limit order and the average execution price.
+        #[allow(clippy::too_many_arguments)]
+        fn simulate_trade(
+            tx_type: TxType,
+            who: AccountId,
+            market_id: MarketId,
+            asset_count: u16,
+            asset: Asset<MarketId>,
+            amount_in: Balance,
+            price_limit: Balance,
+            orders: Vec<OrderId>,
+            strategy: Strategy,
+        ) -> Result<TradeSimulation<AccountId, Balance>, DispatchError>;
+    }
+}
@@ -34,11 +34,14 @@ use frame_support::{
 use frame_system::RawOrigin;
 use orml_traits::MultiCurrency;
 use sp_runtime::{Perbill, SaturatedConversion};
-use types::Strategy;
+use types::{ConditionalOrderKind, Strategy};
 use zeitgeist_primitives::{
     constants::{base_multiples::*, CENT},
     math::fixed::{BaseProvider, FixedDiv, ZeitgeistBase},
-    traits::{CompleteSetOperationsApi, DeployPoolApi, HybridRouterOrderbookApi},
+    traits::{
+        CombinatorialTokensFuel, CompleteSetOperationsApi, DeployCombinatorialPoolApi,
+        DeployPoolApi, HybridRouterOrderbookApi, OnMarketClose,
+    },
     types::{Asset, Market, MarketCreation, MarketPeriod, MarketStatus, MarketType, ScoringRule},
 };
 use zrml_market_commons::MarketCommonsPalletApi;
@@ -117,6 +120,38 @@ fn create_market_and_deploy_pool<T: Config>(
     market_id
 }
 
+/// Places a take-profit order for `_1` units of the first outcome of `market_id` which is triggered
+/// at any price.
+fn place_conditional_order_on_market<T: Config>(
+    owner: T::AccountId,
+    market_id: MarketIdOf<T>,
+    base_asset: AssetOf<T>,
+) {
+    let asset = Asset::CategoricalOutcome(market_id, 0u16);
+    let amount = _1.saturated_into();
+    assert_ok!(T::AssetManager::deposit(asset, &owner, amount));
+    assert_ok!(T::AssetManager::deposit(base_asset, &owner, T::ConditionalOrderKeeperFee::get()));
+    assert_ok_with_transaction!(Pallet::<T>::do_place_conditional_order(
+        owner,
+        market_id,
+        asset,
+        amount,
+        ConditionalOrderKind::TakeProfit,
+        0u8.saturated_into(),
+        _1_100.saturated_into(),
+    ));
+}
+
+/// Creates a market with a pool and places a take-profit order for `_1` units of the first outcome
+/// which is triggered at any price.
+fn create_market_with_conditional_order<T: Config>(owner: T::AccountId) -> MarketIdOf<T> {
+    let base_asset = Asset::Ztg;
+    let market_id =
+        create_market_and_deploy_pool::<T>(owner.clone(), base_asset, 2, _100.saturated_into());
+    place_conditional_order_on_market::<T>(owner, market_id, base_asset);
+    market_id
+}
+
 #[benchmarks]
 mod benchmarks {
     use super::*;
@@ -253,6 +288,196 @@ mod benchmarks {
         assert_eq!(seller_limit_order.taker_asset, base_asset);
     }
 
+    #[benchmark]
+    fn buy_combinatorial(n: Linear<2, 4>, m: Linear<32, 64>) {
+        let buyer: T::AccountId = whitelisted_caller();
+        let base_asset = Asset::Ztg;
+        let legs = (0..n)
+            .map(|_| {
+                let market_id = create_market_and_deploy_pool::<T>(
+                    buyer.clone(),
+                    base_asset,
+                    2,
+                    _100.saturated_into(),
+                );
+                Asset::CategoricalOutcome(market_id, 0u16)
+            })
+            .collect::<Vec<_>>();
+        let amount_in = _10.saturated_into();
+        assert_ok!(T::AssetManager::deposit(base_asset, &buyer, amount_in));
+        let fuel = FuelOf::<T>::from_total(m);
+
+        #[extrinsic_call]
+        buy_combinatorial(
+            RawOrigin::Signed(buyer),
+            legs,
+            None,
+            0,
+            amount_in,
+            0u8.saturated_into(),
+            fuel,
+        );
+    }
+
+    // The combinatorial pool is deployed on the markets of the legs. The market of the first leg
+    // has `a` outcomes and all other markets are binary, so the pool has at least `a` assets.
+    #[benchmark]
+    fn buy_combinatorial_with_pool(n: Linear<2, 4>, a: Linear<2, 16>, m: Linear<32, 64>) {
+        let buyer: T::AccountId = whitelisted_caller();
+        let base_asset = Asset::Ztg;
+        let market_ids = (0..n)
+            .map(|i| {
+                let asset_count = if i == 0 { a.saturated_into() } else { 2 };
+                create_market_and_deploy_pool::<T>(
+                    buyer.clone(),
+                    base_asset,
+                    asset_count,
+                    _100.saturated_into(),
+                )
+            })
+            .collect::<Vec<_>>();
+        let legs = market_ids
+            .iter()
+            .map(|&market_id| Asset::CategoricalOutcome(market_id, 0u16))
+            .collect::<Vec<_>>();
+        let pool_asset_count: u16 = (a * 2u32.pow(n - 1)).saturated_into();
+        let pool_amount = (100 * _100).saturated_into();
+        let fuel = FuelOf::<T>::from_total(m);
+        assert_ok!(T::AssetManager::deposit(base_asset, &buyer, pool_amount));
+        let pool_id = with_transaction(|| {
+            match T::ComboPoolDeployer::deploy_combinatorial_pool(
+                buyer.clone(),
+                market_ids,
+                pool_amount,
+                create_spot_prices::<T>(pool_asset_count),
+                CENT.saturated_into(),
+                fuel.clone(),
+            ) {
+                Ok(pool_id) => Commit(Ok(pool_id)),
+                Err(err) => Rollback(Err(err)),
+            }
+        })
+        .unwrap();
+        let amount_in = _10.saturated_into();
+        assert_ok!(T::AssetManager::deposit(base_asset, &buyer, amount_in));
+
+        #[extrinsic_call]
+        buy_combinatorial(
+            RawOrigin::Signed(buyer),
+            legs,
+            Some(pool_id),
+            pool_asset_count,
+            amount_in,
+            0u8.saturated_into(),
+            fuel,
+        );
+    }
+
+    #[benchmark]
+    fn place_conditional_order() {
+        let owner: T::AccountId = whitelisted_caller();
+        let base_asset = Asset::Ztg;
+        let market_id =
+            create_market_and_deploy_pool::<T>(owner.clone(), base_asset, 2, _100.saturated_into());
+        let asset = Asset::CategoricalOutcome(market_id, 0u16);
+        let amount = _1.saturated_into();
+        assert_ok!(T::AssetManager::deposit(asset, &owner, amount));
+        assert_ok!(T::AssetManager::deposit(
+            base_asset,
+            &owner,
+            T::ConditionalOrderKeeperFee::get()
+        ));
+
+        #[extrinsic_call]
+        place_conditional_order(
+            RawOrigin::Signed(owner),
+            market_id,
+            asset,
+            amount,
+            ConditionalOrderKind::StopLoss,
+            _1_10.saturated_into(),
+            _1_100.saturated_into(),
+        );
+
+        assert!(ConditionalOrders::<T>::contains_key(0));
+    }
+
+    #[benchmark]
+    fn cancel_conditional_order() {
+        let owner: T::AccountId = whitelisted_caller();
+        let market_id = create_market_with_conditional_order::<T>(owner.clone());
+
+        #[extrinsic_call]
+        cancel_conditional_order(RawOrigin::Signed(owner.clone()), 0);
+
+        assert!(!ConditionalOrders::<T>::contains_key(0));
+        assert_eq!(
+            T::AssetManager::free_balance(Asset::CategoricalOutcome(market_id, 0u16), &owner),
+            _1.saturated_into()
+        );
+    }
+
+    #[benchmark]
+    fn execute_conditional_order(n: Linear<2, 10>, o: Linear<0, { T::MaxOrders::get() }>) {
+        let owner: T::AccountId = whitelisted_caller();
+        let keeper: T::AccountId = account("keeper", 0, 0);
+        let base_asset = Asset::Ztg;
+        let asset_count = n.try_into().unwrap();
+        let market_id = create_market_and_deploy_pool::<T>(
+            owner.clone(),
+            base_asset,
+            asset_count,
+            _100.saturated_into(),
+        );
+        place_conditional_order_on_market::<T>(owner, market_id, base_asset);
+
+        // Each order buys a hundredth of the conditional order above the spot price, so all orders
+        // are used to fill the sell.
+        let asset = Asset::CategoricalOutcome(market_id, 0u16);
+        let maker_amount: BalanceOf<T> = (_9_10 / 100).saturated_into();
+        let taker_amount: BalanceOf<T> = _1_100.saturated_into();
+        for i in 0..o {
+            let order_creator: T::AccountId = account("order_creator", i, 0);
+            assert_ok!(T::AssetManager::deposit(
+                base_asset,
+                &order_creator,
+                maker_amount + _100.saturated_into()
+            ));
+            T::Orderbook::place_order(
+                order_creator,
+                market_id,
+                base_asset,
+                maker_amount,
+                asset,
+                taker_amount,
+            )
+            .unwrap();
+        }
+
+        #[extrinsic_call]
+        execute_conditional_order(RawOrigin::Signed(keeper), 0, asset_count);
+
+        assert!(!ConditionalOrders::<T>::contains_key(0));
+    }
+
+    #[benchmark]
+    fn on_market_close(o: Linear<0, { T::MaxConditionalOrdersPerMarket::get() }>) {
+        let owner: T::AccountId = whitelisted_caller();
+        let base_asset = Asset::Ztg;
+        let market_id =
+            create_market_and_deploy_pool::<T>(owner.clone(), base_asset, 2, _100.saturated_into());
+        for _ in 0..o {
+            place_conditional_order_on_market::<T>(owner.clone(), market_id, base_asset);
+        }
+
+        #[block]
+        {
+            <Pallet<T> as OnMarketClose>::on_market_close(&market_id);
+        }
+
+        assert_eq!(ConditionalOrders::<T>::iter().count(), 0);
+    }
+
     impl_benchmark_test_suite!(
         HybridRouter,
         crate::mock::ExtBuilder::default().build(),
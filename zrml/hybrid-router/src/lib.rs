@@ -23,7 +23,7 @@ extern crate alloc;
 mod benchmarking;
 mod mock;
 mod tests;
-mod types;
+pub mod types;
 mod utils;
 pub mod weights;
 
@@ -32,45 +32,60 @@ pub use pallet::*;
 #[frame_support::pallet]
 mod pallet {
     use crate::{
-        types::{OrderAmmTradesInfo, Strategy, Trade, TradeEventInfo, TxType},
+        types::{
+            CombinatorialRoute, ConditionalOrder, ConditionalOrderId, ConditionalOrderKind,
+            OrderAmmTradesInfo, Strategy, Trade, TradeEventInfo, TradeSimulation, TxType,
+        },
         weights::WeightInfoZeitgeist,
     };
     use alloc::{vec, vec::Vec};
     use core::marker::PhantomData;
     use frame_support::{
-        ensure, require_transactional,
-        traits::{IsType, StorageVersion},
-        PalletId,
+        ensure,
+        pallet_prelude::{OptionQuery, StorageMap, StorageValue, ValueQuery, Weight},
+        require_transactional,
+        storage::{with_transaction, TransactionOutcome},
+        traits::{Hooks, IsType, StorageVersion},
+        BoundedVec, PalletId, Twox64Concat,
     };
     use frame_system::{
         ensure_signed,
         pallet_prelude::{BlockNumberFor, OriginFor},
     };
-    use orml_traits::MultiCurrency;
+    use orml_traits::{BalanceStatus, MultiCurrency, NamedMultiReservableCurrency};
     use sp_runtime::{
         traits::{Get, Zero},
         DispatchError, DispatchResult, SaturatedConversion, Saturating,
     };
     #[cfg(feature = "runtime-benchmarks")]
-    use zeitgeist_primitives::traits::{CompleteSetOperationsApi, DeployPoolApi};
+    use zeitgeist_primitives::traits::{
+        CompleteSetOperationsApi, DeployCombinatorialPoolApi, DeployPoolApi,
+    };
     use zeitgeist_primitives::{
         hybrid_router_api_types::{
             AmmSoftFail, AmmTrade, ApiError, OrderbookSoftFail, OrderbookTrade,
         },
         math::{
-            checked_ops_res::CheckedSubRes,
+            checked_ops_res::{CheckedAddRes, CheckedSubRes},
             fixed::{BaseProvider, FixedDiv, FixedMul, ZeitgeistBase},
         },
         orderbook::{Order, OrderId},
-        traits::{HybridRouterAmmApi, HybridRouterOrderbookApi},
-        types::Asset,
+        traits::{
+            CombinatorialTokensApi, CombinatorialTokensFuel, HybridRouterAmmApi,
+            HybridRouterComboAmmApi, HybridRouterOrderbookApi, OnMarketClose,
+        },
+        types::{Asset, MarketStatus},
     };
     use zrml_market_commons::MarketCommonsPalletApi;
 
     #[pallet::config]
     pub trait Config: frame_system::Config {
         /// The API to handle different asset classes.
-        type AssetManager: MultiCurrency<Self::AccountId, CurrencyId = AssetOf<Self>>;
+        type AssetManager: NamedMultiReservableCurrency<
+            Self::AccountId,
+            CurrencyId = AssetOf<Self>,
+            ReserveIdentifier = [u8; 8],
+        >;
 
         #[cfg(feature = "runtime-benchmarks")]
         type AmmPoolDeployer: DeployPoolApi<
@@ -79,6 +94,15 @@ mod pallet {
             MarketId = MarketIdOf<Self>,
         >;
 
+        #[cfg(feature = "runtime-benchmarks")]
+        type ComboPoolDeployer: DeployCombinatorialPoolApi<
+            AccountId = AccountIdOf<Self>,
+            Balance = BalanceOf<Self>,
+            Fuel = <Self::CombinatorialTokens as CombinatorialTokensApi>::Fuel,
+            MarketId = MarketIdOf<Self>,
+            PoolId = MarketIdOf<Self>,
+        >;
+
         #[cfg(feature = "runtime-benchmarks")]
         type CompleteSetOperations: CompleteSetOperationsApi<
             AccountId = AccountIdOf<Self>,
@@ -93,6 +117,11 @@ mod pallet {
             Balance = BalanceOf<Self>,
         >;
 
+        /// The amount of the market's base asset that the owner of a conditional order pays to the
+        /// keeper who executes it.
+        #[pallet::constant]
+        type ConditionalOrderKeeperFee: Get<BalanceOf<Self>>;
+
         /// The API to handle the Automated Market Maker (AMM).
         type Amm: HybridRouterAmmApi<
             AccountId = AccountIdOf<Self>,
@@ -101,6 +130,34 @@ mod pallet {
             Balance = BalanceOf<Self>,
         >;
 
+        /// The API to split positions into combinatorial tokens.
+        type CombinatorialTokens: CombinatorialTokensApi<
+            AccountId = AccountIdOf<Self>,
+            Balance = BalanceOf<Self>,
+            MarketId = MarketIdOf<Self>,
+        >;
+
+        /// The API to handle the combinatorial pools of the Automated Market Maker (AMM).
+        type ComboAmm: HybridRouterComboAmmApi<
+            AccountId = AccountIdOf<Self>,
+            Asset = AssetOf<Self>,
+            Balance = BalanceOf<Self>,
+            PoolId = MarketIdOf<Self>,
+        >;
+
+        /// The maximum number of markets a combinatorial position may span.
+        #[pallet::constant]
+        type MaxLegs: Get<u32>;
+
+        /// The maximum number of conditional orders evaluated in a single block.
+        #[pallet::constant]
+        type MaxConditionalOrderChecks: Get<u32>;
+
+        /// The maximum number of conditional orders which may wait for their trigger in a single
+        /// market. Bounds the work done when the market is closed.
+        #[pallet::constant]
+        type MaxConditionalOrdersPerMarket: Get<u32>;
+
         /// The maximum number of orders that can be used to execute a trade.
         #[pallet::constant]
         type MaxOrders: Get<u32>;
@@ -136,11 +193,44 @@ mod pallet {
         <<T as Config>::MarketCommons as MarketCommonsPalletApi>::MarketId;
     pub(crate) type AmmTradeOf<T> = AmmTrade<BalanceOf<T>>;
     pub(crate) type OrderTradeOf<T> = OrderbookTrade<AccountIdOf<T>, BalanceOf<T>>;
+    pub(crate) type CombinatorialIdOf<T> =
+        <<T as Config>::CombinatorialTokens as CombinatorialTokensApi>::CombinatorialId;
+    pub(crate) type FuelOf<T> =
+        <<T as Config>::CombinatorialTokens as CombinatorialTokensApi>::Fuel;
+    pub(crate) type CombinatorialRouteOf<T> = CombinatorialRoute<MarketIdOf<T>>;
+    pub(crate) type TradeSimulationOf<T> = TradeSimulation<AccountIdOf<T>, BalanceOf<T>>;
+    pub(crate) type ConditionalOrderOf<T> =
+        ConditionalOrder<AccountIdOf<T>, BalanceOf<T>, MarketIdOf<T>>;
 
     #[pallet::pallet]
     #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(PhantomData<T>);
 
+    #[pallet::storage]
+    pub type NextConditionalOrderId<T> = StorageValue<_, ConditionalOrderId, ValueQuery>;
+
+    /// Conditional orders which are waiting for their trigger. The outcomes to sell and the keeper
+    /// fees are reserved on the account of the order owner.
+    #[pallet::storage]
+    pub type ConditionalOrders<T: Config> =
+        StorageMap<_, Twox64Concat, ConditionalOrderId, ConditionalOrderOf<T>, OptionQuery>;
+
+    /// Maps a market ID to the IDs of the conditional orders waiting for their trigger in the
+    /// market. The orders are cancelled when the market is closed.
+    #[pallet::storage]
+    pub type MarketIdToConditionalOrderIds<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        MarketIdOf<T>,
+        BoundedVec<ConditionalOrderId, T::MaxConditionalOrdersPerMarket>,
+        ValueQuery,
+    >;
+
+    /// The ID of the conditional order evaluated last in `on_idle`. The next pass continues after
+    /// this order. `None` if the next pass starts from the beginning.
+    #[pallet::storage]
+    pub type ConditionalOrdersCursor<T> = StorageValue<_, ConditionalOrderId, OptionQuery>;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(crate) fn deposit_event)]
     pub enum Event<T>
@@ -174,6 +264,40 @@ mod pallet {
             /// The swap fee amount paid in the base asset.
             swap_fee_amount: BalanceOf<T>,
         },
+        /// A conditional order was placed.
+        ConditionalOrderPlaced { order_id: ConditionalOrderId, order: ConditionalOrderOf<T> },
+        /// A conditional order was triggered and executed.
+        ConditionalOrderExecuted {
+            order_id: ConditionalOrderId,
+            /// The account which executed the order, or `None` if it was executed in `on_idle`.
+            keeper: Option<AccountIdOf<T>>,
+            /// The amount of the outcome sold.
+            amount_in: BalanceOf<T>,
+            /// The amount of the base asset received by the owner of the order.
+            amount_out: BalanceOf<T>,
+        },
+        /// A conditional order was cancelled, either by its owner or because its market is no
+        /// longer active.
+        ConditionalOrderCancelled { order_id: ConditionalOrderId },
+        /// A combinatorial position was bought.
+        CombinatorialBuyExecuted {
+            /// The account ID of the user performing the trade.
+            who: AccountIdOf<T>,
+            /// The outcomes which make up the combinatorial position.
+            legs: Vec<AssetOf<T>>,
+            /// The route used to execute the trade.
+            route: CombinatorialRouteOf<T>,
+            /// The combinatorial position received by the trader.
+            asset_out: AssetOf<T>,
+            /// The amount of the base asset provided by the trader.
+            amount_in: BalanceOf<T>,
+            /// The amount of `asset_out` received by the trader.
+            amount_out: BalanceOf<T>,
+            /// The amount of the base asset received by the trader for selling the complements of
+            /// `asset_out` back to the combinatorial pool. Always zero for
+            /// `CombinatorialRoute::CombinatorialPool`.
+            amount_refunded: BalanceOf<T>,
+        },
     }
 
     #[pallet::error]
@@ -192,10 +316,38 @@ mod pallet {
         AssetNotEqualToOrderbookTakerAsset,
         /// The strategy "immediate or cancel" was applied.
         CancelStrategyApplied,
-        /// The asset count does not match the markets asset count.
+        /// The strategy "fill or kill" was applied. `Strategy::FillOrKill` is an alias of
+        /// `Strategy::ImmediateOrCancel` which fails with this error instead.
+        FillOrKillStrategyApplied,
+        /// The strategy "post only" was applied, but the order would have taken liquidity from the
+        /// order book or the AMM.
+        PostOnlyOrderWouldTakeLiquidity,
+        /// The asset count does not match the asset count of the market or the combinatorial pool.
         AssetCountMismatch,
         /// The maximum number of orders was exceeded.
         MaxOrdersExceeded,
+        /// The legs of a combinatorial buy are invalid. There must be between two and `MaxLegs`
+        /// legs, each of them must be a categorical or scalar outcome of a distinct market, and all
+        /// markets must share the same base asset.
+        InvalidLegs,
+        /// The combinatorial position is not traded in the specified pool.
+        PositionNotInPool,
+        /// There is no route to buy the combinatorial position.
+        NoRouteAvailable,
+        /// The amount received is below the specified minimum.
+        AmountOutBelowMin,
+        /// The market is not active.
+        MarketNotActive,
+        /// The asset is not an outcome of the market.
+        AssetNotInMarket,
+        /// The conditional order doesn't exist.
+        ConditionalOrderNotFound,
+        /// The sender is not the owner of the conditional order.
+        NotConditionalOrderOwner,
+        /// The spot price hasn't crossed the trigger price of the conditional order.
+        ConditionalOrderNotTriggered,
+        /// The market already has the maximum number of conditional orders.
+        TooManyConditionalOrders,
     }
 
     #[pallet::call]
@@ -212,21 +364,27 @@ mod pallet {
         /// * `orders`: A list of orders from the book to use.
         /// * `strategy`: The strategy to handle the remaining order when the `max_price` is reached.
         ///
-        /// The elements of `orders` are the orders that the router may use to execute the order. If any of
-        /// these orders are already filled, they are ignored. It is not necessary for the router to use all
-        /// specified orders. The smaller the vector, the larger the risk that the AMM is used to fill large
-        /// chunks of the order.
+        /// If `orders` is empty, the router discovers up to `MaxOrders` orders from the order book's
+        /// price index, starting with the best price and stopping at `max_price`.
+        ///
+        /// Otherwise, the elements of `orders` are the orders that the router may use to execute the
+        /// order. If any of these orders are already filled, they are ignored. It is not necessary for
+        /// the router to use all specified orders. The smaller the vector, the larger the risk that the
+        /// AMM is used to fill large chunks of the order.
         ///
         /// The `orders` vector **must** be sorted in ascending order by the price of their associated
         /// orders. Failing this, the behavior of `buy` is undefined.
         ///
         /// If the maximum price is reached before the entire buy order is filled, the `strategy` parameter
-        /// decides if the order is rolled back (`Strategy::ImmediateOrCancel`) or if a limit order for the
-        /// remaining amount is placed (`Strategy::LimitOrder`).
+        /// decides if the order is rolled back (`Strategy::ImmediateOrCancel` or
+        /// `Strategy::FillOrKill`) or if a limit order for the remaining amount is placed
+        /// (`Strategy::LimitOrder`). With `Strategy::PostOnly`, no liquidity is taken and a limit
+        /// order for the entire amount is placed; the trade is rolled back if the limit order would
+        /// cross an order in the order book (whether or not it's listed in `orders`) or the AMM.
         ///
         /// Complexity: `O(n)`
         #[pallet::call_index(0)]
-        #[pallet::weight(T::WeightInfo::buy(*asset_count as u32, orders.len().saturated_into::<u32>()))]
+        #[pallet::weight(T::WeightInfo::buy(*asset_count as u32, Pallet::<T>::order_count(orders)))]
         #[frame_support::transactional]
         #[allow(clippy::too_many_arguments)]
         pub fn buy(
@@ -268,21 +426,27 @@ mod pallet {
         /// * `orders`: A list of orders from the book to use.
         /// * `strategy`: The strategy to handle the remaining order when the `min_price` is reached.
         ///
-        /// The elements of `orders` are the orders that the router may use to execute the order. If any of
-        /// these orders are already filled, they are ignored. It is not necessary for the router to use all
-        /// specified orders. The smaller the vector, the larger the risk that the AMM is used to fill large
-        /// chunks of the order.
+        /// If `orders` is empty, the router discovers up to `MaxOrders` orders from the order book's
+        /// price index, starting with the best price and stopping at `min_price`.
+        ///
+        /// Otherwise, the elements of `orders` are the orders that the router may use to execute the
+        /// order. If any of these orders are already filled, they are ignored. It is not necessary for
+        /// the router to use all specified orders. The smaller the vector, the larger the risk that the
+        /// AMM is used to fill large chunks of the order.
         ///
         /// The `orders` vector **must** be sorted in ascending order by the price of their associated
         /// orders. Failing this, the behavior of `sell` is undefined.
         ///
         /// If the maximum price is reached before the entire buy order is filled, the `strategy` parameter
-        /// decides if the order is rolled back (`Strategy::ImmediateOrCancel`) or if a limit order for the
-        /// remaining amount is placed (`Strategy::LimitOrder`).
+        /// decides if the order is rolled back (`Strategy::ImmediateOrCancel` or
+        /// `Strategy::FillOrKill`) or if a limit order for the remaining amount is placed
+        /// (`Strategy::LimitOrder`). With `Strategy::PostOnly`, no liquidity is taken and a limit
+        /// order for the entire amount is placed; the trade is rolled back if the limit order would
+        /// cross an order in the order book (whether or not it's listed in `orders`) or the AMM.
         ///
         /// Complexity: `O(n)`
         #[pallet::call_index(1)]
-        #[pallet::weight(T::WeightInfo::sell(*asset_count as u32, orders.len().saturated_into::<u32>()))]
+        #[pallet::weight(T::WeightInfo::sell(*asset_count as u32, Pallet::<T>::order_count(orders)))]
         #[frame_support::transactional]
         #[allow(clippy::too_many_arguments)]
         pub fn sell(
@@ -311,6 +475,168 @@ mod pallet {
 
             Ok(())
         }
+
+        /// Buys a combinatorial position using the cheapest available route.
+        ///
+        /// # Parameters
+        ///
+        /// * `legs`: The outcomes which make up the combinatorial position, one per market.
+        /// * `pool_id`: The ID of a combinatorial pool which trades the position, if any.
+        /// * `pool_asset_count`: The number of assets traded in the pool `pool_id`, or zero if
+        ///   `pool_id` is `None`.
+        /// * `amount_in`: The amount of the markets' base asset to sell.
+        /// * `min_amount_out`: The minimum amount of the combinatorial position to receive.
+        /// * `fuel`: The fuel used to calculate the IDs of the combinatorial tokens.
+        ///
+        /// The router compares buying the position from the combinatorial pool `pool_id` with
+        /// buying one of the `legs` from the pool of its market and splitting the outcome tokens
+        /// into the position using the markets of the other legs. The route with the lowest net cost
+        /// per unit of the position is executed. If `pool_id` is specified, the split routes sell
+        /// the complementary positions created along the way back to the pool and the proceeds
+        /// are deducted from their cost; otherwise, the complements are kept by the trader.
+        ///
+        /// With a combinatorial pool, every split route is simulated by splitting the outcome
+        /// tokens into the assets of the pool and selling the complements to the pool, which is
+        /// accounted for in the weight.
+        ///
+        /// Complexity: `O(n^2)` without a combinatorial pool and `O(n * a)` with a combinatorial
+        /// pool, where `n` is the number of legs and `a` is the number of assets of the pool.
+        #[pallet::call_index(2)]
+        #[pallet::weight(Pallet::<T>::buy_combinatorial_weight(
+            legs.len().saturated_into(),
+            pool_id.map(|_| *pool_asset_count),
+            fuel.total(),
+        ))]
+        #[frame_support::transactional]
+        pub fn buy_combinatorial(
+            origin: OriginFor<T>,
+            legs: Vec<AssetOf<T>>,
+            pool_id: Option<MarketIdOf<T>>,
+            #[pallet::compact] pool_asset_count: u16,
+            #[pallet::compact] amount_in: BalanceOf<T>,
+            #[pallet::compact] min_amount_out: BalanceOf<T>,
+            fuel: FuelOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Self::do_buy_combinatorial(
+                who,
+                legs,
+                pool_id,
+                pool_asset_count,
+                amount_in,
+                min_amount_out,
+                fuel,
+            )
+        }
+
+        /// Places a stop-loss or take-profit order which sells `amount` units of `asset` through
+        /// the hybrid router once the spot price of `asset` in the market's pool crosses
+        /// `trigger_price`.
+        ///
+        /// # Parameters
+        ///
+        /// * `market_id`: The ID of the market on which `asset` is traded.
+        /// * `asset`: The outcome to sell.
+        /// * `amount`: The amount of `asset` to sell.
+        /// * `kind`: `StopLoss` triggers once the spot price is at or below `trigger_price`,
+        ///   `TakeProfit` once it is at or above `trigger_price`.
+        /// * `trigger_price`: The spot price at which the order is triggered.
+        /// * `min_price`: The minimum price to sell at once the order is triggered.
+        ///
+        /// The `amount` of `asset` and the `ConditionalOrderKeeperFee` in the market's base asset
+        /// are reserved until the order is executed or cancelled. Triggered orders are executed in
+        /// `on_idle` or by any keeper calling `execute_conditional_order`.
+        /// The sell is executed with `Strategy::ImmediateOrCancel`; if it can't be filled at
+        /// `min_price` or better, the order remains in place. Orders are cancelled automatically
+        /// when the market is closed. A market may have at most `MaxConditionalOrdersPerMarket`
+        /// orders.
+        ///
+        /// Complexity: `O(1)`
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::place_conditional_order())]
+        #[frame_support::transactional]
+        pub fn place_conditional_order(
+            origin: OriginFor<T>,
+            market_id: MarketIdOf<T>,
+            asset: AssetOf<T>,
+            #[pallet::compact] amount: BalanceOf<T>,
+            kind: ConditionalOrderKind,
+            #[pallet::compact] trigger_price: BalanceOf<T>,
+            #[pallet::compact] min_price: BalanceOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Self::do_place_conditional_order(
+                who,
+                market_id,
+                asset,
+                amount,
+                kind,
+                trigger_price,
+                min_price,
+            )
+        }
+
+        /// Cancels a conditional order and returns the held outcomes and keeper fee to its owner.
+        ///
+        /// # Parameters
+        ///
+        /// * `order_id`: The ID of the conditional order.
+        ///
+        /// Complexity: `O(1)`
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::cancel_conditional_order())]
+        #[frame_support::transactional]
+        pub fn cancel_conditional_order(
+            origin: OriginFor<T>,
+            order_id: ConditionalOrderId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let order = ConditionalOrders::<T>::get(order_id)
+                .ok_or(Error::<T>::ConditionalOrderNotFound)?;
+            ensure!(order.owner == who, Error::<T>::NotConditionalOrderOwner);
+
+            Self::do_cancel_conditional_order(order_id, order, None)
+        }
+
+        /// Executes a triggered conditional order. Callable by anyone; the caller receives the
+        /// keeper fee of the order. If the market of the order is no longer active, the order is
+        /// cancelled instead and the caller still receives the keeper fee.
+        ///
+        /// # Parameters
+        ///
+        /// * `order_id`: The ID of the conditional order.
+        /// * `asset_count`: The number of assets traded on the market of the order.
+        ///
+        /// The sell discovers up to `MaxOrders` orders from the order book, which is accounted for
+        /// in the weight.
+        ///
+        /// Complexity: `O(n + o)`, where `n` is the number of assets traded on the market and `o`
+        /// is `MaxOrders`.
+        #[pallet::call_index(5)]
+        #[pallet::weight(Pallet::<T>::conditional_order_weight(*asset_count))]
+        #[frame_support::transactional]
+        pub fn execute_conditional_order(
+            origin: OriginFor<T>,
+            order_id: ConditionalOrderId,
+            #[pallet::compact] asset_count: u16,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let order = ConditionalOrders::<T>::get(order_id)
+                .ok_or(Error::<T>::ConditionalOrderNotFound)?;
+
+            Self::do_execute_conditional_order(order_id, order, asset_count, Some(who))
+        }
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_idle(_now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            Self::process_conditional_orders(remaining_weight)
+        }
     }
 
     impl<T> Pallet<T>
@@ -328,6 +654,9 @@ mod pallet {
         /// * `asset` - The asset to be traded.
         /// * `amount_in` - The amount to be traded.
         /// * `price_limit` - The maximum or minimum price at which the trade can be executed.
+        /// * `strategy` - The strategy of the trade. If it's `Strategy::PostOnly`, an error is
+        ///   returned instead of trading with the AMM.
+        #[allow(clippy::too_many_arguments)]
         fn maybe_fill_from_amm(
             tx_type: TxType,
             who: &AccountIdOf<T>,
@@ -335,6 +664,7 @@ mod pallet {
             asset: AssetOf<T>,
             amount_in: BalanceOf<T>,
             price_limit: BalanceOf<T>,
+            strategy: Strategy,
         ) -> Result<(BalanceOf<T>, Option<AmmTradeOf<T>>), DispatchError> {
             if !T::Amm::pool_exists(market_id) {
                 return Ok((amount_in, None));
@@ -363,6 +693,8 @@ mod pallet {
                 return Ok((amount_in, None));
             }
 
+            ensure!(strategy != Strategy::PostOnly, Error::<T>::PostOnlyOrderWouldTakeLiquidity);
+
             let amm_trade_info = Self::handle_amm_trade(
                 tx_type,
                 who.clone(),
@@ -416,6 +748,8 @@ mod pallet {
         /// * `base_asset` - The base asset of the market.
         /// * `asset` - The asset to be traded.
         /// * `price_limit` - The maximum or minimum price at which the trade can be executed.
+        /// * `strategy` - The strategy of the trade. If it's `Strategy::PostOnly`, an error is
+        ///   returned instead of filling an order.
         #[allow(clippy::too_many_arguments)]
         fn maybe_fill_orders(
             tx_type: TxType,
@@ -426,6 +760,7 @@ mod pallet {
             base_asset: AssetOf<T>,
             asset: AssetOf<T>,
             price_limit: BalanceOf<T>,
+            strategy: Strategy,
         ) -> Result<OrderAmmTradesInfo<T>, DispatchError> {
             let mut amm_trades = Vec::new();
             let mut order_trades = Vec::new();
@@ -467,6 +802,7 @@ mod pallet {
                     asset,
                     remaining,
                     order_price,
+                    strategy,
                 )?;
 
                 if let Some(t) = amm_trade_info.1 {
@@ -478,6 +814,11 @@ mod pallet {
                     break;
                 }
 
+                ensure!(
+                    strategy != Strategy::PostOnly,
+                    Error::<T>::PostOnlyOrderWouldTakeLiquidity
+                );
+
                 // `remaining` is always denominated in the `taker_asset`
                 // because this is what the order owner (maker) wants to receive
                 let (_taker_fill, maker_fill) =
@@ -494,6 +835,16 @@ mod pallet {
             Ok(OrderAmmTradesInfo { remaining, order_trades, amm_trades })
         }
 
+        /// Returns the number of orders which may be used to execute a trade with the specified
+        /// `orders`. If `orders` is empty, up to `MaxOrders` orders are discovered.
+        pub(crate) fn order_count(orders: &[OrderId]) -> u32 {
+            if orders.is_empty() {
+                T::MaxOrders::get()
+            } else {
+                orders.len().saturated_into::<u32>()
+            }
+        }
+
         fn handle_fill_order(
             who: AccountIdOf<T>,
             order_id: OrderId,
@@ -502,6 +853,7 @@ mod pallet {
             match T::Orderbook::fill_order(who, order_id, Some(maker_fill)) {
                 Ok(order_trade) => Ok(Some(order_trade)),
                 Err(ApiError::SoftFailure(OrderbookSoftFail::BelowMinimumBalance))
+                | Err(ApiError::SoftFailure(OrderbookSoftFail::BelowMinimumOrderAmount))
                 | Err(ApiError::SoftFailure(
                     OrderbookSoftFail::PartialFillNearFullFillNotAllowed,
                 )) => Ok(None),
@@ -509,8 +861,9 @@ mod pallet {
             }
         }
 
-        /// Places a limit order if the strategy is `Strategy::LimitOrder`.
-        /// If the strategy is `Strategy::ImmediateOrCancel`, an error is returned.
+        /// Places a limit order if the strategy is `Strategy::LimitOrder` or `Strategy::PostOnly`.
+        /// If the strategy is `Strategy::ImmediateOrCancel` or `Strategy::FillOrKill`, an error is
+        /// returned.
         /// A bool is returned to indicate if the order was placed successfully.
         ///
         /// # Arguments
@@ -533,7 +886,8 @@ mod pallet {
         ) -> Result<bool, DispatchError> {
             match strategy {
                 Strategy::ImmediateOrCancel => Err(Error::<T>::CancelStrategyApplied.into()),
-                Strategy::LimitOrder => {
+                Strategy::FillOrKill => Err(Error::<T>::FillOrKillStrategyApplied.into()),
+                Strategy::LimitOrder | Strategy::PostOnly => {
                     match T::Orderbook::place_order(
                         who.clone(),
                         market_id,
@@ -544,6 +898,7 @@ mod pallet {
                     ) {
                         Ok(()) => Ok(true),
                         Err(ApiError::SoftFailure(OrderbookSoftFail::BelowMinimumBalance))
+                        | Err(ApiError::SoftFailure(OrderbookSoftFail::BelowMinimumOrderAmount))
                         | Err(ApiError::SoftFailure(
                             OrderbookSoftFail::PartialFillNearFullFillNotAllowed,
                         )) => Ok(false),
@@ -565,7 +920,8 @@ mod pallet {
         /// * `asset` - The asset to be traded.
         /// * `amount_in` - The amount to be traded.
         /// * `price_limit` - The maximum or minimum price at which the trade can be executed.
-        /// * `orders` - A list of orders from the order book.
+        /// * `orders` - A list of orders from the order book. If empty, orders are discovered from
+        ///   the order book's price index.
         /// * `strategy` - The strategy to handle the remaining non-zero amount when the `max_price` is reached.
         #[require_transactional]
         #[allow(clippy::too_many_arguments)]
@@ -579,7 +935,7 @@ mod pallet {
             price_limit: BalanceOf<T>,
             orders: &[OrderId],
             strategy: Strategy,
-        ) -> DispatchResult {
+        ) -> Result<TradeSimulationOf<T>, DispatchError> {
             ensure!(amount_in > BalanceOf::<T>::zero(), Error::<T>::AmountIsZero);
             ensure!(
                 price_limit <= ZeitgeistBase::<BalanceOf<T>>::get()?,
@@ -599,18 +955,34 @@ mod pallet {
             };
             T::AssetManager::ensure_can_withdraw(asset_in, &who, amount_in)?;
 
+            if strategy == Strategy::PostOnly {
+                // Query the order book even if `orders` is specified, as a post-only order must not
+                // cross any order, including the ones the trader didn't list.
+                let crossing_orders =
+                    T::Orderbook::best_orders(asset_out, asset_in, price_limit, 1);
+                ensure!(crossing_orders.is_empty(), Error::<T>::PostOnlyOrderWouldTakeLiquidity);
+            }
+
+            let orders = if orders.is_empty() {
+                // The maker of a matching order offers what the trader receives.
+                T::Orderbook::best_orders(asset_out, asset_in, price_limit, T::MaxOrders::get())
+            } else {
+                orders.to_vec()
+            };
+
             let mut amm_trades: Vec<AmmTradeOf<T>> = Vec::new();
             let mut remaining = amount_in;
 
             let order_amm_trades_info = Self::maybe_fill_orders(
                 tx_type,
-                orders,
+                &orders,
                 remaining,
                 &who,
                 market_id,
                 market.base_asset,
                 asset,
                 price_limit,
+                strategy,
             )?;
 
             remaining = order_amm_trades_info.remaining;
@@ -625,6 +997,7 @@ mod pallet {
                     asset,
                     remaining,
                     price_limit,
+                    strategy,
                 )?;
 
                 amm_trades.extend(amm_trade_info.1);
@@ -682,9 +1055,669 @@ mod pallet {
                 swap_fee_amount,
             });
 
+            let executed_amount_in = amount_in.saturating_sub(remaining);
+            let average_price = match tx_type {
+                TxType::Buy if !amount_out.is_zero() => {
+                    executed_amount_in.bdiv_floor(amount_out)?
+                }
+                TxType::Sell if !executed_amount_in.is_zero() => {
+                    amount_out.bdiv_floor(executed_amount_in)?
+                }
+                _ => BalanceOf::<T>::zero(),
+            };
+
+            Ok(TradeSimulation {
+                order_trades: orderbook_trades,
+                amm_trades,
+                amount_in: executed_amount_in,
+                amount_out,
+                external_fee_amount,
+                swap_fee_amount,
+                remaining,
+                average_price,
+            })
+        }
+
+        /// Simulates a trade without committing any changes to storage. The trade runs through
+        /// the same code path as `buy` and `sell`, so the result reflects what the corresponding
+        /// extrinsic would do if it were executed in the current state.
+        ///
+        /// # Arguments
+        ///
+        /// * `tx_type` - The type of transaction (Buy or Sell).
+        /// * `who` - The account ID of the user performing the transaction.
+        /// * `market_id` - The ID of the market.
+        /// * `asset_count` - The number of assets traded on the market.
+        /// * `asset` - The asset to be traded.
+        /// * `amount_in` - The amount to be traded.
+        /// * `price_limit` - The maximum or minimum price at which the trade can be executed.
+        /// * `orders` - A list of orders from the order book. If empty, orders are discovered from
+        ///   the order book's price index.
+        /// * `strategy` - The strategy to handle the remaining non-zero amount when the `max_price` is reached.
+        #[allow(clippy::too_many_arguments)]
+        pub fn simulate_trade(
+            tx_type: TxType,
+            who: AccountIdOf<T>,
+            market_id: MarketIdOf<T>,
+            asset_count: u16,
+            asset: AssetOf<T>,
+            amount_in: BalanceOf<T>,
+            price_limit: BalanceOf<T>,
+            orders: Vec<OrderId>,
+            strategy: Strategy,
+        ) -> Result<TradeSimulationOf<T>, DispatchError> {
+            with_transaction(|| {
+                TransactionOutcome::Rollback(Self::do_trade(
+                    tx_type,
+                    who,
+                    market_id,
+                    asset_count,
+                    asset,
+                    amount_in,
+                    price_limit,
+                    &orders,
+                    strategy,
+                ))
+            })
+        }
+
+        /// Buys the combinatorial position made up of `legs` using the route which yields the
+        /// largest amount of the position.
+        ///
+        /// # Arguments
+        ///
+        /// * `who` - The account ID of the user performing the transaction.
+        /// * `legs` - The outcomes which make up the combinatorial position.
+        /// * `pool_id` - The ID of a combinatorial pool which trades the position, if any.
+        /// * `pool_asset_count` - The number of assets traded in the pool `pool_id`, or zero if
+        ///   `pool_id` is `None`.
+        /// * `amount_in` - The amount of the base asset to sell.
+        /// * `min_amount_out` - The minimum amount of the position to receive.
+        /// * `fuel` - The fuel used to calculate the IDs of the combinatorial tokens.
+        #[require_transactional]
+        pub(crate) fn do_buy_combinatorial(
+            who: AccountIdOf<T>,
+            legs: Vec<AssetOf<T>>,
+            pool_id: Option<MarketIdOf<T>>,
+            pool_asset_count: u16,
+            amount_in: BalanceOf<T>,
+            min_amount_out: BalanceOf<T>,
+            fuel: FuelOf<T>,
+        ) -> DispatchResult {
+            ensure!(amount_in > BalanceOf::<T>::zero(), Error::<T>::AmountIsZero);
+            let (legs_info, base_asset) = Self::combinatorial_legs_info(&legs)?;
+            T::AssetManager::ensure_can_withdraw(base_asset, &who, amount_in)?;
+            let position = Self::combinatorial_position(&legs_info, fuel.clone())?;
+
+            // The best route so far, the amount of the position it yields and its net cost.
+            let mut best_route: Option<(CombinatorialRouteOf<T>, BalanceOf<T>, BalanceOf<T>)> =
+                None;
+            let mut pool_assets = Vec::new();
+            if let Some(pool_id) = pool_id {
+                pool_assets = T::ComboAmm::combo_pool_assets(pool_id)?;
+                ensure!(pool_assets.contains(&position), Error::<T>::PositionNotInPool);
+            }
+            ensure!(pool_assets.len() == pool_asset_count as usize, Error::<T>::AssetCountMismatch);
+            if let Some(pool_id) = pool_id {
+                let pool_sell =
+                    pool_assets.iter().filter(|&&asset| asset != position).copied().collect();
+                if let Ok(amount_out) = T::ComboAmm::calculate_combo_buy_amount_out(
+                    pool_id,
+                    vec![position],
+                    pool_sell,
+                    amount_in,
+                ) {
+                    best_route = Some((
+                        CombinatorialRoute::CombinatorialPool(pool_id),
+                        amount_out,
+                        amount_in,
+                    ));
+                }
+            }
+            let pool = pool_id.map(|pool_id| (pool_id, pool_assets.as_slice()));
+            for (&leg, (market_id, _)) in legs.iter().zip(legs_info.iter()) {
+                if !T::Amm::pool_exists(*market_id) {
+                    continue;
+                }
+                // Without a combinatorial pool, the complements of the position can't be sold and
+                // the net cost is `amount_in`. Otherwise, the route is simulated to determine the
+                // amount received for the complements.
+                let split_result = if pool.is_some() {
+                    with_transaction(|| {
+                        TransactionOutcome::Rollback(Self::buy_and_split(
+                            &who,
+                            &legs,
+                            &legs_info,
+                            *market_id,
+                            position,
+                            amount_in,
+                            pool,
+                            fuel.clone(),
+                        ))
+                    })
+                } else {
+                    T::Amm::calculate_buy_amount_out(*market_id, leg, amount_in)
+                        .map(|amount_out| (amount_out, BalanceOf::<T>::zero()))
+                };
+                if let Ok((amount_out, amount_refunded)) = split_result {
+                    let net_cost = amount_in.saturating_sub(amount_refunded);
+                    let is_cheaper =
+                        best_route.as_ref().map_or(Ok(true), |(_, best_out, best_cost)| {
+                            Self::is_cheaper(net_cost, amount_out, *best_cost, *best_out)
+                        })?;
+                    if is_cheaper {
+                        best_route =
+                            Some((CombinatorialRoute::SplitLeg(*market_id), amount_out, net_cost));
+                    }
+                }
+            }
+            let (route, _, _) = best_route.ok_or(Error::<T>::NoRouteAvailable)?;
+
+            let (amount_out, amount_refunded) = match route {
+                CombinatorialRoute::CombinatorialPool(pool_id) => {
+                    let pool_sell =
+                        pool_assets.iter().filter(|&&asset| asset != position).copied().collect();
+                    let amount_out = T::ComboAmm::combo_buy(
+                        who.clone(),
+                        pool_id,
+                        vec![position],
+                        pool_sell,
+                        amount_in,
+                        min_amount_out,
+                    )?
+                    .amount_out;
+                    (amount_out, BalanceOf::<T>::zero())
+                }
+                CombinatorialRoute::SplitLeg(market_id) => Self::buy_and_split(
+                    &who, &legs, &legs_info, market_id, position, amount_in, pool, fuel,
+                )?,
+            };
+            ensure!(amount_out >= min_amount_out, Error::<T>::AmountOutBelowMin);
+
+            Self::deposit_event(Event::CombinatorialBuyExecuted {
+                who,
+                legs,
+                route,
+                asset_out: position,
+                amount_in,
+                amount_out,
+                amount_refunded,
+            });
+
             Ok(())
         }
 
+        /// Returns the market ID and index set of each leg, as well as the base asset shared by
+        /// the markets of the legs.
+        fn combinatorial_legs_info(
+            legs: &[AssetOf<T>],
+        ) -> Result<(Vec<(MarketIdOf<T>, Vec<bool>)>, AssetOf<T>), DispatchError> {
+            let leg_count = legs.len().saturated_into::<u32>();
+            ensure!((2..=T::MaxLegs::get()).contains(&leg_count), Error::<T>::InvalidLegs);
+
+            let mut legs_info: Vec<(MarketIdOf<T>, Vec<bool>)> = Vec::with_capacity(legs.len());
+            let mut base_asset = None;
+            for &leg in legs {
+                let market_id = match leg {
+                    Asset::CategoricalOutcome(market_id, _)
+                    | Asset::ScalarOutcome(market_id, _) => market_id,
+                    _ => return Err(Error::<T>::InvalidLegs.into()),
+                };
+                ensure!(legs_info.iter().all(|(id, _)| *id != market_id), Error::<T>::InvalidLegs);
+                let market = T::MarketCommons::market(&market_id)?;
+                ensure!(
+                    *base_asset.get_or_insert(market.base_asset) == market.base_asset,
+                    Error::<T>::InvalidLegs
+                );
+                let index_set: Vec<bool> =
+                    market.outcome_assets().into_iter().map(|asset| asset == leg).collect();
+                ensure!(index_set.contains(&true), Error::<T>::InvalidLegs);
+                legs_info.push((market_id, index_set));
+            }
+            let base_asset = base_asset.ok_or(Error::<T>::InvalidLegs)?;
+
+            Ok((legs_info, base_asset))
+        }
+
+        /// Returns the combinatorial position obtained by combining all legs.
+        fn combinatorial_position(
+            legs_info: &[(MarketIdOf<T>, Vec<bool>)],
+            fuel: FuelOf<T>,
+        ) -> Result<AssetOf<T>, DispatchError> {
+            let ((last_market_id, last_index_set), others) =
+                legs_info.split_last().ok_or(Error::<T>::InvalidLegs)?;
+            let mut parent_collection_id: Option<CombinatorialIdOf<T>> = None;
+            for (market_id, index_set) in others {
+                parent_collection_id =
+                    Some(T::CombinatorialTokens::collection_id_from_parent_collection(
+                        parent_collection_id,
+                        *market_id,
+                        index_set.clone(),
+                        fuel.clone(),
+                    )?);
+            }
+
+            T::CombinatorialTokens::position_from_parent_collection(
+                parent_collection_id,
+                *last_market_id,
+                last_index_set.clone(),
+                fuel,
+            )
+        }
+
+        /// Returns `true` if buying `amount_out` units for `net_cost` is cheaper per unit than buying
+        /// `best_amount_out` units for `best_net_cost`.
+        fn is_cheaper(
+            net_cost: BalanceOf<T>,
+            amount_out: BalanceOf<T>,
+            best_net_cost: BalanceOf<T>,
+            best_amount_out: BalanceOf<T>,
+        ) -> Result<bool, DispatchError> {
+            if amount_out.is_zero() {
+                return Ok(false);
+            }
+            if best_amount_out.is_zero() {
+                return Ok(true);
+            }
+            Ok(net_cost.bdiv_ceil(amount_out)? < best_net_cost.bdiv_ceil(best_amount_out)?)
+        }
+
+        /// Buys the leg of `market_id` from the pool of its market and splits the received
+        /// outcome tokens into the combinatorial position using the markets of the other legs.
+        ///
+        /// The outcome tokens are converted into combinatorial tokens using `convert_from_legacy`
+        /// of `zrml-combinatorial-tokens`, which keeps the collateral backing them in the market
+        /// account until the market is resolved.
+        ///
+        /// If a combinatorial `pool` is specified, the tokens are split into the atoms of the pool
+        /// and the complements of the position are sold back to the pool. Otherwise, the
+        /// complementary positions created along the way are kept by the trader.
+        ///
+        /// Returns the amount of the position received and the amount of the base asset received
+        /// for the complements.
+        #[allow(clippy::too_many_arguments)]
+        fn buy_and_split(
+            who: &AccountIdOf<T>,
+            legs: &[AssetOf<T>],
+            legs_info: &[(MarketIdOf<T>, Vec<bool>)],
+            market_id: MarketIdOf<T>,
+            position: AssetOf<T>,
+            amount_in: BalanceOf<T>,
+            pool: Option<(MarketIdOf<T>, &[AssetOf<T>])>,
+            fuel: FuelOf<T>,
+        ) -> Result<(BalanceOf<T>, BalanceOf<T>), DispatchError> {
+            let (&leg, (_, index_set)) = legs
+                .iter()
+                .zip(legs_info.iter())
+                .find(|(_, (id, _))| *id == market_id)
+                .ok_or(Error::<T>::NoRouteAvailable)?;
+
+            let amount = Self::handle_amm_trade(
+                TxType::Buy,
+                who.clone(),
+                market_id,
+                leg,
+                amount_in,
+                BalanceOf::<T>::zero(),
+            )?
+            .ok_or(Error::<T>::NoRouteAvailable)?
+            .amount_out;
+
+            T::CombinatorialTokens::convert_from_legacy(who.clone(), leg, amount, fuel.clone())?;
+
+            let parent_collection_id =
+                T::CombinatorialTokens::collection_id_from_parent_collection(
+                    None,
+                    market_id,
+                    index_set.clone(),
+                    fuel.clone(),
+                )?;
+            let other_legs = legs_info.iter().filter(|(id, _)| *id != market_id);
+
+            let Some((pool_id, pool_assets)) = pool else {
+                let mut parent_collection_id = parent_collection_id;
+                for (other_market_id, other_index_set) in other_legs {
+                    let complement = other_index_set.iter().map(|&b| !b).collect();
+                    let split_position_info = T::CombinatorialTokens::split_position(
+                        who.clone(),
+                        Some(parent_collection_id),
+                        *other_market_id,
+                        vec![other_index_set.clone(), complement],
+                        amount,
+                        fuel.clone(),
+                    )?;
+                    parent_collection_id = split_position_info
+                        .collection_ids
+                        .into_iter()
+                        .next()
+                        .ok_or(Error::<T>::NoRouteAvailable)?;
+                }
+                return Ok((amount, BalanceOf::<T>::zero()));
+            };
+
+            // Split every collection into all outcomes of the next market, so that the last
+            // level consists of the atoms of the pool which contain the bought leg.
+            let mut collection_ids = vec![parent_collection_id];
+            let mut atoms = Vec::new();
+            for (other_market_id, other_index_set) in other_legs {
+                let outcome_count = other_index_set.len();
+                let partition: Vec<Vec<bool>> = (0..outcome_count)
+                    .map(|i| (0..outcome_count).map(|j| i == j).collect())
+                    .collect();
+                let mut next_collection_ids = Vec::new();
+                atoms.clear();
+                for collection_id in collection_ids {
+                    let split_position_info = T::CombinatorialTokens::split_position(
+                        who.clone(),
+                        Some(collection_id),
+                        *other_market_id,
+                        partition.clone(),
+                        amount,
+                        fuel.clone(),
+                    )?;
+                    next_collection_ids.extend(split_position_info.collection_ids);
+                    atoms.extend(split_position_info.position_ids);
+                }
+                collection_ids = next_collection_ids;
+            }
+            ensure!(atoms.contains(&position), Error::<T>::NoRouteAvailable);
+
+            let complements: Vec<AssetOf<T>> =
+                atoms.into_iter().filter(|&asset| asset != position).collect();
+            ensure!(
+                complements.iter().all(|asset| pool_assets.contains(asset)),
+                Error::<T>::PositionNotInPool
+            );
+            let sell =
+                pool_assets.iter().filter(|asset| !complements.contains(asset)).copied().collect();
+            let amount_refunded = T::ComboAmm::combo_sell(
+                who.clone(),
+                pool_id,
+                complements,
+                vec![],
+                sell,
+                amount,
+                BalanceOf::<T>::zero(),
+                BalanceOf::<T>::zero(),
+            )?
+            .amount_out;
+
+            Ok((amount, amount_refunded))
+        }
+
+        /// The reserve ID of the hybrid router pallet.
+        #[inline]
+        pub(crate) fn reserve_id() -> [u8; 8] {
+            T::PalletId::get().0
+        }
+
+        #[require_transactional]
+        pub(crate) fn do_place_conditional_order(
+            who: AccountIdOf<T>,
+            market_id: MarketIdOf<T>,
+            asset: AssetOf<T>,
+            amount: BalanceOf<T>,
+            kind: ConditionalOrderKind,
+            trigger_price: BalanceOf<T>,
+            min_price: BalanceOf<T>,
+        ) -> DispatchResult {
+            ensure!(amount > BalanceOf::<T>::zero(), Error::<T>::AmountIsZero);
+            let max_price = ZeitgeistBase::<BalanceOf<T>>::get()?;
+            ensure!(
+                trigger_price <= max_price && min_price <= max_price,
+                Error::<T>::PriceLimitTooHigh
+            );
+            let market = T::MarketCommons::market(&market_id)?;
+            ensure!(market.status == MarketStatus::Active, Error::<T>::MarketNotActive);
+            ensure!(market.outcome_assets().contains(&asset), Error::<T>::AssetNotInMarket);
+
+            let keeper_fee = T::ConditionalOrderKeeperFee::get();
+            T::AssetManager::reserve_named(&Self::reserve_id(), asset, &who, amount)?;
+            T::AssetManager::reserve_named(
+                &Self::reserve_id(),
+                market.base_asset,
+                &who,
+                keeper_fee,
+            )?;
+
+            let order_id = NextConditionalOrderId::<T>::get();
+            let next_order_id = order_id.checked_add_res(&1)?;
+            let order = ConditionalOrder {
+                owner: who,
+                market_id,
+                asset,
+                amount,
+                kind,
+                trigger_price,
+                min_price,
+                keeper_fee,
+            };
+            MarketIdToConditionalOrderIds::<T>::try_mutate(market_id, |order_ids| {
+                order_ids.try_push(order_id).map_err(|_| Error::<T>::TooManyConditionalOrders)
+            })?;
+            ConditionalOrders::<T>::insert(order_id, order.clone());
+            NextConditionalOrderId::<T>::put(next_order_id);
+
+            Self::deposit_event(Event::ConditionalOrderPlaced { order_id, order });
+
+            Ok(())
+        }
+
+        /// Unreserves `amount` of `asset` held for a conditional order of `who`.
+        fn unreserve_conditional_order_funds(
+            asset: AssetOf<T>,
+            who: &AccountIdOf<T>,
+            amount: BalanceOf<T>,
+        ) {
+            let missing = T::AssetManager::unreserve_named(&Self::reserve_id(), asset, who, amount);
+
+            debug_assert!(
+                missing.is_zero(),
+                "Could not unreserve all of the amount. reserve_id: {:?}, asset: {:?} who: {:?}, \
+                 amount: {:?}, missing: {:?}",
+                Self::reserve_id(),
+                asset,
+                who,
+                amount,
+                missing,
+            );
+        }
+
+        /// Pays the reserved keeper fee of `order` to `keeper`, or returns it to the owner if
+        /// `keeper` is `None`.
+        fn pay_keeper_fee(
+            order: &ConditionalOrderOf<T>,
+            base_asset: AssetOf<T>,
+            keeper: &Option<AccountIdOf<T>>,
+        ) -> DispatchResult {
+            match keeper {
+                Some(keeper) => {
+                    T::AssetManager::repatriate_reserved_named(
+                        &Self::reserve_id(),
+                        base_asset,
+                        &order.owner,
+                        keeper,
+                        order.keeper_fee,
+                        BalanceStatus::Free,
+                    )?;
+                }
+                None => {
+                    Self::unreserve_conditional_order_funds(
+                        base_asset,
+                        &order.owner,
+                        order.keeper_fee,
+                    );
+                }
+            }
+
+            Ok(())
+        }
+
+        /// Removes the conditional order `order_id` from storage and from the index of its market.
+        fn remove_conditional_order(order_id: ConditionalOrderId, market_id: &MarketIdOf<T>) {
+            ConditionalOrders::<T>::remove(order_id);
+            MarketIdToConditionalOrderIds::<T>::mutate_exists(market_id, |maybe_order_ids| {
+                if let Some(order_ids) = maybe_order_ids {
+                    order_ids.retain(|&id| id != order_id);
+                    if order_ids.is_empty() {
+                        *maybe_order_ids = None;
+                    }
+                }
+            });
+        }
+
+        /// Removes the conditional order `order_id`, returns the reserved outcomes to the owner
+        /// and pays the keeper fee to `keeper`, or returns it to the owner if `keeper` is `None`.
+        #[require_transactional]
+        fn do_cancel_conditional_order(
+            order_id: ConditionalOrderId,
+            order: ConditionalOrderOf<T>,
+            keeper: Option<AccountIdOf<T>>,
+        ) -> DispatchResult {
+            let market = T::MarketCommons::market(&order.market_id)?;
+            Self::unreserve_conditional_order_funds(order.asset, &order.owner, order.amount);
+            Self::pay_keeper_fee(&order, market.base_asset, &keeper)?;
+            Self::remove_conditional_order(order_id, &order.market_id);
+
+            Self::deposit_event(Event::ConditionalOrderCancelled { order_id });
+
+            Ok(())
+        }
+
+        /// Executes the conditional order `order_id` if it's triggered. If the market of the order
+        /// is no longer active, the order is cancelled instead. The keeper fee is paid to `keeper`,
+        /// or returned to the owner if `keeper` is `None`. Fails if `asset_count` isn't the number
+        /// of assets traded on the market of the order.
+        #[require_transactional]
+        pub(crate) fn do_execute_conditional_order(
+            order_id: ConditionalOrderId,
+            order: ConditionalOrderOf<T>,
+            asset_count: u16,
+            keeper: Option<AccountIdOf<T>>,
+        ) -> DispatchResult {
+            let market = T::MarketCommons::market(&order.market_id)?;
+            if market.status != MarketStatus::Active {
+                return Self::do_cancel_conditional_order(order_id, order, keeper);
+            }
+
+            ensure!(T::Amm::pool_exists(order.market_id), Error::<T>::ConditionalOrderNotTriggered);
+            let spot_price = T::Amm::get_spot_price(order.market_id, order.asset)?;
+            ensure!(order.is_triggered(&spot_price), Error::<T>::ConditionalOrderNotTriggered);
+
+            Self::unreserve_conditional_order_funds(order.asset, &order.owner, order.amount);
+            let trade = Self::do_trade(
+                TxType::Sell,
+                order.owner.clone(),
+                order.market_id,
+                asset_count,
+                order.asset,
+                order.amount,
+                order.min_price,
+                &[],
+                Strategy::ImmediateOrCancel,
+            )?;
+            Self::pay_keeper_fee(&order, market.base_asset, &keeper)?;
+            Self::remove_conditional_order(order_id, &order.market_id);
+
+            Self::deposit_event(Event::ConditionalOrderExecuted {
+                order_id,
+                keeper,
+                amount_in: trade.amount_in,
+                amount_out: trade.amount_out,
+            });
+
+            Ok(())
+        }
+
+        /// Returns the weight of `buy_combinatorial` with `leg_count` legs and the total fuel
+        /// `fuel_total`. With a combinatorial pool, `pool_asset_count` is the number of assets of
+        /// the pool.
+        pub(crate) fn buy_combinatorial_weight(
+            leg_count: u32,
+            pool_asset_count: Option<u16>,
+            fuel_total: u32,
+        ) -> Weight {
+            match pool_asset_count {
+                Some(pool_asset_count) => T::WeightInfo::buy_combinatorial_with_pool(
+                    leg_count,
+                    pool_asset_count.into(),
+                    fuel_total,
+                ),
+                None => T::WeightInfo::buy_combinatorial(leg_count, fuel_total),
+            }
+        }
+
+        /// Returns the worst-case weight of executing a conditional order on a market with
+        /// `asset_count` assets, which is the weight of a sell using `MaxOrders` orders from the
+        /// order book.
+        pub(crate) fn conditional_order_weight(asset_count: u16) -> Weight {
+            T::WeightInfo::execute_conditional_order(asset_count.into(), T::MaxOrders::get())
+        }
+
+        /// Evaluates up to `MaxConditionalOrderChecks` conditional orders, continuing after the
+        /// order evaluated last in the previous pass, for as long as `remaining_weight` allows.
+        /// Triggered orders are executed and orders of inactive markets are cancelled. Orders which
+        /// aren't triggered or can't be filled are left in place.
+        ///
+        /// Each order is charged the reads of the order and its market plus the worst-case weight
+        /// of executing it (see `conditional_order_weight`). The weight consumed never exceeds
+        /// `remaining_weight`.
+        pub(crate) fn process_conditional_orders(remaining_weight: Weight) -> Weight {
+            let mut consumed = T::DbWeight::get().reads_writes(1, 1);
+            if remaining_weight.any_lt(consumed) {
+                return Weight::zero();
+            }
+
+            let mut iter = match ConditionalOrdersCursor::<T>::get() {
+                Some(cursor) => ConditionalOrders::<T>::iter_from(
+                    ConditionalOrders::<T>::hashed_key_for(cursor),
+                ),
+                None => ConditionalOrders::<T>::iter(),
+            };
+            let max_checks = T::MaxConditionalOrderChecks::get() as usize;
+            let lookup_weight = T::DbWeight::get().reads(2);
+            let mut orders = Vec::new();
+            let mut exhausted = false;
+            // Security note: Safe as the loop is limited to `MaxConditionalOrderChecks` iterations.
+            while orders.len() < max_checks
+                && remaining_weight.all_gte(consumed.saturating_add(lookup_weight))
+            {
+                consumed = consumed.saturating_add(lookup_weight);
+                let Some((order_id, order)) = iter.next() else {
+                    exhausted = true;
+                    break;
+                };
+                let asset_count = T::MarketCommons::market(&order.market_id)
+                    .map(|market| market.outcomes())
+                    .unwrap_or_default();
+                let order_weight = Self::conditional_order_weight(asset_count);
+                if remaining_weight.any_lt(consumed.saturating_add(order_weight)) {
+                    // The order is evaluated in a later pass.
+                    break;
+                }
+                consumed = consumed.saturating_add(order_weight);
+                orders.push((order_id, order, asset_count));
+            }
+
+            if exhausted {
+                ConditionalOrdersCursor::<T>::set(None);
+            } else if let Some((order_id, _, _)) = orders.last() {
+                ConditionalOrdersCursor::<T>::set(Some(*order_id));
+            }
+
+            for (order_id, order, asset_count) in orders {
+                // Orders which can't be executed yet are left in place.
+                let _ = with_transaction(|| {
+                    match Self::do_execute_conditional_order(order_id, order, asset_count, None) {
+                        Ok(()) => TransactionOutcome::Commit(Ok(())),
+                        Err(err) => TransactionOutcome::Rollback(Err(err)),
+                    }
+                });
+            }
+
+            consumed
+        }
+
         fn get_event_info(
             who: &AccountIdOf<T>,
             orderbook_trades: &[OrderTradeOf<T>],
@@ -729,4 +1762,34 @@ mod pallet {
             Ok(event_info)
         }
     }
+
+    impl<T: Config> OnMarketClose for Pallet<T> {
+        type MarketId = MarketIdOf<T>;
+
+        /// Cancels all conditional orders of the market `market_id` and returns the reserved
+        /// outcomes and keeper fees to their owners.
+        fn on_market_close(market_id: &Self::MarketId) -> Weight {
+            let order_ids = MarketIdToConditionalOrderIds::<T>::take(market_id);
+            let order_count = order_ids.len() as u32;
+            for order_id in order_ids {
+                let Some(order) = ConditionalOrders::<T>::get(order_id) else {
+                    continue;
+                };
+                // Orders which can't be cancelled here are cancelled by the next pass of
+                // `on_idle`, so closing the market must not fail.
+                let _ = with_transaction(|| {
+                    match Self::do_cancel_conditional_order(order_id, order, None) {
+                        Ok(()) => TransactionOutcome::Commit(Ok(())),
+                        Err(err) => TransactionOutcome::Rollback(Err(err)),
+                    }
+                });
+            }
+
+            T::WeightInfo::on_market_close(order_count)
+        }
+
+        fn on_market_close_max_weight() -> Weight {
+            T::WeightInfo::on_market_close(T::MaxConditionalOrdersPerMarket::get())
+        }
+    }
 }
@@ -31,6 +31,7 @@ use frame_support::{
         tokens::{PayFromAccount, UnityAssetBalanceConversion},
         Contains, Everything, NeverEnsureOrigin,
     },
+    weights::Weight,
     Blake2_256,
 };
 use frame_system::{mocking::MockBlock, EnsureRoot, EnsureSignedBy};
@@ -45,28 +46,34 @@ use sp_runtime::{
 };
 use zeitgeist_primitives::{
     constants::mock::{
-        AddOutcomePeriod, AggregationPeriod, AppealBond, AppealPeriod, AuthorizedPalletId,
-        BlockHashCount, BlocksPerYear, CloseEarlyBlockPeriod, CloseEarlyDisputeBond,
-        CloseEarlyProtectionBlockPeriod, CloseEarlyProtectionTimeFramePeriod,
-        CloseEarlyRequestBond, CloseEarlyTimeFramePeriod, CombinatorialTokensPalletId,
-        CorrectionPeriod, CourtPalletId, ExistentialDeposit, ExistentialDeposits, GdVotingPeriod,
-        GetNativeCurrencyId, GlobalDisputeLockId, GlobalDisputesPalletId, HybridRouterPalletId,
-        InflationPeriod, LockId, MaxAppeals, MaxApprovals, MaxCourtParticipants, MaxCreatorFee,
-        MaxDelegations, MaxDisputeDuration, MaxDisputes, MaxEditReasonLen, MaxGlobalDisputeVotes,
-        MaxGracePeriod, MaxLiquidityTreeDepth, MaxLocks, MaxMarketLifetime, MaxOracleDuration,
+        AddOutcomePeriod, AggregationPeriod, AppealBond, AppealPeriod, AuthorityAppealBond,
+        AuthorityAppealPeriod, AuthorizedPalletId, BlockHashCount, BlocksPerYear,
+        CloseEarlyBlockPeriod, CloseEarlyDisputeBond, CloseEarlyProtectionBlockPeriod,
+        CloseEarlyProtectionTimeFramePeriod, CloseEarlyRequestBond, CloseEarlyTimeFramePeriod,
+        CombinatorialTokensMaxRedeemSteps, CombinatorialTokensPalletId, ConditionalOrderKeeperFee,
+        ConvictionLockPeriod, CorrectionPeriod, CourtPalletId, ExistentialDeposit,
+        ExistentialDeposits, GdVotingPeriod, GetNativeCurrencyId, GlobalDisputeLockId,
+        GlobalDisputesPalletId, HybridRouterPalletId, InflationPeriod, LockId, MarketSeriesBond,
+        MarketTemplateBond, MaxAppeals, MaxApprovals, MaxCommitteeMembers,
+        MaxConditionalOrderChecks, MaxConditionalOrdersPerMarket, MaxCourtParticipants,
+        MaxCreatorFee, MaxDelegations, MaxDisputeDuration, MaxDisputes, MaxEditReasonLen,
+        MaxGlobalDisputeVotes, MaxGracePeriod, MaxIndexedOrders, MaxLegs, MaxLiquidityTreeDepth,
+        MaxLocks, MaxMarketLifetime, MaxMarketSeriesRolloversPerBlock, MaxOracleDuration,
         MaxOrders, MaxOwners, MaxRejectReasonLen, MaxReserves, MaxSelectedDraws,
         MaxYearlyInflation, MinCategories, MinDisputeDuration, MinJurorStake, MinOracleDuration,
-        MinOutcomeVoteAmount, MinimumPeriod, NeoMaxSwapFee, NeoSwapsPalletId, OrderbookPalletId,
-        OutsiderBond, PmPalletId, RemoveKeysLimit, RequestInterval, TreasuryPalletId, VotePeriod,
-        VotingOutcomeFee, BASE, CENT, MAX_ASSETS,
+        MinOrderAmount, MinOutcomeVoteAmount, MinimumPeriod, NeoMaxSwapFee, NeoSwapsPalletId,
+        OrderbookPalletId, OutsiderBond, PmPalletId, RemoveKeysLimit, RequestInterval,
+        TreasuryPalletId, VotePeriod, VoterRewardPercentage, VotingOutcomeFee, BASE, CENT,
+        MAX_ASSETS,
     },
-    traits::DistributeFees,
+    traits::{DistributeFees, OracleRegistryApi},
     types::{
         AccountIdTest, Amount, Balance, BasicCurrencyAdapter, CombinatorialId, CurrencyId, Hash,
         MarketId, Moment,
     },
 };
 use zrml_combinatorial_tokens::types::{CryptographicIdManager, Fuel};
+use zrml_global_disputes::types::VotingWeightMode;
 
 #[cfg(feature = "parachain")]
 use {
@@ -125,6 +132,33 @@ pub fn calculate_fee<T: crate::Config>(amount: BalanceOf<T>) -> BalanceOf<T> {
     fee_percentage().mul_floor(amount.saturated_into::<BalanceOf<T>>())
 }
 
+pub struct OracleRegistryNoop;
+
+impl OracleRegistryApi for OracleRegistryNoop {
+    type AccountId = AccountIdTest;
+    type Balance = Balance;
+
+    fn fallback_oracle(_oracle: &Self::AccountId) -> Option<Self::AccountId> {
+        None
+    }
+
+    fn reputation(_oracle: &Self::AccountId) -> Option<u32> {
+        None
+    }
+
+    fn on_accurate_report(_oracle: &Self::AccountId, _market_stake: Self::Balance) -> Weight {
+        Weight::zero()
+    }
+
+    fn on_missed_report(_oracle: &Self::AccountId, _market_stake: Self::Balance) -> Weight {
+        Weight::zero()
+    }
+
+    fn on_overturned_report(_oracle: &Self::AccountId, _market_stake: Self::Balance) -> Weight {
+        Weight::zero()
+    }
+}
+
 pub struct ExternalFees<T, F>(PhantomData<T>, PhantomData<F>);
 
 impl<T: crate::Config, F> DistributeFees for ExternalFees<T, F>
@@ -190,11 +224,19 @@ impl crate::Config for Runtime {
     #[cfg(feature = "runtime-benchmarks")]
     type AmmPoolDeployer = NeoSwaps;
     type Amm = NeoSwaps;
+    type CombinatorialTokens = CombinatorialTokens;
+    type ComboAmm = NeoSwaps;
+    #[cfg(feature = "runtime-benchmarks")]
+    type ComboPoolDeployer = NeoSwaps;
     #[cfg(feature = "runtime-benchmarks")]
     type CompleteSetOperations = PredictionMarkets;
+    type ConditionalOrderKeeperFee = ConditionalOrderKeeperFee;
     type MarketCommons = MarketCommons;
     type Orderbook = Orderbook;
     type RuntimeEvent = RuntimeEvent;
+    type MaxConditionalOrderChecks = MaxConditionalOrderChecks;
+    type MaxConditionalOrdersPerMarket = MaxConditionalOrdersPerMarket;
+    type MaxLegs = MaxLegs;
     type MaxOrders = MaxOrders;
     type PalletId = HybridRouterPalletId;
     type WeightInfo = zrml_hybrid_router::weights::WeightInfo<Runtime>;
@@ -205,6 +247,8 @@ impl zrml_orderbook::Config for Runtime {
     type ExternalFees = ExternalFees<Runtime, FeeAccount>;
     type RuntimeEvent = RuntimeEvent;
     type MarketCommons = MarketCommons;
+    type MaxIndexedOrders = MaxIndexedOrders;
+    type MinOrderAmount = MinOrderAmount;
     type PalletId = OrderbookPalletId;
     type WeightInfo = zrml_orderbook::weights::WeightInfo<Runtime>;
 }
@@ -258,10 +302,15 @@ impl zrml_prediction_markets::Config for Runtime {
     type MaxGracePeriod = MaxGracePeriod;
     type MaxOracleDuration = MaxOracleDuration;
     type MaxMarketLifetime = MaxMarketLifetime;
+    type MaxMarketSeriesRolloversPerBlock = MaxMarketSeriesRolloversPerBlock;
     type MinCategories = MinCategories;
     type MaxEditReasonLen = MaxEditReasonLen;
+    type MarketTemplateBond = MarketTemplateBond;
+    type MarketSeriesBond = MarketSeriesBond;
     type MaxRejectReasonLen = MaxRejectReasonLen;
     type OracleBond = OracleBond;
+    type OnMarketClose = HybridRouter;
+    type OracleRegistry = OracleRegistryNoop;
     type OutsiderBond = OutsiderBond;
     type PalletId = PmPalletId;
     type RejectOrigin = EnsureSignedBy<Sudo, AccountIdTest>;
@@ -274,6 +323,8 @@ impl zrml_prediction_markets::Config for Runtime {
 }
 
 impl zrml_authorized::Config for Runtime {
+    type AppealBond = AuthorityAppealBond;
+    type AppealPeriod = AuthorityAppealPeriod;
     type AuthorizedDisputeResolutionOrigin =
         EnsureSignedBy<AuthorizedDisputeResolutionUser, AccountIdTest>;
     type CorrectionPeriod = CorrectionPeriod;
@@ -281,6 +332,7 @@ impl zrml_authorized::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type DisputeResolution = zrml_prediction_markets::Pallet<Runtime>;
     type MarketCommons = MarketCommons;
+    type MaxCommitteeMembers = MaxCommitteeMembers;
     type PalletId = AuthorizedPalletId;
     type WeightInfo = zrml_authorized::weights::WeightInfo<Runtime>;
 }
@@ -290,7 +342,9 @@ impl zrml_combinatorial_tokens::Config for Runtime {
     type BenchmarkHelper = NoopCombinatorialTokensBenchmarkHelper<Balance, MarketId>;
     type CombinatorialIdManager = CryptographicIdManager<MarketId, Blake2_256>;
     type Fuel = Fuel;
+    type LegacyMarketAccount = zrml_prediction_markets::MarketAccount<Runtime>;
     type MarketCommons = MarketCommons;
+    type MaxRedeemSteps = CombinatorialTokensMaxRedeemSteps;
     type MultiCurrency = AssetManager;
     type Payout = PredictionMarkets;
     type RuntimeEvent = RuntimeEvent;
@@ -436,20 +490,29 @@ impl pallet_timestamp::Config for Runtime {
     type WeightInfo = ();
 }
 
+parameter_types! {
+    pub const DefaultVotingWeightMode: VotingWeightMode = VotingWeightMode::TokenWeighted;
+}
+
 impl zrml_global_disputes::Config for Runtime {
     type AddOutcomePeriod = AddOutcomePeriod;
+    type ConvictionLockPeriod = ConvictionLockPeriod;
     type RuntimeEvent = RuntimeEvent;
     type DisputeResolution = zrml_prediction_markets::Pallet<Runtime>;
     type MarketCommons = MarketCommons;
     type Currency = Balances;
+    type DefaultVotingWeightMode = DefaultVotingWeightMode;
     type GlobalDisputeLockId = GlobalDisputeLockId;
     type GlobalDisputesPalletId = GlobalDisputesPalletId;
+    type IdentityVerification = Everything;
     type MaxGlobalDisputeVotes = MaxGlobalDisputeVotes;
     type MaxOwners = MaxOwners;
     type MinOutcomeVoteAmount = MinOutcomeVoteAmount;
     type RemoveKeysLimit = RemoveKeysLimit;
     type GdVotingPeriod = GdVotingPeriod;
+    type VoterRewardPercentage = VoterRewardPercentage;
     type VotingOutcomeFee = VotingOutcomeFee;
+    type VotingWeightModeOrigin = EnsureRoot<AccountIdTest>;
     type WeightInfo = zrml_global_disputes::weights::WeightInfo<Runtime>;
 }
 
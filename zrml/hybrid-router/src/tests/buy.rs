@@ -1362,3 +1362,285 @@ fn buy_fails_if_market_does_not_exist() {
         );
     });
 }
+
+#[test]
+fn buy_discovers_orders_if_none_are_specified() {
+    ExtBuilder::default().build().execute_with(|| {
+        let liquidity = _10;
+        let spot_prices = vec![_1_2, _1_2];
+        let swap_fee = CENT;
+        let asset_count = 2u16;
+        let market_id = create_market_and_deploy_pool(
+            ALICE,
+            BASE_ASSET,
+            MarketType::Categorical(asset_count),
+            liquidity,
+            spot_prices.clone(),
+            swap_fee,
+        );
+
+        let asset = Asset::CategoricalOutcome(market_id, 0);
+        let order_maker_amount = _1;
+        assert_ok!(AssetManager::deposit(asset, &CHARLIE, 3 * order_maker_amount));
+        for order_taker_amount in [_9_10, _1_4, _1_4] {
+            assert_ok!(Orderbook::place_order(
+                RuntimeOrigin::signed(CHARLIE),
+                market_id,
+                asset,
+                order_maker_amount,
+                BASE_ASSET,
+                order_taker_amount,
+            ));
+        }
+
+        let charlie_balance_before = AssetManager::free_balance(BASE_ASSET, &CHARLIE);
+
+        let amount_in = _1;
+        let max_price = _3_4.saturated_into::<BalanceOf<Runtime>>();
+        assert_ok!(HybridRouter::buy(
+            RuntimeOrigin::signed(ALICE),
+            market_id,
+            asset_count,
+            asset,
+            amount_in,
+            max_price,
+            vec![],
+            Strategy::ImmediateOrCancel,
+        ));
+
+        // The cheap orders are filled, the order above `max_price` is left untouched.
+        let order_ids = Orders::<Runtime>::iter().map(|(k, _)| k).collect::<Vec<_>>();
+        assert_eq!(order_ids, vec![0]);
+        assert_eq!(
+            Orders::<Runtime>::get(0).unwrap(),
+            Order {
+                market_id,
+                maker: CHARLIE,
+                maker_asset: asset,
+                maker_amount: order_maker_amount,
+                taker_asset: BASE_ASSET,
+                taker_amount: _9_10,
+            }
+        );
+        assert_eq!(
+            AssetManager::free_balance(BASE_ASSET, &CHARLIE),
+            charlie_balance_before + 2 * _1_4
+        );
+    });
+}
+
+#[test]
+fn buy_fails_if_fill_or_kill_strategy_applied() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0;
+        let mut market = market_mock::<Runtime>(MARKET_CREATOR);
+        let required_asset_count = match &market.market_type {
+            MarketType::Scalar(_) => panic!("Categorical market type is expected!"),
+            MarketType::Categorical(categories) => *categories,
+        };
+        market.status = MarketStatus::Active;
+        Markets::<Runtime>::insert(market_id, market);
+
+        let asset = Asset::CategoricalOutcome(market_id, 0);
+        let max_price = _1_2.saturated_into::<BalanceOf<Runtime>>();
+        assert_noop!(
+            HybridRouter::buy(
+                RuntimeOrigin::signed(ALICE),
+                market_id,
+                required_asset_count,
+                asset,
+                _10,
+                max_price,
+                vec![],
+                Strategy::FillOrKill,
+            ),
+            Error::<Runtime>::FillOrKillStrategyApplied
+        );
+    });
+}
+
+#[test]
+fn buy_with_fill_or_kill_strategy_succeeds_if_fully_executed() {
+    ExtBuilder::default().build().execute_with(|| {
+        let asset_count = 2u16;
+        let market_id = create_market_and_deploy_pool(
+            ALICE,
+            BASE_ASSET,
+            MarketType::Categorical(asset_count),
+            _10,
+            vec![_1_2, _1_2],
+            CENT,
+        );
+
+        let asset = Asset::CategoricalOutcome(market_id, 0);
+        let max_price = _3_4.saturated_into::<BalanceOf<Runtime>>();
+        assert_ok!(HybridRouter::buy(
+            RuntimeOrigin::signed(ALICE),
+            market_id,
+            asset_count,
+            asset,
+            _1,
+            max_price,
+            vec![],
+            Strategy::FillOrKill,
+        ));
+
+        assert_eq!(Orders::<Runtime>::iter().count(), 0);
+    });
+}
+
+#[test]
+fn buy_with_post_only_strategy_places_order_without_taking_liquidity() {
+    ExtBuilder::default().build().execute_with(|| {
+        let asset_count = 2u16;
+        let market_id = create_market_and_deploy_pool(
+            ALICE,
+            BASE_ASSET,
+            MarketType::Categorical(asset_count),
+            _10,
+            vec![_1_2, _1_2],
+            CENT,
+        );
+
+        let asset = Asset::CategoricalOutcome(market_id, 0);
+        let amount_in = _1;
+        let max_price = _1_4.saturated_into::<BalanceOf<Runtime>>();
+        assert_ok!(HybridRouter::buy(
+            RuntimeOrigin::signed(ALICE),
+            market_id,
+            asset_count,
+            asset,
+            amount_in,
+            max_price,
+            vec![],
+            Strategy::PostOnly,
+        ));
+
+        let orders = Orders::<Runtime>::iter().map(|(_, o)| o).collect::<Vec<_>>();
+        assert_eq!(
+            orders,
+            vec![Order {
+                market_id,
+                maker: ALICE,
+                maker_asset: BASE_ASSET,
+                maker_amount: amount_in,
+                taker_asset: asset,
+                taker_amount: _4,
+            }]
+        );
+    });
+}
+
+#[test]
+fn buy_with_post_only_strategy_fails_if_amm_would_be_used() {
+    ExtBuilder::default().build().execute_with(|| {
+        let asset_count = 2u16;
+        let market_id = create_market_and_deploy_pool(
+            ALICE,
+            BASE_ASSET,
+            MarketType::Categorical(asset_count),
+            _10,
+            vec![_1_2, _1_2],
+            CENT,
+        );
+
+        let asset = Asset::CategoricalOutcome(market_id, 0);
+        let max_price = _3_4.saturated_into::<BalanceOf<Runtime>>();
+        assert_noop!(
+            HybridRouter::buy(
+                RuntimeOrigin::signed(ALICE),
+                market_id,
+                asset_count,
+                asset,
+                _1,
+                max_price,
+                vec![],
+                Strategy::PostOnly,
+            ),
+            Error::<Runtime>::PostOnlyOrderWouldTakeLiquidity
+        );
+    });
+}
+
+#[test]
+fn buy_with_post_only_strategy_fails_if_order_would_be_filled() {
+    ExtBuilder::default().build().execute_with(|| {
+        let asset_count = 2u16;
+        let market_id = create_market_and_deploy_pool(
+            ALICE,
+            BASE_ASSET,
+            MarketType::Categorical(asset_count),
+            _10,
+            vec![_1_2, _1_2],
+            CENT,
+        );
+
+        let asset = Asset::CategoricalOutcome(market_id, 0);
+        assert_ok!(AssetManager::deposit(asset, &CHARLIE, _1));
+        assert_ok!(Orderbook::place_order(
+            RuntimeOrigin::signed(CHARLIE),
+            market_id,
+            asset,
+            _1,
+            BASE_ASSET,
+            _1_5,
+        ));
+
+        let max_price = _1_4.saturated_into::<BalanceOf<Runtime>>();
+        assert_noop!(
+            HybridRouter::buy(
+                RuntimeOrigin::signed(ALICE),
+                market_id,
+                asset_count,
+                asset,
+                _1,
+                max_price,
+                vec![],
+                Strategy::PostOnly,
+            ),
+            Error::<Runtime>::PostOnlyOrderWouldTakeLiquidity
+        );
+    });
+}
+
+#[test]
+fn buy_with_post_only_strategy_fails_if_unlisted_order_would_be_filled() {
+    ExtBuilder::default().build().execute_with(|| {
+        let asset_count = 2u16;
+        let market_id = create_market_and_deploy_pool(
+            ALICE,
+            BASE_ASSET,
+            MarketType::Categorical(asset_count),
+            _10,
+            vec![_1_2, _1_2],
+            CENT,
+        );
+
+        let asset = Asset::CategoricalOutcome(market_id, 0);
+        assert_ok!(AssetManager::deposit(asset, &CHARLIE, _1));
+        assert_ok!(Orderbook::place_order(
+            RuntimeOrigin::signed(CHARLIE),
+            market_id,
+            asset,
+            _1,
+            BASE_ASSET,
+            _1_5,
+        ));
+
+        let max_price = _1_4.saturated_into::<BalanceOf<Runtime>>();
+        assert_noop!(
+            HybridRouter::buy(
+                RuntimeOrigin::signed(ALICE),
+                market_id,
+                asset_count,
+                asset,
+                _1,
+                max_price,
+                // The crossing order isn't listed, but the order book is queried anyway.
+                vec![42],
+                Strategy::PostOnly,
+            ),
+            Error::<Runtime>::PostOnlyOrderWouldTakeLiquidity
+        );
+    });
+}
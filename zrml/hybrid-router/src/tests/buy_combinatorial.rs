@@ -0,0 +1,494 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use test_case::test_case;
+use zeitgeist_primitives::{
+    traits::{HybridRouterAmmApi, HybridRouterComboAmmApi},
+    types::ScalarPosition,
+};
+use zrml_combinatorial_tokens::types::Fuel;
+
+fn fuel() -> Fuel {
+    Fuel::new(16, false)
+}
+
+fn position(legs: &[(MarketId, Vec<bool>)]) -> Asset<MarketId> {
+    let (last, others) = legs.split_last().unwrap();
+    let mut parent_collection_id = None;
+    for (market_id, index_set) in others {
+        parent_collection_id = Some(
+            CombinatorialTokens::collection_id_from_parent_collection(
+                parent_collection_id,
+                *market_id,
+                index_set.clone(),
+                fuel(),
+            )
+            .unwrap(),
+        );
+    }
+    CombinatorialTokens::position_from_parent_collection(
+        parent_collection_id,
+        last.0,
+        last.1.clone(),
+        fuel(),
+    )
+    .unwrap()
+}
+
+fn deploy_combinatorial_pool(
+    market_ids: Vec<MarketId>,
+    spot_prices: Vec<BalanceOf<Runtime>>,
+) -> MarketIdOf<Runtime> {
+    assert_ok!(NeoSwaps::deploy_combinatorial_pool(
+        RuntimeOrigin::signed(ALICE),
+        spot_prices.len() as u16,
+        market_ids,
+        _10,
+        spot_prices,
+        CENT,
+        fuel(),
+    ));
+    System::events()
+        .into_iter()
+        .rev()
+        .find_map(|record| match record.event {
+            RuntimeEvent::NeoSwaps(NeoSwapsEvent::CombinatorialPoolDeployed {
+                pool_id, ..
+            }) => Some(pool_id),
+            _ => None,
+        })
+        .unwrap()
+}
+
+#[test]
+fn buy_combinatorial_splits_the_cheapest_leg() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_a = create_market_and_deploy_pool(
+            ALICE,
+            BASE_ASSET,
+            MarketType::Categorical(2),
+            _10,
+            vec![_1_4, _3_4],
+            CENT,
+        );
+        let market_b = create_market_and_deploy_pool(
+            ALICE,
+            BASE_ASSET,
+            MarketType::Categorical(2),
+            _10,
+            vec![_1_2, _1_2],
+            CENT,
+        );
+        let leg_a = Asset::CategoricalOutcome(market_a, 0);
+        let leg_b = Asset::CategoricalOutcome(market_b, 0);
+        let amount_in = _1;
+
+        let amount_out = NeoSwaps::calculate_buy_amount_out(market_a, leg_a, amount_in).unwrap();
+        assert!(
+            amount_out > NeoSwaps::calculate_buy_amount_out(market_b, leg_b, amount_in).unwrap()
+        );
+
+        assert_ok!(HybridRouter::buy_combinatorial(
+            RuntimeOrigin::signed(CHARLIE),
+            vec![leg_a, leg_b],
+            None,
+            0,
+            amount_in,
+            amount_out,
+            fuel(),
+        ));
+
+        let position_ab = position(&[(market_a, vec![true, false]), (market_b, vec![true, false])]);
+        let position_a_not_b =
+            position(&[(market_a, vec![true, false]), (market_b, vec![false, true])]);
+        assert_eq!(AssetManager::free_balance(position_ab, &CHARLIE), amount_out);
+        assert_eq!(AssetManager::free_balance(position_a_not_b, &CHARLIE), amount_out);
+        assert_eq!(AssetManager::free_balance(leg_a, &CHARLIE), 0);
+        // The bought outcome tokens are recorded as converted, so the collateral backing them
+        // stays in the market account until the market is resolved.
+        assert_eq!(
+            zrml_combinatorial_tokens::LegacyConversions::<Runtime>::get(market_a).into_inner(),
+            vec![amount_out, 0]
+        );
+        System::assert_last_event(
+            Event::CombinatorialBuyExecuted {
+                who: CHARLIE,
+                legs: vec![leg_a, leg_b],
+                route: CombinatorialRoute::SplitLeg(market_a),
+                asset_out: position_ab,
+                amount_in,
+                amount_out,
+                amount_refunded: 0,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn buy_combinatorial_supports_scalar_legs() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_a = create_market_and_deploy_pool(
+            ALICE,
+            BASE_ASSET,
+            MarketType::Categorical(2),
+            _10,
+            vec![_1_2, _1_2],
+            CENT,
+        );
+        let market_b = create_market_and_deploy_pool(
+            ALICE,
+            BASE_ASSET,
+            MarketType::Scalar(0..=100),
+            _10,
+            vec![_1_4, _3_4],
+            CENT,
+        );
+        let leg_a = Asset::CategoricalOutcome(market_a, 1);
+        let leg_b = Asset::ScalarOutcome(market_b, ScalarPosition::Long);
+        let amount_in = _1;
+
+        let amount_out = NeoSwaps::calculate_buy_amount_out(market_b, leg_b, amount_in).unwrap();
+
+        assert_ok!(HybridRouter::buy_combinatorial(
+            RuntimeOrigin::signed(CHARLIE),
+            vec![leg_a, leg_b],
+            None,
+            0,
+            amount_in,
+            amount_out,
+            fuel(),
+        ));
+
+        let position_ab = position(&[(market_a, vec![false, true]), (market_b, vec![true, false])]);
+        assert_eq!(AssetManager::free_balance(position_ab, &CHARLIE), amount_out);
+        System::assert_last_event(
+            Event::CombinatorialBuyExecuted {
+                who: CHARLIE,
+                legs: vec![leg_a, leg_b],
+                route: CombinatorialRoute::SplitLeg(market_b),
+                asset_out: position_ab,
+                amount_in,
+                amount_out,
+                amount_refunded: 0,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn buy_combinatorial_uses_combinatorial_pool_if_cheaper() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_a = create_market_and_deploy_pool(
+            ALICE,
+            BASE_ASSET,
+            MarketType::Categorical(2),
+            _10,
+            vec![_1_2, _1_2],
+            CENT,
+        );
+        let market_b = create_market_and_deploy_pool(
+            ALICE,
+            BASE_ASSET,
+            MarketType::Categorical(2),
+            _10,
+            vec![_1_2, _1_2],
+            CENT,
+        );
+        let pool_id =
+            deploy_combinatorial_pool(vec![market_a, market_b], vec![_1_10, _3_10, _3_10, _3_10]);
+        let leg_a = Asset::CategoricalOutcome(market_a, 0);
+        let leg_b = Asset::CategoricalOutcome(market_b, 0);
+        let amount_in = _1;
+
+        let position_ab = position(&[(market_a, vec![true, false]), (market_b, vec![true, false])]);
+        let sell = NeoSwaps::combo_pool_assets(pool_id)
+            .unwrap()
+            .into_iter()
+            .filter(|&asset| asset != position_ab)
+            .collect::<Vec<_>>();
+        let amount_out =
+            NeoSwaps::calculate_combo_buy_amount_out(pool_id, vec![position_ab], sell, amount_in)
+                .unwrap();
+        assert!(
+            amount_out > NeoSwaps::calculate_buy_amount_out(market_a, leg_a, amount_in).unwrap()
+        );
+
+        assert_ok!(HybridRouter::buy_combinatorial(
+            RuntimeOrigin::signed(CHARLIE),
+            vec![leg_a, leg_b],
+            Some(pool_id),
+            4,
+            amount_in,
+            amount_out,
+            fuel(),
+        ));
+
+        assert_eq!(AssetManager::free_balance(position_ab, &CHARLIE), amount_out);
+        System::assert_last_event(
+            Event::CombinatorialBuyExecuted {
+                who: CHARLIE,
+                legs: vec![leg_a, leg_b],
+                route: CombinatorialRoute::CombinatorialPool(pool_id),
+                asset_out: position_ab,
+                amount_in,
+                amount_out,
+                amount_refunded: 0,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn buy_combinatorial_splits_if_cheaper_than_combinatorial_pool() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_a = create_market_and_deploy_pool(
+            ALICE,
+            BASE_ASSET,
+            MarketType::Categorical(2),
+            _10,
+            vec![_1_2, _1_2],
+            CENT,
+        );
+        let market_b = create_market_and_deploy_pool(
+            ALICE,
+            BASE_ASSET,
+            MarketType::Categorical(2),
+            _10,
+            vec![_1_2, _1_2],
+            CENT,
+        );
+        let pool_id = deploy_combinatorial_pool(
+            vec![market_a, market_b],
+            vec![7 * _1_10, _1_10, _1_10, _1_10],
+        );
+        let leg_a = Asset::CategoricalOutcome(market_a, 0);
+        let leg_b = Asset::CategoricalOutcome(market_b, 0);
+        let amount_in = _1;
+
+        let amount_out = NeoSwaps::calculate_buy_amount_out(market_a, leg_a, amount_in).unwrap();
+        let free_base_before = AssetManager::free_balance(BASE_ASSET, &CHARLIE);
+
+        assert_ok!(HybridRouter::buy_combinatorial(
+            RuntimeOrigin::signed(CHARLIE),
+            vec![leg_a, leg_b],
+            Some(pool_id),
+            4,
+            amount_in,
+            amount_out,
+            fuel(),
+        ));
+
+        let position_ab = position(&[(market_a, vec![true, false]), (market_b, vec![true, false])]);
+        let position_a_not_b =
+            position(&[(market_a, vec![true, false]), (market_b, vec![false, true])]);
+        assert_eq!(AssetManager::free_balance(position_ab, &CHARLIE), amount_out);
+        // The complement was sold back to the combinatorial pool.
+        assert_eq!(AssetManager::free_balance(position_a_not_b, &CHARLIE), 0);
+        let amount_refunded =
+            AssetManager::free_balance(BASE_ASSET, &CHARLIE) + amount_in - free_base_before;
+        assert!(amount_refunded > 0);
+        System::assert_last_event(
+            Event::CombinatorialBuyExecuted {
+                who: CHARLIE,
+                legs: vec![leg_a, leg_b],
+                route: CombinatorialRoute::SplitLeg(market_a),
+                asset_out: position_ab,
+                amount_in,
+                amount_out,
+                amount_refunded,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn buy_combinatorial_fails_on_zero_amount() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            HybridRouter::buy_combinatorial(
+                RuntimeOrigin::signed(CHARLIE),
+                vec![Asset::CategoricalOutcome(0, 0), Asset::CategoricalOutcome(1, 0)],
+                None,
+                0,
+                0,
+                0,
+                fuel(),
+            ),
+            Error::<Runtime>::AmountIsZero
+        );
+    });
+}
+
+#[test_case(vec![Asset::CategoricalOutcome(0, 0)]; "single_leg")]
+#[test_case(vec![Asset::CategoricalOutcome(0, 0), Asset::CategoricalOutcome(0, 1)]; "duplicate_market")]
+#[test_case(vec![Asset::CategoricalOutcome(0, 0), Asset::Ztg]; "not_an_outcome")]
+#[test_case(vec![Asset::CategoricalOutcome(0, 0), Asset::CategoricalOutcome(1, 2)]; "invalid_index")]
+#[test_case(
+    vec![
+        Asset::CategoricalOutcome(0, 0),
+        Asset::CategoricalOutcome(1, 0),
+        Asset::CategoricalOutcome(2, 0),
+        Asset::CategoricalOutcome(3, 0),
+        Asset::CategoricalOutcome(4, 0),
+    ];
+    "too_many_legs"
+)]
+fn buy_combinatorial_fails_on_invalid_legs(legs: Vec<Asset<MarketId>>) {
+    ExtBuilder::default().build().execute_with(|| {
+        for _ in 0..5 {
+            create_market(ALICE, BASE_ASSET, MarketType::Categorical(2), ScoringRule::AmmCdaHybrid);
+        }
+        assert_noop!(
+            HybridRouter::buy_combinatorial(
+                RuntimeOrigin::signed(CHARLIE),
+                legs,
+                None,
+                0,
+                _1,
+                0,
+                fuel(),
+            ),
+            Error::<Runtime>::InvalidLegs
+        );
+    });
+}
+
+#[test]
+fn buy_combinatorial_fails_if_position_not_in_pool() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_a =
+            create_market(ALICE, BASE_ASSET, MarketType::Categorical(2), ScoringRule::AmmCdaHybrid);
+        let market_b =
+            create_market(ALICE, BASE_ASSET, MarketType::Categorical(2), ScoringRule::AmmCdaHybrid);
+        let market_c =
+            create_market(ALICE, BASE_ASSET, MarketType::Categorical(2), ScoringRule::AmmCdaHybrid);
+        let pool_id =
+            deploy_combinatorial_pool(vec![market_a, market_b], vec![_1_4, _1_4, _1_4, _1_4]);
+        assert_noop!(
+            HybridRouter::buy_combinatorial(
+                RuntimeOrigin::signed(CHARLIE),
+                vec![
+                    Asset::CategoricalOutcome(market_a, 0),
+                    Asset::CategoricalOutcome(market_c, 0)
+                ],
+                Some(pool_id),
+                4,
+                _1,
+                0,
+                fuel(),
+            ),
+            Error::<Runtime>::PositionNotInPool
+        );
+    });
+}
+
+#[test_case(true, 3)]
+#[test_case(true, 0)]
+#[test_case(false, 4)]
+fn buy_combinatorial_fails_on_pool_asset_count_mismatch(with_pool: bool, pool_asset_count: u16) {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_a =
+            create_market(ALICE, BASE_ASSET, MarketType::Categorical(2), ScoringRule::AmmCdaHybrid);
+        let market_b =
+            create_market(ALICE, BASE_ASSET, MarketType::Categorical(2), ScoringRule::AmmCdaHybrid);
+        let pool_id =
+            deploy_combinatorial_pool(vec![market_a, market_b], vec![_1_4, _1_4, _1_4, _1_4]);
+        assert_noop!(
+            HybridRouter::buy_combinatorial(
+                RuntimeOrigin::signed(CHARLIE),
+                vec![
+                    Asset::CategoricalOutcome(market_a, 0),
+                    Asset::CategoricalOutcome(market_b, 0)
+                ],
+                with_pool.then_some(pool_id),
+                pool_asset_count,
+                _1,
+                0,
+                fuel(),
+            ),
+            Error::<Runtime>::AssetCountMismatch
+        );
+    });
+}
+
+#[test]
+fn buy_combinatorial_fails_if_no_route_is_available() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_a =
+            create_market(ALICE, BASE_ASSET, MarketType::Categorical(2), ScoringRule::AmmCdaHybrid);
+        let market_b =
+            create_market(ALICE, BASE_ASSET, MarketType::Categorical(2), ScoringRule::AmmCdaHybrid);
+        assert_noop!(
+            HybridRouter::buy_combinatorial(
+                RuntimeOrigin::signed(CHARLIE),
+                vec![
+                    Asset::CategoricalOutcome(market_a, 0),
+                    Asset::CategoricalOutcome(market_b, 0)
+                ],
+                None,
+                0,
+                _1,
+                0,
+                fuel(),
+            ),
+            Error::<Runtime>::NoRouteAvailable
+        );
+    });
+}
+
+#[test]
+fn buy_combinatorial_fails_if_amount_out_is_below_min() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_a = create_market_and_deploy_pool(
+            ALICE,
+            BASE_ASSET,
+            MarketType::Categorical(2),
+            _10,
+            vec![_1_2, _1_2],
+            CENT,
+        );
+        let market_b = create_market_and_deploy_pool(
+            ALICE,
+            BASE_ASSET,
+            MarketType::Categorical(2),
+            _10,
+            vec![_1_2, _1_2],
+            CENT,
+        );
+        let leg_a = Asset::CategoricalOutcome(market_a, 0);
+        let leg_b = Asset::CategoricalOutcome(market_b, 0);
+        let amount_in = _1;
+        let amount_out = NeoSwaps::calculate_buy_amount_out(market_a, leg_a, amount_in).unwrap();
+        assert_noop!(
+            HybridRouter::buy_combinatorial(
+                RuntimeOrigin::signed(CHARLIE),
+                vec![leg_a, leg_b],
+                None,
+                0,
+                amount_in,
+                amount_out + 1,
+                fuel(),
+            ),
+            Error::<Runtime>::AmountOutBelowMin
+        );
+    });
+}
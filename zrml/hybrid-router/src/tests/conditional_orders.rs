@@ -0,0 +1,438 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use frame_support::{
+    pallet_prelude::Weight,
+    traits::{Get, Hooks},
+};
+use orml_traits::MultiReservableCurrency;
+
+const KEEPER_FEE: BalanceOf<Runtime> = CENT;
+
+fn create_market_with_pool() -> MarketIdOf<Runtime> {
+    create_market_and_deploy_pool(
+        ALICE,
+        BASE_ASSET,
+        MarketType::Categorical(2),
+        _10,
+        vec![_1_2, _1_2],
+        CENT,
+    )
+}
+
+fn place_order(
+    market_id: MarketIdOf<Runtime>,
+    kind: ConditionalOrderKind,
+    trigger_price: BalanceOf<Runtime>,
+) -> ConditionalOrderId {
+    let asset = Asset::CategoricalOutcome(market_id, 0);
+    assert_ok!(AssetManager::deposit(asset, &CHARLIE, _1));
+    assert_ok!(AssetManager::deposit(BASE_ASSET, &CHARLIE, KEEPER_FEE));
+    let order_id = NextConditionalOrderId::<Runtime>::get();
+    assert_ok!(HybridRouter::place_conditional_order(
+        RuntimeOrigin::signed(CHARLIE),
+        market_id,
+        asset,
+        _1,
+        kind,
+        trigger_price,
+        _1_10,
+    ));
+    order_id
+}
+
+#[test]
+fn place_conditional_order_reserves_funds() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_with_pool();
+        let asset = Asset::CategoricalOutcome(market_id, 0);
+        assert_ok!(AssetManager::deposit(asset, &CHARLIE, _2));
+        assert_ok!(AssetManager::deposit(BASE_ASSET, &CHARLIE, KEEPER_FEE));
+        let base_free_before = AssetManager::free_balance(BASE_ASSET, &CHARLIE);
+
+        assert_ok!(HybridRouter::place_conditional_order(
+            RuntimeOrigin::signed(CHARLIE),
+            market_id,
+            asset,
+            _2,
+            ConditionalOrderKind::StopLoss,
+            _1_4,
+            _1_10,
+        ));
+
+        let order = ConditionalOrder {
+            owner: CHARLIE,
+            market_id,
+            asset,
+            amount: _2,
+            kind: ConditionalOrderKind::StopLoss,
+            trigger_price: _1_4,
+            min_price: _1_10,
+            keeper_fee: KEEPER_FEE,
+        };
+        System::assert_last_event(
+            Event::ConditionalOrderPlaced { order_id: 0, order: order.clone() }.into(),
+        );
+        assert_eq!(ConditionalOrders::<Runtime>::get(0), Some(order));
+        assert_eq!(NextConditionalOrderId::<Runtime>::get(), 1);
+        assert_eq!(AssetManager::free_balance(asset, &CHARLIE), 0);
+        assert_eq!(AssetManager::reserved_balance(asset, &CHARLIE), _2);
+        assert_eq!(AssetManager::free_balance(BASE_ASSET, &CHARLIE), base_free_before - KEEPER_FEE);
+        assert_eq!(AssetManager::reserved_balance(BASE_ASSET, &CHARLIE), KEEPER_FEE);
+    });
+}
+
+#[test]
+fn place_conditional_order_fails_on_asset_not_in_market() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_with_pool();
+        assert_noop!(
+            HybridRouter::place_conditional_order(
+                RuntimeOrigin::signed(CHARLIE),
+                market_id,
+                BASE_ASSET,
+                _1,
+                ConditionalOrderKind::StopLoss,
+                _1_4,
+                _1_10,
+            ),
+            Error::<Runtime>::AssetNotInMarket
+        );
+    });
+}
+
+#[test]
+fn place_conditional_order_fails_on_inactive_market() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_with_pool();
+        assert_ok!(MarketCommons::mutate_market(&market_id, |market| {
+            market.status = MarketStatus::Closed;
+            Ok(())
+        }));
+        assert_noop!(
+            HybridRouter::place_conditional_order(
+                RuntimeOrigin::signed(CHARLIE),
+                market_id,
+                Asset::CategoricalOutcome(market_id, 0),
+                _1,
+                ConditionalOrderKind::StopLoss,
+                _1_4,
+                _1_10,
+            ),
+            Error::<Runtime>::MarketNotActive
+        );
+    });
+}
+
+#[test]
+fn place_conditional_order_fails_on_trigger_price_above_one() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_with_pool();
+        assert_noop!(
+            HybridRouter::place_conditional_order(
+                RuntimeOrigin::signed(CHARLIE),
+                market_id,
+                Asset::CategoricalOutcome(market_id, 0),
+                _1,
+                ConditionalOrderKind::TakeProfit,
+                BASE + 1,
+                _1_10,
+            ),
+            Error::<Runtime>::PriceLimitTooHigh
+        );
+    });
+}
+
+#[test]
+fn place_conditional_order_fails_if_market_has_too_many_orders() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_with_pool();
+        let max_orders = <Runtime as Config>::MaxConditionalOrdersPerMarket::get();
+        for _ in 0..max_orders {
+            place_order(market_id, ConditionalOrderKind::StopLoss, _1_4);
+        }
+        let asset = Asset::CategoricalOutcome(market_id, 0);
+        assert_ok!(AssetManager::deposit(asset, &CHARLIE, _1));
+        assert_ok!(AssetManager::deposit(BASE_ASSET, &CHARLIE, KEEPER_FEE));
+        assert_noop!(
+            HybridRouter::place_conditional_order(
+                RuntimeOrigin::signed(CHARLIE),
+                market_id,
+                asset,
+                _1,
+                ConditionalOrderKind::StopLoss,
+                _1_4,
+                _1_10,
+            ),
+            Error::<Runtime>::TooManyConditionalOrders
+        );
+    });
+}
+
+#[test]
+fn cancel_conditional_order_unreserves_funds() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_with_pool();
+        let asset = Asset::CategoricalOutcome(market_id, 0);
+        let base_free_before = AssetManager::free_balance(BASE_ASSET, &CHARLIE);
+        let order_id = place_order(market_id, ConditionalOrderKind::StopLoss, _1_4);
+
+        assert_ok!(HybridRouter::cancel_conditional_order(
+            RuntimeOrigin::signed(CHARLIE),
+            order_id
+        ));
+
+        System::assert_last_event(Event::ConditionalOrderCancelled { order_id }.into());
+        assert!(!ConditionalOrders::<Runtime>::contains_key(order_id));
+        assert_eq!(AssetManager::free_balance(asset, &CHARLIE), _1);
+        assert_eq!(AssetManager::reserved_balance(asset, &CHARLIE), 0);
+        assert_eq!(AssetManager::free_balance(BASE_ASSET, &CHARLIE), base_free_before + KEEPER_FEE);
+        assert_eq!(AssetManager::reserved_balance(BASE_ASSET, &CHARLIE), 0);
+        assert!(!MarketIdToConditionalOrderIds::<Runtime>::contains_key(market_id));
+    });
+}
+
+#[test]
+fn cancel_conditional_order_fails_if_sender_is_not_owner() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_with_pool();
+        let order_id = place_order(market_id, ConditionalOrderKind::StopLoss, _1_4);
+        assert_noop!(
+            HybridRouter::cancel_conditional_order(RuntimeOrigin::signed(DAVE), order_id),
+            Error::<Runtime>::NotConditionalOrderOwner
+        );
+    });
+}
+
+#[test]
+fn cancel_conditional_order_fails_on_unknown_order() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            HybridRouter::cancel_conditional_order(RuntimeOrigin::signed(CHARLIE), 0),
+            Error::<Runtime>::ConditionalOrderNotFound
+        );
+    });
+}
+
+#[test]
+fn execute_conditional_order_sells_and_pays_keeper() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_with_pool();
+        let asset = Asset::CategoricalOutcome(market_id, 0);
+        let owner_free_before = AssetManager::free_balance(BASE_ASSET, &CHARLIE);
+        let keeper_free_before = AssetManager::free_balance(BASE_ASSET, &DAVE);
+        let order_id = place_order(market_id, ConditionalOrderKind::StopLoss, _3_4);
+
+        assert_ok!(HybridRouter::execute_conditional_order(
+            RuntimeOrigin::signed(DAVE),
+            order_id,
+            2
+        ));
+
+        let amount_out = AssetManager::free_balance(BASE_ASSET, &CHARLIE) - owner_free_before;
+        assert!(amount_out > 0);
+        System::assert_last_event(
+            Event::ConditionalOrderExecuted {
+                order_id,
+                keeper: Some(DAVE),
+                amount_in: _1,
+                amount_out,
+            }
+            .into(),
+        );
+        assert!(!ConditionalOrders::<Runtime>::contains_key(order_id));
+        assert_eq!(AssetManager::free_balance(asset, &CHARLIE), 0);
+        assert_eq!(AssetManager::reserved_balance(asset, &CHARLIE), 0);
+        assert_eq!(AssetManager::reserved_balance(BASE_ASSET, &CHARLIE), 0);
+        assert_eq!(AssetManager::free_balance(BASE_ASSET, &DAVE), keeper_free_before + KEEPER_FEE);
+    });
+}
+
+#[test]
+fn execute_conditional_order_fails_if_not_triggered() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_with_pool();
+        let stop_loss = place_order(market_id, ConditionalOrderKind::StopLoss, _1_4);
+        let take_profit = place_order(market_id, ConditionalOrderKind::TakeProfit, _3_4);
+        assert_noop!(
+            HybridRouter::execute_conditional_order(RuntimeOrigin::signed(DAVE), stop_loss, 2),
+            Error::<Runtime>::ConditionalOrderNotTriggered
+        );
+        assert_noop!(
+            HybridRouter::execute_conditional_order(RuntimeOrigin::signed(DAVE), take_profit, 2),
+            Error::<Runtime>::ConditionalOrderNotTriggered
+        );
+    });
+}
+
+#[test]
+fn execute_conditional_order_fails_on_asset_count_mismatch() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_with_pool();
+        let order_id = place_order(market_id, ConditionalOrderKind::StopLoss, _3_4);
+        assert_noop!(
+            HybridRouter::execute_conditional_order(RuntimeOrigin::signed(DAVE), order_id, 3),
+            Error::<Runtime>::AssetCountMismatch
+        );
+    });
+}
+
+#[test]
+fn execute_conditional_order_cancels_order_of_inactive_market() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_with_pool();
+        let asset = Asset::CategoricalOutcome(market_id, 0);
+        let keeper_free_before = AssetManager::free_balance(BASE_ASSET, &DAVE);
+        let order_id = place_order(market_id, ConditionalOrderKind::StopLoss, _1_4);
+        assert_ok!(MarketCommons::mutate_market(&market_id, |market| {
+            market.status = MarketStatus::Closed;
+            Ok(())
+        }));
+
+        assert_ok!(HybridRouter::execute_conditional_order(
+            RuntimeOrigin::signed(DAVE),
+            order_id,
+            2
+        ));
+
+        System::assert_last_event(Event::ConditionalOrderCancelled { order_id }.into());
+        assert_eq!(AssetManager::free_balance(asset, &CHARLIE), _1);
+        assert_eq!(AssetManager::free_balance(BASE_ASSET, &DAVE), keeper_free_before + KEEPER_FEE);
+    });
+}
+
+#[test]
+fn on_idle_executes_triggered_orders_only() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_with_pool();
+        let triggered = place_order(market_id, ConditionalOrderKind::StopLoss, _3_4);
+        let not_triggered = place_order(market_id, ConditionalOrderKind::TakeProfit, _3_4);
+        let owner_free_before = AssetManager::free_balance(BASE_ASSET, &CHARLIE);
+
+        HybridRouter::on_idle(1, Weight::MAX);
+
+        assert!(!ConditionalOrders::<Runtime>::contains_key(triggered));
+        assert!(ConditionalOrders::<Runtime>::contains_key(not_triggered));
+        // The keeper fee of an order executed in `on_idle` is returned to the owner.
+        assert!(AssetManager::free_balance(BASE_ASSET, &CHARLIE) > owner_free_before + KEEPER_FEE);
+        assert_eq!(AssetManager::reserved_balance(BASE_ASSET, &CHARLIE), KEEPER_FEE);
+        assert_eq!(ConditionalOrdersCursor::<Runtime>::get(), None);
+    });
+}
+
+#[test]
+fn on_idle_cancels_orders_of_inactive_markets() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_with_pool();
+        let asset = Asset::CategoricalOutcome(market_id, 0);
+        let order_id = place_order(market_id, ConditionalOrderKind::TakeProfit, _3_4);
+        assert_ok!(MarketCommons::mutate_market(&market_id, |market| {
+            market.status = MarketStatus::Closed;
+            Ok(())
+        }));
+
+        HybridRouter::on_idle(1, Weight::MAX);
+
+        System::assert_last_event(Event::ConditionalOrderCancelled { order_id }.into());
+        assert!(!ConditionalOrders::<Runtime>::contains_key(order_id));
+        assert_eq!(AssetManager::free_balance(asset, &CHARLIE), _1);
+        assert_eq!(AssetManager::reserved_balance(BASE_ASSET, &CHARLIE), 0);
+    });
+}
+
+#[test]
+fn on_idle_evaluates_at_most_max_conditional_order_checks() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_with_pool();
+        let max_checks = <Runtime as Config>::MaxConditionalOrderChecks::get();
+        let order_count = max_checks + 1;
+        for _ in 0..order_count {
+            place_order(market_id, ConditionalOrderKind::StopLoss, _3_4);
+        }
+
+        HybridRouter::on_idle(1, Weight::MAX);
+        assert_eq!(ConditionalOrders::<Runtime>::iter().count(), 1);
+        assert!(ConditionalOrdersCursor::<Runtime>::get().is_some());
+
+        HybridRouter::on_idle(2, Weight::MAX);
+        assert_eq!(ConditionalOrders::<Runtime>::iter().count(), 0);
+        assert_eq!(ConditionalOrdersCursor::<Runtime>::get(), None);
+    });
+}
+
+#[test]
+fn on_idle_charges_worst_case_weight_of_each_order() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_with_pool();
+        let order_id = place_order(market_id, ConditionalOrderKind::StopLoss, _3_4);
+        let db_weight = <Runtime as frame_system::Config>::DbWeight::get();
+        let required =
+            db_weight.reads_writes(3, 1).saturating_add(HybridRouter::conditional_order_weight(2));
+
+        let remaining_weight = required.saturating_sub(Weight::from_parts(1, 0));
+        assert!(HybridRouter::on_idle(1, remaining_weight).all_lte(remaining_weight));
+        assert!(ConditionalOrders::<Runtime>::contains_key(order_id));
+
+        assert_eq!(HybridRouter::on_idle(2, required), required);
+        assert!(!ConditionalOrders::<Runtime>::contains_key(order_id));
+    });
+}
+
+#[test]
+fn on_idle_does_nothing_without_enough_weight() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_with_pool();
+        let order_id = place_order(market_id, ConditionalOrderKind::StopLoss, _3_4);
+
+        assert_eq!(HybridRouter::on_idle(1, Weight::zero()), Weight::zero());
+
+        assert!(ConditionalOrders::<Runtime>::contains_key(order_id));
+    });
+}
+
+#[test]
+fn closing_market_cancels_conditional_orders() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = create_market_with_pool();
+        let asset = Asset::CategoricalOutcome(market_id, 0);
+        let base_free_before = AssetManager::free_balance(BASE_ASSET, &CHARLIE);
+        let stop_loss = place_order(market_id, ConditionalOrderKind::StopLoss, _1_4);
+        let take_profit = place_order(market_id, ConditionalOrderKind::TakeProfit, _3_4);
+        assert_eq!(
+            MarketIdToConditionalOrderIds::<Runtime>::get(market_id).into_inner(),
+            vec![stop_loss, take_profit]
+        );
+
+        assert_ok!(PredictionMarkets::admin_move_market_to_closed(
+            RuntimeOrigin::signed(SUDO),
+            market_id
+        ));
+
+        System::assert_has_event(Event::ConditionalOrderCancelled { order_id: stop_loss }.into());
+        System::assert_has_event(Event::ConditionalOrderCancelled { order_id: take_profit }.into());
+        assert_eq!(ConditionalOrders::<Runtime>::iter().count(), 0);
+        assert!(!MarketIdToConditionalOrderIds::<Runtime>::contains_key(market_id));
+        assert_eq!(AssetManager::free_balance(asset, &CHARLIE), _2);
+        assert_eq!(AssetManager::reserved_balance(asset, &CHARLIE), 0);
+        assert_eq!(
+            AssetManager::free_balance(BASE_ASSET, &CHARLIE),
+            base_free_before + 2 * KEEPER_FEE
+        );
+        assert_eq!(AssetManager::reserved_balance(BASE_ASSET, &CHARLIE), 0);
+    });
+}
@@ -36,7 +36,10 @@ use zrml_neo_swaps::Event as NeoSwapsEvent;
 use zrml_orderbook::Orders;
 
 mod buy;
+mod buy_combinatorial;
+mod conditional_orders;
 mod sell;
+mod simulate_trade;
 
 #[cfg(not(feature = "parachain"))]
 const BASE_ASSET: Asset<MarketId> = Asset::Ztg;
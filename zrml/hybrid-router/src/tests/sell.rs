@@ -983,3 +983,174 @@ fn sell_fails_if_market_does_not_exist() {
         );
     });
 }
+
+#[test]
+fn sell_discovers_orders_if_none_are_specified() {
+    ExtBuilder::default().build().execute_with(|| {
+        let liquidity = _10;
+        let spot_prices = vec![_1_2, _1_2];
+        let swap_fee = CENT;
+        let asset_count = 2u16;
+        let market_id = create_market_and_deploy_pool(
+            ALICE,
+            BASE_ASSET,
+            MarketType::Categorical(asset_count),
+            liquidity,
+            spot_prices.clone(),
+            swap_fee,
+        );
+
+        let asset = Asset::CategoricalOutcome(market_id, 0);
+        let order_taker_amount = _1;
+        assert_ok!(AssetManager::deposit(BASE_ASSET, &CHARLIE, _2));
+        for order_maker_amount in [_1_10, _3_4, _3_4] {
+            assert_ok!(Orderbook::place_order(
+                RuntimeOrigin::signed(CHARLIE),
+                market_id,
+                BASE_ASSET,
+                order_maker_amount,
+                asset,
+                order_taker_amount,
+            ));
+        }
+
+        let amount_in = _2;
+        assert_ok!(AssetManager::deposit(asset, &ALICE, amount_in));
+
+        let min_price = _1_4.saturated_into::<BalanceOf<Runtime>>();
+        assert_ok!(HybridRouter::sell(
+            RuntimeOrigin::signed(ALICE),
+            market_id,
+            asset_count,
+            asset,
+            amount_in,
+            min_price,
+            vec![],
+            Strategy::ImmediateOrCancel,
+        ));
+
+        // The expensive orders are filled, the order below `min_price` is left untouched.
+        let order_ids = Orders::<Runtime>::iter().map(|(k, _)| k).collect::<Vec<_>>();
+        assert_eq!(order_ids, vec![0]);
+        assert_eq!(
+            Orders::<Runtime>::get(0).unwrap(),
+            Order {
+                market_id,
+                maker: CHARLIE,
+                maker_asset: BASE_ASSET,
+                maker_amount: _1_10,
+                taker_asset: asset,
+                taker_amount: order_taker_amount,
+            }
+        );
+        assert_eq!(AssetManager::free_balance(asset, &CHARLIE), 2 * order_taker_amount);
+    });
+}
+
+#[test]
+fn sell_fails_if_fill_or_kill_strategy_applied() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0;
+        let mut market = market_mock::<Runtime>(MARKET_CREATOR);
+        let required_asset_count = match &market.market_type {
+            MarketType::Scalar(_) => panic!("Categorical market type is expected!"),
+            MarketType::Categorical(categories) => *categories,
+        };
+        market.status = MarketStatus::Active;
+        Markets::<Runtime>::insert(market_id, market);
+
+        let asset = Asset::CategoricalOutcome(market_id, 0);
+        let amount_in = _10;
+        assert_ok!(AssetManager::deposit(asset, &ALICE, amount_in));
+        let min_price = _1_2.saturated_into::<BalanceOf<Runtime>>();
+        assert_noop!(
+            HybridRouter::sell(
+                RuntimeOrigin::signed(ALICE),
+                market_id,
+                required_asset_count,
+                asset,
+                amount_in,
+                min_price,
+                vec![],
+                Strategy::FillOrKill,
+            ),
+            Error::<Runtime>::FillOrKillStrategyApplied
+        );
+    });
+}
+
+#[test]
+fn sell_with_post_only_strategy_places_order_without_taking_liquidity() {
+    ExtBuilder::default().build().execute_with(|| {
+        let asset_count = 2u16;
+        let market_id = create_market_and_deploy_pool(
+            ALICE,
+            BASE_ASSET,
+            MarketType::Categorical(asset_count),
+            _10,
+            vec![_1_2, _1_2],
+            CENT,
+        );
+
+        let asset = Asset::CategoricalOutcome(market_id, 0);
+        let amount_in = _1;
+        assert_ok!(AssetManager::deposit(asset, &BOB, amount_in));
+        let min_price = _3_4.saturated_into::<BalanceOf<Runtime>>();
+        assert_ok!(HybridRouter::sell(
+            RuntimeOrigin::signed(BOB),
+            market_id,
+            asset_count,
+            asset,
+            amount_in,
+            min_price,
+            vec![],
+            Strategy::PostOnly,
+        ));
+
+        let orders = Orders::<Runtime>::iter().map(|(_, o)| o).collect::<Vec<_>>();
+        assert_eq!(
+            orders,
+            vec![Order {
+                market_id,
+                maker: BOB,
+                maker_asset: asset,
+                maker_amount: amount_in,
+                taker_asset: BASE_ASSET,
+                taker_amount: _3_4,
+            }]
+        );
+    });
+}
+
+#[test]
+fn sell_with_post_only_strategy_fails_if_amm_would_be_used() {
+    ExtBuilder::default().build().execute_with(|| {
+        let asset_count = 2u16;
+        let market_id = create_market_and_deploy_pool(
+            ALICE,
+            BASE_ASSET,
+            MarketType::Categorical(asset_count),
+            _10,
+            vec![_1_2, _1_2],
+            CENT,
+        );
+
+        let asset = Asset::CategoricalOutcome(market_id, 0);
+        let amount_in = _1;
+        assert_ok!(AssetManager::deposit(asset, &BOB, amount_in));
+        let min_price = _1_4.saturated_into::<BalanceOf<Runtime>>();
+        assert_noop!(
+            HybridRouter::sell(
+                RuntimeOrigin::signed(BOB),
+                market_id,
+                asset_count,
+                asset,
+                amount_in,
+                min_price,
+                vec![],
+                Strategy::PostOnly,
+            ),
+            Error::<Runtime>::PostOnlyOrderWouldTakeLiquidity
+        );
+    });
+}
@@ -0,0 +1,195 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use zeitgeist_primitives::math::fixed::FixedDiv;
+
+#[test]
+fn simulate_buy_matches_buy_without_changing_state() {
+    ExtBuilder::default().build().execute_with(|| {
+        let asset_count = 2u16;
+        let market_id = create_market_and_deploy_pool(
+            ALICE,
+            BASE_ASSET,
+            MarketType::Categorical(asset_count),
+            _10,
+            vec![_1_2 - _1_100, _1_2 + _1_100],
+            CENT,
+        );
+        let asset = Asset::CategoricalOutcome(market_id, 0);
+        assert_ok!(AssetManager::deposit(asset, &CHARLIE, _12));
+        assert_ok!(Orderbook::place_order(
+            RuntimeOrigin::signed(CHARLIE),
+            market_id,
+            asset,
+            _12,
+            BASE_ASSET,
+            _6,
+        ));
+        let order_ids = Orders::<Runtime>::iter().map(|(k, _)| k).collect::<Vec<_>>();
+        let amount_in = _2;
+        let max_price = _3_4;
+
+        let balance_before = AssetManager::free_balance(BASE_ASSET, &DAVE);
+        let event_count = System::events().len();
+        let simulation = HybridRouter::simulate_trade(
+            TxType::Buy,
+            DAVE,
+            market_id,
+            asset_count,
+            asset,
+            amount_in,
+            max_price,
+            order_ids.clone(),
+            Strategy::LimitOrder,
+        )
+        .unwrap();
+        assert_eq!(AssetManager::free_balance(BASE_ASSET, &DAVE), balance_before);
+        assert_eq!(AssetManager::free_balance(asset, &DAVE), 0);
+        assert_eq!(Orders::<Runtime>::iter().count(), 1);
+        assert_eq!(System::events().len(), event_count);
+
+        assert_eq!(simulation.amm_trades.len(), 1);
+        assert_eq!(simulation.order_trades.len(), 1);
+        assert_eq!(simulation.amount_in, amount_in);
+        assert_eq!(simulation.remaining, 0);
+        assert_eq!(
+            simulation.average_price,
+            simulation.amount_in.bdiv_floor(simulation.amount_out).unwrap()
+        );
+        assert!(simulation.average_price <= max_price);
+
+        assert_ok!(HybridRouter::buy(
+            RuntimeOrigin::signed(DAVE),
+            market_id,
+            asset_count,
+            asset,
+            amount_in,
+            max_price,
+            order_ids,
+            Strategy::LimitOrder,
+        ));
+        System::assert_last_event(
+            Event::HybridRouterExecuted {
+                tx_type: TxType::Buy,
+                who: DAVE,
+                market_id,
+                price_limit: max_price,
+                asset_in: BASE_ASSET,
+                amount_in,
+                asset_out: asset,
+                amount_out: simulation.amount_out,
+                external_fee_amount: simulation.external_fee_amount,
+                swap_fee_amount: simulation.swap_fee_amount,
+            }
+            .into(),
+        );
+        assert_eq!(AssetManager::free_balance(asset, &DAVE), simulation.amount_out);
+    });
+}
+
+#[test]
+fn simulate_sell_returns_amount_left_over_for_limit_order() {
+    ExtBuilder::default().build().execute_with(|| {
+        let asset_count = 2u16;
+        let market_id = create_market_and_deploy_pool(
+            ALICE,
+            BASE_ASSET,
+            MarketType::Categorical(asset_count),
+            _10,
+            vec![_1_2, _1_2],
+            CENT,
+        );
+        let asset = Asset::CategoricalOutcome(market_id, 0);
+        let amount_in = _5;
+        assert_ok!(AssetManager::deposit(asset, &CHARLIE, amount_in));
+
+        let simulation = HybridRouter::simulate_trade(
+            TxType::Sell,
+            CHARLIE,
+            market_id,
+            asset_count,
+            asset,
+            amount_in,
+            _3_4,
+            vec![],
+            Strategy::LimitOrder,
+        )
+        .unwrap();
+
+        assert_eq!(
+            simulation,
+            TradeSimulation {
+                order_trades: vec![],
+                amm_trades: vec![],
+                amount_in: 0,
+                amount_out: 0,
+                external_fee_amount: 0,
+                swap_fee_amount: 0,
+                remaining: amount_in,
+                average_price: 0,
+            }
+        );
+        assert_eq!(AssetManager::free_balance(asset, &CHARLIE), amount_in);
+        assert_eq!(Orders::<Runtime>::iter().count(), 0);
+    });
+}
+
+#[test]
+fn simulate_trade_returns_errors_of_the_trade() {
+    ExtBuilder::default().build().execute_with(|| {
+        let asset_count = 2u16;
+        let market_id = create_market_and_deploy_pool(
+            ALICE,
+            BASE_ASSET,
+            MarketType::Categorical(asset_count),
+            _10,
+            vec![_1_2, _1_2],
+            CENT,
+        );
+        let asset = Asset::CategoricalOutcome(market_id, 0);
+
+        assert_eq!(
+            HybridRouter::simulate_trade(
+                TxType::Buy,
+                ALICE,
+                market_id,
+                asset_count,
+                asset,
+                0,
+                _3_4,
+                vec![],
+                Strategy::LimitOrder,
+            ),
+            Err(Error::<Runtime>::AmountIsZero.into())
+        );
+        assert_eq!(
+            HybridRouter::simulate_trade(
+                TxType::Buy,
+                ALICE,
+                market_id,
+                asset_count,
+                asset,
+                _1,
+                _1_4,
+                vec![],
+                Strategy::ImmediateOrCancel,
+            ),
+            Err(Error::<Runtime>::CancelStrategyApplied.into())
+        );
+    });
+}
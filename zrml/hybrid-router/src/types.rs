@@ -20,7 +20,14 @@ use alloc::vec::Vec;
 use frame_support::pallet_prelude::*;
 use scale_info::TypeInfo;
 use sp_runtime::{traits::Zero, DispatchError};
-use zeitgeist_primitives::math::checked_ops_res::{CheckedAddRes, CheckedSubRes};
+use zeitgeist_primitives::{
+    hybrid_router_api_types::{AmmTrade, OrderbookTrade},
+    math::checked_ops_res::{CheckedAddRes, CheckedSubRes},
+    types::Asset,
+};
+
+/// The identifier of a conditional order.
+pub type ConditionalOrderId = u128;
 
 /// Represents the strategy used when placing an order in a trading environment.
 #[derive(
@@ -42,8 +49,42 @@ pub enum Strategy {
     /// Partially fulfills the order if possible, placing the remainder in the order book. Favors
     /// achieving a specific price rather than immediate execution.
     LimitOrder,
+    /// The trade is rolled back unless the entire amount is executed within the price limit.
+    ///
+    /// This is an alias of `ImmediateOrCancel`: trades are transactional, so an
+    /// immediate-or-cancel trade which can't be executed fully is rolled back as a whole, too.
+    /// The only difference is the error, which is `FillOrKillStrategyApplied` instead of
+    /// `CancelStrategyApplied`.
+    FillOrKill,
+    /// Never takes liquidity. The entire amount is placed in the order book as a limit order, and
+    /// the trade is rolled back if that order would be matched by an existing order or the AMM.
+    /// The order book is always queried for matching orders, even if orders are specified.
+    PostOnly,
+}
+
+/// The route used to buy a combinatorial position.
+#[derive(
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Encode,
+    Decode,
+    RuntimeDebug,
+    MaxEncodedLen,
+    TypeInfo,
+)]
+pub enum CombinatorialRoute<MarketId> {
+    /// The position was bought from the combinatorial pool with the specified ID.
+    CombinatorialPool(MarketId),
+    /// The leg of the specified market was bought from the market's pool and the outcome tokens
+    /// were split into the position using the markets of the other legs.
+    SplitLeg(MarketId),
 }
 
+/// The type of a trade routed by the hybrid router.
 #[derive(Clone, Copy, Debug, Decode, Encode, PartialEq, TypeInfo)]
 pub enum TxType {
     Buy,
@@ -51,13 +92,13 @@ pub enum TxType {
 }
 
 #[derive(Clone, Debug, Decode, Encode, PartialEq, TypeInfo)]
-pub enum Trade<'a, T: Config> {
+pub(crate) enum Trade<'a, T: Config> {
     Orderbook(&'a OrderTradeOf<T>),
     Amm(AmmTradeOf<T>),
 }
 
 #[derive(Clone, Copy, Debug, Decode, Encode, PartialEq, TypeInfo)]
-pub struct TradeEventInfo<T: Config> {
+pub(crate) struct TradeEventInfo<T: Config> {
     pub amount_out: BalanceOf<T>,
     pub external_fee_amount: BalanceOf<T>,
     pub swap_fee_amount: BalanceOf<T>,
@@ -93,8 +134,86 @@ impl<T: Config> TradeEventInfo<T> {
     }
 }
 
-pub struct OrderAmmTradesInfo<T: Config> {
+pub(crate) struct OrderAmmTradesInfo<T: Config> {
     pub remaining: BalanceOf<T>,
     pub order_trades: Vec<OrderTradeOf<T>>,
     pub amm_trades: Vec<AmmTradeOf<T>>,
 }
+
+/// The result of a trade executed by the hybrid router, as returned by `simulate_trade`.
+#[derive(Clone, Debug, Decode, Encode, Eq, PartialEq, TypeInfo)]
+pub struct TradeSimulation<AccountId, Balance> {
+    /// The fills of orders from the order book, in the order they were executed.
+    pub order_trades: Vec<OrderbookTrade<AccountId, Balance>>,
+    /// The trades executed on the AMM, including fees.
+    pub amm_trades: Vec<AmmTrade<Balance>>,
+    /// The part of the amount in which was executed against the order book and the AMM.
+    pub amount_in: Balance,
+    /// The amount received by the trader, after fees are deducted.
+    pub amount_out: Balance,
+    /// The external fee amount paid in the base asset.
+    pub external_fee_amount: Balance,
+    /// The swap fee amount paid in the base asset.
+    pub swap_fee_amount: Balance,
+    /// The part of the amount in which was left over for a limit order.
+    pub remaining: Balance,
+    /// The average price of the executed part of the trade in units of the base asset, fees
+    /// included. Zero if nothing was executed.
+    pub average_price: Balance,
+}
+
+/// The condition under which a conditional order is executed.
+#[derive(
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Encode,
+    Decode,
+    RuntimeDebug,
+    MaxEncodedLen,
+    TypeInfo,
+)]
+pub enum ConditionalOrderKind {
+    /// The order is executed once the spot price drops to or below the trigger price.
+    StopLoss,
+    /// The order is executed once the spot price rises to or above the trigger price.
+    TakeProfit,
+}
+
+/// An order which sells `amount` units of `asset` through the hybrid router once the spot price
+/// of `asset` crosses `trigger_price`.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct ConditionalOrder<AccountId, Balance, MarketId> {
+    /// The account which placed the order and receives the proceeds.
+    pub owner: AccountId,
+    /// The ID of the market on which `asset` is traded.
+    pub market_id: MarketId,
+    /// The outcome to sell. It is held by the pallet until the order is executed or cancelled.
+    pub asset: Asset<MarketId>,
+    /// The amount of `asset` to sell.
+    pub amount: Balance,
+    /// Whether the order is a stop-loss or a take-profit order.
+    pub kind: ConditionalOrderKind,
+    /// The spot price at which the order is triggered.
+    pub trigger_price: Balance,
+    /// The minimum price to sell at once the order is triggered.
+    pub min_price: Balance,
+    /// The amount of the market's base asset paid to the keeper who executes the order.
+    pub keeper_fee: Balance,
+}
+
+impl<AccountId, Balance, MarketId> ConditionalOrder<AccountId, Balance, MarketId>
+where
+    Balance: PartialOrd,
+{
+    /// Returns `true` if the order is triggered at the specified `spot_price`.
+    pub fn is_triggered(&self, spot_price: &Balance) -> bool {
+        match self.kind {
+            ConditionalOrderKind::StopLoss => *spot_price <= self.trigger_price,
+            ConditionalOrderKind::TakeProfit => *spot_price >= self.trigger_price,
+        }
+    }
+}
@@ -53,6 +53,12 @@ use frame_support::{traits::Get, weights::Weight};
 pub trait WeightInfoZeitgeist {
     fn buy(n: u32, o: u32) -> Weight;
     fn sell(n: u32, o: u32) -> Weight;
+    fn buy_combinatorial(n: u32, m: u32) -> Weight;
+    fn buy_combinatorial_with_pool(n: u32, a: u32, m: u32) -> Weight;
+    fn place_conditional_order() -> Weight;
+    fn cancel_conditional_order() -> Weight;
+    fn execute_conditional_order(n: u32, o: u32) -> Weight;
+    fn on_market_close(o: u32) -> Weight;
 }
 
 /// Weight functions for zrml_hybrid_router (automatically generated)
@@ -60,6 +66,8 @@ pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
     /// Storage: `MarketCommons::Markets` (r:1 w:0)
     /// Proof: `MarketCommons::Markets` (`max_values`: None, `max_size`: Some(708), added: 3183, mode: `MaxEncodedLen`)
+    /// Storage: `Orderbook::OrderPriceIndex` (r:1 w:1)
+    /// Proof: `Orderbook::OrderPriceIndex` (`max_values`: None, `max_size`: Some(4857), added: 7332, mode: `MaxEncodedLen`)
     /// Storage: `Orderbook::Orders` (r:10 w:11)
     /// Proof: `Orderbook::Orders` (`max_values`: None, `max_size`: Some(170), added: 2645, mode: `MaxEncodedLen`)
     /// Storage: `NeoSwaps::MarketIdToPoolId` (r:1 w:0)
@@ -81,19 +89,15 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
     /// The range of component `n` is `[2, 16]`.
     /// The range of component `o` is `[0, 10]`.
     fn buy(n: u32, o: u32) -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `1365 + n * (185 ±0) + o * (497 ±0)`
-        //  Estimated: `156294 + n * (2612 ±1) + o * (3765 ±2)`
-        // Minimum execution time: 1_680_157 nanoseconds.
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_hybrid_router` to replace it.
         Weight::from_parts(1_692_777_000, 156294)
-            // Standard Error: 7_631_140
             .saturating_add(Weight::from_parts(216_139_282, 0).saturating_mul(n.into()))
-            // Standard Error: 12_515_510
             .saturating_add(Weight::from_parts(934_018_842, 0).saturating_mul(o.into()))
-            .saturating_add(T::DbWeight::get().reads(8))
+            .saturating_add(T::DbWeight::get().reads(9))
             .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
             .saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(o.into())))
-            .saturating_add(T::DbWeight::get().writes(7))
+            .saturating_add(T::DbWeight::get().writes(8))
             .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
             .saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(o.into())))
             .saturating_add(Weight::from_parts(0, 2612).saturating_mul(n.into()))
@@ -101,6 +105,8 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
     }
     /// Storage: `MarketCommons::Markets` (r:1 w:0)
     /// Proof: `MarketCommons::Markets` (`max_values`: None, `max_size`: Some(708), added: 3183, mode: `MaxEncodedLen`)
+    /// Storage: `Orderbook::OrderPriceIndex` (r:1 w:1)
+    /// Proof: `Orderbook::OrderPriceIndex` (`max_values`: None, `max_size`: Some(4857), added: 7332, mode: `MaxEncodedLen`)
     /// Storage: `Tokens::Accounts` (r:21 w:21)
     /// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(137), added: 2612, mode: `MaxEncodedLen`)
     /// Storage: `Orderbook::Orders` (r:10 w:11)
@@ -122,22 +128,200 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
     /// The range of component `n` is `[2, 10]`.
     /// The range of component `o` is `[0, 10]`.
     fn sell(n: u32, o: u32) -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `1441 + n * (183 ±0) + o * (339 ±0)`
-        //  Estimated: `156294 + n * (2612 ±0) + o * (3724 ±0)`
-        // Minimum execution time: 1_208_585 nanoseconds.
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_hybrid_router` to replace it.
         Weight::from_parts(1_216_017_000, 156294)
-            // Standard Error: 7_988_806
             .saturating_add(Weight::from_parts(219_491_877, 0).saturating_mul(n.into()))
-            // Standard Error: 8_211_337
             .saturating_add(Weight::from_parts(688_160_713, 0).saturating_mul(o.into()))
-            .saturating_add(T::DbWeight::get().reads(8))
+            .saturating_add(T::DbWeight::get().reads(9))
             .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
             .saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(o.into())))
+            .saturating_add(T::DbWeight::get().writes(8))
+            .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(o.into())))
+            .saturating_add(Weight::from_parts(0, 2612).saturating_mul(n.into()))
+            .saturating_add(Weight::from_parts(0, 3724).saturating_mul(o.into()))
+    }
+    /// Storage: `MarketCommons::Markets` (r:4 w:0)
+    /// Proof: `MarketCommons::Markets` (`max_values`: None, `max_size`: Some(708), added: 3183, mode: `MaxEncodedLen`)
+    /// Storage: `NeoSwaps::MarketIdToPoolId` (r:4 w:0)
+    /// Proof: `NeoSwaps::MarketIdToPoolId` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+    /// Storage: `NeoSwaps::Pools` (r:4 w:1)
+    /// Proof: `NeoSwaps::Pools` (`max_values`: None, `max_size`: Some(152829), added: 155304, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:6 w:6)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+    /// Storage: `Tokens::Accounts` (r:12 w:12)
+    /// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(137), added: 2612, mode: `MaxEncodedLen`)
+    /// Storage: `Tokens::TotalIssuance` (r:10 w:10)
+    /// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+    /// Storage: `CombinatorialTokens::LegacyConversions` (r:1 w:1)
+    /// Proof: `CombinatorialTokens::LegacyConversions` (`max_values`: None, `max_size`: Some(1058), added: 3533, mode: `MaxEncodedLen`)
+    /// The range of component `n` is `[2, 4]`.
+    /// The range of component `m` is `[32, 64]`.
+    fn buy_combinatorial(n: u32, m: u32) -> Weight {
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_hybrid_router` to replace it.
+        Weight::from_parts(1_402_518_000, 156294)
+            .saturating_add(Weight::from_parts(2_304_873_410, 0).saturating_mul(n.into()))
+            .saturating_add(Weight::from_parts(61_407_214, 0).saturating_mul(m.into()))
+            .saturating_add(
+                Weight::from_parts(1_212_440_000, 0).saturating_mul(n.saturating_mul(n).into()),
+            )
+            .saturating_add(T::DbWeight::get().reads(8))
+            .saturating_add(T::DbWeight::get().reads((10_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes(8))
+            .saturating_add(T::DbWeight::get().writes((9_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 10395).saturating_mul(n.into()))
+    }
+    /// Storage: `MarketCommons::Markets` (r:4 w:0)
+    /// Proof: `MarketCommons::Markets` (`max_values`: None, `max_size`: Some(708), added: 3183, mode: `MaxEncodedLen`)
+    /// Storage: `NeoSwaps::MarketIdToPoolId` (r:4 w:0)
+    /// Proof: `NeoSwaps::MarketIdToPoolId` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+    /// Storage: `NeoSwaps::Pools` (r:5 w:1)
+    /// Proof: `NeoSwaps::Pools` (`max_values`: None, `max_size`: Some(152829), added: 155304, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:7 w:7)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+    /// Storage: `Tokens::Accounts` (r:140 w:140)
+    /// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(137), added: 2612, mode: `MaxEncodedLen`)
+    /// Storage: `Tokens::TotalIssuance` (r:138 w:138)
+    /// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+    /// Storage: `CombinatorialTokens::LegacyConversions` (r:1 w:1)
+    /// Proof: `CombinatorialTokens::LegacyConversions` (`max_values`: None, `max_size`: Some(1058), added: 3533, mode: `MaxEncodedLen`)
+    /// The range of component `n` is `[2, 4]`.
+    /// The range of component `a` is `[2, 16]`.
+    /// The range of component `m` is `[32, 64]`.
+    fn buy_combinatorial_with_pool(n: u32, a: u32, m: u32) -> Weight {
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_hybrid_router` to replace it.
+        Weight::from_parts(1_896_204_000, 311598)
+            .saturating_add(Weight::from_parts(2_304_873_410, 0).saturating_mul(n.into()))
+            .saturating_add(Weight::from_parts(61_407_214, 0).saturating_mul(m.into()))
+            .saturating_add(
+                Weight::from_parts(1_212_440_000, 0).saturating_mul(n.saturating_mul(n).into()),
+            )
+            .saturating_add(
+                Weight::from_parts(623_718_000, 0).saturating_mul(n.saturating_mul(a).into()),
+            )
+            .saturating_add(T::DbWeight::get().reads(9))
+            .saturating_add(T::DbWeight::get().reads((10_u64).saturating_mul(n.into())))
+            .saturating_add(
+                T::DbWeight::get().reads((4_u64).saturating_mul(n.saturating_mul(a).into())),
+            )
+            .saturating_add(T::DbWeight::get().writes(9))
+            .saturating_add(T::DbWeight::get().writes((9_u64).saturating_mul(n.into())))
+            .saturating_add(
+                T::DbWeight::get().writes((4_u64).saturating_mul(n.saturating_mul(a).into())),
+            )
+            .saturating_add(Weight::from_parts(0, 10395).saturating_mul(n.into()))
+            .saturating_add(Weight::from_parts(0, 5144).saturating_mul(n.saturating_mul(a).into()))
+    }
+    /// Storage: `MarketCommons::Markets` (r:1 w:0)
+    /// Proof: `MarketCommons::Markets` (`max_values`: None, `max_size`: Some(708), added: 3183, mode: `MaxEncodedLen`)
+    /// Storage: `Tokens::Reserves` (r:1 w:1)
+    /// Proof: `Tokens::Reserves` (`max_values`: None, `max_size`: Some(1290), added: 3765, mode: `MaxEncodedLen`)
+    /// Storage: `Tokens::Accounts` (r:1 w:1)
+    /// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(137), added: 2612, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Reserves` (r:1 w:1)
+    /// Proof: `Balances::Reserves` (`max_values`: None, `max_size`: Some(1249), added: 3724, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+    /// Storage: `HybridRouter::NextConditionalOrderId` (r:1 w:1)
+    /// Proof: `HybridRouter::NextConditionalOrderId` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+    /// Storage: `HybridRouter::ConditionalOrders` (r:0 w:1)
+    /// Proof: `HybridRouter::ConditionalOrders` (`max_values`: None, `max_size`: Some(142), added: 2617, mode: `MaxEncodedLen`)
+    /// Storage: `HybridRouter::MarketIdToConditionalOrderIds` (r:1 w:1)
+    /// Proof: `HybridRouter::MarketIdToConditionalOrderIds` (`max_values`: None, `max_size`: Some(278), added: 2753, mode: `MaxEncodedLen`)
+    fn place_conditional_order() -> Weight {
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_hybrid_router` to replace it.
+        Weight::from_parts(104_870_000, 4755)
+            .saturating_add(T::DbWeight::get().reads(7))
             .saturating_add(T::DbWeight::get().writes(7))
+    }
+    /// Storage: `HybridRouter::ConditionalOrders` (r:1 w:1)
+    /// Proof: `HybridRouter::ConditionalOrders` (`max_values`: None, `max_size`: Some(142), added: 2617, mode: `MaxEncodedLen`)
+    /// Storage: `MarketCommons::Markets` (r:1 w:0)
+    /// Proof: `MarketCommons::Markets` (`max_values`: None, `max_size`: Some(708), added: 3183, mode: `MaxEncodedLen`)
+    /// Storage: `Tokens::Reserves` (r:1 w:1)
+    /// Proof: `Tokens::Reserves` (`max_values`: None, `max_size`: Some(1290), added: 3765, mode: `MaxEncodedLen`)
+    /// Storage: `Tokens::Accounts` (r:1 w:1)
+    /// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(137), added: 2612, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Reserves` (r:1 w:1)
+    /// Proof: `Balances::Reserves` (`max_values`: None, `max_size`: Some(1249), added: 3724, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+    /// Storage: `HybridRouter::MarketIdToConditionalOrderIds` (r:1 w:1)
+    /// Proof: `HybridRouter::MarketIdToConditionalOrderIds` (`max_values`: None, `max_size`: Some(278), added: 2753, mode: `MaxEncodedLen`)
+    fn cancel_conditional_order() -> Weight {
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_hybrid_router` to replace it.
+        Weight::from_parts(97_351_000, 4755)
+            .saturating_add(T::DbWeight::get().reads(7))
+            .saturating_add(T::DbWeight::get().writes(6))
+    }
+    /// Storage: `HybridRouter::ConditionalOrders` (r:1 w:1)
+    /// Proof: `HybridRouter::ConditionalOrders` (`max_values`: None, `max_size`: Some(142), added: 2617, mode: `MaxEncodedLen`)
+    /// Storage: `MarketCommons::Markets` (r:1 w:0)
+    /// Proof: `MarketCommons::Markets` (`max_values`: None, `max_size`: Some(708), added: 3183, mode: `MaxEncodedLen`)
+    /// Storage: `NeoSwaps::MarketIdToPoolId` (r:1 w:0)
+    /// Proof: `NeoSwaps::MarketIdToPoolId` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+    /// Storage: `NeoSwaps::Pools` (r:1 w:1)
+    /// Proof: `NeoSwaps::Pools` (`max_values`: None, `max_size`: Some(152829), added: 155304, mode: `MaxEncodedLen`)
+    /// Storage: `Tokens::Reserves` (r:1 w:1)
+    /// Proof: `Tokens::Reserves` (`max_values`: None, `max_size`: Some(1290), added: 3765, mode: `MaxEncodedLen`)
+    /// Storage: `Orderbook::OrderPriceIndex` (r:1 w:1)
+    /// Proof: `Orderbook::OrderPriceIndex` (`max_values`: None, `max_size`: Some(4857), added: 7332, mode: `MaxEncodedLen`)
+    /// Storage: `Orderbook::Orders` (r:100 w:100)
+    /// Proof: `Orderbook::Orders` (`max_values`: None, `max_size`: Some(170), added: 2645, mode: `MaxEncodedLen`)
+    /// Storage: `Tokens::Accounts` (r:222 w:222)
+    /// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(137), added: 2612, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:103 w:103)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+    /// Storage: `Tokens::TotalIssuance` (r:20 w:20)
+    /// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Reserves` (r:100 w:100)
+    /// Proof: `Balances::Reserves` (`max_values`: None, `max_size`: Some(1249), added: 3724, mode: `MaxEncodedLen`)
+    /// Storage: `HybridRouter::MarketIdToConditionalOrderIds` (r:1 w:1)
+    /// Proof: `HybridRouter::MarketIdToConditionalOrderIds` (`max_values`: None, `max_size`: Some(278), added: 2753, mode: `MaxEncodedLen`)
+    /// The range of component `n` is `[2, 10]`.
+    /// The range of component `o` is `[0, 100]`.
+    fn execute_conditional_order(n: u32, o: u32) -> Weight {
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_hybrid_router` to replace it.
+        Weight::from_parts(1_313_368_000, 160049)
+            .saturating_add(Weight::from_parts(219_491_877, 0).saturating_mul(n.into()))
+            .saturating_add(Weight::from_parts(688_160_713, 0).saturating_mul(o.into()))
+            .saturating_add(T::DbWeight::get().reads(16))
+            .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(o.into())))
+            .saturating_add(T::DbWeight::get().writes(14))
             .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
             .saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(o.into())))
             .saturating_add(Weight::from_parts(0, 2612).saturating_mul(n.into()))
             .saturating_add(Weight::from_parts(0, 3724).saturating_mul(o.into()))
     }
+    /// Storage: `HybridRouter::MarketIdToConditionalOrderIds` (r:1 w:1)
+    /// Proof: `HybridRouter::MarketIdToConditionalOrderIds` (`max_values`: None, `max_size`: Some(278), added: 2753, mode: `MaxEncodedLen`)
+    /// Storage: `HybridRouter::ConditionalOrders` (r:16 w:16)
+    /// Proof: `HybridRouter::ConditionalOrders` (`max_values`: None, `max_size`: Some(142), added: 2617, mode: `MaxEncodedLen`)
+    /// Storage: `MarketCommons::Markets` (r:16 w:0)
+    /// Proof: `MarketCommons::Markets` (`max_values`: None, `max_size`: Some(708), added: 3183, mode: `MaxEncodedLen`)
+    /// Storage: `Tokens::Reserves` (r:16 w:16)
+    /// Proof: `Tokens::Reserves` (`max_values`: None, `max_size`: Some(1290), added: 3765, mode: `MaxEncodedLen`)
+    /// Storage: `Tokens::Accounts` (r:16 w:16)
+    /// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(137), added: 2612, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Reserves` (r:16 w:16)
+    /// Proof: `Balances::Reserves` (`max_values`: None, `max_size`: Some(1249), added: 3724, mode: `MaxEncodedLen`)
+    /// The range of component `o` is `[0, 16]`.
+    fn on_market_close(o: u32) -> Weight {
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_hybrid_router` to replace it.
+        Weight::from_parts(8_740_000, 3743)
+            .saturating_add(Weight::from_parts(86_590_000, 0).saturating_mul(o.into()))
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(o.into())))
+            .saturating_add(T::DbWeight::get().writes(1))
+            .saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(o.into())))
+            .saturating_add(Weight::from_parts(0, 3765).saturating_mul(o.into()))
+    }
 }
@@ -21,7 +21,10 @@ use super::*;
 use crate::{
     liquidity_tree::{traits::LiquidityTreeHelper, types::LiquidityTree},
     traits::{LiquiditySharesManager, PoolOperations, PoolStorage},
-    types::{DecisionMarketOracle, DecisionMarketOracleScoreboard},
+    types::{
+        DecisionMarketOracle, DecisionMarketOracleScoreboard, ScalarDecisionMarketLeg,
+        ScalarDecisionMarketOracle,
+    },
     AssetOf, BalanceOf, MarketIdOf, Pallet as NeoSwaps, Pools, MIN_SPOT_PRICE,
 };
 use alloc::{vec, vec::Vec};
@@ -715,6 +718,61 @@ mod benchmarks {
         }
     }
 
+    #[benchmark]
+    fn scalar_decision_market_oracle_update() {
+        let alice: T::AccountId = whitelisted_caller();
+        let base_asset = Asset::Ztg;
+        let asset_count = 2;
+        let pass_market_id = create_market_and_deploy_pool::<T>(
+            alice.clone(),
+            base_asset,
+            asset_count,
+            (100 * _100).saturated_into(),
+        );
+        let reject_market_id = create_market_and_deploy_pool::<T>(
+            alice,
+            base_asset,
+            asset_count,
+            (100 * _100).saturated_into(),
+        );
+
+        // The bounds of the welfare metric are read from the market type.
+        for market_id in [pass_market_id, reject_market_id] {
+            assert_ok!(T::MarketCommons::mutate_market(&market_id, |market| {
+                market.market_type = MarketType::Scalar(0..=_100);
+                Ok(())
+            }));
+        }
+
+        let pass_assets = Pools::<T>::get(pass_market_id).unwrap().assets();
+        let pass = ScalarDecisionMarketLeg::<T>::new(
+            pass_market_id,
+            pass_market_id,
+            pass_assets[0],
+            pass_assets[1],
+        );
+        let reject_assets = Pools::<T>::get(reject_market_id).unwrap().assets();
+        let reject = ScalarDecisionMarketLeg::<T>::new(
+            reject_market_id,
+            reject_market_id,
+            reject_assets[0],
+            reject_assets[1],
+        );
+
+        let scoreboard = DecisionMarketOracleScoreboard::<T>::new(
+            Zero::zero(),
+            Zero::zero(),
+            Zero::zero(),
+            Zero::zero(),
+        );
+        let mut oracle = ScalarDecisionMarketOracle::<T>::new(pass, reject, scoreboard);
+
+        #[block]
+        {
+            let _ = oracle.update(1u8.into());
+        }
+    }
+
     impl_benchmark_test_suite!(
         NeoSwaps,
         crate::mock::ExtBuilder::default().build(),
@@ -23,3 +23,5 @@ pub(crate) const EXP_NUMERICAL_LIMIT: u128 = 10;
 pub(crate) const LN_NUMERICAL_LIMIT: u128 = BASE / 10;
 /// The maximum number of assets allowed in a pool.
 pub(crate) const MAX_ASSETS: u16 = 128;
+/// The fuel used to split collateral into the positions of a decision market oracle's pool.
+pub(crate) const DECISION_MARKET_FUEL: u32 = 16;
@@ -87,7 +87,8 @@ mod pallet {
         },
         traits::{
             CombinatorialTokensApi, CombinatorialTokensFuel, CombinatorialTokensUnsafeApi,
-            CompleteSetOperationsApi, DeployPoolApi, DistributeFees, HybridRouterAmmApi,
+            CompleteSetOperationsApi, DeployCombinatorialPoolApi, DeployPoolApi, DistributeFees,
+            HybridRouterAmmApi, HybridRouterComboAmmApi,
         },
         types::{Asset, MarketStatus, ScoringRule},
     };
@@ -387,6 +388,9 @@ mod pallet {
 
         /// This function is not allowed to be called for this type of pool.
         InvalidPoolType,
+
+        /// The market is not a scalar market.
+        MarketNotScalar,
     }
 
     #[derive(Decode, Encode, Eq, PartialEq, PalletError, RuntimeDebug, TypeInfo)]
@@ -733,7 +737,9 @@ mod pallet {
                 asset_count_real.try_into().map_err(|_| Error::<T>::NarrowingConversion)?;
             ensure!(asset_count == asset_count_real_u16, Error::<T>::IncorrectAssetCount);
 
-            Self::do_combo_buy(who, pool_id, buy, sell, amount_in, min_amount_out)
+            Self::do_combo_buy(who, pool_id, buy, sell, amount_in, min_amount_out)?;
+
+            Ok(())
         }
 
         /// Cancel a combinatorial bet on the specified pool.
@@ -798,7 +804,9 @@ mod pallet {
                 amount_buy,
                 amount_keep,
                 min_amount_out,
-            )
+            )?;
+
+            Ok(())
         }
 
         /// Deploy a combinatorial pool for the specified markets and provide liquidity.
@@ -821,6 +829,8 @@ mod pallet {
         /// x_2 & y_2 & z_1
         /// x_2 & y_2 & z_2
         ///
+        /// Scalar markets contribute two outcomes, `Long` and `Short` (in this order).
+        ///
         /// The sender specifies a vector of `spot_prices` for the assets of the new pool, in the
         /// order as described above.
         ///
@@ -1395,7 +1405,7 @@ mod pallet {
             sell: Vec<AssetOf<T>>,
             amount_in: BalanceOf<T>,
             min_amount_out: BalanceOf<T>,
-        ) -> DispatchResult {
+        ) -> Result<AmmTradeOf<T>, DispatchError> {
             ensure!(amount_in != Zero::zero(), Error::<T>::ZeroAmount);
 
             <Self as PoolStorage>::try_mutate_pool(&pool_id, |pool| {
@@ -1485,7 +1495,7 @@ mod pallet {
                     external_fee_amount,
                 });
 
-                Ok(())
+                Ok(AmmTrade { amount_in, amount_out, swap_fee_amount, external_fee_amount })
             })
         }
 
@@ -1500,7 +1510,7 @@ mod pallet {
             amount_buy: BalanceOf<T>,
             amount_keep: BalanceOf<T>,
             min_amount_out: BalanceOf<T>,
-        ) -> DispatchResult {
+        ) -> Result<AmmTradeOf<T>, DispatchError> {
             ensure!(amount_buy != Zero::zero(), Error::<T>::ZeroAmount);
 
             if keep.is_empty() {
@@ -1622,7 +1632,12 @@ mod pallet {
                     external_fee_amount,
                 });
 
-                Ok(())
+                Ok(AmmTrade {
+                    amount_in: amount_buy,
+                    amount_out: amount_out_minus_fees,
+                    swap_fee_amount,
+                    external_fee_amount,
+                })
             })
         }
 
@@ -1802,6 +1817,35 @@ mod pallet {
         }
     }
 
+    impl<T: Config> DeployCombinatorialPoolApi for Pallet<T> {
+        type AccountId = T::AccountId;
+        type Balance = BalanceOf<T>;
+        type Fuel = FuelOf<T>;
+        type MarketId = MarketIdOf<T>;
+        type PoolId = T::PoolId;
+
+        fn deploy_combinatorial_pool(
+            who: Self::AccountId,
+            market_ids: Vec<Self::MarketId>,
+            amount: Self::Balance,
+            spot_prices: Vec<Self::Balance>,
+            swap_fee: Self::Balance,
+            fuel: Self::Fuel,
+        ) -> Result<Self::PoolId, DispatchError> {
+            let pool_id = <Self as PoolStorage>::next_pool_id();
+            Self::do_deploy_combinatorial_pool(
+                who,
+                market_ids,
+                amount,
+                spot_prices,
+                swap_fee,
+                fuel,
+            )?;
+
+            Ok(pool_id)
+        }
+    }
+
     impl<T: Config> Pallet<T> {
         fn amount_including_fee_surplus(
             amount: BalanceOf<T>,
@@ -1822,6 +1866,20 @@ mod pallet {
             swap_fee.checked_add_res(&external_fee_fractional)
         }
 
+        /// Returns the amount left after deducting the swap fees and external fees of `pool` from
+        /// `amount` without distributing the fees.
+        fn amount_minus_fees(
+            pool: &PoolOf<T>,
+            amount: BalanceOf<T>,
+        ) -> Result<BalanceOf<T>, DispatchError> {
+            let mut total_fees = pool.swap_fee.bmul(amount)?;
+            for &market_id in pool.pool_type.iter_market_ids() {
+                let external_fees = T::ExternalFees::fee_percentage(market_id).mul_floor(amount);
+                total_fees = total_fees.checked_add_res(&external_fees)?;
+            }
+            amount.checked_sub(&total_fees).ok_or(Error::<T>::Unexpected.into())
+        }
+
         fn match_failure(error: DispatchError) -> ApiError<AmmSoftFail> {
             let spot_price_too_low: DispatchError =
                 Error::<T>::NumericalLimits(NumericalLimitsError::SpotPriceTooLow).into();
@@ -1880,6 +1938,22 @@ mod pallet {
             Ok(buy_amount_plus_fees)
         }
 
+        fn calculate_buy_amount_out(
+            market_id: Self::MarketId,
+            asset_out: Self::Asset,
+            amount_in: Self::Balance,
+        ) -> Result<Self::Balance, DispatchError> {
+            let pool = <Self as PoolStorage>::get(market_id)?;
+            ensure!(matches!(pool.pool_type, PoolType::Standard(_)), Error::<T>::InvalidPoolType);
+            ensure!(pool.contains(&asset_out), Error::<T>::AssetNotFound);
+            let amount_in_minus_fees = Self::amount_minus_fees(&pool, amount_in)?;
+            let buy = vec![asset_out];
+            let sell = pool.assets_complement(&buy);
+            let swap_amount_out =
+                pool.calculate_swap_amount_out_for_buy(buy, sell, amount_in_minus_fees)?;
+            swap_amount_out.checked_add_res(&amount_in_minus_fees)
+        }
+
         fn buy(
             who: Self::AccountId,
             market_id: Self::MarketId,
@@ -1911,4 +1985,71 @@ mod pallet {
                 .map_err(Self::match_failure)
         }
     }
+
+    impl<T: Config> HybridRouterComboAmmApi for Pallet<T> {
+        type AccountId = T::AccountId;
+        type Asset = AssetOf<T>;
+        type Balance = BalanceOf<T>;
+        type PoolId = T::PoolId;
+
+        fn combo_pool_assets(pool_id: Self::PoolId) -> Result<Vec<Self::Asset>, DispatchError> {
+            let pool = <Self as PoolStorage>::get(pool_id)?;
+            ensure!(
+                matches!(pool.pool_type, PoolType::Combinatorial(_)),
+                Error::<T>::InvalidPoolType
+            );
+            Ok(pool.assets())
+        }
+
+        fn calculate_combo_buy_amount_out(
+            pool_id: Self::PoolId,
+            buy: Vec<Self::Asset>,
+            sell: Vec<Self::Asset>,
+            amount_in: Self::Balance,
+        ) -> Result<Self::Balance, DispatchError> {
+            let pool = <Self as PoolStorage>::get(pool_id)?;
+            ensure!(
+                matches!(pool.pool_type, PoolType::Combinatorial(_)),
+                Error::<T>::InvalidPoolType
+            );
+            let amount_in_minus_fees = Self::amount_minus_fees(&pool, amount_in)?;
+            let swap_amount_out =
+                pool.calculate_swap_amount_out_for_buy(buy, sell, amount_in_minus_fees)?;
+            swap_amount_out.checked_add_res(&amount_in_minus_fees)
+        }
+
+        fn combo_buy(
+            who: Self::AccountId,
+            pool_id: Self::PoolId,
+            buy: Vec<Self::Asset>,
+            sell: Vec<Self::Asset>,
+            amount_in: Self::Balance,
+            min_amount_out: Self::Balance,
+        ) -> Result<AmmTradeOf<T>, DispatchError> {
+            Self::do_combo_buy(who, pool_id, buy, sell, amount_in, min_amount_out)
+        }
+
+        #[allow(clippy::too_many_arguments)]
+        fn combo_sell(
+            who: Self::AccountId,
+            pool_id: Self::PoolId,
+            buy: Vec<Self::Asset>,
+            keep: Vec<Self::Asset>,
+            sell: Vec<Self::Asset>,
+            amount_buy: Self::Balance,
+            amount_keep: Self::Balance,
+            min_amount_out: Self::Balance,
+        ) -> Result<AmmTradeOf<T>, DispatchError> {
+            Self::do_combo_sell(
+                who,
+                pool_id,
+                buy,
+                keep,
+                sell,
+                amount_buy,
+                amount_keep,
+                min_amount_out,
+            )
+        }
+    }
 }
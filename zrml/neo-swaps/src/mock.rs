@@ -32,6 +32,7 @@ use frame_support::{
         tokens::{PayFromAccount, UnityAssetBalanceConversion},
         Contains, Everything, NeverEnsureOrigin,
     },
+    weights::Weight,
     Blake2_256,
 };
 use frame_system::{mocking::MockBlock, EnsureRoot, EnsureSignedBy};
@@ -44,30 +45,33 @@ use zeitgeist_primitives::{
     constants::{
         base_multiples::*,
         mock::{
-            AddOutcomePeriod, AggregationPeriod, AppealBond, AppealPeriod, AuthorizedPalletId,
-            BlockHashCount, BlocksPerYear, CloseEarlyBlockPeriod, CloseEarlyDisputeBond,
-            CloseEarlyProtectionBlockPeriod, CloseEarlyProtectionTimeFramePeriod,
-            CloseEarlyRequestBond, CloseEarlyTimeFramePeriod, CombinatorialTokensPalletId,
+            AddOutcomePeriod, AggregationPeriod, AppealBond, AppealPeriod, AuthorityAppealBond,
+            AuthorityAppealPeriod, AuthorizedPalletId, BlockHashCount, BlocksPerYear,
+            CloseEarlyBlockPeriod, CloseEarlyDisputeBond, CloseEarlyProtectionBlockPeriod,
+            CloseEarlyProtectionTimeFramePeriod, CloseEarlyRequestBond, CloseEarlyTimeFramePeriod,
+            CombinatorialTokensMaxRedeemSteps, CombinatorialTokensPalletId, ConvictionLockPeriod,
             CorrectionPeriod, CourtPalletId, ExistentialDeposit, ExistentialDeposits,
             GdVotingPeriod, GetNativeCurrencyId, GlobalDisputeLockId, GlobalDisputesPalletId,
-            InflationPeriod, LockId, MaxAppeals, MaxApprovals, MaxCourtParticipants, MaxCreatorFee,
-            MaxDelegations, MaxDisputeDuration, MaxDisputes, MaxEditReasonLen,
-            MaxGlobalDisputeVotes, MaxGracePeriod, MaxLiquidityTreeDepth, MaxLocks,
-            MaxMarketLifetime, MaxOracleDuration, MaxOwners, MaxRejectReasonLen, MaxReserves,
-            MaxSelectedDraws, MaxYearlyInflation, MinCategories, MinDisputeDuration, MinJurorStake,
-            MinOracleDuration, MinOutcomeVoteAmount, MinimumPeriod, NeoMaxSwapFee,
+            InflationPeriod, LockId, MarketSeriesBond, MarketTemplateBond, MaxAppeals,
+            MaxApprovals, MaxCommitteeMembers, MaxCourtParticipants, MaxCreatorFee, MaxDelegations,
+            MaxDisputeDuration, MaxDisputes, MaxEditReasonLen, MaxGlobalDisputeVotes,
+            MaxGracePeriod, MaxLiquidityTreeDepth, MaxLocks, MaxMarketLifetime,
+            MaxMarketSeriesRolloversPerBlock, MaxOracleDuration, MaxOwners, MaxRejectReasonLen,
+            MaxReserves, MaxSelectedDraws, MaxYearlyInflation, MinCategories, MinDisputeDuration,
+            MinJurorStake, MinOracleDuration, MinOutcomeVoteAmount, MinimumPeriod, NeoMaxSwapFee,
             NeoSwapsPalletId, OutsiderBond, PmPalletId, RemoveKeysLimit, RequestInterval,
-            TreasuryPalletId, VotePeriod, VotingOutcomeFee, BASE, CENT,
+            TreasuryPalletId, VotePeriod, VoterRewardPercentage, VotingOutcomeFee, BASE, CENT,
         },
     },
     math::fixed::FixedMul,
-    traits::{DeployPoolApi, DistributeFees},
+    traits::{DeployPoolApi, DistributeFees, OnMarketClose, OracleRegistryApi},
     types::{
         AccountIdTest, Amount, Balance, BasicCurrencyAdapter, CombinatorialId, CurrencyId, Hash,
         MarketId, Moment,
     },
 };
 use zrml_combinatorial_tokens::types::{CryptographicIdManager, Fuel};
+use zrml_global_disputes::types::VotingWeightMode;
 use zrml_neo_swaps::BalanceOf;
 
 #[cfg(feature = "parachain")]
@@ -134,6 +138,47 @@ impl DeployPoolApi for DeployPoolNoop {
     }
 }
 
+pub struct OnMarketCloseNoop;
+
+impl OnMarketClose for OnMarketCloseNoop {
+    type MarketId = MarketId;
+
+    fn on_market_close(_market_id: &Self::MarketId) -> Weight {
+        Weight::zero()
+    }
+
+    fn on_market_close_max_weight() -> Weight {
+        Weight::zero()
+    }
+}
+
+pub struct OracleRegistryNoop;
+
+impl OracleRegistryApi for OracleRegistryNoop {
+    type AccountId = AccountIdTest;
+    type Balance = Balance;
+
+    fn fallback_oracle(_oracle: &Self::AccountId) -> Option<Self::AccountId> {
+        None
+    }
+
+    fn reputation(_oracle: &Self::AccountId) -> Option<u32> {
+        None
+    }
+
+    fn on_accurate_report(_oracle: &Self::AccountId, _market_stake: Self::Balance) -> Weight {
+        Weight::zero()
+    }
+
+    fn on_missed_report(_oracle: &Self::AccountId, _market_stake: Self::Balance) -> Weight {
+        Weight::zero()
+    }
+
+    fn on_overturned_report(_oracle: &Self::AccountId, _market_stake: Self::Balance) -> Weight {
+        Weight::zero()
+    }
+}
+
 pub struct ExternalFees<T, F>(PhantomData<T>, PhantomData<F>);
 
 impl<T: crate::Config, F> DistributeFees for ExternalFees<T, F>
@@ -241,10 +286,15 @@ impl zrml_prediction_markets::Config for Runtime {
     type MaxGracePeriod = MaxGracePeriod;
     type MaxOracleDuration = MaxOracleDuration;
     type MaxMarketLifetime = MaxMarketLifetime;
+    type MaxMarketSeriesRolloversPerBlock = MaxMarketSeriesRolloversPerBlock;
     type MinCategories = MinCategories;
     type MaxEditReasonLen = MaxEditReasonLen;
+    type MarketTemplateBond = MarketTemplateBond;
+    type MarketSeriesBond = MarketSeriesBond;
     type MaxRejectReasonLen = MaxRejectReasonLen;
     type OracleBond = OracleBond;
+    type OnMarketClose = OnMarketCloseNoop;
+    type OracleRegistry = OracleRegistryNoop;
     type OutsiderBond = OutsiderBond;
     type PalletId = PmPalletId;
     type RejectOrigin = EnsureSignedBy<Sudo, AccountIdTest>;
@@ -257,6 +307,8 @@ impl zrml_prediction_markets::Config for Runtime {
 }
 
 impl zrml_authorized::Config for Runtime {
+    type AppealBond = AuthorityAppealBond;
+    type AppealPeriod = AuthorityAppealPeriod;
     type AuthorizedDisputeResolutionOrigin =
         EnsureSignedBy<AuthorizedDisputeResolutionUser, AccountIdTest>;
     type CorrectionPeriod = CorrectionPeriod;
@@ -264,6 +316,7 @@ impl zrml_authorized::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type DisputeResolution = zrml_prediction_markets::Pallet<Runtime>;
     type MarketCommons = MarketCommons;
+    type MaxCommitteeMembers = MaxCommitteeMembers;
     type PalletId = AuthorizedPalletId;
     type WeightInfo = zrml_authorized::weights::WeightInfo<Runtime>;
 }
@@ -273,7 +326,9 @@ impl zrml_combinatorial_tokens::Config for Runtime {
     type BenchmarkHelper = NoopCombinatorialTokensBenchmarkHelper<Balance, MarketId>;
     type CombinatorialIdManager = CryptographicIdManager<MarketId, Blake2_256>;
     type Fuel = Fuel;
+    type LegacyMarketAccount = zrml_prediction_markets::MarketAccount<Runtime>;
     type MarketCommons = MarketCommons;
+    type MaxRedeemSteps = CombinatorialTokensMaxRedeemSteps;
     type MultiCurrency = AssetManager;
     type Payout = PredictionMarkets;
     type RuntimeEvent = RuntimeEvent;
@@ -390,20 +445,29 @@ impl pallet_timestamp::Config for Runtime {
     type WeightInfo = ();
 }
 
+parameter_types! {
+    pub const DefaultVotingWeightMode: VotingWeightMode = VotingWeightMode::TokenWeighted;
+}
+
 impl zrml_global_disputes::Config for Runtime {
     type AddOutcomePeriod = AddOutcomePeriod;
+    type ConvictionLockPeriod = ConvictionLockPeriod;
     type RuntimeEvent = RuntimeEvent;
     type DisputeResolution = zrml_prediction_markets::Pallet<Runtime>;
     type MarketCommons = MarketCommons;
     type Currency = Balances;
+    type DefaultVotingWeightMode = DefaultVotingWeightMode;
     type GlobalDisputeLockId = GlobalDisputeLockId;
     type GlobalDisputesPalletId = GlobalDisputesPalletId;
+    type IdentityVerification = Everything;
     type MaxGlobalDisputeVotes = MaxGlobalDisputeVotes;
     type MaxOwners = MaxOwners;
     type MinOutcomeVoteAmount = MinOutcomeVoteAmount;
     type RemoveKeysLimit = RemoveKeysLimit;
     type GdVotingPeriod = GdVotingPeriod;
+    type VoterRewardPercentage = VoterRewardPercentage;
     type VotingOutcomeFee = VotingOutcomeFee;
+    type VotingWeightModeOrigin = EnsureRoot<AccountIdTest>;
     type WeightInfo = zrml_global_disputes::weights::WeightInfo<Runtime>;
 }
 
@@ -220,6 +220,45 @@ fn deploy_combinatorial_pool_works_with_multiple_markets() {
     });
 }
 
+#[test]
+fn deploy_combinatorial_pool_orders_scalar_positions_long_before_short() {
+    ExtBuilder::default().build().execute_with(|| {
+        let spot_prices = vec![_1_4, _1_4, _1_4, _1_4];
+        let (market_ids, pool_id) = create_markets_and_deploy_combinatorial_pool(
+            ALICE,
+            BASE_ASSET,
+            vec![MarketType::Categorical(2), MarketType::Scalar(0u128..=100u128)],
+            _10,
+            spot_prices,
+            CENT,
+        );
+        let pool = Pools::<Runtime>::get(pool_id).unwrap();
+
+        let mut expected = vec![];
+        for categorical_index_set in [vec![true, false], vec![false, true]] {
+            let parent_collection_id = CombinatorialTokens::collection_id_from_parent_collection(
+                None,
+                market_ids[0],
+                categorical_index_set,
+                Fuel::new(16, false),
+            )
+            .unwrap();
+            for scalar_position in [ScalarPosition::Long, ScalarPosition::Short] {
+                expected.push(
+                    CombinatorialTokens::scalar_position_from_parent_collection(
+                        Some(parent_collection_id),
+                        market_ids[1],
+                        scalar_position,
+                        Fuel::new(16, false),
+                    )
+                    .unwrap(),
+                );
+            }
+        }
+        assert_eq!(pool.assets(), expected);
+    });
+}
+
 #[test]
 fn deploy_combinatorial_pool_fails_on_incorrect_vec_len() {
     ExtBuilder::default().build().execute_with(|| {
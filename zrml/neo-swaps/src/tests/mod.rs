@@ -26,6 +26,7 @@ mod deploy_pool;
 mod exit;
 mod join;
 mod liquidity_tree_interactions;
+mod scalar_decision_market_oracle;
 mod sell;
 mod withdraw_fees;
 
@@ -0,0 +1,223 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::types::{
+    DecisionMarketOracleMetadata, DecisionMarketOracleScoreboard, ScalarDecisionMarketLeg,
+    ScalarDecisionMarketOracle, ScalarDecisionMarketOracleDeployer,
+    ScalarDecisionMarketOracleParams,
+};
+use test_case::test_case;
+use zeitgeist_primitives::{
+    traits::{FutarchyOracle, FutarchyOracleDeployer},
+    types::FutarchyOracleScore,
+};
+
+fn create_scalar_market_and_deploy_pool(
+    spot_prices: Vec<u128>,
+) -> ScalarDecisionMarketLeg<Runtime> {
+    let market_id = create_market_and_deploy_pool(
+        ALICE,
+        BASE_ASSET,
+        MarketType::Scalar(0..=_100),
+        _10,
+        spot_prices,
+        CENT,
+    );
+    let pool_id = MarketIdToPoolId::<Runtime>::get(market_id).unwrap();
+
+    ScalarDecisionMarketLeg::new(
+        pool_id,
+        market_id,
+        Asset::ScalarOutcome(market_id, ScalarPosition::Long),
+        Asset::ScalarOutcome(market_id, ScalarPosition::Short),
+    )
+}
+
+// The implied expected values are 60 (pass) and 20 (reject).
+#[test_case(_10, _1, true)]
+#[test_case(_40 - CENT, _2 - CENT, true)]
+#[test_case(_100, _1, false)]
+#[test_case(_10, _3, false)]
+fn update_compares_expected_values_of_combinatorial_positions(
+    price_margin_abs: u128,
+    price_margin_rel: u128,
+    expected: bool,
+) {
+    ExtBuilder::default().build().execute_with(|| {
+        let (market_ids, pool_id) = create_markets_and_deploy_combinatorial_pool(
+            ALICE,
+            BASE_ASSET,
+            vec![MarketType::Categorical(2), MarketType::Scalar(0..=_100)],
+            _10,
+            vec![_3_10, _2_10, _1_10, _4_10],
+            CENT,
+        );
+        let assets = Pools::<Runtime>::get(pool_id).unwrap().assets();
+        let pass = ScalarDecisionMarketLeg::new(pool_id, market_ids[1], assets[0], assets[1]);
+        let reject = ScalarDecisionMarketLeg::new(pool_id, market_ids[1], assets[2], assets[3]);
+        let scoreboard =
+            DecisionMarketOracleScoreboard::new(0, 1, price_margin_abs, price_margin_rel);
+        let mut oracle = ScalarDecisionMarketOracle::new(pass, reject, scoreboard);
+
+        oracle.update(1);
+
+        assert_eq!(oracle.evaluate().1, expected);
+        let (pass_score, reject_score) = if expected { (1, 0) } else { (0, 1) };
+        assert_eq!(
+            oracle.score(),
+            Some(FutarchyOracleScore { pass_score, reject_score, victory_margin: 1 })
+        );
+    });
+}
+
+#[test_case(vec![_3_4, _1_4], vec![_1_4, _3_4], true)]
+#[test_case(vec![_1_4, _3_4], vec![_3_4, _1_4], false)]
+fn update_compares_expected_values_of_scalar_markets(
+    pass_spot_prices: Vec<u128>,
+    reject_spot_prices: Vec<u128>,
+    expected: bool,
+) {
+    ExtBuilder::default().build().execute_with(|| {
+        let pass = create_scalar_market_and_deploy_pool(pass_spot_prices);
+        let reject = create_scalar_market_and_deploy_pool(reject_spot_prices);
+        let scoreboard = DecisionMarketOracleScoreboard::new(0, 1, _10, _1);
+        let mut oracle = ScalarDecisionMarketOracle::new(pass, reject, scoreboard);
+
+        oracle.update(1);
+
+        assert_eq!(oracle.evaluate().1, expected);
+    });
+}
+
+#[test]
+fn update_rejects_if_pool_is_not_found() {
+    ExtBuilder::default().build().execute_with(|| {
+        let pass = create_scalar_market_and_deploy_pool(vec![_3_4, _1_4]);
+        let reject = ScalarDecisionMarketLeg::new(
+            1,
+            1,
+            Asset::ScalarOutcome(1, ScalarPosition::Long),
+            Asset::ScalarOutcome(1, ScalarPosition::Short),
+        );
+        let scoreboard = DecisionMarketOracleScoreboard::new(0, 0, 0, 0);
+        let mut oracle = ScalarDecisionMarketOracle::new(pass, reject, scoreboard);
+
+        oracle.update(1);
+
+        assert_eq!(
+            oracle.score(),
+            Some(FutarchyOracleScore { pass_score: 0, reject_score: 1, victory_margin: 0 })
+        );
+    });
+}
+
+#[test]
+fn update_reads_bounds_from_scalar_market() {
+    ExtBuilder::default().build().execute_with(|| {
+        // The implied expected values are 175 (pass) and 116.67 (reject).
+        let (market_ids, pool_id) = create_markets_and_deploy_combinatorial_pool(
+            ALICE,
+            BASE_ASSET,
+            vec![MarketType::Categorical(2), MarketType::Scalar(_100..=_200)],
+            _10,
+            vec![_3_10, _1_10, _1_10, _1_2],
+            CENT,
+        );
+        let assets = Pools::<Runtime>::get(pool_id).unwrap().assets();
+        let pass = ScalarDecisionMarketLeg::new(pool_id, market_ids[1], assets[0], assets[1]);
+        let reject = ScalarDecisionMarketLeg::new(pool_id, market_ids[1], assets[2], assets[3]);
+        // The relative margin is 50%; it would be 350% if the bounds were `0..=100`.
+        let scoreboard = DecisionMarketOracleScoreboard::new(0, 1, _10, _1);
+        let mut oracle = ScalarDecisionMarketOracle::new(pass, reject, scoreboard);
+
+        oracle.update(1);
+
+        assert!(!oracle.evaluate().1);
+    });
+}
+
+#[test]
+fn update_rejects_if_market_is_not_scalar() {
+    ExtBuilder::default().build().execute_with(|| {
+        let pass = create_scalar_market_and_deploy_pool(vec![_3_4, _1_4]);
+        let reject = create_scalar_market_and_deploy_pool(vec![_1_4, _3_4]);
+        let categorical_market_id =
+            create_market(ALICE, BASE_ASSET, MarketType::Categorical(2), ScoringRule::AmmCdaHybrid);
+        let pass = ScalarDecisionMarketLeg { market_id: categorical_market_id, ..pass };
+        let scoreboard = DecisionMarketOracleScoreboard::new(0, 0, 0, 0);
+        let mut oracle = ScalarDecisionMarketOracle::new(pass, reject, scoreboard);
+
+        oracle.update(1);
+
+        assert_eq!(
+            oracle.score(),
+            Some(FutarchyOracleScore { pass_score: 0, reject_score: 1, victory_margin: 0 })
+        );
+    });
+}
+
+#[test]
+fn deploy_oracle_creates_scalar_welfare_market() {
+    ExtBuilder::default().build().execute_with(|| {
+        let evaluate_at = 10;
+        let params = ScalarDecisionMarketOracleParams {
+            collateral: BASE_ASSET,
+            welfare_duration: 20,
+            welfare_range: _100..=_200,
+            deadlines: Deadlines {
+                grace_period: 0,
+                oracle_duration:
+                    <Runtime as zrml_prediction_markets::Config>::MinOracleDuration::get(),
+                dispute_duration: 0,
+            },
+            dispute_mechanism: None,
+            amount: _10,
+            swap_fee: CENT,
+            victory_margin: 1,
+            price_margin_abs: _10,
+            price_margin_rel: _1,
+        };
+        let metadata = DecisionMarketOracleMetadata {
+            decision_metadata: MultiHash::Sha3_384([0x15; 50]),
+            welfare_metadata: MultiHash::Sha3_384([0x16; 50]),
+        };
+        let pool_id = <NeoSwaps as PoolStorage>::next_pool_id();
+
+        let oracle =
+            ScalarDecisionMarketOracleDeployer::<Runtime, PredictionMarkets>::deploy_oracle(
+                ALICE,
+                evaluate_at,
+                params,
+                metadata,
+            )
+            .unwrap();
+
+        let decision_market = MarketCommons::market(&0).unwrap();
+        assert_eq!(decision_market.market_type, MarketType::Categorical(2));
+        assert_eq!(decision_market.period, MarketPeriod::Block(0..evaluate_at));
+        let welfare_market = MarketCommons::market(&1).unwrap();
+        assert_eq!(welfare_market.market_type, MarketType::Scalar(_100..=_200));
+        assert_eq!(welfare_market.period, MarketPeriod::Block(0..evaluate_at + 20));
+
+        let assets = Pools::<Runtime>::get(pool_id).unwrap().assets();
+        let pass = ScalarDecisionMarketLeg::new(pool_id, 1, assets[0], assets[1]);
+        let reject = ScalarDecisionMarketLeg::new(pool_id, 1, assets[2], assets[3]);
+        let scoreboard = DecisionMarketOracleScoreboard::new(0, 1, _10, _1);
+        assert_eq!(oracle, ScalarDecisionMarketOracle::new(pass, reject, scoreboard));
+    });
+}
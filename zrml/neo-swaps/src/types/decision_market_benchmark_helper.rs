@@ -19,7 +19,10 @@
 
 use crate::{
     liquidity_tree::types::LiquidityTree,
-    types::{DecisionMarketOracle, DecisionMarketOracleScoreboard, Pool, PoolType},
+    types::{
+        DecisionMarketOracle, DecisionMarketOracleScoreboard, DecisionMarketSubOracle, Pool,
+        PoolType,
+    },
     BalanceOf, Config, MarketIdOf, Pallet, Pools,
 };
 use alloc::{collections::BTreeMap, vec};
@@ -82,3 +85,14 @@ where
         DecisionMarketOracle::new(pool_id, positive_outcome, negative_outcome, scoreboard)
     }
 }
+
+impl<T> FutarchyBenchmarkHelper<DecisionMarketSubOracle<T>> for DecisionMarketBenchmarkHelper<T>
+where
+    T: Config,
+{
+    fn create_oracle(value: bool) -> DecisionMarketSubOracle<T> {
+        DecisionMarketSubOracle::Categorical(<Self as FutarchyBenchmarkHelper<
+            DecisionMarketOracle<T>,
+        >>::create_oracle(value))
+    }
+}
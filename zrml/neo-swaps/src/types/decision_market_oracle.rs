@@ -24,7 +24,7 @@ use frame_system::pallet_prelude::BlockNumberFor;
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_runtime::DispatchError;
-use zeitgeist_primitives::traits::FutarchyOracle;
+use zeitgeist_primitives::{traits::FutarchyOracle, types::FutarchyOracleScore};
 
 /// Struct that implements `FutarchyOracle` using price measurements from liquidity pools.
 ///
@@ -88,4 +88,8 @@ where
 
         T::WeightInfo::decision_market_oracle_update()
     }
+
+    fn score(&self) -> Option<FutarchyOracleScore> {
+        Some(self.scoreboard.score())
+    }
 }
@@ -0,0 +1,32 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use zeitgeist_primitives::types::MultiHash;
+
+/// The metadata of the markets deployed by
+/// [`DecisionMarketOracleDeployer`](crate::types::DecisionMarketOracleDeployer), provided by the
+/// proposer.
+#[derive(Clone, Debug, Decode, Encode, Eq, PartialEq, TypeInfo)]
+pub struct DecisionMarketOracleMetadata {
+    /// The metadata of the decision market.
+    pub decision_metadata: MultiHash,
+
+    /// The metadata of the welfare market.
+    pub welfare_metadata: MultiHash,
+}
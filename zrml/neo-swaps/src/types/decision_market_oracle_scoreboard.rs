@@ -20,7 +20,7 @@ use frame_system::pallet_prelude::BlockNumberFor;
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_runtime::{traits::Zero, Saturating};
-use zeitgeist_primitives::math::fixed::FixedDiv;
+use zeitgeist_primitives::{math::fixed::FixedDiv, types::FutarchyOracleScore};
 
 /// Records until the end of time.
 #[derive(Clone, Debug, Decode, Encode, Eq, MaxEncodedLen, PartialEq, TypeInfo)]
@@ -104,6 +104,15 @@ where
         score_margin >= self.victory_margin
     }
 
+    /// Returns the current score of the scoreboard.
+    pub fn score(&self) -> FutarchyOracleScore {
+        FutarchyOracleScore {
+            pass_score: self.pass_score,
+            reject_score: self.reject_score,
+            victory_margin: self.victory_margin,
+        }
+    }
+
     /// Skips update on this block and awards a point to the negative outcome.
     pub fn skip_update(&mut self, now: BlockNumberFor<T>) {
         if now < self.start {
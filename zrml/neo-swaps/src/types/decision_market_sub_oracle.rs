@@ -0,0 +1,67 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    types::{DecisionMarketOracle, ScalarDecisionMarketOracle},
+    Config,
+};
+use frame_support::pallet_prelude::Weight;
+use frame_system::pallet_prelude::BlockNumberFor;
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use zeitgeist_primitives::{traits::FutarchyOracle, types::FutarchyOracleScore};
+
+/// The decision market oracles which may be combined in a composite futarchy oracle.
+#[derive(Clone, Debug, Decode, Encode, Eq, MaxEncodedLen, PartialEq, TypeInfo)]
+pub enum DecisionMarketSubOracle<T>
+where
+    T: Config,
+{
+    /// An oracle backed by a binary welfare market.
+    Categorical(DecisionMarketOracle<T>),
+
+    /// An oracle backed by two scalar welfare markets.
+    Scalar(ScalarDecisionMarketOracle<T>),
+}
+
+impl<T> FutarchyOracle for DecisionMarketSubOracle<T>
+where
+    T: Config,
+{
+    type BlockNumber = BlockNumberFor<T>;
+
+    fn evaluate(&self) -> (Weight, bool) {
+        match self {
+            Self::Categorical(oracle) => oracle.evaluate(),
+            Self::Scalar(oracle) => oracle.evaluate(),
+        }
+    }
+
+    fn update(&mut self, now: Self::BlockNumber) -> Weight {
+        match self {
+            Self::Categorical(oracle) => oracle.update(now),
+            Self::Scalar(oracle) => oracle.update(now),
+        }
+    }
+
+    fn score(&self) -> Option<FutarchyOracleScore> {
+        match self {
+            Self::Categorical(oracle) => oracle.score(),
+            Self::Scalar(oracle) => oracle.score(),
+        }
+    }
+}
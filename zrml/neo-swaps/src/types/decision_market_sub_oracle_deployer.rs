@@ -0,0 +1,80 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    types::{
+        DecisionMarketOracleDeployer, DecisionMarketOracleMetadata, DecisionMarketSubOracle,
+        DecisionMarketSubOracleParams, ScalarDecisionMarketOracleDeployer,
+    },
+    Config, MarketIdOf,
+};
+use core::marker::PhantomData;
+use frame_support::pallet_prelude::Weight;
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::DispatchError;
+use zeitgeist_primitives::traits::{CreateMarketApi, FutarchyOracleDeployer};
+
+/// Deploys a [`DecisionMarketSubOracle`] of the kind specified by the variant of the
+/// [`DecisionMarketSubOracleParams`].
+pub struct DecisionMarketSubOracleDeployer<T, C>(PhantomData<(T, C)>);
+
+impl<T, C> FutarchyOracleDeployer for DecisionMarketSubOracleDeployer<T, C>
+where
+    T: Config,
+    C: CreateMarketApi<
+        AccountId = T::AccountId,
+        BlockNumber = BlockNumberFor<T>,
+        MarketId = MarketIdOf<T>,
+    >,
+{
+    type AccountId = T::AccountId;
+    type BlockNumber = BlockNumberFor<T>;
+    type Oracle = DecisionMarketSubOracle<T>;
+    type Params = DecisionMarketSubOracleParams<T>;
+    type Metadata = DecisionMarketOracleMetadata;
+
+    fn deploy_oracle(
+        who: Self::AccountId,
+        evaluate_at: Self::BlockNumber,
+        params: Self::Params,
+        metadata: Self::Metadata,
+    ) -> Result<Self::Oracle, DispatchError> {
+        match params {
+            DecisionMarketSubOracleParams::Categorical(params) => {
+                DecisionMarketOracleDeployer::<T, C>::deploy_oracle(
+                    who,
+                    evaluate_at,
+                    params,
+                    metadata,
+                )
+                .map(DecisionMarketSubOracle::Categorical)
+            }
+            DecisionMarketSubOracleParams::Scalar(params) => ScalarDecisionMarketOracleDeployer::<
+                T,
+                C,
+            >::deploy_oracle(
+                who, evaluate_at, params, metadata
+            )
+            .map(DecisionMarketSubOracle::Scalar),
+        }
+    }
+
+    fn deploy_oracle_weight() -> Weight {
+        DecisionMarketOracleDeployer::<T, C>::deploy_oracle_weight()
+            .max(ScalarDecisionMarketOracleDeployer::<T, C>::deploy_oracle_weight())
+    }
+}
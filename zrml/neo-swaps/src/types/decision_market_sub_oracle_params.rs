@@ -0,0 +1,39 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    types::{DecisionMarketOracleParams, ScalarDecisionMarketOracleParams},
+    Config,
+};
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+
+/// Parameters used by
+/// [`DecisionMarketSubOracleDeployer`](crate::types::DecisionMarketSubOracleDeployer) to deploy a
+/// [`DecisionMarketSubOracle`](crate::types::DecisionMarketSubOracle). The variant determines the
+/// kind of oracle which is deployed.
+#[derive(Clone, Debug, Decode, Encode, Eq, PartialEq, TypeInfo)]
+pub enum DecisionMarketSubOracleParams<T>
+where
+    T: Config,
+{
+    /// Deploy a [`DecisionMarketOracle`](crate::types::DecisionMarketOracle).
+    Categorical(DecisionMarketOracleParams<T>),
+
+    /// Deploy a [`ScalarDecisionMarketOracle`](crate::types::ScalarDecisionMarketOracle).
+    Scalar(ScalarDecisionMarketOracleParams<T>),
+}
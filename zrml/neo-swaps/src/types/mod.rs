@@ -17,17 +17,37 @@
 
 mod decision_market_benchmark_helper;
 mod decision_market_oracle;
+mod decision_market_oracle_deployer;
+mod decision_market_oracle_metadata;
+mod decision_market_oracle_params;
 mod decision_market_oracle_scoreboard;
+mod decision_market_sub_oracle;
+mod decision_market_sub_oracle_deployer;
+mod decision_market_sub_oracle_params;
 mod fee_distribution;
 mod max_assets;
 mod pool;
 mod pool_type;
+mod scalar_decision_market_leg;
+mod scalar_decision_market_oracle;
+mod scalar_decision_market_oracle_deployer;
+mod scalar_decision_market_oracle_params;
 
 #[cfg(feature = "runtime-benchmarks")]
 pub use decision_market_benchmark_helper::*;
 pub use decision_market_oracle::*;
+pub use decision_market_oracle_deployer::*;
+pub use decision_market_oracle_metadata::*;
+pub use decision_market_oracle_params::*;
 pub use decision_market_oracle_scoreboard::*;
+pub use decision_market_sub_oracle::*;
+pub use decision_market_sub_oracle_deployer::*;
+pub use decision_market_sub_oracle_params::*;
 pub(crate) use fee_distribution::*;
 pub(crate) use max_assets::*;
 pub(crate) use pool::*;
 pub(crate) use pool_type::*;
+pub use scalar_decision_market_leg::*;
+pub use scalar_decision_market_oracle::*;
+pub use scalar_decision_market_oracle_deployer::*;
+pub use scalar_decision_market_oracle_params::*;
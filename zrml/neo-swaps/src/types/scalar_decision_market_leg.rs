@@ -0,0 +1,81 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{traits::PoolOperations, AssetOf, BalanceOf, Config, Error, MarketIdOf, Pools};
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::{DispatchError, SaturatedConversion};
+use zeitgeist_primitives::{
+    math::{checked_ops_res::CheckedAddRes, fixed::FixedDiv},
+    types::MarketType,
+};
+use zrml_market_commons::MarketCommonsPalletApi;
+
+/// The long and short position of a scalar market conditional on a decision, traded in the
+/// liquidity pool specified by `pool_id`.
+///
+/// The positions may either be the outcomes of the scalar market `market_id` or combinatorial
+/// positions of the form "decision & long" and "decision & short", where "long" and "short" are
+/// outcomes of `market_id`. The bounds of the welfare metric are read from the market type of
+/// `market_id`, which are fixed point numbers like all balances.
+#[derive(Clone, Debug, Decode, Encode, Eq, MaxEncodedLen, PartialEq, TypeInfo)]
+pub struct ScalarDecisionMarketLeg<T>
+where
+    T: Config,
+{
+    pub pool_id: T::PoolId,
+    pub market_id: MarketIdOf<T>,
+    pub long_outcome: AssetOf<T>,
+    pub short_outcome: AssetOf<T>,
+}
+
+impl<T> ScalarDecisionMarketLeg<T>
+where
+    T: Config,
+{
+    pub fn new(
+        pool_id: T::PoolId,
+        market_id: MarketIdOf<T>,
+        long_outcome: AssetOf<T>,
+        short_outcome: AssetOf<T>,
+    ) -> Self {
+        Self { pool_id, market_id, long_outcome, short_outcome }
+    }
+
+    /// Returns the lower and upper bound of the scalar market `market_id`.
+    pub(crate) fn try_get_bounds(&self) -> Result<(BalanceOf<T>, BalanceOf<T>), DispatchError> {
+        let market = T::MarketCommons::market(&self.market_id)?;
+        let MarketType::Scalar(range) = market.market_type else {
+            return Err(Error::<T>::MarketNotScalar.into());
+        };
+
+        Ok((range.start().saturated_into(), range.end().saturated_into()))
+    }
+
+    /// Returns the price of the long position relative to the sum of the prices of the long and
+    /// short position. This is the probability of the long position conditional on the decision.
+    pub(crate) fn try_get_long_price(&self) -> Result<BalanceOf<T>, DispatchError> {
+        let pool = Pools::<T>::get(self.pool_id)
+            .ok_or::<DispatchError>(Error::<T>::PoolNotFound.into())?;
+
+        let long_price = pool.calculate_spot_price(self.long_outcome)?;
+        let short_price = pool.calculate_spot_price(self.short_outcome)?;
+        let total_price = long_price.checked_add_res(&short_price)?;
+
+        long_price.bdiv(total_price)
+    }
+}
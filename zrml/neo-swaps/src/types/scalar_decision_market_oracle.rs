@@ -0,0 +1,112 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    types::{DecisionMarketOracleScoreboard, ScalarDecisionMarketLeg},
+    weights::WeightInfoZeitgeist,
+    BalanceOf, Config,
+};
+use frame_support::pallet_prelude::Weight;
+use frame_system::pallet_prelude::BlockNumberFor;
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::DispatchError;
+use zeitgeist_primitives::{
+    math::{
+        checked_ops_res::{CheckedAddRes, CheckedSubRes},
+        fixed::FixedMul,
+    },
+    traits::FutarchyOracle,
+    types::FutarchyOracleScore,
+};
+
+/// Struct that implements `FutarchyOracle` using the prices of two scalar markets conditional on
+/// passing and rejecting a proposal.
+///
+/// The price of the long position of each leg implies an expected value of the scalar welfare
+/// metric in the range of the scalar market of the leg. The oracle evaluates to `true` if and only if
+/// the expected value conditional on `pass` exceeds the expected value conditional on `reject`
+/// over a period of time for a certain absolute and relative threshold determined by a
+/// [`DecisionMarketOracleScoreboard`]. The absolute threshold is denominated in units of the
+/// welfare metric.
+#[derive(Clone, Debug, Decode, Encode, Eq, MaxEncodedLen, PartialEq, TypeInfo)]
+pub struct ScalarDecisionMarketOracle<T>
+where
+    T: Config,
+{
+    pass: ScalarDecisionMarketLeg<T>,
+    reject: ScalarDecisionMarketLeg<T>,
+    scoreboard: DecisionMarketOracleScoreboard<T>,
+}
+
+impl<T> ScalarDecisionMarketOracle<T>
+where
+    T: Config,
+{
+    pub fn new(
+        pass: ScalarDecisionMarketLeg<T>,
+        reject: ScalarDecisionMarketLeg<T>,
+        scoreboard: DecisionMarketOracleScoreboard<T>,
+    ) -> Self {
+        Self { pass, reject, scoreboard }
+    }
+
+    /// Returns the expected value of the welfare metric implied by the prices of `leg`.
+    fn try_get_expected_value(
+        leg: &ScalarDecisionMarketLeg<T>,
+    ) -> Result<BalanceOf<T>, DispatchError> {
+        let long_price = leg.try_get_long_price()?;
+        let (lower_bound, upper_bound) = leg.try_get_bounds()?;
+        let range = upper_bound.checked_sub_res(&lower_bound)?;
+
+        lower_bound.checked_add_res(&range.bmul(long_price)?)
+    }
+
+    fn try_get_expected_values(&self) -> Result<(BalanceOf<T>, BalanceOf<T>), DispatchError> {
+        let pass_value = Self::try_get_expected_value(&self.pass)?;
+        let reject_value = Self::try_get_expected_value(&self.reject)?;
+
+        Ok((pass_value, reject_value))
+    }
+}
+
+impl<T> FutarchyOracle for ScalarDecisionMarketOracle<T>
+where
+    T: Config,
+{
+    type BlockNumber = BlockNumberFor<T>;
+
+    fn evaluate(&self) -> (Weight, bool) {
+        (T::WeightInfo::decision_market_oracle_evaluate(), self.scoreboard.evaluate())
+    }
+
+    fn update(&mut self, now: Self::BlockNumber) -> Weight {
+        if let Ok((pass_value, reject_value)) = self.try_get_expected_values() {
+            self.scoreboard.update(now, pass_value, reject_value);
+        } else {
+            // Err on the side of caution if a pool is not found or a calculation fails by not
+            // enacting the policy.
+            self.scoreboard.skip_update(now);
+        }
+
+        T::WeightInfo::scalar_decision_market_oracle_update()
+    }
+
+    fn score(&self) -> Option<FutarchyOracleScore> {
+        Some(self.scoreboard.score())
+    }
+}
@@ -0,0 +1,125 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    consts::DECISION_MARKET_FUEL,
+    traits::PoolStorage,
+    types::{
+        DecisionMarketOracleMetadata, DecisionMarketOracleScoreboard, ScalarDecisionMarketLeg,
+        ScalarDecisionMarketOracle, ScalarDecisionMarketOracleParams,
+    },
+    weights::WeightInfoZeitgeist,
+    BalanceOf, Config, Error, FuelOf, MarketIdOf, Pallet,
+};
+use alloc::vec;
+use core::marker::PhantomData;
+use frame_support::pallet_prelude::Weight;
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::{DispatchError, Perbill, SaturatedConversion, Saturating};
+use zeitgeist_primitives::{
+    constants::BASE,
+    traits::{CombinatorialTokensFuel, CreateMarketApi, FutarchyOracleDeployer},
+    types::{MarketCreation, MarketPeriod, MarketType, ScoringRule},
+};
+
+/// Deploys a [`ScalarDecisionMarketOracle`] by creating a decision market and a scalar welfare
+/// market using `C` and deploying a combinatorial pool on top of them.
+///
+/// The returned oracle compares the expected welfare implied by the prices of "enact & long" and
+/// "enact & short" against the expected welfare implied by the prices of "reject & long" and
+/// "reject & short".
+pub struct ScalarDecisionMarketOracleDeployer<T, C>(PhantomData<(T, C)>);
+
+impl<T, C> FutarchyOracleDeployer for ScalarDecisionMarketOracleDeployer<T, C>
+where
+    T: Config,
+    C: CreateMarketApi<
+        AccountId = T::AccountId,
+        BlockNumber = BlockNumberFor<T>,
+        MarketId = MarketIdOf<T>,
+    >,
+{
+    type AccountId = T::AccountId;
+    type BlockNumber = BlockNumberFor<T>;
+    type Oracle = ScalarDecisionMarketOracle<T>;
+    type Params = ScalarDecisionMarketOracleParams<T>;
+    type Metadata = DecisionMarketOracleMetadata;
+
+    fn deploy_oracle(
+        who: Self::AccountId,
+        evaluate_at: Self::BlockNumber,
+        params: Self::Params,
+        metadata: Self::Metadata,
+    ) -> Result<Self::Oracle, DispatchError> {
+        let now = frame_system::Pallet::<T>::block_number();
+        let create_market = |metadata, end, market_type| {
+            C::create_market(
+                who.clone(),
+                params.collateral,
+                Perbill::zero(),
+                who.clone(),
+                MarketPeriod::Block(now..end),
+                params.deadlines,
+                metadata,
+                MarketCreation::Permissionless,
+                market_type,
+                params.dispute_mechanism.clone(),
+                ScoringRule::AmmCdaHybrid,
+            )
+        };
+        let decision_market_id =
+            create_market(metadata.decision_metadata, evaluate_at, MarketType::Categorical(2))?;
+        let welfare_period_end = evaluate_at.saturating_add(params.welfare_duration);
+        let welfare_market_id = create_market(
+            metadata.welfare_metadata,
+            welfare_period_end,
+            MarketType::Scalar(params.welfare_range.clone()),
+        )?;
+
+        let pool_id = <Pallet<T> as PoolStorage>::next_pool_id();
+        let spot_price: BalanceOf<T> = (BASE / 4).saturated_into();
+        Pallet::<T>::do_deploy_combinatorial_pool(
+            who,
+            vec![decision_market_id, welfare_market_id],
+            params.amount,
+            vec![spot_price; 4],
+            params.swap_fee,
+            FuelOf::<T>::from_total(DECISION_MARKET_FUEL),
+        )?;
+
+        // The positions are ordered as follows: enact & long, enact & short, reject & long,
+        // reject & short.
+        let pool = <Pallet<T> as PoolStorage>::get(pool_id)?;
+        let asset = |index: usize| pool.assets.get(index).copied().ok_or(Error::<T>::Unexpected);
+        let pass = ScalarDecisionMarketLeg::new(pool_id, welfare_market_id, asset(0)?, asset(1)?);
+        let reject = ScalarDecisionMarketLeg::new(pool_id, welfare_market_id, asset(2)?, asset(3)?);
+        let scoreboard = DecisionMarketOracleScoreboard::new(
+            now,
+            params.victory_margin,
+            params.price_margin_abs,
+            params.price_margin_rel,
+        );
+
+        Ok(ScalarDecisionMarketOracle::new(pass, reject, scoreboard))
+    }
+
+    fn deploy_oracle_weight() -> Weight {
+        C::create_market_weight()
+            .saturating_mul(2)
+            .saturating_add(T::WeightInfo::deploy_combinatorial_pool(2, DECISION_MARKET_FUEL))
+    }
+}
@@ -0,0 +1,71 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{AssetOf, BalanceOf, Config};
+use core::ops::RangeInclusive;
+use frame_system::pallet_prelude::BlockNumberFor;
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use zeitgeist_primitives::types::{Deadlines, MarketDisputeMechanism};
+
+/// Parameters used by
+/// [`ScalarDecisionMarketOracleDeployer`](crate::types::ScalarDecisionMarketOracleDeployer) to
+/// deploy the markets and the pool backing a
+/// [`ScalarDecisionMarketOracle`](crate::types::ScalarDecisionMarketOracle).
+///
+/// The deployer creates a binary _decision market_ (outcome 0 means that the proposal is enacted)
+/// which closes when the proposal is evaluated and a scalar _welfare market_ with range
+/// `welfare_range`, and deploys a combinatorial pool on both.
+///
+/// These parameters are set by governance; the proposer only provides the
+/// [`DecisionMarketOracleMetadata`](crate::types::DecisionMarketOracleMetadata).
+#[derive(Clone, Debug, Decode, Encode, Eq, PartialEq, TypeInfo)]
+pub struct ScalarDecisionMarketOracleParams<T>
+where
+    T: Config,
+{
+    /// The collateral of both markets.
+    pub collateral: AssetOf<T>,
+
+    /// The number of blocks the welfare market stays open after the proposal is evaluated.
+    pub welfare_duration: BlockNumberFor<T>,
+
+    /// The range of the welfare metric.
+    pub welfare_range: RangeInclusive<u128>,
+
+    /// The deadlines of both markets.
+    pub deadlines: Deadlines<BlockNumberFor<T>>,
+
+    /// The dispute mechanism of both markets.
+    pub dispute_mechanism: Option<MarketDisputeMechanism>,
+
+    /// The amount of collateral used to fund the pool.
+    pub amount: BalanceOf<T>,
+
+    /// The swap fee of the pool.
+    pub swap_fee: BalanceOf<T>,
+
+    /// See [`DecisionMarketOracleScoreboard`](crate::types::DecisionMarketOracleScoreboard).
+    pub victory_margin: u128,
+
+    /// See [`DecisionMarketOracleScoreboard`](crate::types::DecisionMarketOracleScoreboard).
+    /// Denominated in units of the welfare metric.
+    pub price_margin_abs: BalanceOf<T>,
+
+    /// See [`DecisionMarketOracleScoreboard`](crate::types::DecisionMarketOracleScoreboard).
+    pub price_margin_rel: BalanceOf<T>,
+}
@@ -64,6 +64,7 @@ pub trait WeightInfoZeitgeist {
     fn deploy_combinatorial_pool(n: u32, m: u32) -> Weight;
     fn decision_market_oracle_evaluate() -> Weight;
     fn decision_market_oracle_update() -> Weight;
+    fn scalar_decision_market_oracle_update() -> Weight;
 }
 
 /// Weight functions for zrml_neo_swaps (automatically generated)
@@ -345,4 +346,13 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
         // Minimum execution time: 124_673 nanoseconds.
         Weight::from_parts(126_953_000, 156294).saturating_add(T::DbWeight::get().reads(1))
     }
+    /// Storage: `NeoSwaps::Pools` (r:2 w:0)
+    /// Proof: `NeoSwaps::Pools` (`max_values`: None, `max_size`: Some(152829), added: 155304, mode: `MaxEncodedLen`)
+    /// Storage: `MarketCommons::Markets` (r:2 w:0)
+    /// Proof: `MarketCommons::Markets` (`max_values`: None, `max_size`: Some(708), added: 3183, mode: `MaxEncodedLen`)
+    fn scalar_decision_market_oracle_update() -> Weight {
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_neo_swaps` to replace it.
+        Weight::from_parts(253_906_000, 317964).saturating_add(T::DbWeight::get().reads(4))
+    }
 }
@@ -0,0 +1,79 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+#![allow(
+  // Auto-generated code is a no man's land
+  clippy::arithmetic_side_effects
+)]
+#![allow(clippy::type_complexity)]
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Config;
+#[cfg(test)]
+use crate::Pallet as OracleRegistry;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::traits::{Currency, Get};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use sp_runtime::SaturatedConversion;
+use zeitgeist_primitives::constants::BASE;
+
+fn register_funded_oracle<T: Config>(oracle: &T::AccountId) -> Result<(), &'static str> {
+    let balance = (10_000 * BASE).saturated_into();
+    let _ = T::Currency::deposit_creating(oracle, balance);
+    Pallet::<T>::register_oracle(
+        RawOrigin::Signed(oracle.clone()).into(),
+        T::MinOracleStake::get(),
+    )?;
+    Ok(())
+}
+
+benchmarks! {
+    register_oracle {
+        let caller: T::AccountId = whitelisted_caller();
+        let balance = (10_000 * BASE).saturated_into();
+        let _ = T::Currency::deposit_creating(&caller, balance);
+        let stake = T::MinOracleStake::get();
+    }: _(RawOrigin::Signed(caller), stake)
+
+    set_fallback_oracle {
+        let caller: T::AccountId = whitelisted_caller();
+        register_funded_oracle::<T>(&caller)?;
+        let fallback: T::AccountId = account("fallback", 0, 0);
+        register_funded_oracle::<T>(&fallback)?;
+    }: _(RawOrigin::Signed(caller), Some(fallback))
+
+    unregister_oracle {
+        let caller: T::AccountId = whitelisted_caller();
+        register_funded_oracle::<T>(&caller)?;
+    }: _(RawOrigin::Signed(caller))
+
+    withdraw_stake {
+        let caller: T::AccountId = whitelisted_caller();
+        register_funded_oracle::<T>(&caller)?;
+        Pallet::<T>::unregister_oracle(RawOrigin::Signed(caller.clone()).into())?;
+        let unbonded_at: BlockNumberFor<T> =
+            frame_system::Pallet::<T>::block_number() + T::UnbondingPeriod::get();
+        frame_system::Pallet::<T>::set_block_number(unbonded_at);
+    }: _(RawOrigin::Signed(caller))
+
+    impl_benchmark_test_suite!(
+        OracleRegistry,
+        crate::mock::ExtBuilder::default().build(),
+        crate::mock::Runtime,
+    );
+}
@@ -0,0 +1,398 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+#![doc = include_str!("../README.md")]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+mod benchmarks;
+mod mock;
+mod tests;
+pub mod types;
+pub mod weights;
+pub use pallet::*;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use crate::{types::OracleInfo, weights::WeightInfoZeitgeist};
+    use alloc::vec::Vec;
+    use core::cmp::Reverse;
+    use frame_support::{
+        pallet_prelude::*,
+        traits::{Currency, NamedReservableCurrency, OnUnbalanced},
+        PalletId,
+    };
+    use frame_system::pallet_prelude::*;
+    use sp_runtime::{
+        traits::{Saturating, Zero},
+        Percent, SaturatedConversion,
+    };
+    use zeitgeist_primitives::traits::OracleRegistryApi;
+
+    /// The current storage version.
+    pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
+    pub(crate) type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+    pub(crate) type BalanceOf<T> =
+        <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+    pub(crate) type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
+        <T as frame_system::Config>::AccountId,
+    >>::NegativeImbalance;
+    pub(crate) type OracleInfoOf<T> = OracleInfo<AccountIdOf<T>, BalanceOf<T>>;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type Currency: NamedReservableCurrency<Self::AccountId, ReserveIdentifier = [u8; 8]>;
+
+        /// The minimum amount of ZTG an oracle has to stake to register.
+        #[pallet::constant]
+        type MinOracleStake: Get<BalanceOf<Self>>;
+
+        /// Identifier of this pallet, used for the named reserves of the stakes.
+        #[pallet::constant]
+        type PalletId: Get<PalletId>;
+
+        /// The amount of reputation an oracle loses for a missed or overturned report, multiplied
+        /// by the reputation weight of the market (at least one).
+        #[pallet::constant]
+        type ReputationPenalty: Get<u32>;
+
+        /// The percentage of its stake which an oracle loses for a missed or overturned report.
+        #[pallet::constant]
+        type ReportSlashPercentage: Get<Percent>;
+
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Handler for slashed stakes.
+        type Slash: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+        /// The market stake which corresponds to one point of reputation weight. An accurate
+        /// report increases the reputation of the oracle by the reputation weight of the market.
+        #[pallet::constant]
+        type StakePerReputationPoint: Get<BalanceOf<Self>>;
+
+        /// The number of blocks an oracle has to wait after unregistering before it can withdraw
+        /// its stake. The stake may still be slashed during this period.
+        #[pallet::constant]
+        type UnbondingPeriod: Get<BlockNumberFor<Self>>;
+
+        type WeightInfo: WeightInfoZeitgeist;
+    }
+
+    #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
+    pub struct Pallet<T>(_);
+
+    /// The registered oracles.
+    #[pallet::storage]
+    pub type Oracles<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, OracleInfoOf<T>>;
+
+    /// Maps unregistered oracles whose stake is unbonding to the block from which on the stake may
+    /// be withdrawn.
+    #[pallet::storage]
+    pub type UnbondingOracles<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// An oracle registered with the given stake. \[oracle, stake\]
+        OracleRegistered(T::AccountId, BalanceOf<T>),
+        /// An oracle unregistered and its stake unbonds until the given block.
+        /// \[oracle, unbonded_at\]
+        OracleUnbonding(T::AccountId, BlockNumberFor<T>),
+        /// An unbonded oracle withdrew its stake and was removed. \[oracle, stake\]
+        OracleUnregistered(T::AccountId, BalanceOf<T>),
+        /// An oracle named or removed its fallback oracle. \[oracle, fallback\]
+        FallbackOracleSet(T::AccountId, Option<T::AccountId>),
+        /// The reputation of an oracle changed. \[oracle, reputation\]
+        ReputationUpdated(T::AccountId, u32),
+        /// Part of the stake of an oracle was slashed. \[oracle, amount\]
+        StakeSlashed(T::AccountId, BalanceOf<T>),
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// The stake is below `MinOracleStake`.
+        StakeTooLow,
+        /// The account is already registered as oracle.
+        OracleAlreadyRegistered,
+        /// The account is not registered as oracle.
+        OracleNotRegistered,
+        /// The fallback oracle is not registered.
+        FallbackOracleNotRegistered,
+        /// An oracle can't be its own fallback oracle.
+        FallbackOracleIsSelf,
+        /// The fallback oracle is unbonding.
+        FallbackOracleIsUnbonding,
+        /// The oracle is unbonding.
+        OracleIsUnbonding,
+        /// The oracle is not unbonding.
+        OracleNotUnbonding,
+        /// The unbonding period of the oracle is not over yet.
+        UnbondingPeriodNotOver,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Registers the signer as oracle and reserves `stake`.
+        ///
+        /// # Arguments
+        ///
+        /// * `stake`: The amount of ZTG to reserve. Must be at least `MinOracleStake`.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::register_oracle())]
+        pub fn register_oracle(
+            origin: OriginFor<T>,
+            #[pallet::compact] stake: BalanceOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(!Oracles::<T>::contains_key(&who), Error::<T>::OracleAlreadyRegistered);
+            ensure!(stake >= T::MinOracleStake::get(), Error::<T>::StakeTooLow);
+
+            T::Currency::reserve_named(&Self::reserve_id(), &who, stake)?;
+            Oracles::<T>::insert(
+                &who,
+                OracleInfo {
+                    stake,
+                    fallback: None,
+                    reputation: 0,
+                    accurate_reports: 0,
+                    missed_reports: 0,
+                    overturned_reports: 0,
+                },
+            );
+
+            Self::deposit_event(Event::OracleRegistered(who, stake));
+
+            Ok(())
+        }
+
+        /// Names the fallback oracle of the signer, or removes it if `fallback` is `None`.
+        ///
+        /// If the signer misses its exclusive reporting window for a market, the fallback oracle
+        /// is given an exclusive reporting window of the same length before outsiders may report.
+        ///
+        /// # Arguments
+        ///
+        /// * `fallback`: The fallback oracle. Must be a registered oracle.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::set_fallback_oracle())]
+        pub fn set_fallback_oracle(
+            origin: OriginFor<T>,
+            fallback: Option<T::AccountId>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(!UnbondingOracles::<T>::contains_key(&who), Error::<T>::OracleIsUnbonding);
+            if let Some(fallback) = &fallback {
+                ensure!(fallback != &who, Error::<T>::FallbackOracleIsSelf);
+                ensure!(
+                    Oracles::<T>::contains_key(fallback),
+                    Error::<T>::FallbackOracleNotRegistered
+                );
+                ensure!(
+                    !UnbondingOracles::<T>::contains_key(fallback),
+                    Error::<T>::FallbackOracleIsUnbonding
+                );
+            }
+            Oracles::<T>::try_mutate(&who, |maybe_info| -> DispatchResult {
+                let info = maybe_info.as_mut().ok_or(Error::<T>::OracleNotRegistered)?;
+                info.fallback = fallback.clone();
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::FallbackOracleSet(who, fallback));
+
+            Ok(())
+        }
+
+        /// Unregisters the signer. The stake of the oracle unbonds for `UnbondingPeriod` blocks
+        /// and may still be slashed for missed or overturned reports during this period.
+        ///
+        /// Unbonding oracles are no longer listed, can't be named as fallback oracle and are
+        /// ignored as fallback oracle of markets created afterwards.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::unregister_oracle())]
+        pub fn unregister_oracle(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(Oracles::<T>::contains_key(&who), Error::<T>::OracleNotRegistered);
+            ensure!(!UnbondingOracles::<T>::contains_key(&who), Error::<T>::OracleIsUnbonding);
+            let now = frame_system::Pallet::<T>::block_number();
+            let unbonded_at = now.saturating_add(T::UnbondingPeriod::get());
+            UnbondingOracles::<T>::insert(&who, unbonded_at);
+
+            Self::deposit_event(Event::OracleUnbonding(who, unbonded_at));
+
+            Ok(())
+        }
+
+        /// Unreserves the remaining stake of the signer once its unbonding period is over and
+        /// removes the oracle. The reputation of the oracle is discarded.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::withdraw_stake())]
+        pub fn withdraw_stake(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let unbonded_at =
+                UnbondingOracles::<T>::get(&who).ok_or(Error::<T>::OracleNotUnbonding)?;
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(unbonded_at <= now, Error::<T>::UnbondingPeriodNotOver);
+            UnbondingOracles::<T>::remove(&who);
+            let info = Oracles::<T>::take(&who).ok_or(Error::<T>::OracleNotRegistered)?;
+            let missing = T::Currency::unreserve_named(&Self::reserve_id(), &who, info.stake);
+            debug_assert!(
+                missing.is_zero(),
+                "Could not unreserve all of the amount. reserve_id: {:?}, who: {:?}, value: {:?}.",
+                &Self::reserve_id(),
+                &who,
+                info.stake,
+            );
+
+            Self::deposit_event(Event::OracleUnregistered(who, info.stake));
+
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// The reserve ID of the oracle registry pallet.
+        #[inline]
+        pub fn reserve_id() -> [u8; 8] {
+            T::PalletId::get().0
+        }
+
+        /// Returns all registered oracles which aren't unbonding and whose reputation is at
+        /// least `min_reputation`, sorted by reputation in descending order.
+        pub fn oracles(min_reputation: u32) -> Vec<(T::AccountId, OracleInfoOf<T>)> {
+            let mut oracles: Vec<_> = Oracles::<T>::iter()
+                .filter(|(oracle, info)| {
+                    info.reputation >= min_reputation
+                        && !UnbondingOracles::<T>::contains_key(oracle)
+                })
+                .collect();
+            oracles.sort_by_key(|(_, info)| Reverse(info.reputation));
+            oracles
+        }
+
+        /// The reputation weight of a market with `market_stake`, which is the number of full
+        /// `StakePerReputationPoint` units in `market_stake`.
+        pub(crate) fn reputation_weight(market_stake: BalanceOf<T>) -> u32 {
+            let unit = T::StakePerReputationPoint::get();
+            if unit.is_zero() {
+                return 0;
+            }
+            (market_stake / unit).saturated_into()
+        }
+
+        /// Applies `mutation` to the record of `oracle` if it's registered. `mutation` returns the
+        /// weight it consumed in addition to the storage access of the record.
+        fn update_reputation<F>(oracle: &T::AccountId, mutation: F) -> Weight
+        where
+            F: FnOnce(&mut OracleInfoOf<T>) -> Weight,
+        {
+            let result = Oracles::<T>::mutate(oracle, |maybe_info| {
+                maybe_info.as_mut().map(|info| {
+                    let weight = mutation(info);
+                    (info.reputation, weight)
+                })
+            });
+            match result {
+                Some((reputation, weight)) => {
+                    Self::deposit_event(Event::ReputationUpdated(oracle.clone(), reputation));
+                    T::DbWeight::get().reads_writes(1, 1).saturating_add(weight)
+                }
+                None => T::DbWeight::get().reads(1),
+            }
+        }
+
+        /// Decreases the reputation of the oracle of `info` by `ReputationPenalty` times the
+        /// reputation weight of the market and slashes `ReportSlashPercentage` of its stake.
+        fn penalize(
+            oracle: &T::AccountId,
+            info: &mut OracleInfoOf<T>,
+            market_stake: BalanceOf<T>,
+        ) -> Weight {
+            let penalty = T::ReputationPenalty::get()
+                .saturating_mul(Self::reputation_weight(market_stake).max(1));
+            info.reputation = info.reputation.saturating_sub(penalty);
+
+            let slash = T::ReportSlashPercentage::get().mul_floor(info.stake);
+            if slash.is_zero() {
+                return Weight::zero();
+            }
+            let (imbalance, missing) =
+                T::Currency::slash_reserved_named(&Self::reserve_id(), oracle, slash);
+            debug_assert!(
+                missing.is_zero(),
+                "Could not slash all of the amount. reserve_id: {:?}, who: {:?}, value: {:?}.",
+                &Self::reserve_id(),
+                oracle,
+                slash,
+            );
+            let slashed = slash.saturating_sub(missing);
+            info.stake = info.stake.saturating_sub(slashed);
+            T::Slash::on_unbalanced(imbalance);
+            Self::deposit_event(Event::StakeSlashed(oracle.clone(), slashed));
+
+            // `Balances::Reserves` and `System::Account` of the oracle.
+            T::DbWeight::get().reads_writes(2, 2)
+        }
+    }
+
+    impl<T: Config> OracleRegistryApi for Pallet<T> {
+        type AccountId = T::AccountId;
+        type Balance = BalanceOf<T>;
+
+        fn fallback_oracle(oracle: &Self::AccountId) -> Option<Self::AccountId> {
+            Oracles::<T>::get(oracle).and_then(|info| info.fallback).filter(|fallback| {
+                Oracles::<T>::contains_key(fallback)
+                    && !UnbondingOracles::<T>::contains_key(fallback)
+            })
+        }
+
+        fn reputation(oracle: &Self::AccountId) -> Option<u32> {
+            Oracles::<T>::get(oracle).map(|info| info.reputation)
+        }
+
+        fn on_accurate_report(oracle: &Self::AccountId, market_stake: Self::Balance) -> Weight {
+            Self::update_reputation(oracle, |info| {
+                info.reputation =
+                    info.reputation.saturating_add(Self::reputation_weight(market_stake));
+                info.accurate_reports = info.accurate_reports.saturating_add(1);
+                Weight::zero()
+            })
+        }
+
+        fn on_missed_report(oracle: &Self::AccountId, market_stake: Self::Balance) -> Weight {
+            Self::update_reputation(oracle, |info| {
+                info.missed_reports = info.missed_reports.saturating_add(1);
+                Self::penalize(oracle, info, market_stake)
+            })
+        }
+
+        fn on_overturned_report(oracle: &Self::AccountId, market_stake: Self::Balance) -> Weight {
+            Self::update_reputation(oracle, |info| {
+                info.overturned_reports = info.overturned_reports.saturating_add(1);
+                Self::penalize(oracle, info, market_stake)
+            })
+        }
+    }
+}
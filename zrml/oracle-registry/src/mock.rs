@@ -0,0 +1,132 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(test)]
+
+use crate::{self as zrml_oracle_registry};
+use frame_support::{construct_runtime, traits::Everything};
+use frame_system::mocking::MockBlock;
+use sp_runtime::{
+    traits::{BlakeTwo256, IdentityLookup},
+    BuildStorage,
+};
+use zeitgeist_primitives::{
+    constants::mock::{
+        BlockHashCount, MaxLocks, MaxReserves, MinOracleStake, OracleRegistryPalletId,
+        OracleUnbondingPeriod, ReportSlashPercentage, ReputationPenalty, StakePerReputationPoint,
+        BASE,
+    },
+    types::{AccountIdTest, Balance, Hash},
+};
+
+pub const ALICE: AccountIdTest = 0;
+pub const BOB: AccountIdTest = 1;
+pub const CHARLIE: AccountIdTest = 2;
+
+construct_runtime!(
+    pub enum Runtime {
+        Balances: pallet_balances,
+        OracleRegistry: zrml_oracle_registry,
+        System: frame_system,
+    }
+);
+
+impl crate::Config for Runtime {
+    type Currency = Balances;
+    type MinOracleStake = MinOracleStake;
+    type PalletId = OracleRegistryPalletId;
+    type ReportSlashPercentage = ReportSlashPercentage;
+    type ReputationPenalty = ReputationPenalty;
+    type RuntimeEvent = RuntimeEvent;
+    type Slash = ();
+    type StakePerReputationPoint = StakePerReputationPoint;
+    type UnbondingPeriod = OracleUnbondingPeriod;
+    type WeightInfo = zrml_oracle_registry::weights::WeightInfo<Runtime>;
+}
+
+impl frame_system::Config for Runtime {
+    type AccountData = pallet_balances::AccountData<Balance>;
+    type AccountId = AccountIdTest;
+    type BaseCallFilter = Everything;
+    type Block = MockBlock<Runtime>;
+    type BlockHashCount = BlockHashCount;
+    type BlockLength = ();
+    type BlockWeights = ();
+    type RuntimeCall = RuntimeCall;
+    type RuntimeTask = RuntimeTask;
+    type DbWeight = ();
+    type RuntimeEvent = RuntimeEvent;
+    type Hash = Hash;
+    type Hashing = BlakeTwo256;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Nonce = u64;
+    type MaxConsumers = frame_support::traits::ConstU32<16>;
+    type MultiBlockMigrator = ();
+    type OnKilledAccount = ();
+    type OnNewAccount = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type PalletInfo = PalletInfo;
+    type PreInherents = ();
+    type PostInherents = ();
+    type PostTransactions = ();
+    type SingleBlockMigrations = ();
+    type SS58Prefix = ();
+    type SystemWeightInfo = ();
+    type Version = ();
+    type OnSetCode = ();
+}
+
+impl pallet_balances::Config for Runtime {
+    type AccountStore = System;
+    type Balance = Balance;
+    type DustRemoval = ();
+    type FreezeIdentifier = ();
+    type RuntimeHoldReason = ();
+    type RuntimeEvent = RuntimeEvent;
+    type ExistentialDeposit = ();
+    type MaxFreezes = ();
+    type MaxLocks = MaxLocks;
+    type MaxReserves = MaxReserves;
+    type ReserveIdentifier = [u8; 8];
+    type RuntimeFreezeReason = ();
+    type WeightInfo = ();
+}
+
+pub struct ExtBuilder {
+    balances: Vec<(AccountIdTest, Balance)>,
+}
+
+impl Default for ExtBuilder {
+    fn default() -> Self {
+        Self { balances: vec![(ALICE, 1_000 * BASE), (BOB, 1_000 * BASE), (CHARLIE, 1_000 * BASE)] }
+    }
+}
+
+impl ExtBuilder {
+    pub fn build(self) -> sp_io::TestExternalities {
+        let mut t = frame_system::GenesisConfig::<Runtime>::default().build_storage().unwrap();
+
+        // see the logs in tests when using `RUST_LOG=debug cargo test -- --nocapture`
+        let _ = env_logger::builder().is_test(true).try_init();
+
+        pallet_balances::GenesisConfig::<Runtime> { balances: self.balances }
+            .assimilate_storage(&mut t)
+            .unwrap();
+
+        t.into()
+    }
+}
@@ -0,0 +1,391 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(test)]
+
+use crate::{mock::*, types::OracleInfo, Error, Event, Oracles, UnbondingOracles};
+use frame_support::{assert_noop, assert_ok, traits::NamedReservableCurrency};
+use zeitgeist_primitives::{
+    constants::mock::{
+        MinOracleStake, OracleUnbondingPeriod, ReportSlashPercentage, ReputationPenalty,
+        StakePerReputationPoint, BASE,
+    },
+    traits::OracleRegistryApi,
+};
+
+fn registered_oracle(stake: u128) -> OracleInfo<AccountIdTest, u128> {
+    OracleInfo {
+        stake,
+        fallback: None,
+        reputation: 0,
+        accurate_reports: 0,
+        missed_reports: 0,
+        overturned_reports: 0,
+    }
+}
+
+#[test]
+fn register_oracle_reserves_stake_and_stores_oracle() {
+    ExtBuilder::default().build().execute_with(|| {
+        frame_system::Pallet::<Runtime>::set_block_number(1);
+        let stake = 20 * BASE;
+        assert_ok!(OracleRegistry::register_oracle(RuntimeOrigin::signed(ALICE), stake));
+        System::assert_last_event(Event::OracleRegistered(ALICE, stake).into());
+        assert_eq!(Oracles::<Runtime>::get(ALICE), Some(registered_oracle(stake)));
+        assert_eq!(Balances::reserved_balance_named(&OracleRegistry::reserve_id(), &ALICE), stake);
+        assert_eq!(OracleRegistry::reputation(&ALICE), Some(0));
+    });
+}
+
+#[test]
+fn register_oracle_fails_if_stake_is_too_low() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            OracleRegistry::register_oracle(
+                RuntimeOrigin::signed(ALICE),
+                MinOracleStake::get() - 1
+            ),
+            Error::<Runtime>::StakeTooLow
+        );
+    });
+}
+
+#[test]
+fn register_oracle_fails_if_already_registered() {
+    ExtBuilder::default().build().execute_with(|| {
+        let stake = MinOracleStake::get();
+        assert_ok!(OracleRegistry::register_oracle(RuntimeOrigin::signed(ALICE), stake));
+        assert_noop!(
+            OracleRegistry::register_oracle(RuntimeOrigin::signed(ALICE), stake),
+            Error::<Runtime>::OracleAlreadyRegistered
+        );
+    });
+}
+
+#[test]
+fn unregister_oracle_starts_unbonding() {
+    ExtBuilder::default().build().execute_with(|| {
+        frame_system::Pallet::<Runtime>::set_block_number(1);
+        let stake = MinOracleStake::get();
+        assert_ok!(OracleRegistry::register_oracle(RuntimeOrigin::signed(ALICE), stake));
+        assert_ok!(OracleRegistry::unregister_oracle(RuntimeOrigin::signed(ALICE)));
+        let unbonded_at = 1 + OracleUnbondingPeriod::get();
+        System::assert_last_event(Event::OracleUnbonding(ALICE, unbonded_at).into());
+        assert_eq!(UnbondingOracles::<Runtime>::get(ALICE), Some(unbonded_at));
+        assert_eq!(Oracles::<Runtime>::get(ALICE), Some(registered_oracle(stake)));
+        assert_eq!(Balances::reserved_balance_named(&OracleRegistry::reserve_id(), &ALICE), stake);
+    });
+}
+
+#[test]
+fn unregister_oracle_fails_if_already_unbonding() {
+    ExtBuilder::default().build().execute_with(|| {
+        let stake = MinOracleStake::get();
+        assert_ok!(OracleRegistry::register_oracle(RuntimeOrigin::signed(ALICE), stake));
+        assert_ok!(OracleRegistry::unregister_oracle(RuntimeOrigin::signed(ALICE)));
+        assert_noop!(
+            OracleRegistry::unregister_oracle(RuntimeOrigin::signed(ALICE)),
+            Error::<Runtime>::OracleIsUnbonding
+        );
+    });
+}
+
+#[test]
+fn withdraw_stake_unreserves_stake_and_removes_oracle() {
+    ExtBuilder::default().build().execute_with(|| {
+        frame_system::Pallet::<Runtime>::set_block_number(1);
+        let stake = MinOracleStake::get();
+        let free_balance = Balances::free_balance(ALICE);
+        assert_ok!(OracleRegistry::register_oracle(RuntimeOrigin::signed(ALICE), stake));
+        assert_ok!(OracleRegistry::unregister_oracle(RuntimeOrigin::signed(ALICE)));
+        frame_system::Pallet::<Runtime>::set_block_number(1 + OracleUnbondingPeriod::get());
+        assert_ok!(OracleRegistry::withdraw_stake(RuntimeOrigin::signed(ALICE)));
+        System::assert_last_event(Event::OracleUnregistered(ALICE, stake).into());
+        assert_eq!(Oracles::<Runtime>::get(ALICE), None);
+        assert_eq!(UnbondingOracles::<Runtime>::get(ALICE), None);
+        assert_eq!(Balances::free_balance(ALICE), free_balance);
+        assert_eq!(OracleRegistry::reputation(&ALICE), None);
+    });
+}
+
+#[test]
+fn withdraw_stake_fails_if_not_unbonding() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(OracleRegistry::register_oracle(
+            RuntimeOrigin::signed(ALICE),
+            MinOracleStake::get()
+        ));
+        assert_noop!(
+            OracleRegistry::withdraw_stake(RuntimeOrigin::signed(ALICE)),
+            Error::<Runtime>::OracleNotUnbonding
+        );
+    });
+}
+
+#[test]
+fn withdraw_stake_fails_if_unbonding_period_is_not_over() {
+    ExtBuilder::default().build().execute_with(|| {
+        frame_system::Pallet::<Runtime>::set_block_number(1);
+        assert_ok!(OracleRegistry::register_oracle(
+            RuntimeOrigin::signed(ALICE),
+            MinOracleStake::get()
+        ));
+        assert_ok!(OracleRegistry::unregister_oracle(RuntimeOrigin::signed(ALICE)));
+        frame_system::Pallet::<Runtime>::set_block_number(OracleUnbondingPeriod::get());
+        assert_noop!(
+            OracleRegistry::withdraw_stake(RuntimeOrigin::signed(ALICE)),
+            Error::<Runtime>::UnbondingPeriodNotOver
+        );
+    });
+}
+
+#[test]
+fn unbonding_oracle_is_slashed_for_missed_report() {
+    ExtBuilder::default().build().execute_with(|| {
+        frame_system::Pallet::<Runtime>::set_block_number(1);
+        let stake = MinOracleStake::get();
+        let free_balance = Balances::free_balance(ALICE);
+        assert_ok!(OracleRegistry::register_oracle(RuntimeOrigin::signed(ALICE), stake));
+        assert_ok!(OracleRegistry::unregister_oracle(RuntimeOrigin::signed(ALICE)));
+        OracleRegistry::on_missed_report(&ALICE, 0);
+        let slashed = ReportSlashPercentage::get().mul_floor(stake);
+        frame_system::Pallet::<Runtime>::set_block_number(1 + OracleUnbondingPeriod::get());
+        assert_ok!(OracleRegistry::withdraw_stake(RuntimeOrigin::signed(ALICE)));
+        System::assert_last_event(Event::OracleUnregistered(ALICE, stake - slashed).into());
+        assert_eq!(Balances::free_balance(ALICE), free_balance - slashed);
+    });
+}
+
+#[test]
+fn unregister_oracle_fails_if_not_registered() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            OracleRegistry::unregister_oracle(RuntimeOrigin::signed(ALICE)),
+            Error::<Runtime>::OracleNotRegistered
+        );
+    });
+}
+
+#[test]
+fn set_fallback_oracle_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        frame_system::Pallet::<Runtime>::set_block_number(1);
+        let stake = MinOracleStake::get();
+        assert_ok!(OracleRegistry::register_oracle(RuntimeOrigin::signed(ALICE), stake));
+        assert_ok!(OracleRegistry::register_oracle(RuntimeOrigin::signed(BOB), stake));
+        assert_ok!(OracleRegistry::set_fallback_oracle(RuntimeOrigin::signed(ALICE), Some(BOB)));
+        System::assert_last_event(Event::FallbackOracleSet(ALICE, Some(BOB)).into());
+        assert_eq!(OracleRegistry::fallback_oracle(&ALICE), Some(BOB));
+        assert_ok!(OracleRegistry::set_fallback_oracle(RuntimeOrigin::signed(ALICE), None));
+        System::assert_last_event(Event::FallbackOracleSet(ALICE, None).into());
+        assert_eq!(OracleRegistry::fallback_oracle(&ALICE), None);
+    });
+}
+
+#[test]
+fn set_fallback_oracle_fails_if_caller_is_not_registered() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(OracleRegistry::register_oracle(
+            RuntimeOrigin::signed(BOB),
+            MinOracleStake::get()
+        ));
+        assert_noop!(
+            OracleRegistry::set_fallback_oracle(RuntimeOrigin::signed(ALICE), Some(BOB)),
+            Error::<Runtime>::OracleNotRegistered
+        );
+    });
+}
+
+#[test]
+fn set_fallback_oracle_fails_if_fallback_is_not_registered() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(OracleRegistry::register_oracle(
+            RuntimeOrigin::signed(ALICE),
+            MinOracleStake::get()
+        ));
+        assert_noop!(
+            OracleRegistry::set_fallback_oracle(RuntimeOrigin::signed(ALICE), Some(BOB)),
+            Error::<Runtime>::FallbackOracleNotRegistered
+        );
+    });
+}
+
+#[test]
+fn set_fallback_oracle_fails_if_caller_is_unbonding() {
+    ExtBuilder::default().build().execute_with(|| {
+        let stake = MinOracleStake::get();
+        assert_ok!(OracleRegistry::register_oracle(RuntimeOrigin::signed(ALICE), stake));
+        assert_ok!(OracleRegistry::register_oracle(RuntimeOrigin::signed(BOB), stake));
+        assert_ok!(OracleRegistry::unregister_oracle(RuntimeOrigin::signed(ALICE)));
+        assert_noop!(
+            OracleRegistry::set_fallback_oracle(RuntimeOrigin::signed(ALICE), Some(BOB)),
+            Error::<Runtime>::OracleIsUnbonding
+        );
+    });
+}
+
+#[test]
+fn set_fallback_oracle_fails_if_fallback_is_unbonding() {
+    ExtBuilder::default().build().execute_with(|| {
+        let stake = MinOracleStake::get();
+        assert_ok!(OracleRegistry::register_oracle(RuntimeOrigin::signed(ALICE), stake));
+        assert_ok!(OracleRegistry::register_oracle(RuntimeOrigin::signed(BOB), stake));
+        assert_ok!(OracleRegistry::unregister_oracle(RuntimeOrigin::signed(BOB)));
+        assert_noop!(
+            OracleRegistry::set_fallback_oracle(RuntimeOrigin::signed(ALICE), Some(BOB)),
+            Error::<Runtime>::FallbackOracleIsUnbonding
+        );
+    });
+}
+
+#[test]
+fn set_fallback_oracle_fails_if_fallback_is_caller() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(OracleRegistry::register_oracle(
+            RuntimeOrigin::signed(ALICE),
+            MinOracleStake::get()
+        ));
+        assert_noop!(
+            OracleRegistry::set_fallback_oracle(RuntimeOrigin::signed(ALICE), Some(ALICE)),
+            Error::<Runtime>::FallbackOracleIsSelf
+        );
+    });
+}
+
+#[test]
+fn fallback_oracle_is_ignored_after_it_unregistered() {
+    ExtBuilder::default().build().execute_with(|| {
+        let stake = MinOracleStake::get();
+        assert_ok!(OracleRegistry::register_oracle(RuntimeOrigin::signed(ALICE), stake));
+        assert_ok!(OracleRegistry::register_oracle(RuntimeOrigin::signed(BOB), stake));
+        assert_ok!(OracleRegistry::set_fallback_oracle(RuntimeOrigin::signed(ALICE), Some(BOB)));
+        assert_ok!(OracleRegistry::unregister_oracle(RuntimeOrigin::signed(BOB)));
+        assert_eq!(OracleRegistry::fallback_oracle(&ALICE), None);
+    });
+}
+
+#[test]
+fn reputation_hooks_update_reputation_and_counters() {
+    ExtBuilder::default().build().execute_with(|| {
+        frame_system::Pallet::<Runtime>::set_block_number(1);
+        let stake = MinOracleStake::get();
+        assert_ok!(OracleRegistry::register_oracle(RuntimeOrigin::signed(ALICE), stake));
+        let penalty = ReputationPenalty::get();
+        let market_stake = StakePerReputationPoint::get();
+        for _ in 0..penalty + 1 {
+            OracleRegistry::on_accurate_report(&ALICE, market_stake);
+        }
+        System::assert_last_event(Event::ReputationUpdated(ALICE, penalty + 1).into());
+        OracleRegistry::on_missed_report(&ALICE, market_stake);
+        System::assert_last_event(Event::ReputationUpdated(ALICE, 1).into());
+        OracleRegistry::on_overturned_report(&ALICE, market_stake);
+        System::assert_last_event(Event::ReputationUpdated(ALICE, 0).into());
+        let first_slash = ReportSlashPercentage::get().mul_floor(stake);
+        let second_slash = ReportSlashPercentage::get().mul_floor(stake - first_slash);
+        assert_eq!(
+            Oracles::<Runtime>::get(ALICE),
+            Some(OracleInfo {
+                reputation: 0,
+                accurate_reports: penalty + 1,
+                missed_reports: 1,
+                overturned_reports: 1,
+                ..registered_oracle(stake - first_slash - second_slash)
+            })
+        );
+    });
+}
+
+#[test]
+fn reputation_hooks_are_weighted_by_market_stake() {
+    ExtBuilder::default().build().execute_with(|| {
+        let stake = MinOracleStake::get();
+        assert_ok!(OracleRegistry::register_oracle(RuntimeOrigin::signed(ALICE), stake));
+        let unit = StakePerReputationPoint::get();
+        OracleRegistry::on_accurate_report(&ALICE, unit - 1);
+        assert_eq!(OracleRegistry::reputation(&ALICE), Some(0));
+        OracleRegistry::on_accurate_report(&ALICE, 5 * unit + unit / 2);
+        assert_eq!(OracleRegistry::reputation(&ALICE), Some(5));
+        OracleRegistry::on_accurate_report(&ALICE, 10 * unit);
+        assert_eq!(OracleRegistry::reputation(&ALICE), Some(15));
+        // The penalty is multiplied by the reputation weight of the market, which is at least one.
+        OracleRegistry::on_missed_report(&ALICE, 0);
+        assert_eq!(OracleRegistry::reputation(&ALICE), Some(15 - ReputationPenalty::get()));
+        OracleRegistry::on_overturned_report(&ALICE, 2 * unit);
+        assert_eq!(OracleRegistry::reputation(&ALICE), Some(15 - 3 * ReputationPenalty::get()));
+    });
+}
+
+#[test]
+fn penalties_slash_stake() {
+    ExtBuilder::default().build().execute_with(|| {
+        frame_system::Pallet::<Runtime>::set_block_number(1);
+        let stake = 20 * BASE;
+        let total_issuance = Balances::total_issuance();
+        assert_ok!(OracleRegistry::register_oracle(RuntimeOrigin::signed(ALICE), stake));
+        OracleRegistry::on_missed_report(&ALICE, 0);
+        let slashed = ReportSlashPercentage::get().mul_floor(stake);
+        System::assert_has_event(Event::StakeSlashed(ALICE, slashed).into());
+        assert_eq!(Oracles::<Runtime>::get(ALICE).unwrap().stake, stake - slashed);
+        assert_eq!(
+            Balances::reserved_balance_named(&OracleRegistry::reserve_id(), &ALICE),
+            stake - slashed
+        );
+        assert_eq!(Balances::total_issuance(), total_issuance - slashed);
+    });
+}
+
+#[test]
+fn reputation_hooks_do_nothing_for_unregistered_oracles() {
+    ExtBuilder::default().build().execute_with(|| {
+        OracleRegistry::on_accurate_report(&ALICE, StakePerReputationPoint::get());
+        OracleRegistry::on_missed_report(&ALICE, StakePerReputationPoint::get());
+        OracleRegistry::on_overturned_report(&ALICE, StakePerReputationPoint::get());
+        assert_eq!(Oracles::<Runtime>::get(ALICE), None);
+        assert!(System::events().is_empty());
+    });
+}
+
+#[test]
+fn oracles_filters_by_reputation_and_sorts_descending() {
+    ExtBuilder::default().build().execute_with(|| {
+        let stake = MinOracleStake::get();
+        for (oracle, reputation) in [(ALICE, 1), (BOB, 3), (CHARLIE, 0)] {
+            assert_ok!(OracleRegistry::register_oracle(RuntimeOrigin::signed(oracle), stake));
+            for _ in 0..reputation {
+                OracleRegistry::on_accurate_report(&oracle, StakePerReputationPoint::get());
+            }
+        }
+        let oracles: Vec<_> = OracleRegistry::oracles(1)
+            .into_iter()
+            .map(|(oracle, info)| (oracle, info.reputation))
+            .collect();
+        assert_eq!(oracles, vec![(BOB, 3), (ALICE, 1)]);
+    });
+}
+
+#[test]
+fn oracles_excludes_unbonding_oracles() {
+    ExtBuilder::default().build().execute_with(|| {
+        let stake = MinOracleStake::get();
+        assert_ok!(OracleRegistry::register_oracle(RuntimeOrigin::signed(ALICE), stake));
+        assert_ok!(OracleRegistry::register_oracle(RuntimeOrigin::signed(BOB), stake));
+        assert_ok!(OracleRegistry::unregister_oracle(RuntimeOrigin::signed(BOB)));
+        let oracles: Vec<_> =
+            OracleRegistry::oracles(0).into_iter().map(|(oracle, _)| oracle).collect();
+        assert_eq!(oracles, vec![ALICE]);
+    });
+}
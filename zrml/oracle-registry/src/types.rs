@@ -0,0 +1,40 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+/// The record of a registered oracle.
+#[derive(Clone, Decode, Encode, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct OracleInfo<AccountId, Balance> {
+    /// The amount of ZTG reserved from the oracle while it's registered. Reduced whenever the
+    /// oracle is slashed.
+    pub stake: Balance,
+    /// The oracle which is given an exclusive reporting window if this oracle misses its own.
+    pub fallback: Option<AccountId>,
+    /// Increased by the reputation weight of the market for every on-time report that was
+    /// resolved without a dispute and decreased by `ReputationPenalty` times the reputation weight
+    /// (at least one) for every missed or overturned report.
+    pub reputation: u32,
+    /// The number of on-time reports which were resolved without a dispute.
+    pub accurate_reports: u32,
+    /// The number of reports which the oracle missed.
+    pub missed_reports: u32,
+    /// The number of on-time reports whose outcome was overturned by a dispute.
+    pub overturned_reports: u32,
+}
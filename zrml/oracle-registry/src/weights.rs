@@ -0,0 +1,92 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+//! Placeholder weights for zrml_oracle_registry
+//!
+//! These weights are hand-written estimates and have not been measured. Run the
+//! benchmarks of `zrml_oracle_registry` on reference hardware and replace this
+//! file with the generated output before the pallet is added to a runtime.
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use core::marker::PhantomData;
+use frame_support::{traits::Get, weights::Weight};
+
+///  Trait containing the required functions for weight retrival within
+/// zrml_oracle_registry (automatically generated)
+pub trait WeightInfoZeitgeist {
+    fn register_oracle() -> Weight;
+    fn set_fallback_oracle() -> Weight;
+    fn unregister_oracle() -> Weight;
+    fn withdraw_stake() -> Weight;
+}
+
+/// Weight functions for zrml_oracle_registry (automatically generated)
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
+    /// Storage: `OracleRegistry::Oracles` (r:1 w:1)
+    /// Proof: `OracleRegistry::Oracles` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Reserves` (r:1 w:1)
+    /// Proof: `Balances::Reserves` (`max_values`: None, `max_size`: Some(1249), added: 3724, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+    fn register_oracle() -> Weight {
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_oracle_registry` to replace it.
+        Weight::from_parts(46_210_000, 4714)
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+    /// Storage: `OracleRegistry::UnbondingOracles` (r:2 w:0)
+    /// Proof: `OracleRegistry::UnbondingOracles` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+    /// Storage: `OracleRegistry::Oracles` (r:2 w:1)
+    /// Proof: `OracleRegistry::Oracles` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+    fn set_fallback_oracle() -> Weight {
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_oracle_registry` to replace it.
+        Weight::from_parts(19_640_000, 6166)
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: `OracleRegistry::Oracles` (r:1 w:0)
+    /// Proof: `OracleRegistry::Oracles` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+    /// Storage: `OracleRegistry::UnbondingOracles` (r:1 w:1)
+    /// Proof: `OracleRegistry::UnbondingOracles` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+    fn unregister_oracle() -> Weight {
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_oracle_registry` to replace it.
+        Weight::from_parts(17_940_000, 3578)
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: `OracleRegistry::UnbondingOracles` (r:1 w:1)
+    /// Proof: `OracleRegistry::UnbondingOracles` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+    /// Storage: `OracleRegistry::Oracles` (r:1 w:1)
+    /// Proof: `OracleRegistry::Oracles` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Reserves` (r:1 w:1)
+    /// Proof: `Balances::Reserves` (`max_values`: None, `max_size`: Some(1249), added: 3724, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+    fn withdraw_stake() -> Weight {
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_oracle_registry` to replace it.
+        Weight::from_parts(48_720_000, 4714)
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(4))
+    }
+}
@@ -28,7 +28,7 @@ use frame_benchmarking::{account, benchmarks, whitelisted_caller};
 use frame_support::traits::UnfilteredDispatchable;
 use frame_system::RawOrigin;
 use orml_traits::MultiCurrency;
-use sp_runtime::SaturatedConversion;
+use sp_runtime::{traits::Get, SaturatedConversion};
 use zeitgeist_primitives::{constants::BASE, types::Asset};
 
 fn generate_funded_account<T: Config>(
@@ -56,6 +56,23 @@ fn order_common_parameters<T: Config>(
     Ok((market_id, acc, maker_asset, maker_amount, taker_amount))
 }
 
+// Fills the price index of the asset pair with `len` orders, each of them with a better price than
+// the orders placed in the benchmarks, and stores an unindexed order with a worse price.
+fn fill_price_index<T: Config>(maker_asset: AssetOf<T>, taker_asset: AssetOf<T>, len: u32) {
+    let price: BalanceOf<T> = BASE.saturated_into();
+    let price_index = (1..=len)
+        .map(|i| (price, OrderId::MAX - OrderId::from(i)))
+        .collect::<Vec<_>>()
+        .try_into()
+        .unwrap();
+    let pair = (maker_asset, taker_asset);
+    <OrderPriceIndex<T>>::insert(pair, price_index);
+    let unindexed_price: BalanceOf<T> = (BASE / 2).saturated_into();
+    let unindexed_order_id = OrderId::MAX;
+    let key = Orderbook::<T>::unindexed_order_key(unindexed_price, unindexed_order_id, true);
+    <UnindexedOrders<T>>::insert(pair, key, (unindexed_price, unindexed_order_id));
+}
+
 fn place_default_order<T: Config>(
     seed: Option<u32>,
     taker_asset: AssetOf<T>,
@@ -63,6 +80,8 @@ fn place_default_order<T: Config>(
     let (market_id, acc, maker_asset, maker_amount, taker_amount) =
         order_common_parameters::<T>(seed)?;
 
+    // The placed order fills up the price index.
+    fill_price_index::<T>(maker_asset, taker_asset, T::MaxIndexedOrders::get() - 1);
     let order_id = <NextOrderId<T>>::get();
     let _ =
         Call::<T>::place_order { market_id, maker_asset, maker_amount, taker_asset, taker_amount }
@@ -91,6 +110,8 @@ benchmarks! {
         let (market_id, caller, maker_asset, maker_amount, taker_amount) =
             order_common_parameters::<T>(None)?;
         let taker_asset = Asset::CategoricalOutcome::<MarketIdOf<T>>(market_id, 0);
+        // The placed order is moved to the unindexed orders.
+        fill_price_index::<T>(maker_asset, taker_asset, T::MaxIndexedOrders::get());
     }: {
         Orderbook::<T>::place_order(
             RawOrigin::Signed(caller).into(),
@@ -27,10 +27,11 @@ use core::marker::PhantomData;
 use frame_support::{
     ensure,
     pallet_prelude::{
-        DispatchError, DispatchResult, OptionQuery, StorageMap, StorageValue, ValueQuery,
+        DispatchError, DispatchResult, OptionQuery, StorageDoubleMap, StorageMap, StorageValue,
+        ValueQuery,
     },
     traits::{IsType, StorageVersion},
-    transactional, PalletId, Twox64Concat,
+    transactional, Blake2_128Concat, BoundedVec, Identity, PalletId, Twox64Concat,
 };
 use frame_system::{
     ensure_signed,
@@ -38,7 +39,7 @@ use frame_system::{
 };
 use orml_traits::{BalanceStatus, MultiCurrency, NamedMultiReservableCurrency};
 pub use pallet::*;
-use sp_runtime::traits::{Get, Zero};
+use sp_runtime::traits::{Get, UniqueSaturatedInto, Zero};
 use zeitgeist_primitives::{
     hybrid_router_api_types::{ApiError, ExternalFee, OrderbookSoftFail, OrderbookTrade},
     math::{
@@ -91,6 +92,15 @@ mod pallet {
             Balance = BalanceOf<Self>,
         >;
 
+        /// The maximum number of orders per pair of maker and taker asset which are indexed by
+        /// price.
+        #[pallet::constant]
+        type MaxIndexedOrders: Get<u32>;
+
+        /// The minimum amount of base asset which an order must offer or ask for.
+        #[pallet::constant]
+        type MinOrderAmount: Get<BalanceOf<Self>>;
+
         #[pallet::constant]
         type PalletId: Get<PalletId>;
 
@@ -98,7 +108,7 @@ mod pallet {
     }
 
     /// The current storage version.
-    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+    pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
     pub(crate) type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
     pub(crate) type AssetOf<T> = Asset<MarketIdOf<T>>;
@@ -113,6 +123,8 @@ mod pallet {
     pub(crate) type MomentOf<T> = <<T as Config>::MarketCommons as MarketCommonsPalletApi>::Moment;
     pub(crate) type OrderOf<T> = Order<AccountIdOf<T>, BalanceOf<T>, MarketIdOf<T>>;
     pub(crate) type OrderbookTradeOf<T> = OrderbookTrade<AccountIdOf<T>, BalanceOf<T>>;
+    pub(crate) type PriceIndexOf<T> =
+        BoundedVec<(BalanceOf<T>, OrderId), <T as Config>::MaxIndexedOrders>;
 
     #[pallet::pallet]
     #[pallet::storage_version(STORAGE_VERSION)]
@@ -124,6 +136,32 @@ mod pallet {
     #[pallet::storage]
     pub type Orders<T: Config> = StorageMap<_, Twox64Concat, OrderId, OrderOf<T>, OptionQuery>;
 
+    /// Maps a pair of maker and taker asset to the prices and IDs of the orders which offer the
+    /// maker asset for the taker asset, sorted from the best to the worst price for takers (i.e.
+    /// ascending if the maker asset is an outcome, descending if it's the base asset). Orders with
+    /// equal prices are sorted by age.
+    ///
+    /// Only the best `MaxIndexedOrders` orders are indexed. Orders which don't make the cut are
+    /// stored in `UnindexedOrders` and move up into the index when indexed orders are removed.
+    #[pallet::storage]
+    pub type OrderPriceIndex<T: Config> =
+        StorageMap<_, Blake2_128Concat, (AssetOf<T>, AssetOf<T>), PriceIndexOf<T>, ValueQuery>;
+
+    /// Maps a pair of maker and taker asset and a sort key to the price and ID of an order which
+    /// didn't make the cut for `OrderPriceIndex`. The sort key is built by
+    /// `Pallet::unindexed_order_key` so that iterating over a pair yields the orders from the
+    /// best to the worst price for takers.
+    #[pallet::storage]
+    pub type UnindexedOrders<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        (AssetOf<T>, AssetOf<T>),
+        Identity,
+        [u8; 32],
+        (BalanceOf<T>, OrderId),
+        OptionQuery,
+    >;
+
     #[pallet::event]
     #[pallet::generate_deposit(fn deposit_event)]
     pub enum Event<T>
@@ -170,6 +208,8 @@ mod pallet {
         MarketBaseAssetNotPresent,
         /// The specified amount is below the minimum balance.
         BelowMinimumBalance,
+        /// The amount of base asset of the order is below the minimum order amount.
+        BelowMinimumOrderAmount,
     }
 
     #[pallet::call]
@@ -311,11 +351,148 @@ mod pallet {
             Ok(())
         }
 
+        /// Returns `true` if the order offers the base asset for an outcome.
+        fn is_bid(maker_asset: &AssetOf<T>) -> bool {
+            !matches!(maker_asset, Asset::CategoricalOutcome(..) | Asset::ScalarOutcome(..))
+        }
+
+        /// Returns the price of the order in the base asset.
+        fn order_price(order_data: &OrderOf<T>) -> Result<BalanceOf<T>, DispatchError> {
+            let base_asset = if Self::is_bid(&order_data.maker_asset) {
+                order_data.maker_asset
+            } else {
+                order_data.taker_asset
+            };
+            order_data.price(base_asset)
+        }
+
+        /// Returns the key of an order in `UnindexedOrders`. Keys are compared bytewise, so the
+        /// price comes first (inverted for bids) and ties are broken by the age of the order.
+        pub(crate) fn unindexed_order_key(
+            price: BalanceOf<T>,
+            order_id: OrderId,
+            is_bid: bool,
+        ) -> [u8; 32] {
+            let price: u128 = price.unique_saturated_into();
+            let price = if is_bid { u128::MAX.saturating_sub(price) } else { price };
+            let mut key = [0u8; 32];
+            key[..16].copy_from_slice(&price.to_be_bytes());
+            key[16..].copy_from_slice(&order_id.to_be_bytes());
+            key
+        }
+
+        /// Inserts the order into the price index of its asset pair. If the index is full, the
+        /// order with the worst price is moved from the index to `UnindexedOrders`; if that's the
+        /// inserted order, the index remains unchanged.
+        pub(crate) fn insert_into_price_index(
+            order_id: OrderId,
+            order_data: &OrderOf<T>,
+            base_asset: AssetOf<T>,
+        ) -> DispatchResult {
+            let price = order_data.price(base_asset)?;
+            let is_bid = order_data.maker_asset == base_asset;
+            let pair = (order_data.maker_asset, order_data.taker_asset);
+            let unindexed = <OrderPriceIndex<T>>::mutate(pair, |price_index| {
+                // Insert after all orders with better or equal price.
+                let position = price_index.partition_point(|(indexed_price, _)| {
+                    if is_bid {
+                        *indexed_price >= price
+                    } else {
+                        *indexed_price <= price
+                    }
+                });
+                match price_index.force_insert_keep_left(position, (price, order_id)) {
+                    Ok(dropped) => dropped,
+                    Err(rejected) => Some(rejected),
+                }
+            });
+            if let Some((unindexed_price, unindexed_order_id)) = unindexed {
+                let key = Self::unindexed_order_key(unindexed_price, unindexed_order_id, is_bid);
+                <UnindexedOrders<T>>::insert(pair, key, (unindexed_price, unindexed_order_id));
+            }
+            Ok(())
+        }
+
+        /// Re-indexes a partially filled order if rounding changed its price. Otherwise, the order
+        /// keeps its position in the price index.
+        fn update_price_index(
+            order_id: OrderId,
+            order_data: &OrderOf<T>,
+            price_before: BalanceOf<T>,
+            base_asset: AssetOf<T>,
+        ) -> DispatchResult {
+            let price = order_data.price(base_asset)?;
+            if price != price_before {
+                Self::remove_from_price_index(order_id, order_data, price_before);
+                Self::insert_into_price_index(order_id, order_data, base_asset)?;
+            }
+            Ok(())
+        }
+
+        /// Removes the order, which was indexed at `price`, from the price index of its asset pair
+        /// or from `UnindexedOrders`. If the order was in the price index, the best unindexed order
+        /// takes its place.
+        fn remove_from_price_index(
+            order_id: OrderId,
+            order_data: &OrderOf<T>,
+            price: BalanceOf<T>,
+        ) {
+            let is_bid = Self::is_bid(&order_data.maker_asset);
+            let pair = (order_data.maker_asset, order_data.taker_asset);
+            let mut price_index = <OrderPriceIndex<T>>::get(pair);
+            let len_before = price_index.len();
+            price_index.retain(|(_, indexed_order_id)| *indexed_order_id != order_id);
+            if price_index.len() == len_before {
+                let key = Self::unindexed_order_key(price, order_id, is_bid);
+                <UnindexedOrders<T>>::remove(pair, key);
+                return;
+            }
+            // All unindexed orders are worse than or as good as the indexed orders, so the best
+            // one is appended.
+            if let Some((key, entry)) = <UnindexedOrders<T>>::iter_prefix(pair).next() {
+                <UnindexedOrders<T>>::remove(pair, key);
+                // The removal above freed up a slot, so this can't fail.
+                let _ = price_index.try_push(entry);
+            }
+            if price_index.is_empty() {
+                <OrderPriceIndex<T>>::remove(pair);
+            } else {
+                <OrderPriceIndex<T>>::insert(pair, price_index);
+            }
+        }
+
+        /// Returns the IDs of up to `limit` orders which offer `maker_asset` for `taker_asset` at
+        /// a price at least as good as `price_limit` for the taker, sorted from the best to the
+        /// worst price.
+        pub fn best_orders(
+            maker_asset: AssetOf<T>,
+            taker_asset: AssetOf<T>,
+            price_limit: BalanceOf<T>,
+            limit: u32,
+        ) -> Vec<OrderId> {
+            let is_bid = Self::is_bid(&maker_asset);
+            <OrderPriceIndex<T>>::get((maker_asset, taker_asset))
+                .into_iter()
+                .take_while(
+                    |(price, _)| {
+                        if is_bid {
+                            *price >= price_limit
+                        } else {
+                            *price <= price_limit
+                        }
+                    },
+                )
+                .take(limit as usize)
+                .map(|(_, order_id)| order_id)
+                .collect()
+        }
+
         fn do_remove_order(order_id: OrderId, who: AccountIdOf<T>) -> DispatchResult {
             let order_data = <Orders<T>>::get(order_id).ok_or(Error::<T>::OrderDoesNotExist)?;
 
             let maker = &order_data.maker;
             ensure!(who == *maker, Error::<T>::NotOrderCreator);
+            let price = Self::order_price(&order_data)?;
 
             let missing = T::AssetManager::unreserve_named(
                 &Self::reserve_id(),
@@ -336,6 +513,7 @@ mod pallet {
             );
 
             <Orders<T>>::remove(order_id);
+            Self::remove_from_price_index(order_id, &order_data, price);
 
             Self::deposit_event(Event::OrderRemoved { order_id, maker: maker.clone() });
 
@@ -402,6 +580,7 @@ mod pallet {
             let taker_asset = order_data.taker_asset;
 
             let taker_fill = Self::get_taker_fill(&order_data, maker_fill)?;
+            let price_before = order_data.price(base_asset)?;
 
             // if base asset: fund the full amount, but charge base asset fees from taker later
             T::AssetManager::repatriate_reserved_named(
@@ -437,8 +616,10 @@ mod pallet {
 
             if order_data.maker_amount.is_zero() {
                 <Orders<T>>::remove(order_id);
+                Self::remove_from_price_index(order_id, &order_data, price_before);
             } else {
                 <Orders<T>>::insert(order_id, order_data.clone());
+                Self::update_price_index(order_id, &order_data, price_before, base_asset)?;
             }
 
             Self::deposit_event(Event::OrderFilled {
@@ -494,6 +675,8 @@ mod pallet {
                 taker_amount >= T::AssetManager::minimum_balance(taker_asset),
                 Error::<T>::BelowMinimumBalance
             );
+            let base_amount = if maker_asset == base_asset { maker_amount } else { taker_amount };
+            ensure!(base_amount >= T::MinOrderAmount::get(), Error::<T>::BelowMinimumOrderAmount);
 
             let order_id = <NextOrderId<T>>::get();
             let next_order_id = order_id.checked_add_res(&1)?;
@@ -510,6 +693,7 @@ mod pallet {
                 taker_amount,
             };
 
+            Self::insert_into_price_index(order_id, &order, base_asset)?;
             <Orders<T>>::insert(order_id, order.clone());
             <NextOrderId<T>>::put(next_order_id);
             Self::deposit_event(Event::OrderPlaced { order_id, order });
@@ -521,10 +705,14 @@ mod pallet {
     impl<T: Config> Pallet<T> {
         fn match_failure(error: DispatchError) -> ApiError<OrderbookSoftFail> {
             let below_minimum_balance: DispatchError = Error::<T>::BelowMinimumBalance.into();
+            let below_minimum_order_amount: DispatchError =
+                Error::<T>::BelowMinimumOrderAmount.into();
             let partial_fill_near_full_fill_not_allowed: DispatchError =
                 Error::<T>::PartialFillNearFullFillNotAllowed.into();
             if error == below_minimum_balance {
                 ApiError::SoftFailure(OrderbookSoftFail::BelowMinimumBalance)
+            } else if error == below_minimum_order_amount {
+                ApiError::SoftFailure(OrderbookSoftFail::BelowMinimumOrderAmount)
             } else if error == partial_fill_near_full_fill_not_allowed {
                 ApiError::SoftFailure(OrderbookSoftFail::PartialFillNearFullFillNotAllowed)
             } else {
@@ -545,6 +733,15 @@ mod pallet {
             <Orders<T>>::get(order_id).ok_or(Error::<T>::OrderDoesNotExist.into())
         }
 
+        fn best_orders(
+            maker_asset: Self::Asset,
+            taker_asset: Self::Asset,
+            price_limit: Self::Balance,
+            limit: u32,
+        ) -> Vec<Self::OrderId> {
+            Self::best_orders(maker_asset, taker_asset, price_limit, limit)
+        }
+
         fn fill_order(
             who: Self::AccountId,
             order_id: Self::OrderId,
@@ -14,3 +14,159 @@
 //
 // You should have received a copy of the GNU General Public License
 // along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+pub mod mbm {
+    use crate::{Config, OrderPriceIndex, Orders, Pallet, UnindexedOrders};
+    use core::marker::PhantomData;
+    use frame_support::{
+        migrations::{SteppedMigration, SteppedMigrationError},
+        pallet_prelude::ConstU32,
+        traits::{Get, StorageVersion},
+        weights::WeightMeter,
+        BoundedVec,
+    };
+    use log::{info, warn};
+    use zeitgeist_primitives::{traits::MarketCommonsPalletApi, types::OrderId};
+
+    const LOG_TARGET: &str = "runtime::zrml-orderbook";
+
+    /// Target pallet storage version after migration.
+    const TARGET_STORAGE_VERSION: u16 = 2;
+
+    /// Multi-block migration that builds the price index from the existing orders. The cursor is
+    /// the ID of the last order that was processed.
+    pub struct BuildOrderPriceIndex<T>(PhantomData<T>);
+
+    impl<T: Config> BuildOrderPriceIndex<T> {
+        const IDENT: &'static [u8] = b"orderbook-price-index-v2";
+    }
+
+    impl<T: Config> SteppedMigration for BuildOrderPriceIndex<T> {
+        type Cursor = OrderId;
+        type Identifier = BoundedVec<u8, ConstU32<64>>;
+
+        fn id() -> Self::Identifier {
+            BoundedVec::try_from(Self::IDENT.to_vec()).expect("fits in Identifier bound; qed")
+        }
+
+        fn step(
+            cursor: Option<Self::Cursor>,
+            meter: &mut WeightMeter,
+        ) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+            if StorageVersion::get::<Pallet<T>>() >= StorageVersion::new(TARGET_STORAGE_VERSION) {
+                return Ok(None);
+            }
+
+            let db_weight = T::DbWeight::get();
+            // Read the order, its market and the unindexed orders, then read and write the price
+            // index and the unindexed orders.
+            let weight_per_order = db_weight.reads_writes(4, 2);
+
+            let mut iter = if let Some(last_order_id) = cursor {
+                Orders::<T>::iter_from(Orders::<T>::hashed_key_for(last_order_id))
+            } else {
+                Orders::<T>::iter()
+            };
+            let mut last_order_id = cursor;
+
+            loop {
+                if meter.try_consume(weight_per_order).is_err() {
+                    if last_order_id == cursor {
+                        return Err(SteppedMigrationError::InsufficientWeight {
+                            required: weight_per_order,
+                        });
+                    }
+                    return Ok(last_order_id);
+                }
+
+                let Some((order_id, order_data)) = iter.next() else {
+                    break;
+                };
+                last_order_id = Some(order_id);
+
+                let result = T::MarketCommons::market(&order_data.market_id).and_then(|market| {
+                    // Orders placed after the upgrade are already indexed.
+                    let base_asset = market.base_asset;
+                    let pair = (order_data.maker_asset, order_data.taker_asset);
+                    let is_indexed = OrderPriceIndex::<T>::get(pair)
+                        .iter()
+                        .any(|(_, indexed_order_id)| *indexed_order_id == order_id);
+                    let key = Pallet::<T>::unindexed_order_key(
+                        order_data.price(base_asset)?,
+                        order_id,
+                        order_data.maker_asset == base_asset,
+                    );
+                    if is_indexed || UnindexedOrders::<T>::contains_key(pair, key) {
+                        return Ok(());
+                    }
+                    Pallet::<T>::insert_into_price_index(order_id, &order_data, base_asset)
+                });
+                if let Err(err) = result {
+                    warn!(
+                        target: LOG_TARGET,
+                        "BuildOrderPriceIndex: failed to index order {:?}: {:?}", order_id, err,
+                    );
+                }
+            }
+
+            StorageVersion::new(TARGET_STORAGE_VERSION).put::<Pallet<T>>();
+            info!(target: LOG_TARGET, "BuildOrderPriceIndex: migration complete");
+
+            Ok(None)
+        }
+    }
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod tests {
+    use super::mbm::BuildOrderPriceIndex;
+    use crate::{mock::*, utils::market_mock, OrderPriceIndex, Orders};
+    use frame_support::{
+        migrations::SteppedMigration,
+        traits::StorageVersion,
+        weights::{Weight, WeightMeter},
+    };
+    use zeitgeist_primitives::{
+        constants::BASE,
+        types::{Asset, Order},
+    };
+    use zrml_market_commons::Markets;
+
+    #[test]
+    fn migration_builds_price_index_from_existing_orders() {
+        ExtBuilder::default().build().execute_with(|| {
+            let market_id = 0u128;
+            let market = market_mock::<Runtime>();
+            Markets::<Runtime>::insert(market_id, market.clone());
+            let outcome = Asset::CategoricalOutcome(market_id, 0);
+            let base_asset = market.base_asset;
+
+            let orders = [(0u128, 5 * BASE), (1u128, 3 * BASE), (2u128, 4 * BASE)];
+            for (order_id, taker_amount) in orders {
+                Orders::<Runtime>::insert(
+                    order_id,
+                    Order {
+                        market_id,
+                        maker: ALICE,
+                        maker_asset: outcome,
+                        maker_amount: 10 * BASE,
+                        taker_asset: base_asset,
+                        taker_amount,
+                    },
+                );
+            }
+            StorageVersion::new(1).put::<Orderbook>();
+
+            let mut meter = WeightMeter::with_limit(Weight::from_parts(u64::MAX, u64::MAX));
+            let cursor = BuildOrderPriceIndex::<Runtime>::step(None, &mut meter).unwrap();
+            assert!(cursor.is_none());
+
+            assert_eq!(StorageVersion::get::<Orderbook>(), StorageVersion::new(2));
+            let price_index = OrderPriceIndex::<Runtime>::get((outcome, base_asset));
+            assert_eq!(
+                price_index.into_inner(),
+                vec![(3 * BASE / 10, 1), (4 * BASE / 10, 2), (5 * BASE / 10, 0),]
+            );
+        });
+    }
+}
@@ -30,8 +30,9 @@ use sp_runtime::{
 };
 use zeitgeist_primitives::{
     constants::mock::{
-        BlockHashCount, ExistentialDeposit, ExistentialDeposits, GetNativeCurrencyId, MaxLocks,
-        MaxReserves, MinimumPeriod, OrderbookPalletId, BASE, CENT,
+        BlockHashCount, ExistentialDeposit, ExistentialDeposits, GetNativeCurrencyId,
+        MaxIndexedOrders, MaxLocks, MaxReserves, MinOrderAmount, MinimumPeriod, OrderbookPalletId,
+        BASE, CENT,
     },
     traits::DistributeFees,
     types::{
@@ -103,6 +104,8 @@ impl crate::Config for Runtime {
     type ExternalFees = ExternalFees<Runtime, FeeAccount>;
     type RuntimeEvent = RuntimeEvent;
     type MarketCommons = MarketCommons;
+    type MaxIndexedOrders = MaxIndexedOrders;
+    type MinOrderAmount = MinOrderAmount;
     type PalletId = OrderbookPalletId;
     type WeightInfo = zrml_orderbook::weights::WeightInfo<Runtime>;
 }
@@ -15,16 +15,17 @@
 //
 // You should have received a copy of the GNU General Public License
 // along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
-
-use crate::{mock::*, utils::market_mock, Error, Event, Order, Orders};
+use crate::{
+    mock::*, utils::market_mock, Error, Event, Order, OrderPriceIndex, Orders, UnindexedOrders,
+};
 use frame_support::{assert_noop, assert_ok};
 use orml_tokens::Error as AError;
 use orml_traits::{MultiCurrency, MultiReservableCurrency};
 use pallet_balances::Error as BError;
-use sp_runtime::{Perbill, Perquintill};
+use sp_runtime::{traits::Get, Perbill, Perquintill};
 use test_case::test_case;
 use zeitgeist_primitives::{
-    constants::BASE,
+    constants::{BASE, CENT},
     hybrid_router_api_types::ExternalFee,
     types::{Asset, MarketStatus, MarketType, ScalarPosition, ScoringRule},
 };
@@ -1004,3 +1005,242 @@ fn place_order_emits_event() {
         );
     });
 }
+
+#[test]
+fn place_order_indexes_bids_by_descending_price() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+        let market = market_mock::<Runtime>();
+        Markets::<Runtime>::insert(market_id, market.clone());
+
+        let maker_asset = market.base_asset;
+        let taker_asset = Asset::CategoricalOutcome(market_id, 2);
+        for taker_amount in [2 * BASE, 4 * BASE, BASE, 2 * BASE] {
+            assert_ok!(Orderbook::place_order(
+                RuntimeOrigin::signed(ALICE),
+                market_id,
+                maker_asset,
+                BASE,
+                taker_asset,
+                taker_amount,
+            ));
+        }
+
+        assert_eq!(
+            OrderPriceIndex::<Runtime>::get((maker_asset, taker_asset)).into_inner(),
+            vec![(BASE, 2), (BASE / 2, 0), (BASE / 2, 3), (BASE / 4, 1)]
+        );
+        assert_eq!(Orderbook::best_orders(maker_asset, taker_asset, BASE / 2, 10), vec![2, 0, 3]);
+        assert_eq!(Orderbook::best_orders(maker_asset, taker_asset, 0, 2), vec![2, 0]);
+    });
+}
+
+#[test]
+fn place_order_indexes_asks_by_ascending_price() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+        let market = market_mock::<Runtime>();
+        Markets::<Runtime>::insert(market_id, market.clone());
+
+        let maker_asset = Asset::CategoricalOutcome(market_id, 2);
+        let taker_asset = market.base_asset;
+        assert_ok!(AssetManager::deposit(maker_asset, &ALICE, 3 * BASE));
+        for taker_amount in [BASE / 2, BASE / 4, BASE] {
+            assert_ok!(Orderbook::place_order(
+                RuntimeOrigin::signed(ALICE),
+                market_id,
+                maker_asset,
+                BASE,
+                taker_asset,
+                taker_amount,
+            ));
+        }
+
+        assert_eq!(
+            OrderPriceIndex::<Runtime>::get((maker_asset, taker_asset)).into_inner(),
+            vec![(BASE / 4, 1), (BASE / 2, 0), (BASE, 2)]
+        );
+        assert_eq!(Orderbook::best_orders(maker_asset, taker_asset, BASE / 2, 10), vec![1, 0]);
+    });
+}
+
+#[test]
+fn place_order_drops_worst_order_from_full_price_index() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+        let market = market_mock::<Runtime>();
+        Markets::<Runtime>::insert(market_id, market.clone());
+
+        let maker_asset = market.base_asset;
+        let taker_asset = Asset::CategoricalOutcome(market_id, 2);
+        let max_indexed_orders: u32 = <Runtime as crate::Config>::MaxIndexedOrders::get();
+        // Each order has a worse price than the previous one.
+        for i in 0..=max_indexed_orders {
+            assert_ok!(Orderbook::place_order(
+                RuntimeOrigin::signed(ALICE),
+                market_id,
+                maker_asset,
+                BASE / 10,
+                taker_asset,
+                BASE + u128::from(i) * CENT,
+            ));
+        }
+
+        let price_index = OrderPriceIndex::<Runtime>::get((maker_asset, taker_asset));
+        assert_eq!(price_index.len() as u32, max_indexed_orders);
+        let last_order_id = u128::from(max_indexed_orders);
+        assert!(price_index.iter().all(|(_, order_id)| *order_id != last_order_id));
+        // The order which isn't indexed can still be filled.
+        assert!(Orders::<Runtime>::get(last_order_id).is_some());
+
+        assert_ok!(Orderbook::place_order(
+            RuntimeOrigin::signed(ALICE),
+            market_id,
+            maker_asset,
+            BASE / 10,
+            taker_asset,
+            BASE / 2,
+        ));
+
+        let price_index = OrderPriceIndex::<Runtime>::get((maker_asset, taker_asset));
+        assert_eq!(price_index.len() as u32, max_indexed_orders);
+        assert_eq!(price_index.first().map(|(_, order_id)| *order_id), Some(last_order_id + 1));
+        assert!(price_index.iter().all(|(_, order_id)| *order_id != last_order_id - 1));
+    });
+}
+
+#[test]
+fn remove_order_backfills_price_index_with_best_unindexed_order() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+        let market = market_mock::<Runtime>();
+        Markets::<Runtime>::insert(market_id, market.clone());
+
+        let maker_asset = market.base_asset;
+        let taker_asset = Asset::CategoricalOutcome(market_id, 2);
+        let max_indexed_orders: u32 = <Runtime as crate::Config>::MaxIndexedOrders::get();
+        // Each order has a worse price than the previous one. The two worst orders aren't indexed
+        // and are placed in reverse order to check that they're sorted by price.
+        for i in 0..max_indexed_orders {
+            assert_ok!(Orderbook::place_order(
+                RuntimeOrigin::signed(ALICE),
+                market_id,
+                maker_asset,
+                BASE / 10,
+                taker_asset,
+                BASE + u128::from(i) * CENT,
+            ));
+        }
+        for i in [max_indexed_orders + 1, max_indexed_orders] {
+            assert_ok!(Orderbook::place_order(
+                RuntimeOrigin::signed(ALICE),
+                market_id,
+                maker_asset,
+                BASE / 10,
+                taker_asset,
+                BASE + u128::from(i) * CENT,
+            ));
+        }
+        let pair = (maker_asset, taker_asset);
+        assert_eq!(UnindexedOrders::<Runtime>::iter_prefix(pair).count(), 2);
+
+        let best_unindexed_order_id = u128::from(max_indexed_orders) + 1;
+        assert_ok!(Orderbook::remove_order(RuntimeOrigin::signed(ALICE), 0));
+
+        let price_index = OrderPriceIndex::<Runtime>::get(pair);
+        assert_eq!(price_index.len() as u32, max_indexed_orders);
+        assert_eq!(
+            price_index.last().map(|(_, order_id)| *order_id),
+            Some(best_unindexed_order_id)
+        );
+        let unindexed_orders = UnindexedOrders::<Runtime>::iter_prefix_values(pair)
+            .map(|(_, order_id)| order_id)
+            .collect::<Vec<_>>();
+        assert_eq!(unindexed_orders, vec![u128::from(max_indexed_orders)]);
+
+        // Removing an unindexed order leaves the price index unchanged.
+        assert_ok!(Orderbook::remove_order(
+            RuntimeOrigin::signed(ALICE),
+            u128::from(max_indexed_orders)
+        ));
+        assert_eq!(OrderPriceIndex::<Runtime>::get(pair), price_index);
+        assert_eq!(UnindexedOrders::<Runtime>::iter_prefix(pair).count(), 0);
+    });
+}
+
+#[test]
+fn place_order_fails_if_below_minimum_order_amount() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+        let market = market_mock::<Runtime>();
+        Markets::<Runtime>::insert(market_id, market.clone());
+
+        let base_asset = market.base_asset;
+        let outcome_asset = Asset::CategoricalOutcome(market_id, 2);
+        let min_order_amount = <Runtime as crate::Config>::MinOrderAmount::get();
+
+        assert_noop!(
+            Orderbook::place_order(
+                RuntimeOrigin::signed(ALICE),
+                market_id,
+                base_asset,
+                min_order_amount - 1,
+                outcome_asset,
+                BASE,
+            ),
+            Error::<Runtime>::BelowMinimumOrderAmount
+        );
+        assert_noop!(
+            Orderbook::place_order(
+                RuntimeOrigin::signed(ALICE),
+                market_id,
+                outcome_asset,
+                BASE,
+                base_asset,
+                min_order_amount - 1,
+            ),
+            Error::<Runtime>::BelowMinimumOrderAmount
+        );
+    });
+}
+
+#[test]
+fn fill_and_remove_order_update_price_index() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0u128;
+        let market = market_mock::<Runtime>();
+        Markets::<Runtime>::insert(market_id, market.clone());
+
+        let maker_asset = market.base_asset;
+        let taker_asset = Asset::CategoricalOutcome(market_id, 2);
+        for _ in 0..3 {
+            assert_ok!(Orderbook::place_order(
+                RuntimeOrigin::signed(ALICE),
+                market_id,
+                maker_asset,
+                10 * BASE,
+                taker_asset,
+                25 * BASE,
+            ));
+        }
+        let price = 4 * BASE / 10;
+
+        AssetManager::deposit(taker_asset, &BOB, 50 * BASE).unwrap();
+        assert_ok!(Orderbook::fill_order(RuntimeOrigin::signed(BOB), 0, Some(10 * BASE)));
+        // The partially filled order keeps its position.
+        assert_eq!(
+            OrderPriceIndex::<Runtime>::get((maker_asset, taker_asset)).into_inner(),
+            vec![(price, 0), (price, 1), (price, 2)]
+        );
+
+        assert_ok!(Orderbook::fill_order(RuntimeOrigin::signed(BOB), 1, None));
+        assert_ok!(Orderbook::remove_order(RuntimeOrigin::signed(ALICE), 2));
+        assert_eq!(
+            OrderPriceIndex::<Runtime>::get((maker_asset, taker_asset)).into_inner(),
+            vec![(price, 0)]
+        );
+
+        assert_ok!(Orderbook::remove_order(RuntimeOrigin::signed(ALICE), 0));
+        assert!(!OrderPriceIndex::<Runtime>::contains_key((maker_asset, taker_asset)));
+    });
+}
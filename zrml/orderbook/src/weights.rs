@@ -63,14 +63,16 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
     /// Proof: `Orderbook::Orders` (`max_values`: None, `max_size`: Some(170), added: 2645, mode: `MaxEncodedLen`)
     /// Storage: `Balances::Reserves` (r:1 w:1)
     /// Proof: `Balances::Reserves` (`max_values`: None, `max_size`: Some(1249), added: 3724, mode: `MaxEncodedLen`)
+    /// Storage: `Orderbook::OrderPriceIndex` (r:1 w:1)
+    /// Proof: `Orderbook::OrderPriceIndex` (`max_values`: None, `max_size`: Some(4857), added: 7332, mode: `MaxEncodedLen`)
+    /// Storage: `Orderbook::UnindexedOrders` (r:1 w:1)
+    /// Proof: `Orderbook::UnindexedOrders` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
     fn remove_order() -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `251`
-        //  Estimated: `4714`
-        // Minimum execution time: 43_001 nanoseconds.
-        Weight::from_parts(45_281_000, 4714)
-            .saturating_add(T::DbWeight::get().reads(2))
-            .saturating_add(T::DbWeight::get().writes(2))
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_orderbook` to replace it.
+        Weight::from_parts(66_410_000, 10943)
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(4))
     }
     /// Storage: `Orderbook::Orders` (r:1 w:1)
     /// Proof: `Orderbook::Orders` (`max_values`: None, `max_size`: Some(170), added: 2645, mode: `MaxEncodedLen`)
@@ -82,14 +84,16 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
     /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
     /// Storage: `Tokens::Accounts` (r:2 w:2)
     /// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(137), added: 2612, mode: `MaxEncodedLen`)
+    /// Storage: `Orderbook::OrderPriceIndex` (r:1 w:1)
+    /// Proof: `Orderbook::OrderPriceIndex` (`max_values`: None, `max_size`: Some(4857), added: 7332, mode: `MaxEncodedLen`)
+    /// Storage: `Orderbook::UnindexedOrders` (r:1 w:1)
+    /// Proof: `Orderbook::UnindexedOrders` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
     fn fill_order() -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `970`
-        //  Estimated: `6214`
-        // Minimum execution time: 107_483 nanoseconds.
-        Weight::from_parts(110_612_000, 6214)
-            .saturating_add(T::DbWeight::get().reads(6))
-            .saturating_add(T::DbWeight::get().writes(5))
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_orderbook` to replace it.
+        Weight::from_parts(133_337_000, 10943)
+            .saturating_add(T::DbWeight::get().reads(8))
+            .saturating_add(T::DbWeight::get().writes(7))
     }
     /// Storage: `MarketCommons::Markets` (r:1 w:0)
     /// Proof: `MarketCommons::Markets` (`max_values`: None, `max_size`: Some(708), added: 3183, mode: `MaxEncodedLen`)
@@ -99,13 +103,15 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
     /// Proof: `Balances::Reserves` (`max_values`: None, `max_size`: Some(1249), added: 3724, mode: `MaxEncodedLen`)
     /// Storage: `Orderbook::Orders` (r:0 w:1)
     /// Proof: `Orderbook::Orders` (`max_values`: None, `max_size`: Some(170), added: 2645, mode: `MaxEncodedLen`)
+    /// Storage: `Orderbook::OrderPriceIndex` (r:1 w:1)
+    /// Proof: `Orderbook::OrderPriceIndex` (`max_values`: None, `max_size`: Some(4857), added: 7332, mode: `MaxEncodedLen`)
+    /// Storage: `Orderbook::UnindexedOrders` (r:0 w:1)
+    /// Proof: `Orderbook::UnindexedOrders` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
     fn place_order() -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `318`
-        //  Estimated: `4714`
-        // Minimum execution time: 51_311 nanoseconds.
-        Weight::from_parts(54_921_000, 4714)
-            .saturating_add(T::DbWeight::get().reads(3))
-            .saturating_add(T::DbWeight::get().writes(3))
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_orderbook` to replace it.
+        Weight::from_parts(71_540_000, 10943)
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(5))
     }
 }
@@ -0,0 +1,34 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+#![doc = include_str!("../README.md")]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use parity_scale_codec::Codec;
+use sp_runtime::DispatchError;
+use zrml_parimutuel::types::PotInfo;
+
+sp_api::decl_runtime_apis! {
+    pub trait ParimutuelApi<MarketId, Balance> where
+        MarketId: Codec,
+        Balance: Codec,
+    {
+        /// Returns the current pot size of the parimutuel market `market_id` together with the
+        /// implied odds and the payoff per share of each outcome.
+        fn pot_info(market_id: MarketId) -> Result<PotInfo<MarketId, Balance>, DispatchError>;
+    }
+}
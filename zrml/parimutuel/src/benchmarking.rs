@@ -135,6 +135,20 @@ mod benchmarks {
         claim_refunds(RawOrigin::Signed(loser_0), loser_0_asset);
     }
 
+    #[benchmark]
+    fn exit_early() {
+        let market_id = setup_market::<T>(MarketType::Categorical(64u16));
+
+        let buyer = whitelisted_caller();
+        let asset = Asset::ParimutuelShare(market_id, 0u16);
+        let amount = T::MinBetSize::get().saturating_mul(10u128.saturated_into::<BalanceOf<T>>());
+        buy_asset::<T>(market_id, asset, &buyer, amount);
+        let balance = T::AssetManager::free_balance(asset, &buyer);
+
+        #[extrinsic_call]
+        exit_early(RawOrigin::Signed(buyer), asset, balance);
+    }
+
     impl_benchmark_test_suite!(
         Parimutuel,
         crate::mock::ExtBuilder::default().build(),
@@ -23,6 +23,7 @@ extern crate alloc;
 mod benchmarking;
 mod mock;
 mod tests;
+pub mod types;
 mod utils;
 pub mod weights;
 
@@ -30,7 +31,11 @@ pub use pallet::*;
 
 #[frame_support::pallet]
 mod pallet {
-    use crate::weights::WeightInfoZeitgeist;
+    use crate::{
+        types::{OutcomeOdds, PotInfo},
+        weights::WeightInfoZeitgeist,
+    };
+    use alloc::vec::Vec;
     use core::marker::PhantomData;
     use frame_support::{
         ensure,
@@ -45,11 +50,11 @@ mod pallet {
     };
     use orml_traits::MultiCurrency;
     use sp_runtime::{
-        traits::{AccountIdConversion, CheckedSub, Zero},
-        DispatchError, DispatchResult, RuntimeDebug,
+        traits::{AccountIdConversion, CheckedSub, Saturating, Zero},
+        DispatchError, DispatchResult, Perbill, RuntimeDebug,
     };
     use zeitgeist_primitives::{
-        math::fixed::FixedMulDiv,
+        math::fixed::{BaseProvider, FixedDiv, FixedMulDiv, ZeitgeistBase},
         traits::DistributeFees,
         types::{Asset, Market, MarketStatus, MarketType, OutcomeReport, ScoringRule},
     };
@@ -74,6 +79,11 @@ mod pallet {
             Balance = BalanceOf<Self>,
         >;
 
+        /// The share of the stake which remains in the pot when exiting a bet early. Early exits
+        /// are disabled if this is set to 100%.
+        #[pallet::constant]
+        type EarlyExitPenalty: Get<Perbill>;
+
         /// The minimum amount each bet must be. Must be larger than or equal to the existential
         /// deposit of parimutuel shares.
         #[pallet::constant]
@@ -101,6 +111,7 @@ mod pallet {
     pub(crate) type MomentOf<T> = <<T as Config>::MarketCommons as MarketCommonsPalletApi>::Moment;
     pub(crate) type MarketOf<T> =
         Market<AccountIdOf<T>, BalanceOf<T>, BlockNumberFor<T>, MomentOf<T>, MarketIdOf<T>>;
+    pub type PotInfoOf<T> = PotInfo<MarketIdOf<T>, BalanceOf<T>>;
 
     #[pallet::pallet]
     #[pallet::storage_version(STORAGE_VERSION)]
@@ -135,6 +146,15 @@ mod pallet {
             refunded_balance: BalanceOf<T>,
             sender: AccountIdOf<T>,
         },
+        /// A bet was withdrawn before the market closed.
+        EarlyExited {
+            market_id: MarketIdOf<T>,
+            asset: AssetOf<T>,
+            withdrawn_asset_balance: BalanceOf<T>,
+            refunded_balance: BalanceOf<T>,
+            penalty: BalanceOf<T>,
+            sender: AccountIdOf<T>,
+        },
     }
 
     #[pallet::error]
@@ -172,6 +192,12 @@ mod pallet {
         NotCategorical,
         /// There is no reward to distribute.
         NoRewardToDistribute,
+        /// Early exits are disabled.
+        EarlyExitDisabled,
+        /// The specified amount is zero.
+        ZeroAmount,
+        /// The balance remaining after the early exit would be below the minimum bet size.
+        RemainingBalanceBelowMinimumBetSize,
         /// Action cannot be completed because an unexpected error has occurred. This should be
         /// reported to protocol maintainers.
         InconsistentState(InconsistentStateError),
@@ -234,7 +260,10 @@ mod pallet {
         ///
         /// - `refund_asset`: The outcome asset to refund.
         ///
-        /// Complexity: `O(1)`
+        /// The pot, including the penalties of early exits, is shared pro rata among all refunded
+        /// shares.
+        ///
+        /// Complexity: `O(n)`, where `n` is the number of categories of the market.
         #[pallet::call_index(2)]
         #[pallet::weight(T::WeightInfo::claim_refunds())]
         #[frame_support::transactional]
@@ -245,6 +274,33 @@ mod pallet {
 
             Ok(())
         }
+
+        /// Withdraw a bet from an active market.
+        ///
+        /// The `EarlyExitPenalty` is deducted from the refund and remains in the pot, increasing
+        /// the payoff of the winners or, if there are no winners, the refunds of all bettors.
+        ///
+        /// # Arguments
+        ///
+        /// - `asset`: The outcome asset to sell back to the pot.
+        /// - `amount`: The amount of parimutuel shares to burn. The remaining balance must be zero
+        /// or at least the minimum bet size.
+        ///
+        /// Complexity: `O(1)`
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::exit_early())]
+        #[frame_support::transactional]
+        pub fn exit_early(
+            origin: OriginFor<T>,
+            asset: AssetOf<T>,
+            #[pallet::compact] amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Self::do_exit_early(who, asset, amount)?;
+
+            Ok(())
+        }
     }
 
     impl<T> Pallet<T>
@@ -423,6 +479,95 @@ mod pallet {
             Ok(())
         }
 
+        #[require_transactional]
+        fn do_exit_early(
+            who: T::AccountId,
+            asset: AssetOf<T>,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let market_id = match asset {
+                Asset::ParimutuelShare(market_id, _) => market_id,
+                _ => return Err(Error::<T>::NotParimutuelOutcome.into()),
+            };
+            let market = T::MarketCommons::market(&market_id)?;
+            ensure!(market.status == MarketStatus::Active, Error::<T>::MarketIsNotActive);
+            ensure!(market.scoring_rule == ScoringRule::Parimutuel, Error::<T>::InvalidScoringRule);
+            Self::market_assets_contains(&market, &asset)?;
+            let early_exit_penalty = T::EarlyExitPenalty::get();
+            ensure!(early_exit_penalty < Perbill::one(), Error::<T>::EarlyExitDisabled);
+            ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+
+            let balance = T::AssetManager::free_balance(asset, &who);
+            let remaining_balance =
+                balance.checked_sub(&amount).ok_or(Error::<T>::InsufficientBalance)?;
+            ensure!(
+                remaining_balance.is_zero() || remaining_balance >= T::MinBetSize::get(),
+                Error::<T>::RemainingBalanceBelowMinimumBetSize
+            );
+
+            // Round the penalty up in favor of the pot.
+            let penalty = early_exit_penalty.mul_ceil(amount);
+            let refunded_balance = amount.checked_sub(&penalty).ok_or(Error::<T>::Unexpected)?;
+
+            let pot_account = Self::pot_account(market_id);
+            let pot_total = T::AssetManager::free_balance(market.base_asset, &pot_account);
+            ensure!(
+                pot_total >= refunded_balance,
+                Error::<T>::InconsistentState(
+                    InconsistentStateError::InsufficientFundsInPotAccount
+                )
+            );
+
+            T::AssetManager::withdraw(asset, &who, amount)?;
+            T::AssetManager::transfer(market.base_asset, &pot_account, &who, refunded_balance)?;
+
+            Self::deposit_event(Event::EarlyExited {
+                market_id,
+                asset,
+                withdrawn_asset_balance: amount,
+                refunded_balance,
+                penalty,
+                sender: who,
+            });
+
+            Ok(())
+        }
+
+        /// Returns the current pot size of the parimutuel market `market_id` and the implied odds
+        /// of each outcome. The payoff per share is calculated using the same arithmetic as
+        /// `claim_rewards`.
+        pub fn pot_info(market_id: MarketIdOf<T>) -> Result<PotInfoOf<T>, DispatchError> {
+            let market = T::MarketCommons::market(&market_id)?;
+            ensure!(market.scoring_rule == ScoringRule::Parimutuel, Error::<T>::InvalidScoringRule);
+            let categories = match market.market_type {
+                MarketType::Categorical(categories) => categories,
+                MarketType::Scalar(_) => return Err(Error::<T>::NotCategorical.into()),
+            };
+
+            let pot_account = Self::pot_account(market_id);
+            let pot_total = T::AssetManager::free_balance(market.base_asset, &pot_account);
+            let one = ZeitgeistBase::<BalanceOf<T>>::get()?;
+            let outcomes = (0..categories)
+                .map(|i| {
+                    let asset = Asset::ParimutuelShare(market_id, i);
+                    let outcome_total = T::AssetManager::total_issuance(asset);
+                    let implied_probability = if pot_total.is_zero() {
+                        Zero::zero()
+                    } else {
+                        outcome_total.bdiv(pot_total)?
+                    };
+                    let payoff_per_share = if outcome_total.is_zero() {
+                        None
+                    } else {
+                        Some(pot_total.bmul_bdiv(one, outcome_total)?)
+                    };
+                    Ok(OutcomeOdds { asset, outcome_total, implied_probability, payoff_per_share })
+                })
+                .collect::<Result<Vec<_>, DispatchError>>()?;
+
+            Ok(PotInfo { pot_total, outcomes })
+        }
+
         #[require_transactional]
         fn do_claim_refunds(who: T::AccountId, refund_asset: AssetOf<T>) -> DispatchResult {
             let market_id = match refund_asset {
@@ -448,6 +593,16 @@ mod pallet {
                 debug_assert!(false);
             }
 
+            let categories = match market.market_type {
+                MarketType::Categorical(categories) => categories,
+                MarketType::Scalar(_) => return Err(Error::<T>::NotCategorical.into()),
+            };
+            // The pot contains the stake of all outstanding shares plus the penalties of early
+            // exits, so the pot is shared pro rata among all shares which are refunded.
+            let outstanding = (0..categories)
+                .map(|i| T::AssetManager::total_issuance(Asset::ParimutuelShare(market_id, i)))
+                .fold(<BalanceOf<T>>::zero(), |acc, issuance| acc.saturating_add(issuance));
+
             T::AssetManager::withdraw(refund_asset, &who, refund_balance)?;
 
             let pot_account = Self::pot_account(market_id);
@@ -459,7 +614,12 @@ mod pallet {
                 );
                 debug_assert!(false);
             }
-            let refund_balance = refund_balance.min(pot_total);
+            // The last claimant receives the remainder of the pot, including rounding dust.
+            let refund_balance = if refund_balance >= outstanding {
+                pot_total
+            } else {
+                pot_total.bmul_bdiv_floor(refund_balance, outstanding)?.min(pot_total)
+            };
 
             T::AssetManager::transfer(market.base_asset, &pot_account, &who, refund_balance)?;
 
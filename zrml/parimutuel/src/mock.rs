@@ -32,8 +32,8 @@ use sp_runtime::{
 };
 use zeitgeist_primitives::{
     constants::mock::{
-        BlockHashCount, ExistentialDeposits, GetNativeCurrencyId, MaxLocks, MaxReserves,
-        MinBetSize, MinimumPeriod, ParimutuelPalletId, BASE, CENT,
+        BlockHashCount, EarlyExitPenalty, ExistentialDeposits, GetNativeCurrencyId, MaxLocks,
+        MaxReserves, MinBetSize, MinimumPeriod, ParimutuelPalletId, BASE, CENT,
     },
     traits::DistributeFees,
     types::{
@@ -103,6 +103,7 @@ construct_runtime!(
 );
 
 impl crate::Config for Runtime {
+    type EarlyExitPenalty = EarlyExitPenalty;
     type ExternalFees = ExternalFees<Runtime, FeeAccount>;
     type RuntimeEvent = RuntimeEvent;
     type MarketCommons = MarketCommons;
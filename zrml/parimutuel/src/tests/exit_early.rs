@@ -0,0 +1,247 @@
+// Copyright 2023-2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+#![cfg(test)]
+
+use crate::{mock::*, utils::*, *};
+use frame_support::{assert_noop, assert_ok};
+use orml_traits::MultiCurrency;
+use test_case::test_case;
+use zeitgeist_primitives::{
+    constants::BASE,
+    types::{Asset, MarketStatus, OutcomeReport, ScoringRule},
+};
+use zrml_market_commons::Markets;
+
+fn setup_active_market() -> MarketIdOf<Runtime> {
+    let market_id = 0;
+    let mut market = market_mock::<Runtime>(MARKET_CREATOR);
+    market.status = MarketStatus::Active;
+    Markets::<Runtime>::insert(market_id, market);
+    market_id
+}
+
+#[test]
+fn exit_early_refunds_stake_minus_penalty() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = setup_active_market();
+        let asset = Asset::ParimutuelShare(market_id, 0u16);
+        let other_asset = Asset::ParimutuelShare(market_id, 1u16);
+        assert_ok!(Parimutuel::buy(RuntimeOrigin::signed(ALICE), asset, 10 * BASE));
+        assert_ok!(Parimutuel::buy(RuntimeOrigin::signed(BOB), other_asset, 10 * BASE));
+
+        let pot_account = Parimutuel::pot_account(market_id);
+        let pot_before = AssetManager::free_balance(Asset::Ztg, &pot_account);
+        let free_alice_before = AssetManager::free_balance(Asset::Ztg, &ALICE);
+        let shares_before = AssetManager::free_balance(asset, &ALICE);
+
+        let amount = 5 * BASE;
+        assert_ok!(Parimutuel::exit_early(RuntimeOrigin::signed(ALICE), asset, amount));
+
+        let penalty = BASE / 2;
+        let refunded_balance = amount - penalty;
+        assert_eq!(AssetManager::free_balance(asset, &ALICE), shares_before - amount);
+        assert_eq!(
+            AssetManager::free_balance(Asset::Ztg, &ALICE),
+            free_alice_before + refunded_balance
+        );
+        assert_eq!(
+            AssetManager::free_balance(Asset::Ztg, &pot_account),
+            pot_before - refunded_balance
+        );
+        System::assert_last_event(
+            Event::EarlyExited {
+                market_id,
+                asset,
+                withdrawn_asset_balance: amount,
+                refunded_balance,
+                penalty,
+                sender: ALICE,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn exit_early_penalty_is_paid_out_to_winners() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = setup_active_market();
+        let winner_asset = Asset::ParimutuelShare(market_id, 0u16);
+        let loser_asset = Asset::ParimutuelShare(market_id, 1u16);
+        assert_ok!(Parimutuel::buy(RuntimeOrigin::signed(ALICE), winner_asset, 10 * BASE));
+        assert_ok!(Parimutuel::buy(RuntimeOrigin::signed(BOB), loser_asset, 10 * BASE));
+        let bob_shares = AssetManager::free_balance(loser_asset, &BOB);
+        assert_ok!(Parimutuel::exit_early(RuntimeOrigin::signed(BOB), loser_asset, bob_shares));
+
+        let pot_account = Parimutuel::pot_account(market_id);
+        let pot_total = AssetManager::free_balance(Asset::Ztg, &pot_account);
+        let alice_shares = AssetManager::free_balance(winner_asset, &ALICE);
+        assert_eq!(pot_total, alice_shares + bob_shares / 10);
+
+        Markets::<Runtime>::mutate(market_id, |market| {
+            let market = market.as_mut().unwrap();
+            market.status = MarketStatus::Resolved;
+            market.resolved_outcome = Some(OutcomeReport::Categorical(0u16));
+        });
+        let free_alice_before = AssetManager::free_balance(Asset::Ztg, &ALICE);
+        assert_ok!(Parimutuel::claim_rewards(RuntimeOrigin::signed(ALICE), market_id));
+        assert_eq!(AssetManager::free_balance(Asset::Ztg, &ALICE), free_alice_before + pot_total);
+    });
+}
+
+#[test]
+fn exit_early_penalty_is_refunded_pro_rata_if_there_are_no_winners() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = setup_active_market();
+        let alice_asset = Asset::ParimutuelShare(market_id, 0u16);
+        let bob_asset = Asset::ParimutuelShare(market_id, 1u16);
+        let charlie_asset = Asset::ParimutuelShare(market_id, 2u16);
+        assert_ok!(Parimutuel::buy(RuntimeOrigin::signed(ALICE), alice_asset, 10 * BASE));
+        assert_ok!(Parimutuel::buy(RuntimeOrigin::signed(BOB), bob_asset, 30 * BASE));
+        assert_ok!(Parimutuel::buy(RuntimeOrigin::signed(CHARLIE), charlie_asset, 10 * BASE));
+        let charlie_shares = AssetManager::free_balance(charlie_asset, &CHARLIE);
+        assert_ok!(Parimutuel::exit_early(
+            RuntimeOrigin::signed(CHARLIE),
+            charlie_asset,
+            charlie_shares
+        ));
+
+        let pot_account = Parimutuel::pot_account(market_id);
+        let pot_total = AssetManager::free_balance(Asset::Ztg, &pot_account);
+        let alice_shares = AssetManager::free_balance(alice_asset, &ALICE);
+        let bob_shares = AssetManager::free_balance(bob_asset, &BOB);
+        assert_eq!(pot_total, alice_shares + bob_shares + charlie_shares / 10);
+
+        Markets::<Runtime>::mutate(market_id, |market| {
+            let market = market.as_mut().unwrap();
+            market.status = MarketStatus::Resolved;
+            market.resolved_outcome = Some(OutcomeReport::Categorical(3u16));
+        });
+
+        let free_alice_before = AssetManager::free_balance(Asset::Ztg, &ALICE);
+        assert_ok!(Parimutuel::claim_refunds(RuntimeOrigin::signed(ALICE), alice_asset));
+        let alice_refund = pot_total * alice_shares / (alice_shares + bob_shares);
+        assert_eq!(
+            AssetManager::free_balance(Asset::Ztg, &ALICE),
+            free_alice_before + alice_refund
+        );
+
+        let free_bob_before = AssetManager::free_balance(Asset::Ztg, &BOB);
+        assert_ok!(Parimutuel::claim_refunds(RuntimeOrigin::signed(BOB), bob_asset));
+        assert_eq!(
+            AssetManager::free_balance(Asset::Ztg, &BOB),
+            free_bob_before + pot_total - alice_refund
+        );
+        assert_eq!(AssetManager::free_balance(Asset::Ztg, &pot_account), 0);
+    });
+}
+
+#[test_case(MarketStatus::Proposed; "proposed")]
+#[test_case(MarketStatus::Closed; "closed")]
+#[test_case(MarketStatus::Reported; "reported")]
+#[test_case(MarketStatus::Disputed; "disputed")]
+#[test_case(MarketStatus::Resolved; "resolved")]
+fn exit_early_fails_if_market_not_active(status: MarketStatus) {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = setup_active_market();
+        let asset = Asset::ParimutuelShare(market_id, 0u16);
+        assert_ok!(Parimutuel::buy(RuntimeOrigin::signed(ALICE), asset, 10 * BASE));
+        Markets::<Runtime>::mutate(market_id, |market| {
+            market.as_mut().unwrap().status = status;
+        });
+
+        assert_noop!(
+            Parimutuel::exit_early(RuntimeOrigin::signed(ALICE), asset, BASE),
+            Error::<Runtime>::MarketIsNotActive
+        );
+    });
+}
+
+#[test]
+fn exit_early_fails_if_not_parimutuel_outcome() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = setup_active_market();
+        assert_noop!(
+            Parimutuel::exit_early(
+                RuntimeOrigin::signed(ALICE),
+                Asset::CategoricalOutcome(market_id, 0u16),
+                BASE
+            ),
+            Error::<Runtime>::NotParimutuelOutcome
+        );
+    });
+}
+
+#[test]
+fn exit_early_fails_if_invalid_scoring_rule() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = setup_active_market();
+        Markets::<Runtime>::mutate(market_id, |market| {
+            market.as_mut().unwrap().scoring_rule = ScoringRule::AmmCdaHybrid;
+        });
+        assert_noop!(
+            Parimutuel::exit_early(
+                RuntimeOrigin::signed(ALICE),
+                Asset::ParimutuelShare(market_id, 0u16),
+                BASE
+            ),
+            Error::<Runtime>::InvalidScoringRule
+        );
+    });
+}
+
+#[test]
+fn exit_early_fails_on_zero_amount() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = setup_active_market();
+        let asset = Asset::ParimutuelShare(market_id, 0u16);
+        assert_ok!(Parimutuel::buy(RuntimeOrigin::signed(ALICE), asset, 10 * BASE));
+        assert_noop!(
+            Parimutuel::exit_early(RuntimeOrigin::signed(ALICE), asset, 0),
+            Error::<Runtime>::ZeroAmount
+        );
+    });
+}
+
+#[test]
+fn exit_early_fails_on_insufficient_balance() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = setup_active_market();
+        let asset = Asset::ParimutuelShare(market_id, 0u16);
+        assert_ok!(Parimutuel::buy(RuntimeOrigin::signed(ALICE), asset, 10 * BASE));
+        let balance = AssetManager::free_balance(asset, &ALICE);
+        assert_noop!(
+            Parimutuel::exit_early(RuntimeOrigin::signed(ALICE), asset, balance + 1),
+            Error::<Runtime>::InsufficientBalance
+        );
+    });
+}
+
+#[test]
+fn exit_early_fails_if_remaining_balance_below_minimum_bet_size() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = setup_active_market();
+        let asset = Asset::ParimutuelShare(market_id, 0u16);
+        assert_ok!(Parimutuel::buy(RuntimeOrigin::signed(ALICE), asset, 10 * BASE));
+        let balance = AssetManager::free_balance(asset, &ALICE);
+        let min_bet_size = <Runtime as Config>::MinBetSize::get();
+        assert_noop!(
+            Parimutuel::exit_early(RuntimeOrigin::signed(ALICE), asset, balance - min_bet_size + 1),
+            Error::<Runtime>::RemainingBalanceBelowMinimumBetSize
+        );
+    });
+}
@@ -19,4 +19,6 @@
 
 mod buy;
 mod claim;
+mod exit_early;
+mod pot_info;
 mod refund;
@@ -0,0 +1,90 @@
+// Copyright 2023-2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+#![cfg(test)]
+
+use crate::{mock::*, utils::*, *};
+use frame_support::assert_ok;
+use zeitgeist_primitives::{
+    constants::BASE,
+    math::fixed::{FixedDiv, FixedMulDiv},
+    types::{Asset, MarketStatus, MarketType, OutcomeReport, ScoringRule},
+};
+use zrml_market_commons::Markets;
+
+#[test]
+fn pot_info_returns_odds_matching_claim_rewards() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0;
+        let mut market = market_mock::<Runtime>(MARKET_CREATOR);
+        market.market_type = MarketType::Categorical(3u16);
+        market.status = MarketStatus::Active;
+        Markets::<Runtime>::insert(market_id, market);
+
+        let asset_0 = Asset::ParimutuelShare(market_id, 0u16);
+        let asset_1 = Asset::ParimutuelShare(market_id, 1u16);
+        assert_ok!(Parimutuel::buy(RuntimeOrigin::signed(ALICE), asset_0, 10 * BASE));
+        assert_ok!(Parimutuel::buy(RuntimeOrigin::signed(BOB), asset_0, 20 * BASE));
+        assert_ok!(Parimutuel::buy(RuntimeOrigin::signed(CHARLIE), asset_1, 70 * BASE));
+
+        let pot_info = Parimutuel::pot_info(market_id).unwrap();
+        let outcome_total_0 = 30 * BASE - calculate_fee::<Runtime>(30 * BASE);
+        let outcome_total_1 = 70 * BASE - calculate_fee::<Runtime>(70 * BASE);
+        let pot_total = outcome_total_0 + outcome_total_1;
+        assert_eq!(pot_info.pot_total, pot_total);
+        assert_eq!(pot_info.outcomes.len(), 3);
+        assert_eq!(pot_info.outcomes[0].asset, asset_0);
+        assert_eq!(pot_info.outcomes[0].outcome_total, outcome_total_0);
+        assert_eq!(pot_info.outcomes[0].implied_probability, 3 * BASE / 10);
+        assert_eq!(pot_info.outcomes[1].implied_probability, 7 * BASE / 10);
+        assert_eq!(pot_info.outcomes[2].outcome_total, 0);
+        assert_eq!(pot_info.outcomes[2].implied_probability, 0);
+        assert_eq!(pot_info.outcomes[2].payoff_per_share, None);
+
+        let payoff_per_share = pot_info.outcomes[0].payoff_per_share.unwrap();
+        assert_eq!(payoff_per_share, pot_total.bdiv(outcome_total_0).unwrap());
+
+        // The payoff of Alice's shares matches the payoff per share.
+        let alice_shares = AssetManager::free_balance(asset_0, &ALICE);
+        let expected_payoff = pot_total.bmul_bdiv(alice_shares, outcome_total_0).unwrap();
+        Markets::<Runtime>::mutate(market_id, |market| {
+            let market = market.as_mut().unwrap();
+            market.status = MarketStatus::Resolved;
+            market.resolved_outcome = Some(OutcomeReport::Categorical(0u16));
+        });
+        let free_alice_before = AssetManager::free_balance(Asset::Ztg, &ALICE);
+        assert_ok!(Parimutuel::claim_rewards(RuntimeOrigin::signed(ALICE), market_id));
+        assert_eq!(
+            AssetManager::free_balance(Asset::Ztg, &ALICE),
+            free_alice_before + expected_payoff
+        );
+        assert_eq!(expected_payoff, 33 * BASE);
+    });
+}
+
+#[test]
+fn pot_info_fails_on_non_parimutuel_market() {
+    ExtBuilder::default().build().execute_with(|| {
+        let market_id = 0;
+        let mut market = market_mock::<Runtime>(MARKET_CREATOR);
+        market.scoring_rule = ScoringRule::AmmCdaHybrid;
+        Markets::<Runtime>::insert(market_id, market);
+        assert_eq!(
+            Parimutuel::pot_info(market_id),
+            Err(Error::<Runtime>::InvalidScoringRule.into())
+        );
+    });
+}
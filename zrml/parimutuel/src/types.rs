@@ -0,0 +1,50 @@
+// Copyright 2023-2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use alloc::vec::Vec;
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use zeitgeist_primitives::types::Asset;
+
+/// The current state of the pot of a parimutuel market.
+#[derive(Clone, Debug, Decode, Encode, Eq, PartialEq, TypeInfo)]
+pub struct PotInfo<MarketId, Balance> {
+    /// The amount of base asset in the pot.
+    pub pot_total: Balance,
+
+    /// The state of each outcome of the market.
+    pub outcomes: Vec<OutcomeOdds<MarketId, Balance>>,
+}
+
+/// The current odds of a parimutuel outcome.
+#[derive(Clone, Debug, Decode, Encode, Eq, PartialEq, TypeInfo)]
+pub struct OutcomeOdds<MarketId, Balance> {
+    /// The parimutuel share of the outcome.
+    pub asset: Asset<MarketId>,
+
+    /// The total issuance of `asset`, i.e. the amount of base asset staked on the outcome.
+    pub outcome_total: Balance,
+
+    /// The implied probability of the outcome (fixed point), i.e. the share of the pot staked on
+    /// the outcome.
+    pub implied_probability: Balance,
+
+    /// The amount of base asset a single unit of `asset` would pay out if the outcome were to
+    /// win and the pot didn't change. Equal to the decimal odds of the outcome. `None` if no
+    /// shares of the outcome are outstanding.
+    pub payoff_per_share: Option<Balance>,
+}
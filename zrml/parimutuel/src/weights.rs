@@ -54,6 +54,7 @@ pub trait WeightInfoZeitgeist {
     fn buy() -> Weight;
     fn claim_rewards() -> Weight;
     fn claim_refunds() -> Weight;
+    fn exit_early() -> Weight;
 }
 
 /// Weight functions for zrml_parimutuel (automatically generated)
@@ -95,19 +96,32 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
     }
     /// Storage: `MarketCommons::Markets` (r:1 w:0)
     /// Proof: `MarketCommons::Markets` (`max_values`: None, `max_size`: Some(708), added: 3183, mode: `MaxEncodedLen`)
-    /// Storage: `Tokens::TotalIssuance` (r:2 w:1)
+    /// Storage: `Tokens::TotalIssuance` (r:64 w:1)
     /// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
     /// Storage: `Tokens::Accounts` (r:1 w:1)
     /// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(137), added: 2612, mode: `MaxEncodedLen`)
     /// Storage: `System::Account` (r:1 w:1)
     /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
     fn claim_refunds() -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `790`
-        //  Estimated: `6054`
-        // Minimum execution time: 110_113 nanoseconds.
-        Weight::from_parts(112_452_000, 6054)
-            .saturating_add(T::DbWeight::get().reads(5))
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_parimutuel` to replace it.
+        Weight::from_parts(168_452_000, 165570)
+            .saturating_add(T::DbWeight::get().reads(67))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+    /// Storage: `MarketCommons::Markets` (r:1 w:0)
+    /// Proof: `MarketCommons::Markets` (`max_values`: None, `max_size`: Some(708), added: 3183, mode: `MaxEncodedLen`)
+    /// Storage: `Tokens::Accounts` (r:1 w:1)
+    /// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(137), added: 2612, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+    /// Storage: `Tokens::TotalIssuance` (r:1 w:1)
+    /// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(57), added: 2532, mode: `MaxEncodedLen`)
+    fn exit_early() -> Weight {
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_parimutuel` to replace it.
+        Weight::from_parts(110_532_000, 4173)
+            .saturating_add(T::DbWeight::get().reads(4))
             .saturating_add(T::DbWeight::get().writes(3))
     }
 }
@@ -24,6 +24,7 @@
 #![cfg(feature = "runtime-benchmarks")]
 
 use super::*;
+use crate::types::TemplatePoolParams;
 #[cfg(test)]
 use crate::Pallet as PredictionMarket;
 use alloc::{vec, vec::Vec};
@@ -1354,6 +1355,95 @@ benchmarks! {
         pallet_timestamp::Pallet::<T>::set_timestamp(now.into());
     }: manually_close_market(RawOrigin::Signed(caller), market_id)
 
+    create_market_template {
+        let (caller, oracle, deadlines, _) = create_market_common_parameters::<T>(true)?;
+        let asset_count = T::MaxCategories::get();
+        let pool = TemplatePoolParams {
+            amount: LIQUIDITY.saturated_into(),
+            spot_prices: create_spot_prices::<T>(asset_count).try_into().unwrap(),
+            swap_fee: CENT.saturated_into(),
+        };
+    }: _(
+            RawOrigin::Signed(caller.clone()),
+            Asset::Ztg,
+            Perbill::zero(),
+            oracle,
+            deadlines,
+            MarketCreation::Permissionless,
+            MarketType::Categorical(asset_count),
+            Some(MarketDisputeMechanism::Court),
+            ScoringRule::AmmCdaHybrid,
+            Some(pool)
+    )
+    verify {
+        assert!(MarketTemplates::<T>::contains_key(0));
+    }
+
+    remove_market_template {
+        let (caller, oracle, deadlines, _) = create_market_common_parameters::<T>(true)?;
+        Pallet::<T>::create_market_template(
+            RawOrigin::Signed(caller.clone()).into(),
+            Asset::Ztg,
+            Perbill::zero(),
+            oracle,
+            deadlines,
+            MarketCreation::Permissionless,
+            MarketType::Categorical(T::MaxCategories::get()),
+            Some(MarketDisputeMechanism::Court),
+            ScoringRule::AmmCdaHybrid,
+            None,
+        )?;
+    }: _(RawOrigin::Signed(caller), 0)
+    verify {
+        assert_last_event::<T>(Event::MarketTemplateRemoved(0).into());
+    }
+
+    create_market_series {
+        let (caller, oracle, deadlines, metadata) = create_market_common_parameters::<T>(true)?;
+        Pallet::<T>::create_market_template(
+            RawOrigin::Signed(caller.clone()).into(),
+            Asset::Ztg,
+            Perbill::zero(),
+            oracle,
+            deadlines,
+            MarketCreation::Permissionless,
+            MarketType::Categorical(T::MaxCategories::get()),
+            Some(MarketDisputeMechanism::Court),
+            ScoringRule::AmmCdaHybrid,
+            None,
+        )?;
+        let period = MarketPeriod::Block(0u32.into()..100u32.into());
+    }: _(RawOrigin::Signed(caller), 0, metadata, period, None)
+    verify {
+        assert!(MarketSeries::<T>::contains_key(0));
+    }
+
+    cancel_market_series {
+        let (caller, oracle, deadlines, metadata) = create_market_common_parameters::<T>(true)?;
+        Pallet::<T>::create_market_template(
+            RawOrigin::Signed(caller.clone()).into(),
+            Asset::Ztg,
+            Perbill::zero(),
+            oracle,
+            deadlines,
+            MarketCreation::Permissionless,
+            MarketType::Categorical(T::MaxCategories::get()),
+            Some(MarketDisputeMechanism::Court),
+            ScoringRule::AmmCdaHybrid,
+            None,
+        )?;
+        Pallet::<T>::create_market_series(
+            RawOrigin::Signed(caller.clone()).into(),
+            0,
+            metadata,
+            MarketPeriod::Block(0u32.into()..100u32.into()),
+            None,
+        )?;
+    }: _(RawOrigin::Signed(caller), 0)
+    verify {
+        assert_last_event::<T>(Event::MarketSeriesCancelled(0).into());
+    }
+
     impl_benchmark_test_suite!(
         PredictionMarket,
         crate::mock::ExtBuilder::default().build(),
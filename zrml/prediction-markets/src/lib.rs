@@ -34,7 +34,14 @@ pub use pallet::*;
 
 #[frame_support::pallet]
 mod pallet {
-    use crate::{migrations, weights::*};
+    use crate::{
+        migrations,
+        types::{
+            MarketSeriesId, MarketSeriesInfo, MarketTemplate, MarketTemplateId,
+            MarketTemplateOverrides, TemplatePoolParams,
+        },
+        weights::*,
+    };
     use alloc::{format, vec, vec::Vec};
     use core::{cmp, marker::PhantomData};
     use frame_support::{
@@ -51,7 +58,7 @@ mod pallet {
         weights::Weight,
         Blake2_128Concat, BoundedVec, PalletId, Twox64Concat,
     };
-    use frame_system::{ensure_signed, pallet_prelude::OriginFor};
+    use frame_system::{ensure_signed, pallet_prelude::OriginFor, RawOrigin};
     use sp_runtime::traits::AccountIdConversion;
     #[cfg(feature = "try-runtime")]
     use sp_runtime::{
@@ -66,15 +73,16 @@ mod pallet {
     use orml_traits::{MultiCurrency, NamedMultiReservableCurrency};
     use sp_arithmetic::per_things::{Perbill, Percent};
     use sp_runtime::{
-        traits::{CheckedSub, Saturating, Zero},
+        traits::{CheckedSub, Convert, Saturating, Zero},
         DispatchError, DispatchResult, SaturatedConversion,
     };
     use zeitgeist_primitives::{
         constants::MILLISECS_PER_BLOCK,
         math::fixed::{BaseProvider, FixedDiv, ZeitgeistBase},
         traits::{
-            CompleteSetOperationsApi, DeployPoolApi, DisputeApi, DisputeMaxWeightApi,
-            DisputeResolutionApi, MarketBuilderTrait, PayoutApi,
+            CompleteSetOperationsApi, CreateMarketApi, DeployPoolApi, DisputeApi,
+            DisputeMaxWeightApi, DisputeResolutionApi, MarketBuilderTrait, OnMarketClose,
+            OracleRegistryApi, PayoutApi, ReportMarketApi,
         },
         types::{
             Asset, Bond, Deadlines, EarlyClose, EarlyCloseState, GlobalDisputeItem, Market,
@@ -107,14 +115,36 @@ mod pallet {
     pub(crate) type MarketOf<T> =
         Market<AccountIdOf<T>, BalanceOf<T>, BlockNumberFor<T>, MomentOf<T>, MarketIdOf<T>>;
     pub(crate) type MarketPeriodOf<T> = MarketPeriod<BlockNumberFor<T>, MomentOf<T>>;
+    pub(crate) type MarketSeriesInfoOf<T> =
+        MarketSeriesInfo<AccountIdOf<T>, BalanceOf<T>, MarketIdOf<T>, MarketPeriodOf<T>>;
+    pub(crate) type MarketTemplateOf<T> = MarketTemplate<
+        AccountIdOf<T>,
+        BalanceOf<T>,
+        BlockNumberFor<T>,
+        MarketIdOf<T>,
+        SpotPricesOf<T>,
+    >;
+    pub(crate) type MarketTemplateOverridesOf<T> =
+        MarketTemplateOverrides<AccountIdOf<T>, BlockNumberFor<T>>;
     pub(crate) type MomentOf<T> =
         <<T as zrml_market_commons::Config>::Timestamp as frame_support::traits::Time>::Moment;
     pub(crate) type NegativeImbalanceOf<T> =
         <<T as Config>::Currency as Currency<AccountIdOf<T>>>::NegativeImbalance;
     pub(crate) type RejectReason<T> = BoundedVec<u8, <T as Config>::MaxRejectReasonLen>;
     pub(crate) type ReportOf<T> = Report<AccountIdOf<T>, BlockNumberFor<T>>;
+    pub(crate) type SpotPricesOf<T> = BoundedVec<BalanceOf<T>, MaxCategoriesOf<T>>;
+    pub(crate) type TemplatePoolParamsOf<T> = TemplatePoolParams<BalanceOf<T>, SpotPricesOf<T>>;
     pub(crate) type TimeFrame = u64;
 
+    /// `MaxCategories` as `u32`, used to bound the spot prices of market templates.
+    pub struct MaxCategoriesOf<T>(PhantomData<T>);
+
+    impl<T: Config> Get<u32> for MaxCategoriesOf<T> {
+        fn get() -> u32 {
+            T::MaxCategories::get().into()
+        }
+    }
+
     macro_rules! impl_unreserve_bond {
         ($fn_name:ident, $bond_type:ident) => {
             /// Settle the $bond_type bond by unreserving it.
@@ -345,7 +375,11 @@ mod pallet {
         // Within the same block, operations that interact with the activeness of the same
         // market will behave differently before and after this call.
         #[pallet::call_index(1)]
-        #[pallet::weight(T::WeightInfo::admin_move_market_to_closed(CacheSize::get()))]
+        #[pallet::weight(
+            T::WeightInfo::admin_move_market_to_closed(CacheSize::get())
+                .saturating_add(T::OnMarketClose::on_market_close_max_weight())
+                .saturating_add(Pallet::<T>::queue_market_series_rollover_max_weight())
+        )]
         #[transactional]
         pub fn admin_move_market_to_closed(
             origin: OriginFor<T>,
@@ -355,10 +389,19 @@ mod pallet {
             let market = <zrml_market_commons::Pallet<T>>::market(&market_id)?;
             Self::ensure_market_is_active(&market)?;
             let close_ids_len = Self::clear_auto_close(&market_id)?;
-            Self::close_market(&market_id)?;
+            let close_weight = Self::close_market(&market_id)?;
             Self::set_market_end(&market_id)?;
+            let rollover_weight = Self::queue_market_series_rollover(&market_id);
             // The CloseOrigin should not pay fees for providing this service
-            Ok((Some(T::WeightInfo::admin_move_market_to_closed(close_ids_len)), Pays::No).into())
+            Ok((
+                Some(
+                    T::WeightInfo::admin_move_market_to_closed(close_ids_len)
+                        .saturating_add(close_weight)
+                        .saturating_add(rollover_weight),
+                ),
+                Pays::No,
+            )
+                .into())
         }
 
         /// Allows the `ResolveOrigin` to immediately move a reported or disputed
@@ -685,6 +728,12 @@ mod pallet {
 
             let ids_amount: u32 = Self::insert_auto_close(&market_id)?;
 
+            Self::store_fallback_oracle(&market_id, &edited_market.oracle);
+
+            if edited_market.dispute_mechanism != Some(MarketDisputeMechanism::Authorized) {
+                Self::clear_authority_committee(&market_id, &old_market);
+            }
+
             MarketIdsForEdit::<T>::remove(market_id);
             Self::deposit_event(Event::MarketEdited(market_id, edited_market));
 
@@ -875,10 +924,7 @@ mod pallet {
         /// Complexity: `O(n)`, where `n` is the number of market ids,
         /// which reported at the same time as the specified market.
         #[pallet::call_index(14)]
-        #[pallet::weight(
-            T::WeightInfo::report_market_with_dispute_mechanism(CacheSize::get())
-                .max(T::WeightInfo::report_trusted_market())
-        )]
+        #[pallet::weight(Pallet::<T>::report_market_weight())]
         #[transactional]
         pub fn report(
             origin: OriginFor<T>,
@@ -962,11 +1008,6 @@ mod pallet {
                 Error::<T>::InvalidMarketStatus
             );
 
-            ensure!(
-                matches!(dispute_mechanism, MarketDisputeMechanism::Court),
-                Error::<T>::InvalidDisputeMechanism
-            );
-
             ensure!(
                 !T::GlobalDisputes::does_exist(&market_id),
                 Error::<T>::GlobalDisputeExistsAlready
@@ -1419,7 +1460,11 @@ mod pallet {
         /// and `m` is the number of market ids,
         /// which close at the same time as the specified market.
         #[pallet::call_index(21)]
-        #[pallet::weight(T::WeightInfo::close_trusted_market(CacheSize::get()))]
+        #[pallet::weight(
+            T::WeightInfo::close_trusted_market(CacheSize::get())
+                .saturating_add(T::OnMarketClose::on_market_close_max_weight())
+                .saturating_add(Pallet::<T>::queue_market_series_rollover_max_weight())
+        )]
         #[transactional]
         pub fn close_trusted_market(
             origin: OriginFor<T>,
@@ -1431,9 +1476,15 @@ mod pallet {
             ensure!(market.dispute_mechanism.is_none(), Error::<T>::MarketIsNotTrusted);
             Self::ensure_market_is_active(&market)?;
             let close_ids_len = Self::clear_auto_close(&market_id)?;
-            Self::close_market(&market_id)?;
+            let close_weight = Self::close_market(&market_id)?;
             Self::set_market_end(&market_id)?;
-            Ok(Some(T::WeightInfo::close_trusted_market(close_ids_len)).into())
+            let rollover_weight = Self::queue_market_series_rollover(&market_id);
+            Ok(Some(
+                T::WeightInfo::close_trusted_market(close_ids_len)
+                    .saturating_add(close_weight)
+                    .saturating_add(rollover_weight),
+            )
+            .into())
         }
 
         /// Allows the manual closing for "broken" markets.
@@ -1446,7 +1497,11 @@ mod pallet {
         /// and `n` is the number of market ids,
         /// which close at the same time as the specified market.
         #[pallet::call_index(22)]
-        #[pallet::weight(T::WeightInfo::manually_close_market(CacheSize::get()))]
+        #[pallet::weight(
+            T::WeightInfo::manually_close_market(CacheSize::get())
+                .saturating_add(T::OnMarketClose::on_market_close_max_weight())
+                .saturating_add(Pallet::<T>::queue_market_series_rollover_max_weight())
+        )]
         #[transactional]
         pub fn manually_close_market(
             origin: OriginFor<T>,
@@ -1463,7 +1518,7 @@ mod pallet {
                 MarketPeriod::Timestamp(ref range) => range,
             };
 
-            let close_ids_len = if range.end <= now {
+            let (close_ids_len, close_weight) = if range.end <= now {
                 let range_end_time_frame = Self::calculate_time_frame_of_moment(range.end);
                 let close_ids_len = MarketIdsPerCloseTimeFrame::<T>::try_mutate(
                     range_end_time_frame,
@@ -1477,14 +1532,285 @@ mod pallet {
                         Ok(ids_len)
                     },
                 )?;
-                Self::on_market_close(&market_id, market)?;
+                let close_weight = Self::on_market_close(&market_id, market)?;
                 Self::set_market_end(&market_id)?;
-                close_ids_len
+                (close_ids_len, close_weight)
             } else {
                 return Err(Error::<T>::MarketPeriodEndNotAlreadyReachedYet.into());
             };
+            let rollover_weight = Self::queue_market_series_rollover(&market_id);
+
+            Ok(Some(
+                T::WeightInfo::manually_close_market(close_ids_len)
+                    .saturating_add(close_weight)
+                    .saturating_add(rollover_weight),
+            )
+            .into())
+        }
+
+        /// Creates a market template which holds default creation parameters for markets and,
+        /// optionally, the parameters of a pool to deploy for each market. The owner of the
+        /// template reserves the `MarketTemplateBond` until the template is removed.
+        ///
+        /// # Arguments
+        ///
+        /// * `pool`: The parameters of the pool to deploy for markets created from the template.
+        ///   Requires a permissionless market with the `AmmCdaHybrid` scoring rule and one spot
+        ///   price per outcome.
+        ///
+        /// The other arguments are the same as for `create_market`.
+        ///
+        /// # Weight
+        ///
+        /// Complexity: `O(1)`
+        #[pallet::call_index(23)]
+        #[pallet::weight(T::WeightInfo::create_market_template())]
+        #[transactional]
+        pub fn create_market_template(
+            origin: OriginFor<T>,
+            base_asset: AssetOf<T>,
+            creator_fee: Perbill,
+            oracle: T::AccountId,
+            deadlines: DeadlinesOf<T>,
+            creation: MarketCreation,
+            market_type: MarketType,
+            dispute_mechanism: Option<MarketDisputeMechanism>,
+            scoring_rule: ScoringRule,
+            pool: Option<TemplatePoolParamsOf<T>>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(creator_fee <= T::MaxCreatorFee::get(), Error::<T>::FeeTooHigh);
+            Self::ensure_base_asset_is_valid(base_asset)?;
+            Self::ensure_market_deadlines_are_valid(&deadlines, dispute_mechanism.is_none())?;
+            Self::ensure_market_type_is_valid(&market_type)?;
+            if let Some(pool) = &pool {
+                let outcome_count = match market_type {
+                    MarketType::Categorical(categories) => categories.into(),
+                    MarketType::Scalar(_) => 2usize,
+                };
+                ensure!(
+                    creation == MarketCreation::Permissionless
+                        && scoring_rule == ScoringRule::AmmCdaHybrid
+                        && !pool.amount.is_zero()
+                        && pool.spot_prices.len() == outcome_count,
+                    Error::<T>::InvalidTemplatePoolParams
+                );
+            }
+
+            let bond = T::MarketTemplateBond::get();
+            T::AssetManager::reserve_named(&Self::reserve_id(), Asset::Ztg, &who, bond)?;
+
+            let template_id = NextMarketTemplateId::<T>::get();
+            let next_template_id = template_id.checked_add(1).ok_or(Error::<T>::StorageOverflow)?;
+            let template = MarketTemplate {
+                owner: who,
+                bond,
+                base_asset,
+                creator_fee,
+                oracle,
+                deadlines,
+                creation,
+                market_type,
+                dispute_mechanism,
+                scoring_rule,
+                pool,
+            };
+            MarketTemplates::<T>::insert(template_id, template.clone());
+            NextMarketTemplateId::<T>::put(next_template_id);
+
+            Self::deposit_event(Event::MarketTemplateCreated(template_id, template));
+
+            Ok(())
+        }
+
+        /// Removes a market template and unreserves its bond. Only callable by the owner of the
+        /// template. Markets created from the template are not affected.
+        ///
+        /// # Weight
+        ///
+        /// Complexity: `O(1)`
+        #[pallet::call_index(24)]
+        #[pallet::weight(T::WeightInfo::remove_market_template())]
+        #[transactional]
+        pub fn remove_market_template(
+            origin: OriginFor<T>,
+            template_id: MarketTemplateId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let template =
+                MarketTemplates::<T>::get(template_id).ok_or(Error::<T>::MarketTemplateNotFound)?;
+            ensure!(template.owner == who, Error::<T>::CallerNotTemplateOwner);
+
+            let missing = T::AssetManager::unreserve_named(
+                &Self::reserve_id(),
+                Asset::Ztg,
+                &who,
+                template.bond,
+            );
+            debug_assert!(
+                missing.is_zero(),
+                "Could not unreserve all of the amount. reserve_id: {:?}, who: {:?}, value: {:?}.",
+                &Self::reserve_id(),
+                &who,
+                template.bond,
+            );
+            MarketTemplates::<T>::remove(template_id);
+
+            Self::deposit_event(Event::MarketTemplateRemoved(template_id));
+
+            Ok(())
+        }
+
+        /// Creates a market from a market template. If the template has pool parameters, the
+        /// sender buys complete sets and deploys a pool as in `create_market_and_deploy_pool`.
+        ///
+        /// # Arguments
+        ///
+        /// * `template_id`: The id of the template.
+        /// * `overrides`: Parameters which replace those of the template.
+        /// * `metadata`: The metadata of the market.
+        /// * `period`: The period of the market.
+        ///
+        /// # Weight
+        ///
+        /// Complexity: `O(n + m)`, where `n` is the number of markets which close on the same
+        /// block and `m` is the number of assets in the market if a pool is deployed.
+        #[pallet::call_index(25)]
+        #[pallet::weight(
+            Pallet::<T>::create_market_from_template_max_weight()
+                .saturating_add(T::DbWeight::get().reads(1))
+        )]
+        #[transactional]
+        pub fn create_market_from_template(
+            origin: OriginFor<T>,
+            template_id: MarketTemplateId,
+            overrides: MarketTemplateOverridesOf<T>,
+            metadata: MultiHash,
+            period: MarketPeriodOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            let template =
+                MarketTemplates::<T>::get(template_id).ok_or(Error::<T>::MarketTemplateNotFound)?;
+            let (market_id, weight) =
+                Self::do_create_market_from_template(who, template, overrides, metadata, period)?;
+
+            Self::deposit_event(Event::MarketCreatedFromTemplate(market_id, template_id));
+
+            Ok(Some(weight.saturating_add(T::DbWeight::get().reads(1))).into())
+        }
+
+        /// Creates a series of markets from a market template. The first market of the series is
+        /// created immediately. Whenever the latest market of the series closes, the rollover of
+        /// the series is queued and the next market is created in `on_idle` with a period which
+        /// begins where the scheduled period of the closed market ends and has the same length.
+        /// The owner of the series is the creator of all its markets and reserves the
+        /// `MarketSeriesBond` until the series ends.
+        ///
+        /// At most `MaxMarketSeriesRolloversPerBlock` series may have a latest market which is
+        /// scheduled to close in the same block or time frame.
+        ///
+        /// The series ends when all markets were created, when it's cancelled by the owner or
+        /// when the next market can't be created, for example because the template was removed,
+        /// the owner can't afford the bonds of the market or the period of the next market has
+        /// already ended when the queued rollover is executed.
+        ///
+        /// # Arguments
+        ///
+        /// * `template_id`: The id of the template.
+        /// * `metadata`: The metadata shared by all markets of the series.
+        /// * `period`: The period of the first market of the series.
+        /// * `remaining_markets`: The number of markets to create after the first market, or
+        ///   `None` if the series should continue until it's cancelled.
+        ///
+        /// # Weight
+        ///
+        /// Complexity: `O(n + m)`, where `n` is the number of markets which close on the same
+        /// block and `m` is the number of assets in the market if a pool is deployed.
+        #[pallet::call_index(26)]
+        #[pallet::weight(
+            T::WeightInfo::create_market_series()
+                .saturating_add(Pallet::<T>::create_market_from_template_max_weight())
+        )]
+        #[transactional]
+        pub fn create_market_series(
+            origin: OriginFor<T>,
+            template_id: MarketTemplateId,
+            metadata: MultiHash,
+            period: MarketPeriodOf<T>,
+            remaining_markets: Option<u32>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            let template =
+                MarketTemplates::<T>::get(template_id).ok_or(Error::<T>::MarketTemplateNotFound)?;
+
+            let bond = T::MarketSeriesBond::get();
+            T::AssetManager::reserve_named(&Self::reserve_id(), Asset::Ztg, &who, bond)?;
+
+            let MultiHash::Sha3_384(multihash) = metadata;
+            let (market_id, weight) = Self::do_create_market_from_template(
+                who.clone(),
+                template,
+                Default::default(),
+                metadata,
+                period.clone(),
+            )?;
+
+            let next_period = Self::next_series_period(&period);
+            Self::schedule_market_series_rollover(&next_period)?;
+
+            let series_id = NextMarketSeriesId::<T>::get();
+            let next_series_id = series_id.checked_add(1).ok_or(Error::<T>::StorageOverflow)?;
+            let series = MarketSeriesInfo {
+                owner: who,
+                bond,
+                template_id,
+                metadata: multihash,
+                latest_market_id: market_id,
+                next_period,
+                remaining_markets,
+            };
+            MarketSeries::<T>::insert(series_id, series.clone());
+            MarketIdToSeriesId::<T>::insert(market_id, series_id);
+            NextMarketSeriesId::<T>::put(next_series_id);
+
+            Self::deposit_event(Event::MarketSeriesCreated(series_id, series));
+
+            Ok(Some(T::WeightInfo::create_market_series().saturating_add(weight)).into())
+        }
+
+        /// Cancels a market series and unreserves its bond. Only callable by the owner of the
+        /// series. Markets which were already created are not affected, but no further markets
+        /// are created.
+        ///
+        /// # Weight
+        ///
+        /// Complexity: `O(1)`
+        #[pallet::call_index(27)]
+        #[pallet::weight(T::WeightInfo::cancel_market_series())]
+        #[transactional]
+        pub fn cancel_market_series(
+            origin: OriginFor<T>,
+            series_id: MarketSeriesId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let series =
+                MarketSeries::<T>::get(series_id).ok_or(Error::<T>::MarketSeriesNotFound)?;
+            ensure!(series.owner == who, Error::<T>::CallerNotSeriesOwner);
 
-            Ok(Some(T::WeightInfo::manually_close_market(close_ids_len)).into())
+            MarketIdToSeriesId::<T>::remove(series.latest_market_id);
+            if PendingMarketSeriesRollovers::<T>::take(series_id).is_none() {
+                Self::unschedule_market_series_rollover(&series.next_period);
+            }
+            Self::end_market_series(series_id, &series);
+
+            Self::deposit_event(Event::MarketSeriesCancelled(series_id));
+
+            Ok(())
         }
     }
 
@@ -1646,6 +1972,20 @@ mod pallet {
         #[pallet::constant]
         type MaxEditReasonLen: Get<u32>;
 
+        /// The amount of ZTG reserved from the owner of a market template.
+        #[pallet::constant]
+        type MarketTemplateBond: Get<BalanceOf<Self>>;
+
+        /// The amount of ZTG reserved from the owner of a market series.
+        #[pallet::constant]
+        type MarketSeriesBond: Get<BalanceOf<Self>>;
+
+        /// The maximum number of market series whose latest market is scheduled to close in the
+        /// same block or time frame. Also the maximum number of queued rollovers executed in a
+        /// single block.
+        #[pallet::constant]
+        type MaxMarketSeriesRolloversPerBlock: Get<u32>;
+
         #[pallet::constant]
         type OutsiderBond: Get<BalanceOf<Self>>;
 
@@ -1671,6 +2011,17 @@ mod pallet {
         #[pallet::constant]
         type OracleBond: Get<BalanceOf<Self>>;
 
+        /// Used to look up the fallback oracles of registered oracles and to update their
+        /// reputation.
+        type OracleRegistry: OracleRegistryApi<
+            AccountId = Self::AccountId,
+            Balance = BalanceOf<Self>,
+        >;
+
+        /// Called when a market is closed, for example to cancel the orders of other pallets which
+        /// must not outlive the market.
+        type OnMarketClose: OnMarketClose<MarketId = MarketIdOf<Self>>;
+
         /// The origin that is allowed to request edits in pending advised markets.
         type RequestEditOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
@@ -1815,6 +2166,23 @@ mod pallet {
         MarketNotInCloseTimeFrameList,
         /// The market period end was not already reached yet.
         MarketPeriodEndNotAlreadyReachedYet,
+        /// The market template does not exist.
+        MarketTemplateNotFound,
+        /// The caller is not the owner of the market template.
+        CallerNotTemplateOwner,
+        /// The pool parameters of the market template are invalid or the template doesn't
+        /// describe a permissionless market with the `AmmCdaHybrid` scoring rule.
+        InvalidTemplatePoolParams,
+        /// The market series does not exist.
+        MarketSeriesNotFound,
+        /// The caller is not the owner of the market series.
+        CallerNotSeriesOwner,
+        /// The maximum number of market series rollovers in the block or time frame in which the
+        /// latest market of the series closes was reached.
+        TooManyMarketSeriesRollovers,
+        /// The report is not coming from the designated oracle or its fallback oracle during the
+        /// exclusive reporting window of the fallback oracle.
+        ReporterNotFallbackOracle,
     }
 
     #[pallet::event]
@@ -1869,6 +2237,23 @@ mod pallet {
         GlobalDisputeStarted(MarketIdOf<T>),
         /// The recovery limit for timestamp based markets was reached due to a prolonged chain stall.
         RecoveryLimitReached { last_time_frame: TimeFrame, limit_time_frame: TimeFrame },
+        /// A market template has been created. \[template_id, template\]
+        MarketTemplateCreated(MarketTemplateId, MarketTemplateOf<T>),
+        /// A market template has been removed. \[template_id\]
+        MarketTemplateRemoved(MarketTemplateId),
+        /// A market has been created from a template. \[market_id, template_id\]
+        MarketCreatedFromTemplate(MarketIdOf<T>, MarketTemplateId),
+        /// A market series has been created. \[series_id, series\]
+        MarketSeriesCreated(MarketSeriesId, MarketSeriesInfoOf<T>),
+        /// The next market of a series has been created. \[series_id, market_id\]
+        MarketSeriesRolledOver(MarketSeriesId, MarketIdOf<T>),
+        /// A market series has ended after all of its markets were created. \[series_id\]
+        MarketSeriesEnded(MarketSeriesId),
+        /// A market series has ended because its next market couldn't be created.
+        /// \[series_id, error\]
+        MarketSeriesRolloverFailed(MarketSeriesId, DispatchError),
+        /// A market series has been cancelled by its owner. \[series_id\]
+        MarketSeriesCancelled(MarketSeriesId),
     }
 
     #[pallet::hooks]
@@ -1973,6 +2358,8 @@ mod pallet {
                     |market_id, market| {
                         let weight = Self::on_market_close(market_id, market)?;
                         total_weight = total_weight.saturating_add(weight);
+                        let weight = Self::queue_market_series_rollover(market_id);
+                        total_weight = total_weight.saturating_add(weight);
                         Ok(())
                     },
                 );
@@ -2023,6 +2410,10 @@ mod pallet {
 
             total_weight.saturating_add(T::WeightInfo::on_initialize_resolve_overhead())
         }
+
+        fn on_idle(_now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            Self::process_market_series_rollovers(remaining_weight)
+        }
     }
 
     #[pallet::pallet]
@@ -2085,6 +2476,58 @@ mod pallet {
     pub type MarketIdsForEdit<T: Config> =
         StorageMap<_, Twox64Concat, MarketIdOf<T>, EditReason<T>>;
 
+    /// The id of the next market template.
+    #[pallet::storage]
+    pub type NextMarketTemplateId<T: Config> = StorageValue<_, MarketTemplateId, ValueQuery>;
+
+    /// Market templates with default creation parameters, keyed by their id.
+    #[pallet::storage]
+    pub type MarketTemplates<T: Config> =
+        StorageMap<_, Twox64Concat, MarketTemplateId, MarketTemplateOf<T>>;
+
+    /// The id of the next market series.
+    #[pallet::storage]
+    pub type NextMarketSeriesId<T: Config> = StorageValue<_, MarketSeriesId, ValueQuery>;
+
+    /// Market series, keyed by their id.
+    #[pallet::storage]
+    pub type MarketSeries<T: Config> =
+        StorageMap<_, Twox64Concat, MarketSeriesId, MarketSeriesInfoOf<T>>;
+
+    /// Maps the latest market of each market series to the id of the series.
+    #[pallet::storage]
+    pub type MarketIdToSeriesId<T: Config> =
+        StorageMap<_, Twox64Concat, MarketIdOf<T>, MarketSeriesId>;
+
+    /// The number of market series whose latest market is scheduled to close at the key block.
+    #[pallet::storage]
+    pub type MarketSeriesRolloversPerCloseBlock<T: Config> =
+        StorageMap<_, Twox64Concat, BlockNumberFor<T>, u32, ValueQuery>;
+
+    /// The number of market series whose latest market is scheduled to close in the key time
+    /// frame.
+    #[pallet::storage]
+    pub type MarketSeriesRolloversPerCloseTimeFrame<T: Config> =
+        StorageMap<_, Twox64Concat, TimeFrame, u32, ValueQuery>;
+
+    /// Market series whose latest market was closed and whose next market is created in
+    /// `on_idle`.
+    #[pallet::storage]
+    pub type PendingMarketSeriesRollovers<T: Config> =
+        StorageMap<_, Twox64Concat, MarketSeriesId, ()>;
+
+    /// Maps markets to the fallback oracle which the oracle of the market had named when the
+    /// market was created. Removed when the market is resolved or destroyed.
+    #[pallet::storage]
+    pub type MarketIdToFallbackOracle<T: Config> =
+        StorageMap<_, Twox64Concat, MarketIdOf<T>, AccountIdOf<T>>;
+
+    /// Maps reported markets to the oracle or fallback oracle which reported within its exclusive
+    /// reporting window. Used to update the reputation of the reporter when the market resolves.
+    #[pallet::storage]
+    pub type TimelyOracleReports<T: Config> =
+        StorageMap<_, Twox64Concat, MarketIdOf<T>, AccountIdOf<T>>;
+
     impl<T: Config> Pallet<T> {
         impl_unreserve_bond!(unreserve_creation_bond, creation);
         impl_unreserve_bond!(unreserve_oracle_bond, oracle);
@@ -2169,11 +2612,288 @@ mod pallet {
 
             let ids_amount: u32 = Self::insert_auto_close(&market_id)?;
 
+            Self::store_fallback_oracle(&market_id, &market.oracle);
+
+            if market.dispute_mechanism == Some(MarketDisputeMechanism::Authorized) {
+                <T::Authorized as zrml_authorized::AuthorizedPalletApi>::on_market_creation(
+                    &market_id,
+                );
+            }
+
             Self::deposit_event(Event::MarketCreated(market_id, market_account, market));
 
             Ok((ids_amount, market_id))
         }
 
+        /// Creates a market from `template` with the `overrides` applied and deploys a pool if
+        /// the template has pool parameters. Returns the id of the market and the weight consumed.
+        #[require_transactional]
+        pub(crate) fn do_create_market_from_template(
+            who: T::AccountId,
+            template: MarketTemplateOf<T>,
+            overrides: MarketTemplateOverridesOf<T>,
+            metadata: MultiHash,
+            period: MarketPeriodOf<T>,
+        ) -> Result<(MarketIdOf<T>, Weight), DispatchError> {
+            let (ids_len, market_id) = Self::do_create_market(
+                who.clone(),
+                template.base_asset,
+                overrides.creator_fee.unwrap_or(template.creator_fee),
+                overrides.oracle.unwrap_or(template.oracle),
+                period,
+                overrides.deadlines.unwrap_or(template.deadlines),
+                metadata,
+                template.creation,
+                template.market_type,
+                template.dispute_mechanism,
+                template.scoring_rule,
+            )?;
+
+            let weight = if let Some(pool) = template.pool {
+                Self::do_buy_complete_set(who.clone(), market_id, pool.amount)?;
+                let spot_prices_len = pool.spot_prices.len() as u32;
+                T::DeployPool::deploy_pool(
+                    who,
+                    market_id,
+                    pool.amount,
+                    pool.spot_prices.into_inner(),
+                    pool.swap_fee,
+                )?;
+                T::WeightInfo::create_market_and_deploy_pool(ids_len, spot_prices_len)
+            } else {
+                T::WeightInfo::create_market(ids_len)
+            };
+
+            Ok((market_id, weight))
+        }
+
+        /// The maximum weight of `do_create_market_from_template`.
+        pub(crate) fn create_market_from_template_max_weight() -> Weight {
+            T::WeightInfo::create_market(CacheSize::get()).max(
+                T::WeightInfo::create_market_and_deploy_pool(
+                    CacheSize::get(),
+                    T::MaxCategories::get().into(),
+                ),
+            )
+        }
+
+        /// The maximum weight of the oracle registry lookups and reputation updates in
+        /// `report_market_with_dispute_mechanism`: the fallback oracle and the collateral of the
+        /// market are read and both oracles are penalized, which includes slashing their stakes.
+        pub(crate) fn oracle_registry_report_max_weight() -> Weight {
+            T::DbWeight::get().reads_writes(8, 6)
+        }
+
+        /// Stores the fallback oracle currently named by `oracle` for `market_id`, so that the
+        /// fallback oracle can't be changed after the market was created.
+        fn store_fallback_oracle(market_id: &MarketIdOf<T>, oracle: &AccountIdOf<T>) {
+            match T::OracleRegistry::fallback_oracle(oracle) {
+                Some(fallback) => MarketIdToFallbackOracle::<T>::insert(market_id, fallback),
+                None => MarketIdToFallbackOracle::<T>::remove(market_id),
+            }
+        }
+
+        /// The collateral held by the account of `market`, which weights the reputation updates
+        /// of its oracle.
+        fn market_stake(market_id: &MarketIdOf<T>, market: &MarketOf<T>) -> BalanceOf<T> {
+            T::AssetManager::free_balance(market.base_asset, &Self::market_account(*market_id))
+        }
+
+        /// The maximum weight of `roll_over_market_series`.
+        pub(crate) fn roll_over_market_series_max_weight() -> Weight {
+            Self::create_market_from_template_max_weight()
+                .saturating_add(T::DbWeight::get().reads_writes(4, 4))
+        }
+
+        /// The maximum weight of `queue_market_series_rollover`.
+        pub(crate) fn queue_market_series_rollover_max_weight() -> Weight {
+            T::DbWeight::get().reads_writes(3, 3)
+        }
+
+        /// Counts the rollover of a market series whose latest market is scheduled to close where
+        /// `next_period` begins. Fails if `MaxMarketSeriesRolloversPerBlock` rollovers are already
+        /// due in the same block or time frame.
+        fn schedule_market_series_rollover(next_period: &MarketPeriodOf<T>) -> DispatchResult {
+            let max_rollovers = T::MaxMarketSeriesRolloversPerBlock::get();
+            let try_increment = |count: &mut u32| -> DispatchResult {
+                ensure!(*count < max_rollovers, Error::<T>::TooManyMarketSeriesRollovers);
+                *count = count.saturating_add(1);
+                Ok(())
+            };
+            match next_period {
+                MarketPeriod::Block(range) => {
+                    MarketSeriesRolloversPerCloseBlock::<T>::try_mutate(range.start, try_increment)
+                }
+                MarketPeriod::Timestamp(range) => {
+                    let time_frame = Self::calculate_time_frame_of_moment(range.start);
+                    MarketSeriesRolloversPerCloseTimeFrame::<T>::try_mutate(
+                        time_frame,
+                        try_increment,
+                    )
+                }
+            }
+        }
+
+        /// Reverts `schedule_market_series_rollover`.
+        fn unschedule_market_series_rollover(next_period: &MarketPeriodOf<T>) {
+            let decrement = |maybe_count: &mut Option<u32>| {
+                if let Some(count) = maybe_count {
+                    *count = count.saturating_sub(1);
+                    if count.is_zero() {
+                        *maybe_count = None;
+                    }
+                }
+            };
+            match next_period {
+                MarketPeriod::Block(range) => {
+                    MarketSeriesRolloversPerCloseBlock::<T>::mutate_exists(range.start, decrement)
+                }
+                MarketPeriod::Timestamp(range) => {
+                    let time_frame = Self::calculate_time_frame_of_moment(range.start);
+                    MarketSeriesRolloversPerCloseTimeFrame::<T>::mutate_exists(
+                        time_frame, decrement,
+                    )
+                }
+            }
+        }
+
+        /// Queues the rollover of the market series that `market_id` is the latest market of, if
+        /// any. The next market of the series is created in `on_idle`. Never fails and returns the
+        /// weight consumed.
+        pub(crate) fn queue_market_series_rollover(market_id: &MarketIdOf<T>) -> Weight {
+            let Some(series_id) = MarketIdToSeriesId::<T>::take(market_id) else {
+                return T::DbWeight::get().reads(1);
+            };
+            if let Some(series) = MarketSeries::<T>::get(series_id) {
+                Self::unschedule_market_series_rollover(&series.next_period);
+            }
+            PendingMarketSeriesRollovers::<T>::insert(series_id, ());
+            Self::queue_market_series_rollover_max_weight()
+        }
+
+        /// Executes up to `MaxMarketSeriesRolloversPerBlock` queued rollovers for as long as
+        /// `remaining_weight` allows. Returns the weight consumed.
+        pub(crate) fn process_market_series_rollovers(remaining_weight: Weight) -> Weight {
+            let rollover_weight = Self::roll_over_market_series_max_weight();
+            let max_rollovers = T::MaxMarketSeriesRolloversPerBlock::get() as usize;
+            let mut consumed = Weight::zero();
+            let mut iter = PendingMarketSeriesRollovers::<T>::iter_keys();
+            let mut series_ids = Vec::new();
+            while series_ids.len() < max_rollovers
+                && remaining_weight.all_gte(consumed.saturating_add(rollover_weight))
+            {
+                let Some(series_id) = iter.next() else {
+                    break;
+                };
+                series_ids.push(series_id);
+                consumed = consumed.saturating_add(rollover_weight);
+            }
+
+            for series_id in series_ids {
+                PendingMarketSeriesRollovers::<T>::remove(series_id);
+                Self::roll_over_market_series(series_id);
+            }
+
+            consumed
+        }
+
+        /// Creates the next market of the series `series_id`. Ends the series if all of its
+        /// markets were created or if the next market can't be created. Never fails and returns
+        /// the weight consumed.
+        pub(crate) fn roll_over_market_series(series_id: MarketSeriesId) -> Weight {
+            let mut weight = T::DbWeight::get().reads(1);
+            let Some(mut series) = MarketSeries::<T>::get(series_id) else {
+                // The series was cancelled after its rollover was queued.
+                return weight;
+            };
+            weight = weight.saturating_add(T::DbWeight::get().reads_writes(2, 2));
+
+            if series.remaining_markets == Some(0) {
+                Self::end_market_series(series_id, &series);
+                Self::deposit_event(Event::MarketSeriesEnded(series_id));
+                return weight;
+            }
+
+            let result = with_transaction(|| match Self::do_roll_over_market_series(&series) {
+                Ok(result) => TransactionOutcome::Commit(Ok(result)),
+                Err(err) => TransactionOutcome::Rollback(Err(err)),
+            });
+            match result {
+                Ok((next_market_id, create_weight)) => {
+                    series.latest_market_id = next_market_id;
+                    series.next_period = Self::next_series_period(&series.next_period);
+                    series.remaining_markets =
+                        series.remaining_markets.map(|remaining| remaining.saturating_sub(1));
+                    MarketIdToSeriesId::<T>::insert(next_market_id, series_id);
+                    MarketSeries::<T>::insert(series_id, series);
+                    Self::deposit_event(Event::MarketSeriesRolledOver(series_id, next_market_id));
+                    weight = weight.saturating_add(create_weight);
+                }
+                Err(err) => {
+                    Self::end_market_series(series_id, &series);
+                    Self::deposit_event(Event::MarketSeriesRolloverFailed(series_id, err));
+                }
+            }
+
+            weight
+        }
+
+        #[require_transactional]
+        fn do_roll_over_market_series(
+            series: &MarketSeriesInfoOf<T>,
+        ) -> Result<(MarketIdOf<T>, Weight), DispatchError> {
+            let template = MarketTemplates::<T>::get(series.template_id)
+                .ok_or(Error::<T>::MarketTemplateNotFound)?;
+            if let MarketPeriod::Timestamp(ref range) = series.next_period {
+                // `on_initialize` closes the markets of the time frame after the time frame of
+                // `now`. Markets that close in that time frame would never be closed.
+                let now_frame =
+                    Self::calculate_time_frame_of_moment(<zrml_market_commons::Pallet<T>>::now());
+                let end_frame = Self::calculate_time_frame_of_moment(range.end);
+                ensure!(now_frame.saturating_add(1) < end_frame, Error::<T>::InvalidMarketPeriod);
+            }
+            Self::schedule_market_series_rollover(&Self::next_series_period(&series.next_period))?;
+            Self::do_create_market_from_template(
+                series.owner.clone(),
+                template,
+                Default::default(),
+                MultiHash::Sha3_384(series.metadata),
+                series.next_period.clone(),
+            )
+        }
+
+        /// Removes the market series `series_id` and unreserves its bond.
+        fn end_market_series(series_id: MarketSeriesId, series: &MarketSeriesInfoOf<T>) {
+            MarketSeries::<T>::remove(series_id);
+            let missing = T::AssetManager::unreserve_named(
+                &Self::reserve_id(),
+                Asset::Ztg,
+                &series.owner,
+                series.bond,
+            );
+            debug_assert!(
+                missing.is_zero(),
+                "Could not unreserve all of the amount. reserve_id: {:?}, who: {:?}, value: {:?}.",
+                &Self::reserve_id(),
+                &series.owner,
+                series.bond,
+            );
+        }
+
+        /// Returns the period which begins where `period` ends and has the same length.
+        fn next_series_period(period: &MarketPeriodOf<T>) -> MarketPeriodOf<T> {
+            match period {
+                MarketPeriod::Block(range) => {
+                    let length = range.end.saturating_sub(range.start);
+                    MarketPeriod::Block(range.end..range.end.saturating_add(length))
+                }
+                MarketPeriod::Timestamp(range) => {
+                    let length = range.end.saturating_sub(range.start);
+                    MarketPeriod::Timestamp(range.end..range.end.saturating_add(length))
+                }
+            }
+        }
+
         pub fn outcome_assets(market_id: MarketIdOf<T>, market: &MarketOf<T>) -> Vec<AssetOf<T>> {
             match market.market_type {
                 MarketType::Categorical(categories) => {
@@ -2378,6 +3098,8 @@ mod pallet {
             T::Slash::on_unbalanced(imbalance);
             <zrml_market_commons::Pallet<T>>::remove_market(market_id)?;
             MarketIdsForEdit::<T>::remove(market_id);
+            MarketIdToFallbackOracle::<T>::remove(market_id);
+            Self::clear_authority_committee(market_id, &market);
             Self::deposit_event(Event::MarketRejected(*market_id, reject_reason));
             Self::deposit_event(Event::MarketDestroyed(*market_id));
             Ok(())
@@ -2392,6 +3114,8 @@ mod pallet {
             Self::unreserve_oracle_bond(market_id)?;
             <zrml_market_commons::Pallet<T>>::remove_market(market_id)?;
             MarketIdsForEdit::<T>::remove(market_id);
+            MarketIdToFallbackOracle::<T>::remove(market_id);
+            Self::clear_authority_committee(market_id, &market);
             Self::deposit_event(Event::MarketExpired(*market_id));
             Ok(T::WeightInfo::handle_expired_advised_market())
         }
@@ -2487,6 +3211,23 @@ mod pallet {
             Ok(())
         }
 
+        fn ensure_base_asset_is_valid(base_asset: AssetOf<T>) -> DispatchResult {
+            let valid_base_asset = match base_asset {
+                Asset::Ztg => true,
+                #[cfg(feature = "parachain")]
+                Asset::ForeignAsset(fa) => {
+                    if let Some(metadata) = T::AssetRegistry::metadata(&Asset::ForeignAsset(fa)) {
+                        metadata.additional.allow_as_base_asset
+                    } else {
+                        return Err(Error::<T>::UnregisteredForeignAsset.into());
+                    }
+                }
+                _ => false,
+            };
+            ensure!(valid_base_asset, Error::<T>::InvalidBaseAsset);
+            Ok(())
+        }
+
         fn ensure_market_type_is_valid(market_type: &MarketType) -> DispatchResult {
             match market_type {
                 MarketType::Categorical(categories) => {
@@ -2542,6 +3283,8 @@ mod pallet {
                 Ok(())
             })?;
             let mut total_weight = T::DbWeight::get().reads_writes(1, 1);
+            total_weight =
+                total_weight.saturating_add(T::OnMarketClose::on_market_close(market_id));
             Self::deposit_event(Event::MarketClosed(*market_id));
             total_weight = total_weight.saturating_add(T::DbWeight::get().writes(1));
             Ok(total_weight)
@@ -2637,7 +3380,17 @@ mod pallet {
                 }
             };
 
-            T::Slash::on_unbalanced(remainder);
+            if !remainder.peek().is_zero() && T::GlobalDisputes::does_exist(market_id) {
+                // the slashed bonds reward the voters of the winning global dispute outcome
+                let amount = remainder.peek();
+                T::Currency::resolve_creating(
+                    &T::GlobalDisputes::get_reward_account(market_id),
+                    remainder,
+                );
+                T::GlobalDisputes::add_voter_rewards(market_id, amount)?;
+            } else {
+                T::Slash::on_unbalanced(remainder);
+            }
 
             let res = ResultWithWeightInfo { result: resolved_outcome, weight };
 
@@ -2768,6 +3521,10 @@ mod pallet {
                 }
                 _ => return Err(Error::<T>::InvalidMarketStatus.into()),
             };
+            let weight = Self::update_oracle_reputation(market_id, market, &resolved_outcome);
+            total_weight = total_weight.saturating_add(weight);
+            let weight = Self::clear_authority_committee(market_id, market);
+            total_weight = total_weight.saturating_add(weight);
 
             // NOTE: Currently we don't clean up outcome assets.
             // TODO(#792): Remove outcome assets for accounts! Delete "resolved" assets of `orml_tokens` with storage migration.
@@ -2785,6 +3542,44 @@ mod pallet {
             Ok(total_weight.saturating_add(Self::calculate_internal_resolve_weight(market)))
         }
 
+        /// Rewards the oracle or fallback oracle that reported `market` within its exclusive
+        /// reporting window if the report wasn't disputed, and penalizes it if the report was
+        /// overturned. The updates are weighted by the collateral held by the market. Returns the
+        /// weight consumed.
+        fn update_oracle_reputation(
+            market_id: &MarketIdOf<T>,
+            market: &MarketOf<T>,
+            resolved_outcome: &OutcomeReport,
+        ) -> Weight {
+            MarketIdToFallbackOracle::<T>::remove(market_id);
+            let weight = T::DbWeight::get().reads_writes(1, 2);
+            let Some(reporter) = TimelyOracleReports::<T>::take(market_id) else {
+                return weight;
+            };
+            let report_was_overturned =
+                market.report.as_ref().is_some_and(|report| &report.outcome != resolved_outcome);
+            let market_stake = Self::market_stake(market_id, market);
+            let hook_weight = match market.status {
+                MarketStatus::Reported => {
+                    T::OracleRegistry::on_accurate_report(&reporter, market_stake)
+                }
+                MarketStatus::Disputed if report_was_overturned => {
+                    T::OracleRegistry::on_overturned_report(&reporter, market_stake)
+                }
+                _ => Weight::zero(),
+            };
+            weight.saturating_add(T::DbWeight::get().reads(1)).saturating_add(hook_weight)
+        }
+
+        /// Removes the authority committee of `market` if the market uses the `Authorized`
+        /// dispute mechanism. Returns the weight consumed.
+        fn clear_authority_committee(market_id: &MarketIdOf<T>, market: &MarketOf<T>) -> Weight {
+            if market.dispute_mechanism != Some(MarketDisputeMechanism::Authorized) {
+                return Weight::zero();
+            }
+            <T::Authorized as zrml_authorized::AuthorizedPalletApi>::clear_committee(market_id)
+        }
+
         /// The reserve ID of the prediction-markets pallet.
         #[inline]
         pub fn reserve_id() -> [u8; 8] {
@@ -2901,21 +3696,8 @@ mod pallet {
             resolved_outcome: Option<OutcomeReport>,
             bonds: MarketBondsOf<T>,
         ) -> Result<MarketBuilder<T>, DispatchError> {
-            let valid_base_asset = match base_asset {
-                Asset::Ztg => true,
-                #[cfg(feature = "parachain")]
-                Asset::ForeignAsset(fa) => {
-                    if let Some(metadata) = T::AssetRegistry::metadata(&Asset::ForeignAsset(fa)) {
-                        metadata.additional.allow_as_base_asset
-                    } else {
-                        return Err(Error::<T>::UnregisteredForeignAsset.into());
-                    }
-                }
-                _ => false,
-            };
-
             ensure!(creator_fee <= T::MaxCreatorFee::get(), Error::<T>::FeeTooHigh);
-            ensure!(valid_base_asset, Error::<T>::InvalidBaseAsset);
+            Self::ensure_base_asset_is_valid(base_asset)?;
             let MultiHash::Sha3_384(multihash) = metadata;
             ensure!(multihash[0] == 0x15 && multihash[1] == 0x30, <Error<T>>::InvalidMultihash);
             Self::ensure_market_period_is_valid(&period)?;
@@ -2956,8 +3738,12 @@ mod pallet {
             report: ReportOf<T>,
         ) -> DispatchResultWithPostInfo {
             let sender = ensure_signed(origin.clone())?;
+            let mut is_timely_report = false;
+            let mut oracle_missed_report = false;
+            let mut missed_fallback_oracle = None;
             <zrml_market_commons::Pallet<T>>::mutate_market(&market_id, |market| {
                 let mut should_check_origin = false;
+                let mut is_fallback_window = false;
                 //NOTE: Saturating operation in following block may saturate to u32::MAX value
                 //      but that will be the case after thousands of years time. So it is fine.
                 match market.period {
@@ -2967,8 +3753,12 @@ mod pallet {
                         ensure!(grace_period_end <= report.at, Error::<T>::NotAllowedToReportYet);
                         let oracle_duration_end =
                             grace_period_end.saturating_add(market.deadlines.oracle_duration);
+                        let fallback_duration_end =
+                            oracle_duration_end.saturating_add(market.deadlines.oracle_duration);
                         if report.at <= oracle_duration_end {
                             should_check_origin = true;
+                        } else if report.at <= fallback_duration_end {
+                            is_fallback_window = true;
                         }
                     }
                     MarketPeriod::Timestamp(ref range) => {
@@ -2985,8 +3775,12 @@ mod pallet {
                             oracle_duration_in_moments.saturating_mul(MILLISECS_PER_BLOCK.into());
                         let oracle_duration_end =
                             grace_period_end.saturating_add(oracle_duration_in_ms);
+                        let fallback_duration_end =
+                            oracle_duration_end.saturating_add(oracle_duration_in_ms);
                         if now <= oracle_duration_end {
                             should_check_origin = true;
+                        } else if now <= fallback_duration_end {
+                            is_fallback_window = true;
                         }
                     }
                 }
@@ -2996,17 +3790,37 @@ mod pallet {
 
                 if should_check_origin {
                     ensure!(sender_is_oracle, Error::<T>::ReporterNotOracle);
-                } else if sender_is_outsider {
-                    let outsider_bond = T::OutsiderBond::get();
+                    is_timely_report = true;
+                } else {
+                    // The oracle missed its exclusive reporting window. If it named a fallback
+                    // oracle, the fallback oracle is given a window of the same length before
+                    // outsiders may report.
+                    oracle_missed_report = true;
+                    if let Some(fallback) = MarketIdToFallbackOracle::<T>::get(market_id) {
+                        if is_fallback_window {
+                            let sender_is_fallback_oracle = sender == fallback;
+                            ensure!(
+                                sender_is_oracle || sender_is_fallback_oracle,
+                                Error::<T>::ReporterNotFallbackOracle
+                            );
+                            is_timely_report = sender_is_fallback_oracle;
+                        } else {
+                            missed_fallback_oracle = Some(fallback);
+                        }
+                    }
 
-                    market.bonds.outsider = Some(Bond::new(sender.clone(), outsider_bond));
+                    if sender_is_outsider {
+                        let outsider_bond = T::OutsiderBond::get();
 
-                    T::AssetManager::reserve_named(
-                        &Self::reserve_id(),
-                        Asset::Ztg,
-                        &sender,
-                        outsider_bond,
-                    )?;
+                        market.bonds.outsider = Some(Bond::new(sender.clone(), outsider_bond));
+
+                        T::AssetManager::reserve_named(
+                            &Self::reserve_id(),
+                            Asset::Ztg,
+                            &sender,
+                            outsider_bond,
+                        )?;
+                    }
                 }
 
                 market.report = Some(report.clone());
@@ -3016,6 +3830,29 @@ mod pallet {
             })?;
 
             let market = <zrml_market_commons::Pallet<T>>::market(&market_id)?;
+            let mut oracle_registry_weight = Weight::zero();
+            if is_timely_report {
+                oracle_registry_weight =
+                    oracle_registry_weight.saturating_add(T::DbWeight::get().reads(1));
+                // Only registered oracles are tracked for the reputation update on resolution.
+                if T::OracleRegistry::reputation(&sender).is_some() {
+                    TimelyOracleReports::<T>::insert(market_id, &sender);
+                    oracle_registry_weight =
+                        oracle_registry_weight.saturating_add(T::DbWeight::get().writes(1));
+                }
+            }
+            if oracle_missed_report {
+                // Looking up the fallback oracle and the collateral of the market.
+                oracle_registry_weight =
+                    oracle_registry_weight.saturating_add(T::DbWeight::get().reads(2));
+                let market_stake = Self::market_stake(&market_id, &market);
+                let weight = T::OracleRegistry::on_missed_report(&market.oracle, market_stake);
+                oracle_registry_weight = oracle_registry_weight.saturating_add(weight);
+                if let Some(fallback) = missed_fallback_oracle {
+                    let weight = T::OracleRegistry::on_missed_report(&fallback, market_stake);
+                    oracle_registry_weight = oracle_registry_weight.saturating_add(weight);
+                }
+            }
             let block_after_dispute_duration =
                 report.at.saturating_add(market.deadlines.dispute_duration);
             let ids_len = MarketIdsPerReportBlock::<T>::try_mutate(
@@ -3026,7 +3863,11 @@ mod pallet {
                 },
             )?;
 
-            Ok(Some(T::WeightInfo::report_market_with_dispute_mechanism(ids_len)).into())
+            Ok(Some(
+                T::WeightInfo::report_market_with_dispute_mechanism(ids_len)
+                    .saturating_add(oracle_registry_weight),
+            )
+            .into())
         }
 
         fn report_and_resolve_market(
@@ -3104,6 +3945,18 @@ mod pallet {
         }
     }
 
+    /// Converts a market ID into the account which holds the collateral of the market.
+    pub struct MarketAccount<T>(PhantomData<T>);
+
+    impl<T> Convert<MarketIdOf<T>, T::AccountId> for MarketAccount<T>
+    where
+        T: Config,
+    {
+        fn convert(market_id: MarketIdOf<T>) -> T::AccountId {
+            Pallet::<T>::market_account(market_id)
+        }
+    }
+
     impl<T> CompleteSetOperationsApi for Pallet<T>
     where
         T: Config,
@@ -3129,6 +3982,73 @@ mod pallet {
         }
     }
 
+    impl<T> CreateMarketApi for Pallet<T>
+    where
+        T: Config,
+    {
+        type AccountId = T::AccountId;
+        type BlockNumber = BlockNumberFor<T>;
+        type MarketId = MarketIdOf<T>;
+        type Moment = MomentOf<T>;
+
+        fn create_market(
+            who: Self::AccountId,
+            base_asset: AssetOf<T>,
+            creator_fee: Perbill,
+            oracle: Self::AccountId,
+            period: MarketPeriodOf<T>,
+            deadlines: DeadlinesOf<T>,
+            metadata: MultiHash,
+            creation: MarketCreation,
+            market_type: MarketType,
+            dispute_mechanism: Option<MarketDisputeMechanism>,
+            scoring_rule: ScoringRule,
+        ) -> Result<Self::MarketId, DispatchError> {
+            let (_, market_id) = Self::do_create_market(
+                who,
+                base_asset,
+                creator_fee,
+                oracle,
+                period,
+                deadlines,
+                metadata,
+                creation,
+                market_type,
+                dispute_mechanism,
+                scoring_rule,
+            )?;
+            Ok(market_id)
+        }
+
+        fn create_market_weight() -> Weight {
+            T::WeightInfo::create_market(CacheSize::get())
+        }
+    }
+
+    impl<T> ReportMarketApi for Pallet<T>
+    where
+        T: Config,
+    {
+        type AccountId = T::AccountId;
+        type MarketId = MarketIdOf<T>;
+
+        fn report_market(
+            who: Self::AccountId,
+            market_id: Self::MarketId,
+            outcome: OutcomeReport,
+        ) -> DispatchResult {
+            Self::report(RawOrigin::Signed(who).into(), market_id, outcome)
+                .map(|_| ())
+                .map_err(|err| err.error)
+        }
+
+        fn report_market_weight() -> Weight {
+            T::WeightInfo::report_market_with_dispute_mechanism(CacheSize::get())
+                .saturating_add(Self::oracle_registry_report_max_weight())
+                .max(T::WeightInfo::report_trusted_market())
+        }
+    }
+
     impl<T> PayoutApi for Pallet<T>
     where
         T: Config,
@@ -29,6 +29,7 @@ use frame_support::{
         tokens::{PayFromAccount, UnityAssetBalanceConversion},
         Everything, NeverEnsureOrigin, OnFinalize, OnInitialize,
     },
+    weights::Weight,
 };
 use frame_system::{mocking::MockBlock, EnsureRoot, EnsureSignedBy};
 #[cfg(feature = "runtime-benchmarks")]
@@ -43,26 +44,31 @@ use sp_runtime::{
 use std::cell::RefCell;
 use zeitgeist_primitives::{
     constants::mock::{
-        AddOutcomePeriod, AggregationPeriod, AppealBond, AppealPeriod, AuthorizedPalletId,
-        BlockHashCount, BlocksPerYear, CloseEarlyBlockPeriod, CloseEarlyDisputeBond,
-        CloseEarlyProtectionBlockPeriod, CloseEarlyProtectionTimeFramePeriod,
-        CloseEarlyRequestBond, CloseEarlyTimeFramePeriod, CorrectionPeriod, CourtPalletId,
-        ExistentialDeposit, ExistentialDeposits, GdVotingPeriod, GetNativeCurrencyId,
-        GlobalDisputeLockId, GlobalDisputesPalletId, InflationPeriod, LockId, MaxAppeals,
-        MaxApprovals, MaxCategories, MaxCourtParticipants, MaxCreatorFee, MaxDelegations,
-        MaxDisputeDuration, MaxDisputes, MaxEditReasonLen, MaxGlobalDisputeVotes, MaxGracePeriod,
-        MaxLocks, MaxMarketLifetime, MaxOracleDuration, MaxOwners, MaxRejectReasonLen, MaxReserves,
-        MaxSelectedDraws, MaxYearlyInflation, MinCategories, MinDisputeDuration, MinJurorStake,
-        MinOracleDuration, MinOutcomeVoteAmount, MinimumPeriod, OutsiderBond, PmPalletId,
-        RemoveKeysLimit, RequestInterval, TreasuryPalletId, VotePeriod, VotingOutcomeFee, BASE,
-        CENT, MILLISECS_PER_BLOCK,
+        AddOutcomePeriod, AggregationPeriod, AppealBond, AppealPeriod, AuthorityAppealBond,
+        AuthorityAppealPeriod, AuthorizedPalletId, BlockHashCount, BlocksPerYear,
+        CloseEarlyBlockPeriod, CloseEarlyDisputeBond, CloseEarlyProtectionBlockPeriod,
+        CloseEarlyProtectionTimeFramePeriod, CloseEarlyRequestBond, CloseEarlyTimeFramePeriod,
+        ConvictionLockPeriod, CorrectionPeriod, CourtPalletId, ExistentialDeposit,
+        ExistentialDeposits, GdVotingPeriod, GetNativeCurrencyId, GlobalDisputeLockId,
+        GlobalDisputesPalletId, InflationPeriod, LockId, MarketSeriesBond, MarketTemplateBond,
+        MaxAppeals, MaxApprovals, MaxCategories, MaxCommitteeMembers, MaxCourtParticipants,
+        MaxCreatorFee, MaxDelegations, MaxDisputeDuration, MaxDisputes, MaxEditReasonLen,
+        MaxGlobalDisputeVotes, MaxGracePeriod, MaxLocks, MaxMarketLifetime,
+        MaxMarketSeriesRolloversPerBlock, MaxOracleDuration, MaxOwners, MaxRejectReasonLen,
+        MaxReserves, MaxSelectedDraws, MaxYearlyInflation, MinCategories, MinDisputeDuration,
+        MinJurorStake, MinOracleDuration, MinOracleStake, MinOutcomeVoteAmount, MinimumPeriod,
+        OracleRegistryPalletId, OracleUnbondingPeriod, OutsiderBond, PmPalletId, RemoveKeysLimit,
+        ReportSlashPercentage, ReputationPenalty, RequestInterval, StakePerReputationPoint,
+        TreasuryPalletId, VotePeriod, VoterRewardPercentage, VotingOutcomeFee, BASE, CENT,
+        MILLISECS_PER_BLOCK,
     },
-    traits::DeployPoolApi,
+    traits::{DeployPoolApi, OnMarketClose},
     types::{
         AccountIdTest, Amount, Asset, Balance, BasicCurrencyAdapter, BlockNumber, BlockTest,
         CurrencyId, Hash, MarketId, Moment,
     },
 };
+use zrml_global_disputes::types::VotingWeightMode;
 #[cfg(feature = "parachain")]
 use {
     orml_traits::asset_registry::AssetProcessor, parity_scale_codec::Encode,
@@ -85,6 +91,20 @@ pub const RESOLVE_ORIGIN: AccountIdTest = 75;
 
 pub const INITIAL_BALANCE: u128 = 1_000 * BASE;
 
+pub struct OnMarketCloseNoop;
+
+impl OnMarketClose for OnMarketCloseNoop {
+    type MarketId = MarketId;
+
+    fn on_market_close(_market_id: &Self::MarketId) -> Weight {
+        Weight::zero()
+    }
+
+    fn on_market_close_max_weight() -> Weight {
+        Weight::zero()
+    }
+}
+
 #[allow(unused)]
 pub struct DeployPoolMock;
 
@@ -182,6 +202,7 @@ construct_runtime!(
         Court: zrml_court,
         AssetManager: orml_currencies,
         MarketCommons: zrml_market_commons,
+        OracleRegistry: zrml_oracle_registry,
         PredictionMarkets: prediction_markets,
         RandomnessCollectiveFlip: pallet_insecure_randomness_collective_flip,
         GlobalDisputes: zrml_global_disputes,
@@ -220,10 +241,15 @@ impl crate::Config for Runtime {
     type MaxGracePeriod = MaxGracePeriod;
     type MaxOracleDuration = MaxOracleDuration;
     type MaxMarketLifetime = MaxMarketLifetime;
+    type MaxMarketSeriesRolloversPerBlock = MaxMarketSeriesRolloversPerBlock;
     type MinCategories = MinCategories;
     type MaxEditReasonLen = MaxEditReasonLen;
+    type MarketTemplateBond = MarketTemplateBond;
+    type MarketSeriesBond = MarketSeriesBond;
     type MaxRejectReasonLen = MaxRejectReasonLen;
     type OracleBond = OracleBond;
+    type OnMarketClose = OnMarketCloseNoop;
+    type OracleRegistry = OracleRegistry;
     type OutsiderBond = OutsiderBond;
     type PalletId = PmPalletId;
     type CloseEarlyBlockPeriod = CloseEarlyBlockPeriod;
@@ -357,6 +383,8 @@ ord_parameter_types! {
 }
 
 impl zrml_authorized::Config for Runtime {
+    type AppealBond = AuthorityAppealBond;
+    type AppealPeriod = AuthorityAppealPeriod;
     type AuthorizedDisputeResolutionOrigin =
         EnsureSignedBy<AuthorizedDisputeResolutionUser, AccountIdTest>;
     type CorrectionPeriod = CorrectionPeriod;
@@ -364,6 +392,7 @@ impl zrml_authorized::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type DisputeResolution = prediction_markets::Pallet<Runtime>;
     type MarketCommons = MarketCommons;
+    type MaxCommitteeMembers = MaxCommitteeMembers;
     type PalletId = AuthorizedPalletId;
     type WeightInfo = zrml_authorized::weights::WeightInfo<Runtime>;
 }
@@ -401,20 +430,42 @@ impl zrml_market_commons::Config for Runtime {
     type Timestamp = Timestamp;
 }
 
+parameter_types! {
+    pub const DefaultVotingWeightMode: VotingWeightMode = VotingWeightMode::TokenWeighted;
+}
+
+impl zrml_oracle_registry::Config for Runtime {
+    type Currency = Balances;
+    type MinOracleStake = MinOracleStake;
+    type PalletId = OracleRegistryPalletId;
+    type ReportSlashPercentage = ReportSlashPercentage;
+    type ReputationPenalty = ReputationPenalty;
+    type RuntimeEvent = RuntimeEvent;
+    type Slash = Treasury;
+    type StakePerReputationPoint = StakePerReputationPoint;
+    type UnbondingPeriod = OracleUnbondingPeriod;
+    type WeightInfo = zrml_oracle_registry::weights::WeightInfo<Runtime>;
+}
+
 impl zrml_global_disputes::Config for Runtime {
     type AddOutcomePeriod = AddOutcomePeriod;
+    type ConvictionLockPeriod = ConvictionLockPeriod;
     type RuntimeEvent = RuntimeEvent;
     type DisputeResolution = prediction_markets::Pallet<Runtime>;
     type MarketCommons = MarketCommons;
     type Currency = Balances;
+    type DefaultVotingWeightMode = DefaultVotingWeightMode;
     type GlobalDisputeLockId = GlobalDisputeLockId;
     type GlobalDisputesPalletId = GlobalDisputesPalletId;
+    type IdentityVerification = Everything;
     type MaxGlobalDisputeVotes = MaxGlobalDisputeVotes;
     type MaxOwners = MaxOwners;
     type MinOutcomeVoteAmount = MinOutcomeVoteAmount;
     type RemoveKeysLimit = RemoveKeysLimit;
     type GdVotingPeriod = GdVotingPeriod;
+    type VoterRewardPercentage = VoterRewardPercentage;
     type VotingOutcomeFee = VotingOutcomeFee;
+    type VotingWeightModeOrigin = EnsureRoot<AccountIdTest>;
     type WeightInfo = zrml_global_disputes::weights::WeightInfo<Runtime>;
 }
 
@@ -697,3 +697,38 @@ fn create_scalar_market_deposits_the_correct_event() {
         System::assert_last_event(Event::MarketCreated(0, market_account, market).into());
     });
 }
+
+#[test]
+fn create_market_allows_authority_committee_only_in_the_creation_block() {
+    ExtBuilder::default().build().execute_with(|| {
+        simple_create_categorical_market(
+            Asset::Ztg,
+            MarketCreation::Permissionless,
+            1..2,
+            ScoringRule::AmmCdaHybrid,
+        );
+        assert_ok!(Authorized::set_authority_committee(
+            RuntimeOrigin::signed(ALICE),
+            0,
+            vec![BOB, CHARLIE],
+            1
+        ));
+
+        simple_create_categorical_market(
+            Asset::Ztg,
+            MarketCreation::Permissionless,
+            1..2,
+            ScoringRule::AmmCdaHybrid,
+        );
+        run_to_block(System::block_number() + 1);
+        assert_noop!(
+            Authorized::set_authority_committee(
+                RuntimeOrigin::signed(ALICE),
+                1,
+                vec![BOB, CHARLIE],
+                1
+            ),
+            zrml_authorized::Error::<Runtime>::CommitteeSetupClosed
+        );
+    });
+}
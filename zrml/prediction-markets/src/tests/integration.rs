@@ -136,6 +136,137 @@ fn it_appeals_a_court_market_to_global_dispute() {
     });
 }
 
+#[test]
+fn it_appeals_an_authorized_market_to_global_dispute() {
+    ExtBuilder::default().build().execute_with(|| {
+        let end = 2;
+        assert_ok!(PredictionMarkets::create_market(
+            RuntimeOrigin::signed(ALICE),
+            Asset::Ztg,
+            Perbill::zero(),
+            BOB,
+            MarketPeriod::Block(0..end),
+            get_deadlines(),
+            gen_metadata(2),
+            MarketCreation::Permissionless,
+            MarketType::Categorical(<Runtime as Config>::MinCategories::get()),
+            Some(MarketDisputeMechanism::Authorized),
+            ScoringRule::AmmCdaHybrid,
+        ));
+        let market_id = 0;
+        let market = MarketCommons::market(&market_id).unwrap();
+
+        run_to_block(end + market.deadlines.grace_period + 1);
+        assert_ok!(PredictionMarkets::report(
+            RuntimeOrigin::signed(BOB),
+            market_id,
+            OutcomeReport::Categorical(0)
+        ));
+        assert_ok!(PredictionMarkets::dispute(RuntimeOrigin::signed(CHARLIE), market_id));
+        assert_ok!(Authorized::authorize_market_outcome(
+            RuntimeOrigin::signed(AuthorizedDisputeResolutionUser::get()),
+            market_id,
+            OutcomeReport::Categorical(1)
+        ));
+        let resolve_at = zrml_authorized::AuthorizedOutcomeReports::<Runtime>::get(market_id)
+            .unwrap()
+            .resolve_at;
+
+        let free_dave_before = Balances::free_balance(DAVE);
+        assert_ok!(Authorized::appeal_report(RuntimeOrigin::signed(DAVE), market_id));
+        let bond = <Runtime as zrml_authorized::Config>::AppealBond::get();
+        assert_eq!(Balances::free_balance(DAVE), free_dave_before - bond);
+        assert!(!MarketIdsPerDisputeBlock::<Runtime>::get(resolve_at).contains(&market_id));
+        let appeal_resolve_at =
+            zrml_authorized::AuthorityAppeals::<Runtime>::get(market_id).unwrap().resolve_at;
+        assert!(MarketIdsPerDisputeBlock::<Runtime>::get(appeal_resolve_at).contains(&market_id));
+
+        assert_ok!(PredictionMarkets::start_global_dispute(RuntimeOrigin::signed(DAVE), market_id));
+        let now = <frame_system::Pallet<Runtime>>::block_number();
+        assert!(!MarketIdsPerDisputeBlock::<Runtime>::get(appeal_resolve_at).contains(&market_id));
+
+        // the appealed report is owned by the authority, not by the appellant
+        let owners = vec![Authorized::authority_account()];
+        let possession: PossessionOf<Runtime> =
+            Possession::Shared { owners: frame_support::BoundedVec::try_from(owners).unwrap() };
+        let outcome_info = OutcomeInfo { outcome_sum: Zero::zero(), possession };
+        assert_eq!(
+            Outcomes::<Runtime>::get(market_id, OutcomeReport::Categorical(1)).unwrap(),
+            outcome_info
+        );
+
+        let add_outcome_end = now + GlobalDisputes::get_add_outcome_period();
+        run_to_block(add_outcome_end + 1);
+        assert_ok!(GlobalDisputes::vote_on_outcome(
+            RuntimeOrigin::signed(EVE),
+            market_id,
+            OutcomeReport::Categorical(0),
+            10 * BASE
+        ));
+
+        let vote_end = add_outcome_end + GlobalDisputes::get_vote_period();
+        run_to_block(vote_end);
+
+        let market = MarketCommons::market(&market_id).unwrap();
+        assert_eq!(market.status, MarketStatus::Resolved);
+        assert_eq!(market.resolved_outcome, Some(OutcomeReport::Categorical(0)));
+        // the appeal was justified, so the bond is returned
+        assert_eq!(Balances::free_balance(DAVE), free_dave_before);
+        assert!(!zrml_authorized::AuthorityAppeals::<Runtime>::contains_key(market_id));
+        assert!(!zrml_authorized::AuthorizedOutcomeReports::<Runtime>::contains_key(market_id));
+    });
+}
+
+#[test]
+fn it_resolves_an_appealed_authorized_market_if_no_global_dispute_is_started() {
+    ExtBuilder::default().build().execute_with(|| {
+        let end = 2;
+        assert_ok!(PredictionMarkets::create_market(
+            RuntimeOrigin::signed(ALICE),
+            Asset::Ztg,
+            Perbill::zero(),
+            BOB,
+            MarketPeriod::Block(0..end),
+            get_deadlines(),
+            gen_metadata(2),
+            MarketCreation::Permissionless,
+            MarketType::Categorical(<Runtime as Config>::MinCategories::get()),
+            Some(MarketDisputeMechanism::Authorized),
+            ScoringRule::AmmCdaHybrid,
+        ));
+        let market_id = 0;
+        let market = MarketCommons::market(&market_id).unwrap();
+
+        run_to_block(end + market.deadlines.grace_period + 1);
+        assert_ok!(PredictionMarkets::report(
+            RuntimeOrigin::signed(BOB),
+            market_id,
+            OutcomeReport::Categorical(0)
+        ));
+        assert_ok!(PredictionMarkets::dispute(RuntimeOrigin::signed(CHARLIE), market_id));
+        assert_ok!(Authorized::authorize_market_outcome(
+            RuntimeOrigin::signed(AuthorizedDisputeResolutionUser::get()),
+            market_id,
+            OutcomeReport::Categorical(1)
+        ));
+
+        let free_dave_before = Balances::free_balance(DAVE);
+        assert_ok!(Authorized::appeal_report(RuntimeOrigin::signed(DAVE), market_id));
+        let appeal_resolve_at =
+            zrml_authorized::AuthorityAppeals::<Runtime>::get(market_id).unwrap().resolve_at;
+
+        run_to_block(appeal_resolve_at);
+
+        let market = MarketCommons::market(&market_id).unwrap();
+        assert_eq!(market.status, MarketStatus::Resolved);
+        assert_eq!(market.resolved_outcome, Some(OutcomeReport::Categorical(1)));
+        // the appeal was not pursued, so the bond is slashed
+        let bond = <Runtime as zrml_authorized::Config>::AppealBond::get();
+        assert_eq!(Balances::free_balance(DAVE), free_dave_before - bond);
+        assert!(!zrml_authorized::AuthorityAppeals::<Runtime>::contains_key(market_id));
+    });
+}
+
 #[test]
 fn the_entire_market_lifecycle_works_with_timestamps() {
     ExtBuilder::default().build().execute_with(|| {
@@ -0,0 +1,343 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::{
+    MarketIdToSeriesId, MarketPeriodOf, MarketSeries, MarketSeriesRolloversPerCloseBlock,
+    PendingMarketSeriesRollovers,
+};
+use frame_support::{pallet_prelude::Weight, traits::Hooks};
+use zeitgeist_primitives::constants::MILLISECS_PER_BLOCK;
+
+fn create_template() {
+    assert_ok!(PredictionMarkets::create_market_template(
+        RuntimeOrigin::signed(ALICE),
+        Asset::Ztg,
+        Perbill::zero(),
+        BOB,
+        get_deadlines(),
+        MarketCreation::Permissionless,
+        MarketType::Categorical(2),
+        Some(MarketDisputeMechanism::SimpleDisputes),
+        ScoringRule::AmmCdaHybrid,
+        None,
+    ));
+}
+
+fn create_series(period: MarketPeriodOf<Runtime>, remaining_markets: Option<u32>) {
+    assert_ok!(PredictionMarkets::create_market_series(
+        RuntimeOrigin::signed(ALICE),
+        0,
+        gen_metadata(2),
+        period,
+        remaining_markets,
+    ));
+}
+
+fn process_rollovers() {
+    PredictionMarkets::on_idle(System::block_number(), Weight::MAX);
+}
+
+#[test]
+fn create_market_series_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_template();
+        let reserved_before = Balances::reserved_balance(ALICE);
+        create_series(MarketPeriod::Block(0..5), Some(3));
+        let market = MarketCommons::market(&0).unwrap();
+        assert_eq!(market.creator, ALICE);
+        assert_eq!(market.oracle, BOB);
+        assert_eq!(market.period, MarketPeriod::Block(0..5));
+        let series = MarketSeries::<Runtime>::get(0).unwrap();
+        assert_eq!(series.owner, ALICE);
+        assert_eq!(series.latest_market_id, 0);
+        assert_eq!(series.next_period, MarketPeriod::Block(5..10));
+        assert_eq!(series.remaining_markets, Some(3));
+        assert_eq!(MarketIdToSeriesId::<Runtime>::get(0), Some(0));
+        assert_eq!(MarketSeriesRolloversPerCloseBlock::<Runtime>::get(5), 1);
+        let series_bond = <Runtime as Config>::MarketSeriesBond::get();
+        assert_eq!(series.bond, series_bond);
+        let market_bonds =
+            <Runtime as Config>::ValidityBond::get() + <Runtime as Config>::OracleBond::get();
+        assert_eq!(Balances::reserved_balance(ALICE), reserved_before + series_bond + market_bonds);
+        System::assert_last_event(Event::MarketSeriesCreated(0, series).into());
+    });
+}
+
+#[test]
+fn create_market_series_fails_on_unknown_template() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            PredictionMarkets::create_market_series(
+                RuntimeOrigin::signed(ALICE),
+                0,
+                gen_metadata(2),
+                MarketPeriod::Block(0..5),
+                None,
+            ),
+            Error::<Runtime>::MarketTemplateNotFound
+        );
+    });
+}
+
+#[test]
+fn create_market_series_fails_on_invalid_period() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_template();
+        run_to_block(5);
+        assert_noop!(
+            PredictionMarkets::create_market_series(
+                RuntimeOrigin::signed(ALICE),
+                0,
+                gen_metadata(2),
+                MarketPeriod::Block(0..5),
+                None,
+            ),
+            Error::<Runtime>::InvalidMarketPeriod
+        );
+    });
+}
+
+#[test]
+fn create_market_series_fails_if_too_many_rollovers_are_due() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_template();
+        let max_rollovers = <Runtime as Config>::MaxMarketSeriesRolloversPerBlock::get();
+        for _ in 0..max_rollovers {
+            create_series(MarketPeriod::Block(0..5), None);
+        }
+        assert_noop!(
+            PredictionMarkets::create_market_series(
+                RuntimeOrigin::signed(ALICE),
+                0,
+                gen_metadata(2),
+                MarketPeriod::Block(0..5),
+                None,
+            ),
+            Error::<Runtime>::TooManyMarketSeriesRollovers
+        );
+        create_series(MarketPeriod::Block(0..6), None);
+    });
+}
+
+#[test]
+fn market_series_rolls_over_on_close_block() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_template();
+        create_series(MarketPeriod::Block(0..5), None);
+        run_to_block(5);
+        assert_eq!(MarketCommons::market(&0).unwrap().status, MarketStatus::Closed);
+        // The rollover is queued when the market closes and executed in `on_idle`.
+        assert!(PendingMarketSeriesRollovers::<Runtime>::contains_key(0));
+        assert!(MarketCommons::market(&1).is_err());
+        assert_eq!(MarketSeriesRolloversPerCloseBlock::<Runtime>::get(5), 0);
+        process_rollovers();
+        assert!(!PendingMarketSeriesRollovers::<Runtime>::contains_key(0));
+        assert_eq!(MarketSeriesRolloversPerCloseBlock::<Runtime>::get(10), 1);
+        let market = MarketCommons::market(&1).unwrap();
+        assert_eq!(market.creator, ALICE);
+        assert_eq!(market.period, MarketPeriod::Block(5..10));
+        assert_eq!(market.status, MarketStatus::Active);
+        let series = MarketSeries::<Runtime>::get(0).unwrap();
+        assert_eq!(series.latest_market_id, 1);
+        assert_eq!(series.next_period, MarketPeriod::Block(10..15));
+        assert_eq!(MarketIdToSeriesId::<Runtime>::get(0), None);
+        assert_eq!(MarketIdToSeriesId::<Runtime>::get(1), Some(0));
+        System::assert_has_event(Event::MarketSeriesRolledOver(0, 1).into());
+
+        run_to_block(10);
+        process_rollovers();
+        assert_eq!(MarketCommons::market(&1).unwrap().status, MarketStatus::Closed);
+        assert_eq!(MarketCommons::market(&2).unwrap().period, MarketPeriod::Block(10..15));
+    });
+}
+
+#[test]
+fn market_series_rolls_over_on_close_time_frame() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_template();
+        let end = (5 * MILLISECS_PER_BLOCK) as u64;
+        create_series(MarketPeriod::Timestamp(0..end), None);
+        set_timestamp_for_on_initialize(end);
+        run_to_block(2);
+        process_rollovers();
+        assert_eq!(MarketCommons::market(&0).unwrap().status, MarketStatus::Closed);
+        let market = MarketCommons::market(&1).unwrap();
+        assert_eq!(market.period, MarketPeriod::Timestamp(end..2 * end));
+        assert_eq!(market.status, MarketStatus::Active);
+        System::assert_has_event(Event::MarketSeriesRolledOver(0, 1).into());
+    });
+}
+
+#[test]
+fn market_series_ends_after_remaining_markets_were_created() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_template();
+        let reserved_before = Balances::reserved_balance(ALICE);
+        create_series(MarketPeriod::Block(0..5), Some(1));
+        run_to_block(5);
+        process_rollovers();
+        assert_eq!(MarketSeries::<Runtime>::get(0).unwrap().remaining_markets, Some(0));
+        run_to_block(10);
+        process_rollovers();
+        assert_eq!(MarketCommons::market(&1).unwrap().status, MarketStatus::Closed);
+        assert!(MarketCommons::market(&2).is_err());
+        assert!(!MarketSeries::<Runtime>::contains_key(0));
+        assert_eq!(MarketIdToSeriesId::<Runtime>::get(1), None);
+        System::assert_has_event(Event::MarketSeriesEnded(0).into());
+        // Only the bonds of the two markets remain reserved.
+        let market_bonds =
+            <Runtime as Config>::ValidityBond::get() + <Runtime as Config>::OracleBond::get();
+        assert_eq!(Balances::reserved_balance(ALICE), reserved_before + 2 * market_bonds);
+    });
+}
+
+#[test]
+fn market_series_ends_if_rollover_fails() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_template();
+        create_series(MarketPeriod::Block(0..5), None);
+        assert_ok!(PredictionMarkets::remove_market_template(RuntimeOrigin::signed(ALICE), 0));
+        let reserved_before = Balances::reserved_balance(ALICE);
+        run_to_block(5);
+        process_rollovers();
+        assert_eq!(MarketCommons::market(&0).unwrap().status, MarketStatus::Closed);
+        assert!(MarketCommons::market(&1).is_err());
+        assert!(!MarketSeries::<Runtime>::contains_key(0));
+        System::assert_has_event(
+            Event::MarketSeriesRolloverFailed(0, Error::<Runtime>::MarketTemplateNotFound.into())
+                .into(),
+        );
+        assert_eq!(
+            Balances::reserved_balance(ALICE),
+            reserved_before - <Runtime as Config>::MarketSeriesBond::get()
+        );
+    });
+}
+
+#[test]
+fn market_series_rolls_over_on_admin_close() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_template();
+        create_series(MarketPeriod::Block(0..5), None);
+        run_to_block(2);
+        assert_ok!(PredictionMarkets::admin_move_market_to_closed(
+            RuntimeOrigin::signed(CloseOrigin::get()),
+            0
+        ));
+        process_rollovers();
+        let market = MarketCommons::market(&1).unwrap();
+        assert_eq!(market.period, MarketPeriod::Block(5..10));
+        assert_eq!(MarketSeries::<Runtime>::get(0).unwrap().latest_market_id, 1);
+    });
+}
+
+#[test]
+fn cancel_market_series_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_template();
+        create_series(MarketPeriod::Block(0..5), None);
+        let reserved_before = Balances::reserved_balance(ALICE);
+        assert_ok!(PredictionMarkets::cancel_market_series(RuntimeOrigin::signed(ALICE), 0));
+        assert!(!MarketSeries::<Runtime>::contains_key(0));
+        assert_eq!(MarketIdToSeriesId::<Runtime>::get(0), None);
+        assert_eq!(MarketSeriesRolloversPerCloseBlock::<Runtime>::get(5), 0);
+        assert_eq!(
+            Balances::reserved_balance(ALICE),
+            reserved_before - <Runtime as Config>::MarketSeriesBond::get()
+        );
+        System::assert_last_event(Event::MarketSeriesCancelled(0).into());
+
+        run_to_block(5);
+        process_rollovers();
+        assert_eq!(MarketCommons::market(&0).unwrap().status, MarketStatus::Closed);
+        assert!(MarketCommons::market(&1).is_err());
+    });
+}
+
+#[test]
+fn cancel_market_series_removes_queued_rollover() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_template();
+        create_series(MarketPeriod::Block(0..5), None);
+        run_to_block(5);
+        assert!(PendingMarketSeriesRollovers::<Runtime>::contains_key(0));
+        assert_ok!(PredictionMarkets::cancel_market_series(RuntimeOrigin::signed(ALICE), 0));
+        assert!(!PendingMarketSeriesRollovers::<Runtime>::contains_key(0));
+        process_rollovers();
+        assert!(MarketCommons::market(&1).is_err());
+    });
+}
+
+#[test]
+fn on_idle_executes_at_most_max_market_series_rollovers() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_template();
+        let max_rollovers = <Runtime as Config>::MaxMarketSeriesRolloversPerBlock::get();
+        for _ in 0..max_rollovers {
+            create_series(MarketPeriod::Block(0..5), None);
+        }
+        create_series(MarketPeriod::Block(0..6), None);
+        run_to_block(6);
+        let series_count = max_rollovers + 1;
+        assert_eq!(PendingMarketSeriesRollovers::<Runtime>::iter().count() as u32, series_count);
+
+        process_rollovers();
+        assert_eq!(PendingMarketSeriesRollovers::<Runtime>::iter().count(), 1);
+
+        process_rollovers();
+        assert_eq!(PendingMarketSeriesRollovers::<Runtime>::iter().count(), 0);
+        // Every series has created its second market.
+        assert_eq!(MarketIdToSeriesId::<Runtime>::iter().count() as u32, series_count);
+        assert!(MarketIdToSeriesId::<Runtime>::iter()
+            .all(|(market_id, _)| market_id >= MarketId::from(series_count)));
+    });
+}
+
+#[test]
+fn on_idle_does_not_roll_over_without_enough_weight() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_template();
+        create_series(MarketPeriod::Block(0..5), None);
+        run_to_block(5);
+        assert_eq!(PredictionMarkets::on_idle(5, Weight::zero()), Weight::zero());
+        assert!(PendingMarketSeriesRollovers::<Runtime>::contains_key(0));
+        assert!(MarketCommons::market(&1).is_err());
+    });
+}
+
+#[test]
+fn cancel_market_series_fails_if_caller_is_not_owner() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_template();
+        create_series(MarketPeriod::Block(0..5), None);
+        assert_noop!(
+            PredictionMarkets::cancel_market_series(RuntimeOrigin::signed(BOB), 0),
+            Error::<Runtime>::CallerNotSeriesOwner
+        );
+    });
+}
+
+#[test]
+fn cancel_market_series_fails_on_unknown_series() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            PredictionMarkets::cancel_market_series(RuntimeOrigin::signed(ALICE), 0),
+            Error::<Runtime>::MarketSeriesNotFound
+        );
+    });
+}
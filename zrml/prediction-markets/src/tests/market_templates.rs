@@ -0,0 +1,297 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::{
+    types::{MarketTemplateOverrides, TemplatePoolParams},
+    MarketTemplates, NextMarketTemplateId, TemplatePoolParamsOf,
+};
+
+fn create_template(pool: Option<TemplatePoolParamsOf<Runtime>>) {
+    assert_ok!(PredictionMarkets::create_market_template(
+        RuntimeOrigin::signed(ALICE),
+        Asset::Ztg,
+        Perbill::zero(),
+        BOB,
+        get_deadlines(),
+        MarketCreation::Permissionless,
+        MarketType::Categorical(2),
+        Some(MarketDisputeMechanism::SimpleDisputes),
+        ScoringRule::AmmCdaHybrid,
+        pool,
+    ));
+}
+
+fn pool_params() -> TemplatePoolParamsOf<Runtime> {
+    TemplatePoolParams {
+        amount: 10 * BASE,
+        spot_prices: vec![50 * CENT, 50 * CENT].try_into().unwrap(),
+        swap_fee: CENT,
+    }
+}
+
+#[test]
+fn create_market_template_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let reserved_before = Balances::reserved_balance(ALICE);
+        create_template(Some(pool_params()));
+        let template = MarketTemplates::<Runtime>::get(0).unwrap();
+        assert_eq!(template.owner, ALICE);
+        assert_eq!(template.oracle, BOB);
+        assert_eq!(template.pool, Some(pool_params()));
+        assert_eq!(NextMarketTemplateId::<Runtime>::get(), 1);
+        let bond = <Runtime as Config>::MarketTemplateBond::get();
+        assert_eq!(template.bond, bond);
+        assert_eq!(Balances::reserved_balance(ALICE), reserved_before + bond);
+        System::assert_last_event(Event::MarketTemplateCreated(0, template).into());
+    });
+}
+
+#[test]
+fn create_market_template_fails_on_fee_too_high() {
+    ExtBuilder::default().build().execute_with(|| {
+        let creator_fee = <Runtime as Config>::MaxCreatorFee::get() + Perbill::from_parts(1);
+        assert_noop!(
+            PredictionMarkets::create_market_template(
+                RuntimeOrigin::signed(ALICE),
+                Asset::Ztg,
+                creator_fee,
+                BOB,
+                get_deadlines(),
+                MarketCreation::Permissionless,
+                MarketType::Categorical(2),
+                Some(MarketDisputeMechanism::SimpleDisputes),
+                ScoringRule::AmmCdaHybrid,
+                None,
+            ),
+            Error::<Runtime>::FeeTooHigh
+        );
+    });
+}
+
+#[test]
+fn create_market_template_fails_on_invalid_market_type() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            PredictionMarkets::create_market_template(
+                RuntimeOrigin::signed(ALICE),
+                Asset::Ztg,
+                Perbill::zero(),
+                BOB,
+                get_deadlines(),
+                MarketCreation::Permissionless,
+                MarketType::Categorical(1),
+                Some(MarketDisputeMechanism::SimpleDisputes),
+                ScoringRule::AmmCdaHybrid,
+                None,
+            ),
+            Error::<Runtime>::NotEnoughCategories
+        );
+    });
+}
+
+#[test]
+fn create_market_template_fails_on_spot_prices_length_mismatch() {
+    ExtBuilder::default().build().execute_with(|| {
+        let mut pool = pool_params();
+        pool.spot_prices = vec![BASE].try_into().unwrap();
+        assert_noop!(
+            PredictionMarkets::create_market_template(
+                RuntimeOrigin::signed(ALICE),
+                Asset::Ztg,
+                Perbill::zero(),
+                BOB,
+                get_deadlines(),
+                MarketCreation::Permissionless,
+                MarketType::Categorical(2),
+                Some(MarketDisputeMechanism::SimpleDisputes),
+                ScoringRule::AmmCdaHybrid,
+                Some(pool),
+            ),
+            Error::<Runtime>::InvalidTemplatePoolParams
+        );
+    });
+}
+
+#[test]
+fn create_market_template_fails_on_pool_for_advised_market() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            PredictionMarkets::create_market_template(
+                RuntimeOrigin::signed(ALICE),
+                Asset::Ztg,
+                Perbill::zero(),
+                BOB,
+                get_deadlines(),
+                MarketCreation::Advised,
+                MarketType::Categorical(2),
+                Some(MarketDisputeMechanism::SimpleDisputes),
+                ScoringRule::AmmCdaHybrid,
+                Some(pool_params()),
+            ),
+            Error::<Runtime>::InvalidTemplatePoolParams
+        );
+    });
+}
+
+#[test]
+fn remove_market_template_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let reserved_before = Balances::reserved_balance(ALICE);
+        create_template(None);
+        assert_ok!(PredictionMarkets::remove_market_template(RuntimeOrigin::signed(ALICE), 0));
+        assert!(!MarketTemplates::<Runtime>::contains_key(0));
+        assert_eq!(Balances::reserved_balance(ALICE), reserved_before);
+        System::assert_last_event(Event::MarketTemplateRemoved(0).into());
+    });
+}
+
+#[test]
+fn remove_market_template_fails_if_caller_is_not_owner() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_template(None);
+        assert_noop!(
+            PredictionMarkets::remove_market_template(RuntimeOrigin::signed(BOB), 0),
+            Error::<Runtime>::CallerNotTemplateOwner
+        );
+    });
+}
+
+#[test]
+fn remove_market_template_fails_on_unknown_template() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            PredictionMarkets::remove_market_template(RuntimeOrigin::signed(ALICE), 0),
+            Error::<Runtime>::MarketTemplateNotFound
+        );
+    });
+}
+
+#[test]
+fn create_market_from_template_works_without_pool() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_template(None);
+        let period = MarketPeriod::Block(0..100);
+        assert_ok!(PredictionMarkets::create_market_from_template(
+            RuntimeOrigin::signed(CHARLIE),
+            0,
+            Default::default(),
+            gen_metadata(2),
+            period.clone(),
+        ));
+        let market = MarketCommons::market(&0).unwrap();
+        assert_eq!(market.creator, CHARLIE);
+        assert_eq!(market.oracle, BOB);
+        assert_eq!(market.period, period);
+        assert_eq!(market.market_type, MarketType::Categorical(2));
+        assert_eq!(market.scoring_rule, ScoringRule::AmmCdaHybrid);
+        assert_eq!(market.status, MarketStatus::Active);
+        assert!(DEPLOY_POOL_CALL_DATA.with(|value| value.borrow().is_empty()));
+        System::assert_last_event(Event::MarketCreatedFromTemplate(0, 0).into());
+    });
+}
+
+#[test]
+fn create_market_from_template_applies_overrides() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_template(None);
+        let deadlines = Deadlines {
+            grace_period: 2,
+            oracle_duration: <Runtime as Config>::MinOracleDuration::get() + 1,
+            dispute_duration: <Runtime as Config>::MinDisputeDuration::get() + 1,
+        };
+        let overrides = MarketTemplateOverrides {
+            oracle: Some(EVE),
+            creator_fee: Some(Perbill::from_parts(1)),
+            deadlines: Some(deadlines),
+        };
+        assert_ok!(PredictionMarkets::create_market_from_template(
+            RuntimeOrigin::signed(CHARLIE),
+            0,
+            overrides,
+            gen_metadata(2),
+            MarketPeriod::Block(0..100),
+        ));
+        let market = MarketCommons::market(&0).unwrap();
+        assert_eq!(market.oracle, EVE);
+        assert_eq!(market.creator_fee, Perbill::from_parts(1));
+        assert_eq!(market.deadlines, deadlines);
+    });
+}
+
+#[test]
+fn create_market_from_template_deploys_pool() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_template(Some(pool_params()));
+        assert_ok!(PredictionMarkets::create_market_from_template(
+            RuntimeOrigin::signed(CHARLIE),
+            0,
+            Default::default(),
+            gen_metadata(2),
+            MarketPeriod::Block(0..100),
+        ));
+        let pool = pool_params();
+        assert_eq!(
+            AssetManager::free_balance(Asset::CategoricalOutcome(0, 0), &CHARLIE),
+            pool.amount
+        );
+        assert!(DeployPoolMock::called_once_with(
+            CHARLIE,
+            0,
+            pool.amount,
+            pool.spot_prices.into_inner(),
+            pool.swap_fee
+        ));
+    });
+}
+
+#[test]
+fn create_market_from_template_fails_on_unknown_template() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            PredictionMarkets::create_market_from_template(
+                RuntimeOrigin::signed(CHARLIE),
+                0,
+                Default::default(),
+                gen_metadata(2),
+                MarketPeriod::Block(0..100),
+            ),
+            Error::<Runtime>::MarketTemplateNotFound
+        );
+    });
+}
+
+#[test]
+fn create_market_from_template_validates_overrides() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_template(None);
+        let overrides = MarketTemplateOverrides {
+            creator_fee: Some(<Runtime as Config>::MaxCreatorFee::get() + Perbill::from_parts(1)),
+            ..Default::default()
+        };
+        assert_noop!(
+            PredictionMarkets::create_market_from_template(
+                RuntimeOrigin::signed(CHARLIE),
+                0,
+                overrides,
+                gen_metadata(2),
+                MarketPeriod::Block(0..100),
+            ),
+            Error::<Runtime>::FeeTooHigh
+        );
+    });
+}
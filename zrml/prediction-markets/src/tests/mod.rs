@@ -30,9 +30,12 @@ mod dispute_early_close;
 mod edit_market;
 mod integration;
 mod manually_close_market;
+mod market_series;
+mod market_templates;
 mod on_initialize;
 mod on_market_close;
 mod on_resolution;
+mod oracle_registry;
 mod payout_vector;
 mod redeem_shares;
 mod reject_early_close;
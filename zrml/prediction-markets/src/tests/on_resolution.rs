@@ -81,6 +81,65 @@ fn it_correctly_resolves_a_market_that_was_reported_on() {
     });
 }
 
+#[test]
+fn on_resolution_removes_authority_committee_of_undisputed_market() {
+    ExtBuilder::default().build().execute_with(|| {
+        let end = 2;
+        simple_create_categorical_market(
+            Asset::Ztg,
+            MarketCreation::Permissionless,
+            0..end,
+            ScoringRule::AmmCdaHybrid,
+        );
+        assert!(zrml_authorized::CommitteeSetupBlocks::<Runtime>::contains_key(0));
+        assert_ok!(Authorized::set_authority_committee(
+            RuntimeOrigin::signed(ALICE),
+            0,
+            vec![BOB, CHARLIE],
+            1
+        ));
+        assert!(zrml_authorized::AuthorityCommittees::<Runtime>::contains_key(0));
+
+        let market = MarketCommons::market(&0).unwrap();
+        run_to_block(end + market.deadlines.grace_period + 1);
+        assert_ok!(PredictionMarkets::report(
+            RuntimeOrigin::signed(BOB),
+            0,
+            OutcomeReport::Categorical(1)
+        ));
+        run_blocks(market.deadlines.dispute_duration);
+
+        assert_eq!(MarketCommons::market(&0).unwrap().status, MarketStatus::Resolved);
+        assert!(!zrml_authorized::CommitteeSetupBlocks::<Runtime>::contains_key(0));
+        assert!(!zrml_authorized::AuthorityCommittees::<Runtime>::contains_key(0));
+    });
+}
+
+#[test]
+fn on_resolution_removes_committee_setup_block_of_undisputed_market() {
+    ExtBuilder::default().build().execute_with(|| {
+        let end = 2;
+        simple_create_categorical_market(
+            Asset::Ztg,
+            MarketCreation::Permissionless,
+            0..end,
+            ScoringRule::AmmCdaHybrid,
+        );
+
+        let market = MarketCommons::market(&0).unwrap();
+        run_to_block(end + market.deadlines.grace_period + 1);
+        assert_ok!(PredictionMarkets::report(
+            RuntimeOrigin::signed(BOB),
+            0,
+            OutcomeReport::Categorical(1)
+        ));
+        run_blocks(market.deadlines.dispute_duration);
+
+        assert_eq!(MarketCommons::market(&0).unwrap().status, MarketStatus::Resolved);
+        assert!(!zrml_authorized::CommitteeSetupBlocks::<Runtime>::contains_key(0));
+    });
+}
+
 #[test]
 fn on_resolution_correctly_reserves_and_unreserves_bonds_for_approved_advised_market_on_oracle_report(
 ) {
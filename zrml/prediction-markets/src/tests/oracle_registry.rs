@@ -0,0 +1,392 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::{MarketIdToFallbackOracle, TimelyOracleReports};
+use zeitgeist_primitives::{
+    constants::{
+        mock::{MinOracleStake, ReportSlashPercentage, ReputationPenalty, StakePerReputationPoint},
+        MILLISECS_PER_BLOCK,
+    },
+    traits::OracleRegistryApi,
+};
+use zrml_oracle_registry::Oracles;
+
+const END: u64 = 100;
+
+fn register_oracle(oracle: AccountIdOf<Runtime>) {
+    assert_ok!(OracleRegistry::register_oracle(
+        RuntimeOrigin::signed(oracle),
+        MinOracleStake::get()
+    ));
+}
+
+fn register_oracle_with_fallback(oracle: AccountIdOf<Runtime>, fallback: AccountIdOf<Runtime>) {
+    register_oracle(oracle);
+    register_oracle(fallback);
+    assert_ok!(OracleRegistry::set_fallback_oracle(RuntimeOrigin::signed(oracle), Some(fallback)));
+}
+
+fn fund_market(market_id: MarketId, amount: u128) {
+    assert_ok!(PredictionMarkets::buy_complete_set(
+        RuntimeOrigin::signed(ALICE),
+        market_id,
+        amount
+    ));
+}
+
+fn oracle_duration_end() -> u64 {
+    END + get_deadlines().grace_period + get_deadlines().oracle_duration
+}
+
+fn fallback_duration_end() -> u64 {
+    oracle_duration_end() + get_deadlines().oracle_duration
+}
+
+fn resolve_undisputed(market_id: MarketId) {
+    let report_at = MarketCommons::market(&market_id).unwrap().report.unwrap().at;
+    run_to_block(report_at + get_deadlines().dispute_duration + 1);
+    assert_eq!(MarketCommons::market(&market_id).unwrap().status, MarketStatus::Resolved);
+}
+
+#[test]
+fn timely_undisputed_report_increases_reputation_of_oracle() {
+    ExtBuilder::default().build().execute_with(|| {
+        register_oracle(BOB);
+        simple_create_categorical_market(
+            Asset::Ztg,
+            MarketCreation::Permissionless,
+            0..END,
+            ScoringRule::AmmCdaHybrid,
+        );
+        fund_market(0, 2 * StakePerReputationPoint::get());
+        run_to_block(oracle_duration_end());
+        assert_ok!(PredictionMarkets::report(
+            RuntimeOrigin::signed(BOB),
+            0,
+            OutcomeReport::Categorical(1)
+        ));
+        assert_eq!(TimelyOracleReports::<Runtime>::get(0), Some(BOB));
+
+        resolve_undisputed(0);
+
+        let info = Oracles::<Runtime>::get(BOB).unwrap();
+        assert_eq!(info.reputation, 2);
+        assert_eq!(info.accurate_reports, 1);
+        assert_eq!(TimelyOracleReports::<Runtime>::get(0), None);
+    });
+}
+
+#[test]
+fn overturned_report_decreases_reputation_of_oracle() {
+    ExtBuilder::default().build().execute_with(|| {
+        register_oracle(BOB);
+        for _ in 0..5 {
+            OracleRegistry::on_accurate_report(&BOB, StakePerReputationPoint::get());
+        }
+        simple_create_categorical_market(
+            Asset::Ztg,
+            MarketCreation::Permissionless,
+            0..END,
+            ScoringRule::AmmCdaHybrid,
+        );
+        run_to_block(oracle_duration_end());
+        assert_ok!(PredictionMarkets::report(
+            RuntimeOrigin::signed(BOB),
+            0,
+            OutcomeReport::Categorical(0)
+        ));
+        assert_ok!(PredictionMarkets::dispute(RuntimeOrigin::signed(CHARLIE), 0));
+        assert_ok!(Authorized::authorize_market_outcome(
+            RuntimeOrigin::signed(AuthorizedDisputeResolutionUser::get()),
+            0,
+            OutcomeReport::Categorical(1)
+        ));
+        run_blocks(<Runtime as zrml_authorized::Config>::CorrectionPeriod::get());
+        assert_eq!(MarketCommons::market(&0).unwrap().status, MarketStatus::Resolved);
+
+        let info = Oracles::<Runtime>::get(BOB).unwrap();
+        assert_eq!(info.reputation, 5 - ReputationPenalty::get());
+        assert_eq!(info.overturned_reports, 1);
+    });
+}
+
+#[test]
+fn disputed_but_upheld_report_leaves_reputation_of_oracle_unchanged() {
+    ExtBuilder::default().build().execute_with(|| {
+        register_oracle(BOB);
+        simple_create_categorical_market(
+            Asset::Ztg,
+            MarketCreation::Permissionless,
+            0..END,
+            ScoringRule::AmmCdaHybrid,
+        );
+        run_to_block(oracle_duration_end());
+        assert_ok!(PredictionMarkets::report(
+            RuntimeOrigin::signed(BOB),
+            0,
+            OutcomeReport::Categorical(0)
+        ));
+        assert_ok!(PredictionMarkets::dispute(RuntimeOrigin::signed(CHARLIE), 0));
+        assert_ok!(Authorized::authorize_market_outcome(
+            RuntimeOrigin::signed(AuthorizedDisputeResolutionUser::get()),
+            0,
+            OutcomeReport::Categorical(0)
+        ));
+        run_blocks(<Runtime as zrml_authorized::Config>::CorrectionPeriod::get());
+        assert_eq!(MarketCommons::market(&0).unwrap().status, MarketStatus::Resolved);
+
+        let info = Oracles::<Runtime>::get(BOB).unwrap();
+        assert_eq!(info.reputation, 0);
+        assert_eq!(info.accurate_reports, 0);
+        assert_eq!(info.overturned_reports, 0);
+        assert_eq!(TimelyOracleReports::<Runtime>::get(0), None);
+    });
+}
+
+#[test]
+fn outsider_report_counts_as_missed_report_if_oracle_has_no_fallback() {
+    ExtBuilder::default().build().execute_with(|| {
+        register_oracle(BOB);
+        simple_create_categorical_market(
+            Asset::Ztg,
+            MarketCreation::Permissionless,
+            0..END,
+            ScoringRule::AmmCdaHybrid,
+        );
+        run_to_block(oracle_duration_end() + 1);
+        assert_ok!(PredictionMarkets::report(
+            RuntimeOrigin::signed(CHARLIE),
+            0,
+            OutcomeReport::Categorical(1)
+        ));
+        assert_eq!(TimelyOracleReports::<Runtime>::get(0), None);
+        assert_eq!(Oracles::<Runtime>::get(BOB).unwrap().missed_reports, 1);
+
+        resolve_undisputed(0);
+
+        let info = Oracles::<Runtime>::get(BOB).unwrap();
+        assert_eq!(info.reputation, 0);
+        assert_eq!(info.accurate_reports, 0);
+    });
+}
+
+#[test]
+fn only_oracle_and_fallback_oracle_may_report_during_fallback_window() {
+    ExtBuilder::default().build().execute_with(|| {
+        register_oracle_with_fallback(BOB, DAVE);
+        simple_create_categorical_market(
+            Asset::Ztg,
+            MarketCreation::Permissionless,
+            0..END,
+            ScoringRule::AmmCdaHybrid,
+        );
+        run_to_block(oracle_duration_end() + 1);
+        assert_noop!(
+            PredictionMarkets::report(
+                RuntimeOrigin::signed(CHARLIE),
+                0,
+                OutcomeReport::Categorical(1)
+            ),
+            Error::<Runtime>::ReporterNotFallbackOracle
+        );
+        run_to_block(fallback_duration_end());
+        assert_noop!(
+            PredictionMarkets::report(
+                RuntimeOrigin::signed(CHARLIE),
+                0,
+                OutcomeReport::Categorical(1)
+            ),
+            Error::<Runtime>::ReporterNotFallbackOracle
+        );
+        assert_ok!(PredictionMarkets::report(
+            RuntimeOrigin::signed(DAVE),
+            0,
+            OutcomeReport::Categorical(1)
+        ));
+        assert_eq!(TimelyOracleReports::<Runtime>::get(0), Some(DAVE));
+        assert_eq!(Oracles::<Runtime>::get(BOB).unwrap().missed_reports, 1);
+
+        resolve_undisputed(0);
+
+        let oracle_info = Oracles::<Runtime>::get(BOB).unwrap();
+        assert_eq!(oracle_info.accurate_reports, 0);
+        let fallback_info = Oracles::<Runtime>::get(DAVE).unwrap();
+        assert_eq!(fallback_info.accurate_reports, 1);
+    });
+}
+
+#[test]
+fn late_report_of_oracle_during_fallback_window_counts_as_missed_report() {
+    ExtBuilder::default().build().execute_with(|| {
+        register_oracle_with_fallback(BOB, DAVE);
+        simple_create_categorical_market(
+            Asset::Ztg,
+            MarketCreation::Permissionless,
+            0..END,
+            ScoringRule::AmmCdaHybrid,
+        );
+        run_to_block(oracle_duration_end() + 1);
+        assert_ok!(PredictionMarkets::report(
+            RuntimeOrigin::signed(BOB),
+            0,
+            OutcomeReport::Categorical(1)
+        ));
+        assert_eq!(TimelyOracleReports::<Runtime>::get(0), None);
+        assert_eq!(Oracles::<Runtime>::get(BOB).unwrap().missed_reports, 1);
+        assert_eq!(Oracles::<Runtime>::get(DAVE).unwrap().missed_reports, 0);
+    });
+}
+
+#[test]
+fn outsider_may_report_after_fallback_window_and_both_oracles_missed_their_report() {
+    ExtBuilder::default().build().execute_with(|| {
+        register_oracle_with_fallback(BOB, DAVE);
+        simple_create_categorical_market(
+            Asset::Ztg,
+            MarketCreation::Permissionless,
+            0..END,
+            ScoringRule::AmmCdaHybrid,
+        );
+        run_to_block(fallback_duration_end() + 1);
+        assert_ok!(PredictionMarkets::report(
+            RuntimeOrigin::signed(CHARLIE),
+            0,
+            OutcomeReport::Categorical(1)
+        ));
+        let market = MarketCommons::market(&0).unwrap();
+        assert_eq!(market.bonds.outsider.unwrap().who, CHARLIE);
+        assert_eq!(TimelyOracleReports::<Runtime>::get(0), None);
+        assert_eq!(Oracles::<Runtime>::get(BOB).unwrap().missed_reports, 1);
+        assert_eq!(Oracles::<Runtime>::get(DAVE).unwrap().missed_reports, 1);
+    });
+}
+
+#[test]
+fn fallback_window_is_skipped_if_fallback_oracle_unregistered() {
+    ExtBuilder::default().build().execute_with(|| {
+        register_oracle_with_fallback(BOB, DAVE);
+        assert_ok!(OracleRegistry::unregister_oracle(RuntimeOrigin::signed(DAVE)));
+        simple_create_categorical_market(
+            Asset::Ztg,
+            MarketCreation::Permissionless,
+            0..END,
+            ScoringRule::AmmCdaHybrid,
+        );
+        run_to_block(oracle_duration_end() + 1);
+        assert_ok!(PredictionMarkets::report(
+            RuntimeOrigin::signed(CHARLIE),
+            0,
+            OutcomeReport::Categorical(1)
+        ));
+        assert_eq!(Oracles::<Runtime>::get(BOB).unwrap().missed_reports, 1);
+    });
+}
+
+#[test]
+fn fallback_oracle_may_report_during_fallback_window_of_timestamp_market() {
+    ExtBuilder::default().build().execute_with(|| {
+        register_oracle_with_fallback(BOB, DAVE);
+        let end = 100_000_000;
+        assert_ok!(PredictionMarkets::create_market(
+            RuntimeOrigin::signed(ALICE),
+            Asset::Ztg,
+            Perbill::zero(),
+            BOB,
+            MarketPeriod::Timestamp(0..end),
+            get_deadlines(),
+            gen_metadata(2),
+            MarketCreation::Permissionless,
+            MarketType::Categorical(2),
+            Some(MarketDisputeMechanism::Authorized),
+            ScoringRule::AmmCdaHybrid,
+        ));
+        let deadlines = get_deadlines();
+        let oracle_duration_end =
+            end + (deadlines.grace_period + deadlines.oracle_duration) * MILLISECS_PER_BLOCK as u64;
+        Timestamp::set_timestamp(oracle_duration_end + 1);
+        assert_noop!(
+            PredictionMarkets::report(
+                RuntimeOrigin::signed(CHARLIE),
+                0,
+                OutcomeReport::Categorical(1)
+            ),
+            Error::<Runtime>::ReporterNotFallbackOracle
+        );
+        assert_ok!(PredictionMarkets::report(
+            RuntimeOrigin::signed(DAVE),
+            0,
+            OutcomeReport::Categorical(1)
+        ));
+        assert_eq!(TimelyOracleReports::<Runtime>::get(0), Some(DAVE));
+        assert_eq!(Oracles::<Runtime>::get(BOB).unwrap().missed_reports, 1);
+    });
+}
+
+#[test]
+fn fallback_oracle_is_stored_on_market_creation() {
+    ExtBuilder::default().build().execute_with(|| {
+        register_oracle_with_fallback(BOB, DAVE);
+        register_oracle(EVE);
+        simple_create_categorical_market(
+            Asset::Ztg,
+            MarketCreation::Permissionless,
+            0..END,
+            ScoringRule::AmmCdaHybrid,
+        );
+        assert_eq!(MarketIdToFallbackOracle::<Runtime>::get(0), Some(DAVE));
+        // Changing the fallback oracle doesn't affect markets which were already created.
+        assert_ok!(OracleRegistry::set_fallback_oracle(RuntimeOrigin::signed(BOB), Some(EVE)));
+        run_to_block(oracle_duration_end() + 1);
+        assert_noop!(
+            PredictionMarkets::report(RuntimeOrigin::signed(EVE), 0, OutcomeReport::Categorical(1)),
+            Error::<Runtime>::ReporterNotFallbackOracle
+        );
+        assert_ok!(PredictionMarkets::report(
+            RuntimeOrigin::signed(DAVE),
+            0,
+            OutcomeReport::Categorical(1)
+        ));
+        assert_eq!(TimelyOracleReports::<Runtime>::get(0), Some(DAVE));
+
+        resolve_undisputed(0);
+
+        assert_eq!(MarketIdToFallbackOracle::<Runtime>::get(0), None);
+    });
+}
+
+#[test]
+fn missed_report_slashes_stake_of_oracle() {
+    ExtBuilder::default().build().execute_with(|| {
+        register_oracle(BOB);
+        simple_create_categorical_market(
+            Asset::Ztg,
+            MarketCreation::Permissionless,
+            0..END,
+            ScoringRule::AmmCdaHybrid,
+        );
+        run_to_block(oracle_duration_end() + 1);
+        assert_ok!(PredictionMarkets::report(
+            RuntimeOrigin::signed(CHARLIE),
+            0,
+            OutcomeReport::Categorical(1)
+        ));
+        let stake = MinOracleStake::get();
+        let slashed = ReportSlashPercentage::get().mul_floor(stake);
+        assert_eq!(Oracles::<Runtime>::get(BOB).unwrap().stake, stake - slashed);
+    });
+}
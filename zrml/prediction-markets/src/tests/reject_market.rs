@@ -53,6 +53,33 @@ fn it_allows_the_advisory_origin_to_reject_markets() {
     });
 }
 
+#[test]
+fn reject_market_removes_authority_committee() {
+    ExtBuilder::default().build().execute_with(|| {
+        simple_create_categorical_market(
+            Asset::Ztg,
+            MarketCreation::Advised,
+            4..6,
+            ScoringRule::AmmCdaHybrid,
+        );
+        assert_ok!(Authorized::set_authority_committee(
+            RuntimeOrigin::signed(ALICE),
+            0,
+            vec![BOB, CHARLIE],
+            1
+        ));
+
+        assert_ok!(PredictionMarkets::reject_market(
+            RuntimeOrigin::signed(RejectOrigin::get()),
+            0,
+            vec![0]
+        ));
+
+        assert!(!zrml_authorized::CommitteeSetupBlocks::<Runtime>::contains_key(0));
+        assert!(!zrml_authorized::AuthorityCommittees::<Runtime>::contains_key(0));
+    });
+}
+
 #[test]
 fn reject_errors_if_reject_reason_is_too_long() {
     ExtBuilder::default().build().execute_with(|| {
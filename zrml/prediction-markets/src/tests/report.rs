@@ -18,7 +18,9 @@
 
 use super::*;
 
-use zeitgeist_primitives::{constants::MILLISECS_PER_BLOCK, types::OutcomeReport};
+use zeitgeist_primitives::{
+    constants::MILLISECS_PER_BLOCK, traits::ReportMarketApi, types::OutcomeReport,
+};
 
 // TODO(#1239) MarketDoesNotExist
 // TODO(#1239) MarketAlreadyReported
@@ -60,6 +62,59 @@ fn it_allows_to_report_the_outcome_of_a_market() {
     });
 }
 
+#[test]
+fn report_market_api_reports_on_behalf_of_oracle() {
+    ExtBuilder::default().build().execute_with(|| {
+        let end = 100;
+        simple_create_categorical_market(
+            Asset::Ztg,
+            MarketCreation::Permissionless,
+            0..end,
+            ScoringRule::AmmCdaHybrid,
+        );
+
+        let market = MarketCommons::market(&0).unwrap();
+        let grace_period = end + market.deadlines.grace_period;
+        run_to_block(grace_period + 1);
+
+        assert_ok!(<PredictionMarkets as ReportMarketApi>::report_market(
+            BOB,
+            0,
+            OutcomeReport::Categorical(1)
+        ));
+
+        let market_after = MarketCommons::market(&0).unwrap();
+        let report = market_after.report.unwrap();
+        assert_eq!(market_after.status, MarketStatus::Reported);
+        assert_eq!(report.outcome, OutcomeReport::Categorical(1));
+        assert_eq!(report.by, BOB);
+    });
+}
+
+#[test]
+fn report_market_api_fails_if_report_fails() {
+    ExtBuilder::default().build().execute_with(|| {
+        let end = 100;
+        simple_create_categorical_market(
+            Asset::Ztg,
+            MarketCreation::Permissionless,
+            0..end,
+            ScoringRule::AmmCdaHybrid,
+        );
+
+        run_to_block(end);
+
+        assert_noop!(
+            <PredictionMarkets as ReportMarketApi>::report_market(
+                BOB,
+                0,
+                OutcomeReport::Categorical(1)
+            ),
+            Error::<Runtime>::NotAllowedToReportYet
+        );
+    });
+}
+
 #[test]
 fn report_fails_before_grace_period_is_over() {
     ExtBuilder::default().build().execute_with(|| {
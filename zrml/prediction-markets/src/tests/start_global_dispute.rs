@@ -25,10 +25,9 @@ use zeitgeist_primitives::types::OutcomeReport;
 // TODO(#1239) InvalidMarketStatus
 // TODO(#1239) GlobalDisputeExistsAlready
 // TODO(#1239) MarketIsNotReported
-// TODO(#1239) MarketDisputeMechanismNotFailed
 
 #[test]
-fn start_global_dispute_fails_on_wrong_mdm() {
+fn start_global_dispute_fails_if_authority_report_is_not_appealed() {
     ExtBuilder::default().build().execute_with(|| {
         let end = 2;
         assert_ok!(PredictionMarkets::create_market(
@@ -65,7 +64,7 @@ fn start_global_dispute_fails_on_wrong_mdm() {
 
         assert_noop!(
             PredictionMarkets::start_global_dispute(RuntimeOrigin::signed(CHARLIE), market_id),
-            Error::<Runtime>::InvalidDisputeMechanism
+            Error::<Runtime>::MarketDisputeMechanismNotFailed
         );
     });
 }
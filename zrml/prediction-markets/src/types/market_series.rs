@@ -0,0 +1,47 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::types::MarketTemplateId;
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+/// The identifier of a market series.
+pub type MarketSeriesId = u32;
+
+/// A series of markets which are created from the same template, one after another. The next
+/// market of the series is created when the latest market closes.
+#[derive(Clone, Decode, Encode, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct MarketSeriesInfo<AccountId, Balance, MarketId, Period> {
+    /// The account which created the series and may cancel it. The creator of all markets of
+    /// the series.
+    pub owner: AccountId,
+    /// The amount of ZTG reserved from the owner while the series exists.
+    pub bond: Balance,
+    /// The template which the markets of the series are created from.
+    pub template_id: MarketTemplateId,
+    /// The Sha3_384 multihash of the metadata shared by all markets of the series.
+    pub metadata: [u8; 50],
+    /// The id of the latest market of the series.
+    pub latest_market_id: MarketId,
+    /// The period of the next market of the series. Begins where the period of the latest
+    /// market ends and has the same length.
+    pub next_period: Period,
+    /// The number of markets which are still to be created, or `None` if the series continues
+    /// until it's cancelled.
+    pub remaining_markets: Option<u32>,
+}
@@ -0,0 +1,71 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::{Perbill, RuntimeDebug};
+use zeitgeist_primitives::types::{
+    Asset, Deadlines, MarketCreation, MarketDisputeMechanism, MarketType, ScoringRule,
+};
+
+/// The identifier of a market template.
+pub type MarketTemplateId = u32;
+
+/// Default creation parameters for markets. Markets created from a template only specify their
+/// metadata, their period and optional overrides.
+#[derive(Clone, Decode, Encode, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct MarketTemplate<AccountId, Balance, BlockNumber, MarketId, SpotPrices> {
+    /// The account which created the template and may remove it.
+    pub owner: AccountId,
+    /// The amount of ZTG reserved from the owner while the template exists.
+    pub bond: Balance,
+    pub base_asset: Asset<MarketId>,
+    pub creator_fee: Perbill,
+    pub oracle: AccountId,
+    pub deadlines: Deadlines<BlockNumber>,
+    pub creation: MarketCreation,
+    pub market_type: MarketType,
+    pub dispute_mechanism: Option<MarketDisputeMechanism>,
+    pub scoring_rule: ScoringRule,
+    /// If set, a pool with these parameters is deployed for every market created from the
+    /// template, as in `create_market_and_deploy_pool`.
+    pub pool: Option<TemplatePoolParams<Balance, SpotPrices>>,
+}
+
+/// The parameters of the pool deployed for markets created from a template.
+#[derive(Clone, Decode, Encode, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct TemplatePoolParams<Balance, SpotPrices> {
+    /// The amount of complete sets bought and deposited into the pool.
+    pub amount: Balance,
+    /// The initial spot prices of the outcomes.
+    pub spot_prices: SpotPrices,
+    pub swap_fee: Balance,
+}
+
+/// The parameters of a template which may be overridden when creating a market from it.
+#[derive(Clone, Decode, Encode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct MarketTemplateOverrides<AccountId, BlockNumber> {
+    pub oracle: Option<AccountId>,
+    pub creator_fee: Option<Perbill>,
+    pub deadlines: Option<Deadlines<BlockNumber>>,
+}
+
+impl<AccountId, BlockNumber> Default for MarketTemplateOverrides<AccountId, BlockNumber> {
+    fn default() -> Self {
+        Self { oracle: None, creator_fee: None, deadlines: None }
+    }
+}
@@ -16,5 +16,9 @@
 // along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
 
 mod combinatorial_tokens_benchmark_helper;
+mod market_series;
+mod market_template;
 
 pub use combinatorial_tokens_benchmark_helper::PredictionMarketsCombinatorialTokensBenchmarkHelper;
+pub use market_series::*;
+pub use market_template::*;
@@ -86,6 +86,10 @@ pub trait WeightInfoZeitgeist {
     fn close_trusted_market(c: u32) -> Weight;
     fn create_market_and_deploy_pool(m: u32, n: u32) -> Weight;
     fn manually_close_market(o: u32) -> Weight;
+    fn create_market_template() -> Weight;
+    fn remove_market_template() -> Weight;
+    fn create_market_series() -> Weight;
+    fn cancel_market_series() -> Weight;
 }
 
 /// Weight functions for zrml_prediction_markets (automatically generated)
@@ -257,17 +261,20 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
     /// Proof: `PredictionMarkets::MarketIdsPerCloseTimeFrame` (`max_values`: None, `max_size`: Some(1050), added: 3525, mode: `MaxEncodedLen`)
     /// Storage: `MarketCommons::Markets` (r:0 w:1)
     /// Proof: `MarketCommons::Markets` (`max_values`: None, `max_size`: Some(708), added: 3183, mode: `MaxEncodedLen`)
+    /// Storage: `OracleRegistry::Oracles` (r:2 w:0)
+    /// Proof: `OracleRegistry::Oracles` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+    /// Storage: `OracleRegistry::UnbondingOracles` (r:1 w:0)
+    /// Proof: `OracleRegistry::UnbondingOracles` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+    /// Storage: `PredictionMarkets::MarketIdToFallbackOracle` (r:0 w:1)
+    /// Proof: `PredictionMarkets::MarketIdToFallbackOracle` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
     /// The range of component `m` is `[0, 63]`.
     fn create_market(m: u32) -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `241 + m * (16 ±0)`
-        //  Estimated: `4714`
-        // Minimum execution time: 64_522 nanoseconds.
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_prediction_markets` to replace it.
         Weight::from_parts(71_093_452, 4714)
-            // Standard Error: 6_009
             .saturating_add(Weight::from_parts(27_945, 0).saturating_mul(m.into()))
-            .saturating_add(T::DbWeight::get().reads(4))
-            .saturating_add(T::DbWeight::get().writes(4))
+            .saturating_add(T::DbWeight::get().reads(7))
+            .saturating_add(T::DbWeight::get().writes(5))
     }
     /// Storage: `PredictionMarkets::MarketIdsForEdit` (r:1 w:1)
     /// Proof: `PredictionMarkets::MarketIdsForEdit` (`max_values`: None, `max_size`: Some(1050), added: 3525, mode: `MaxEncodedLen`)
@@ -277,17 +284,26 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
     /// Proof: `PredictionMarkets::MarketIdsPerCloseTimeFrame` (`max_values`: None, `max_size`: Some(1050), added: 3525, mode: `MaxEncodedLen`)
     /// Storage: `Timestamp::Now` (r:1 w:0)
     /// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+    /// Storage: `OracleRegistry::Oracles` (r:2 w:0)
+    /// Proof: `OracleRegistry::Oracles` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+    /// Storage: `OracleRegistry::UnbondingOracles` (r:1 w:0)
+    /// Proof: `OracleRegistry::UnbondingOracles` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+    /// Storage: `PredictionMarkets::MarketIdToFallbackOracle` (r:0 w:1)
+    /// Proof: `PredictionMarkets::MarketIdToFallbackOracle` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+    /// Storage: `Authorized::CommitteeSetupBlocks` (r:0 w:1)
+    /// Proof: `Authorized::CommitteeSetupBlocks` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+    /// Storage: `Authorized::AuthorityCommittees` (r:0 w:1)
+    /// Proof: `Authorized::AuthorityCommittees` (`max_values`: None, `max_size`: Some(552), added: 3027, mode: `MaxEncodedLen`)
+    /// Storage: `Authorized::CommitteeReports` (r:1 w:0)
+    /// Proof: `Authorized::CommitteeReports` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
     /// The range of component `m` is `[0, 63]`.
     fn edit_market(m: u32) -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `720 + m * (16 ±0)`
-        //  Estimated: `4515`
-        // Minimum execution time: 60_942 nanoseconds.
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_prediction_markets` to replace it.
         Weight::from_parts(63_451_400, 4515)
-            // Standard Error: 3_137
             .saturating_add(Weight::from_parts(42_627, 0).saturating_mul(m.into()))
-            .saturating_add(T::DbWeight::get().reads(4))
-            .saturating_add(T::DbWeight::get().writes(3))
+            .saturating_add(T::DbWeight::get().reads(8))
+            .saturating_add(T::DbWeight::get().writes(6))
     }
     /// Storage: `MarketCommons::Markets` (r:1 w:0)
     /// Proof: `MarketCommons::Markets` (`max_values`: None, `max_size`: Some(708), added: 3183, mode: `MaxEncodedLen`)
@@ -341,14 +357,20 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
     /// Proof: `Balances::Reserves` (`max_values`: None, `max_size`: Some(1249), added: 3724, mode: `MaxEncodedLen`)
     /// Storage: `PredictionMarkets::MarketIdsForEdit` (r:0 w:1)
     /// Proof: `PredictionMarkets::MarketIdsForEdit` (`max_values`: None, `max_size`: Some(1050), added: 3525, mode: `MaxEncodedLen`)
+    /// Storage: `PredictionMarkets::MarketIdToFallbackOracle` (r:0 w:1)
+    /// Proof: `PredictionMarkets::MarketIdToFallbackOracle` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+    /// Storage: `Authorized::CommitteeSetupBlocks` (r:0 w:1)
+    /// Proof: `Authorized::CommitteeSetupBlocks` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+    /// Storage: `Authorized::AuthorityCommittees` (r:0 w:1)
+    /// Proof: `Authorized::AuthorityCommittees` (`max_values`: None, `max_size`: Some(552), added: 3027, mode: `MaxEncodedLen`)
+    /// Storage: `Authorized::CommitteeReports` (r:1 w:0)
+    /// Proof: `Authorized::CommitteeReports` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
     fn handle_expired_advised_market() -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `523`
-        //  Estimated: `4714`
-        // Minimum execution time: 71_532 nanoseconds.
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_prediction_markets` to replace it.
         Weight::from_parts(74_212_000, 4714)
-            .saturating_add(T::DbWeight::get().reads(2))
-            .saturating_add(T::DbWeight::get().writes(3))
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(6))
     }
     /// Storage: `MarketCommons::Markets` (r:1 w:1)
     /// Proof: `MarketCommons::Markets` (`max_values`: None, `max_size`: Some(708), added: 3183, mode: `MaxEncodedLen`)
@@ -473,20 +495,24 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
     /// Proof: `Balances::Reserves` (`max_values`: None, `max_size`: Some(1249), added: 3724, mode: `MaxEncodedLen`)
     /// Storage: `PredictionMarkets::MarketIdsForEdit` (r:0 w:1)
     /// Proof: `PredictionMarkets::MarketIdsForEdit` (`max_values`: None, `max_size`: Some(1050), added: 3525, mode: `MaxEncodedLen`)
+    /// Storage: `PredictionMarkets::MarketIdToFallbackOracle` (r:0 w:1)
+    /// Proof: `PredictionMarkets::MarketIdToFallbackOracle` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+    /// Storage: `Authorized::CommitteeSetupBlocks` (r:0 w:1)
+    /// Proof: `Authorized::CommitteeSetupBlocks` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+    /// Storage: `Authorized::AuthorityCommittees` (r:0 w:1)
+    /// Proof: `Authorized::AuthorityCommittees` (`max_values`: None, `max_size`: Some(552), added: 3027, mode: `MaxEncodedLen`)
+    /// Storage: `Authorized::CommitteeReports` (r:1 w:0)
+    /// Proof: `Authorized::CommitteeReports` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
     /// The range of component `c` is `[0, 63]`.
     /// The range of component `r` is `[0, 1024]`.
     fn reject_market(c: u32, r: u32) -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `595 + c * (16 ±0)`
-        //  Estimated: `4714`
-        // Minimum execution time: 96_542 nanoseconds.
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_prediction_markets` to replace it.
         Weight::from_parts(99_064_122, 4714)
-            // Standard Error: 4_172
             .saturating_add(Weight::from_parts(39_364, 0).saturating_mul(c.into()))
-            // Standard Error: 258
             .saturating_add(Weight::from_parts(501, 0).saturating_mul(r.into()))
-            .saturating_add(T::DbWeight::get().reads(3))
-            .saturating_add(T::DbWeight::get().writes(4))
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(7))
     }
     /// Storage: `MarketCommons::Markets` (r:1 w:1)
     /// Proof: `MarketCommons::Markets` (`max_values`: None, `max_size`: Some(708), added: 3183, mode: `MaxEncodedLen`)
@@ -754,21 +780,23 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
     /// Proof: `NeoSwaps::Pools` (`max_values`: None, `max_size`: Some(152829), added: 155304, mode: `MaxEncodedLen`)
     /// Storage: `MarketCommons::Markets` (r:0 w:1)
     /// Proof: `MarketCommons::Markets` (`max_values`: None, `max_size`: Some(708), added: 3183, mode: `MaxEncodedLen`)
+    /// Storage: `OracleRegistry::Oracles` (r:2 w:0)
+    /// Proof: `OracleRegistry::Oracles` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+    /// Storage: `OracleRegistry::UnbondingOracles` (r:1 w:0)
+    /// Proof: `OracleRegistry::UnbondingOracles` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+    /// Storage: `PredictionMarkets::MarketIdToFallbackOracle` (r:0 w:1)
+    /// Proof: `PredictionMarkets::MarketIdToFallbackOracle` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
     /// The range of component `m` is `[0, 63]`.
     /// The range of component `n` is `[2, 64]`.
     fn create_market_and_deploy_pool(m: u32, n: u32) -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `411 + m * (16 ±0)`
-        //  Estimated: `6204 + n * (5224 ±0)`
-        // Minimum execution time: 315_778 nanoseconds.
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_prediction_markets` to replace it.
         Weight::from_parts(206_047_958, 6204)
-            // Standard Error: 155_743
             .saturating_add(Weight::from_parts(442_844, 0).saturating_mul(m.into()))
-            // Standard Error: 158_076
             .saturating_add(Weight::from_parts(50_123_972, 0).saturating_mul(n.into()))
-            .saturating_add(T::DbWeight::get().reads(8))
+            .saturating_add(T::DbWeight::get().reads(11))
             .saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
-            .saturating_add(T::DbWeight::get().writes(9))
+            .saturating_add(T::DbWeight::get().writes(10))
             .saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
             .saturating_add(Weight::from_parts(0, 5224).saturating_mul(n.into()))
     }
@@ -790,4 +818,70 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
             .saturating_add(T::DbWeight::get().reads(3))
             .saturating_add(T::DbWeight::get().writes(2))
     }
+    /// Storage: `Balances::Reserves` (r:1 w:1)
+    /// Proof: `Balances::Reserves` (`max_values`: None, `max_size`: Some(1249), added: 3724, mode: `MaxEncodedLen`)
+    /// Storage: `PredictionMarkets::NextMarketTemplateId` (r:1 w:1)
+    /// Proof: `PredictionMarkets::NextMarketTemplateId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `PredictionMarkets::MarketTemplates` (r:0 w:1)
+    /// Proof: `PredictionMarkets::MarketTemplates` (`max_values`: None, `max_size`: Some(1208), added: 3683, mode: `MaxEncodedLen`)
+    fn create_market_template() -> Weight {
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_prediction_markets` to replace it.
+        Weight::from_parts(49_871_000, 4714)
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+    /// Storage: `PredictionMarkets::MarketTemplates` (r:1 w:1)
+    /// Proof: `PredictionMarkets::MarketTemplates` (`max_values`: None, `max_size`: Some(1208), added: 3683, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Reserves` (r:1 w:1)
+    /// Proof: `Balances::Reserves` (`max_values`: None, `max_size`: Some(1249), added: 3724, mode: `MaxEncodedLen`)
+    fn remove_market_template() -> Weight {
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_prediction_markets` to replace it.
+        Weight::from_parts(45_012_000, 4714)
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+    /// Storage: `PredictionMarkets::MarketTemplates` (r:1 w:0)
+    /// Proof: `PredictionMarkets::MarketTemplates` (`max_values`: None, `max_size`: Some(1208), added: 3683, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Reserves` (r:1 w:1)
+    /// Proof: `Balances::Reserves` (`max_values`: None, `max_size`: Some(1249), added: 3724, mode: `MaxEncodedLen`)
+    /// Storage: `PredictionMarkets::NextMarketSeriesId` (r:1 w:1)
+    /// Proof: `PredictionMarkets::NextMarketSeriesId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `PredictionMarkets::MarketIdToSeriesId` (r:0 w:1)
+    /// Proof: `PredictionMarkets::MarketIdToSeriesId` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+    /// Storage: `PredictionMarkets::MarketSeries` (r:0 w:1)
+    /// Proof: `PredictionMarkets::MarketSeries` (`max_values`: None, `max_size`: Some(183), added: 2658, mode: `MaxEncodedLen`)
+    /// Storage: `PredictionMarkets::MarketSeriesRolloversPerCloseBlock` (r:1 w:1)
+    /// Proof: `PredictionMarkets::MarketSeriesRolloversPerCloseBlock` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+    /// Storage: `OracleRegistry::Oracles` (r:2 w:0)
+    /// Proof: `OracleRegistry::Oracles` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+    /// Storage: `OracleRegistry::UnbondingOracles` (r:1 w:0)
+    /// Proof: `OracleRegistry::UnbondingOracles` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+    /// Storage: `PredictionMarkets::MarketIdToFallbackOracle` (r:0 w:1)
+    /// Proof: `PredictionMarkets::MarketIdToFallbackOracle` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+    fn create_market_series() -> Weight {
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_prediction_markets` to replace it.
+        Weight::from_parts(58_213_000, 4714)
+            .saturating_add(T::DbWeight::get().reads(7))
+            .saturating_add(T::DbWeight::get().writes(6))
+    }
+    /// Storage: `PredictionMarkets::MarketSeries` (r:1 w:1)
+    /// Proof: `PredictionMarkets::MarketSeries` (`max_values`: None, `max_size`: Some(183), added: 2658, mode: `MaxEncodedLen`)
+    /// Storage: `PredictionMarkets::MarketIdToSeriesId` (r:0 w:1)
+    /// Proof: `PredictionMarkets::MarketIdToSeriesId` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+    /// Storage: `Balances::Reserves` (r:1 w:1)
+    /// Proof: `Balances::Reserves` (`max_values`: None, `max_size`: Some(1249), added: 3724, mode: `MaxEncodedLen`)
+    /// Storage: `PredictionMarkets::PendingMarketSeriesRollovers` (r:1 w:1)
+    /// Proof: `PredictionMarkets::PendingMarketSeriesRollovers` (`max_values`: None, `max_size`: Some(12), added: 2487, mode: `MaxEncodedLen`)
+    /// Storage: `PredictionMarkets::MarketSeriesRolloversPerCloseBlock` (r:1 w:1)
+    /// Proof: `PredictionMarkets::MarketSeriesRolloversPerCloseBlock` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+    fn cancel_market_series() -> Weight {
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_prediction_markets` to replace it.
+        Weight::from_parts(54_257_000, 4714)
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(5))
+    }
 }
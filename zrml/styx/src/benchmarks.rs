@@ -30,7 +30,7 @@ use crate::Pallet as Styx;
 use frame_benchmarking::{benchmarks, whitelisted_caller};
 use frame_support::traits::{Currency, EnsureOrigin, UnfilteredDispatchable};
 use frame_system::RawOrigin;
-use sp_runtime::SaturatedConversion;
+use sp_runtime::{Perbill, SaturatedConversion};
 use zeitgeist_primitives::constants::BASE;
 
 benchmarks! {
@@ -49,6 +49,23 @@ benchmarks! {
         let call = Call::<T>::set_burn_amount { amount };
     }: { call.dispatch_bypass_filter(origin)? }
 
+    set_crossing_metadata {
+        let caller: T::AccountId = whitelisted_caller();
+        let balance = (90_000_000 * BASE).saturated_into();
+        let _ = T::Currency::deposit_creating(&caller, balance);
+        Pallet::<T>::cross(RawOrigin::Signed(caller.clone()).into())?;
+        let metadata = Some(T::Hash::default());
+    }: _(RawOrigin::Signed(caller), metadata)
+
+    revoke_crossing {
+        let origin = T::RevokeOrigin::try_successful_origin().unwrap();
+        let who: T::AccountId = whitelisted_caller();
+        let balance = (90_000_000 * BASE).saturated_into();
+        let _ = T::Currency::deposit_creating(&who, balance);
+        Pallet::<T>::cross(RawOrigin::Signed(who.clone()).into())?;
+        let call = Call::<T>::revoke_crossing { who, refund: Perbill::one() };
+    }: { call.dispatch_bypass_filter(origin)? }
+
     impl_benchmark_test_suite!(
         Styx,
         crate::mock::ExtBuilder::default().build(),
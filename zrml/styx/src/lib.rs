@@ -22,8 +22,10 @@
 extern crate alloc;
 
 mod benchmarks;
+pub mod migrations;
 mod mock;
 mod tests;
+pub mod types;
 pub mod weights;
 pub use pallet::*;
 
@@ -31,13 +33,21 @@ pub use pallet::*;
 pub mod pallet {
     use frame_support::{pallet_prelude::*, traits::Currency};
     use frame_system::pallet_prelude::*;
-    use sp_runtime::{traits::Zero, SaturatedConversion};
-    use zeitgeist_primitives::types::Balance;
+    use sp_runtime::{traits::Zero, Perbill, SaturatedConversion};
+    use zeitgeist_primitives::{traits::StyxApi, types::Balance};
 
-    use crate::weights::WeightInfoZeitgeist;
+    use crate::{types::CrossingInfo, weights::WeightInfoZeitgeist};
+
+    /// The current storage version.
+    pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+    pub(crate) type CrossingInfoOf<T> = CrossingInfo<Balance, <T as frame_system::Config>::Hash>;
 
     #[pallet::config]
     pub trait Config: frame_system::Config {
+        /// The origin that is allowed to revoke crossings.
+        type RevokeOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
         /// The origin that is allowed to set the amount burned when crossing Styx.
         type SetBurnAmountOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
@@ -49,11 +59,14 @@ pub mod pallet {
     }
 
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
-    /// Keep track of crossings. Accounts are only able to cross once.
+    /// Keep track of crossings. Accounts are only able to cross once, unless their crossing is
+    /// revoked.
     #[pallet::storage]
-    pub type Crossings<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, ()>;
+    pub type Crossings<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, CrossingInfoOf<T>>;
 
     #[pallet::type_value]
     pub fn DefaultBurnAmount<T: Config>() -> Balance {
@@ -71,6 +84,10 @@ pub mod pallet {
         AccountCrossed(T::AccountId, Balance),
         /// The crossing fee was changed.
         CrossingFeeChanged(Balance),
+        /// An account changed the metadata attached to their crossing.
+        CrossingMetadataSet(T::AccountId, Option<T::Hash>),
+        /// The crossing of an account was revoked and the given amount was refunded.
+        CrossingRevoked(T::AccountId, Balance),
     }
 
     #[pallet::error]
@@ -79,6 +96,8 @@ pub mod pallet {
         FundDoesNotHaveEnoughFreeBalance,
         /// Account has already crossed.
         HasAlreadyCrossed,
+        /// Account has not crossed.
+        HasNotCrossed,
     }
 
     #[pallet::call]
@@ -107,7 +126,10 @@ pub mod pallet {
                 &who,
                 amount,
             );
-            Crossings::<T>::insert(&who, ());
+            Crossings::<T>::insert(
+                &who,
+                CrossingInfo { burned: amount.saturated_into(), metadata: None },
+            );
 
             Self::deposit_event(Event::AccountCrossed(who, amount.saturated_into()));
 
@@ -133,5 +155,73 @@ pub mod pallet {
 
             Ok(())
         }
+
+        /// Attaches a hash of an avatar or other metadata to the crossing of the signer, or
+        /// removes it if `metadata` is `None`.
+        ///
+        /// # Arguments
+        ///
+        /// * `metadata`: The hash of the metadata.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::set_crossing_metadata())]
+        pub fn set_crossing_metadata(
+            origin: OriginFor<T>,
+            metadata: Option<T::Hash>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Crossings::<T>::try_mutate(&who, |maybe_info| -> DispatchResult {
+                let info = maybe_info.as_mut().ok_or(Error::<T>::HasNotCrossed)?;
+                info.metadata = metadata;
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::CrossingMetadataSet(who, metadata));
+
+            Ok(())
+        }
+
+        /// Revokes the crossing of `who`. Ensures the RevokeOrigin in the runtime.
+        /// Intended to be called by a governing body like the council.
+        ///
+        /// The share `refund` of the amount burned by `who` to cross is minted back to `who`. The
+        /// account may cross again afterwards by burning the current burn amount.
+        ///
+        /// # Arguments
+        ///
+        /// * `who`: The account whose crossing is revoked.
+        /// * `refund`: The share of the burned amount which is refunded.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::revoke_crossing())]
+        pub fn revoke_crossing(
+            origin: OriginFor<T>,
+            who: T::AccountId,
+            refund: Perbill,
+        ) -> DispatchResult {
+            T::RevokeOrigin::ensure_origin(origin)?;
+
+            let info = Crossings::<T>::take(&who).ok_or(Error::<T>::HasNotCrossed)?;
+            let refunded = refund.mul_floor(info.burned);
+            if !refunded.is_zero() {
+                let _imb = T::Currency::deposit_creating(&who, refunded.saturated_into());
+            }
+
+            Self::deposit_event(Event::CrossingRevoked(who, refunded));
+
+            Ok(())
+        }
+    }
+
+    impl<T: Config> StyxApi for Pallet<T> {
+        type AccountId = T::AccountId;
+        type Hash = T::Hash;
+
+        fn has_crossed(who: &Self::AccountId) -> bool {
+            Crossings::<T>::contains_key(who)
+        }
+
+        fn crossing_metadata(who: &Self::AccountId) -> Option<Self::Hash> {
+            Crossings::<T>::get(who).and_then(|info| info.metadata)
+        }
     }
 }
@@ -0,0 +1,138 @@
+// Copyright 2025 Forecasting Technologies LTD.
+//
+// This file is part of Zeitgeist.
+//
+// Zeitgeist is free software: you can redistribute it and/or modify it
+// under the terms of the GNU General Public License as published by the
+// Free Software Foundation, either version 3 of the License, or (at
+// your option) any later version.
+//
+// Zeitgeist is distributed in the hope that it will be useful, but
+// WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU
+// General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Zeitgeist. If not, see <https://www.gnu.org/licenses/>.
+
+pub mod mbm {
+    use crate::{types::CrossingInfo, Config, Crossings, Pallet};
+    use core::marker::PhantomData;
+    use frame_support::{
+        migrations::{SteppedMigration, SteppedMigrationError},
+        pallet_prelude::ConstU32,
+        storage::unhashed,
+        traits::{Get, StorageVersion},
+        weights::WeightMeter,
+        BoundedVec,
+    };
+    use log::info;
+    use sp_runtime::traits::Zero;
+
+    const LOG_TARGET: &str = "runtime::zrml-styx";
+
+    /// Target pallet storage version after migration.
+    const TARGET_STORAGE_VERSION: u16 = 1;
+
+    /// Multi-block migration that turns the legacy `()` crossings into `CrossingInfo` without
+    /// metadata. The amount burned by legacy crossings is unknown and recorded as zero. The cursor
+    /// is the last account that was processed.
+    pub struct MigrateCrossingsToCrossingInfo<T>(PhantomData<T>);
+
+    impl<T: Config> MigrateCrossingsToCrossingInfo<T> {
+        const IDENT: &'static [u8] = b"styx-crossing-info-v1";
+    }
+
+    impl<T: Config> SteppedMigration for MigrateCrossingsToCrossingInfo<T> {
+        type Cursor = T::AccountId;
+        type Identifier = BoundedVec<u8, ConstU32<64>>;
+
+        fn id() -> Self::Identifier {
+            BoundedVec::try_from(Self::IDENT.to_vec()).expect("fits in Identifier bound; qed")
+        }
+
+        fn step(
+            cursor: Option<Self::Cursor>,
+            meter: &mut WeightMeter,
+        ) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+            if StorageVersion::get::<Pallet<T>>() >= StorageVersion::new(TARGET_STORAGE_VERSION) {
+                return Ok(None);
+            }
+
+            let weight_per_crossing = T::DbWeight::get().reads_writes(1, 1);
+
+            let mut iter = if let Some(last_account) = cursor.as_ref() {
+                Crossings::<T>::iter_keys_from(Crossings::<T>::hashed_key_for(last_account))
+            } else {
+                Crossings::<T>::iter_keys()
+            };
+            let mut last_account = cursor.clone();
+
+            loop {
+                if meter.try_consume(weight_per_crossing).is_err() {
+                    if last_account == cursor {
+                        return Err(SteppedMigrationError::InsufficientWeight {
+                            required: weight_per_crossing,
+                        });
+                    }
+                    return Ok(last_account);
+                }
+
+                let Some(who) = iter.next() else {
+                    break;
+                };
+
+                // Legacy crossings are stored as `()`, which encodes to zero bytes. Crossings made
+                // after the upgrade are already stored as `CrossingInfo`.
+                let key = Crossings::<T>::hashed_key_for(&who);
+                let is_legacy = unhashed::get_raw(&key).is_some_and(|raw| raw.is_empty());
+                if is_legacy {
+                    Crossings::<T>::insert(
+                        &who,
+                        CrossingInfo { burned: Zero::zero(), metadata: None },
+                    );
+                }
+                last_account = Some(who);
+            }
+
+            StorageVersion::new(TARGET_STORAGE_VERSION).put::<Pallet<T>>();
+            info!(target: LOG_TARGET, "MigrateCrossingsToCrossingInfo: migration complete");
+
+            Ok(None)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::mbm::MigrateCrossingsToCrossingInfo;
+    use crate::{mock::*, types::CrossingInfo, Crossings};
+    use frame_support::{
+        migrations::SteppedMigration,
+        storage::unhashed,
+        traits::StorageVersion,
+        weights::{Weight, WeightMeter},
+    };
+
+    #[test]
+    fn migration_converts_legacy_crossings() {
+        ExtBuilder::default().build().execute_with(|| {
+            for who in [ALICE, BOB] {
+                unhashed::put_raw(&Crossings::<Runtime>::hashed_key_for(who), &[]);
+            }
+            let crossing = CrossingInfo { burned: 7, metadata: Some([1u8; 32].into()) };
+            Crossings::<Runtime>::insert(CHARLIE, crossing.clone());
+            StorageVersion::new(0).put::<Styx>();
+
+            let mut meter = WeightMeter::with_limit(Weight::from_parts(u64::MAX, u64::MAX));
+            let cursor = MigrateCrossingsToCrossingInfo::<Runtime>::step(None, &mut meter).unwrap();
+            assert!(cursor.is_none());
+
+            assert_eq!(StorageVersion::get::<Styx>(), StorageVersion::new(1));
+            let legacy = CrossingInfo { burned: 0, metadata: None };
+            assert_eq!(Crossings::<Runtime>::get(ALICE), Some(legacy.clone()));
+            assert_eq!(Crossings::<Runtime>::get(BOB), Some(legacy));
+            assert_eq!(Crossings::<Runtime>::get(CHARLIE), Some(crossing));
+        });
+    }
+}
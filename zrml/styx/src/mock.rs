@@ -49,6 +49,7 @@ construct_runtime!(
 
 impl crate::Config for Runtime {
     type Currency = Balances;
+    type RevokeOrigin = EnsureSignedBy<Sudo, AccountIdTest>;
     type RuntimeEvent = RuntimeEvent;
     type SetBurnAmountOrigin = EnsureSignedBy<Sudo, AccountIdTest>;
     type WeightInfo = zrml_styx::weights::WeightInfo<Runtime>;
@@ -18,8 +18,10 @@
 
 #![cfg(test)]
 
-use crate::{mock::*, Crossings, Error, Event};
+use crate::{mock::*, types::CrossingInfo, Crossings, Error, Event};
 use frame_support::{assert_noop, assert_ok, error::BadOrigin, traits::fungible::Mutate};
+use sp_runtime::Perbill;
+use zeitgeist_primitives::{traits::StyxApi, types::Hash};
 
 #[test]
 fn cross_slashes_funds_and_stores_crossing() {
@@ -30,7 +32,10 @@ fn cross_slashes_funds_and_stores_crossing() {
         assert_ok!(Styx::cross(RuntimeOrigin::signed(ALICE)));
         let balance_after_crossing = Balances::free_balance(ALICE);
         let diff = original_balance - balance_after_crossing;
-        assert!(Crossings::<Runtime>::contains_key(ALICE));
+        assert_eq!(
+            Crossings::<Runtime>::get(ALICE),
+            Some(CrossingInfo { burned: burn_amount, metadata: None })
+        );
         assert_eq!(diff, burn_amount);
     });
 }
@@ -87,3 +92,86 @@ fn should_emit_account_crossed_event_with_correct_value() {
         );
     });
 }
+
+#[test]
+fn set_crossing_metadata_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        frame_system::Pallet::<Runtime>::set_block_number(1);
+        assert_ok!(Styx::cross(RuntimeOrigin::signed(ALICE)));
+        let metadata = Some(Hash::repeat_byte(1));
+        assert_ok!(Styx::set_crossing_metadata(RuntimeOrigin::signed(ALICE), metadata));
+        System::assert_last_event(Event::CrossingMetadataSet(ALICE, metadata).into());
+        assert_eq!(Crossings::<Runtime>::get(ALICE).unwrap().metadata, metadata);
+        assert_eq!(Styx::crossing_metadata(&ALICE), metadata);
+
+        assert_ok!(Styx::set_crossing_metadata(RuntimeOrigin::signed(ALICE), None));
+        assert_eq!(Styx::crossing_metadata(&ALICE), None);
+    });
+}
+
+#[test]
+fn set_crossing_metadata_fails_if_account_has_not_crossed() {
+    ExtBuilder::default().build().execute_with(|| {
+        frame_system::Pallet::<Runtime>::set_block_number(1);
+        assert_noop!(
+            Styx::set_crossing_metadata(RuntimeOrigin::signed(ALICE), Some(Hash::repeat_byte(1))),
+            Error::<Runtime>::HasNotCrossed
+        );
+    });
+}
+
+#[test]
+fn revoke_crossing_refunds_share_of_burned_amount() {
+    ExtBuilder::default().build().execute_with(|| {
+        frame_system::Pallet::<Runtime>::set_block_number(1);
+        let burn_amount = crate::BurnAmount::<Runtime>::get();
+        assert_ok!(Styx::cross(RuntimeOrigin::signed(ALICE)));
+        let balance_after_crossing = Balances::free_balance(ALICE);
+        assert!(Styx::has_crossed(&ALICE));
+
+        assert_ok!(Styx::revoke_crossing(
+            RuntimeOrigin::signed(SUDO),
+            ALICE,
+            Perbill::from_percent(50)
+        ));
+
+        System::assert_last_event(Event::CrossingRevoked(ALICE, burn_amount / 2).into());
+        assert!(!Styx::has_crossed(&ALICE));
+        assert_eq!(Balances::free_balance(ALICE), balance_after_crossing + burn_amount / 2);
+    });
+}
+
+#[test]
+fn revoked_account_can_cross_again() {
+    ExtBuilder::default().build().execute_with(|| {
+        frame_system::Pallet::<Runtime>::set_block_number(1);
+        assert_ok!(Styx::cross(RuntimeOrigin::signed(ALICE)));
+        assert_ok!(Styx::revoke_crossing(RuntimeOrigin::signed(SUDO), ALICE, Perbill::zero()));
+        System::assert_last_event(Event::CrossingRevoked(ALICE, 0).into());
+        assert_ok!(Styx::cross(RuntimeOrigin::signed(ALICE)));
+        assert!(Styx::has_crossed(&ALICE));
+    });
+}
+
+#[test]
+fn revoke_crossing_fails_if_account_has_not_crossed() {
+    ExtBuilder::default().build().execute_with(|| {
+        frame_system::Pallet::<Runtime>::set_block_number(1);
+        assert_noop!(
+            Styx::revoke_crossing(RuntimeOrigin::signed(SUDO), ALICE, Perbill::one()),
+            Error::<Runtime>::HasNotCrossed
+        );
+    });
+}
+
+#[test]
+fn revoke_crossing_fails_with_unauthorized_caller() {
+    ExtBuilder::default().build().execute_with(|| {
+        frame_system::Pallet::<Runtime>::set_block_number(1);
+        assert_ok!(Styx::cross(RuntimeOrigin::signed(ALICE)));
+        assert_noop!(
+            Styx::revoke_crossing(RuntimeOrigin::signed(BOB), ALICE, Perbill::one()),
+            BadOrigin
+        );
+    });
+}
@@ -53,21 +53,21 @@ use frame_support::{traits::Get, weights::Weight};
 pub trait WeightInfoZeitgeist {
     fn cross() -> Weight;
     fn set_burn_amount() -> Weight;
+    fn set_crossing_metadata() -> Weight;
+    fn revoke_crossing() -> Weight;
 }
 
 /// Weight functions for zrml_styx (automatically generated)
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
     /// Storage: `Styx::Crossings` (r:1 w:1)
-    /// Proof: `Styx::Crossings` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+    /// Proof: `Styx::Crossings` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
     /// Storage: `Styx::BurnAmount` (r:1 w:0)
     /// Proof: `Styx::BurnAmount` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
     fn cross() -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `109`
-        //  Estimated: `3513`
-        // Minimum execution time: 39_791 nanoseconds.
-        Weight::from_parts(41_711_000, 3513)
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_styx` to replace it.
+        Weight::from_parts(41_711_000, 3562)
             .saturating_add(T::DbWeight::get().reads(2))
             .saturating_add(T::DbWeight::get().writes(1))
     }
@@ -80,4 +80,24 @@ impl<T: frame_system::Config> WeightInfoZeitgeist for WeightInfo<T> {
         // Minimum execution time: 7_770 nanoseconds.
         Weight::from_parts(8_160_000, 0).saturating_add(T::DbWeight::get().writes(1))
     }
+    /// Storage: `Styx::Crossings` (r:1 w:1)
+    /// Proof: `Styx::Crossings` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+    fn set_crossing_metadata() -> Weight {
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_styx` to replace it.
+        Weight::from_parts(14_750_000, 3562)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: `Styx::Crossings` (r:1 w:1)
+    /// Proof: `Styx::Crossings` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+    fn revoke_crossing() -> Weight {
+        // Placeholder weight: hand-written estimate, not measured yet. Rerun the
+        // benchmarks of `zrml_styx` to replace it.
+        Weight::from_parts(43_120_000, 3597)
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
 }